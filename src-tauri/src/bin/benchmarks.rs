@@ -0,0 +1,25 @@
+//! Performance benchmarks
+//!
+//! Usage: cargo run --release --bin benchmarks -- [iterations] [--json]
+//!
+//! Runs the same benchmarks as the hidden `run_benchmarks` command and
+//! prints the timings with the machine profile.
+
+use std::env;
+
+use flint::core::bench::run_benchmarks;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let json = args.iter().any(|arg| arg == "--json");
+    let iterations = args.iter().find_map(|arg| arg.parse().ok()).unwrap_or(10);
+
+    match run_benchmarks(iterations) {
+        Ok(report) if json => println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default()),
+        Ok(report) => print!("{}", report.to_text()),
+        Err(e) => {
+            eprintln!("Benchmarks failed: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Tauri commands for Wwise audio banks and packages
+
+use crate::core::audio::events::SkinAudioEvents;
+use crate::core::audio::{self, AudioContainerInfo, AudioExtractResult, AudioReplaceResult};
+use crate::core::league::guard::ensure_outside_league;
+use crate::error::CommandError;
+use std::path::PathBuf;
+
+/// Lists the embedded WEMs and events of a BNK or WPK file
+///
+/// # Arguments
+/// * `path` - Path to the `.bnk` or `.wpk` file
+///
+/// # Returns
+/// * `Ok(AudioContainerInfo)` - Container version, WEM entries with their codec, and event ids
+/// * `Err(CommandError)` - Error message if the file isn't a valid bank or package
+#[tauri::command]
+pub async fn list_audio_events(path: String) -> Result<AudioContainerInfo, CommandError> {
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || audio::read_audio_container(&path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Maps the Wwise events of a skin or audio BIN to their banks and WEMs
+///
+/// Bank files are looked up next to the BIN and in its parent folders;
+/// events stay listed without WEMs when their banks aren't found.
+///
+/// # Arguments
+/// * `bin_path` - Path to the skin BIN (or an audio/events BIN)
+///
+/// # Returns
+/// * `Ok(SkinAudioEvents)` - Bank units with their events and the WEMs each can play
+/// * `Err(CommandError)` - Error message if the BIN can't be read
+#[tauri::command]
+pub async fn list_audio_events_for_skin(bin_path: String) -> Result<SkinAudioEvents, CommandError> {
+    let bin_path = PathBuf::from(bin_path);
+
+    tokio::task::spawn_blocking(move || audio::events::list_skin_audio_events(&bin_path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Extracts the WEMs of a BNK or WPK file
+///
+/// # Arguments
+/// * `path` - Path to the `.bnk` or `.wpk` file
+/// * `output_dir` - Folder to write the files to
+/// * `names` - Entry names to extract; all of them if omitted
+/// * `convert` - Convert PCM WEMs to WAV and Ogg streams to `.ogg` (default: false)
+///
+/// # Returns
+/// * `Ok(AudioExtractResult)` - Written files and WEMs that couldn't be converted
+/// * `Err(CommandError)` - Error message if the file can't be read or written
+#[tauri::command]
+pub async fn extract_audio(
+    path: String,
+    output_dir: String,
+    names: Option<Vec<String>>,
+    convert: Option<bool>,
+) -> Result<AudioExtractResult, CommandError> {
+    ensure_outside_league(&output_dir)?;
+    let path = PathBuf::from(path);
+    let output_dir = PathBuf::from(output_dir);
+
+    tokio::task::spawn_blocking(move || {
+        audio::extract_audio(&path, &output_dir, names.as_deref(), convert.unwrap_or(false))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Replaces one WEM inside a BNK or WPK file
+///
+/// # Arguments
+/// * `path` - Path to the `.bnk` or `.wpk` file, rewritten in place
+/// * `entry` - Entry name as returned by `list_audio_events` (e.g. `"12345.wem"`)
+/// * `source_path` - A `.wem` file, or a 16-bit PCM WAV to convert
+///
+/// # Returns
+/// * `Ok(AudioReplaceResult)` - Old and new sizes and the codec of the new WEM
+/// * `Err(CommandError)` - Error message if the entry doesn't exist or the source can't be used
+#[tauri::command]
+pub async fn replace_audio_clip(
+    path: String,
+    entry: String,
+    source_path: String,
+) -> Result<AudioReplaceResult, CommandError> {
+    ensure_outside_league(&path)?;
+    let path = PathBuf::from(path);
+    let source_path = PathBuf::from(source_path);
+
+    tokio::task::spawn_blocking(move || audio::replace_audio_clip(&path, &entry, &source_path))
+        .await?
+        .map_err(CommandError::from)
+}
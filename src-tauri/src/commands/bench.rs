@@ -0,0 +1,24 @@
+//! Tauri command for the performance benchmarks
+
+use crate::core::bench::{self, BenchmarkReport};
+use crate::error::CommandError;
+
+/// Runs the performance benchmarks on generated sample data
+///
+/// Not exposed in the UI; maintainers ask for its output when a performance
+/// regression is reported.
+///
+/// # Arguments
+/// * `iterations` - Timed runs per benchmark (default: 5)
+///
+/// # Returns
+/// * `Ok(BenchmarkReport)` - Timings and the machine profile
+/// * `Err(CommandError)` - Error message if the sample data can't be written
+#[tauri::command]
+pub async fn run_benchmarks(iterations: Option<u32>) -> Result<BenchmarkReport, CommandError> {
+    tracing::info!("Running performance benchmarks");
+
+    let report = tokio::task::spawn_blocking(move || bench::run_benchmarks(iterations.unwrap_or(5))).await??;
+    tracing::info!("Benchmark results:\n{}", report.to_text());
+    Ok(report)
+}
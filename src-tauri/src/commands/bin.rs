@@ -1,17 +1,56 @@
-use crate::core::bin::{bin_to_json, bin_to_text, json_to_bin, read_bin, text_to_bin, write_bin};
-use crate::state::HashtableState;
+use crate::core::bin::{
+    bin_to_json, bin_to_text, get_cached_bin_hashes, json_to_bin, read_bin, text_to_bin,
+    text_to_bin_strict, write_bin,
+};
+use crate::core::bin::editor::{self, BinField, BinObjectNode, BinTreeNode, BinValue};
+use crate::core::bin::limits::{self, BinSettings};
+use crate::core::bin::search::{self, BinReplaceResult, BinSearchMode, BinSearchResult};
+use crate::core::bin::LazyBin;
+use crate::commands::app_data_dir;
+use crate::core::project::variables::ProjectVariables;
+use crate::core::store::write_replacing;
+use crate::state::{HashtableState, RequestState};
+use crate::error::{CommandError, Error};
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use tauri::{AppHandle, State};
 use std::fs;
 use std::path::Path;
+use ts_rs::TS;
 
 /// Metadata information about a bin file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct BinInfo {
     pub entry_count: usize,
     pub version: u32,
 }
 
+/// Header of one object in a BIN opened in streaming mode
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinObjectHeader {
+    pub path_hash: u32,
+    pub class_hash: u32,
+    /// Entry path, if the hash is known
+    pub path_name: Option<String>,
+    /// Class name, if the hash is known
+    pub class_name: Option<String>,
+    /// Size of the object body in bytes
+    pub size: u32,
+}
+
+/// Object listing of a BIN read without parsing object bodies
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinIndex {
+    pub version: u32,
+    pub is_override: bool,
+    pub dependencies: Vec<String>,
+    #[ts(type = "number")]
+    pub file_size: u64,
+    pub objects: Vec<BinObjectHeader>,
+}
+
 /// Converts a binary .bin file to Python-like text format (.py)
 ///
 /// # Arguments
@@ -20,36 +59,36 @@ pub struct BinInfo {
 /// * `state` - The managed HashtableState for hash resolution
 ///
 /// # Returns
-/// * `Result<(), String>` - Ok if conversion succeeded, error message otherwise
+/// * `Result<(), CommandError>` - Ok if conversion succeeded, error message otherwise
 #[tauri::command]
 pub async fn convert_bin_to_text(
     input_path: String,
     output_path: String,
     state: State<'_, HashtableState>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     tracing::info!("Converting bin to text: {} -> {}", input_path, output_path);
     
     // Validate input path
     if input_path.is_empty() {
         tracing::error!("Input path cannot be empty");
-        return Err("Input path cannot be empty".to_string());
+        return Err(CommandError::plain("error.input_path_empty"));
     }
     if output_path.is_empty() {
         tracing::error!("Output path cannot be empty");
-        return Err("Output path cannot be empty".to_string());
+        return Err(CommandError::plain("error.output_path_empty"));
     }
 
     let input = Path::new(&input_path);
     if !input.exists() {
         tracing::error!("Input file does not exist: {}", input_path);
-        return Err(format!("Input file does not exist: {}", input_path));
+        return Err(CommandError::new("error.file_not_found", [("path", input_path.clone())]));
     }
 
     // Read the binary file
     let data = fs::read(input)
         .map_err(|e| {
             tracing::error!("Failed to read input file '{}': {}", input_path, e);
-            format!("Failed to read input file '{}': {}", input_path, e)
+            Error::io_with_path(e, input)
         })?;
 
     tracing::debug!("Read {} bytes from {}", data.len(), input_path);
@@ -58,7 +97,7 @@ pub async fn convert_bin_to_text(
     let bin = read_bin(&data)
         .map_err(|e| {
             tracing::error!("Failed to parse bin file '{}': {}", input_path, e);
-            format!("Failed to parse bin file '{}': {}", input_path, e)
+            bin_error("error.bin_parse", &input_path, e)
         })?;
 
     tracing::debug!("Parsed bin file with {} objects", bin.objects.len());
@@ -71,14 +110,15 @@ pub async fn convert_bin_to_text(
     let text = bin_to_text(&bin, hashtable_ref)
         .map_err(|e| {
             tracing::error!("Failed to convert to text: {}", e);
-            format!("Failed to convert to text: {}", e)
+            bin_error("error.bin_to_text", &input_path, e)
         })?;
+    let text = collapse_project_variables(input, text);
 
     // Write to output file
-    fs::write(&output_path, text)
+    write_replacing(Path::new(&output_path), text.as_bytes())
         .map_err(|e| {
             tracing::error!("Failed to write output file '{}': {}", output_path, e);
-            format!("Failed to write output file '{}': {}", output_path, e)
+            Error::io_with_path(e, &output_path)
         })?;
 
     tracing::info!("Successfully converted bin to text: {}", output_path);
@@ -94,33 +134,33 @@ pub async fn convert_bin_to_text(
 /// * `state` - The managed HashtableState for hash resolution
 ///
 /// # Returns
-/// * `Result<(), String>` - Ok if conversion succeeded, error message otherwise
+/// * `Result<(), CommandError>` - Ok if conversion succeeded, error message otherwise
 #[tauri::command]
 pub async fn convert_bin_to_json(
     input_path: String,
     output_path: String,
     state: State<'_, HashtableState>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     // Validate input path
     if input_path.is_empty() {
-        return Err("Input path cannot be empty".to_string());
+        return Err(CommandError::plain("error.input_path_empty"));
     }
     if output_path.is_empty() {
-        return Err("Output path cannot be empty".to_string());
+        return Err(CommandError::plain("error.output_path_empty"));
     }
 
     let input = Path::new(&input_path);
     if !input.exists() {
-        return Err(format!("Input file does not exist: {}", input_path));
+        return Err(CommandError::new("error.file_not_found", [("path", input_path.clone())]));
     }
 
     // Read the binary file
     let data = fs::read(input)
-        .map_err(|e| format!("Failed to read input file: {}", e))?;
+        .map_err(|e| Error::io_with_path(e, input))?;
 
     // Parse the bin file
     let bin = read_bin(&data)
-        .map_err(|e| format!("Failed to parse bin file: {}", e))?;
+        .map_err(|e| bin_error("error.bin_parse", &input_path, e))?;
 
     // Get hashtable for resolution (lazy loaded on first use)
     let hashtable = state.get_hashtable();
@@ -128,11 +168,11 @@ pub async fn convert_bin_to_json(
 
     // Convert to JSON format
     let json = bin_to_json(&bin, hashtable_ref)
-        .map_err(|e| format!("Failed to convert to JSON: {}", e))?;
+        .map_err(|e| bin_error("error.bin_to_json", &input_path, e))?;
 
     // Write to output file
-    fs::write(&output_path, json)
-        .map_err(|e| format!("Failed to write output file: {}", e))?;
+    write_replacing(Path::new(&output_path), json.as_bytes())
+        .map_err(|e| Error::io_with_path(e, &output_path))?;
 
     Ok(())
 }
@@ -142,39 +182,41 @@ pub async fn convert_bin_to_json(
 /// # Arguments
 /// * `input_path` - Path to the input .py file
 /// * `output_path` - Path to the output .bin file
+/// * `strict` - Fail with diagnostics on unknown names, malformed hashes and kind mismatches
 /// * `state` - The managed HashtableState for string-to-hash conversion
 ///
 /// # Returns
-/// * `Result<(), String>` - Ok if conversion succeeded, error message otherwise
+/// * `Result<(), CommandError>` - Ok if conversion succeeded, error message otherwise
 #[tauri::command]
 pub async fn convert_text_to_bin(
     input_path: String,
     output_path: String,
+    strict: Option<bool>,
     state: State<'_, HashtableState>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     tracing::info!("Converting text to bin: {} -> {}", input_path, output_path);
     
     // Validate input path
     if input_path.is_empty() {
         tracing::error!("Input path cannot be empty");
-        return Err("Input path cannot be empty".to_string());
+        return Err(CommandError::plain("error.input_path_empty"));
     }
     if output_path.is_empty() {
         tracing::error!("Output path cannot be empty");
-        return Err("Output path cannot be empty".to_string());
+        return Err(CommandError::plain("error.output_path_empty"));
     }
 
     let input = Path::new(&input_path);
     if !input.exists() {
         tracing::error!("Input file does not exist: {}", input_path);
-        return Err(format!("Input file does not exist: {}", input_path));
+        return Err(CommandError::new("error.file_not_found", [("path", input_path.clone())]));
     }
 
     // Read the text file
     let text = fs::read_to_string(input)
         .map_err(|e| {
             tracing::error!("Failed to read input file '{}': {}", input_path, e);
-            format!("Failed to read input file '{}': {}", input_path, e)
+            Error::io_with_path(e, input)
         })?;
 
     tracing::debug!("Read {} characters from {}", text.len(), input_path);
@@ -183,11 +225,17 @@ pub async fn convert_text_to_bin(
     let hashtable = state.get_hashtable();
     let hashtable_ref = hashtable.as_ref().map(|h| h.as_ref());
 
+    let text = expand_project_variables(input, &text)?;
+
     // Parse text to bin
-    let bin = text_to_bin(&text, hashtable_ref)
-        .map_err(|e| {
+    let bin = if strict.unwrap_or(false) {
+        text_to_bin_strict(&text, Some(&*get_cached_bin_hashes().read()))
+    } else {
+        text_to_bin(&text, hashtable_ref)
+    }
+    .map_err(|e| {
             tracing::error!("Failed to parse text from '{}': {}", input_path, e);
-            format!("Failed to parse text from '{}': {}", input_path, e)
+            bin_error("error.bin_text_parse", &input_path, e)
         })?;
 
     tracing::debug!("Parsed text to bin with {} objects", bin.objects.len());
@@ -196,14 +244,14 @@ pub async fn convert_text_to_bin(
     let data = write_bin(&bin)
         .map_err(|e| {
             tracing::error!("Failed to write bin: {}", e);
-            format!("Failed to write bin: {}", e)
+            bin_error("error.bin_write", &output_path, e)
         })?;
 
     // Write to output file
-    fs::write(&output_path, data)
+    write_replacing(Path::new(&output_path), &data)
         .map_err(|e| {
             tracing::error!("Failed to write output file '{}': {}", output_path, e);
-            format!("Failed to write output file '{}': {}", output_path, e)
+            Error::io_with_path(e, &output_path)
         })?;
 
     tracing::info!("Successfully converted text to bin: {}", output_path);
@@ -219,29 +267,29 @@ pub async fn convert_text_to_bin(
 /// * `state` - The managed HashtableState for string-to-hash conversion
 ///
 /// # Returns
-/// * `Result<(), String>` - Ok if conversion succeeded, error message otherwise
+/// * `Result<(), CommandError>` - Ok if conversion succeeded, error message otherwise
 #[tauri::command]
 pub async fn convert_json_to_bin(
     input_path: String,
     output_path: String,
     state: State<'_, HashtableState>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     // Validate input path
     if input_path.is_empty() {
-        return Err("Input path cannot be empty".to_string());
+        return Err(CommandError::plain("error.input_path_empty"));
     }
     if output_path.is_empty() {
-        return Err("Output path cannot be empty".to_string());
+        return Err(CommandError::plain("error.output_path_empty"));
     }
 
     let input = Path::new(&input_path);
     if !input.exists() {
-        return Err(format!("Input file does not exist: {}", input_path));
+        return Err(CommandError::new("error.file_not_found", [("path", input_path.clone())]));
     }
 
     // Read the JSON file
     let json = fs::read_to_string(input)
-        .map_err(|e| format!("Failed to read input file: {}", e))?;
+        .map_err(|e| Error::io_with_path(e, input))?;
 
     // Get hashtable for conversion (lazy loaded on first use)
     let hashtable = state.get_hashtable();
@@ -249,15 +297,15 @@ pub async fn convert_json_to_bin(
 
     // Parse JSON to bin
     let bin = json_to_bin(&json, hashtable_ref)
-        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        .map_err(|e| bin_error("error.bin_json_parse", &input_path, e))?;
 
     // Convert to binary
     let data = write_bin(&bin)
-        .map_err(|e| format!("Failed to write bin: {}", e))?;
+        .map_err(|e| bin_error("error.bin_write", &output_path, e))?;
 
     // Write to output file
-    fs::write(&output_path, data)
-        .map_err(|e| format!("Failed to write output file: {}", e))?;
+    write_replacing(Path::new(&output_path), &data)
+        .map_err(|e| Error::io_with_path(e, &output_path))?;
 
     Ok(())
 }
@@ -268,26 +316,26 @@ pub async fn convert_json_to_bin(
 /// * `input_path` - Path to the .bin file
 ///
 /// # Returns
-/// * `Result<BinInfo, String>` - Metadata about the bin file
+/// * `Result<BinInfo, CommandError>` - Metadata about the bin file
 #[tauri::command]
-pub async fn read_bin_info(input_path: String) -> Result<BinInfo, String> {
+pub async fn read_bin_info(input_path: String) -> Result<BinInfo, CommandError> {
     // Validate input path
     if input_path.is_empty() {
-        return Err("Input path cannot be empty".to_string());
+        return Err(CommandError::plain("error.input_path_empty"));
     }
 
     let input = Path::new(&input_path);
     if !input.exists() {
-        return Err(format!("Input file does not exist: {}", input_path));
+        return Err(CommandError::new("error.file_not_found", [("path", input_path.clone())]));
     }
 
     // Read the binary file
     let data = fs::read(input)
-        .map_err(|e| format!("Failed to read input file: {}", e))?;
+        .map_err(|e| Error::io_with_path(e, input))?;
 
     // Parse the bin file
     let bin = read_bin(&data)
-        .map_err(|e| format!("Failed to parse bin file: {}", e))?;
+        .map_err(|e| bin_error("error.bin_parse", &input_path, e))?;
 
     // Return metadata
     Ok(BinInfo {
@@ -303,39 +351,39 @@ pub async fn read_bin_info(input_path: String) -> Result<BinInfo, String> {
 /// * `state` - The managed HashtableState for hash resolution
 ///
 /// # Returns
-/// * `Result<String, String>` - Python-like text format for the editor
+/// * `Result<String, CommandError>` - Python-like text format for the editor
 #[tauri::command]
 pub async fn parse_bin_file_to_text(
     path: String,
     _state: State<'_, HashtableState>,
-) -> Result<String, String> {
+) -> Result<String, CommandError> {
     tracing::info!("Parsing BIN file for editor: {}", path);
     
     // Validate path
     if path.is_empty() {
-        return Err("Path cannot be empty".to_string());
+        return Err(CommandError::plain("error.path_empty"));
     }
 
     let input = Path::new(&path);
     if !input.exists() {
-        return Err(format!("File does not exist: {}", path));
+        return Err(CommandError::new("error.file_not_found", [("path", path.clone())]));
     }
 
     // Read the binary file
     let data = fs::read(input)
-        .map_err(|e| format!("Failed to read file: {}", e))?;
+        .map_err(|e| Error::io_with_path(e, input))?;
 
     tracing::debug!("Read {} bytes from {}", data.len(), path);
 
     // Parse with ritobin_rust
     let bin = crate::core::bin::read_bin_ltk(&data)
-        .map_err(|e| format!("Failed to parse bin file: {}", e))?;
+        .map_err(|e| bin_error("error.bin_parse", &path, e))?;
 
     tracing::debug!("Parsed bin file with {} objects", bin.objects.len());
 
     // Convert to text format using cached hash resolution (faster)
     let text = crate::core::bin::tree_to_text_cached(&bin)
-        .map_err(|e| format!("Failed to convert to text: {}", e))?;
+        .map_err(|e| bin_error("error.bin_to_text", &path, e))?;
 
     tracing::info!("Successfully parsed BIN file to text ({} chars)", text.len());
 
@@ -350,24 +398,29 @@ pub async fn parse_bin_file_to_text(
 ///
 /// # Arguments
 /// * `bin_path` - Path to the .bin file
+/// * `request_id` - ID that `cancel_request` can use to abandon a conversion
 ///
 /// # Returns
-/// * `Result<String, String>` - The text content (either from cache or freshly converted)
+/// * `Result<String, CommandError>` - The text content (either from cache or freshly converted)
 #[tauri::command]
 pub async fn read_or_convert_bin(
     bin_path: String,
+    request_id: Option<String>,
     _state: State<'_, HashtableState>,
-) -> Result<String, String> {
+    requests: State<'_, RequestState>,
+) -> Result<String, CommandError> {
+    let request = requests.begin(request_id);
+    let cancel = request.token();
     tracing::info!("[BIN_READ] === Starting read_or_convert_bin ===");
     tracing::info!("[BIN_READ] Path: {}", bin_path);
     
     if bin_path.is_empty() {
-        return Err("Path cannot be empty".to_string());
+        return Err(CommandError::plain("error.path_empty"));
     }
 
     let bin_file = Path::new(&bin_path);
     if !bin_file.exists() {
-        return Err(format!("File does not exist: {}", bin_path));
+        return Err(CommandError::new("error.file_not_found", [("path", bin_path.clone())]));
     }
 
     // Log .bin file size
@@ -394,7 +447,7 @@ pub async fn read_or_convert_bin(
                     // Cache is valid, read it directly - NO CONVERSION!
                     tracing::info!("[BIN_READ] *** CACHE HIT *** Reading cached file directly");
                     let content = fs::read_to_string(ritobin_file)
-                        .map_err(|e| format!("Failed to read cached file: {}", e))?;
+                        .map_err(|e| Error::io_with_path(e, ritobin_file))?;
                     tracing::info!("[BIN_READ] *** CACHE HIT *** Loaded {} chars from cache", content.len());
                     return Ok(content);
                 } else {
@@ -411,17 +464,20 @@ pub async fn read_or_convert_bin(
     
     // Read and parse the binary file
     let data = fs::read(bin_file)
-        .map_err(|e| format!("Failed to read file: {}", e))?;
+        .map_err(|e| Error::io_with_path(e, bin_file))?;
     tracing::info!("[BIN_READ] Read {} bytes from .bin file", data.len());
+    cancel.check()?;
 
     tracing::info!("[BIN_READ] Parsing BIN structure...");
     let bin = crate::core::bin::read_bin_ltk(&data)
-        .map_err(|e| format!("Failed to parse bin file: {}", e))?;
+        .map_err(|e| bin_error("error.bin_parse", &bin_path, e))?;
     tracing::info!("[BIN_READ] Parsed: {} objects, {} dependencies", bin.objects.len(), bin.dependencies.len());
+    cancel.check()?;
 
     tracing::info!("[BIN_READ] Converting to text (using cached hashes)...");
     let text = crate::core::bin::tree_to_text_cached(&bin)
-        .map_err(|e| format!("Failed to convert to text: {}", e))?;
+        .map_err(|e| bin_error("error.bin_to_text", &bin_path, e))?;
+    let text = collapse_project_variables(bin_file, text);
     tracing::info!("[BIN_READ] Converted to {} chars of text", text.len());
 
     // Cache the result
@@ -440,32 +496,40 @@ pub async fn read_or_convert_bin(
 /// # Arguments
 /// * `bin_path` - Path to the .bin file
 /// * `content` - The edited text content
+/// * `strict` - Refuse to save if strict checks report any issue
 ///
 /// # Returns
-/// * `Result<(), String>` - Ok if save succeeded
+/// * `Result<(), CommandError>` - Ok if save succeeded
 #[tauri::command]
 pub async fn save_ritobin_to_bin(
     bin_path: String,
     content: String,
+    strict: Option<bool>,
     _state: State<'_, HashtableState>,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     tracing::info!("Saving ritobin content to: {}", bin_path);
     
     if bin_path.is_empty() {
-        return Err("Path cannot be empty".to_string());
+        return Err(CommandError::plain("error.path_empty"));
     }
 
     // Parse the text content back to BIN structure
-    let bin = crate::core::bin::text_to_tree(&content)
-        .map_err(|e| format!("Failed to parse text content: {}", e))?;
+    let expanded = expand_project_variables(Path::new(&bin_path), &content)?;
+    let bin = if strict.unwrap_or(false) {
+        text_to_bin_strict(&expanded, Some(&*get_cached_bin_hashes().read()))
+            .map_err(CommandError::from)?
+    } else {
+        crate::core::bin::text_to_tree(&expanded)
+            .map_err(|e| bin_error("error.bin_text_parse", &bin_path, e))?
+    };
 
     // Convert to binary format
     let binary_data = crate::core::bin::write_bin_ltk(&bin)
-        .map_err(|e| format!("Failed to convert to binary: {}", e))?;
+        .map_err(|e| bin_error("error.bin_write", &bin_path, e))?;
 
     // Write the .bin file
-    fs::write(&bin_path, &binary_data)
-        .map_err(|e| format!("Failed to write .bin file: {}", e))?;
+    write_replacing(Path::new(&bin_path), &binary_data)
+        .map_err(|e| Error::io_with_path(e, &bin_path))?;
 
     tracing::info!("Saved .bin file: {} ({} bytes)", bin_path, binary_data.len());
 
@@ -480,6 +544,264 @@ pub async fn save_ritobin_to_bin(
     Ok(())
 }
 
+/// Error for a BIN conversion step that failed on `path`
+fn bin_error(key: &str, path: &str, e: impl std::fmt::Display) -> CommandError {
+    CommandError::new(key, [("path", path.to_string()), ("detail", e.to_string())])
+}
+
+/// Expands `${NAME}` project variables if the file belongs to a project
+fn expand_project_variables(path: &Path, text: &str) -> Result<String, CommandError> {
+    if !text.contains("${") {
+        return Ok(text.to_string());
+    }
+    match ProjectVariables::for_file(path) {
+        Some(variables) => variables.expand(text).map_err(CommandError::from),
+        None => Ok(text.to_string()),
+    }
+}
+
+/// Collapses project variable values back to `${NAME}` if the project opted in
+fn collapse_project_variables(path: &Path, text: String) -> String {
+    match ProjectVariables::for_file(path) {
+        Some(variables) => variables.collapse(&text),
+        None => text,
+    }
+}
+
+/// Returns the persisted BIN parsing settings
+///
+/// # Returns
+/// * `Result<BinSettings, CommandError>` - The current settings
+#[tauri::command]
+pub async fn get_bin_settings(app: AppHandle) -> Result<BinSettings, CommandError> {
+    let dir = app_data_dir(&app)?;
+    Ok(limits::load_settings(&dir))
+}
+
+/// Sets the size above which BIN files are not parsed in full
+///
+/// # Arguments
+/// * `megabytes` - New limit in MB
+///
+/// # Returns
+/// * `Result<BinSettings, CommandError>` - The updated settings
+#[tauri::command]
+pub async fn set_bin_size_limit(megabytes: u64, app: AppHandle) -> Result<BinSettings, CommandError> {
+    let dir = app_data_dir(&app)?;
+
+    let settings = BinSettings { max_bin_size_mb: megabytes };
+    limits::save_settings(&dir, &settings).map_err(CommandError::from)?;
+    settings.apply();
+    tracing::info!("BIN size limit set to {} MB", megabytes);
+    Ok(settings)
+}
+
+/// Lists the objects of a BIN file without parsing their bodies
+///
+/// Works on files of any size, including ones above the BIN size limit.
+/// Object bodies are loaded with `read_bin_objects`.
+///
+/// # Arguments
+/// * `path` - Path to the .bin file
+///
+/// # Returns
+/// * `Result<BinIndex, CommandError>` - Dependencies and object headers
+#[tauri::command]
+pub async fn read_bin_index(path: String) -> Result<BinIndex, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let bin = LazyBin::open(&path).map_err(|e| bin_error("error.bin_open", &path, e))?;
+        let hashes = get_cached_bin_hashes().read();
+
+        let objects = bin
+            .objects()
+            .iter()
+            .map(|object| BinObjectHeader {
+                path_hash: object.path_hash,
+                class_hash: object.class_hash,
+                path_name: hashes.entries.get(&object.path_hash).cloned(),
+                class_name: hashes.types.get(&object.class_hash).cloned(),
+                size: object.size,
+            })
+            .collect();
+
+        Ok::<_, CommandError>(BinIndex {
+            version: bin.version,
+            is_override: bin.is_override,
+            dependencies: bin.dependencies.clone(),
+            file_size: bin.file_size,
+            objects,
+        })
+    })
+    .await?
+}
+
+/// Parses selected objects of a BIN file and returns them as ritobin text
+///
+/// Only the requested object bodies are read from disk, so this works on
+/// files above the BIN size limit.
+///
+/// # Arguments
+/// * `path` - Path to the .bin file
+/// * `path_hashes` - Path hashes of the objects to load
+///
+/// # Returns
+/// * `Result<String, CommandError>` - Ritobin text containing only those objects
+#[tauri::command]
+pub async fn read_bin_objects(path: String, path_hashes: Vec<u32>) -> Result<String, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let bin = LazyBin::open(&path).map_err(|e| bin_error("error.bin_open", &path, e))?;
+        let tree = bin
+            .load_tree(&path_hashes)
+            .map_err(|e| bin_error("error.bin_parse", &path, e))?;
+        crate::core::bin::tree_to_text_cached(&tree)
+            .map_err(|e| bin_error("error.bin_to_text", &path, e))
+    })
+    .await?
+}
+
+/// Reads a BIN file as a tree of typed nodes for the property editor
+///
+/// # Arguments
+/// * `path` - Path to the .bin file
+///
+/// # Returns
+/// * `Result<BinTreeNode, CommandError>` - Objects and their properties, with known hash names
+#[tauri::command]
+pub async fn get_bin_tree(path: String) -> Result<BinTreeNode, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        editor::read_bin_tree(Path::new(&path), &hashes)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Replaces one property value in a BIN file
+///
+/// The new value must have the kind of the value it replaces (for lists,
+/// options and maps also the same item kinds); anything else is refused
+/// without writing the file.
+///
+/// # Arguments
+/// * `path` - Path to the .bin file
+/// * `object_hash` - Path hash of the object to edit
+/// * `property_path` - Field name, then field names, list indices or map keys below it
+/// * `value` - The new value
+///
+/// # Returns
+/// * `Result<BinObjectNode, CommandError>` - The edited object
+#[tauri::command]
+pub async fn set_bin_property(
+    path: String,
+    object_hash: u32,
+    property_path: Vec<String>,
+    value: BinValue,
+) -> Result<BinObjectNode, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        editor::set_bin_property(Path::new(&path), object_hash, &property_path, &value, &hashes)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Adds a new object to a BIN file
+///
+/// # Arguments
+/// * `path` - Path to the .bin file
+/// * `object_path` - Entry path of the new object (or a `0x` hash)
+/// * `class_name` - Class of the new object (or a `0x` hash)
+/// * `properties` - Initial properties (default: none)
+///
+/// # Returns
+/// * `Result<BinObjectNode, CommandError>` - The added object
+#[tauri::command]
+pub async fn add_bin_object(
+    path: String,
+    object_path: String,
+    class_name: String,
+    properties: Option<Vec<BinField>>,
+) -> Result<BinObjectNode, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        let properties = properties.unwrap_or_default();
+        editor::add_bin_object(Path::new(&path), &object_path, &class_name, &properties, &hashes)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Searches all BIN files of a project for a string, hash or regex
+///
+/// # Arguments
+/// * `project_path` - Project directory; its `content` folder is searched
+/// * `query` - What to look for
+/// * `mode` - `text` (default), `hash` or `regex`
+/// * `case_sensitive` - Case-sensitive text and regex matching (default: false)
+/// * `max_results` - Matches to return at most (default: 1000)
+///
+/// # Returns
+/// * `Result<BinSearchResult, CommandError>` - Matches with file, object and property path
+#[tauri::command]
+pub async fn search_bins(
+    project_path: String,
+    query: String,
+    mode: Option<BinSearchMode>,
+    case_sensitive: Option<bool>,
+    max_results: Option<usize>,
+) -> Result<BinSearchResult, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        search::search_bins(
+            &Path::new(&project_path).join("content"),
+            &query,
+            mode.unwrap_or_default(),
+            case_sensitive.unwrap_or(false),
+            max_results.unwrap_or(1000),
+            &hashes,
+        )
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Replaces text in the string values of all BIN files of a project
+///
+/// # Arguments
+/// * `project_path` - Project directory; its `content` folder is searched
+/// * `query` - Text or regex to replace
+/// * `replacement` - Replacement text (`$1` groups expand in regex mode)
+/// * `mode` - `text` (default) or `regex`
+/// * `case_sensitive` - Case-sensitive matching (default: false)
+/// * `dry_run` - Only report what would change (default: false)
+///
+/// # Returns
+/// * `Result<BinReplaceResult, CommandError>` - Every replaced value and the rewritten files
+#[tauri::command]
+pub async fn replace_in_bins(
+    project_path: String,
+    query: String,
+    replacement: String,
+    mode: Option<BinSearchMode>,
+    case_sensitive: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<BinReplaceResult, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        search::replace_in_bins(
+            &Path::new(&project_path).join("content"),
+            &query,
+            &replacement,
+            mode.unwrap_or_default(),
+            case_sensitive.unwrap_or(false),
+            dry_run.unwrap_or(false),
+            &hashes,
+        )
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -503,7 +825,7 @@ mod tests {
         let result = read_bin_info("".to_string()).await;
 
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Input path cannot be empty"));
+        assert!(result.unwrap_err().message.contains("Input path cannot be empty"));
     }
 
     #[tokio::test]
@@ -511,6 +833,6 @@ mod tests {
         let result = read_bin_info("nonexistent.bin".to_string()).await;
 
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("does not exist"));
+        assert!(result.unwrap_err().message.contains("does not exist"));
     }
 }
@@ -2,55 +2,140 @@
 //!
 //! These commands expose champion discovery functionality to the frontend.
 
+use crate::core::champion::discovery::attach_champion_icons;
+use crate::core::champion::metadata::{apply_roster, apply_skin_metadata, fetch_roster, fetch_skin_metadata, CACHE_DIR};
 use crate::core::champion::{
     discover_champions as core_discover_champions,
     get_champion_skins as core_get_champion_skins,
-    ChampionInfo, SkinInfo,
+    preview_skin_contents as core_preview_skin_contents,
+    ChampionInfo, SkinContentsPreview, SkinInfo,
 };
+use crate::core::wad::extractor::find_champion_wad;
+use crate::error::CommandError;
+use crate::state::HashtableState;
 use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// Edge length of the champion icons returned by discovery
+const CHAMPION_ICON_SIZE: u32 = 64;
 
 /// Discover all champions in a League installation
 ///
 /// # Arguments
 /// * `league_path` - Path to League of Legends installation
+/// * `include_icons` - Read each champion's square icon from its WAD (default: false)
+/// * `include_metadata` - Add display names, titles and keys from Data Dragon (default: false)
 ///
 /// # Returns
 /// * `Ok(Vec<ChampionInfo>)` - List of discovered champions
-/// * `Err(String)` - Error message if discovery failed
+/// * `Err(CommandError)` - Error message if discovery failed
 #[tauri::command]
-pub async fn discover_champions(league_path: String) -> Result<Vec<ChampionInfo>, String> {
+pub async fn discover_champions(
+    league_path: String,
+    include_icons: Option<bool>,
+    include_metadata: Option<bool>,
+    app: AppHandle,
+) -> Result<Vec<ChampionInfo>, CommandError> {
     tracing::info!("Frontend requested champion discovery for: {}", league_path);
 
     let path = PathBuf::from(league_path);
+    let with_icons = include_icons.unwrap_or(false);
+
+    let mut champions = tokio::task::spawn_blocking(move || {
+        let mut champions = core_discover_champions(&path)?;
+        if with_icons {
+            attach_champion_icons(&mut champions, CHAMPION_ICON_SIZE);
+        }
+        Ok::<_, crate::error::Error>(champions)
+    })
+    .await?
+    .map_err(CommandError::from)?;
 
-    tokio::task::spawn_blocking(move || core_discover_champions(&path))
-        .await
-        .map_err(|e| format!("Task failed: {}", e))?
-        .map_err(|e| e.to_string())
+    if include_metadata.unwrap_or(false) {
+        // Like skin names, roster data is optional and never fails discovery
+        match app.path().app_data_dir() {
+            Ok(data_dir) => match fetch_roster(&data_dir.join(CACHE_DIR)).await {
+                Ok(roster) => apply_roster(&mut champions, &roster),
+                Err(e) => tracing::warn!("Failed to get the champion roster: {}", e),
+            },
+            Err(e) => tracing::warn!("No app data directory for champion metadata: {}", e),
+        }
+        champions.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    Ok(champions)
 }
 
 /// Get skins for a specific champion
 ///
+/// Skin names and chroma parents come from CommunityDragon (cached for a
+/// week); without it the skins keep generated "Skin N" names.
+///
 /// # Arguments
 /// * `league_path` - Path to League installation
 /// * `champion` - Champion internal name
 ///
 /// # Returns
 /// * `Ok(Vec<SkinInfo>)` - List of skins
-/// * `Err(String)` - Error message if discovery failed
+/// * `Err(CommandError)` - Error message if discovery failed
 #[tauri::command]
 pub async fn get_champion_skins(
     league_path: String,
     champion: String,
-) -> Result<Vec<SkinInfo>, String> {
+    app: AppHandle,
+) -> Result<Vec<SkinInfo>, CommandError> {
     tracing::info!("Frontend requested skins for: {}", champion);
 
     let path = PathBuf::from(league_path);
+    let name = champion.clone();
+    let mut skins = tokio::task::spawn_blocking(move || core_get_champion_skins(&path, &name))
+        .await?
+        .map_err(CommandError::from)?;
+
+    // Names are a nicety; a missing or unreachable cache must not fail discovery
+    match app.path().app_data_dir() {
+        Ok(data_dir) => match fetch_skin_metadata(&data_dir.join(CACHE_DIR), &champion).await {
+            Ok(Some(metadata)) => apply_skin_metadata(&mut skins, &metadata),
+            Ok(None) => tracing::debug!("No skin metadata listed for {}", champion),
+            Err(e) => tracing::warn!("Failed to get skin names for {}: {}", champion, e),
+        },
+        Err(e) => tracing::warn!("No app data directory for skin metadata: {}", e),
+    }
+
+    Ok(skins)
+}
+
+/// Summarize what a skin references before creating a project from it
+///
+/// Reads the skin BIN and its linked BINs from the champion WAD without
+/// extracting anything.
+///
+/// # Arguments
+/// * `league_path` - Path to League installation
+/// * `champion` - Champion internal name
+/// * `skin_id` - Skin number (0 for the base skin)
+///
+/// # Returns
+/// * `Ok(SkinContentsPreview)` - Linked BINs, referenced assets by type and extraction size
+/// * `Err(CommandError)` - Error message if the WAD or skin BIN couldn't be read
+#[tauri::command]
+pub async fn preview_skin_contents(
+    league_path: String,
+    champion: String,
+    skin_id: u32,
+    hashtable_state: tauri::State<'_, HashtableState>,
+) -> Result<SkinContentsPreview, CommandError> {
+    tracing::info!("Frontend requested contents of {} skin {}", champion, skin_id);
+
+    let wad_path = find_champion_wad(PathBuf::from(league_path), &champion)
+        .ok_or_else(|| CommandError::new("error.champion_wad_not_found", [("champion", champion.clone())]))?;
+    let hashtable = hashtable_state.get_hashtable();
 
-    tokio::task::spawn_blocking(move || core_get_champion_skins(&path, &champion))
-        .await
-        .map_err(|e| format!("Task failed: {}", e))?
-        .map_err(|e| e.to_string())
+    tokio::task::spawn_blocking(move || {
+        core_preview_skin_contents(&wad_path, &champion, skin_id, hashtable.as_deref())
+    })
+    .await?
+    .map_err(CommandError::from)
 }
 
 /// Search champions by name
@@ -1,4 +1,7 @@
-use crate::core::checkpoint::{Checkpoint, CheckpointDiff, CheckpointFileContent, CheckpointManager, CheckpointProgress};
+use crate::core::checkpoint::{
+    Checkpoint, CheckpointDiff, CheckpointFileContent, CheckpointManager, CheckpointProgress, SnapshotInfo,
+};
+use crate::error::CommandError;
 use std::path::PathBuf;
 use tauri::{AppHandle, Emitter};
 
@@ -8,10 +11,10 @@ pub async fn create_checkpoint(
     project_path: String,
     message: String,
     tags: Vec<String>,
-) -> Result<Checkpoint, String> {
+) -> Result<Checkpoint, CommandError> {
     let path = PathBuf::from(project_path);
     let manager = CheckpointManager::new(path);
-    manager.init().map_err(|e| e.to_string())?;
+    manager.init().map_err(CommandError::from)?;
 
     let app_handle = app.clone();
     manager.create_checkpoint_with_progress(
@@ -24,22 +27,22 @@ pub async fn create_checkpoint(
                 total,
             });
         }),
-    ).map_err(|e| e.to_string())
+    ).map_err(CommandError::from)
 }
 
 #[tauri::command]
-pub async fn list_checkpoints(project_path: String) -> Result<Vec<Checkpoint>, String> {
+pub async fn list_checkpoints(project_path: String) -> Result<Vec<Checkpoint>, CommandError> {
     let path = PathBuf::from(project_path);
     let manager = CheckpointManager::new(path);
-    manager.list_checkpoints().map_err(|e| e.to_string())
+    manager.list_checkpoints().map_err(CommandError::from)
 }
 
 #[tauri::command]
-pub async fn restore_checkpoint(project_path: String, checkpoint_id: String) -> Result<(), String> {
+pub async fn restore_checkpoint(project_path: String, checkpoint_id: String) -> Result<(), CommandError> {
     let path = PathBuf::from(project_path);
     let manager = CheckpointManager::new(path);
-    manager.init().map_err(|e| e.to_string())?;
-    manager.restore_checkpoint(&checkpoint_id).map_err(|e| e.to_string())
+    manager.init().map_err(CommandError::from)?;
+    manager.restore_checkpoint(&checkpoint_id).map_err(CommandError::from)
 }
 
 #[tauri::command]
@@ -47,17 +50,17 @@ pub async fn compare_checkpoints(
     project_path: String,
     from_id: String,
     to_id: String,
-) -> Result<CheckpointDiff, String> {
+) -> Result<CheckpointDiff, CommandError> {
     let path = PathBuf::from(project_path);
     let manager = CheckpointManager::new(path);
-    manager.compare_checkpoints(&from_id, &to_id).map_err(|e| e.to_string())
+    manager.compare_checkpoints(&from_id, &to_id).map_err(CommandError::from)
 }
 
 #[tauri::command]
-pub async fn delete_checkpoint(project_path: String, checkpoint_id: String) -> Result<(), String> {
+pub async fn delete_checkpoint(project_path: String, checkpoint_id: String) -> Result<(), CommandError> {
     let path = PathBuf::from(project_path);
     let manager = CheckpointManager::new(path);
-    manager.delete_checkpoint(&checkpoint_id).map_err(|e| e.to_string())
+    manager.delete_checkpoint(&checkpoint_id).map_err(CommandError::from)
 }
 
 #[tauri::command]
@@ -65,8 +68,54 @@ pub async fn read_checkpoint_file(
     project_path: String,
     hash: String,
     file_path: String,
-) -> Result<CheckpointFileContent, String> {
+) -> Result<CheckpointFileContent, CommandError> {
     let path = PathBuf::from(project_path);
     let manager = CheckpointManager::new(path);
-    manager.read_checkpoint_file(&hash, &file_path).map_err(|e| e.to_string())
+    manager.read_checkpoint_file(&hash, &file_path).map_err(CommandError::from)
+}
+
+/// Snapshot the project's `content/` folder, e.g. before a destructive step
+///
+/// Progress is emitted as `checkpoint-progress` events.
+#[tauri::command]
+pub async fn create_snapshot(
+    app: AppHandle,
+    project_path: String,
+    message: String,
+) -> Result<SnapshotInfo, CommandError> {
+    let manager = CheckpointManager::new(PathBuf::from(project_path));
+    tokio::task::spawn_blocking(move || {
+        manager
+            .create_snapshot(
+                message,
+                Some(move |phase: &str, current: u64, total: u64| {
+                    let _ = app.emit("checkpoint-progress", CheckpointProgress {
+                        phase: phase.to_string(),
+                        current,
+                        total,
+                    });
+                }),
+            )
+            .map(|snapshot| SnapshotInfo::from(&snapshot))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Lists the project's snapshots, newest first
+#[tauri::command]
+pub async fn list_snapshots(project_path: String) -> Result<Vec<SnapshotInfo>, CommandError> {
+    let manager = CheckpointManager::new(PathBuf::from(project_path));
+    manager.list_snapshots().map_err(CommandError::from)
+}
+
+/// Rolls the project's `content/` folder back to a snapshot
+///
+/// The current content is snapshotted first, so a restore can be undone.
+#[tauri::command]
+pub async fn restore_snapshot(project_path: String, snapshot_id: String) -> Result<SnapshotInfo, CommandError> {
+    let manager = CheckpointManager::new(PathBuf::from(project_path));
+    tokio::task::spawn_blocking(move || manager.restore_snapshot(&snapshot_id))
+        .await?
+        .map_err(CommandError::from)
 }
@@ -0,0 +1,67 @@
+//! Tauri commands for chroma variant generation
+
+use crate::core::chroma::{self, ColorShift, ColorShiftResult};
+use crate::core::project::{open_project, Project};
+use crate::error::CommandError;
+use std::path::PathBuf;
+
+/// Create a chroma layer from copies of the base layer's textures
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `layer` - Name of the new layer (letters, digits, `_` or `-`)
+/// * `priority` - Layer priority; defaults to one above the current highest
+/// * `textures` - Textures to copy, relative to `content/base`; all of them if omitted
+///
+/// # Returns
+/// * `Ok(Project)` - The updated project with the new layer
+/// * `Err(CommandError)` - Error message if the name is taken or a texture doesn't exist
+#[tauri::command]
+pub async fn create_chroma_layer(
+    project_path: String,
+    layer: String,
+    priority: Option<i32>,
+    textures: Option<Vec<String>>,
+) -> Result<Project, CommandError> {
+    tracing::info!("Frontend requested chroma layer '{}' for: {}", layer, project_path);
+
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = open_project(&project_path)?;
+        chroma::create_chroma_layer(&mut project, &layer, priority, textures.as_deref())?;
+        Ok::<_, crate::error::Error>(project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Recolor textures of a project layer
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `layer` - Layer whose textures are recolored
+/// * `textures` - Textures relative to the layer folder; all except distortion maps if omitted or empty
+/// * `shift` - HSL shift or tint to apply
+///
+/// # Returns
+/// * `Ok(ColorShiftResult)` - Recolored textures and per-texture failures
+/// * `Err(CommandError)` - Error message if the project or layer can't be found
+#[tauri::command]
+pub async fn apply_color_shift(
+    project_path: String,
+    layer: String,
+    textures: Option<Vec<String>>,
+    shift: ColorShift,
+) -> Result<ColorShiftResult, CommandError> {
+    tracing::info!("Frontend requested color shift on layer '{}' of: {}", layer, project_path);
+
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&project_path)?;
+        chroma::apply_color_shift(&project, &layer, &textures.unwrap_or_default(), &shift)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
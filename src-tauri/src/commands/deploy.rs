@@ -0,0 +1,109 @@
+//! Tauri commands for deploying projects into a mod manager
+
+use crate::core::deploy::{self, DeployProfile, DeployResult, DeploySettings};
+use crate::commands::app_data_dir;
+use crate::error::CommandError;
+use crate::messages::Message;
+use crate::state::RequestState;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, State};
+
+/// Returns the saved deploy profiles
+#[tauri::command]
+pub async fn list_deploy_profiles(app: AppHandle) -> Result<DeploySettings, CommandError> {
+    let dir = app_data_dir(&app)?;
+    tokio::task::spawn_blocking(move || deploy::load_settings(&dir))
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Saves a deploy profile, replacing one with the same name
+///
+/// # Returns
+/// * `Result<DeploySettings, CommandError>` - All saved profiles
+#[tauri::command]
+pub async fn save_deploy_profile(profile: DeployProfile, app: AppHandle) -> Result<DeploySettings, CommandError> {
+    let dir = app_data_dir(&app)?;
+    tokio::task::spawn_blocking(move || deploy::save_profile(&dir, profile))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Deletes a deploy profile; mods it deployed stay in the manager
+#[tauri::command]
+pub async fn delete_deploy_profile(name: String, app: AppHandle) -> Result<DeploySettings, CommandError> {
+    let dir = app_data_dir(&app)?;
+    tokio::task::spawn_blocking(move || deploy::delete_profile(&dir, &name))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Deploys a project into the mod manager of a saved profile
+///
+/// Progress is emitted as `deploy-progress` events.
+///
+/// # Arguments
+/// * `project_path` - Project folder
+/// * `profile_name` - Saved profile to deploy with; the last one used when not given
+/// * `request_id` - Id to cancel the deploy with
+#[tauri::command]
+pub async fn deploy_to_overlay(
+    project_path: String,
+    profile_name: Option<String>,
+    request_id: Option<String>,
+    app: AppHandle,
+    requests: State<'_, RequestState>,
+) -> Result<DeployResult, CommandError> {
+    let dir = app_data_dir(&app)?;
+    let mut settings = deploy::load_settings(&dir);
+    let profile_name = profile_name
+        .or_else(|| settings.last_profile.clone())
+        .ok_or_else(|| CommandError::plain("error.deploy_profile_required"))?;
+    let profile = settings
+        .profiles
+        .iter()
+        .find(|p| p.name == profile_name)
+        .cloned()
+        .ok_or_else(|| CommandError::new("error.deploy_profile_not_found", [("name", profile_name.clone())]))?;
+
+    let request = requests.begin(request_id);
+    let cancel = request.token();
+    let progress_app = app.clone();
+    let project_path = PathBuf::from(project_path);
+
+    let result = tokio::task::spawn_blocking(move || {
+        let on_progress = |current: usize, total: usize| {
+            let message = Message::new(
+                "progress.deploy.wad",
+                [("current", current.to_string()), ("total", total.to_string())],
+            );
+            let _ = progress_app.emit("deploy-progress", message.progress_payload(serde_json::json!({
+                "status": "deploying",
+                "progress": current as f64 / total.max(1) as f64,
+            })));
+        };
+        deploy::deploy_project(&project_path, &profile, &cancel, Some(&on_progress))
+    })
+    .await?;
+
+    match result {
+        Ok(result) => {
+            settings.last_profile = Some(profile_name);
+            if let Err(e) = deploy::save_settings(&dir, &settings) {
+                tracing::warn!("Failed to remember deploy profile: {}", e);
+            }
+            let complete = Message::new("progress.deploy.complete", [("mod", result.mod_name.clone())]);
+            let _ = app.emit("deploy-progress", complete.progress_payload(
+                serde_json::json!({ "status": "complete", "progress": 1.0 }),
+            ));
+            Ok(result)
+        }
+        Err(e) => {
+            let failed = Message::new("progress.deploy.failed", [("detail", e.to_string())]);
+            let _ = app.emit("deploy-progress", failed.progress_payload(
+                serde_json::json!({ "status": "error", "progress": 0.0 }),
+            ));
+            Err(e.into())
+        }
+    }
+}
@@ -1,20 +1,43 @@
 //! Tauri commands for export operations
 //!
 //! These commands expose export and repathing functionality to the frontend.
-//! Uses ltk_fantome for league-mod compatible .fantome export.
+//! Packages use the league-mod compatible .fantome and .modpkg layouts.
 
+use crate::core::export::archive::PackProgress;
+use crate::core::export::conflicts::{check_conflicts as core_check_conflicts, ConflictReport};
+use crate::core::export::fantome::pack_fantome;
 use crate::core::export::generate_fantome_filename;
-use crate::core::repath::{organize_project, OrganizerConfig};
-use ltk_fantome::pack_to_fantome;
-use ltk_mod_project::{ModProject, ModProjectAuthor};
+use crate::core::export::layers::{flatten_layers, flatten_stage_path, layer_files, preview_export, ExportPreview};
+use crate::core::export::manifest::{write_export_manifest, PackageFormat};
+use crate::core::export::preview::{load_thumbnail, PreviewFormat};
+use crate::core::export::validate::{validate_fantome as core_validate_fantome, FantomeValidationReport};
+use crate::core::export::verify::{verify_package, PackageVerification};
+use crate::core::wad::packer::{self, WadPackResult};
+use crate::core::bin::closure::GameWadLookup;
+use crate::core::bin::concat::ConcatCollision;
+use crate::core::checkpoint::CheckpointManager;
+use crate::core::project::TargetKind;
+use crate::core::league::guard::ensure_outside_league;
+use crate::core::project::open_project;
+use crate::core::repath::{organize_layers, plan_organize, OrganizePlan, OrganizeProgress, OrganizerConfig, OrganizerResult};
+use crate::error::{CommandError, Error};
+use crate::messages::Message;
+use crate::state::{CancelToken, RequestState};
+use ltk_mod_project::{ModProject, ModProjectAuthor, ModProjectLayer};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
+use std::io::BufWriter;
 use std::path::{Path, PathBuf};
-use tauri::Emitter;
+use tauri::{Emitter, State};
+use ts_rs::TS;
 
 /// Metadata for export operations (received from frontend)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Only a fallback for folders without mod.config.json; edit project
+/// metadata with `update_project_metadata` instead.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct ExportMetadata {
     pub name: String,
     pub author: String,
@@ -23,29 +46,43 @@ pub struct ExportMetadata {
 }
 
 /// Result of export operation (sent to frontend)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct ExportResult {
     pub success: bool,
     pub output_path: String,
     pub file_count: usize,
+    #[ts(type = "number")]
     pub total_size: u64,
     pub message: String,
+    /// Path of the README manifest written next to the package, if generated
+    #[serde(default)]
+    pub manifest_path: Option<String>,
+    /// Smoke test of the written package, if requested
+    #[serde(default)]
+    pub verification: Option<PackageVerification>,
 }
 
 /// Result of repath operation (sent to frontend)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct RepathResultDto {
     pub success: bool,
     pub bins_processed: usize,
     pub paths_modified: usize,
     pub files_relocated: usize,
     pub missing_paths: Vec<String>,
+    /// Objects more than one linked BIN defined, and which definition was kept
+    pub concat_collisions: Vec<ConcatCollision>,
+    /// Why concatenation was skipped, per failed target
+    pub concat_errors: Vec<String>,
     pub message: String,
 }
 
 /// Repath a project's assets with a unique prefix
 ///
 /// This modifies BIN file paths and relocates asset files to prevent conflicts.
+/// Every layer of the project is repathed with the same prefix.
 ///
 /// # Arguments
 /// * `project_path` - Path to the project directory
@@ -57,51 +94,51 @@ pub async fn repath_project_cmd(
     creator_name: Option<String>,
     project_name: Option<String>,
     app: tauri::AppHandle,
-) -> Result<RepathResultDto, String> {
+) -> Result<RepathResultDto, CommandError> {
     tracing::info!("Frontend requested repathing for: {}", project_path);
 
     let path = PathBuf::from(&project_path);
-    let content_base = path.join("content").join("base");
+    let content_dir = path.join("content");
+    let layers = project_layer_names(&path);
     
-    let creator = creator_name.unwrap_or_else(|| "bum".to_string());
-    let project = project_name.unwrap_or_else(|| "mod".to_string());
+    snapshot_content(&path, "repathing").await?;
 
     // Emit start event
-    let _ = app.emit("repath-progress", serde_json::json!({
-        "status": "starting",
-        "message": "Starting repathing..."
-    }));
+    let _ = app.emit("repath-progress", Message::plain("progress.repath.starting").progress_payload(
+        serde_json::json!({ "status": "starting" }),
+    ));
 
-    let config = OrganizerConfig {
-        enable_concat: true,
-        enable_repath: true,
-        creator_name: creator.clone(),
-        project_name: project.clone(),
-        champion: String::new(), // Champion not provided in direct repath call
-        target_skin_id: 0,
-        cleanup_unused: true,
-    };
+    let config = manual_repath_config(&path, creator_name, project_name);
 
+    let progress_app = app.clone();
     let result = tokio::task::spawn_blocking(move || {
         // Empty mappings since this is a manual repath, not from extraction
         let path_mappings: HashMap<String, String> = HashMap::new();
-        organize_project(&content_base, &config, &path_mappings)
+        let on_progress = |p: OrganizeProgress| {
+            let _ = progress_app.emit("repath-progress", p.message().progress_payload(serde_json::json!({
+                "status": "progress",
+                "phase": p.phase,
+                "current": p.current,
+                "total": p.total,
+                "item": p.item,
+            })));
+        };
+        organize_layers(&content_dir, &layers, &config, &path_mappings, Some(&on_progress))
     })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?;
+    .await?;
 
     match result {
         Ok(result) => {
-            let repath_res = result.repath_result.as_ref();
-            let bins_processed = repath_res.map(|r| r.bins_processed).unwrap_or(0);
-            let paths_modified = repath_res.map(|r| r.paths_modified).unwrap_or(0);
-            let files_relocated = repath_res.map(|r| r.files_relocated).unwrap_or(0);
-            let missing_paths = repath_res.map(|r| r.missing_paths.clone()).unwrap_or_default();
+            let (bins_processed, paths_modified, files_relocated, missing_paths) = repath_totals(&result);
+            let (concat_collisions, concat_errors) = concat_report(&result);
 
-            let _ = app.emit("repath-progress", serde_json::json!({
-                "status": "complete",
-                "message": format!("Repathed {} paths in {} BIN files", paths_modified, bins_processed)
-            }));
+            let complete = Message::new(
+                "progress.repath.complete",
+                [("paths", paths_modified.to_string()), ("bins", bins_processed.to_string())],
+            );
+            let _ = app.emit("repath-progress", complete.progress_payload(
+                serde_json::json!({ "status": "complete" }),
+            ));
 
             Ok(RepathResultDto {
                 success: true,
@@ -109,6 +146,8 @@ pub async fn repath_project_cmd(
                 paths_modified,
                 files_relocated,
                 missing_paths,
+                concat_collisions,
+                concat_errors,
                 message: format!(
                     "Successfully repathed {} paths in {} BIN files",
                     paths_modified, bins_processed
@@ -116,33 +155,104 @@ pub async fn repath_project_cmd(
             })
         }
         Err(e) => {
-            let _ = app.emit("repath-progress", serde_json::json!({
-                "status": "error",
-                "message": format!("Repathing failed: {}", e)
-            }));
+            let failed = Message::new("progress.repath.failed", [("detail", e.to_string())]);
+            let _ = app.emit("repath-progress", failed.progress_payload(
+                serde_json::json!({ "status": "error" }),
+            ));
 
-            Err(e.to_string())
+            Err(e.into())
         }
     }
 }
 
-/// Export a project as a .fantome mod package using ltk_fantome
+/// Shows what `repath_project_cmd` would do, without changing the project
+///
+/// Lists the BINs concatenated, the paths rewritten, and the files moved or
+/// deleted, so the destructive cleanup steps can be checked first.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `creator_name` - Creator name for prefix, as passed to `repath_project_cmd`
+/// * `project_name` - Project name for prefix, as passed to `repath_project_cmd`
+#[tauri::command]
+pub async fn preview_organize_project(
+    project_path: String,
+    creator_name: Option<String>,
+    project_name: Option<String>,
+) -> Result<OrganizePlan, CommandError> {
+    let path = PathBuf::from(&project_path);
+    let content_dir = path.join("content");
+    let layers = project_layer_names(&path);
+    let config = manual_repath_config(&path, creator_name, project_name);
+
+    tokio::task::spawn_blocking(move || plan_organize(&content_dir, &layers, &config, &HashMap::new()))
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Organizer settings for a repath started from the frontend
+///
+/// The target comes from the saved project, which locates its WAD folder
+/// (`{champion}.wad.client`, `map11.wad.client`).
+fn manual_repath_config(project_path: &Path, creator_name: Option<String>, project_name: Option<String>) -> OrganizerConfig {
+    let saved = open_project(project_path).ok();
+    OrganizerConfig {
+        enable_concat: true,
+        enable_repath: true,
+        creator_name: creator_name.unwrap_or_else(|| "bum".to_string()),
+        project_name: project_name.unwrap_or_else(|| "mod".to_string()),
+        champion: saved.as_ref().map(|p| p.champion.clone()).unwrap_or_default(),
+        target_skin_id: saved.as_ref().map(|p| p.skin_id).unwrap_or(0),
+        cleanup_unused: true,
+        include_conventional_assets: true,
+        extra_targets: saved.as_ref().map(|p| p.extra_targets.clone()).unwrap_or_default(),
+        version: saved.as_ref().map(|p| p.version.clone()).unwrap_or_default(),
+        target_kind: saved.as_ref().map(|p| p.target_kind).unwrap_or_default(),
+        repath_options: saved.map(|p| p.repath).unwrap_or_default(),
+    }
+}
+
+/// Snapshots `content/` before a step that rewrites it in place
+///
+/// Repathing moves assets and concatenation deletes the BINs it merges;
+/// the snapshot is what `restore_snapshot` rolls back to.
+async fn snapshot_content(project_path: &Path, step: &str) -> Result<(), CommandError> {
+    let manager = CheckpointManager::new(project_path.to_path_buf());
+    let message = format!("Before {}", step);
+    let snapshot = tokio::task::spawn_blocking(move || manager.create_snapshot(message, None::<fn(&str, u64, u64)>))
+        .await?
+        .map_err(CommandError::from)?;
+    tracing::info!("Snapshot {} taken before {}", snapshot.id, step);
+    Ok(())
+}
+
+/// Export a project as a .fantome mod package
 ///
 /// # Arguments
 /// * `project_path` - Path to the project directory
 /// * `output_path` - Path where the .fantome file will be created
-/// * `champion` - Champion name for WAD structure (unused by ltk_fantome, kept for API compat)
-/// * `metadata` - Mod metadata
+/// * `champion` - Champion name for WAD structure (only used for repathing, kept for API compat)
+/// * `metadata` - Mod metadata, only used when the project has no mod.config.json
 /// * `auto_repath` - Whether to run repathing before export (default: true)
+/// * `include_manifest` - Generate a README manifest, embed it and write it next to the package (default: false)
+/// * `flatten_layers` - Merge all enabled layers into the package by priority (default: false, base layer only)
+/// * `verify` - Re-open the package and check its contents afterwards (default: false)
+/// * `request_id` - ID that `cancel_request` can use to stop packing
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn export_fantome(
     project_path: String,
     output_path: String,
     champion: String,
     metadata: ExportMetadata,
     auto_repath: Option<bool>,
+    include_manifest: Option<bool>,
+    flatten_layers: Option<bool>,
+    verify: Option<bool>,
+    request_id: Option<String>,
     app: tauri::AppHandle,
-) -> Result<ExportResult, String> {
+    requests: State<'_, RequestState>,
+) -> Result<ExportResult, CommandError> {
     tracing::info!(
         "Frontend requested fantome export: {} -> {}",
         project_path,
@@ -151,53 +261,89 @@ pub async fn export_fantome(
 
     let path = PathBuf::from(&project_path);
     let output = PathBuf::from(&output_path);
+    ensure_outside_league(&output)?;
     let do_repath = auto_repath.unwrap_or(true);
+    let do_flatten = flatten_layers.unwrap_or(false);
+    let layers = project_layer_names(&path);
+    let request = requests.begin(request_id);
 
     // Step 1: Repath if requested
     if do_repath {
-        let _ = app.emit("export-progress", serde_json::json!({
-            "status": "repathing",
-            "progress": 0.2,
-            "message": "Repathing assets..."
-        }));
+        snapshot_content(&path, "export repathing").await?;
+        let saved = open_project(&path).ok();
+        let _ = app.emit("export-progress", Message::plain("progress.export.repathing").progress_payload(
+            serde_json::json!({ "status": "repathing", "progress": 0.2 }),
+        ));
+
+        // The saved mod.config.json is what gets packed, so the repath prefix
+        // comes from it too; the passed metadata only covers unsaved projects
+        let (creator_name, project_name, version) = match &saved {
+            Some(project) => (
+                project.authors.first().map(|a| a.name.clone()).unwrap_or_else(|| metadata.author.clone()),
+                project.name.clone(),
+                project.version.clone(),
+            ),
+            None => (metadata.author.clone(), slugify(&metadata.name), metadata.version.clone()),
+        };
 
         let config = OrganizerConfig {
             enable_concat: true,
             enable_repath: true,
-            creator_name: metadata.author.clone(),
-            project_name: slugify(&metadata.name),
+            creator_name,
+            project_name,
             champion: champion.clone(),
             target_skin_id: 0,
             cleanup_unused: false,
+            include_conventional_assets: true,
+            extra_targets: saved.as_ref().map(|p| p.extra_targets.clone()).unwrap_or_default(),
+            version,
+            target_kind: saved.as_ref().map(|p| p.target_kind).unwrap_or_default(),
+            repath_options: saved.map(|p| p.repath).unwrap_or_default(),
         };
 
-        let repath_path = path.join("content").join("base");
+        let content_dir = path.join("content");
+        let repath_layers = layers.clone();
+        let progress_app = app.clone();
         let repath_result = tokio::task::spawn_blocking(move || {
             let path_mappings: HashMap<String, String> = HashMap::new();
-            organize_project(&repath_path, &config, &path_mappings)
+            // Repathing covers the 0.2 - 0.5 range of the export
+            let on_progress = |p: OrganizeProgress| {
+                let _ = progress_app.emit("export-progress", p.message().progress_payload(serde_json::json!({
+                    "status": "repathing",
+                    "progress": 0.2 + 0.3 * p.fraction(),
+                })));
+            };
+            organize_layers(&content_dir, &repath_layers, &config, &path_mappings, Some(&on_progress))
         })
-        .await
-        .map_err(|e| format!("Repath task failed: {}", e))?;
+        .await?;
 
         if let Err(e) = repath_result {
             tracing::warn!("Repathing failed (continuing anyway): {}", e);
         }
     }
 
-    // Step 2: Export using ltk_fantome
-    let _ = app.emit("export-progress", serde_json::json!({
-        "status": "exporting",
-        "progress": 0.5,
-        "message": "Creating fantome package..."
-    }));
+    let manifest_path = if include_manifest.unwrap_or(false) {
+        Some(generate_manifest(&path, &output, PackageFormat::Fantome).await?)
+    } else {
+        None
+    };
+
+    // Step 2: Pack the fantome
+    let _ = app.emit("export-progress", Message::plain("progress.export.fantome").progress_payload(
+        serde_json::json!({ "status": "exporting", "progress": 0.5 }),
+    ));
 
     // Read ModProject from mod.config.json (contains author from project creation)
     let mod_config_path = path.join("mod.config.json");
     let mod_project = if mod_config_path.exists() {
         let config_data = std::fs::read_to_string(&mod_config_path)
-            .map_err(|e| format!("Failed to read mod.config.json: {}", e))?;
-        serde_json::from_str::<ModProject>(&config_data)
-            .map_err(|e| format!("Failed to parse mod.config.json: {}", e))?
+            .map_err(|e| Error::io_with_path(e, &mod_config_path))?;
+        serde_json::from_str::<ModProject>(&config_data).map_err(|e| {
+            CommandError::new("error.mod_config_parse", [
+                ("path", mod_config_path.display().to_string()),
+                ("detail", e.to_string()),
+            ])
+        })?
     } else {
         // Fallback: create from metadata if mod.config.json doesn't exist
         ModProject {
@@ -213,22 +359,66 @@ pub async fn export_fantome(
         }
     };
 
+    // Disabled layers (flint.json) never end up in the package
+    let enabled_layers: Vec<ModProjectLayer> = open_project(&path)
+        .map(|project| project.enabled_layers().into_iter().cloned().collect())
+        .unwrap_or_default();
+    let extra_layers: Vec<String> = enabled_layers
+        .iter()
+        .filter(|l| l.name != "base" && !layer_files(&path, &l.name).is_empty())
+        .map(|l| l.name.clone())
+        .collect();
+
+    // Fantome has no layers, so without flattening only the base layer is packed
+    let skipped_note = if !do_flatten && !extra_layers.is_empty() {
+        tracing::warn!("Fantome export left out layers: {}", extra_layers.join(", "));
+        format!(" (layers not included: {})", extra_layers.join(", "))
+    } else {
+        String::new()
+    };
+    let flatten = do_flatten && !extra_layers.is_empty();
+    let packed_layers: Vec<String> = if flatten {
+        enabled_layers.iter().map(|l| l.name.clone()).collect()
+    } else {
+        vec!["base".to_string()]
+    };
+
     let export_path = path.clone();
     let export_output = output.clone();
+    let cancel = request.token();
+    let progress_app = app.clone();
 
     let result = tokio::task::spawn_blocking(move || {
-        export_with_ltk_fantome(&export_path, &export_output, &mod_project)
+        // Packing covers the 0.5 - 1.0 range of the export
+        let on_progress = |p: PackProgress| {
+            let _ = progress_app.emit("export-progress", p.message().progress_payload(serde_json::json!({
+                "status": "exporting",
+                "progress": 0.5 + 0.5 * p.fraction(),
+                "current": p.current,
+                "total": p.total,
+                "item": p.item,
+            })));
+        };
+        if flatten {
+            export_flattened_fantome(&export_path, &export_output, mod_project, &enabled_layers, &cancel, &on_progress)
+        } else {
+            export_fantome_package(&export_path, &export_output, &mod_project, &cancel, &on_progress)
+        }
     })
-    .await
-    .map_err(|e| format!("Export task failed: {}", e))?;
+    .await?;
 
     match result {
         Ok((file_count, total_size)) => {
-            let _ = app.emit("export-progress", serde_json::json!({
-                "status": "complete",
-                "progress": 1.0,
-                "message": format!("Export complete: {}", output.display())
-            }));
+            let verification = if verify.unwrap_or(false) {
+                Some(verify_export(&app, &path, &output, packed_layers).await?)
+            } else {
+                None
+            };
+
+            let complete = Message::new("progress.export.complete", [("path", output.display().to_string())]);
+            let _ = app.emit("export-progress", complete.progress_payload(
+                serde_json::json!({ "status": "complete", "progress": 1.0 }),
+            ));
 
             Ok(ExportResult {
                 success: true,
@@ -236,46 +426,110 @@ pub async fn export_fantome(
                 file_count,
                 total_size,
                 message: format!(
-                    "Successfully exported {} files ({} bytes)",
-                    file_count, total_size
+                    "Successfully exported {} files ({} bytes){}",
+                    file_count, total_size, skipped_note
                 ),
+                manifest_path,
+                verification,
             })
         }
         Err(e) => {
-            let _ = app.emit("export-progress", serde_json::json!({
-                "status": "error",
-                "progress": 0.0,
-                "message": format!("Export failed: {}", e)
-            }));
+            let failed = Message::new("progress.export.failed", [("detail", e.to_string())]);
+            let _ = app.emit("export-progress", failed.progress_payload(
+                serde_json::json!({ "status": "error", "progress": 0.0 }),
+            ));
 
             Err(e)
         }
     }
 }
 
-/// Helper function to export using ltk_fantome::pack_to_fantome
-fn export_with_ltk_fantome(
+/// Layer names of a project, or just the base layer if flint.json can't be read
+fn project_layer_names(project_path: &Path) -> Vec<String> {
+    open_project(project_path)
+        .map(|project| project.layer_names())
+        .unwrap_or_else(|_| vec!["base".to_string()])
+}
+
+/// Sums the repath results of the base layer and all other layers
+///
+/// Returns (bins processed, paths modified, files relocated, missing paths).
+fn repath_totals(result: &OrganizerResult) -> (usize, usize, usize, Vec<String>) {
+    let mut totals = (0, 0, 0, Vec::new());
+    let targets = result.target_results.iter().filter_map(|(_, r)| r.repath_result.as_ref());
+    let all = result.repath_result.iter().chain(result.layer_results.iter().map(|(_, r)| r)).chain(targets);
+    for repath in all {
+        totals.0 += repath.bins_processed;
+        totals.1 += repath.paths_modified;
+        totals.2 += repath.files_relocated;
+        totals.3.extend(repath.missing_paths.iter().cloned());
+    }
+    totals.3.sort();
+    totals.3.dedup();
+    totals
+}
+
+/// Collects the concat collisions and failures of all targets
+fn concat_report(result: &OrganizerResult) -> (Vec<ConcatCollision>, Vec<String>) {
+    let mut report = (Vec::new(), Vec::new());
+    let all = std::iter::once(result).chain(result.target_results.iter().map(|(_, r)| r));
+    for target in all {
+        if let Some(concat) = &target.concat_result {
+            report.0.extend(concat.collisions.iter().cloned());
+        }
+        report.1.extend(target.concat_error.iter().cloned());
+    }
+    report
+}
+
+/// Packs the enabled layers, flattened by priority, as a single fantome
+///
+/// The layers are merged into a staging copy of the project that is removed
+/// again once the package is written.
+fn export_flattened_fantome(
     project_path: &Path,
     output_path: &Path,
-    mod_project: &ModProject,
-) -> Result<(usize, u64), String> {
-    // Create output file
-    let file = File::create(output_path)
-        .map_err(|e| format!("Failed to create output file: {}", e))?;
+    mut mod_project: ModProject,
+    layers: &[ModProjectLayer],
+    cancel: &CancelToken,
+    on_progress: &dyn Fn(PackProgress),
+) -> Result<(usize, u64), CommandError> {
+    let stage_root = flatten_stage_path(project_path);
+    flatten_layers(project_path, layers, &stage_root)
+        .map_err(|e| CommandError::new("error.flatten_layers", [("detail", e.to_string())]))?;
 
-    // Count files before export
-    let content_base = project_path.join("content").join("base");
-    let file_count = walkdir::WalkDir::new(&content_base)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file())
-        .count();
+    // The thumbnail is resolved against the root that is packed
+    mod_project.thumbnail = mod_project
+        .thumbnail
+        .map(|thumbnail| project_path.join(thumbnail).to_string_lossy().to_string());
 
-    // Use ltk_fantome to pack
-    pack_to_fantome(file, mod_project, project_path)
-        .map_err(|e| format!("ltk_fantome export failed: {}", e))?;
+    let result = export_fantome_package(&stage_root, output_path, &mod_project, cancel, on_progress);
+    if let Err(e) = std::fs::remove_dir_all(&stage_root) {
+        tracing::warn!("Failed to remove fantome staging folder: {}", e);
+    }
+    result
+}
+
+/// Packs `content/base` of a project into a .fantome at `output_path`
+///
+/// A failed or cancelled pack removes the partial package again.
+fn export_fantome_package(
+    project_path: &Path,
+    output_path: &Path,
+    mod_project: &ModProject,
+    cancel: &CancelToken,
+    on_progress: &dyn Fn(PackProgress),
+) -> Result<(usize, u64), CommandError> {
+    let file = File::create(output_path).map_err(|e| Error::io_with_path(e, output_path))?;
+
+    let file_count = match pack_fantome(BufWriter::new(file), mod_project, project_path, cancel, Some(on_progress)) {
+        Ok(count) => count,
+        Err(e) => {
+            let _ = std::fs::remove_file(output_path);
+            return Err(e.into());
+        }
+    };
 
-    // Get output file size
     let total_size = std::fs::metadata(output_path)
         .map(|m| m.len())
         .unwrap_or(0);
@@ -283,35 +537,154 @@ fn export_with_ltk_fantome(
     Ok((file_count, total_size))
 }
 
+/// Re-opens an exported package and checks it against the packed layers
+///
+/// The main skin BIN chain is only followed for champion projects. References
+/// are looked up in the game when the project has a League installation.
+async fn verify_export(
+    app: &tauri::AppHandle,
+    project_path: &Path,
+    package: &Path,
+    layers: Vec<String>,
+) -> Result<PackageVerification, CommandError> {
+    let _ = app.emit("export-progress", Message::plain("progress.export.verifying").progress_payload(
+        serde_json::json!({ "status": "verifying", "progress": 1.0 }),
+    ));
+
+    let project_path = project_path.to_path_buf();
+    let package = package.to_path_buf();
+    let verification = tokio::task::spawn_blocking(move || {
+        let project = open_project(&project_path).ok();
+        let main_bin = project
+            .as_ref()
+            .filter(|p| p.target_kind == TargetKind::Champion && !p.champion.is_empty())
+            .map(|p| format!("data/characters/{}/skins/skin{}.bin", p.champion.to_lowercase(), p.skin_id));
+        let mut game = project
+            .as_ref()
+            .and_then(|p| p.league_path.as_deref())
+            .and_then(|league_path| GameWadLookup::from_league_path(league_path).ok());
+
+        let mut expected: Vec<String> = layers.iter().flat_map(|layer| layer_files(&project_path, layer)).collect();
+        expected.sort();
+        expected.dedup();
+        verify_package(&package, &expected, main_bin.as_deref(), game.as_mut())
+    })
+    .await?
+    .map_err(CommandError::from)?;
+
+    if !verification.valid {
+        tracing::warn!(
+            "Exported package failed verification: {} missing, {} empty, {} invalid BINs, {} unresolved references",
+            verification.missing_files.len(),
+            verification.empty_files.len(),
+            verification.invalid_bins.len(),
+            verification.unresolved_references.len()
+        );
+    }
+    Ok(verification)
+}
+
+/// Writes the export manifest for a project, returning the side-by-side path
+async fn generate_manifest(
+    project_path: &Path,
+    output_path: &Path,
+    format: PackageFormat,
+) -> Result<String, CommandError> {
+    let project_path = project_path.to_path_buf();
+    let output_path = output_path.to_path_buf();
+
+    let written = tokio::task::spawn_blocking(move || {
+        let project = open_project(&project_path)?;
+        write_export_manifest(&project, &output_path, format)
+    })
+    .await?
+    .map_err(|e| CommandError::new("error.manifest", [("detail", e.to_string())]))?;
+
+    Ok(written.to_string_lossy().to_string())
+}
+
 /// Generate a suggested filename for the fantome export
 #[tauri::command]
 pub fn get_fantome_filename(name: String, version: String) -> String {
     generate_fantome_filename(&name, &version)
 }
 
-/// Get export preview (list of files that would be exported)
+/// Check a .fantome file for structural problems
+///
+/// Works on packages built by any tool, to help find out why a mod fails
+/// to load in a mod manager.
+///
+/// # Arguments
+/// * `path` - Path to the .fantome file
+///
+/// # Returns
+/// * `Ok(FantomeValidationReport)` - Issues found, with `valid` false if any is an error
+/// * `Err(CommandError)` - Error message if the file can't be read
+#[tauri::command]
+pub async fn validate_fantome(path: String) -> Result<FantomeValidationReport, CommandError> {
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || core_validate_fantome(&path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Check which of a project's files other mods also replace
+///
+/// Mods shipping the same chunk of the same WAD fight in a mod manager;
+/// only one of them is seen in game.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `mod_paths` - .fantome or .modpkg files, mod folders, or a folder of
+///   mod folders (e.g. cslol-manager's `installed`)
+///
+/// # Returns
+/// * `Ok(ConflictReport)` - Overlapping files grouped by path, and per-mod counts
+/// * `Err(CommandError)` - Error message if the project couldn't be opened
+#[tauri::command]
+pub async fn check_conflicts(project_path: String, mod_paths: Vec<String>) -> Result<ConflictReport, CommandError> {
+    tracing::info!("Frontend requested conflict check of {} against {} paths", project_path, mod_paths.len());
+
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&project_path)?;
+        Ok::<_, crate::error::Error>(core_check_conflicts(&project, &mod_paths))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Get export preview (files that would be exported, with sizes and WAD layout)
+///
+/// Base layer files are listed relative to `content/base`; files of the other
+/// enabled layers are prefixed with their layer name (e.g. `chroma1/...`).
+/// Each file is compressed as the WAD packer would to estimate the package size.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(ExportPreview)` - Files with size, kind and chunk hash, per-WAD and package totals
+/// * `Err(CommandError)` - Error message if the content folder is missing or a file can't be read
 #[tauri::command]
-pub async fn get_export_preview(project_path: String) -> Result<Vec<String>, String> {
+pub async fn get_export_preview(project_path: String) -> Result<ExportPreview, CommandError> {
     let path = PathBuf::from(&project_path);
     let content_base = path.join("content").join("base");
 
     if !content_base.exists() {
-        return Err(format!("Content directory not found: {}", content_base.display()));
+        return Err(CommandError::new("error.content_dir_missing", [("path", content_base.display().to_string())]));
     }
 
-    let files: Vec<String> = walkdir::WalkDir::new(&content_base)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file())
-        .filter_map(|e| {
-            e.path()
-                .strip_prefix(&content_base)
-                .ok()
-                .map(|p| p.to_string_lossy().to_string())
-        })
-        .collect();
-
-    Ok(files)
+    tokio::task::spawn_blocking(move || {
+        let layers: Vec<ModProjectLayer> = open_project(&path)
+            .map(|project| project.enabled_layers().into_iter().cloned().collect())
+            .unwrap_or_else(|_| vec![ModProjectLayer::base()]);
+        preview_export(&path, &layers)
+    })
+    .await?
+    .map_err(CommandError::from)
 }
 
 /// Export a project as a .modpkg mod package using ltk_modpkg
@@ -319,12 +692,15 @@ pub async fn get_export_preview(project_path: String) -> Result<Vec<String>, Str
 /// # Arguments
 /// * `project_path` - Path to the project directory
 /// * `output_path` - Path where the .modpkg file will be created
+/// * `include_manifest` - Generate a README manifest, embed it and write it next to the package (default: false)
 #[tauri::command]
 pub async fn export_modpkg(
     project_path: String,
     output_path: String,
+    include_manifest: Option<bool>,
+    verify: Option<bool>,
     app: tauri::AppHandle,
-) -> Result<ExportResult, String> {
+) -> Result<ExportResult, CommandError> {
     tracing::info!(
         "Frontend requested modpkg export: {} -> {}",
         project_path,
@@ -333,40 +709,62 @@ pub async fn export_modpkg(
 
     let path = PathBuf::from(&project_path);
     let output = PathBuf::from(&output_path);
+    ensure_outside_league(&output)?;
 
-    let _ = app.emit("export-progress", serde_json::json!({
-        "status": "exporting",
-        "progress": 0.3,
-        "message": "Creating modpkg package..."
-    }));
+    let _ = app.emit("export-progress", Message::plain("progress.export.modpkg").progress_payload(
+        serde_json::json!({ "status": "exporting", "progress": 0.3 }),
+    ));
 
     // Read ModProject from mod.config.json
     let mod_config_path = path.join("mod.config.json");
     let mod_project = if mod_config_path.exists() {
         let config_data = std::fs::read_to_string(&mod_config_path)
-            .map_err(|e| format!("Failed to read mod.config.json: {}", e))?;
-        serde_json::from_str::<ModProject>(&config_data)
-            .map_err(|e| format!("Failed to parse mod.config.json: {}", e))?
+            .map_err(|e| Error::io_with_path(e, &mod_config_path))?;
+        serde_json::from_str::<ModProject>(&config_data).map_err(|e| {
+            CommandError::new("error.mod_config_parse", [
+                ("path", mod_config_path.display().to_string()),
+                ("detail", e.to_string()),
+            ])
+        })?
+    } else {
+        return Err(CommandError::new("error.mod_config_missing", [("path", mod_config_path.display().to_string())]));
+    };
+
+    let manifest_path = if include_manifest.unwrap_or(false) {
+        Some(generate_manifest(&path, &output, PackageFormat::Modpkg).await?)
     } else {
-        return Err("mod.config.json not found - cannot export modpkg without project metadata".to_string());
+        None
     };
 
+    // Disabled layers (flint.json) are left out; the rest keep their priority
+    let layers: Vec<ModProjectLayer> = open_project(&path)
+        .map_err(CommandError::from)?
+        .enabled_layers()
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let packed_layers: Vec<String> = layers.iter().map(|l| l.name.clone()).collect();
     let export_path = path.clone();
     let export_output = output.clone();
 
     let result = tokio::task::spawn_blocking(move || {
-        export_with_ltk_modpkg(&export_path, &export_output, &mod_project)
+        export_with_ltk_modpkg(&export_path, &export_output, &mod_project, &layers)
     })
-    .await
-    .map_err(|e| format!("Export task failed: {}", e))?;
+    .await?;
 
     match result {
         Ok((file_count, total_size)) => {
-            let _ = app.emit("export-progress", serde_json::json!({
-                "status": "complete",
-                "progress": 1.0,
-                "message": format!("Export complete: {}", output.display())
-            }));
+            let verification = if verify.unwrap_or(false) {
+                Some(verify_export(&app, &path, &output, packed_layers).await?)
+            } else {
+                None
+            };
+
+            let complete = Message::new("progress.export.complete", [("path", output.display().to_string())]);
+            let _ = app.emit("export-progress", complete.progress_payload(
+                serde_json::json!({ "status": "complete", "progress": 1.0 }),
+            ));
 
             Ok(ExportResult {
                 success: true,
@@ -377,50 +775,153 @@ pub async fn export_modpkg(
                     "Successfully exported {} files ({} bytes)",
                     file_count, total_size
                 ),
+                manifest_path,
+                verification,
             })
         }
         Err(e) => {
-            let _ = app.emit("export-progress", serde_json::json!({
-                "status": "error",
-                "progress": 0.0,
-                "message": format!("Export failed: {}", e)
-            }));
+            let failed = Message::new("progress.export.failed", [("detail", e.to_string())]);
+            let _ = app.emit("export-progress", failed.progress_payload(
+                serde_json::json!({ "status": "error", "progress": 0.0 }),
+            ));
 
             Err(e)
         }
     }
 }
 
+/// Packs a WAD folder of the project's base layer into a raw WAD archive
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `wad_name` - WAD folder under `content/base` (e.g. "Ahri.wad.client");
+///   defaults to the only one, or the one named after the project's champion
+/// * `output_path` - WAD file to write; defaults to `output/{wad_name}` in the project
+/// * `request_id` - ID that `cancel_request` can use to stop packing
+#[tauri::command]
+pub async fn pack_wad(
+    project_path: String,
+    wad_name: Option<String>,
+    output_path: Option<String>,
+    request_id: Option<String>,
+    app: tauri::AppHandle,
+    requests: State<'_, RequestState>,
+) -> Result<WadPackResult, CommandError> {
+    let path = PathBuf::from(&project_path);
+    let base = path.join("content").join("base");
+    let wad_name = match wad_name {
+        Some(name) => name,
+        None => default_wad_folder(&path, &base)?,
+    };
+    let output = output_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| path.join("output").join(&wad_name));
+    ensure_outside_league(&output)?;
+
+    let request = requests.begin(request_id);
+    let cancel = request.token();
+    let progress_app = app.clone();
+    let wad_dir = base.join(&wad_name);
+
+    let result = tokio::task::spawn_blocking(move || {
+        let on_progress = |current: usize, total: usize| {
+            let message = Message::new(
+                "progress.export.wad",
+                [("current", current.to_string()), ("total", total.to_string())],
+            );
+            let _ = progress_app.emit("export-progress", message.progress_payload(serde_json::json!({
+                "status": "exporting",
+                "progress": current as f64 / total.max(1) as f64,
+                "current": current,
+                "total": total,
+            })));
+        };
+        packer::pack_wad(&wad_dir, &output, &cancel, Some(&on_progress))
+    })
+    .await?;
+
+    match result {
+        Ok(result) => {
+            let complete = Message::new("progress.export.complete", [("path", result.output_path.clone())]);
+            let _ = app.emit("export-progress", complete.progress_payload(
+                serde_json::json!({ "status": "complete", "progress": 1.0 }),
+            ));
+            Ok(result)
+        }
+        Err(e) => {
+            let failed = Message::new("progress.export.failed", [("detail", e.to_string())]);
+            let _ = app.emit("export-progress", failed.progress_payload(
+                serde_json::json!({ "status": "error", "progress": 0.0 }),
+            ));
+            Err(e.into())
+        }
+    }
+}
+
+/// The WAD folder packed when none is named: the only one in the base
+/// layer, or the one named after the project's champion
+fn default_wad_folder(project_path: &Path, base: &Path) -> Result<String, CommandError> {
+    let folders: Vec<String> = std::fs::read_dir(base)
+        .map_err(|e| crate::error::Error::io_with_path(e, base))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.to_lowercase().ends_with(".wad.client"))
+        .collect();
+
+    if let [only] = folders.as_slice() {
+        return Ok(only.clone());
+    }
+    let champion = open_project(project_path)
+        .map(|project| format!("{}.wad.client", project.champion.to_lowercase()))
+        .unwrap_or_default();
+    folders
+        .into_iter()
+        .find(|name| name.to_lowercase() == champion)
+        .ok_or_else(|| CommandError::plain("error.wad_folder_ambiguous"))
+}
+
+/// Error for a failed modpkg build step
+fn modpkg_error(e: impl std::fmt::Display) -> CommandError {
+    CommandError::new("error.modpkg_build", [("detail", e.to_string())])
+}
+
 /// Helper function to export using ltk_modpkg
+///
+/// Only the given layers are packed, each with its priority from mod.config.json.
 fn export_with_ltk_modpkg(
     project_path: &Path,
     output_path: &Path,
     mod_project: &ModProject,
-) -> Result<(usize, u64), String> {
+    layers: &[ModProjectLayer],
+) -> Result<(usize, u64), CommandError> {
     use ltk_modpkg::builder::{ModpkgBuilder, ModpkgChunkBuilder, ModpkgLayerBuilder};
-    use ltk_modpkg::{ModpkgMetadata, ModpkgAuthor};
+    use ltk_modpkg::{ModpkgMetadata, ModpkgAuthor, ModpkgLicense};
     use std::io::Write;
 
-    // Collect all files and their data
-    let content_base = project_path.join("content").join("base");
-    let mut file_map: HashMap<String, Vec<u8>> = HashMap::new();
-    
-    for entry in walkdir::WalkDir::new(&content_base)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file())
-    {
-        let file_path = entry.path();
-        let relative_path = file_path
-            .strip_prefix(&content_base)
-            .map_err(|e| format!("Failed to get relative path: {}", e))?;
-        
-        let file_data = std::fs::read(file_path)
-            .map_err(|e| format!("Failed to read file {}: {}", file_path.display(), e))?;
-        
-        // Normalize path separators and lowercase (modpkg builder lowercases paths internally)
-        let normalized_path = relative_path.to_string_lossy().replace("\\", "/").to_lowercase();
-        file_map.insert(normalized_path, file_data);
+    // Collect all files and their data, keyed by (layer, path)
+    let mut file_map: HashMap<(String, String), Vec<u8>> = HashMap::new();
+
+    for layer in layers {
+        let content_dir = project_path.join("content").join(&layer.name);
+
+        for entry in walkdir::WalkDir::new(&content_dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_file())
+        {
+            let file_path = entry.path();
+            let relative_path = file_path
+                .strip_prefix(&content_dir)
+                .map_err(modpkg_error)?;
+
+            let file_data = std::fs::read(file_path)
+                .map_err(|e| Error::io_with_path(e, file_path))?;
+
+            // Normalize path separators and lowercase (modpkg builder lowercases paths internally)
+            let normalized_path = relative_path.to_string_lossy().replace("\\", "/").to_lowercase();
+            file_map.insert((layer.name.clone(), normalized_path), file_data);
+        }
     }
 
     let file_count = file_map.len();
@@ -445,36 +946,63 @@ fn export_with_ltk_modpkg(
                 ltk_mod_project::ModProjectAuthor::Role { name, role } => ModpkgAuthor::new(name.clone(), Some(role.clone())),
             }
         }).collect(),
+        license: match &mod_project.license {
+            Some(ltk_mod_project::ModProjectLicense::Spdx(spdx_id)) => ModpkgLicense::Spdx { spdx_id: spdx_id.clone() },
+            Some(ltk_mod_project::ModProjectLicense::Custom { name, url }) => ModpkgLicense::Custom { name: name.clone(), url: url.clone() },
+            None => ModpkgLicense::None,
+        },
         ..Default::default()
     };
 
-    // Build the modpkg - add base layer and chunks
+    // Build the modpkg - add layers and chunks
     let mut builder = ModpkgBuilder::default()
         .with_metadata(metadata)
-        .map_err(|e| format!("Failed to set metadata: {}", e))?
-        .with_layer(ModpkgLayerBuilder::base());
+        .map_err(modpkg_error)?;
+    for layer in layers {
+        builder = builder.with_layer(ModpkgLayerBuilder::new(&layer.name).with_priority(layer.priority));
+    }
+
+    // Embed the project README like ltk_fantome does for META/README.md
+    let readme_path = project_path.join("README.md");
+    if readme_path.exists() {
+        let readme = std::fs::read_to_string(&readme_path)
+            .map_err(|e| Error::io_with_path(e, &readme_path))?;
+        builder = builder
+            .with_readme(&readme)
+            .map_err(modpkg_error)?;
+    }
+
+    // The thumbnail is a WebP meta chunk; fantomes get it as META/image.png
+    if let Some(thumbnail) = &mod_project.thumbnail {
+        let thumbnail_path = project_path.join(thumbnail);
+        if thumbnail_path.is_file() {
+            let thumbnail = load_thumbnail(&thumbnail_path, PreviewFormat::Webp)?;
+            builder = builder
+                .with_thumbnail(thumbnail)
+                .map_err(modpkg_error)?;
+        }
+    }
 
     // Add all files as chunks
-    for path in file_map.keys() {
+    for (layer, path) in file_map.keys() {
         let chunk = ModpkgChunkBuilder::new()
             .with_path(path)
-            .map_err(|e| format!("Failed to set chunk path: {}", e))?
-            .with_layer("base");
+            .map_err(modpkg_error)?
+            .with_layer(layer);
         builder = builder.with_chunk(chunk);
     }
 
     // Create output file
-    let mut output_file = File::create(output_path)
-        .map_err(|e| format!("Failed to create output file: {}", e))?;
+    let mut output_file = File::create(output_path).map_err(|e| Error::io_with_path(e, output_path))?;
 
     // Build to writer with data provider closure
     builder.build_to_writer(&mut output_file, |chunk_builder, cursor| {
-        if let Some(data) = file_map.get(&chunk_builder.path) {
+        if let Some(data) = file_map.get(&(chunk_builder.layer.clone(), chunk_builder.path.clone())) {
             cursor.write_all(data)?;
         }
         Ok(())
     })
-    .map_err(|e| format!("Failed to build modpkg: {}", e))?;
+    .map_err(modpkg_error)?;
 
     // Get output file size
     let total_size = std::fs::metadata(output_path)
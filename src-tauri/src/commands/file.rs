@@ -4,14 +4,26 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
-use image::{RgbaImage, Rgba};
-use ltk_texture::Texture;
-use std::io::Cursor;
+use crate::commands::app_data_dir;
+use crate::core::chroma::{recolor_texture_file, ColorShift};
+use crate::core::diff::{diff_lines, TextDiff, DEFAULT_CONTEXT};
+use crate::core::export::preview::{decode_texture_rgba, encode_preview, PreviewFormat};
+use crate::core::league::guard::ensure_outside_league;
+use crate::core::texture::encode::{self, TexConversion, TexFormat};
+use crate::core::texture::inspect::{self, TextureView};
+use crate::core::texture::{replace_texture_file, TextureReplacement};
+use crate::core::thumbnail::{self, Thumbnail};
+use crate::error::{CommandError, Error};
+use crate::state::{CancelToken, RequestState};
+use tauri::{AppHandle, State};
+use ts_rs::TS;
 
 /// Information about a file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct FileInfo {
     pub path: String,
+    #[ts(type = "number")]
     pub size: u64,
     pub file_type: String,
     pub extension: String,
@@ -20,149 +32,26 @@ pub struct FileInfo {
 }
 
 /// Result of decoding a DDS file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct DecodedImage {
     /// Base64-encoded PNG data
     pub data: String,
+    /// Size of the decoded mip level
     pub width: u32,
     pub height: u32,
     pub format: String,
+    pub mip_level: u32,
+    pub mip_count: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct RecolorFolderResult {
     pub processed: u32,
     pub failed: u32,
 }
 
-// =============================================================================
-// HSL Color Transformation Helpers
-// =============================================================================
-
-fn rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
-    let max = r.max(g).max(b);
-    let min = r.min(g).min(b);
-    let l = (max + min) / 2.0;
-
-    if max == min {
-        return (0.0, 0.0, l);
-    }
-
-    let d = max - min;
-    let s = if l > 0.5 { d / (2.0 - max - min) } else { d / (max + min) };
-
-    let mut h = if max == r {
-        (g - b) / d + (if g < b { 6.0 } else { 0.0 })
-    } else if max == g {
-        (b - r) / d + 2.0
-    } else {
-        (r - g) / d + 4.0
-    };
-    h /= 6.0;
-
-    (h * 360.0, s, l)
-}
-
-fn hue_to_rgb(p: f32, q: f32, mut t: f32) -> f32 {
-    if t < 0.0 { t += 1.0; }
-    if t > 1.0 { t -= 1.0; }
-    if t < 1.0/6.0 { return p + (q - p) * 6.0 * t; }
-    if t < 1.0/2.0 { return q; }
-    if t < 2.0/3.0 { return p + (q - p) * (2.0/3.0 - t) * 6.0; }
-    p
-}
-
-fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
-    if s == 0.0 {
-        return (l, l, l);
-    }
-
-    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
-    let p = 2.0 * l - q;
-
-    let h = h / 360.0;
-    let r = hue_to_rgb(p, q, h + 1.0/3.0);
-    let g = hue_to_rgb(p, q, h);
-    let b = hue_to_rgb(p, q, h - 1.0/3.0);
-
-    (r, g, b)
-}
-
-fn apply_hsl_to_image(img: &mut RgbaImage, hue_shift: f32, sat_mult: f32, bri_mult: f32) {
-    for pixel in img.pixels_mut() {
-        let Rgba([r, g, b, a]) = *pixel;
-        
-        // Convert to normalized float
-        let rf = r as f32 / 255.0;
-        let gf = g as f32 / 255.0;
-        let bf = b as f32 / 255.0;
-        
-        // Convert to HSL
-        let (h, s, l) = rgb_to_hsl(rf, gf, bf);
-        
-        // Apply shifts/multipliers
-        let new_h = (h + hue_shift) % 360.0;
-        let new_h = if new_h < 0.0 { new_h + 360.0 } else { new_h };
-        let new_s = (s * sat_mult).clamp(0.0, 1.0);
-        let new_l = (l * bri_mult).clamp(0.0, 1.0);
-        
-        // Convert back to RGB
-        let (nr, ng, nb) = hsl_to_rgb(new_h, new_s, new_l);
-        
-        // Update pixel
-        *pixel = Rgba([
-            (nr * 255.0).round() as u8,
-            (ng * 255.0).round() as u8,
-            (nb * 255.0).round() as u8,
-            a
-        ]);
-    }
-}
-
-/// Colorize mode: Set all pixels to a target hue while preserving lightness
-/// This makes everything "one color" while keeping the original shading
-/// Skips transparent pixels and very dark/black pixels to preserve backgrounds
-fn colorize_image_impl(img: &mut RgbaImage, target_hue: f32, preserve_saturation: bool) {
-    for pixel in img.pixels_mut() {
-        let Rgba([r, g, b, a]) = *pixel;
-        
-        // Skip fully transparent pixels (preserve alpha)
-        if a == 0 {
-            continue;
-        }
-        
-        // Convert to normalized float
-        let rf = r as f32 / 255.0;
-        let gf = g as f32 / 255.0;
-        let bf = b as f32 / 255.0;
-        
-        // Convert to HSL
-        let (_h, s, l) = rgb_to_hsl(rf, gf, bf);
-        
-        // Skip very dark pixels (black backgrounds) - threshold at ~10% lightness
-        if l < 0.10 {
-            continue;
-        }
-        
-        // Skip very light pixels (pure white areas) - optional, keep for now
-        // if l > 0.95 { continue; }
-        
-        // Set to target hue, optionally preserve original saturation
-        let new_s = if preserve_saturation { s } else { 0.7_f32.min(s.max(0.3)) };
-        
-        // Convert back to RGB with target hue
-        let (nr, ng, nb) = hsl_to_rgb(target_hue, new_s, l);
-        
-        // Update pixel (alpha is preserved)
-        *pixel = Rgba([
-            (nr * 255.0).round() as u8,
-            (ng * 255.0).round() as u8,
-            (nb * 255.0).round() as u8,
-            a
-        ]);
-    }
-}
-
 /// Detect file type from extension and magic bytes using league-toolkit's LeagueFileKind
 fn detect_file_type(path: &Path, data: &[u8]) -> (String, String) {
     let extension = path
@@ -220,23 +109,87 @@ fn detect_file_type(path: &Path, data: &[u8]) -> (String, String) {
     (file_type, extension)
 }
 
+/// Most bytes `read_file_bytes` returns from one call
+const MAX_READ_BYTES: u64 = 64 * 1024 * 1024;
+
 /// Read raw file bytes from disk
 ///
+/// Bytes go over IPC as a raw binary response rather than a JSON array.
+/// Big assets are read in chunks by passing `offset` and `length`; a single
+/// read over 64 MB fails with `error.file_too_large` instead of stalling the
+/// UI or running out of memory.
+///
 /// # Arguments
 /// * `path` - Path to the file
+/// * `offset` - Byte to start at (default 0)
+/// * `length` - Most bytes to read (default: to the end of the file)
 ///
 /// # Returns
-/// * `Ok(Vec<u8>)` - File contents as bytes
-/// * `Err(String)` - Error message
+/// * `Ok(Response)` - The bytes read; fewer than `length` at the end of the file
+/// * `Err(CommandError)` - Error message
 #[tauri::command]
-pub async fn read_file_bytes(path: String) -> Result<Vec<u8>, String> {
-    let path = Path::new(&path);
+pub async fn read_file_bytes(
+    path: String,
+    offset: Option<u64>,
+    length: Option<u64>,
+) -> Result<tauri::ipc::Response, CommandError> {
+    tokio::task::spawn_blocking(move || read_file_range(Path::new(&path), offset.unwrap_or(0), length))
+        .await?
+        .map(tauri::ipc::Response::new)
+}
+
+fn read_file_range(path: &Path, offset: u64, length: Option<u64>) -> Result<Vec<u8>, CommandError> {
+    use std::io::{Read, Seek, SeekFrom};
 
     if !path.exists() {
-        return Err(format!("File not found: {}", path.display()));
+        return Err(CommandError::new("error.file_not_found", [("path", path.display().to_string())]));
     }
 
-    fs::read(path).map_err(|e| format!("Failed to read file: {}", e))
+    let mut file = fs::File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    let size = file.metadata().map_err(|e| Error::io_with_path(e, path))?.len();
+    let available = size.saturating_sub(offset);
+    let length = length.map_or(available, |length| length.min(available));
+    if length > MAX_READ_BYTES {
+        return Err(CommandError::new(
+            "error.file_too_large",
+            [
+                ("path", path.display().to_string()),
+                ("size", length.to_string()),
+                ("limit", MAX_READ_BYTES.to_string()),
+            ],
+        ));
+    }
+
+    file.seek(SeekFrom::Start(offset)).map_err(|e| Error::io_with_path(e, path))?;
+    let mut data = Vec::with_capacity(length as usize);
+    file.take(length)
+        .read_to_end(&mut data)
+        .map_err(|e| Error::io_with_path(e, path))?;
+    Ok(data)
+}
+
+/// Read a window of a file as hex rows, annotated with the header fields of
+/// WAD, BIN and SKN files
+///
+/// # Arguments
+/// * `path` - Path to the file
+/// * `offset` - Byte to start at (default 0)
+/// * `length` - Bytes to show (default 4 KB, at most 64 KB)
+///
+/// # Returns
+/// * `Ok(HexDump)` - Rows, annotations and any structural problem found
+/// * `Err(CommandError)` - Error message
+#[tauri::command]
+pub async fn read_file_hex(
+    path: String,
+    offset: Option<u64>,
+    length: Option<u32>,
+) -> Result<crate::core::hexview::HexDump, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        crate::core::hexview::read_file_hex(Path::new(&path), offset.unwrap_or(0), length.unwrap_or(4096))
+    })
+    .await?
+    .map_err(CommandError::from)
 }
 
 /// Get file metadata and type information
@@ -246,25 +199,25 @@ pub async fn read_file_bytes(path: String) -> Result<Vec<u8>, String> {
 ///
 /// # Returns
 /// * `Ok(FileInfo)` - File metadata
-/// * `Err(String)` - Error message
+/// * `Err(CommandError)` - Error message
 #[tauri::command]
-pub async fn read_file_info(path: String) -> Result<FileInfo, String> {
+pub async fn read_file_info(path: String) -> Result<FileInfo, CommandError> {
     let path_buf = std::path::PathBuf::from(&path);
 
     if !path_buf.exists() {
-        return Err(format!("File not found: {}", path));
+        return Err(CommandError::new("error.file_not_found", [("path", path.to_string())]));
     }
 
-    let metadata = fs::metadata(&path_buf).map_err(|e| format!("Failed to read metadata: {}", e))?;
+    let metadata = fs::metadata(&path_buf).map_err(|e| Error::io_with_path(e, &path_buf))?;
 
     // Read first few bytes for magic detection
-    let data = fs::read(&path_buf).map_err(|e| format!("Failed to read file: {}", e))?;
+    let data = fs::read(&path_buf).map_err(|e| Error::io_with_path(e, &path_buf))?;
 
     let (file_type, extension) = detect_file_type(&path_buf, &data);
 
     // Try to get dimensions for texture files (DDS and TEX)
     let dimensions = if file_type == "image/dds" || file_type == "image/tex" {
-        parse_texture_dimensions(&data).ok()
+        parse_texture_dimensions(&data)
     } else {
         None
     };
@@ -279,56 +232,101 @@ pub async fn read_file_info(path: String) -> Result<FileInfo, String> {
 }
 
 /// Parse texture dimensions using ltk_texture (handles both DDS and TEX)
-fn parse_texture_dimensions(data: &[u8]) -> Result<(u32, u32), String> {
+fn parse_texture_dimensions(data: &[u8]) -> Option<(u32, u32)> {
     use ltk_texture::Texture;
     use std::io::Cursor;
 
     let mut cursor = Cursor::new(data);
-    let texture = Texture::from_reader(&mut cursor)
-        .map_err(|e| format!("Failed to parse texture: {:?}", e))?;
+    let texture = Texture::from_reader(&mut cursor).ok()?;
 
-    Ok((texture.width(), texture.height()))
+    Some(crate::core::texture::texture_size(&texture, data))
 }
 
 /// Decode a DDS or TEX texture file to base64-encoded PNG
 ///
 /// # Arguments
 /// * `path` - Path to the texture file (DDS or TEX)
+/// * `request_id` - ID that `cancel_request` can use to abandon the decode
 ///
 /// # Returns
 /// * `Ok(DecodedImage)` - Base64 PNG data with dimensions
-/// * `Err(String)` - Error message
+/// * `Err(CommandError)` - Error message, or `error.cancelled`
+#[tauri::command]
+pub async fn decode_dds_to_png(
+    path: String,
+    request_id: Option<String>,
+    requests: State<'_, RequestState>,
+) -> Result<DecodedImage, CommandError> {
+    let request = requests.begin(request_id);
+    decode_texture_to_png(path, request.token()).await
+}
+
+/// Decode one mip level of a DDS or TEX texture to PNG for inspection
+///
+/// # Arguments
+/// * `path` - Path to the texture file (DDS or TEX)
+/// * `mip` - Mip level to decode; 0 (the default) is full resolution
+/// * `view` - Channel to isolate, normal map or alpha preview (default: plain RGBA)
+/// * `request_id` - ID that `cancel_request` can use to abandon the decode
+///
+/// # Returns
+/// * `Ok(DecodedImage)` - Base64 PNG data with the mip's dimensions and the mip count
+/// * `Err(CommandError)` - Error message, or `error.cancelled`
 #[tauri::command]
-pub async fn decode_dds_to_png(path: String) -> Result<DecodedImage, String> {
+pub async fn decode_texture(
+    path: String,
+    mip: Option<u32>,
+    view: Option<TextureView>,
+    request_id: Option<String>,
+    requests: State<'_, RequestState>,
+) -> Result<DecodedImage, CommandError> {
+    let request = requests.begin(request_id);
+    let cancel = request.token();
+    tokio::task::spawn_blocking(move || {
+        decode_texture_blocking(&path, mip.unwrap_or(0), view.unwrap_or_default(), &cancel)
+    })
+    .await?
+}
+
+/// Decodes a texture to PNG, stopping between stages once `cancel` is set
+pub(crate) async fn decode_texture_to_png(
+    path: String,
+    cancel: CancelToken,
+) -> Result<DecodedImage, CommandError> {
+    tokio::task::spawn_blocking(move || decode_texture_blocking(&path, 0, TextureView::Rgba, &cancel)).await?
+}
+
+fn decode_texture_blocking(
+    path: &str,
+    mip: u32,
+    view: TextureView,
+    cancel: &CancelToken,
+) -> Result<DecodedImage, CommandError> {
     use ltk_texture::Texture;
     use std::io::Cursor;
 
-    let path_buf = std::path::PathBuf::from(&path);
+    cancel.check()?;
 
     // Read the texture file
-    let data = fs::read(&path_buf).map_err(|e| format!("Failed to read texture file: {}", e))?;
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
 
     if data.len() < 4 {
-        return Err("File too small to be a valid texture".to_string());
+        return Err(CommandError::new("error.texture_too_small", [("path", path.to_string())]));
     }
 
     // Use ltk_texture to read the texture (automatically handles DDS and TEX)
     let mut cursor = Cursor::new(&data);
-    let texture = Texture::from_reader(&mut cursor)
-        .map_err(|e| format!("Failed to parse texture: {:?}", e))?;
-
-    let width = texture.width();
-    let height = texture.height();
+    let texture = Texture::from_reader(&mut cursor).map_err(|e| {
+        CommandError::new("error.texture_parse", [("path", path.to_string()), ("detail", format!("{:?}", e))])
+    })?;
 
-    // Decode the mipmap level 0 (full resolution)
-    let surface = texture
-        .decode_mipmap(0)
-        .map_err(|e| format!("Failed to decode texture: {:?}", e))?;
+    cancel.check()?;
 
-    // Convert to RGBA image
-    let rgba_image = surface
-        .into_rgba_image()
-        .map_err(|e| format!("Failed to convert to RGBA: {:?}", e))?;
+    // Decode the requested mipmap level (0 is full resolution)
+    let mut rgba_image = inspect::decode_mip(&texture, mip)?;
+    let (width, height) = rgba_image.dimensions();
+    inspect::apply_view(&mut rgba_image, view);
+    cancel.check()?;
 
     // Determine format based on magic bytes
     let format = match &data[0..4] {
@@ -344,7 +342,7 @@ pub async fn decode_dds_to_png(path: String) -> Result<DecodedImage, String> {
         let encoder = image::codecs::png::PngEncoder::new(&mut png_data);
         encoder
             .write_image(rgba_image.as_raw(), width, height, image::ExtendedColorType::Rgba8)
-            .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+            .map_err(|e| CommandError::new("error.png_encode", [("detail", e.to_string())]))?;
     }
 
     // Base64 encode
@@ -355,7 +353,99 @@ pub async fn decode_dds_to_png(path: String) -> Result<DecodedImage, String> {
         width,
         height,
         format: format.to_string(),
+        mip_level: mip,
+        mip_count: inspect::mip_count(&texture),
+    })
+}
+
+/// Get a small PNG preview of a file for the project tree
+///
+/// Thumbnails are cached on disk by path, modification time and size, so a
+/// file is only decoded again after it changes.
+///
+/// # Arguments
+/// * `path` - DDS, TEX, PNG/JPG/TGA, SKN or SCB/SCO file
+/// * `size` - Longest side in pixels (16-512)
+///
+/// # Returns
+/// * `Ok(Thumbnail)` - Base64 PNG data with its dimensions
+#[tauri::command]
+pub async fn get_file_thumbnail(path: String, size: u32, app: AppHandle) -> Result<Thumbnail, CommandError> {
+    let cache_dir = app_data_dir(&app)?.join(thumbnail::CACHE_DIR);
+
+    tokio::task::spawn_blocking(move || thumbnail::get_thumbnail(&cache_dir, Path::new(&path), size))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Cancel a running preview request
+///
+/// The command started with this `request_id` stops at its next checkpoint
+/// and fails with `error.cancelled`.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether a request with that ID was running
+#[tauri::command]
+pub async fn cancel_request(
+    request_id: String,
+    requests: State<'_, RequestState>,
+) -> Result<bool, CommandError> {
+    Ok(requests.cancel(&request_id))
+}
+
+/// Export a texture or model turnaround as a shareable preview image
+///
+/// # Arguments
+/// * `asset` - Path to the texture file (DDS or TEX); ignored when `frames` is given
+/// * `output_path` - Destination file path
+/// * `format` - Output format ("png", "gif" or "webp")
+/// * `size` - Optional maximum edge length per frame in pixels
+/// * `frames` - Base64 PNG captures from the 3D viewport for a model turnaround
+/// * `frame_delay_ms` - Delay between GIF frames (default 80ms)
+///
+/// # Returns
+/// * `Ok(String)` - Path of the written image
+/// * `Err(CommandError)` - Error message
+#[tauri::command]
+pub async fn export_preview_image(
+    asset: String,
+    output_path: String,
+    format: PreviewFormat,
+    size: Option<u32>,
+    frames: Option<Vec<String>>,
+    frame_delay_ms: Option<u32>,
+) -> Result<String, CommandError> {
+    ensure_outside_league(&output_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        let images = match frames {
+            Some(frames) if !frames.is_empty() => frames
+                .iter()
+                .map(|frame| {
+                    let encoded = frame.split_once(',').map(|(_, d)| d).unwrap_or(frame);
+                    let bytes = STANDARD
+                        .decode(encoded)
+                        .map_err(|e| CommandError::new("error.preview_frame", [("detail", e.to_string())]))?;
+                    image::load_from_memory(&bytes)
+                        .map(|img| img.to_rgba8())
+                        .map_err(|e| CommandError::new("error.preview_frame", [("detail", e.to_string())]))
+                })
+                .collect::<Result<Vec<_>, CommandError>>()?,
+            _ => {
+                let data = fs::read(&asset).map_err(|e| Error::io_with_path(e, &asset))?;
+                vec![decode_texture_rgba(&data).map_err(CommandError::from)?]
+            }
+        };
+
+        let encoded = encode_preview(images, format, size, frame_delay_ms.unwrap_or(80))
+            .map_err(CommandError::from)?;
+
+        fs::write(&output_path, encoded).map_err(|e| Error::io_with_path(e, &output_path))?;
+
+        tracing::info!("Exported {} preview to {}", format.extension(), output_path);
+        Ok(output_path)
     })
+    .await?
 }
 
 
@@ -367,16 +457,134 @@ pub async fn decode_dds_to_png(path: String) -> Result<DecodedImage, String> {
 ///
 /// # Returns
 /// * `Ok(String)` - File content as string
-/// * `Err(String)` - Error message
+/// * `Err(CommandError)` - Error message
 #[tauri::command]
-pub async fn read_text_file(path: String) -> Result<String, String> {
+pub async fn read_text_file(path: String) -> Result<String, CommandError> {
     let path = Path::new(&path);
 
     if !path.exists() {
-        return Err(format!("File not found: {}", path.display()));
+        return Err(CommandError::new("error.file_not_found", [("path", path.display().to_string())]));
     }
 
-    fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))
+    fs::read_to_string(path).map_err(|e| Error::io_with_path(e, path).into())
+}
+
+/// Diff two text files line by line
+///
+/// Suited to ritobin caches, exported JSON or a file against its backup; only
+/// the changed hunks are returned, so multi-MB files stay cheap to render.
+///
+/// # Arguments
+/// * `a` - Path to the original file
+/// * `b` - Path to the changed file
+/// * `context` - Unchanged lines shown around each change (default: 3)
+///
+/// # Returns
+/// * `Ok(TextDiff)` - Hunks and added/removed line counts
+/// * `Err(CommandError)` - Error message if either file can't be read
+#[tauri::command]
+pub async fn diff_text_files(a: String, b: String, context: Option<usize>) -> Result<TextDiff, CommandError> {
+    let (path_a, path_b) = (PathBuf::from(&a), PathBuf::from(&b));
+
+    tokio::task::spawn_blocking(move || {
+        let old = fs::read(&path_a).map_err(|e| Error::io_with_path(e, &path_a))?;
+        let new = fs::read(&path_b).map_err(|e| Error::io_with_path(e, &path_b))?;
+        Ok(diff_lines(
+            &String::from_utf8_lossy(&old),
+            &String::from_utf8_lossy(&new),
+            context.unwrap_or(DEFAULT_CONTEXT),
+        ))
+    })
+    .await?
+}
+
+/// Replace a texture with a PNG, JPG or TGA image
+///
+/// The image is re-encoded in the target's existing format (BC1, BC3, ETC1,
+/// ...) with generated mipmaps, then written over the target.
+///
+/// # Arguments
+/// * `source_path` - Image to use
+/// * `target_path` - DDS or TEX file in the project to overwrite
+///
+/// # Returns
+/// * `Ok(TextureReplacement)` - Format and dimensions written
+/// * `Err(CommandError)` - Error message if either file can't be read or the image can't be encoded
+#[tauri::command]
+pub async fn replace_texture(
+    source_path: String,
+    target_path: String,
+) -> Result<TextureReplacement, CommandError> {
+    ensure_outside_league(&target_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        replace_texture_file(Path::new(&source_path), Path::new(&target_path))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Output path of a TEX conversion: the given path, or the input with a `.tex` extension
+fn tex_output_path(input_path: &str, output_path: Option<String>) -> PathBuf {
+    output_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(input_path).with_extension("tex"))
+}
+
+/// Convert a DDS file to League's TEX format
+///
+/// # Arguments
+/// * `input_path` - DDS file to convert
+/// * `output_path` - Where to write the TEX; next to the input if omitted
+/// * `format` - BC1, BC3 or BGRA8; matches the DDS compression if omitted
+/// * `mipmaps` - Generate mipmaps (default true)
+///
+/// # Returns
+/// * `Ok(TexConversion)` - Written path, format and dimensions
+/// * `Err(CommandError)` - Error message if the DDS can't be read or encoded
+#[tauri::command]
+pub async fn convert_dds_to_tex(
+    input_path: String,
+    output_path: Option<String>,
+    format: Option<TexFormat>,
+    mipmaps: Option<bool>,
+) -> Result<TexConversion, CommandError> {
+    let output = tex_output_path(&input_path, output_path);
+    ensure_outside_league(&output)?;
+
+    tokio::task::spawn_blocking(move || {
+        encode::convert_dds_to_tex(Path::new(&input_path), &output, format, mipmaps.unwrap_or(true))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Convert a PNG image to League's TEX format
+///
+/// # Arguments
+/// * `input_path` - Image to convert
+/// * `output_path` - Where to write the TEX; next to the input if omitted
+/// * `format` - BC1, BC3 or BGRA8; BC3 for images with transparency, BC1 otherwise, if omitted
+/// * `mipmaps` - Generate mipmaps (default true)
+///
+/// # Returns
+/// * `Ok(TexConversion)` - Written path, format and dimensions
+/// * `Err(CommandError)` - Error message if the image can't be read or encoded
+#[tauri::command]
+pub async fn convert_png_to_tex(
+    input_path: String,
+    output_path: Option<String>,
+    format: Option<TexFormat>,
+    mipmaps: Option<bool>,
+) -> Result<TexConversion, CommandError> {
+    let output = tex_output_path(&input_path, output_path);
+    ensure_outside_league(&output)?;
+
+    tokio::task::spawn_blocking(move || {
+        encode::convert_image_to_tex(Path::new(&input_path), &output, format, mipmaps.unwrap_or(true))
+    })
+    .await?
+    .map_err(CommandError::from)
 }
 
 /// Recolor a single texture file (DDS or TEX)
@@ -386,7 +594,7 @@ pub async fn recolor_image(
     hue: f32,
     saturation: f32,
     brightness: f32,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     recolor_single_file(&path, hue, saturation, brightness).await
 }
 
@@ -396,80 +604,20 @@ async fn recolor_single_file(
     hue: f32,
     saturation: f32,
     brightness: f32,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
+    shift_single_file(path, ColorShift::Hsl { hue, saturation, brightness }).await
+}
+
+/// Applies a color shift to a DDS or TEX file in place
+async fn shift_single_file(path: &str, shift: ColorShift) -> Result<(), CommandError> {
     let path_buf = PathBuf::from(path);
     if !path_buf.exists() {
-        return Err(format!("File not found: {}", path));
-    }
-
-    let data = fs::read(&path_buf).map_err(|e| format!("Failed to read file: {}", e))?;
-    if data.len() < 4 {
-        return Err("File too small".into());
-    }
-
-    let is_tex = &data[0..4] == b"TEX\0";
-    let is_dds = &data[0..4] == b"DDS ";
-
-    if !is_tex && !is_dds {
-        return Err("Not a supported texture format (DDS or TEX)".into());
-    }
-
-    // Decode using ltk_texture
-    let mut cursor = Cursor::new(&data);
-    let texture = Texture::from_reader(&mut cursor)
-        .map_err(|e| format!("Failed to parse texture: {:?}", e))?;
-
-    // Decode top mipmap
-    let surface = texture.decode_mipmap(0)
-        .map_err(|e| format!("Failed to decode mipmap: {:?}", e))?;
-    
-    let mut rgba_img = surface.into_rgba_image()
-        .map_err(|e| format!("Failed to get RGBA image: {:?}", e))?;
-
-    // Apply HSL transform
-    apply_hsl_to_image(&mut rgba_img, hue, saturation, brightness);
-
-    // Save back to original file
-    match texture {
-        Texture::Tex(tex) => {
-            use ltk_texture::tex::EncodeOptions;
-            let options = EncodeOptions::new(tex.format).with_mipmaps();
-            let new_tex = ltk_texture::Tex::encode_rgba_image(&rgba_img, options)
-                .map_err(|e| format!("Failed to encode TEX: {:?}", e))?;
-            
-            let mut output = fs::File::create(&path_buf).map_err(|e| format!("Failed to create output file: {}", e))?;
-            new_tex.write(&mut output).map_err(|e| format!("Failed to write TEX: {}", e))?;
-        }
-        Texture::Dds(mut _dds) => {
-            // Re-parse with ddsfile to get header info and encode with image_dds
-            let mut cursor = Cursor::new(&data);
-            let dds = ddsfile::Dds::read(&mut cursor).map_err(|e| format!("Failed to parse DDS: {}", e))?;
-            
-            // Try to match format
-            let format = if let Some(fourcc) = dds.header.spf.fourcc {
-                if fourcc.0 == u32::from_le_bytes(*b"DXT1") {
-                    image_dds::ImageFormat::BC1RgbaUnorm
-                } else {
-                    // DXT5 and other formats default to BC3
-                    image_dds::ImageFormat::BC3RgbaUnorm
-                }
-            } else {
-                image_dds::ImageFormat::Bgra8Unorm
-            };
-
-            let new_dds = image_dds::dds_from_image(
-                &rgba_img,
-                format,
-                image_dds::Quality::Normal,
-                image_dds::Mipmaps::GeneratedAutomatic,
-            ).map_err(|e| format!("Failed to encode DDS: {:?}", e))?;
-
-            let mut output = fs::File::create(&path_buf).map_err(|e| format!("Failed to create output file: {}", e))?;
-            new_dds.write(&mut output).map_err(|e| format!("Failed to write DDS: {}", e))?;
-        }
+        return Err(CommandError::new("error.file_not_found", [("path", path.to_string())]));
     }
 
-    Ok(())
+    tokio::task::spawn_blocking(move || recolor_texture_file(&path_buf, &shift))
+        .await?
+        .map_err(CommandError::from)
 }
 
 /// Recolor all texture files in a folder recursively
@@ -480,10 +628,10 @@ pub async fn recolor_folder(
     saturation: f32,
     brightness: f32,
     skip_distortion: Option<bool>,
-) -> Result<RecolorFolderResult, String> {
+) -> Result<RecolorFolderResult, CommandError> {
     let root = PathBuf::from(&path);
     if !root.exists() || !root.is_dir() {
-        return Err("Invalid folder path".into());
+        return Err(CommandError::new("error.folder_not_found", [("path", path.clone())]));
     }
 
     let should_skip_distortion = skip_distortion.unwrap_or(true);
@@ -523,7 +671,7 @@ pub async fn colorize_image(
     path: String,
     target_hue: f32,
     preserve_saturation: bool,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     colorize_single_file(&path, target_hue, preserve_saturation).await
 }
 
@@ -532,80 +680,8 @@ async fn colorize_single_file(
     path: &str,
     target_hue: f32,
     preserve_saturation: bool,
-) -> Result<(), String> {
-    let path_buf = PathBuf::from(path);
-    if !path_buf.exists() {
-        return Err(format!("File not found: {}", path));
-    }
-
-    let data = fs::read(&path_buf).map_err(|e| format!("Failed to read file: {}", e))?;
-    if data.len() < 4 {
-        return Err("File too small".into());
-    }
-
-    let is_tex = &data[0..4] == b"TEX\0";
-    let is_dds = &data[0..4] == b"DDS ";
-
-    if !is_tex && !is_dds {
-        return Err("Not a supported texture format (DDS or TEX)".into());
-    }
-
-    // Decode using ltk_texture
-    let mut cursor = Cursor::new(&data);
-    let texture = Texture::from_reader(&mut cursor)
-        .map_err(|e| format!("Failed to parse texture: {:?}", e))?;
-
-    // Decode top mipmap
-    let surface = texture.decode_mipmap(0)
-        .map_err(|e| format!("Failed to decode mipmap: {:?}", e))?;
-    
-    let mut rgba_img = surface.into_rgba_image()
-        .map_err(|e| format!("Failed to get RGBA image: {:?}", e))?;
-
-    // Apply colorize transform
-    colorize_image_impl(&mut rgba_img, target_hue, preserve_saturation);
-
-    // Save back to original file
-    match texture {
-        Texture::Tex(tex) => {
-            use ltk_texture::tex::EncodeOptions;
-            let options = EncodeOptions::new(tex.format).with_mipmaps();
-            let new_tex = ltk_texture::Tex::encode_rgba_image(&rgba_img, options)
-                .map_err(|e| format!("Failed to encode TEX: {:?}", e))?;
-            
-            let mut output = fs::File::create(&path_buf).map_err(|e| format!("Failed to create output file: {}", e))?;
-            new_tex.write(&mut output).map_err(|e| format!("Failed to write TEX: {}", e))?;
-        }
-        Texture::Dds(mut _dds) => {
-            // Re-parse with ddsfile to get header info and encode with image_dds
-            let mut cursor = Cursor::new(&data);
-            let dds = ddsfile::Dds::read(&mut cursor).map_err(|e| format!("Failed to parse DDS: {}", e))?;
-            
-            // Try to match format
-            let format = if let Some(fourcc) = dds.header.spf.fourcc {
-                if fourcc.0 == u32::from_le_bytes(*b"DXT1") {
-                    image_dds::ImageFormat::BC1RgbaUnorm
-                } else {
-                    // DXT5 and other formats default to BC3
-                    image_dds::ImageFormat::BC3RgbaUnorm
-                }
-            } else {
-                image_dds::ImageFormat::Bgra8Unorm
-            };
-
-            let new_dds = image_dds::dds_from_image(
-                &rgba_img,
-                format,
-                image_dds::Quality::Normal,
-                image_dds::Mipmaps::GeneratedAutomatic,
-            ).map_err(|e| format!("Failed to encode DDS: {:?}", e))?;
-
-            let mut output = fs::File::create(&path_buf).map_err(|e| format!("Failed to create output file: {}", e))?;
-            new_dds.write(&mut output).map_err(|e| format!("Failed to write DDS: {}", e))?;
-        }
-    }
-
-    Ok(())
+) -> Result<(), CommandError> {
+    shift_single_file(path, ColorShift::Tint { hue: target_hue, preserve_saturation }).await
 }
 
 /// Colorize all texture files in a folder recursively
@@ -615,10 +691,10 @@ pub async fn colorize_folder(
     target_hue: f32,
     preserve_saturation: bool,
     skip_distortion: Option<bool>,
-) -> Result<RecolorFolderResult, String> {
+) -> Result<RecolorFolderResult, CommandError> {
     let root = PathBuf::from(&path);
     if !root.exists() || !root.is_dir() {
-        return Err("Invalid folder path".into());
+        return Err(CommandError::new("error.folder_not_found", [("path", path.clone())]));
     }
 
     let should_skip_distortion = skip_distortion.unwrap_or(true);
@@ -1,51 +1,229 @@
 use crate::core::hash::{download_hashes as core_download_hashes, DownloadStats};
+use crate::core::hash::audit::{audit_hash_update, HashResolutionReport};
+use crate::core::hash::custom::{self, CustomHashResult, HashSource};
+use crate::core::hash::unknown::{self, UnknownHashReport};
 use crate::core::hash::downloader::get_ritoshark_hash_dir;
+use crate::core::hash::scheduler::{self, HashUpdateSchedule};
+use crate::core::hash::{parse_hash_value, HashVariant, Hashtable};
 use crate::state::HashtableState;
+use crate::error::CommandError;
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, State};
+use ts_rs::TS;
+
+/// The RitoShark hash directory shared with other tools
+fn hash_dir() -> Result<PathBuf, CommandError> {
+    get_ritoshark_hash_dir().map_err(|e| CommandError::new("error.hash_dir", [("detail", e.to_string())]))
+}
 
 /// Status information about the loaded hashtable
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct HashStatus {
     pub loaded_count: usize,
+    /// xxh64 WAD path hashes
+    pub path_count: usize,
+    /// FNV-1a BIN entry/field/type name hashes
+    pub bin_name_count: usize,
     pub last_updated: Option<String>,
 }
 
+/// Result of looking up a single hash
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HashLookup {
+    /// The hash in canonical hex form (8 digits for FNV, 16 for xxh64)
+    pub hash: String,
+    pub variant: HashVariant,
+    /// True when the variant was detected from the input rather than given
+    pub detected: bool,
+    /// The name or path, if the hash is known
+    pub path: Option<String>,
+}
+
+/// Hashes of a name or path a mod author typed in
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HashedString {
+    /// xxh64 of the lowercased path, as WAD chunks are keyed (16 hex digits)
+    pub xxh64: String,
+    /// FNV-1a of the lowercased name, as BIN entries and fields are keyed (8 hex digits)
+    pub fnv1a32: String,
+    /// True when a loaded hash file already lists the path
+    pub known_path: bool,
+    /// True when a loaded hash file already lists the BIN name
+    pub known_bin_name: bool,
+}
+
+/// Event emitted with a `HashResolutionReport` when a hash update lets
+/// previously unknown files resolve
+pub const HASH_RESOLUTION_EVENT: &str = "hash-resolution-improved";
+
 /// Downloads hash files from CommunityDragon repository
 ///
+/// When WADs or projects are given and new hash files were downloaded, they
+/// are audited against the updated hashes afterwards and a
+/// `hash-resolution-improved` event is emitted if any unknown files now resolve.
+///
 /// # Arguments
 /// * `force` - If true, downloads all files regardless of age
+/// * `wad_paths` - WADs currently open in the explorer
+/// * `project_paths` - Open projects
 ///
 /// # Returns
-/// * `Result<DownloadStats, String>` - Statistics about the download operation
+/// * `Result<DownloadStats, CommandError>` - Statistics about the download operation
 #[tauri::command]
-pub async fn download_hashes(force: bool) -> Result<DownloadStats, String> {
+pub async fn download_hashes(
+    force: bool,
+    wad_paths: Option<Vec<String>>,
+    project_paths: Option<Vec<String>>,
+    app: AppHandle,
+    state: State<'_, HashtableState>,
+) -> Result<DownloadStats, CommandError> {
     // Get the RitoShark hash directory
-    let hash_dir = get_ritoshark_hash_dir()
-        .map_err(|e| format!("Failed to get hash directory: {}", e))?;
+    let hash_dir = hash_dir()?;
+
+    let wad_paths = wad_paths.unwrap_or_default();
+    let project_paths = project_paths.unwrap_or_default();
+    let audit = !wad_paths.is_empty() || !project_paths.is_empty();
+
+    // The audit compares against the hashes in use before the download, so
+    // make sure they are loaded while they're still the old ones
+    if audit {
+        let state = state.inner().clone();
+        tokio::task::spawn_blocking(move || state.get_hashtable())
+            .await?;
+    }
     
     // Download hashes to the directory
     let stats = core_download_hashes(&hash_dir, force)
         .await
-        .map_err(|e| format!("Failed to download hashes: {}", e))?;
+        .map_err(|e| CommandError::new("error.hash_download", [("detail", e.to_string())]))?;
+
+    if audit && stats.downloaded > 0 {
+        match rescan_sources(state.inner().clone(), hash_dir, wad_paths, project_paths).await {
+            Ok(report) => emit_report(&app, &report),
+            Err(e) => tracing::warn!("Hash resolution audit failed: {}", e),
+        }
+    }
     
     Ok(stats)
 }
 
+/// Re-reads the hash files on disk and reports which unknown files in the
+/// given WADs and projects the updated hashes resolve
+///
+/// The freshly read hashes replace the loaded hashtable, so the WAD explorer
+/// and later extractions use them without restarting. Emits
+/// `hash-resolution-improved` when anything new resolves.
+///
+/// # Arguments
+/// * `wad_paths` - WADs to audit
+/// * `project_paths` - Projects to audit for hash-named files
+///
+/// # Returns
+/// * `Result<HashResolutionReport, CommandError>` - Per-source improvements
+#[tauri::command]
+pub async fn rescan_after_hash_update(
+    wad_paths: Vec<String>,
+    project_paths: Vec<String>,
+    app: AppHandle,
+    state: State<'_, HashtableState>,
+) -> Result<HashResolutionReport, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    let report = rescan_sources(state.inner().clone(), hash_dir, wad_paths, project_paths).await?;
+    emit_report(&app, &report);
+    Ok(report)
+}
+
+/// Loads the hash files from disk, audits the sources against the currently
+/// loaded hashtable, then swaps the new hashtable in
+async fn rescan_sources(
+    state: HashtableState,
+    hash_dir: PathBuf,
+    wad_paths: Vec<String>,
+    project_paths: Vec<String>,
+) -> Result<HashResolutionReport, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        state.set_hash_dir(hash_dir.clone());
+        let after = Hashtable::from_directory(&hash_dir)?;
+        // Nothing was shown with older hashes if none were loaded yet, so
+        // WADs have nothing to improve on; projects are audited either way
+        let before = state.loaded();
+        let before = before.as_deref().unwrap_or(&after);
+
+        let report = audit_hash_update(&wad_paths, &project_paths, before, &after);
+        tracing::info!(
+            "Hash audit: {} newly resolved across {} of {} sources",
+            report.total_resolved(),
+            report.improved.len(),
+            report.scanned
+        );
+
+        state.replace(after);
+        Ok::<_, crate::error::Error>(report)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+fn emit_report(app: &AppHandle, report: &HashResolutionReport) {
+    if !report.improved.is_empty() {
+        let _ = app.emit(HASH_RESOLUTION_EVENT, report);
+    }
+}
+
+/// Re-downloads all hash files now, bypassing the update schedule
+///
+/// # Returns
+/// * `Result<Option<DownloadStats>, CommandError>` - Download statistics, or None if
+///   an update was already running
+#[tauri::command]
+pub async fn force_hash_update() -> Result<Option<DownloadStats>, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    scheduler::force_hash_update(&hash_dir)
+        .await
+        .map_err(|e| CommandError::new("error.hash_update", [("detail", e.to_string())]))
+}
+
+/// Returns the automatic hash update schedule
+#[tauri::command]
+pub async fn get_hash_update_schedule() -> Result<HashUpdateSchedule, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    Ok(scheduler::load_schedule(&hash_dir))
+}
+
+/// Sets how often the app checks for hash updates on launch
+///
+/// # Arguments
+/// * `hours` - Minimum hours between automatic checks
+///
+/// # Returns
+/// * `Result<HashUpdateSchedule, CommandError>` - The updated schedule
+#[tauri::command]
+pub async fn set_hash_update_interval(hours: u64) -> Result<HashUpdateSchedule, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    scheduler::set_update_interval(&hash_dir, hours).map_err(CommandError::from)
+}
+
 /// Returns information about the currently loaded hashtable
 ///
 /// # Arguments
 /// * `state` - The managed HashtableState
 ///
 /// # Returns
-/// * `Result<HashStatus, String>` - Status information about the hashtable
+/// * `Result<HashStatus, CommandError>` - Status information about the hashtable
 #[tauri::command]
-pub async fn get_hash_status(state: State<'_, HashtableState>) -> Result<HashStatus, String> {
+pub async fn get_hash_status(state: State<'_, HashtableState>) -> Result<HashStatus, CommandError> {
     let loaded_count = state.len();
     
     // Try to get last modified time of the hash directory
-    let hash_dir = get_ritoshark_hash_dir()
-        .map_err(|e| format!("Failed to get hash directory: {}", e))?;
+    let hash_dir = hash_dir()?;
     
     let last_updated = if hash_dir.exists() {
         std::fs::metadata(&hash_dir)
@@ -69,22 +247,231 @@ pub async fn get_hash_status(state: State<'_, HashtableState>) -> Result<HashSta
     
     Ok(HashStatus {
         loaded_count,
+        path_count: state.count(HashVariant::Xxh64),
+        bin_name_count: state.count(HashVariant::Fnv1a32),
         last_updated,
     })
 }
 
+/// Looks up a hash pasted from another tool
+///
+/// # Arguments
+/// * `hash` - Hash value (0x-prefixed hex, plain hex, or decimal)
+/// * `variant` - Hash function the value came from; detected from the digit
+///   count when omitted
+///
+/// # Returns
+/// * `Result<HashLookup, CommandError>` - The canonical hash, its variant and the resolved path
+#[tauri::command]
+pub async fn lookup_hash(
+    hash: String,
+    variant: Option<HashVariant>,
+    state: State<'_, HashtableState>,
+) -> Result<HashLookup, CommandError> {
+    let hash_str = hash.trim();
+    let value = parse_hash_value(hash_str)
+        .map_err(|e| CommandError::new("error.hash_value_invalid", [("hash", hash_str.to_string()), ("detail", e.to_string())]))?;
+
+    let detected = variant.is_none();
+    let variant = match variant.or_else(|| HashVariant::detect(hash_str)) {
+        Some(variant) => variant,
+        None => {
+            return Err(CommandError::new("error.hash_variant_unknown", [("hash", hash_str.to_string())]))
+        }
+    };
+    if variant == HashVariant::Fnv1a32 && value > u32::MAX as u64 {
+        return Err(CommandError::new("error.hash_too_wide", [("hash", hash_str.to_string())]));
+    }
+
+    let path = state
+        .get_hashtable()
+        .and_then(|hashtable| hashtable.lookup(value, variant).map(str::to_string));
+
+    Ok(HashLookup {
+        hash: variant.format(value),
+        variant,
+        detected,
+        path,
+    })
+}
+
+/// Computes the WAD path and BIN name hashes of a string
+///
+/// Paths are normalized to forward slashes before hashing, as WAD chunk
+/// paths are. Both hashes ignore case.
+///
+/// # Arguments
+/// * `value` - Asset path or BIN name
+///
+/// # Returns
+/// * `Result<HashedString, CommandError>` - Both hashes and whether each is already known
+#[tauri::command]
+pub async fn hash_string(
+    value: String,
+    state: State<'_, HashtableState>,
+) -> Result<HashedString, CommandError> {
+    let value = value.trim().replace('\\', "/");
+    if value.is_empty() {
+        return Err(CommandError::plain("error.nothing_to_hash"));
+    }
+
+    let state = state.inner().clone();
+    let hashed = tokio::task::spawn_blocking(move || {
+        let xxh64 = HashVariant::Xxh64.hash(&value);
+        let fnv1a32 = HashVariant::Fnv1a32.hash(&value);
+        let hashtable = state.get_hashtable();
+        let known_path = hashtable
+            .as_deref()
+            .and_then(|hashtable| hashtable.lookup_path(&value))
+            .is_some();
+        let known_bin_name = hashtable
+            .as_deref()
+            .is_some_and(|hashtable| hashtable.lookup(fnv1a32, HashVariant::Fnv1a32).is_some());
+
+        HashedString {
+            xxh64: HashVariant::Xxh64.format(xxh64),
+            fnv1a32: HashVariant::Fnv1a32.format(fnv1a32),
+            known_path,
+            known_bin_name,
+        }
+    })
+    .await?;
+    Ok(hashed)
+}
+
+/// Adds hashes to the user hash list
+///
+/// User lists take priority over the CommunityDragon lists and the loaded
+/// hashtable picks the new names up right away.
+///
+/// # Arguments
+/// * `entries` - `<hash> <name>` lines, or bare names to hash (names with a
+///   `/` as WAD paths, others as BIN names)
+///
+/// # Returns
+/// * `Result<CustomHashResult, CommandError>` - How many were added and the updated list
+#[tauri::command]
+pub async fn add_custom_hashes(
+    entries: Vec<String>,
+    state: State<'_, HashtableState>,
+) -> Result<CustomHashResult, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let result = custom::add_custom_hashes(&hash_dir, &entries)?;
+        if result.added > 0 {
+            refresh_loaded(&state, hash_dir)?;
+        }
+        Ok::<_, crate::error::Error>(result)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Copies a hash list file into the hash directory as a user list
+///
+/// # Arguments
+/// * `path` - Hash file in CDragon `<hash> <name>` format
+///
+/// # Returns
+/// * `Result<HashSource, CommandError>` - The imported list
+#[tauri::command]
+pub async fn import_hash_file(
+    path: String,
+    state: State<'_, HashtableState>,
+) -> Result<HashSource, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let source = custom::import_hash_file(&hash_dir, &PathBuf::from(path))?;
+        refresh_loaded(&state, hash_dir)?;
+        Ok::<_, crate::error::Error>(source)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Deletes a user hash list and drops its names from the loaded hashtable
+///
+/// # Arguments
+/// * `name` - File name of the list, as returned by `list_hash_sources`
+#[tauri::command]
+pub async fn remove_hash_source(
+    name: String,
+    state: State<'_, HashtableState>,
+) -> Result<(), CommandError> {
+    let hash_dir = hash_dir()?;
+
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        custom::remove_hash_source(&hash_dir, &name)?;
+        refresh_loaded(&state, hash_dir)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Lists the hash files in the hash directory, user lists last
+#[tauri::command]
+pub async fn list_hash_sources() -> Result<Vec<HashSource>, CommandError> {
+    let hash_dir = hash_dir()?;
+
+    custom::list_hash_sources(&hash_dir).map_err(CommandError::from)
+}
+
+/// Re-reads the hash files if a hashtable is loaded; an unloaded one picks
+/// the change up when it's first loaded
+fn refresh_loaded(state: &HashtableState, hash_dir: PathBuf) -> crate::error::Result<()> {
+    if state.loaded().is_some() {
+        state.replace(Hashtable::from_directory(&hash_dir)?);
+    }
+    state.set_hash_dir(hash_dir);
+    Ok(())
+}
+
+/// Lists the hashes of a WAD or project the loaded hashtable can't resolve
+///
+/// # Arguments
+/// * `path` - A WAD file, or a project folder with hash-named files
+/// * `guess` - Also hash common champion asset paths and name matching chunks
+/// * `champions` - Champions to guess paths for; detected when omitted
+///
+/// # Returns
+/// * `Result<UnknownHashReport, CommandError>` - Unknown hashes with their kind, size and guesses
+#[tauri::command]
+pub async fn report_unknown_hashes(
+    path: String,
+    guess: Option<bool>,
+    champions: Option<Vec<String>>,
+    state: State<'_, HashtableState>,
+) -> Result<UnknownHashReport, CommandError> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let hashtable = state.get_hashtable().unwrap_or_else(|| std::sync::Arc::new(Hashtable::empty()));
+        unknown::report_unknown_hashes(
+            &PathBuf::from(path),
+            &hashtable,
+            guess.unwrap_or(false),
+            &champions.unwrap_or_default(),
+        )
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
 /// Reloads the hashtable from disk
 ///
 /// # Arguments
 /// * `state` - The managed HashtableState
 ///
 /// # Returns
-/// * `Result<(), String>` - Ok if reload succeeded, error message otherwise
+/// * `Result<(), CommandError>` - Ok if reload succeeded, error message otherwise
 #[tauri::command]
-pub async fn reload_hashes(state: State<'_, HashtableState>) -> Result<(), String> {
+pub async fn reload_hashes(state: State<'_, HashtableState>) -> Result<(), CommandError> {
     // Get the hash directory
-    let hash_dir = get_ritoshark_hash_dir()
-        .map_err(|e| format!("Failed to get hash directory: {}", e))?;
+    let hash_dir = hash_dir()?;
     
     // Ensure the directory is set (this doesn't load, just sets the path)
     state.set_hash_dir(hash_dir);
@@ -96,7 +483,7 @@ pub async fn reload_hashes(state: State<'_, HashtableState>) -> Result<(), Strin
         tracing::info!("Hashtable is loaded with {} entries", state.len());
         Ok(())
     } else {
-        Err("Failed to load hashtable".to_string())
+        Err(CommandError::plain("error.hashtable_unavailable"))
     }
 }
 
@@ -108,6 +495,8 @@ mod tests {
     fn test_hash_status_serialization() {
         let status = HashStatus {
             loaded_count: 100,
+            path_count: 60,
+            bin_name_count: 40,
             last_updated: Some("2024-01-01T00:00:00Z".to_string()),
         };
 
@@ -2,25 +2,42 @@
 //!
 //! These commands expose league detection functionality to the frontend.
 
-use crate::core::league::{detect_league_installation, validate_league_path, LeagueInstallation};
+use crate::core::league::{
+    detect_league_installation, find_league_installs, validate_league_path, InstallCandidate, LeagueInstallation,
+};
+use crate::error::CommandError;
 
 /// Automatically detect League of Legends installation
 ///
-/// Searches Windows registry and common installation paths.
+/// Returns the best valid install found by `detect_league_installs`.
 ///
 /// # Returns
 /// * `Ok(LeagueInstallation)` - Detected installation info
-/// * `Err(String)` - Error message if detection failed
+/// * `Err(CommandError)` - Error message if detection failed
 #[tauri::command]
-pub async fn detect_league() -> Result<LeagueInstallation, String> {
+pub async fn detect_league() -> Result<LeagueInstallation, CommandError> {
     tracing::info!("Frontend requested League detection");
     
     tokio::task::spawn_blocking(move || {
         detect_league_installation()
     })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
-    .map_err(|e| e.to_string())
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// List every League install that can be found, best first
+///
+/// Checks the Riot Client's install list, a running game, the Windows
+/// registry, and the usual Riot, Garena and Tencent folders on every drive.
+///
+/// # Returns
+/// * `Ok(Vec<InstallCandidate>)` - Candidates with their source, game version and validity
+/// * `Err(CommandError)` - Error message if the search couldn't run
+#[tauri::command]
+pub async fn detect_league_installs() -> Result<Vec<InstallCandidate>, CommandError> {
+    tracing::info!("Frontend requested the list of League installs");
+
+    Ok(tokio::task::spawn_blocking(find_league_installs).await?)
 }
 
 /// Validate a manually specified League path
@@ -30,15 +47,14 @@ pub async fn detect_league() -> Result<LeagueInstallation, String> {
 ///
 /// # Returns
 /// * `Ok(LeagueInstallation)` - Validated installation info
-/// * `Err(String)` - Error message if validation failed
+/// * `Err(CommandError)` - Error message if validation failed
 #[tauri::command]
-pub async fn validate_league(path: String) -> Result<LeagueInstallation, String> {
+pub async fn validate_league(path: String) -> Result<LeagueInstallation, CommandError> {
     tracing::info!("Frontend requested validation for path: {}", path);
     
     tokio::task::spawn_blocking(move || {
         validate_league_path(&path)
     })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
-    .map_err(|e| e.to_string())
+    .await?
+    .map_err(CommandError::from)
 }
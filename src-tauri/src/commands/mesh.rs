@@ -7,21 +7,24 @@ use std::collections::HashMap;
 
 use crate::core::mesh::skn::{parse_skn_file, SknMeshData};
 use crate::core::mesh::scb::{parse_scb_file, ScbMeshData};
-use crate::core::mesh::texture::{find_skin_bin, extract_texture_mapping, lookup_material_texture_by_name, MaterialProperties};
-use crate::commands::file::decode_dds_to_png;
+use crate::core::mesh::shader::{build_shader_catalog, ShaderCatalog};
+use crate::core::mesh::texture::{find_skin_bin, extract_texture_mapping, MaterialProperties};
+use crate::commands::file::decode_texture_to_png;
+use crate::state::{CancelToken, RequestState};
+use tauri::State;
 
 /// Read and parse an SCB (Static Mesh Binary) file
 /// 
 /// Returns mesh data including vertices, normals, UVs, indices, and materials
 /// for 3D rendering in the frontend.
 #[tauri::command]
-pub async fn read_scb_mesh(path: String) -> Result<ScbMeshData, String> {
+pub async fn read_scb_mesh(path: String) -> Result<ScbMeshData, CommandError> {
     tracing::debug!("Reading SCB mesh: {}", path);
     
     parse_scb_file(&path)
         .map_err(|e| {
             tracing::error!("Failed to parse SCB file {}: {}", path, e);
-            format!("Failed to parse SCB file: {}", e)
+            CommandError::new("error.scb_parse", [("path", path.clone()), ("detail", e.to_string())])
         })
 }
 
@@ -29,23 +32,37 @@ pub async fn read_scb_mesh(path: String) -> Result<ScbMeshData, String> {
 /// 
 /// Returns mesh data including vertices, normals, UVs, indices, materials,
 /// and decoded textures for 3D rendering in the frontend.
+///
+/// Passing a `request_id` lets `cancel_request` abandon the load, including
+/// texture decodes still in flight.
 #[tauri::command]
-pub async fn read_skn_mesh(path: String) -> Result<SknMeshData, String> {
+pub async fn read_skn_mesh(
+    path: String,
+    request_id: Option<String>,
+    requests: State<'_, RequestState>,
+) -> Result<SknMeshData, CommandError> {
+    let request = requests.begin(request_id);
+    load_skn_mesh(path, request.token()).await
+}
+
+async fn load_skn_mesh(path: String, cancel: CancelToken) -> Result<SknMeshData, CommandError> {
     tracing::info!("Reading SKN mesh: {}", path);
     
     let skn_path = Path::new(&path);
+    cancel.check()?;
     
     // Parse the SKN file
     let mut mesh_data = parse_skn_file(&path)
         .map_err(|e| {
             tracing::error!("Failed to parse SKN file {}: {}", path, e);
-            format!("Failed to parse SKN file: {}", e)
+            CommandError::new("error.skn_parse", [("path", path.clone()), ("detail", e.to_string())])
         })?;
     
     tracing::info!("SKN parsed successfully. Materials: {:?}", 
         mesh_data.materials.iter().map(|m| &m.name).collect::<Vec<_>>());
     
     // Try to find and parse skin0.bin for texture mappings
+    cancel.check()?;
     if let Some(bin_path) = find_skin_bin(skn_path) {
         tracing::info!("Found skin0.bin: {}", bin_path.display());
         
@@ -101,13 +118,13 @@ pub async fn read_skn_mesh(path: String) -> Result<SknMeshData, String> {
                         // Strategy 6: Search for StaticMaterialDef matching this material name
                         .or_else(|| {
                             tracing::debug!("Trying StaticMaterialDef lookup for: {}", material_name);
-                            lookup_material_texture_by_name(&texture_mapping.ritobin_content, material_name)
+                            texture_mapping.lookup_material(material_name)
                         })
                         // Strategy 7: Try StaticMaterialDef lookup with stripped name
                         .or_else(|| {
                             material_name.strip_prefix("mesh_").and_then(|stripped| {
                                 tracing::debug!("Trying StaticMaterialDef lookup for stripped name: {}", stripped);
-                                lookup_material_texture_by_name(&texture_mapping.ritobin_content, stripped)
+                                texture_mapping.lookup_material(stripped)
                             })
                         })
                         // Strategy 8: Fallback to default texture (no UV transforms)
@@ -153,8 +170,9 @@ pub async fn read_skn_mesh(path: String) -> Result<SknMeshData, String> {
                 // Load all textures in parallel
                 let load_futures: Vec<_> = texture_tasks.into_iter()
                     .map(|(path_key, resolved_path, _)| {
+                        let cancel = cancel.clone();
                         async move {
-                            match decode_dds_to_png(resolved_path.to_string_lossy().to_string()).await {
+                            match decode_texture_to_png(resolved_path.to_string_lossy().to_string(), cancel).await {
                                 Ok(decoded) => Some((path_key, decoded.data)),
                                 Err(e) => {
                                     tracing::warn!("Failed to decode texture {}: {}", resolved_path.display(), e);
@@ -166,6 +184,7 @@ pub async fn read_skn_mesh(path: String) -> Result<SknMeshData, String> {
                     .collect();
                 
                 let results = futures::future::join_all(load_futures).await;
+                cancel.check()?;
                 
                 // Build decoded textures lookup
                 let mut decoded_textures: HashMap<String, String> = HashMap::new();
@@ -275,7 +294,7 @@ fn resolve_texture_path(base_dir: &Path, texture_path: &str) -> Option<std::path
 pub async fn resolve_asset_path(
     asset_path: String,
     bin_path: String
-) -> Result<String, String> {
+) -> Result<String, CommandError> {
     tracing::debug!("Resolving asset path: {} relative to {}", asset_path, bin_path);
     
     let bin_path = std::path::Path::new(&bin_path);
@@ -385,7 +404,10 @@ pub async fn resolve_asset_path(
         }
     }
     
-    Err(format!("Asset not found: {} (searched from {})", asset_path, content_root.display()))
+    Err(CommandError::new(
+        "error.asset_not_found",
+        [("path", asset_path.to_string()), ("root", content_root.display().to_string())],
+    ))
 }
 
 use crate::core::mesh::skl::{parse_skl_file, SklData};
@@ -395,68 +417,100 @@ use crate::core::mesh::skl::{parse_skl_file, SklData};
 /// Returns skeleton data including bone hierarchy with names, parent IDs,
 /// and local transforms for visualization and animation.
 #[tauri::command]
-pub async fn read_skl_skeleton(path: String) -> Result<SklData, String> {
+pub async fn read_skl_skeleton(path: String) -> Result<SklData, CommandError> {
     tracing::debug!("Reading SKL skeleton: {}", path);
     
     parse_skl_file(&path)
         .map_err(|e| {
             tracing::error!("Failed to parse SKL file {}: {}", path, e);
-            format!("Failed to parse SKL file: {}", e)
+            CommandError::new("error.skl_parse", [("path", path.clone()), ("detail", e.to_string())])
         })
 }
 
 use crate::core::mesh::animation::{
     find_animation_bin, extract_animation_list, parse_animation_file, 
     resolve_animation_path, evaluate_animation_at,
-    AnimationList, AnimationData, AnimationPose,
+    AnimationList, AnimationData, AnimationPose, BakedAnimation,
 };
+use crate::core::mesh::anim_events::{self, AnimationAudioTimeline};
+use crate::core::mesh::animation::{self, load_animation_asset};
+use crate::core::mesh::render::{render_turntable, MaterialTexture, Posing};
+use crate::core::mesh::clip_replace::{self, ClipReplacement};
+use crate::core::mesh::retarget::{self, RetargetReport};
+use crate::error::{CommandError, Error};
 
 /// Get list of available animations for a model
 /// 
 /// Parses the animation BIN file to extract AtomicClipData animation paths
 #[tauri::command]
-pub async fn read_animation_list(skn_path: String) -> Result<AnimationList, String> {
+pub async fn read_animation_list(skn_path: String) -> Result<AnimationList, CommandError> {
     tracing::debug!("Reading animation list for: {}", skn_path);
     
     let skn_path = std::path::Path::new(&skn_path);
     
     // Find animation BIN file
-    let bin_path = find_animation_bin(skn_path)
-        .ok_or_else(|| "Animation BIN file not found".to_string())?;
+    let bin_path = find_animation_bin(skn_path).ok_or_else(|| {
+        CommandError::new("error.animation_bin_not_found", [("path", skn_path.display().to_string())])
+    })?;
     
     tracing::debug!("Found animation BIN: {}", bin_path.display());
     
     extract_animation_list(&bin_path)
         .map_err(|e| {
             tracing::error!("Failed to extract animation list: {}", e);
-            format!("Failed to extract animation list: {}", e)
+            CommandError::new("error.animation_list", [("path", bin_path.display().to_string()), ("detail", e.to_string())])
         })
 }
 
-/// Read and parse an ANM animation file
+/// Get the sound/particle event timeline of an animation clip
+///
+/// Merges the clip's event track with Wwise event IDs so the previewer can
+/// show when sounds fire.
+///
+/// # Arguments
+/// * `skn_path` - Path to the model, used to locate the animation BIN
+/// * `clip` - Clip name, .anm file stem, or .anm path
 #[tauri::command]
-pub async fn read_animation(path: String, base_path: Option<String>) -> Result<AnimationData, String> {
-    tracing::debug!("Reading animation: {}", path);
-    
-    // Try to resolve the animation path
+pub async fn get_animation_audio_timeline(skn_path: String, clip: String) -> Result<AnimationAudioTimeline, CommandError> {
+    let bin_path = find_animation_bin(Path::new(&skn_path))
+        .ok_or_else(|| CommandError::new("error.animation_bin_not_found", [("path", skn_path.clone())]))?;
+
+    anim_events::get_animation_audio_timeline(&bin_path, &clip)
+        .map_err(|e| CommandError::new("error.animation_timeline", [("clip", clip.clone()), ("detail", e.to_string())]))
+}
+
+/// Finds an ANM file, as referenced from a BIN when `base_path` (a file in
+/// the project) is given, otherwise as a plain path
+fn find_animation_file(path: &str, base_path: Option<String>) -> Result<std::path::PathBuf, CommandError> {
     let resolved_path = if let Some(base) = base_path {
-        let base_dir = std::path::Path::new(&base).parent().unwrap_or(std::path::Path::new("."));
-        resolve_animation_path(base_dir, &path)
+        let base_dir = std::path::Path::new(&base)
+            .parent()
+            .unwrap_or(std::path::Path::new("."));
+        resolve_animation_path(base_dir, path)
     } else {
-        Some(std::path::PathBuf::from(&path))
+        Some(std::path::PathBuf::from(path))
     };
     
     let anim_path = resolved_path
-        .ok_or_else(|| format!("Could not resolve animation path: {}", path))?;
+        .ok_or_else(|| CommandError::new("error.animation_path_unresolved", [("path", path.to_string())]))?;
     
     if !anim_path.exists() {
-        return Err(format!("Animation file not found: {}", anim_path.display()));
+        return Err(CommandError::new("error.animation_not_found", [("path", anim_path.display().to_string())]));
     }
+    Ok(anim_path)
+}
+
+/// Read and parse an ANM animation file
+#[tauri::command]
+pub async fn read_animation(path: String, base_path: Option<String>) -> Result<AnimationData, CommandError> {
+    tracing::debug!("Reading animation: {}", path);
+    
+    let anim_path = find_animation_file(&path, base_path)?;
     
     parse_animation_file(&anim_path)
         .map_err(|e| {
             tracing::error!("Failed to parse animation {}: {}", anim_path.display(), e);
-            format!("Failed to parse animation: {}", e)
+            CommandError::new("error.animation_parse", [("path", anim_path.display().to_string()), ("detail", e.to_string())])
         })
 }
 
@@ -468,29 +522,393 @@ pub async fn evaluate_animation(
     path: String, 
     base_path: Option<String>, 
     time: f32
-) -> Result<AnimationPose, String> {
+) -> Result<AnimationPose, CommandError> {
     tracing::debug!("Evaluating animation at time {}: {}", time, path);
     
-    // Resolve the animation path
-    let resolved_path = if let Some(base) = base_path {
-        let base_dir = std::path::Path::new(&base)
-            .parent()
-            .unwrap_or(std::path::Path::new("."));
-        resolve_animation_path(base_dir, &path)
-    } else {
-        Some(std::path::PathBuf::from(&path))
-    };
-    
-    let anim_path = resolved_path
-        .ok_or_else(|| format!("Could not resolve animation path: {}", path))?;
-    
-    if !anim_path.exists() {
-        return Err(format!("Animation file not found: {}", anim_path.display()));
-    }
+    let anim_path = find_animation_file(&path, base_path)?;
     
     evaluate_animation_at(&anim_path, time)
         .map_err(|e| {
             tracing::error!("Failed to evaluate animation {}: {}", anim_path.display(), e);
-            format!("Failed to evaluate animation: {}", e)
+            CommandError::new("error.animation_evaluate", [("path", anim_path.display().to_string()), ("detail", e.to_string())])
         })
 }
+
+/// Bake an animation to skinning matrices for every frame in one call
+///
+/// Lets the previewer play a clip without a round-trip per frame.
+///
+/// # Arguments
+/// * `path` - .anm path (absolute, or as referenced from the BIN with `base_path`)
+/// * `skl_path` - Skeleton the matrices are computed for; they follow its bone order
+/// * `fps` - Sample rate (default: the animation's own, at most 120)
+/// * `base_path` - A file in the project to resolve BIN-referenced paths from
+///
+/// # Returns
+/// * `Ok(BakedAnimation)` - Column-major 4x4 matrices, joint by joint, frame by frame
+#[tauri::command]
+pub async fn bake_animation(
+    path: String,
+    skl_path: String,
+    fps: Option<f32>,
+    base_path: Option<String>,
+) -> Result<BakedAnimation, CommandError> {
+    tracing::debug!("Baking animation {} against {}", path, skl_path);
+
+    let anim_path = find_animation_file(&path, base_path)?;
+    tokio::task::spawn_blocking(move || {
+        let skeleton = parse_skl_file(&skl_path)
+            .map_err(|e| CommandError::new("error.skl_parse", [("path", skl_path.clone()), ("detail", e.to_string())]))?;
+        let asset = load_animation_asset(&anim_path).map_err(|e| {
+            CommandError::new("error.animation_load", [("path", anim_path.display().to_string()), ("detail", e.to_string())])
+        })?;
+        animation::bake_animation(&asset, &skeleton, fps)
+            .map_err(|e| CommandError::new("error.animation_bake", [("detail", e.to_string())]))
+    })
+    .await?
+}
+
+/// Render a turntable of a model to a sequence of PNG files
+///
+/// Renders offscreen with a software rasterizer, so the images have the same
+/// size and framing every time regardless of the viewport.
+///
+/// # Arguments
+/// * `skn` - Path to the SKN mesh
+/// * `skl` - Skeleton path (defaults to the .skl next to the mesh when animating)
+/// * `animation` - Optional .anm path (absolute or as referenced from the BIN); plays once over the turn
+/// * `frames` - Number of frames in the full turn
+/// * `resolution` - Edge length of each square frame in pixels
+/// * `output_dir` - Directory to write `turntable_000.png`, `turntable_001.png`, ... into
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - Paths of the written frames in order
+#[tauri::command]
+pub async fn render_model_turntable(
+    skn: String,
+    skl: Option<String>,
+    animation: Option<String>,
+    frames: u32,
+    resolution: u32,
+    output_dir: String,
+) -> Result<Vec<String>, CommandError> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    tracing::info!("Rendering {}-frame turntable of {}", frames, skn);
+
+    let output_dir = std::path::PathBuf::from(output_dir);
+    crate::core::league::guard::ensure_outside_league(&output_dir)?;
+
+    // Reuses the preview's material -> texture resolution
+    let mut mesh = load_skn_mesh(skn.clone(), CancelToken::default()).await?;
+    let textures: HashMap<String, MaterialTexture> = std::mem::take(&mut mesh.material_data)
+        .into_iter()
+        .filter_map(|(name, material)| {
+            let png = STANDARD.decode(&material.texture).ok()?;
+            let image = image::load_from_memory(&png).ok()?.to_rgba8();
+            Some((name, MaterialTexture {
+                image,
+                uv_scale: material.uv_scale,
+                uv_offset: material.uv_offset,
+            }))
+        })
+        .collect();
+
+    tokio::task::spawn_blocking(move || {
+        let skn_path = Path::new(&skn);
+        let skl_path = skl.map(std::path::PathBuf::from).or_else(|| {
+            let sibling = skn_path.with_extension("skl");
+            (animation.is_some() && sibling.exists()).then_some(sibling)
+        });
+
+        let skeleton = skl_path
+            .map(|path| {
+                parse_skl_file(&path).map_err(|e| {
+                    CommandError::new("error.skl_parse", [("path", path.display().to_string()), ("detail", e.to_string())])
+                })
+            })
+            .transpose()?;
+        let animation = match animation {
+            Some(anim) => {
+                if skeleton.is_none() {
+                    return Err(CommandError::plain("error.animation_needs_skeleton"));
+                }
+                let base_dir = skn_path.parent().unwrap_or(Path::new("."));
+                let anim_path = resolve_animation_path(base_dir, &anim)
+                    .ok_or_else(|| CommandError::new("error.animation_path_unresolved", [("path", anim.clone())]))?;
+                Some(load_animation_asset(&anim_path).map_err(|e| {
+                    CommandError::new("error.animation_load", [("path", anim_path.display().to_string()), ("detail", e.to_string())])
+                })?)
+            }
+            None => None,
+        };
+        let posing = skeleton.as_ref().map(|skeleton| Posing {
+            skeleton,
+            animation: animation.as_ref(),
+        });
+
+        let images = render_turntable(&mesh, &textures, posing, frames, resolution)
+            .map_err(|e| CommandError::new("error.turntable_render", [("detail", e.to_string())]))?;
+
+        std::fs::create_dir_all(&output_dir).map_err(|e| Error::io_with_path(e, &output_dir))?;
+        images
+            .iter()
+            .enumerate()
+            .map(|(i, image)| {
+                let path = output_dir.join(format!("turntable_{:03}.png", i));
+                image.save(&path).map_err(|e| {
+                    CommandError::new("error.image_write", [("path", path.display().to_string()), ("detail", e.to_string())])
+                })?;
+                Ok(path.to_string_lossy().to_string())
+            })
+            .collect()
+    })
+    .await?
+}
+
+/// Build a catalog of shaders and their sampler/param conventions
+///
+/// Scans StaticMaterialDef blocks in every BIN under the given directory so the
+/// material editor can show labels like "Glow mask (G channel)" for raw names.
+///
+/// # Arguments
+/// * `path` - Directory to scan (usually the project's content folder)
+#[tauri::command]
+pub async fn get_shader_catalog(path: String) -> Result<ShaderCatalog, CommandError> {
+    tracing::info!("Building shader catalog for: {}", path);
+
+    tokio::task::spawn_blocking(move || {
+        build_shader_catalog(Path::new(&path))
+            .map_err(|e| CommandError::new("error.shader_catalog", [("path", path.clone()), ("detail", e.to_string())]))
+    })
+    .await?
+}
+
+/// Replace the .anm used by an animation clip in a project
+///
+/// Copies the new file next to the clip's current animation, points the
+/// clip's `mAnimationFilePath` at it and checks its joints against the
+/// project skeleton.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `clip_name` - Clip name, .anm file stem, or .anm path
+/// * `new_anm_path` - The replacement .anm file
+///
+/// # Returns
+/// * `Ok(ClipReplacement)` - Updated paths and joint compatibility warnings
+#[tauri::command]
+pub async fn replace_animation_clip(
+    project_path: String,
+    clip_name: String,
+    new_anm_path: String,
+) -> Result<ClipReplacement, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let project = crate::core::project::open_project(Path::new(&project_path))?;
+        clip_replace::replace_animation_clip(&project.assets_path(), &clip_name, Path::new(&new_anm_path))
+            .map_err(|e| CommandError::new("error.clip_replace", [("clip", clip_name.clone()), ("detail", e.to_string())]))
+    })
+    .await?
+}
+
+/// Retarget an animation to another skeleton
+///
+/// Joint tracks are matched by name, resampled and written as a new .anm.
+///
+/// # Arguments
+/// * `anm_path` - Animation to retarget
+/// * `source_skl` - Skeleton the animation was made for
+/// * `target_skl` - Skeleton of the replacement mesh
+/// * `output_path` - Where to write the new .anm
+/// * `bone_map` - Target joint name -> source joint name, for joints that were renamed
+///
+/// # Returns
+/// * `Ok(RetargetReport)` - Mapped joints and joints left in bind pose
+#[tauri::command]
+pub async fn retarget_animation(
+    anm_path: String,
+    source_skl: String,
+    target_skl: String,
+    output_path: String,
+    bone_map: Option<HashMap<String, String>>,
+) -> Result<RetargetReport, CommandError> {
+    crate::core::league::guard::ensure_outside_league(&output_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        retarget::retarget_animation(
+            Path::new(&anm_path),
+            Path::new(&source_skl),
+            Path::new(&target_skl),
+            Path::new(&output_path),
+            &bone_map.unwrap_or_default(),
+        )
+        .map_err(|e| CommandError::new("error.animation_retarget", [("path", anm_path.clone()), ("detail", e.to_string())]))
+    })
+    .await?
+}
+
+use crate::core::bin::get_cached_bin_hashes;
+use crate::core::mesh::material::{self, MaterialParams};
+
+/// List the shader params of a StaticMaterialDef
+///
+/// # Arguments
+/// * `bin_path` - BIN file holding the material
+/// * `material` - Material path, its last segment (e.g. "Body_inst"), or hex hash
+///
+/// # Returns
+/// * `Ok(MaterialParams)` - Every `paramValues` entry with its vec4 value
+#[tauri::command]
+pub async fn get_material_params(bin_path: String, material: String) -> Result<MaterialParams, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        material::get_material_params(Path::new(&bin_path), &hashes, &material)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Set a shader param of a StaticMaterialDef and save the BIN
+///
+/// Meant for live tweaking (emissive intensity or color from a slider).
+/// Scalar params take a single value; fewer than four values only replace
+/// the leading components. Params the material doesn't list yet are added.
+///
+/// # Arguments
+/// * `bin_path` - BIN file holding the material
+/// * `material` - Material path, its last segment, or hex hash
+/// * `param` - Param name (e.g. "Emissive_Intensity"), case-insensitive
+/// * `value` - One to four components
+///
+/// # Returns
+/// * `Ok(MaterialParams)` - The material's params after the change
+#[tauri::command]
+pub async fn set_material_param(
+    bin_path: String,
+    material: String,
+    param: String,
+    value: Vec<f32>,
+) -> Result<MaterialParams, CommandError> {
+    crate::core::league::guard::ensure_outside_league(&bin_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        material::set_material_param(Path::new(&bin_path), &hashes, &material, &param, &value)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+use crate::core::mesh::submesh::{self, SkinSubmeshes};
+
+/// Resolves the skin BIN of an SKN, preferring an explicit path
+fn skin_bin_path(skn_path: &str, bin_path: Option<String>) -> Result<std::path::PathBuf, CommandError> {
+    match bin_path {
+        Some(bin_path) => Ok(std::path::PathBuf::from(bin_path)),
+        None => find_skin_bin(Path::new(skn_path))
+            .ok_or_else(|| CommandError::new("error.skin_bin_not_found", [("path", skn_path.to_string())])),
+    }
+}
+
+/// List the submeshes of an SKN with their texture, material and visibility
+///
+/// # Arguments
+/// * `skn_path` - SKN file
+/// * `bin_path` - Skin BIN; found next to the SKN when omitted
+///
+/// # Returns
+/// * `Ok(SkinSubmeshes)` - Default texture/material and one entry per submesh
+#[tauri::command]
+pub async fn get_skin_submeshes(skn_path: String, bin_path: Option<String>) -> Result<SkinSubmeshes, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        let bin_path = skin_bin_path(&skn_path, bin_path)?;
+        let hashes = get_cached_bin_hashes().read();
+        Ok::<_, CommandError>(submesh::get_submeshes(Path::new(&skn_path), &bin_path, &hashes)?)
+    })
+    .await?
+}
+
+/// Set or clear the texture override of a submesh and save the skin BIN
+///
+/// # Arguments
+/// * `skn_path` - SKN file
+/// * `bin_path` - Skin BIN; found next to the SKN when omitted
+/// * `submesh` - Submesh name, case-insensitive
+/// * `texture` - Texture path, or `None` to fall back to the default texture
+///
+/// # Returns
+/// * `Ok(SkinSubmeshes)` - The submeshes after the change
+#[tauri::command]
+pub async fn set_submesh_texture(
+    skn_path: String,
+    bin_path: Option<String>,
+    submesh: String,
+    texture: Option<String>,
+) -> Result<SkinSubmeshes, CommandError> {
+    let bin_path = skin_bin_path(&skn_path, bin_path)?;
+    crate::core::league::guard::ensure_outside_league(&bin_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        submesh::set_submesh_texture(Path::new(&skn_path), &bin_path, &hashes, &submesh, texture.as_deref())
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Show or hide a submesh by default and save the skin BIN
+///
+/// # Arguments
+/// * `skn_path` - SKN file
+/// * `bin_path` - Skin BIN; found next to the SKN when omitted
+/// * `submesh` - Submesh name, case-insensitive
+/// * `hidden` - Whether the submesh (and its shadow) starts hidden
+///
+/// # Returns
+/// * `Ok(SkinSubmeshes)` - The submeshes after the change
+#[tauri::command]
+pub async fn set_submesh_hidden(
+    skn_path: String,
+    bin_path: Option<String>,
+    submesh: String,
+    hidden: bool,
+) -> Result<SkinSubmeshes, CommandError> {
+    let bin_path = skin_bin_path(&skn_path, bin_path)?;
+    crate::core::league::guard::ensure_outside_league(&bin_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        submesh::set_submesh_hidden(Path::new(&skn_path), &bin_path, &hashes, &submesh, hidden)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Rename a submesh in the SKN and in the skin BIN's references to it
+///
+/// # Arguments
+/// * `skn_path` - SKN file
+/// * `bin_path` - Skin BIN; found next to the SKN when omitted
+/// * `old_name` - Current submesh name, case-insensitive
+/// * `new_name` - New name, at most 63 ASCII characters
+///
+/// # Returns
+/// * `Ok(SkinSubmeshes)` - The submeshes after the rename
+#[tauri::command]
+pub async fn rename_submesh(
+    skn_path: String,
+    bin_path: Option<String>,
+    old_name: String,
+    new_name: String,
+) -> Result<SkinSubmeshes, CommandError> {
+    let bin_path = skin_bin_path(&skn_path, bin_path)?;
+    crate::core::league::guard::ensure_outside_league(&skn_path)?;
+    crate::core::league::guard::ensure_outside_league(&bin_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        submesh::rename_submesh(Path::new(&skn_path), &bin_path, &hashes, &old_name, &new_name)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
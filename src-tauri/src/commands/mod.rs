@@ -11,3 +11,24 @@ pub mod export;
 pub mod mesh;
 pub mod checkpoint;
 pub mod updater;
+pub mod store;
+pub mod chroma;
+pub mod onboarding;
+pub mod vfx;
+pub mod audio;
+pub mod bench;
+pub mod deploy;
+pub mod vcs;
+pub mod trash;
+pub mod stringtable;
+
+use crate::error::CommandError;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// The app's data directory, where settings and caches live
+pub(crate) fn app_data_dir(app: &AppHandle) -> Result<PathBuf, CommandError> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| CommandError::new("error.app_data_dir", [("detail", e.to_string())]))
+}
@@ -0,0 +1,59 @@
+//! Tauri commands for the first-run setup flow
+
+use crate::core::hash::get_ritoshark_hash_dir;
+use crate::core::onboarding::{self, OnboardingStatus, OnboardingStep};
+use crate::commands::app_data_dir;
+use crate::error::CommandError;
+use tauri::AppHandle;
+
+/// Returns which setup steps are done and which one to show next
+///
+/// # Returns
+/// * `Result<OnboardingStatus, CommandError>` - Step states and chosen values, or error message
+#[tauri::command]
+pub async fn get_onboarding_status(app: AppHandle) -> Result<OnboardingStatus, CommandError> {
+    let dir = app_data_dir(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        let hash_dir = get_ritoshark_hash_dir().ok();
+        onboarding::status(onboarding::load_settings(&dir), hash_dir.as_deref())
+    })
+    .await
+    .map_err(CommandError::from)
+}
+
+/// Completes or skips a setup step and saves the progress
+///
+/// # Arguments
+/// * `step` - Step to complete
+/// * `value` - League path, creator name or output directory, depending on the step
+/// * `skip` - Skip the step instead of completing it
+///
+/// # Returns
+/// * `Result<OnboardingStatus, CommandError>` - The updated status, or why the step was rejected
+#[tauri::command]
+pub async fn complete_onboarding_step(
+    step: OnboardingStep,
+    value: Option<String>,
+    skip: Option<bool>,
+    app: AppHandle,
+) -> Result<OnboardingStatus, CommandError> {
+    let dir = app_data_dir(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        let hash_dir = get_ritoshark_hash_dir().ok();
+        let mut settings = onboarding::load_settings(&dir);
+        onboarding::complete_step(
+            &mut settings,
+            step,
+            value.as_deref(),
+            skip.unwrap_or(false),
+            hash_dir.as_deref(),
+        )?;
+        onboarding::save_settings(&dir, &settings)?;
+        tracing::info!("Onboarding step {:?} done", step);
+        Ok::<_, crate::error::Error>(onboarding::status(settings, hash_dir.as_deref()))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
@@ -2,19 +2,47 @@
 //!
 //! These commands expose project management functionality to the frontend.
 
+use crate::core::project::compat::{check_project_compatibility as core_check_project_compatibility, ProjectCompatibility};
+use crate::core::project::metadata::{update_project_metadata as core_update_project_metadata, MetadataUpdate, ProjectMetadata};
+use crate::core::project::variables::ProjectVariables;
+use crate::core::project::vanilla::{record_vanilla_manifest, update_project_assets as core_update_project_assets, ProjectUpdateReport};
 use crate::core::project::{
-    create_project as core_create_project,
+    create_target_project as core_create_target_project,
+    find_target_wad,
     open_project as core_open_project,
+    relink_league_path as core_relink_league_path,
     save_project as core_save_project,
-    Project,
+    set_layer_metadata as core_set_layer_metadata,
+    set_project_thumbnail as core_set_project_thumbnail,
+    variables::set_project_variables as core_set_project_variables,
+    variables::set_repath_options as core_set_repath_options,
+    Project, TargetKind,
 };
-use crate::core::repath::{organize_project, OrganizerConfig};
+use crate::core::repath::{organize_project, OrganizeProgress, OrganizerConfig, RepathOptions};
 use crate::core::bin::{classify_bin, BinCategory};
-use crate::core::wad::extractor::{find_champion_wad, extract_skin_assets};
-use crate::state::HashtableState;
-use league_toolkit::wad::Wad;
+use crate::core::league::detect_game_version;
+use crate::core::league::guard::ensure_outside_league;
+use crate::core::bin::closure::{
+    project_dependency_tree, resolve_project_closure, BinDependencyNode, LinkedClosureReport,
+};
+use crate::core::hash::get_ritoshark_hash_dir;
+use crate::core::import::import_fantome as core_import_fantome;
+use crate::core::import::{import_raw_mod as core_import_raw_mod, RawImportResult};
+use crate::core::store::AssetStore;
+use crate::core::bin::cache::{clean_orphan_caches as core_clean_orphan_caches, OrphanCacheReport};
+use crate::core::wad::extractor::{
+    extract_skin_assets, extraction_checkpoint_path, import_wad, wad_folder_name, ExtractionCheckpoint,
+    ExtractionProgress, ExtractionResult, ExtractionResumeResult, WadImportResult,
+};
+use crate::core::wad::filter::ExtractionFilter;
+use crate::core::wad::identify::identify_wad;
+use crate::core::wad::reader::WadReader;
+use crate::state::{HashtableState, RequestState};
+use crate::error::{CommandError, Error};
+use crate::messages::Message;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 
 /// Create a new project
 ///
@@ -25,10 +53,20 @@ use tauri::Emitter;
 /// * `league_path` - Path to League installation
 /// * `output_path` - Directory where project will be created
 /// * `creator_name` - Creator name for repathing (e.g., "SirDexal")
+/// * `include_conventional_assets` - Keep loading screen / HUD icons for the skin (default: true)
+/// * `target_kind` - Champion, map or global WAD project; inferred from `wad_path` if
+///   one is given, champion otherwise
+/// * `wad_path` - A specific WAD to create the project from instead of looking
+///   up `champion` in the installation; `champion` may then be empty to use
+///   the name inferred from the WAD
+/// * `extraction_filter` - Limit champion extraction to one skin, some kinds of
+///   file or matching paths (default: everything)
+/// * `init_git` - Put the project under git version control, with the finished
+///   extraction as the first commit (default: false)
 ///
 /// # Returns
 /// * `Ok(Project)` - The created project
-/// * `Err(String)` - Error message if creation failed
+/// * `Err(CommandError)` - Error message if creation failed
 #[tauri::command]
 #[allow(clippy::too_many_arguments)]
 pub async fn create_project(
@@ -38,9 +76,15 @@ pub async fn create_project(
     league_path: String,
     output_path: String,
     creator_name: Option<String>,
+    include_conventional_assets: Option<bool>,
+    target_kind: Option<TargetKind>,
+    wad_path: Option<String>,
+    extraction_filter: Option<ExtractionFilter>,
+    init_git: Option<bool>,
     hashtable_state: tauri::State<'_, HashtableState>,
+    requests: tauri::State<'_, RequestState>,
     app: tauri::AppHandle,
-) -> Result<Project, String> {
+) -> Result<Project, CommandError> {
     tracing::info!(
         "Frontend requested project creation: {} ({} skin {})",
         name, champion, skin_id
@@ -48,31 +92,52 @@ pub async fn create_project(
 
     let league_path_buf = PathBuf::from(&league_path);
     let output_path_buf = PathBuf::from(&output_path);
+    ensure_outside_league(&output_path_buf)?;
 
     // Get hashtable (lazy-loaded on first use)
-    let _ = app.emit("project-create-progress", serde_json::json!({
-        "phase": "init",
-        "message": "Initializing..."
-    }));
+    let _ = app.emit("project-create-progress", Message::plain("progress.project.init").progress_payload(
+        serde_json::json!({ "phase": "init" }),
+    ));
 
-    let hashtable = hashtable_state.get_hashtable().ok_or_else(|| 
-        "Failed to load hashtable. Please check that hash files are available.".to_string()
-    )?;
+    let hashtable = hashtable_state.get_hashtable().ok_or_else(|| CommandError::plain("error.hashtable_unavailable"))?;
     
     tracing::info!("Hashtable ready with {} entries", hashtable.len());
 
     // 2. Validate WAD existence before creating project
-    let wad_path = find_champion_wad(&league_path_buf, &champion)
-        .ok_or_else(|| format!(
-            "Champion WAD not found for '{}'. Please check League installation.",
-            champion
-        ))?;
+    let (target_kind, champion, wad_path) = match wad_path {
+        Some(wad_path) => {
+            let wad_path = PathBuf::from(wad_path);
+            let probe_path = wad_path.clone();
+            let probe_hashtable = hashtable.clone();
+            let identity = tokio::task::spawn_blocking(move || identify_wad(&probe_path, Some(&*probe_hashtable)))
+                .await?
+                .map_err(CommandError::from)?;
+            let target = if champion.is_empty() {
+                match identity.name {
+                    Some(name) => name,
+                    None => wad_folder_name(&wad_path)?.trim_end_matches(".wad.client").to_string(),
+                }
+            } else {
+                champion
+            };
+            (target_kind.unwrap_or_else(|| identity.target.into()), target, wad_path)
+        }
+        None => {
+            let target_kind = target_kind.unwrap_or_default();
+            let wad_path = find_target_wad(&league_path_buf, target_kind, &champion).ok_or_else(|| match target_kind {
+                TargetKind::Champion => CommandError::new("error.champion_wad_not_found", [("champion", champion.clone())]),
+                TargetKind::Map | TargetKind::Global => {
+                    CommandError::new("error.target_wad_not_found", [("name", champion.clone())])
+                }
+            })?;
+            (target_kind, champion, wad_path)
+        }
+    };
 
     // 3. Create the project directory structure
-    let _ = app.emit("project-create-progress", serde_json::json!({
-        "phase": "create",
-        "message": "Creating project structure..."
-    }));
+    let _ = app.emit("project-create-progress", Message::plain("progress.project.create").progress_payload(
+        serde_json::json!({ "phase": "create" }),
+    ));
 
     let name_clone = name.clone();
     let champion_clone = champion.clone();
@@ -81,84 +146,189 @@ pub async fn create_project(
     let creator_clone = creator_name.clone();
 
     let project = tokio::task::spawn_blocking(move || {
-        core_create_project(&name_clone, &champion_clone, skin_id, &league_clone, &output_clone, creator_clone)
+        core_create_target_project(
+            &name_clone,
+            target_kind,
+            &champion_clone,
+            skin_id,
+            &league_clone,
+            &output_clone,
+            creator_clone,
+        )
     })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
-    .map_err(|e| e.to_string())?;
+    .await?
+    .map_err(CommandError::from)?;
     
     // 4. Extract skin assets into the project
-    let _ = app.emit("project-create-progress", serde_json::json!({
-        "phase": "extract",
-        "message": format!("Extracting {} skin {} assets...", champion, skin_id)
-    }));
+    let extract_message = match target_kind {
+        TargetKind::Champion => Message::new(
+            "progress.project.extract",
+            [("champion", champion.clone()), ("skin_id", skin_id.to_string())],
+        ),
+        TargetKind::Map | TargetKind::Global => Message::new("progress.project.extract_wad", [("wad", champion.clone())]),
+    };
+    let project_path_str = project.project_path.to_string_lossy().to_string();
+    let _ = app.emit("project-create-progress", extract_message.progress_payload(
+        serde_json::json!({ "phase": "extract", "project_path": project_path_str }),
+    ));
 
     tracing::info!("Extracting assets for {} skin {}...", champion, skin_id);
     
     let assets_path = project.assets_path();
     let champion_for_extract = champion.clone();
+    let checkpoint_path = extraction_checkpoint_path(&project.project_path);
+    let store = app.path().app_data_dir().ok().and_then(|dir| AssetStore::from_settings(&dir));
+    let manifest_wad = wad_path.clone();
+    let request = requests.begin(Some(extraction_request_id(&project_path_str)));
+    let cancel = request.token();
+    let progress_app = app.clone();
+    
+    let extraction_filter = extraction_filter.unwrap_or_default();
+    if !extraction_filter.is_empty() {
+        tracing::info!("Extracting with filter: {:?}", extraction_filter);
+    }
     
     let extraction_result = tokio::task::spawn_blocking(move || {
-        let mut wad = Wad::mount(std::fs::File::open(&wad_path)
-            .map_err(|e| format!("Failed to open WAD: {}", e))?)
-            .map_err(|e| format!("Failed to mount WAD: {}", e))?;
+        let on_progress = |p: ExtractionProgress| {
+            let _ = progress_app.emit("project-create-progress", p.message().progress_payload(serde_json::json!({
+                "phase": "extract",
+                "current": p.current,
+                "total": p.total,
+                "item": p.item,
+            })));
+        };
+        let mut reader = WadReader::open(&wad_path)?;
         
-        extract_skin_assets(
-            &mut wad,
-            &assets_path,
-            &champion_for_extract,
-            skin_id,
-            &hashtable,
-        ).map_err(|e| e.to_string())
+        match target_kind {
+            TargetKind::Champion => extract_skin_assets(
+                reader.wad_mut(),
+                &assets_path,
+                &champion_for_extract,
+                skin_id,
+                &hashtable,
+                Some(&checkpoint_path),
+                store.as_ref(),
+                &extraction_filter,
+                &cancel,
+                Some(&on_progress),
+            ),
+            // Map and UI mods can touch anything in the WAD, so nothing is filtered out
+            TargetKind::Map | TargetKind::Global => {
+                let wad_dir = assets_path.join(format!("{}.wad.client", champion_for_extract.to_lowercase()));
+                import_wad(reader.wad_mut(), wad_dir, Some(&hashtable)).map(|imported| ExtractionResult {
+                    extracted_count: imported.imported_count,
+                    path_mappings: imported.path_mappings,
+                    warnings: imported.warnings,
+                    skipped_count: 0,
+                    filtered_count: 0,
+                })
+            }
+        }
+        .map_err(CommandError::from)
     })
     .await;
+    drop(request);
     
     let extraction_result = match extraction_result {
         Ok(Ok(result)) => {
-            tracing::info!("Extracted {} assets to project", result.extracted_count);
+            tracing::info!(
+                "Extracted {} assets to project ({} left out by the filter)",
+                result.extracted_count,
+                result.filtered_count
+            );
+            if !result.warnings.is_empty() {
+                tracing::warn!(
+                    "{} chunks collided during extraction and were kept under disambiguated names",
+                    result.warnings.len()
+                );
+            }
             result
         }
+        Ok(Err(e)) if e.key == "error.cancelled" => {
+            tracing::info!("Extraction cancelled, removing the unfinished project");
+            if let Err(cleanup_err) = std::fs::remove_dir_all(&project.project_path) {
+                tracing::error!("Failed to clean up project directory: {}", cleanup_err);
+            }
+            return Err(e);
+        }
         Ok(Err(e)) => {
             tracing::error!("Asset extraction failed: {}", e);
             tracing::info!("Cleaning up project directory due to failure...");
             if let Err(cleanup_err) = std::fs::remove_dir_all(&project.project_path) {
                 tracing::error!("Failed to clean up project directory: {}", cleanup_err);
             }
-            return Err(format!("Asset extraction failed: {}. Project creation cancelled.", e));
+            return Err(CommandError::new("error.project_extraction", [("detail", e.message)]));
         }
         Err(e) => {
             tracing::error!("Extraction task panicked: {}", e);
             if let Err(cleanup_err) = std::fs::remove_dir_all(&project.project_path) {
                 tracing::error!("Failed to clean up project directory: {}", cleanup_err);
             }
-            return Err(format!("Internal error during extraction: {}", e));
+            return Err(e.into());
         }
     };
 
-    // 5. Repath assets if creator name is provided
+    // Remember what the vanilla files looked like so a later patch update can
+    // tell them apart from the author's edits
+    let manifest_project = project.clone();
+    match tokio::task::spawn_blocking(move || record_vanilla_manifest(&manifest_project, &manifest_wad)).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => tracing::warn!("Failed to record vanilla manifest (continuing): {}", e),
+        Err(e) => tracing::warn!("Vanilla manifest task panicked (continuing): {}", e),
+    }
+
+    // 5. Pull in linked BINs that live in other game WADs so repath doesn't leave
+    // the project pointing at files it never extracted. The closure starts at
+    // the champion's skin BIN; map and global projects hold the whole WAD.
+    if target_kind == TargetKind::Champion {
+        let closure_project = project.clone();
+        let closure_mappings = extraction_result.path_mappings.clone();
+        match tokio::task::spawn_blocking(move || {
+            resolve_project_closure(&closure_project, &closure_mappings, true)
+        })
+        .await
+        {
+            Ok(Ok(report)) if report.extracted_count > 0 || report.missing_count > 0 => {
+                tracing::info!(
+                    "Linked BIN closure: {} extracted from other WADs, {} missing",
+                    report.extracted_count,
+                    report.missing_count
+                );
+            }
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => tracing::warn!("Failed to resolve linked BINs (continuing): {}", e),
+            Err(e) => tracing::warn!("Linked BIN resolution task panicked (continuing): {}", e),
+        }
+    }
+
+    // 6. Repath assets if creator name is provided
     if let Some(creator) = creator_name {
         if !creator.is_empty() {
-            let _ = app.emit("project-create-progress", serde_json::json!({
-                "phase": "repath",
-                "message": format!("Repathing assets to ASSETS/{}/{}...", creator, name)
-            }));
+            let repath_message = Message::new(
+                "progress.project.repath",
+                [("creator", creator.clone()), ("project", name.clone())],
+            );
+            let _ = app.emit("project-create-progress", repath_message.progress_payload(
+                serde_json::json!({ "phase": "repath" }),
+            ));
 
             tracing::info!("Repathing assets with prefix: ASSETS/{}/{}", creator, name);
 
-            let repath_config = OrganizerConfig {
-                enable_concat: true,
-                enable_repath: true,
-                creator_name: creator.clone(),
-                project_name: name.clone(),
-                champion: champion.clone(),
-                target_skin_id: skin_id,
-                cleanup_unused: true,
-            };
+            let repath_config = new_project_organizer_config(&project, &creator, include_conventional_assets.unwrap_or(true));
 
             let assets_path_for_repath = project.assets_path();
             let path_mappings = extraction_result.path_mappings.clone();
+            let progress_app = app.clone();
             let repath_result = tokio::task::spawn_blocking(move || {
-                organize_project(&assets_path_for_repath, &repath_config, &path_mappings)
+                let on_progress = |p: OrganizeProgress| {
+                    let _ = progress_app.emit("project-create-progress", p.message().progress_payload(serde_json::json!({
+                        "phase": "repath",
+                        "current": p.current,
+                        "total": p.total,
+                        "item": p.item,
+                    })));
+                };
+                organize_project(&assets_path_for_repath, &repath_config, &path_mappings, Some(&on_progress))
             })
             .await;
 
@@ -185,14 +355,58 @@ pub async fn create_project(
         }
     }
 
-    let _ = app.emit("project-create-progress", serde_json::json!({
-        "phase": "complete",
-        "message": "Project created successfully!"
-    }));
+    if init_git.unwrap_or(false) {
+        init_project_vcs(&project.project_path, project.authors.first().map(|a| a.name.clone())).await;
+    }
+
+    let _ = app.emit("project-create-progress", Message::plain("progress.project.complete").progress_payload(
+        serde_json::json!({ "phase": "complete" }),
+    ));
 
     Ok(project)
 }
 
+/// Concat and repath settings for a freshly extracted project
+///
+/// # Arguments
+/// * `project` - The new project
+/// * `creator` - Creator name used as the asset prefix
+/// * `include_conventional_assets` - Keep loading screen / HUD icons for the skin
+fn new_project_organizer_config(project: &Project, creator: &str, include_conventional_assets: bool) -> OrganizerConfig {
+    OrganizerConfig {
+        enable_concat: true,
+        enable_repath: true,
+        creator_name: creator.to_string(),
+        // The name as typed; `name` is its slug
+        project_name: project.display_name.clone(),
+        champion: project.champion.clone(),
+        target_skin_id: project.skin_id,
+        cleanup_unused: true,
+        include_conventional_assets,
+        extra_targets: Vec::new(),
+        version: project.version.clone(),
+        repath_options: project.repath.clone(),
+        target_kind: project.target_kind,
+    }
+}
+
+/// Puts a new project under git with its extracted state as the first commit
+///
+/// A failure is logged and leaves a working project without version control.
+async fn init_project_vcs(project_path: &std::path::Path, author: Option<String>) {
+    let path = project_path.to_path_buf();
+    let result = tokio::task::spawn_blocking(move || {
+        crate::core::vcs::init_repository(&path)?;
+        crate::core::vcs::commit_project(&path, "Create project", author.as_deref())
+    })
+    .await;
+    match result {
+        Ok(Ok(commit)) => tracing::info!("Project committed as {}", commit.id),
+        Ok(Err(e)) => tracing::warn!("Failed to put project under version control (continuing): {}", e),
+        Err(e) => tracing::warn!("Version control task panicked (continuing): {}", e),
+    }
+}
+
 
 /// Open an existing project
 ///
@@ -201,17 +415,16 @@ pub async fn create_project(
 ///
 /// # Returns
 /// * `Ok(Project)` - The loaded project
-/// * `Err(String)` - Error message if loading failed
+/// * `Err(CommandError)` - Error message if loading failed
 #[tauri::command]
-pub async fn open_project(path: String) -> Result<Project, String> {
+pub async fn open_project(path: String) -> Result<Project, CommandError> {
     tracing::info!("Frontend requested opening project: {}", path);
 
     let path = PathBuf::from(path);
 
     tokio::task::spawn_blocking(move || core_open_project(&path))
-        .await
-        .map_err(|e| format!("Task failed: {}", e))?
-        .map_err(|e| e.to_string())
+        .await?
+        .map_err(CommandError::from)
 }
 
 /// Save project state
@@ -221,15 +434,633 @@ pub async fn open_project(path: String) -> Result<Project, String> {
 ///
 /// # Returns
 /// * `Ok(())` - If save succeeded
-/// * `Err(String)` - Error message if save failed
+/// * `Err(CommandError)` - Error message if save failed
 #[tauri::command]
-pub async fn save_project(project: Project) -> Result<(), String> {
+pub async fn save_project(project: Project) -> Result<(), CommandError> {
     tracing::info!("Frontend requested saving project: {}", project.name);
 
     tokio::task::spawn_blocking(move || core_save_project(&project))
-        .await
-        .map_err(|e| format!("Task failed: {}", e))?
-        .map_err(|e| e.to_string())
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Relink a project to a moved League installation
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `league_path` - Path to the new League installation
+///
+/// # Returns
+/// * `Ok(Project)` - The updated project
+/// * `Err(CommandError)` - Error message if the new path is invalid or lacks the champion WAD
+#[tauri::command]
+pub async fn relink_league_path(project_path: String, league_path: String) -> Result<Project, CommandError> {
+    tracing::info!("Frontend requested relinking project {} to {}", project_path, league_path);
+
+    let project_path = PathBuf::from(project_path);
+    let league_path = PathBuf::from(league_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        core_relink_league_path(&mut project, &league_path)?;
+        Ok::<_, crate::error::Error>(project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Compare a project's recorded game version with its League installation
+///
+/// A project extracted on an earlier patch can reference assets the current
+/// WADs no longer have; the result says whether updating its assets is advised.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(ProjectCompatibility)` - Both versions, how they compare and a warning if they differ
+/// * `Err(CommandError)` - Error message if the project couldn't be opened
+#[tauri::command]
+pub async fn check_project_compatibility(project_path: String) -> Result<ProjectCompatibility, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        Ok::<_, crate::error::Error>(core_check_project_compatibility(&project))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Update the BIN text variables stored in flint.json
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `variables` - User variables, used as `${NAME}` in BIN text
+/// * `collapse_variables` - Replace variable values with `${NAME}` when reading BINs
+///
+/// # Returns
+/// * `Ok(Project)` - The updated project
+/// * `Err(CommandError)` - Error message if a variable name is invalid
+#[tauri::command]
+pub async fn set_project_variables(
+    project_path: String,
+    variables: BTreeMap<String, String>,
+    collapse_variables: bool,
+) -> Result<Project, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        core_set_project_variables(&mut project, variables, collapse_variables)?;
+        Ok::<_, crate::error::Error>(project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Update the repath prefix template and strategy stored in flint.json
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `options` - Prefix template, extensions to leave in place, BIN-only mode
+///
+/// # Returns
+/// * `Ok(Project)` - The updated project
+/// * `Err(CommandError)` - Error message if the template is invalid
+#[tauri::command]
+pub async fn set_repath_options(project_path: String, options: RepathOptions) -> Result<Project, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        core_set_repath_options(&mut project, options)?;
+        Ok::<_, crate::error::Error>(project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Update a layer's priority, description or export toggle
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `layer` - Layer name
+/// * `priority` - New priority; higher-priority layers override lower ones
+/// * `enabled` - Whether the layer is included in exports
+/// * `description` - New description (empty string clears it)
+///
+/// # Returns
+/// * `Ok(Project)` - The updated project
+/// * `Err(CommandError)` - Error message if the layer doesn't exist or is the base layer being disabled
+#[tauri::command]
+pub async fn set_layer_metadata(
+    project_path: String,
+    layer: String,
+    priority: Option<i32>,
+    enabled: Option<bool>,
+    description: Option<String>,
+) -> Result<Project, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        core_set_layer_metadata(&mut project, &layer, priority, enabled, description)?;
+        Ok::<_, crate::error::Error>(project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Set or remove the thumbnail shown for the mod in mod managers
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `image_path` - PNG, JPEG, WebP, DDS or TEX image; None removes the thumbnail
+///
+/// # Returns
+/// * `Ok(Project)` - The updated project
+/// * `Err(CommandError)` - Error message if the image can't be read or written
+#[tauri::command]
+pub async fn set_project_thumbnail(project_path: String, image_path: Option<String>) -> Result<Project, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        core_set_project_thumbnail(&mut project, image_path.as_deref().map(std::path::Path::new))?;
+        Ok::<_, crate::error::Error>(project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Get the mod metadata stored in a project's mod.config.json
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(ProjectMetadata)` - Name, version, description, authors, license and thumbnail
+/// * `Err(CommandError)` - Error message if the project can't be opened
+#[tauri::command]
+pub async fn get_project_metadata(project_path: String) -> Result<ProjectMetadata, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        Ok::<_, crate::error::Error>(ProjectMetadata::from(&project))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Update the mod metadata in a project's mod.config.json
+///
+/// Exports read their metadata from the saved project, so this is the one
+/// place names, versions, authors and the license are edited.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `update` - Fields to change; the name must be a slug and the version semver
+///
+/// # Returns
+/// * `Ok(ProjectMetadata)` - The metadata after the update
+/// * `Err(CommandError)` - Error message if a field is invalid or the project can't be saved
+#[tauri::command]
+pub async fn update_project_metadata(project_path: String, update: MetadataUpdate) -> Result<ProjectMetadata, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        core_update_project_metadata(&mut project, update)?;
+        Ok::<_, crate::error::Error>(ProjectMetadata::from(&project))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Resolve the linked BIN closure of a project across game WADs
+///
+/// Reports every dependency reachable from the main skin BIN and where it
+/// was found. Linked data BINs that only exist in other game WADs can be
+/// extracted into the project; champion root and animation BINs are always
+/// left pointing at game data.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `extract` - Extract out-of-project linked data BINs (default: false)
+///
+/// # Returns
+/// * `Ok(LinkedClosureReport)` - Status of each dependency
+/// * `Err(CommandError)` - Error message if the project or game data can't be read
+#[tauri::command]
+pub async fn resolve_linked_bins(
+    project_path: String,
+    extract: Option<bool>,
+) -> Result<LinkedClosureReport, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        resolve_project_closure(&project, &HashMap::new(), extract.unwrap_or(false))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Get the linked BIN dependency tree of a project's main skin BIN
+///
+/// Walks the `dependencies` of every BIN in the project recursively, with
+/// each BIN's category, object count and size, and which BINs concatenation
+/// would merge. Dependencies outside the project are listed but not expanded.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(BinDependencyNode)` - The main skin BIN and everything it links
+/// * `Err(CommandError)` - Error message if the project or main BIN can't be found
+#[tauri::command]
+pub async fn get_bin_dependency_tree(project_path: String) -> Result<BinDependencyNode, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        project_dependency_tree(&project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Remove `.ritobin` caches whose source BIN no longer exists
+///
+/// Scans every content layer of the project.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(OrphanCacheReport)` - Removed caches and reclaimed space
+/// * `Err(CommandError)` - Error message if the project can't be opened or a cache can't be removed
+#[tauri::command]
+pub async fn clean_orphan_caches(project_path: String) -> Result<OrphanCacheReport, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        let content_dir = project.project_path.join("content");
+        if !content_dir.exists() {
+            return Ok(OrphanCacheReport::default());
+        }
+        core_clean_orphan_caches(&content_dir)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Merge the chunks of an existing WAD into a project's base layer
+///
+/// Lets older mods or raw extractions be ported into a project instead of
+/// only extracting fresh assets from the League install.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `wad_path` - The `.wad.client` (or `.wad`) file to import
+/// * `hashtable_state` - Hashtable state for path resolution
+///
+/// # Returns
+/// * `Ok(WadImportResult)` - Imported chunks, renamed paths and warnings
+/// * `Err(CommandError)` - Error message if the project or WAD can't be opened
+#[tauri::command]
+pub async fn import_wad_into_project(
+    project_path: String,
+    wad_path: String,
+    hashtable_state: tauri::State<'_, HashtableState>,
+) -> Result<WadImportResult, CommandError> {
+    tracing::info!("Importing {} into project {}", wad_path, project_path);
+
+    let hashtable = hashtable_state.get_hashtable();
+    let project_path = PathBuf::from(project_path);
+    let wad_path = PathBuf::from(wad_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        let wad_output_dir = project.assets_path().join(wad_folder_name(&wad_path)?);
+
+        let mut reader = WadReader::open(&wad_path)?;
+
+        import_wad(reader.wad_mut(), &wad_output_dir, hashtable.as_deref())
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Create a project from an existing `.fantome` mod
+///
+/// Unpacks the WAD folders into `content/base` and rebuilds `mod.config.json`
+/// and `flint.json`, so downloaded or previously exported mods can be edited.
+///
+/// # Arguments
+/// * `fantome_path` - The `.fantome` archive to import
+/// * `output_path` - Directory where the project will be created
+/// * `league_path` - League installation to link the project to, if known
+///
+/// # Returns
+/// * `Ok(Project)` - The imported project
+/// * `Err(CommandError)` - Error message if the archive can't be imported
+#[tauri::command]
+pub async fn import_fantome(
+    fantome_path: String,
+    output_path: String,
+    league_path: Option<String>,
+) -> Result<Project, CommandError> {
+    tracing::info!("Frontend requested fantome import: {}", fantome_path);
+
+    let output_path = PathBuf::from(output_path);
+    ensure_outside_league(&output_path)?;
+    let hash_dir = get_ritoshark_hash_dir().ok();
+
+    tokio::task::spawn_blocking(move || {
+        core_import_fantome(
+            &PathBuf::from(fantome_path),
+            &output_path,
+            league_path.as_deref().map(std::path::Path::new),
+            hash_dir.as_deref(),
+        )
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Create a project from a loose folder mod
+///
+/// Copies an `ASSETS`/`DATA` dump into a new project and infers the champion
+/// from its paths.
+///
+/// # Arguments
+/// * `folder` - Folder holding the mod files
+/// * `output_path` - Directory where the project will be created
+/// * `name` - Project name; the folder name when not given
+/// * `league_path` - League installation to link the project to, if known
+/// * `unrepath` - Undo a repath prefix found under `ASSETS/`
+///
+/// # Returns
+/// * `Ok(RawImportResult)` - The imported project and what was done to it
+/// * `Err(CommandError)` - Error message if the folder can't be imported
+#[tauri::command]
+pub async fn import_raw_mod(
+    folder: String,
+    output_path: String,
+    name: Option<String>,
+    league_path: Option<String>,
+    unrepath: Option<bool>,
+) -> Result<RawImportResult, CommandError> {
+    tracing::info!("Frontend requested raw mod import: {}", folder);
+
+    let output_path = PathBuf::from(output_path);
+    ensure_outside_league(&output_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        core_import_raw_mod(
+            &PathBuf::from(folder),
+            &output_path,
+            name.as_deref(),
+            league_path.as_deref().map(std::path::Path::new),
+            unrepath.unwrap_or(true),
+        )
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Finish an asset extraction that was interrupted
+///
+/// Re-runs skin extraction for the project's champion. Chunks the interrupted
+/// run recorded in its checkpoint are skipped when their files are still
+/// intact, so only the missing part is extracted. The create steps that
+/// follow extraction (linked BINs from other WADs, then concat and repath
+/// when the project has an author) run afterwards; failures there are
+/// returned as warnings.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `hashtable_state` - Hashtable state for path resolution
+/// * `app` - App handle, used to locate the asset store and emit `extraction-progress`
+///
+/// # Returns
+/// * `Ok(ExtractionResumeResult)` - How many chunks were extracted and skipped
+/// * `Err(CommandError)` - Error message if there is nothing to resume or extraction failed
+#[tauri::command]
+pub async fn resume_project_extraction(
+    project_path: String,
+    hashtable_state: tauri::State<'_, HashtableState>,
+    requests: tauri::State<'_, RequestState>,
+    app: tauri::AppHandle,
+) -> Result<ExtractionResumeResult, CommandError> {
+    let hashtable = hashtable_state.get_hashtable().ok_or_else(|| CommandError::plain("error.hashtable_unavailable"))?;
+    let request = requests.begin(Some(extraction_request_id(&project_path)));
+    let cancel = request.token();
+    let on_progress = extraction_progress_emitter(app.clone(), project_path.clone());
+    let project_path = PathBuf::from(project_path);
+    let store = app.path().app_data_dir().ok().and_then(|dir| AssetStore::from_settings(&dir));
+
+    tokio::task::spawn_blocking(move || {
+        let project = core_open_project(&project_path)?;
+        let checkpoint_path = extraction_checkpoint_path(&project.project_path);
+        if !checkpoint_path.exists() {
+            return Err(CommandError::plain("error.no_extraction_to_resume"));
+        }
+
+        let wad_path = find_target_wad(project.require_league_path()?, project.target_kind, &project.champion)
+            .ok_or_else(|| CommandError::new("error.target_wad_not_found", [("name", project.champion.clone())]))?;
+        let mut reader = WadReader::open(&wad_path)?;
+
+        let filter = ExtractionCheckpoint::load(&checkpoint_path).filter().clone();
+        let result = extract_skin_assets(
+            reader.wad_mut(),
+            project.assets_path(),
+            &project.champion,
+            project.skin_id,
+            &hashtable,
+            Some(&checkpoint_path),
+            store.as_ref(),
+            &filter,
+            &cancel,
+            Some(&on_progress),
+        )?;
+        tracing::info!(
+            "Resumed extraction: {} extracted, {} already done",
+            result.extracted_count,
+            result.skipped_count
+        );
+        if let Err(e) = record_vanilla_manifest(&project, &wad_path) {
+            tracing::warn!("Failed to record vanilla manifest: {}", e);
+        }
+
+        // The interrupted create never got to the steps after extraction
+        let mut warnings = result.warnings;
+        if project.target_kind == TargetKind::Champion {
+            if let Err(e) = resolve_project_closure(&project, &result.path_mappings, true) {
+                tracing::warn!("Failed to resolve linked BINs after resuming: {}", e);
+                warnings.push(format!("Linked BINs from other WADs weren't extracted: {}", e));
+            }
+        }
+        // Projects are created with the creator as their author, and only repathed if there was one
+        if let Some(creator) = project.authors.first().map(|a| a.name.clone()).filter(|name| !name.is_empty()) {
+            let config = new_project_organizer_config(&project, &creator, true);
+            if let Err(e) = organize_project(&project.assets_path(), &config, &result.path_mappings, None) {
+                tracing::warn!("Repathing failed after resuming: {}", e);
+                warnings.push(format!("Repathing failed: {}", e));
+            }
+        }
+
+        Ok(ExtractionResumeResult {
+            extracted_count: result.extracted_count,
+            skipped_count: result.skipped_count,
+            warnings,
+        })
+    })
+    .await?
+}
+
+/// Add another champion skin to a project
+///
+/// Extracts the champion's skin assets into its own `{champion}.wad.client`
+/// folder and records it in flint.json, so organize and export handle it
+/// alongside the primary champion.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `champion` - Champion internal name
+/// * `skin_id` - Skin of that champion to extract
+/// * `filter` - Which of the champion's files to extract (default: everything)
+///
+/// Progress is emitted as `extraction-progress`, and `cancel_extraction`
+/// stops it before the project is changed.
+///
+/// # Returns
+/// * `Ok(Project)` - The project with the new target
+/// * `Err(CommandError)` - Error message if the champion can't be added
+#[tauri::command]
+pub async fn add_project_target(
+    project_path: String,
+    champion: String,
+    skin_id: u32,
+    filter: Option<ExtractionFilter>,
+    hashtable_state: tauri::State<'_, HashtableState>,
+    requests: tauri::State<'_, RequestState>,
+    app: tauri::AppHandle,
+) -> Result<Project, CommandError> {
+    tracing::info!("Frontend requested adding {} skin {} to {}", champion, skin_id, project_path);
+
+    let hashtable = hashtable_state.get_hashtable().ok_or_else(|| CommandError::plain("error.hashtable_unavailable"))?;
+    let request = requests.begin(Some(extraction_request_id(&project_path)));
+    let cancel = request.token();
+    let on_progress = extraction_progress_emitter(app.clone(), project_path.clone());
+    let project_path = PathBuf::from(project_path);
+    let store = app.path().app_data_dir().ok().and_then(|dir| AssetStore::from_settings(&dir));
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        project.add_champion_target(&champion, skin_id)?;
+
+        let wad_path = find_target_wad(project.require_league_path()?, TargetKind::Champion, &champion)
+            .ok_or_else(|| CommandError::new("error.champion_wad_not_found", [("champion", champion.clone())]))?;
+        let mut reader = WadReader::open(&wad_path)?;
+
+        let result = extract_skin_assets(
+            reader.wad_mut(),
+            project.assets_path(),
+            &champion,
+            skin_id,
+            &hashtable,
+            None,
+            store.as_ref(),
+            &filter.unwrap_or_default(),
+            &cancel,
+            Some(&on_progress),
+        )?;
+        tracing::info!("Extracted {} assets for {}", result.extracted_count, champion);
+
+        core_save_project(&project)?;
+        Ok::<_, CommandError>(project)
+    })
+    .await?
+}
+
+/// ID an extraction into `project_path` is registered under
+fn extraction_request_id(project_path: &str) -> String {
+    format!("extraction:{}", project_path)
+}
+
+/// Progress callback that forwards chunk progress as `extraction-progress`
+fn extraction_progress_emitter(app: tauri::AppHandle, project_path: String) -> impl Fn(ExtractionProgress) + Sync {
+    move |p: ExtractionProgress| {
+        let _ = app.emit("extraction-progress", p.message().progress_payload(serde_json::json!({
+            "project_path": project_path,
+            "current": p.current,
+            "total": p.total,
+            "progress": p.fraction(),
+            "item": p.item,
+        })));
+    }
+}
+
+/// Cancel the asset extraction running for a project
+///
+/// Extraction stops after the batch of chunks it is working on and fails
+/// with `error.cancelled`. Its checkpoint is kept, so
+/// `resume_project_extraction` can pick up where it stopped. A project still
+/// being created is removed instead.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory, as sent with the
+///   `extract` phase of `project-create-progress`
+///
+/// # Returns
+/// * `Ok(bool)` - Whether an extraction was running for that project
+#[tauri::command]
+pub async fn cancel_extraction(
+    project_path: String,
+    requests: tauri::State<'_, RequestState>,
+) -> Result<bool, CommandError> {
+    Ok(requests.cancel(&extraction_request_id(&project_path)))
+}
+
+/// Brings a project's vanilla assets up to date with the installed League patch
+///
+/// Chunks the patch changed are re-extracted where the project still holds
+/// the original file, wherever repath moved it. Files the author edited are
+/// kept and listed, and changed BINs are flagged for review. The project's
+/// recorded game version is updated afterwards.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(ProjectUpdateReport)` - Updated, preserved and flagged files
+/// * `Err(CommandError)` - Error message if the champion WAD can't be found or read
+#[tauri::command]
+pub async fn update_project_assets(project_path: String) -> Result<ProjectUpdateReport, CommandError> {
+    let project_path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let mut project = core_open_project(&project_path)?;
+        let league_path = project.require_league_path()?.to_path_buf();
+        let wad_path = find_target_wad(&league_path, project.target_kind, &project.champion)
+            .ok_or_else(|| CommandError::new("error.target_wad_not_found", [("name", project.champion.clone())]))?;
+
+        let mut report = core_update_project_assets(&project, &wad_path)?;
+        report.previous_game_version = project.game_version.clone();
+        report.game_version = detect_game_version(&league_path);
+
+        if report.game_version.is_some() && report.game_version != project.game_version {
+            project.game_version = report.game_version.clone();
+            core_save_project(&project)?;
+        }
+        Ok::<_, CommandError>(report)
+    })
+    .await?
 }
 
 /// List files in a project directory
@@ -239,16 +1070,16 @@ pub async fn save_project(project: Project) -> Result<(), String> {
 ///
 /// # Returns
 /// * `Ok(FileTree)` - The file tree structure
-/// * `Err(String)` - Error message if listing failed
+/// * `Err(CommandError)` - Error message if listing failed
 #[tauri::command]
-pub async fn list_project_files(project_path: String) -> Result<serde_json::Value, String> {
+pub async fn list_project_files(project_path: String) -> Result<serde_json::Value, CommandError> {
     use std::fs;
     use serde_json::json;
     
     let path = PathBuf::from(&project_path);
     
     if !path.exists() {
-        return Err(format!("Project path does not exist: {}", project_path));
+        return Err(CommandError::new("error.project_path_missing", [("path", project_path.clone())]));
     }
     
     fn build_tree(dir: &std::path::Path, base: &std::path::Path) -> serde_json::Value {
@@ -288,8 +1119,7 @@ pub async fn list_project_files(project_path: String) -> Result<serde_json::Valu
     }
     
     let tree = tokio::task::spawn_blocking(move || build_tree(&path, &path))
-        .await
-        .map_err(|e| format!("Task failed: {}", e))?;
+        .await?;
     
     Ok(tree)
 }
@@ -306,12 +1136,12 @@ pub async fn list_project_files(project_path: String) -> Result<serde_json::Valu
 ///
 /// # Returns
 /// * `Ok(usize)` - Number of BIN files converted
-/// * `Err(String)` - Error message if conversion failed
+/// * `Err(CommandError)` - Error message if conversion failed
 #[tauri::command]
 pub async fn preconvert_project_bins(
     project_path: String,
     app: tauri::AppHandle,
-) -> Result<usize, String> {
+) -> Result<usize, CommandError> {
     use std::fs;
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
@@ -322,7 +1152,7 @@ pub async fn preconvert_project_bins(
     
     let path = std::path::PathBuf::from(&project_path);
     if !path.exists() {
-        return Err(format!("Project path does not exist: {}", project_path));
+        return Err(CommandError::new("error.project_path_missing", [("path", project_path.clone())]));
     }
     
     // Pre-warm the hash cache before parallel processing
@@ -330,6 +1160,8 @@ pub async fn preconvert_project_bins(
     tracing::info!("Pre-warming BIN hash cache...");
     let _ = crate::core::bin::get_cached_bin_hashes();
     tracing::info!("Hash cache ready");
+
+    let variables = ProjectVariables::for_file(&path.join("mod.config.json"));
     
     // Find all .bin files
     let bin_files: Vec<_> = WalkDir::new(&path)
@@ -434,7 +1266,7 @@ pub async fn preconvert_project_bins(
         batch.par_iter().for_each(|bin_path| {
             let bin_path_str = bin_path.to_string_lossy().to_string();
             
-            match convert_bin_file_sync(&bin_path_str) {
+            match convert_bin_file_sync(&bin_path_str, variables.as_ref()) {
                 Ok(_) => {
                     converted_clone.fetch_add(1, Ordering::Relaxed);
                     tracing::debug!("Converted: {}", bin_path.display());
@@ -469,37 +1301,40 @@ pub async fn preconvert_project_bins(
 
 /// Synchronous helper function to convert a single BIN file to ritobin
 /// Used by parallel processing (rayon doesn't work well with async)
-fn convert_bin_file_sync(bin_path: &str) -> Result<(), String> {
+fn convert_bin_file_sync(bin_path: &str, variables: Option<&ProjectVariables>) -> Result<(), CommandError> {
     use std::fs;
-    use crate::core::bin::{read_bin_ltk, tree_to_text_cached, MAX_BIN_SIZE};
+    use crate::core::bin::{bin_size_limit, read_bin_ltk, tree_to_text_cached};
     
     // Check file size before reading to avoid loading huge corrupt files
-    let metadata = fs::metadata(bin_path)
-        .map_err(|e| format!("Failed to get file metadata for '{}': {}", bin_path, e))?;
+    let metadata = fs::metadata(bin_path).map_err(|e| Error::io_with_path(e, bin_path))?;
     
     let file_size = metadata.len() as usize;
     
     // Reject suspiciously large files (using the same limit as ltk_bridge)
-    if file_size > MAX_BIN_SIZE {
-        return Err(format!(
-            "BIN file too large ({} bytes, max {} bytes) - likely corrupt, skipping: {}",
-            file_size, MAX_BIN_SIZE, bin_path
-        ));
+    let limit = bin_size_limit();
+    if file_size > limit {
+        return Err(CommandError::new("error.bin_too_large", [
+            ("path", bin_path.to_string()),
+            ("size", file_size.to_string()),
+            ("limit", limit.to_string()),
+        ]));
     }
     
-    let data = fs::read(bin_path)
-        .map_err(|e| format!("Failed to read file '{}': {}", bin_path, e))?;
+    let data = fs::read(bin_path).map_err(|e| Error::io_with_path(e, bin_path))?;
 
     let bin = read_bin_ltk(&data)
-        .map_err(|e| format!("Failed to parse bin file '{}': {}", bin_path, e))?;
+        .map_err(|e| Error::bin_conversion_with_path(e.to_string(), bin_path))?;
 
     // Use cached hash resolution for performance
     let text = tree_to_text_cached(&bin)
-        .map_err(|e| format!("Failed to convert to text for '{}': {}", bin_path, e))?;
+        .map_err(|e| Error::bin_conversion_with_path(e.to_string(), bin_path))?;
+    let text = match variables {
+        Some(variables) => variables.collapse(&text),
+        None => text,
+    };
 
     let ritobin_path = format!("{}.ritobin", bin_path);
-    fs::write(&ritobin_path, &text)
-        .map_err(|e| format!("Failed to write ritobin '{}': {}", ritobin_path, e))?;
+    fs::write(&ritobin_path, &text).map_err(|e| Error::io_with_path(e, &ritobin_path))?;
 
     Ok(())
 }
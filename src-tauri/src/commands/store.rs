@@ -0,0 +1,44 @@
+//! Tauri commands for the shared asset store
+
+use crate::core::store::{self, AssetStore, AssetStoreSettings, AssetStoreStats, STORE_DIR_NAME};
+use crate::commands::app_data_dir;
+use crate::error::CommandError;
+use tauri::AppHandle;
+
+/// Returns whether the asset store is enabled and how much it holds
+///
+/// # Returns
+/// * `Result<AssetStoreStats, CommandError>` - Store setting and size, or error message
+#[tauri::command]
+pub async fn get_asset_store_status(app: AppHandle) -> Result<AssetStoreStats, CommandError> {
+    let dir = app_data_dir(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        let enabled = store::load_settings(&dir).enabled;
+        AssetStore::new(dir.join(STORE_DIR_NAME)).stats(enabled)
+    })
+    .await
+    .map_err(CommandError::from)
+}
+
+/// Turns placing extracted files through the shared store on or off
+///
+/// Only affects extractions from now on; files already in projects stay as they are.
+///
+/// # Arguments
+/// * `enabled` - Whether new extractions use the store
+///
+/// # Returns
+/// * `Result<AssetStoreStats, CommandError>` - The updated store status
+#[tauri::command]
+pub async fn set_asset_store_enabled(enabled: bool, app: AppHandle) -> Result<AssetStoreStats, CommandError> {
+    let dir = app_data_dir(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        store::save_settings(&dir, &AssetStoreSettings { enabled })?;
+        tracing::info!("Asset store {}", if enabled { "enabled" } else { "disabled" });
+        Ok(AssetStore::new(dir.join(STORE_DIR_NAME)).stats(enabled))
+    })
+    .await?
+    .map_err(|e: crate::error::Error| e.into())
+}
@@ -0,0 +1,45 @@
+//! Tauri commands for RiotStringTable files
+
+use crate::core::league::guard::ensure_outside_league;
+use crate::core::stringtable::{self, StringTable};
+use crate::error::CommandError;
+use std::path::PathBuf;
+
+/// Reads the entries of a `.stringtable` file
+///
+/// # Arguments
+/// * `path` - Path to the `.stringtable` file
+///
+/// # Returns
+/// * `Ok(StringTable)` - Version, header fields and every entry's hash and text
+/// * `Err(CommandError)` - Error message if the file isn't a valid string table
+#[tauri::command]
+pub async fn read_stringtable(path: String) -> Result<StringTable, CommandError> {
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || stringtable::read_stringtable(&path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Writes a `.stringtable` file
+///
+/// Entries with a `key` are stored under that key's hash, so new strings
+/// can be added by name.
+///
+/// # Arguments
+/// * `path` - Path to write, replacing any existing file
+/// * `table` - The table, usually as returned by `read_stringtable` and edited
+///
+/// # Returns
+/// * `Ok(())` - The file was written
+/// * `Err(CommandError)` - Error message if two entries share a hash or the file can't be written
+#[tauri::command]
+pub async fn write_stringtable(path: String, table: StringTable) -> Result<(), CommandError> {
+    ensure_outside_league(&path)?;
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || stringtable::write_stringtable(&path, &table))
+        .await?
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,32 @@
+//! Tauri commands for the project trash
+
+use crate::core::trash::{self, RestoreResult, TrashSessionInfo};
+use crate::error::CommandError;
+use std::path::PathBuf;
+
+/// Lists the files repath cleanup moved to the project trash, newest session first
+#[tauri::command]
+pub async fn list_cleaned_files(project_path: String) -> Result<Vec<TrashSessionInfo>, CommandError> {
+    let path = PathBuf::from(project_path);
+    tokio::task::spawn_blocking(move || trash::list_sessions(&path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Moves cleaned files back where they were
+///
+/// # Arguments
+/// * `session_id` - Trash session to restore from
+/// * `paths` - Original paths (relative to the project) to restore; the whole
+///   session when not given
+#[tauri::command]
+pub async fn restore_cleaned_files(
+    project_path: String,
+    session_id: String,
+    paths: Option<Vec<String>>,
+) -> Result<RestoreResult, CommandError> {
+    let path = PathBuf::from(project_path);
+    tokio::task::spawn_blocking(move || trash::restore_files(&path, &session_id, paths.as_deref()))
+        .await?
+        .map_err(CommandError::from)
+}
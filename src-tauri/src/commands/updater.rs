@@ -3,11 +3,14 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::process::Command;
 use tauri::{AppHandle, Emitter};
+use ts_rs::TS;
+use crate::error::{CommandError, Error};
 
 const GITHUB_OWNER: &str = "RitoShark";
 const GITHUB_REPO: &str = "Flint";
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct UpdateInfo {
     pub available: bool,
     pub current_version: String,
@@ -17,9 +20,12 @@ pub struct UpdateInfo {
     pub published_at: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct DownloadProgress {
+    #[ts(type = "number")]
     pub downloaded: u64,
+    #[ts(type = "number")]
     pub total: u64,
 }
 
@@ -43,7 +49,7 @@ pub fn get_current_version() -> String {
 }
 
 #[tauri::command]
-pub async fn check_for_updates() -> Result<UpdateInfo, String> {
+pub async fn check_for_updates() -> Result<UpdateInfo, CommandError> {
     let current_version = get_current_version();
 
     let client = reqwest::Client::new();
@@ -58,7 +64,7 @@ pub async fn check_for_updates() -> Result<UpdateInfo, String> {
         .header("Accept", "application/vnd.github.v3+json")
         .send()
         .await
-        .map_err(|e| format!("Failed to fetch releases: {}", e))?;
+        .map_err(|e| CommandError::new("error.update_check", [("detail", e.to_string())]))?;
 
     if response.status() == 404 {
         return Ok(UpdateInfo {
@@ -72,13 +78,13 @@ pub async fn check_for_updates() -> Result<UpdateInfo, String> {
     }
 
     if !response.status().is_success() {
-        return Err(format!("GitHub API error: {}", response.status()));
+        return Err(CommandError::new("error.update_check", [("detail", response.status().to_string())]));
     }
 
     let release: GitHubRelease = response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse release: {}", e))?;
+        .map_err(|e| CommandError::new("error.update_check", [("detail", e.to_string())]))?;
 
     let latest_version = release.tag_name.trim_start_matches('v').to_string();
 
@@ -124,9 +130,9 @@ pub async fn check_for_updates() -> Result<UpdateInfo, String> {
 pub async fn download_and_install_update(
     app: AppHandle,
     download_url: String,
-) -> Result<(), String> {
+) -> Result<(), CommandError> {
     if download_url.is_empty() {
-        return Err("No download URL provided".to_string());
+        return Err(CommandError::plain("error.update_url_missing"));
     }
 
     tracing::info!("Downloading update from: {}", download_url);
@@ -137,10 +143,10 @@ pub async fn download_and_install_update(
         .header("User-Agent", format!("Flint/{}", get_current_version()))
         .send()
         .await
-        .map_err(|e| format!("Failed to download update: {}", e))?;
+        .map_err(|e| CommandError::new("error.update_download", [("detail", e.to_string())]))?;
 
     if !response.status().is_success() {
-        return Err(format!("Download failed: {}", response.status()));
+        return Err(CommandError::new("error.update_download", [("detail", response.status().to_string())]));
     }
 
     // Get total size from Content-Length header
@@ -158,15 +164,16 @@ pub async fn download_and_install_update(
     // Stream download with real progress events
     let mut downloaded: u64 = 0;
     let mut file = std::fs::File::create(&installer_path)
-        .map_err(|e| format!("Failed to create installer file: {}", e))?;
+        .map_err(|e| Error::io_with_path(e, &installer_path))?;
 
     let mut stream = response.bytes_stream();
     use futures::StreamExt;
 
     while let Some(chunk_result) = stream.next().await {
-        let chunk = chunk_result.map_err(|e| format!("Failed to read download chunk: {}", e))?;
+        let chunk = chunk_result
+            .map_err(|e| CommandError::new("error.update_download", [("detail", e.to_string())]))?;
         file.write_all(&chunk)
-            .map_err(|e| format!("Failed to write installer: {}", e))?;
+            .map_err(|e| Error::io_with_path(e, &installer_path))?;
 
         downloaded += chunk.len() as u64;
 
@@ -183,12 +190,12 @@ pub async fn download_and_install_update(
     {
         Command::new(&installer_path)
             .spawn()
-            .map_err(|e| format!("Failed to launch installer: {}", e))?;
+            .map_err(|e| CommandError::new("error.update_launch", [("detail", e.to_string())]))?;
     }
 
     #[cfg(not(target_os = "windows"))]
     {
-        return Err("Auto-update is only supported on Windows".to_string());
+        return Err(CommandError::plain("error.update_unsupported"));
     }
 
     tracing::info!("Exiting for update...");
@@ -2,12 +2,19 @@
 //!
 //! These commands expose asset validation functionality to the frontend.
 
+use crate::core::project::open_project;
 use crate::core::validation::{
+    build_asset_graph as core_build_asset_graph,
+    check_conventional_assets as core_check_conventional_assets,
+    estimate_project_vram,
     extract_asset_references as core_extract_references,
     validate_assets as core_validate_assets,
-    AssetReference, ValidationReport,
+    validate_project,
+    AssetGraph, AssetReference, ConventionalAssetStatus, ReportFormat, ValidationReport, VramReport,
 };
+use crate::error::{CommandError, Error};
 use std::collections::HashSet;
+use std::path::PathBuf;
 
 /// Extract asset references from BIN content
 ///
@@ -42,3 +49,151 @@ pub fn validate_assets(
     let hash_set: HashSet<u64> = available_hashes.into_iter().collect();
     core_validate_assets(&references, &hash_set, &source_file)
 }
+
+/// Validate every BIN reference of a project against its files and the game's WADs
+///
+/// References the mod doesn't ship are looked up in the League installation's
+/// WADs (the champion WAD first) and reported as provided by the game rather
+/// than missing.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Ok(ValidationReport)` - Counts, missing assets and game-provided assets
+/// * `Err(CommandError)` - Error message if the project couldn't be opened
+#[tauri::command]
+pub async fn validate_project_references(project_path: String) -> Result<ValidationReport, CommandError> {
+    tracing::info!("Frontend requested reference validation for: {}", project_path);
+
+    let path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&path)?;
+        Ok::<_, Error>(validate_project(&project)?.references)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Check a project for assets the game loads by path convention
+///
+/// Loading screens and HUD icons aren't referenced from the skin BIN, so
+/// regular reference validation can't notice when they're missing.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `Vec<ConventionalAssetStatus>` - One entry per conventional asset kind
+#[tauri::command]
+pub async fn check_conventional_assets(project_path: String) -> Result<Vec<ConventionalAssetStatus>, CommandError> {
+    tracing::info!("Frontend requested conventional asset check for: {}", project_path);
+
+    let path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&path)?;
+        Ok::<_, crate::error::Error>(core_check_conventional_assets(
+            &project.assets_path(),
+            &project.champion,
+            project.skin_id,
+        ))
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Estimate the GPU memory used by a project's textures
+///
+/// Sums the decoded size of every texture the mod ships, including mip
+/// chains, and compares each against the game texture it replaces when the
+/// project has a League installation.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `VramReport` - Per-texture estimates, totals and warnings
+#[tauri::command]
+pub async fn estimate_vram_usage(project_path: String) -> Result<VramReport, CommandError> {
+    tracing::info!("Frontend requested VRAM estimate for: {}", project_path);
+
+    let path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&path)?;
+        estimate_project_vram(&project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Render a project's validation summary as a shareable document
+///
+/// Covers missing asset references, files with unresolved hash names,
+/// conventional assets and texture memory warnings.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `format` - `markdown` or `html`
+/// * `output_path` - File to write the document to; defaults to
+///   `output/validation_report.{md,html}` inside the project
+///
+/// # Returns
+/// * `Ok(String)` - Path of the written report
+/// * `Err(CommandError)` - Error message if validation or writing failed
+#[tauri::command]
+pub async fn export_validation_report(
+    project_path: String,
+    format: ReportFormat,
+    output_path: Option<String>,
+) -> Result<String, CommandError> {
+    tracing::info!("Frontend requested validation report for: {}", project_path);
+
+    let path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&path)?;
+        let document = validate_project(&project)?.render(format);
+
+        let output = output_path.map(PathBuf::from).unwrap_or_else(|| {
+            project
+                .output_path()
+                .join(format!("validation_report.{}", format.extension()))
+        });
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        std::fs::write(&output, document).map_err(|e| Error::io_with_path(e, &output))?;
+
+        Ok::<_, Error>(output.to_string_lossy().to_string())
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Build the asset dependency graph of a project
+///
+/// Unlike `extract_asset_references`, the graph keeps the edges between BINs
+/// and the paths they reference, so the frontend can show which BINs use a
+/// file before it is renamed or deleted.
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+///
+/// # Returns
+/// * `AssetGraph` - One node per game path with forward and reverse edges
+#[tauri::command]
+pub async fn build_asset_graph(project_path: String) -> Result<AssetGraph, CommandError> {
+    tracing::info!("Frontend requested asset graph for: {}", project_path);
+
+    let path = PathBuf::from(project_path);
+
+    tokio::task::spawn_blocking(move || {
+        let project = open_project(&path)?;
+        core_build_asset_graph(&project)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
@@ -0,0 +1,62 @@
+//! Tauri commands for project version control
+
+use crate::core::project::open_project;
+use crate::core::vcs::{self, CommitInfo, WorkingTreeDiff};
+use crate::error::CommandError;
+use std::path::PathBuf;
+
+/// Number of commits `project_history` returns when no limit is given
+const DEFAULT_HISTORY_LIMIT: usize = 200;
+
+/// Puts a project under git version control
+///
+/// # Returns
+/// * `Result<bool, CommandError>` - True if a new repository was created,
+///   false if the project already was one
+#[tauri::command]
+pub async fn init_project_repository(project_path: String) -> Result<bool, CommandError> {
+    let path = PathBuf::from(project_path);
+    tokio::task::spawn_blocking(move || vcs::init_repository(&path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Commits every change in a project
+///
+/// When git has no identity configured the commit is authored by the
+/// project's first author.
+///
+/// # Arguments
+/// * `project_path` - Project folder, already a repository
+/// * `message` - Commit message
+#[tauri::command]
+pub async fn commit_project(project_path: String, message: String) -> Result<CommitInfo, CommandError> {
+    let path = PathBuf::from(project_path);
+    tokio::task::spawn_blocking(move || {
+        let author = open_project(&path).ok().and_then(|project| project.authors.into_iter().next().map(|a| a.name));
+        vcs::commit_project(&path, &message, author.as_deref())
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Lists a project's commits, newest first
+///
+/// # Arguments
+/// * `limit` - Maximum number of commits (default: 200)
+#[tauri::command]
+pub async fn project_history(project_path: String, limit: Option<usize>) -> Result<Vec<CommitInfo>, CommandError> {
+    let path = PathBuf::from(project_path);
+    tokio::task::spawn_blocking(move || vcs::project_history(&path, limit.unwrap_or(DEFAULT_HISTORY_LIMIT)))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Lists the changes made since the last commit
+#[tauri::command]
+pub async fn diff_working_tree(project_path: String) -> Result<WorkingTreeDiff, CommandError> {
+    let path = PathBuf::from(project_path);
+    tokio::task::spawn_blocking(move || vcs::diff_working_tree(&path))
+        .await?
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,54 @@
+//! Tauri commands for VFX color editing
+
+use crate::core::bin::get_cached_bin_hashes;
+use crate::core::vfx::{self, VfxRecolor, VfxRecolorResult, VfxSystemColors};
+use crate::error::CommandError;
+use std::path::PathBuf;
+
+/// Lists the colors of the particle systems in a BIN file
+///
+/// # Arguments
+/// * `path` - Path to a skin or particle BIN
+///
+/// # Returns
+/// * `Ok(Vec<VfxSystemColors>)` - Every `VfxSystemDefinitionData` with color values
+/// * `Err(CommandError)` - Error message if the BIN can't be read
+#[tauri::command]
+pub async fn get_vfx_colors(path: String) -> Result<Vec<VfxSystemColors>, CommandError> {
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        vfx::get_vfx_colors(&path, &hashes)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Recolors the particle systems of a BIN file
+///
+/// # Arguments
+/// * `path` - Path to a skin or particle BIN
+/// * `recolor` - HSV shift or palette mapping to apply
+/// * `object_hashes` - Systems to recolor; all of them if omitted
+///
+/// # Returns
+/// * `Ok(VfxRecolorResult)` - The recolored systems and the number of changed values
+/// * `Err(CommandError)` - Error message if the BIN can't be read or saved
+#[tauri::command]
+pub async fn recolor_vfx(
+    path: String,
+    recolor: VfxRecolor,
+    object_hashes: Option<Vec<u32>>,
+) -> Result<VfxRecolorResult, CommandError> {
+    tracing::info!("Frontend requested VFX recolor of: {}", path);
+
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || {
+        let hashes = get_cached_bin_hashes().read();
+        vfx::recolor_vfx(&path, &hashes, object_hashes.as_deref(), &recolor)
+    })
+    .await?
+    .map_err(CommandError::from)
+}
@@ -1,18 +1,32 @@
-use crate::core::wad::extractor::{extract_all, extract_chunk};
+use crate::commands::app_data_dir;
+use crate::core::wad::diff::{self, WadDiff};
+use crate::core::wad::explorer::{self, ExplorerNode, GameWadEntry};
+use crate::core::wad::extractor::{extract_all, extract_chunk, extract_filtered};
+use crate::core::wad::filter::ExtractionFilter;
+use crate::core::wad::identify::{self, WadIdentity};
+use crate::core::wad::patcher::{self, ChunkPatchResult};
 use crate::core::wad::reader::WadReader;
-use crate::state::HashtableState;
+use crate::core::wad::search_index::{self, IndexUpdateStats, WadSearchHit};
+use crate::core::wad::ui::{self, UiAsset, UiAssetKind, UiAssetPreview, UiWad};
+use crate::state::{HashtableState, SearchIndexState};
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
+use crate::core::league::guard::ensure_outside_league;
+use crate::error::CommandError;
+use ts_rs::TS;
 
 /// Information about a WAD archive
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct WadInfo {
     pub path: String,
     pub chunk_count: usize,
 }
 
 /// Information about a chunk within a WAD archive
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct ChunkInfo {
     pub path_hash: String,
     pub resolved_path: Option<String>,
@@ -21,10 +35,13 @@ pub struct ChunkInfo {
 }
 
 /// Result of a WAD extraction operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct ExtractionResult {
     pub extracted_count: usize,
     pub failed_count: usize,
+    /// Chunks whose paths collided and were saved under disambiguated names
+    pub warnings: Vec<String>,
 }
 
 /// Opens a WAD file and returns metadata about it
@@ -33,12 +50,12 @@ pub struct ExtractionResult {
 /// * `path` - Path to the WAD file
 /// 
 /// # Returns
-/// * `Result<WadInfo, String>` - WAD metadata or error message
+/// * `Result<WadInfo, CommandError>` - WAD metadata or error message
 /// 
 /// # Requirements
 /// Validates: Requirements 3.1
 #[tauri::command]
-pub async fn read_wad(path: String) -> Result<WadInfo, String> {
+pub async fn read_wad(path: String) -> Result<WadInfo, CommandError> {
     let reader = WadReader::open(&path)?;
     
     Ok(WadInfo {
@@ -47,6 +64,24 @@ pub async fn read_wad(path: String) -> Result<WadInfo, String> {
     })
 }
 
+/// Infers the champion, map or UI target of a loose WAD file, so a dropped
+/// WAD can start a project without a League installation
+///
+/// # Arguments
+/// * `path` - Path to the WAD file
+/// * `state` - Hashtable state for sampling chunk paths
+///
+/// # Returns
+/// * `Result<WadIdentity, CommandError>` - Inferred target or error message
+#[tauri::command]
+pub async fn identify_wad(path: String, state: State<'_, HashtableState>) -> Result<WadIdentity, CommandError> {
+    let hashtable = state.get_hashtable();
+
+    tokio::task::spawn_blocking(move || identify::identify_wad(std::path::Path::new(&path), hashtable.as_deref()))
+        .await?
+        .map_err(CommandError::from)
+}
+
 /// Returns a list of all chunks in a WAD archive with resolved paths
 /// 
 /// # Arguments
@@ -54,7 +89,7 @@ pub async fn read_wad(path: String) -> Result<WadInfo, String> {
 /// * `state` - Hashtable state for path resolution
 /// 
 /// # Returns
-/// * `Result<Vec<ChunkInfo>, String>` - List of chunk information or error message
+/// * `Result<Vec<ChunkInfo>, CommandError>` - List of chunk information or error message
 /// 
 /// # Requirements
 /// Validates: Requirements 3.2, 3.3, 3.4
@@ -62,7 +97,7 @@ pub async fn read_wad(path: String) -> Result<WadInfo, String> {
 pub async fn get_wad_chunks(
     path: String,
     state: State<'_, HashtableState>,
-) -> Result<Vec<ChunkInfo>, String> {
+) -> Result<Vec<ChunkInfo>, CommandError> {
     let reader = WadReader::open(&path)?;
     let chunks = reader.chunks();
     
@@ -101,10 +136,11 @@ pub async fn get_wad_chunks(
 /// * `wad_path` - Path to the WAD file
 /// * `output_dir` - Directory where chunks should be extracted
 /// * `chunk_hashes` - Optional list of chunk hashes to extract (None = extract all)
+/// * `filter` - Skin, file kind and path filter applied when extracting all chunks
 /// * `state` - Hashtable state for path resolution
 /// 
 /// # Returns
-/// * `Result<ExtractionResult, String>` - Extraction statistics or error message
+/// * `Result<ExtractionResult, CommandError>` - Extraction statistics or error message
 /// 
 /// # Requirements
 /// Validates: Requirements 4.1, 4.2, 4.3, 4.4
@@ -113,8 +149,10 @@ pub async fn extract_wad(
     wad_path: String,
     output_dir: String,
     chunk_hashes: Option<Vec<String>>,
+    filter: Option<ExtractionFilter>,
     state: State<'_, HashtableState>,
-) -> Result<ExtractionResult, String> {
+) -> Result<ExtractionResult, CommandError> {
+    ensure_outside_league(&output_dir)?;
     let mut reader = WadReader::open(&wad_path)?;
     
     // Get hashtable for path resolution (lazy loaded on first use)
@@ -123,13 +161,14 @@ pub async fn extract_wad(
     
     let mut extracted_count = 0;
     let mut failed_count = 0;
+    let mut warnings = Vec::new();
     
     if let Some(hashes) = chunk_hashes {
         // Extract specific chunks
         for hash_str in hashes {
             // Parse the hash string
             let path_hash = u64::from_str_radix(&hash_str, 16)
-                .map_err(|e| format!("Invalid hash format '{}': {}", hash_str, e))?;
+                .map_err(|e| CommandError::new("error.chunk_hash_invalid", [("hash", hash_str.clone()), ("detail", e.to_string())]))?;
             
             // Check if the chunk exists and get its data
             let chunk_exists = reader.get_chunk(path_hash).is_some();
@@ -162,8 +201,15 @@ pub async fn extract_wad(
         }
     } else {
         // Extract all chunks
-        match extract_all(reader.wad_mut(), &output_dir, hashtable_ref) {
-            Ok(count) => extracted_count = count,
+        let extracted = match filter {
+            Some(filter) => extract_filtered(reader.wad_mut(), &output_dir, hashtable_ref, &filter),
+            None => extract_all(reader.wad_mut(), &output_dir, hashtable_ref),
+        };
+        match extracted {
+            Ok(result) => {
+                extracted_count = result.extracted_count;
+                warnings = result.warnings;
+            }
             Err(e) => return Err(e.into()),
         }
     }
@@ -171,5 +217,284 @@ pub async fn extract_wad(
     Ok(ExtractionResult {
         extracted_count,
         failed_count,
+        warnings,
+    })
+}
+
+/// Lists every WAD archive in a League installation for the game file explorer
+///
+/// # Arguments
+/// * `league_path` - Path to the League installation root
+///
+/// # Returns
+/// * `Result<Vec<GameWadEntry>, CommandError>` - WADs grouped by category or error message
+#[tauri::command]
+pub async fn list_game_wads(league_path: String) -> Result<Vec<GameWadEntry>, CommandError> {
+    tokio::task::spawn_blocking(move || explorer::scan_game_wads(&league_path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Lists one directory level of a WAD's virtual tree (read-only, no project needed)
+///
+/// # Arguments
+/// * `wad_path` - Path to the WAD file
+/// * `directory` - Virtual directory to list, empty for the root
+/// * `state` - Hashtable state for path resolution
+///
+/// # Returns
+/// * `Result<Vec<ExplorerNode>, CommandError>` - Child directories and files or error message
+#[tauri::command]
+pub async fn browse_wad_directory(
+    wad_path: String,
+    directory: Option<String>,
+    state: State<'_, HashtableState>,
+) -> Result<Vec<ExplorerNode>, CommandError> {
+    let hashtable = state.get_hashtable();
+
+    tokio::task::spawn_blocking(move || {
+        explorer::list_wad_directory(
+            &wad_path,
+            directory.as_deref().unwrap_or(""),
+            hashtable.as_deref(),
+        )
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Reads a single decompressed chunk from a WAD for previewing
+///
+/// # Arguments
+/// * `wad_path` - Path to the WAD file
+/// * `path_hash` - Hex path hash of the chunk
+///
+/// # Returns
+/// * `Result<Vec<u8>, CommandError>` - Chunk contents or error message
+#[tauri::command]
+pub async fn read_wad_chunk(wad_path: String, path_hash: String) -> Result<Vec<u8>, CommandError> {
+    let path_hash = u64::from_str_radix(&path_hash, 16)
+        .map_err(|e| CommandError::new("error.chunk_hash_invalid", [("hash", path_hash.clone()), ("detail", e.to_string())]))?;
+
+    tokio::task::spawn_blocking(move || explorer::read_chunk_data(&wad_path, path_hash))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Compares two WAD files chunk by chunk, e.g. a champion WAD before and
+/// after a League patch
+///
+/// # Arguments
+/// * `old_path` - WAD from the earlier patch
+/// * `new_path` - WAD from the later patch
+///
+/// # Returns
+/// * `Result<WadDiff, CommandError>` - Added, removed and modified chunks, or error message
+#[tauri::command]
+pub async fn diff_wads(
+    old_path: String,
+    new_path: String,
+    state: State<'_, HashtableState>,
+) -> Result<WadDiff, CommandError> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let hashtable = state.get_hashtable();
+        diff::diff_wads(&old_path, &new_path, hashtable.as_deref())
     })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Default number of hits returned by `search_game_wads`
+const DEFAULT_SEARCH_LIMIT: usize = 500;
+
+/// Location of the persisted search index in the app data directory
+fn search_index_path(app: &AppHandle) -> Result<PathBuf, CommandError> {
+    Ok(app_data_dir(app)?.join(search_index::INDEX_FILE_NAME))
+}
+
+/// Loads the persisted WAD search index and rescans WADs changed since it was built
+///
+/// # Arguments
+/// * `league_path` - Path to the League installation root
+///
+/// # Returns
+/// * `Result<IndexUpdateStats, CommandError>` - How many WADs were reused or rescanned, or error message
+#[tauri::command]
+pub async fn refresh_wad_search_index(
+    league_path: String,
+    app: AppHandle,
+    state: State<'_, HashtableState>,
+    index_state: State<'_, SearchIndexState>,
+) -> Result<IndexUpdateStats, CommandError> {
+    let index_path = search_index_path(&app)?;
+    let hashtable = state.get_hashtable();
+
+    let (index, stats) = tokio::task::spawn_blocking(move || {
+        search_index::refresh_index(&index_path, league_path.as_ref(), hashtable.as_deref())
+    })
+    .await?
+    .map_err(CommandError::from)?;
+
+    index_state.set(index);
+    Ok(stats)
+}
+
+/// Searches chunk paths across every game WAD using the persisted index
+///
+/// The index is refreshed on the first search of a session; later searches
+/// only read the in-memory copy.
+///
+/// # Arguments
+/// * `league_path` - Path to the League installation root
+/// * `query` - Whitespace-separated terms, matched against paths and hex hashes
+/// * `limit` - Maximum number of hits (default 500)
+///
+/// # Returns
+/// * `Result<Vec<WadSearchHit>, CommandError>` - Matching chunks or error message
+#[tauri::command]
+pub async fn search_game_wads(
+    league_path: String,
+    query: String,
+    limit: Option<usize>,
+    app: AppHandle,
+    state: State<'_, HashtableState>,
+    index_state: State<'_, SearchIndexState>,
+) -> Result<Vec<WadSearchHit>, CommandError> {
+    let index = match index_state.get(league_path.as_ref()) {
+        Some(index) => index,
+        None => {
+            let index_path = search_index_path(&app)?;
+            let hashtable = state.get_hashtable();
+            let (index, _) = tokio::task::spawn_blocking(move || {
+                search_index::refresh_index(&index_path, league_path.as_ref(), hashtable.as_deref())
+            })
+            .await?
+            .map_err(CommandError::from)?;
+            index_state.set(index)
+        }
+    };
+
+    let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    tokio::task::spawn_blocking(move || index.search(&query, limit))
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Replaces a single chunk inside a WAD without rebuilding the archive
+///
+/// Intended for quick in-game testing of a one-file change. The chunk is
+/// recompressed and appended, and only its TOC entry is updated.
+///
+/// # Arguments
+/// * `wad_path` - WAD file to patch
+/// * `path_or_hash` - Chunk path or 16-digit hex path hash
+/// * `file` - File whose contents replace the chunk
+/// * `output_path` - If set, a patched copy is written here and `wad_path` is left untouched
+///
+/// # Returns
+/// * `Result<ChunkPatchResult, CommandError>` - Patch details or error message
+#[tauri::command]
+pub async fn patch_wad_chunk(
+    wad_path: String,
+    path_or_hash: String,
+    file: String,
+    output_path: Option<String>,
+) -> Result<ChunkPatchResult, CommandError> {
+    tokio::task::spawn_blocking(move || {
+        // Game WADs are only ever patched as copies
+        ensure_outside_league(output_path.as_deref().unwrap_or(&wad_path))?;
+
+        let data = std::fs::read(&file)
+            .map_err(|e| crate::error::Error::io_with_path(e, &file))?;
+
+        match output_path {
+            Some(output) => patcher::patch_wad_chunk_copy(&wad_path, &output, &path_or_hash, &data),
+            None => patcher::patch_wad_chunk(&wad_path, &path_or_hash, &data),
+        }
+    })
+    .await?
+    .map_err(CommandError::from)
+}
+
+/// Lists the UI WADs (shared and localized) of a League installation
+///
+/// # Arguments
+/// * `league_path` - Path to the League installation root
+///
+/// # Returns
+/// * `Result<Vec<UiWad>, CommandError>` - UI archives, shared one first, or error message
+#[tauri::command]
+pub async fn list_ui_wads(league_path: String) -> Result<Vec<UiWad>, CommandError> {
+    tokio::task::spawn_blocking(move || ui::scan_ui_wads(&league_path))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Lists the fonts, HUD atlases, textures and UI data of a UI WAD
+///
+/// # Arguments
+/// * `wad_path` - Path to the UI WAD
+/// * `kind` - Only list chunks of this kind
+/// * `state` - Hashtable state for path resolution
+///
+/// # Returns
+/// * `Result<Vec<UiAsset>, CommandError>` - Chunks grouped by kind or error message
+#[tauri::command]
+pub async fn list_ui_assets(
+    wad_path: String,
+    kind: Option<UiAssetKind>,
+    state: State<'_, HashtableState>,
+) -> Result<Vec<UiAsset>, CommandError> {
+    let hashtable = state.get_hashtable();
+
+    tokio::task::spawn_blocking(move || ui::list_ui_assets(&wad_path, hashtable.as_deref(), kind))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Builds a kind-aware preview of a UI chunk: an image for textures, font
+/// metadata and data for fonts, ritobin text for BINs
+///
+/// # Arguments
+/// * `wad_path` - Path to the UI WAD
+/// * `path_hash` - Hex path hash of the chunk
+///
+/// # Returns
+/// * `Result<UiAssetPreview, CommandError>` - Preview or error message
+#[tauri::command]
+pub async fn preview_ui_asset(wad_path: String, path_hash: String) -> Result<UiAssetPreview, CommandError> {
+    let path_hash = u64::from_str_radix(&path_hash, 16)
+        .map_err(|e| CommandError::new("error.chunk_hash_invalid", [("hash", path_hash.clone()), ("detail", e.to_string())]))?;
+
+    tokio::task::spawn_blocking(move || ui::preview_ui_asset(&wad_path, path_hash))
+        .await?
+        .map_err(CommandError::from)
+}
+
+/// Extracts UI chunks of the given kinds into `{output_dir}/{wad name}/`
+///
+/// # Arguments
+/// * `wad_path` - Path to the UI WAD
+/// * `output_dir` - Directory to extract into, e.g. a project's `content/base`
+/// * `kinds` - Kinds to extract, all when empty
+/// * `state` - Hashtable state for path resolution
+///
+/// # Returns
+/// * `Result<usize, CommandError>` - Number of extracted files or error message
+#[tauri::command]
+pub async fn extract_ui_assets(
+    wad_path: String,
+    output_dir: String,
+    kinds: Vec<UiAssetKind>,
+    state: State<'_, HashtableState>,
+) -> Result<usize, CommandError> {
+    ensure_outside_league(&output_dir)?;
+    let hashtable = state
+        .get_hashtable()
+        .ok_or_else(|| CommandError::plain("error.hashtable_unavailable"))?;
+
+    tokio::task::spawn_blocking(move || ui::extract_ui_assets(&wad_path, &output_dir, &hashtable, &kinds))
+        .await?
+        .map_err(CommandError::from)
 }
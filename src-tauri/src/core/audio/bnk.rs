@@ -0,0 +1,147 @@
+//! Wwise sound bank (BNK) reading
+//!
+//! A bank is a list of tagged sections: `BKHD` (version and bank id),
+//! `DIDX` (id, offset, size of each embedded WEM), `DATA` (the WEM bytes,
+//! which `DIDX` offsets are relative to) and `HIRC` (the event, action and
+//! sound objects). Only section headers and the index are read; WEM data is
+//! left on disk until it is extracted.
+
+use crate::error::{Error, Result};
+use byteorder::{ReadBytesExt, LE};
+use std::io::{Read, Seek, SeekFrom};
+
+/// HIRC object type of events
+const HIRC_EVENT: u8 = 4;
+
+/// A WEM stored in a bank's DATA section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BankWem {
+    pub id: u32,
+    /// Absolute offset in the bank file
+    pub offset: u64,
+    pub size: u32,
+}
+
+/// The index of a sound bank
+#[derive(Debug, Clone, Default)]
+pub struct BankIndex {
+    pub version: u32,
+    pub bank_id: u32,
+    pub wems: Vec<BankWem>,
+    /// Ids of the HIRC event objects
+    pub event_ids: Vec<u32>,
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::InvalidInput(format!("Invalid sound bank: {}", message.into()))
+}
+
+/// Rebuilds a bank with some of its embedded WEMs replaced
+///
+/// DIDX and DATA are rewritten, every other section is copied as is. WEMs
+/// stay in index order and 16-byte aligned, like Wwise writes them.
+///
+/// # Arguments
+/// * `bank` - The whole original bank
+/// * `replace` - New data for a WEM id, or None to keep the WEM
+pub fn rebuild_bank(bank: &[u8], replace: impl Fn(u32) -> Option<Vec<u8>>) -> Result<Vec<u8>> {
+    let index = read_bank(&mut std::io::Cursor::new(bank))?;
+
+    let mut didx = Vec::with_capacity(index.wems.len() * 12);
+    let mut data = Vec::new();
+    for wem in &index.wems {
+        let start = data.len().next_multiple_of(16);
+        data.resize(start, 0);
+        match replace(wem.id) {
+            Some(new) => data.extend_from_slice(&new),
+            None => data.extend_from_slice(&bank[wem.offset as usize..wem.offset as usize + wem.size as usize]),
+        }
+        didx.extend_from_slice(&wem.id.to_le_bytes());
+        didx.extend_from_slice(&(start as u32).to_le_bytes());
+        didx.extend_from_slice(&((data.len() - start) as u32).to_le_bytes());
+    }
+
+    let mut out = Vec::with_capacity(bank.len());
+    let mut position = 0;
+    while position + 8 <= bank.len() {
+        let tag = &bank[position..position + 4];
+        let size = u32::from_le_bytes(bank[position + 4..position + 8].try_into().unwrap_or_default()) as usize;
+        let body = &bank[position + 8..position + 8 + size];
+        let body = match tag {
+            b"DIDX" => &didx[..],
+            b"DATA" => &data[..],
+            _ => body,
+        };
+        out.extend_from_slice(tag);
+        out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        out.extend_from_slice(body);
+        position += 8 + size;
+    }
+    Ok(out)
+}
+
+/// Reads the index of a BNK file
+pub fn read_bank<R: Read + Seek>(reader: &mut R) -> Result<BankIndex> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut index = BankIndex::default();
+    let mut has_header = false;
+    let mut entries: Vec<(u32, u32, u32)> = Vec::new();
+    let mut data_section = None;
+
+    let mut position = 0u64;
+    while position + 8 <= file_len {
+        let mut tag = [0u8; 4];
+        reader.read_exact(&mut tag)?;
+        let size = reader.read_u32::<LE>()? as u64;
+        let body = position + 8;
+        if body + size > file_len {
+            return Err(invalid(format!("section {} runs past the end of the file", String::from_utf8_lossy(&tag))));
+        }
+
+        match &tag {
+            b"BKHD" => {
+                index.version = reader.read_u32::<LE>()?;
+                index.bank_id = reader.read_u32::<LE>()?;
+                has_header = true;
+            }
+            b"DIDX" => {
+                for _ in 0..size / 12 {
+                    entries.push((reader.read_u32::<LE>()?, reader.read_u32::<LE>()?, reader.read_u32::<LE>()?));
+                }
+            }
+            b"DATA" => data_section = Some((body, size)),
+            b"HIRC" => {
+                let count = reader.read_u32::<LE>()?;
+                for _ in 0..count {
+                    let kind = reader.read_u8()?;
+                    let object_size = reader.read_u32::<LE>()? as u64;
+                    let object_start = reader.stream_position()?;
+                    if kind == HIRC_EVENT {
+                        index.event_ids.push(reader.read_u32::<LE>()?);
+                    }
+                    reader.seek(SeekFrom::Start(object_start + object_size))?;
+                }
+            }
+            _ => {}
+        }
+
+        position = body + size;
+        reader.seek(SeekFrom::Start(position))?;
+    }
+
+    if !has_header {
+        return Err(invalid("missing BKHD section"));
+    }
+    if !entries.is_empty() {
+        let (data_start, data_size) = data_section.ok_or_else(|| invalid("DIDX without a DATA section"))?;
+        for (id, offset, size) in entries {
+            if offset as u64 + size as u64 > data_size {
+                return Err(invalid(format!("WEM {} runs past the DATA section", id)));
+            }
+            index.wems.push(BankWem { id, offset: data_start + offset as u64, size });
+        }
+    }
+    Ok(index)
+}
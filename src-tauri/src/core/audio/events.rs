@@ -0,0 +1,325 @@
+//! Skin audio event mapping
+//!
+//! Skin BINs (and the champion's audio BINs) declare their sounds as
+//! `BankUnit`s: a name, the bank files it loads (`_audio.bnk`,
+//! `_events.bnk`, `_audio.wpk`) and the Wwise event names it plays. This
+//! module pairs each event name with its Wwise id and, when the banks are
+//! on disk, with the WEMs the event can play and the file holding each one.
+
+use super::bnk::read_bank;
+use super::hirc::Hierarchy;
+use super::wpk::{read_package, WPK_MAGIC};
+use crate::core::bin::ltk_bridge;
+use crate::core::mesh::anim_events::wwise_short_id;
+use crate::error::{Error, Result};
+use league_toolkit::hash::fnv1a::hash_lower;
+use ltk_meta::value::*;
+use ltk_meta::{BinTree, PropertyValueEnum};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+
+/// Folders above the BIN searched for bank files
+const MAX_BANK_DEPTH: usize = 8;
+
+/// A WEM an event can play
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioEventWem {
+    /// Wwise media id, also the WEM's entry name (`{id}.wem`)
+    pub id: u32,
+    /// Bank path (as written in the BIN) of the BNK or WPK holding the WEM
+    pub container: Option<String>,
+}
+
+/// A Wwise event declared by a bank unit
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioEventMapping {
+    pub name: String,
+    /// Wwise short id (FNV-1 of the lowercased name)
+    pub event_id: u32,
+    /// Whether a loaded bank defines the event
+    pub defined: bool,
+    pub wems: Vec<AudioEventWem>,
+}
+
+/// A `BankUnit` of a skin or audio BIN
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioBankUnit {
+    pub name: String,
+    pub voice_over: bool,
+    pub bank_paths: Vec<String>,
+    /// Bank paths with no file found near the BIN
+    pub missing_banks: Vec<String>,
+    pub events: Vec<AudioEventMapping>,
+}
+
+/// The bank units of a BIN with their events resolved
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SkinAudioEvents {
+    pub bin_path: String,
+    pub units: Vec<AudioBankUnit>,
+}
+
+/// A bank unit as declared in the BIN
+struct DeclaredUnit {
+    name: String,
+    voice_over: bool,
+    bank_paths: Vec<String>,
+    events: Vec<String>,
+}
+
+fn field<'a>(value: &'a StructValue, name: &str) -> Option<&'a PropertyValueEnum> {
+    value.properties.get(&hash_lower(name)).map(|prop| &prop.value)
+}
+
+fn list_items(value: &PropertyValueEnum) -> &[PropertyValueEnum] {
+    match value {
+        PropertyValueEnum::Container(c) | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(c)) => {
+            &c.items
+        }
+        _ => &[],
+    }
+}
+
+fn strings(value: Option<&PropertyValueEnum>) -> Vec<String> {
+    value
+        .map(list_items)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| match item {
+            PropertyValueEnum::String(s) => Some(s.0.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects the `BankUnit` structs nested anywhere in a value
+fn collect_units(value: &PropertyValueEnum, units: &mut Vec<DeclaredUnit>) {
+    let structure = match value {
+        PropertyValueEnum::Embedded(EmbeddedValue(s)) | PropertyValueEnum::Struct(s) => s,
+        PropertyValueEnum::Optional(o) => {
+            if let Some(inner) = o.value.as_deref() {
+                collect_units(inner, units);
+            }
+            return;
+        }
+        other => {
+            for item in list_items(other) {
+                collect_units(item, units);
+            }
+            return;
+        }
+    };
+
+    if structure.class_hash == hash_lower("BankUnit") {
+        units.push(DeclaredUnit {
+            name: match field(structure, "name") {
+                Some(PropertyValueEnum::String(s)) => s.0.clone(),
+                _ => String::new(),
+            },
+            voice_over: matches!(field(structure, "voiceOver"), Some(PropertyValueEnum::Bool(b)) if b.0),
+            bank_paths: strings(field(structure, "bankPath")),
+            events: strings(field(structure, "events")),
+        });
+        return;
+    }
+    for prop in structure.properties.values() {
+        collect_units(&prop.value, units);
+    }
+}
+
+/// The bank units declared by a BIN, in file order
+fn declared_units(tree: &BinTree) -> Vec<DeclaredUnit> {
+    let mut units = Vec::new();
+    for object in tree.objects.values() {
+        for prop in object.properties.values() {
+            collect_units(&prop.value, &mut units);
+        }
+    }
+    units
+}
+
+/// Finds a bank file by its BIN path, relative to the BIN's folder or one of its parents
+fn resolve_bank(bin_dir: &Path, bank_path: &str) -> Option<PathBuf> {
+    let lowered = bank_path.to_lowercase();
+    bin_dir
+        .ancestors()
+        .take(MAX_BANK_DEPTH)
+        .flat_map(|dir| [dir.join(bank_path), dir.join(&lowered)])
+        .find(|candidate| candidate.is_file())
+}
+
+/// Loads a bank file into the hierarchy and records which media ids it stores
+fn load_bank(path: &Path, bank_path: &str, hierarchy: &mut Hierarchy, media: &mut HashMap<u32, String>) -> Result<()> {
+    let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mut reader = BufReader::new(file);
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).map_err(|e| Error::io_with_path(e, path))?;
+
+    if &magic == WPK_MAGIC {
+        for wem in read_package(&mut reader)?.wems {
+            if let Some(id) = wem.name.split('.').next().and_then(|stem| stem.parse().ok()) {
+                media.entry(id).or_insert_with(|| bank_path.to_string());
+            }
+        }
+    } else {
+        for wem in read_bank(&mut reader)?.wems {
+            media.entry(wem.id).or_insert_with(|| bank_path.to_string());
+        }
+        hierarchy.add_bank(&mut reader)?;
+    }
+    Ok(())
+}
+
+/// Maps the events of every bank unit in a parsed BIN
+///
+/// # Arguments
+/// * `tree` - The skin or audio BIN
+/// * `bin_dir` - Folder of the BIN, where the search for bank files starts
+pub fn map_audio_events(tree: &BinTree, bin_dir: &Path) -> Vec<AudioBankUnit> {
+    declared_units(tree)
+        .into_iter()
+        .map(|unit| {
+            let mut hierarchy = Hierarchy::default();
+            let mut media = HashMap::new();
+            let mut missing_banks = Vec::new();
+            for bank_path in &unit.bank_paths {
+                let Some(path) = resolve_bank(bin_dir, bank_path) else {
+                    missing_banks.push(bank_path.clone());
+                    continue;
+                };
+                if let Err(e) = load_bank(&path, bank_path, &mut hierarchy, &mut media) {
+                    tracing::warn!("Skipping unreadable bank {}: {}", path.display(), e);
+                    missing_banks.push(bank_path.clone());
+                }
+            }
+
+            let events = unit
+                .events
+                .into_iter()
+                .map(|name| {
+                    let event_id = wwise_short_id(&name);
+                    AudioEventMapping {
+                        defined: hierarchy.has_event(event_id),
+                        wems: hierarchy
+                            .event_media(event_id)
+                            .into_iter()
+                            .map(|id| AudioEventWem { id, container: media.get(&id).cloned() })
+                            .collect(),
+                        name,
+                        event_id,
+                    }
+                })
+                .collect();
+
+            AudioBankUnit {
+                name: unit.name,
+                voice_over: unit.voice_over,
+                bank_paths: unit.bank_paths,
+                missing_banks,
+                events,
+            }
+        })
+        .collect()
+}
+
+/// Reads a skin or audio BIN and maps the events of its bank units
+pub fn list_skin_audio_events(bin_path: &Path) -> Result<SkinAudioEvents> {
+    let data = fs::read(bin_path).map_err(|e| Error::io_with_path(e, bin_path))?;
+    let tree = ltk_bridge::read_bin(&data).map_err(|e| Error::bin_conversion_with_path(e.to_string(), bin_path))?;
+    let bin_dir = bin_path.parent().unwrap_or(Path::new("."));
+    Ok(SkinAudioEvents {
+        bin_path: bin_path.to_string_lossy().to_string(),
+        units: map_audio_events(&tree, bin_dir),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::audio::hirc::tests::{container, event, hierarchy_bank, play, sound};
+
+    #[test]
+    fn test_map_audio_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let sounds = dir.path().join("assets/sounds/ahri");
+        fs::create_dir_all(&sounds).unwrap();
+        let bank = hierarchy_bank(&[
+            container(50, 0),
+            sound(60, 1001, 50),
+            sound(61, 1002, 50),
+            play(40, 50),
+            event(wwise_short_id("Play_sfx_Ahri_Q"), &[40]),
+        ]);
+        fs::write(sounds.join("ahri_base_sfx_events.bnk"), bank).unwrap();
+
+        let mut package = WPK_MAGIC.to_vec();
+        package.extend_from_slice(&1u32.to_le_bytes());
+        package.extend_from_slice(&1u32.to_le_bytes());
+        package.extend_from_slice(&16u32.to_le_bytes());
+        package.extend_from_slice(&44u32.to_le_bytes());
+        package.extend_from_slice(&0u32.to_le_bytes());
+        package.extend_from_slice(&8u32.to_le_bytes());
+        package.extend("1001.wem".encode_utf16().flat_map(u16::to_le_bytes));
+        fs::write(sounds.join("ahri_base_sfx_audio.wpk"), package).unwrap();
+
+        let tree = ltk_bridge::text_to_tree(r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin0" = SkinCharacterDataProperties {
+        skinAudioProperties: embed = skinAudioProperties {
+            bankUnits: list2[embed] = {
+                BankUnit {
+                    name: string = "Ahri_Base_SFX"
+                    bankPath: list[string] = {
+                        "ASSETS/Sounds/Ahri/Ahri_Base_SFX_audio.wpk"
+                        "ASSETS/Sounds/Ahri/Ahri_Base_SFX_events.bnk"
+                    }
+                    events: list[string] = {
+                        "Play_sfx_Ahri_Q"
+                        "Play_sfx_Ahri_W"
+                    }
+                }
+                BankUnit {
+                    name: string = "Ahri_Base_VO"
+                    bankPath: list[string] = {
+                        "ASSETS/Sounds/Ahri/Ahri_Base_VO_audio.wpk"
+                    }
+                    voiceOver: bool = true
+                }
+            }
+        }
+    }
+}
+"#)
+        .unwrap();
+
+        let units = map_audio_events(&tree, &dir.path().join("data/characters/ahri/skins"));
+        assert_eq!(units.len(), 2);
+        let sfx = &units[0];
+        assert_eq!(sfx.name, "Ahri_Base_SFX");
+        assert!(sfx.missing_banks.is_empty());
+
+        let q = &sfx.events[0];
+        assert_eq!(q.event_id, wwise_short_id("play_sfx_ahri_q"));
+        assert!(q.defined);
+        let wems: Vec<_> = q.wems.iter().map(|w| (w.id, w.container.as_deref())).collect();
+        assert_eq!(
+            wems,
+            vec![(1001, Some("ASSETS/Sounds/Ahri/Ahri_Base_SFX_audio.wpk")), (1002, None)]
+        );
+        assert!(!sfx.events[1].defined && sfx.events[1].wems.is_empty());
+
+        assert!(units[1].voice_over);
+        assert_eq!(units[1].missing_banks, units[1].bank_paths);
+    }
+}
@@ -0,0 +1,310 @@
+//! Wwise object hierarchy (HIRC) resolution
+//!
+//! An event doesn't name its WEMs directly: it lists actions, a play action
+//! targets a sound or a container, and containers hold sounds (or more
+//! containers) through each child's parent id. Sounds finally carry the
+//! media id of their WEM. This module reads just enough of each object to
+//! follow that chain.
+//!
+//! The parent id sits behind a few fields whose presence changed between
+//! bank versions, so every plausible layout is tried and the one landing on
+//! a known object wins.
+
+use crate::error::Result;
+use byteorder::{ReadBytesExt, LE};
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+const HIRC_SOUND: u8 = 2;
+const HIRC_ACTION: u8 = 3;
+const HIRC_EVENT: u8 = 4;
+/// Random/sequence, switch, actor-mixer and layer containers
+const HIRC_CONTAINERS: [u8; 4] = [5, 6, 7, 9];
+const ACTION_PLAY: u16 = 0x0403;
+/// Plugin type of source plugins (tone generators, silence), which carry parameters
+const PLUGIN_SOURCE: u32 = 2;
+
+/// The objects of one or more banks that events resolve through
+#[derive(Debug, Clone, Default)]
+pub struct Hierarchy {
+    /// Action ids of each event
+    events: HashMap<u32, Vec<u32>>,
+    /// Target of each play action
+    actions: HashMap<u32, u32>,
+    /// WEM media id of each sound
+    sounds: HashMap<u32, u32>,
+    /// Direct children of each object
+    children: HashMap<u32, Vec<u32>>,
+}
+
+struct RawObject {
+    kind: u8,
+    id: u32,
+    body: Vec<u8>,
+}
+
+/// Wwise's variable-length integer: 7 bits per byte, high bit set while more follow
+fn read_var<R: Read>(reader: &mut R) -> std::io::Result<u32> {
+    let mut value = 0u32;
+    loop {
+        let byte = reader.read_u8()?;
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Reads the bank version and the HIRC objects of a BNK file
+fn read_objects<R: Read + Seek>(reader: &mut R) -> Result<(u32, Vec<RawObject>)> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut version = 0;
+    let mut objects = Vec::new();
+    let mut position = 0u64;
+    while position + 8 <= file_len {
+        let mut tag = [0u8; 4];
+        reader.read_exact(&mut tag)?;
+        let size = reader.read_u32::<LE>()? as u64;
+        let end = (position + 8 + size).min(file_len);
+
+        match &tag {
+            b"BKHD" => version = reader.read_u32::<LE>()?,
+            b"HIRC" => {
+                let count = reader.read_u32::<LE>()?;
+                for _ in 0..count {
+                    let kind = reader.read_u8()?;
+                    let object_size = reader.read_u32::<LE>()? as u64;
+                    if object_size < 4 || reader.stream_position()? + object_size > end {
+                        break;
+                    }
+                    let id = reader.read_u32::<LE>()?;
+                    let mut body = vec![0; object_size as usize - 4];
+                    reader.read_exact(&mut body)?;
+                    objects.push(RawObject { kind, id, body });
+                }
+            }
+            _ => {}
+        }
+
+        position = end;
+        reader.seek(SeekFrom::Start(position))?;
+    }
+    Ok((version, objects))
+}
+
+/// Candidate parent ids of a node, read from its NodeBaseParams at the cursor
+///
+/// Effects come first; the metadata effect list (newer banks) and the
+/// attachment override byte (older banks) may or may not follow.
+fn parent_candidates(body: &mut Cursor<&[u8]>, version: u32) -> Option<Vec<u32>> {
+    body.read_u8().ok()?;
+    let effects = body.read_u8().ok()? as i64;
+    if effects > 0 {
+        body.seek(SeekFrom::Current(1 + effects * 7)).ok()?;
+    }
+    let start = body.position();
+
+    let mut layouts = vec![(false, true), (false, false), (true, false), (true, true)];
+    if version > 136 {
+        layouts.rotate_left(2);
+    }
+    let mut parents = Vec::new();
+    for (metadata, attachment) in layouts {
+        body.set_position(start);
+        if metadata {
+            body.read_u8().ok()?;
+            let count = body.read_u8().ok()? as i64;
+            body.seek(SeekFrom::Current(count * 6)).ok()?;
+        }
+        if attachment {
+            body.read_u8().ok()?;
+        }
+        // Override bus, then the parent
+        if body.read_u32::<LE>().is_ok() {
+            if let Ok(parent) = body.read_u32::<LE>() {
+                parents.push(parent);
+            }
+        }
+    }
+    Some(parents)
+}
+
+impl Hierarchy {
+    /// Adds the HIRC objects of a BNK file
+    pub fn add_bank<R: Read + Seek>(&mut self, reader: &mut R) -> Result<()> {
+        let (version, objects) = read_objects(reader)?;
+        let known: HashSet<u32> = objects.iter().map(|object| object.id).collect();
+
+        for object in &objects {
+            let mut body = Cursor::new(object.body.as_slice());
+            let node = match object.kind {
+                HIRC_EVENT => {
+                    let count = if version <= 122 { body.read_u32::<LE>().ok() } else { read_var(&mut body).ok() };
+                    let actions = (0..count.unwrap_or(0)).map_while(|_| body.read_u32::<LE>().ok()).collect();
+                    self.events.insert(object.id, actions);
+                    continue;
+                }
+                HIRC_ACTION => {
+                    if let (Ok(ACTION_PLAY), Ok(target)) = (body.read_u16::<LE>(), body.read_u32::<LE>()) {
+                        self.actions.insert(object.id, target);
+                    }
+                    continue;
+                }
+                HIRC_SOUND => {
+                    let Ok(plugin) = body.read_u32::<LE>() else { continue };
+                    let (Ok(_stream), Ok(media)) = (body.read_u8(), body.read_u32::<LE>()) else { continue };
+                    self.sounds.insert(object.id, media);
+                    // Media size and bit flags, then plugin parameters for source plugins
+                    let skipped = body.read_u32::<LE>().and_then(|_| body.read_u8());
+                    if skipped.is_err() {
+                        continue;
+                    }
+                    if plugin & 0x0F == PLUGIN_SOURCE {
+                        let Ok(size) = body.read_u32::<LE>() else { continue };
+                        body.set_position(body.position() + size as u64);
+                    }
+                    parent_candidates(&mut body, version)
+                }
+                kind if HIRC_CONTAINERS.contains(&kind) => parent_candidates(&mut body, version),
+                _ => continue,
+            };
+
+            let parent = node
+                .unwrap_or_default()
+                .into_iter()
+                .find(|parent| *parent != object.id && known.contains(parent));
+            if let Some(parent) = parent {
+                self.children.entry(parent).or_default().push(object.id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether an event with this id was loaded
+    pub fn has_event(&self, event_id: u32) -> bool {
+        self.events.contains_key(&event_id)
+    }
+
+    /// Media ids of the WEMs an event can play, in the order they're reached
+    pub fn event_media(&self, event_id: u32) -> Vec<u32> {
+        let mut media = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending: Vec<u32> = self
+            .events
+            .get(&event_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|action| self.actions.get(action).copied())
+            .rev()
+            .collect();
+
+        while let Some(node) = pending.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            if let Some(&id) = self.sounds.get(&node) {
+                if !media.contains(&id) {
+                    media.push(id);
+                }
+            }
+            if let Some(children) = self.children.get(&node) {
+                pending.extend(children.iter().rev());
+            }
+        }
+        media
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn object(kind: u8, id: u32, body: &[u8]) -> Vec<u8> {
+        let mut out = vec![kind];
+        out.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+        out.extend_from_slice(&id.to_le_bytes());
+        out.extend_from_slice(body);
+        out
+    }
+
+    /// NodeBaseParams up to the parent id, in the layout of version 134 banks
+    fn node_base(parent: u32) -> Vec<u8> {
+        let mut out = vec![0, 0, 0];
+        out.extend_from_slice(&0u32.to_le_bytes());
+        out.extend_from_slice(&parent.to_le_bytes());
+        // Properties that follow and are never read
+        out.extend_from_slice(&[0; 12]);
+        out
+    }
+
+    pub(crate) fn sound(id: u32, media: u32, parent: u32) -> Vec<u8> {
+        let mut body = 0x0004_0001u32.to_le_bytes().to_vec();
+        body.push(0);
+        body.extend_from_slice(&media.to_le_bytes());
+        body.extend_from_slice(&100u32.to_le_bytes());
+        body.push(0);
+        body.extend(node_base(parent));
+        object(HIRC_SOUND, id, &body)
+    }
+
+    pub(crate) fn container(id: u32, parent: u32) -> Vec<u8> {
+        object(5, id, &node_base(parent))
+    }
+
+    pub(crate) fn play(id: u32, target: u32) -> Vec<u8> {
+        let mut body = ACTION_PLAY.to_le_bytes().to_vec();
+        body.extend_from_slice(&target.to_le_bytes());
+        body.extend_from_slice(&[0; 8]);
+        object(HIRC_ACTION, id, &body)
+    }
+
+    pub(crate) fn event(id: u32, actions: &[u32]) -> Vec<u8> {
+        let mut body = vec![actions.len() as u8];
+        body.extend(actions.iter().flat_map(|a| a.to_le_bytes()));
+        object(HIRC_EVENT, id, &body)
+    }
+
+    /// A bank with only BKHD and HIRC sections
+    pub(crate) fn hierarchy_bank(objects: &[Vec<u8>]) -> Vec<u8> {
+        let mut hirc = (objects.len() as u32).to_le_bytes().to_vec();
+        hirc.extend(objects.concat());
+        let mut bank = b"BKHD".to_vec();
+        bank.extend_from_slice(&8u32.to_le_bytes());
+        bank.extend_from_slice(&134u32.to_le_bytes());
+        bank.extend_from_slice(&0xABCDu32.to_le_bytes());
+        bank.extend_from_slice(b"HIRC");
+        bank.extend_from_slice(&(hirc.len() as u32).to_le_bytes());
+        bank.extend(hirc);
+        bank
+    }
+
+    #[test]
+    fn test_read_var() {
+        assert_eq!(read_var(&mut Cursor::new([0x05])).unwrap(), 5);
+        assert_eq!(read_var(&mut Cursor::new([0x81, 0x00])).unwrap(), 128);
+    }
+
+    #[test]
+    fn test_event_media_through_containers() {
+        let bank = hierarchy_bank(&[
+            container(50, 0),
+            container(51, 50),
+            sound(60, 1001, 51),
+            sound(61, 1002, 51),
+            sound(62, 1003, 0),
+            play(40, 50),
+            play(41, 62),
+            event(30, &[40, 41]),
+            event(31, &[41]),
+        ]);
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.add_bank(&mut Cursor::new(bank)).unwrap();
+
+        assert!(hierarchy.has_event(30));
+        assert_eq!(hierarchy.event_media(30), vec![1001, 1002, 1003]);
+        assert_eq!(hierarchy.event_media(31), vec![1003]);
+        assert!(hierarchy.event_media(99).is_empty());
+    }
+}
@@ -0,0 +1,405 @@
+//! Wwise audio containers
+//!
+//! Champion sounds ship as BNK sound banks (events plus short embedded
+//! WEMs) and WPK packages (the longer streamed WEMs, voice-over mostly).
+//! This module lists what a container holds and extracts the WEMs, turning
+//! them into WAV or Ogg files when the codec allows it.
+
+pub mod bnk;
+pub mod events;
+pub mod hirc;
+pub mod wem;
+pub mod wpk;
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use ts_rs::TS;
+use wem::{detect_codec, to_playable, wav_to_wem, WemCodec, HEADER_PROBE};
+
+/// Kind of audio container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioContainerKind {
+    Bnk,
+    Wpk,
+}
+
+/// A WEM inside a BNK or WPK
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioEntry {
+    /// Wwise media id; for WPK entries, parsed from the file name
+    pub id: Option<u32>,
+    /// File name the WEM is extracted under
+    pub name: String,
+    #[ts(type = "number")]
+    pub offset: u64,
+    pub size: u32,
+    pub codec: WemCodec,
+}
+
+/// Contents of a BNK or WPK file
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioContainerInfo {
+    pub kind: AudioContainerKind,
+    pub version: u32,
+    /// Sound bank id, for BNKs
+    pub bank_id: Option<u32>,
+    pub entries: Vec<AudioEntry>,
+    /// Ids of the bank's events; WPKs have none
+    pub event_ids: Vec<u32>,
+}
+
+/// Outcome of an audio extraction
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioExtractResult {
+    /// Written files, relative to the output folder
+    pub extracted: Vec<String>,
+    /// WEMs written as `.wem` because their codec couldn't be converted
+    pub unconverted: Vec<String>,
+}
+
+/// Outcome of replacing a WEM
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AudioReplaceResult {
+    pub entry: String,
+    pub old_size: u32,
+    pub new_size: u32,
+    pub codec: WemCodec,
+    /// Whether the source was a WAV converted to WEM
+    pub reencoded: bool,
+}
+
+fn open(path: &Path) -> Result<BufReader<File>> {
+    let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    Ok(BufReader::new(file))
+}
+
+fn read_range<R: Read + Seek>(reader: &mut R, offset: u64, len: usize) -> Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut data = vec![0; len];
+    reader.read_exact(&mut data)?;
+    Ok(data)
+}
+
+/// Lists the WEMs and events of a BNK or WPK file
+pub fn read_audio_container(path: &Path) -> Result<AudioContainerInfo> {
+    let mut reader = open(path)?;
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).map_err(|e| Error::io_with_path(e, path))?;
+
+    let mut info = match &magic {
+        b"BKHD" => {
+            let bank = bnk::read_bank(&mut reader)?;
+            AudioContainerInfo {
+                kind: AudioContainerKind::Bnk,
+                version: bank.version,
+                bank_id: Some(bank.bank_id),
+                entries: bank
+                    .wems
+                    .iter()
+                    .map(|wem| AudioEntry {
+                        id: Some(wem.id),
+                        name: format!("{}.wem", wem.id),
+                        offset: wem.offset,
+                        size: wem.size,
+                        codec: WemCodec::Unknown,
+                    })
+                    .collect(),
+                event_ids: bank.event_ids,
+            }
+        }
+        magic if magic == wpk::WPK_MAGIC => {
+            let package = wpk::read_package(&mut reader)?;
+            AudioContainerInfo {
+                kind: AudioContainerKind::Wpk,
+                version: package.version,
+                bank_id: None,
+                entries: package
+                    .wems
+                    .into_iter()
+                    .map(|wem| AudioEntry {
+                        id: wem.name.split('.').next().and_then(|stem| stem.parse().ok()),
+                        name: wem.name,
+                        offset: wem.offset,
+                        size: wem.size,
+                        codec: WemCodec::Unknown,
+                    })
+                    .collect(),
+                event_ids: Vec::new(),
+            }
+        }
+        _ => {
+            return Err(Error::InvalidInput(format!(
+                "Not a Wwise sound bank or WPK: {}",
+                path.display()
+            )))
+        }
+    };
+
+    for entry in &mut info.entries {
+        let header = read_range(&mut reader, entry.offset, HEADER_PROBE.min(entry.size as usize))?;
+        entry.codec = detect_codec(&header);
+    }
+    Ok(info)
+}
+
+/// Extracts the WEMs of a BNK or WPK file
+///
+/// # Arguments
+/// * `path` - The BNK or WPK file
+/// * `output_dir` - Folder the files are written to (created if needed)
+/// * `names` - Entries to extract by name, all of them if None
+/// * `convert` - Write PCM WEMs as WAV and Ogg streams as `.ogg`; other
+///   codecs are kept as `.wem` and listed in `unconverted`
+pub fn extract_audio(
+    path: &Path,
+    output_dir: &Path,
+    names: Option<&[String]>,
+    convert: bool,
+) -> Result<AudioExtractResult> {
+    let info = read_audio_container(path)?;
+    let mut reader = open(path)?;
+    fs::create_dir_all(output_dir).map_err(|e| Error::io_with_path(e, output_dir))?;
+
+    let mut result = AudioExtractResult::default();
+    for entry in &info.entries {
+        if names.is_some_and(|names| !names.contains(&entry.name)) {
+            continue;
+        }
+        let data = read_range(&mut reader, entry.offset, entry.size as usize)?;
+
+        let (name, data) = match convert.then(|| to_playable(&data)).flatten() {
+            Some((extension, converted)) => {
+                let stem = entry.name.rsplit_once('.').map_or(entry.name.as_str(), |(stem, _)| stem);
+                (format!("{}.{}", stem, extension), converted)
+            }
+            None => {
+                if convert {
+                    result.unconverted.push(entry.name.clone());
+                }
+                (entry.name.clone(), data)
+            }
+        };
+
+        // WPK names are plain file names; never let one leave the output folder
+        let file_name = Path::new(&name).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or(name);
+        let output = output_dir.join(&file_name);
+        fs::write(&output, data).map_err(|e| Error::io_with_path(e, &output))?;
+        result.extracted.push(file_name);
+    }
+    Ok(result)
+}
+
+/// Reads a replacement clip as WEM bytes
+///
+/// `.wem` files are taken as they are; WAV files are wrapped as PCM WEM,
+/// which only works for 16-bit PCM. Encoding to Vorbis needs Wwise itself.
+fn load_clip(source: &Path) -> Result<(Vec<u8>, bool)> {
+    let data = fs::read(source).map_err(|e| Error::io_with_path(e, source))?;
+    let is_wem = source
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("wem"));
+    if is_wem {
+        if !data.starts_with(b"RIFF") && !data.starts_with(b"OggS") {
+            return Err(Error::InvalidInput(format!("Not a WEM file: {}", source.display())));
+        }
+        return Ok((data, false));
+    }
+    match wav_to_wem(&data) {
+        Some(wem) => Ok((wem, true)),
+        None => Err(Error::InvalidInput(format!(
+            "Unsupported audio file: {}. Use a .wem or a 16-bit PCM WAV (convert other formats to WAV first)",
+            source.display()
+        ))),
+    }
+}
+
+/// Replaces one WEM of a BNK or WPK file
+///
+/// The container is rebuilt around the new data, so the offsets and sizes
+/// of every entry after it are updated.
+///
+/// # Arguments
+/// * `path` - The BNK or WPK file, rewritten in place
+/// * `entry` - Name of the entry to replace, as listed by [`read_audio_container`]
+/// * `source` - A `.wem` file, or a 16-bit PCM WAV to wrap as WEM
+pub fn replace_audio_clip(path: &Path, entry: &str, source: &Path) -> Result<AudioReplaceResult> {
+    let info = read_audio_container(path)?;
+    let target = info
+        .entries
+        .iter()
+        .find(|e| e.name == entry)
+        .ok_or_else(|| Error::InvalidInput(format!("No entry named {} in {}", entry, path.display())))?;
+    let (clip, reencoded) = load_clip(source)?;
+    let new_size = u32::try_from(clip.len())
+        .map_err(|_| Error::InvalidInput(format!("Audio file is too large: {}", source.display())))?;
+
+    let original = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    let rebuilt = match (info.kind, target.id) {
+        (AudioContainerKind::Bnk, Some(id)) => {
+            bnk::rebuild_bank(&original, |wem_id| (wem_id == id).then(|| clip.clone()))?
+        }
+        _ => wpk::rebuild_package(&original, |name| (name == entry).then(|| clip.clone()))?,
+    };
+    crate::core::store::write_replacing(path, &rebuilt).map_err(|e| Error::io_with_path(e, path))?;
+
+    Ok(AudioReplaceResult {
+        entry: entry.to_string(),
+        old_size: target.size,
+        new_size,
+        codec: detect_codec(&clip[..HEADER_PROBE.min(clip.len())]),
+        reencoded,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wem::tests::pcm_wem;
+
+    fn section(tag: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut out = tag.to_vec();
+        out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        out.extend_from_slice(body);
+        out
+    }
+
+    fn words(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    fn build_bank(wems: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut didx = Vec::new();
+        let mut data = Vec::new();
+        for (id, wem) in wems {
+            didx.extend(words(&[*id, data.len() as u32, wem.len() as u32]));
+            data.extend_from_slice(wem);
+            // Embedded WEMs are 16-byte aligned
+            data.resize(data.len().next_multiple_of(16), 0);
+        }
+        let mut hirc = words(&[2]);
+        for (kind, id) in [(2u8, 100u32), (4, 200)] {
+            hirc.push(kind);
+            hirc.extend(words(&[8, id, 0]));
+        }
+
+        let mut bank = section(b"BKHD", &words(&[145, 0xABCD, 0, 0]));
+        bank.extend(section(b"DIDX", &didx));
+        bank.extend(section(b"DATA", &data));
+        bank.extend(section(b"HIRC", &hirc));
+        bank
+    }
+
+    fn build_package(wems: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut table = Vec::new();
+        let header_len = 12 + 4 * wems.len();
+        let entries_len: usize = wems.iter().map(|(name, _)| 12 + name.len() * 2).sum();
+        let mut data_offset = header_len + entries_len;
+        let mut offsets = Vec::new();
+        for (name, wem) in wems {
+            offsets.push((header_len + table.len()) as u32);
+            table.extend(words(&[data_offset as u32, wem.len() as u32, name.len() as u32]));
+            table.extend(name.encode_utf16().flat_map(u16::to_le_bytes));
+            data_offset += wem.len();
+        }
+
+        let mut package = b"r3d2".to_vec();
+        package.extend(words(&[1, wems.len() as u32]));
+        package.extend(words(&offsets));
+        package.extend(table);
+        for (_, wem) in wems {
+            package.extend_from_slice(wem);
+        }
+        package
+    }
+
+    #[test]
+    fn test_list_and_extract_bank_and_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let pcm = pcm_wem(&[1, 0, 2, 0]);
+        let mut vorbis = pcm_wem(&[9; 6]);
+        vorbis[20..22].copy_from_slice(&0xFFFFu16.to_le_bytes());
+
+        let bank_path = dir.path().join("ahri_base_sfx_audio.bnk");
+        fs::write(&bank_path, build_bank(&[(11, pcm.clone()), (22, vorbis.clone())])).unwrap();
+        let info = read_audio_container(&bank_path).unwrap();
+        assert_eq!(info.kind, AudioContainerKind::Bnk);
+        assert_eq!((info.version, info.bank_id), (145, Some(0xABCD)));
+        assert_eq!(info.event_ids, vec![200]);
+        let codecs: Vec<_> = info.entries.iter().map(|e| (e.name.as_str(), e.codec)).collect();
+        assert_eq!(codecs, vec![("11.wem", WemCodec::Pcm), ("22.wem", WemCodec::Vorbis)]);
+
+        let out = dir.path().join("out");
+        let result = extract_audio(&bank_path, &out, None, true).unwrap();
+        assert_eq!(result.extracted, vec!["11.wav", "22.wem"]);
+        assert_eq!(result.unconverted, vec!["22.wem"]);
+        assert_eq!(fs::read(out.join("22.wem")).unwrap(), vorbis);
+        assert_eq!(&fs::read(out.join("11.wav")).unwrap()[44..], &[1, 0, 2, 0]);
+
+        let package_path = dir.path().join("ahri_base_vo_audio.wpk");
+        fs::write(&package_path, build_package(&[("33.wem", vorbis.clone()), ("44.wem", pcm.clone())])).unwrap();
+        let info = read_audio_container(&package_path).unwrap();
+        assert_eq!(info.kind, AudioContainerKind::Wpk);
+        assert_eq!(info.entries[1].id, Some(44));
+        assert_eq!(info.entries[1].codec, WemCodec::Pcm);
+
+        let only = vec!["44.wem".to_string()];
+        let result = extract_audio(&package_path, &out, Some(&only), false).unwrap();
+        assert_eq!(result.extracted, vec!["44.wem"]);
+        assert_eq!(fs::read(out.join("44.wem")).unwrap(), pcm);
+    }
+
+    #[test]
+    fn test_replace_audio_clip_in_bank_and_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = pcm_wem(&[1, 0, 2, 0]);
+        let second = pcm_wem(&[3, 0, 4, 0, 5, 0]);
+
+        // A WAV source is wrapped as PCM WEM
+        let wav_path = dir.path().join("new.wav");
+        let (_, wav) = to_playable(&pcm_wem(&[7; 80])).unwrap();
+        fs::write(&wav_path, &wav).unwrap();
+
+        let bank_path = dir.path().join("sfx.bnk");
+        fs::write(&bank_path, build_bank(&[(11, first.clone()), (22, second.clone())])).unwrap();
+        let result = replace_audio_clip(&bank_path, "11.wem", &wav_path).unwrap();
+        assert!(result.reencoded);
+        assert_eq!(result.codec, WemCodec::Pcm);
+        assert_eq!(result.old_size, first.len() as u32);
+
+        let info = read_audio_container(&bank_path).unwrap();
+        assert_eq!(info.event_ids, vec![200]);
+        assert_eq!(info.entries[0].size, result.new_size);
+        assert_eq!(info.entries[1].offset % 16, info.entries[0].offset % 16);
+        let out = dir.path().join("bank_out");
+        extract_audio(&bank_path, &out, None, true).unwrap();
+        assert_eq!(fs::read(out.join("11.wav")).unwrap(), wav);
+        assert_eq!(&fs::read(out.join("22.wav")).unwrap()[44..], &[3, 0, 4, 0, 5, 0]);
+
+        // A WEM source is copied as is
+        let wem_path = dir.path().join("new.wem");
+        let replacement = pcm_wem(&[8; 30]);
+        fs::write(&wem_path, &replacement).unwrap();
+        let package_path = dir.path().join("vo.wpk");
+        fs::write(&package_path, build_package(&[("33.wem", first.clone()), ("44.wem", second.clone())])).unwrap();
+        let result = replace_audio_clip(&package_path, "33.wem", &wem_path).unwrap();
+        assert!(!result.reencoded);
+
+        let out = dir.path().join("package_out");
+        extract_audio(&package_path, &out, None, false).unwrap();
+        assert_eq!(fs::read(out.join("33.wem")).unwrap(), replacement);
+        assert_eq!(fs::read(out.join("44.wem")).unwrap(), second);
+
+        assert!(replace_audio_clip(&package_path, "55.wem", &wem_path).is_err());
+        fs::write(dir.path().join("new.mp3"), b"ID3").unwrap();
+        assert!(replace_audio_clip(&package_path, "33.wem", &dir.path().join("new.mp3")).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+//! WEM stream inspection
+//!
+//! A WEM is a RIFF/WAVE file whose `fmt ` chunk names a Wwise codec. Only
+//! PCM streams can become plain WAV files here: Wwise Vorbis needs the
+//! codebook rebuild done by ww2ogg, which Flint doesn't ship, so those stay
+//! `.wem`.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Bytes read from the start of a WEM to detect its codec
+pub const HEADER_PROBE: usize = 64;
+
+/// Audio codec of a WEM stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum WemCodec {
+    Pcm,
+    Adpcm,
+    /// Wwise Vorbis (needs ww2ogg to play)
+    Vorbis,
+    Opus,
+    /// A plain Ogg stream instead of RIFF, as written by some mod tools
+    Ogg,
+    Unknown,
+}
+
+/// The `fmt ` fields needed to write a WAV header
+#[derive(Debug, Clone, Copy)]
+struct WaveFormat {
+    tag: u16,
+    channels: u16,
+    sample_rate: u32,
+    block_align: u16,
+    bits_per_sample: u16,
+}
+
+fn u16_at(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn u32_at(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// Iterates the (id, body) chunks of a RIFF/WAVE file; a truncated last
+/// chunk is cut to the data available
+fn riff_chunks(data: &[u8]) -> impl Iterator<Item = (&[u8], &[u8])> {
+    let is_riff = data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WAVE";
+    let mut at = if is_riff { 12 } else { data.len() };
+    std::iter::from_fn(move || {
+        let id = data.get(at..at + 4)?;
+        let size = u32_at(data, at + 4)? as usize;
+        let start = at + 8;
+        let end = start.saturating_add(size).min(data.len());
+        // Chunks are padded to an even size
+        at = start.saturating_add(size + (size & 1));
+        Some((id, &data[start..end]))
+    })
+}
+
+fn wave_format(data: &[u8]) -> Option<WaveFormat> {
+    let (_, fmt) = riff_chunks(data).find(|(id, _)| *id == b"fmt ")?;
+    Some(WaveFormat {
+        tag: u16_at(fmt, 0)?,
+        channels: u16_at(fmt, 2)?,
+        sample_rate: u32_at(fmt, 4)?,
+        block_align: u16_at(fmt, 12)?,
+        bits_per_sample: u16_at(fmt, 14)?,
+    })
+}
+
+/// Detects the codec from the start of a WEM (see [`HEADER_PROBE`])
+pub fn detect_codec(header: &[u8]) -> WemCodec {
+    if header.starts_with(b"OggS") {
+        return WemCodec::Ogg;
+    }
+    match wave_format(header).map(|format| format.tag) {
+        Some(0x0001) => WemCodec::Pcm,
+        // Wwise writes PCM as WAVE_FORMAT_EXTENSIBLE
+        Some(0xFFFE) => WemCodec::Pcm,
+        Some(0x0002) => WemCodec::Adpcm,
+        Some(0xFFFF) => WemCodec::Vorbis,
+        Some(0x3040) | Some(0x3041) => WemCodec::Opus,
+        _ => WemCodec::Unknown,
+    }
+}
+
+/// Rewrites a PCM WEM as a standard 44-byte-header WAV file
+pub fn wem_to_wav(data: &[u8]) -> Option<Vec<u8>> {
+    if detect_codec(data) != WemCodec::Pcm {
+        return None;
+    }
+    let format = wave_format(data)?;
+    let (_, samples) = riff_chunks(data).find(|(id, _)| *id == b"data")?;
+
+    let mut wav = Vec::with_capacity(44 + samples.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + samples.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&format.channels.to_le_bytes());
+    wav.extend_from_slice(&format.sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(format.sample_rate * format.block_align as u32).to_le_bytes());
+    wav.extend_from_slice(&format.block_align.to_le_bytes());
+    wav.extend_from_slice(&format.bits_per_sample.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+    wav.extend_from_slice(samples);
+    Some(wav)
+}
+
+/// Wraps 16-bit PCM WAV samples in a Wwise PCM WEM
+///
+/// Wwise stores PCM as WAVE_FORMAT_EXTENSIBLE with a short extension
+/// (valid bits and channel mask). Returns None for anything but 16-bit PCM.
+pub fn wav_to_wem(data: &[u8]) -> Option<Vec<u8>> {
+    let format = wave_format(data)?;
+    if !matches!(format.tag, 0x0001 | 0xFFFE) || format.bits_per_sample != 16 || format.channels == 0 {
+        return None;
+    }
+    let (_, samples) = riff_chunks(data).find(|(id, _)| *id == b"data")?;
+    let channel_mask: u32 = match format.channels {
+        1 => 0x4,
+        2 => 0x3,
+        n => (1u32 << n.min(31)) - 1,
+    };
+
+    let mut fmt = Vec::with_capacity(24);
+    fmt.extend_from_slice(&0xFFFEu16.to_le_bytes());
+    fmt.extend_from_slice(&format.channels.to_le_bytes());
+    fmt.extend_from_slice(&format.sample_rate.to_le_bytes());
+    fmt.extend_from_slice(&(format.sample_rate * format.block_align as u32).to_le_bytes());
+    fmt.extend_from_slice(&format.block_align.to_le_bytes());
+    fmt.extend_from_slice(&16u16.to_le_bytes());
+    fmt.extend_from_slice(&6u16.to_le_bytes());
+    fmt.extend_from_slice(&16u16.to_le_bytes());
+    fmt.extend_from_slice(&channel_mask.to_le_bytes());
+
+    let mut wem = Vec::with_capacity(12 + 8 + fmt.len() + 8 + samples.len() + 1);
+    wem.extend_from_slice(b"RIFF\0\0\0\0WAVEfmt ");
+    wem.extend_from_slice(&(fmt.len() as u32).to_le_bytes());
+    wem.extend_from_slice(&fmt);
+    wem.extend_from_slice(b"data");
+    wem.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+    wem.extend_from_slice(samples);
+    if samples.len() % 2 == 1 {
+        wem.push(0);
+    }
+    let riff_size = (wem.len() - 8) as u32;
+    wem[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Some(wem)
+}
+
+/// Converts a WEM to a format common players open
+///
+/// # Returns
+/// The file extension and contents, or None if the codec can't be converted
+pub fn to_playable(data: &[u8]) -> Option<(&'static str, Vec<u8>)> {
+    match detect_codec(data) {
+        WemCodec::Ogg => Some(("ogg", data.to_vec())),
+        WemCodec::Pcm => wem_to_wav(data).map(|wav| ("wav", wav)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A Wwise-style PCM WEM: extensible format tag and an extra chunk
+    pub(crate) fn pcm_wem(samples: &[u8]) -> Vec<u8> {
+        let mut fmt = Vec::new();
+        fmt.extend_from_slice(&0xFFFEu16.to_le_bytes());
+        fmt.extend_from_slice(&2u16.to_le_bytes());
+        fmt.extend_from_slice(&48000u32.to_le_bytes());
+        fmt.extend_from_slice(&(48000u32 * 4).to_le_bytes());
+        fmt.extend_from_slice(&4u16.to_le_bytes());
+        fmt.extend_from_slice(&16u16.to_le_bytes());
+        fmt.extend_from_slice(&[0; 8]);
+
+        let mut body = b"WAVE".to_vec();
+        for (id, chunk) in [(b"fmt ", &fmt[..]), (b"akd ", &[1, 2, 3][..]), (b"data", samples)] {
+            body.extend_from_slice(id);
+            body.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+            body.extend_from_slice(chunk);
+            if chunk.len() % 2 == 1 {
+                body.push(0);
+            }
+        }
+        let mut wem = b"RIFF".to_vec();
+        wem.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        wem.extend_from_slice(&body);
+        wem
+    }
+
+    #[test]
+    fn test_pcm_wem_to_wav() {
+        let wem = pcm_wem(&[1, 0, 2, 0, 3, 0, 4, 0]);
+        assert_eq!(detect_codec(&wem[..HEADER_PROBE.min(wem.len())]), WemCodec::Pcm);
+
+        let (extension, wav) = to_playable(&wem).unwrap();
+        assert_eq!(extension, "wav");
+        assert_eq!(wav.len(), 44 + 8);
+        assert_eq!(u16_at(&wav, 20), Some(1));
+        assert_eq!(u32_at(&wav, 28), Some(48000 * 4));
+        assert_eq!(&wav[44..], &[1, 0, 2, 0, 3, 0, 4, 0]);
+
+        // WAV -> WEM -> WAV keeps the samples
+        let rewrapped = wav_to_wem(&wav).unwrap();
+        assert_eq!(detect_codec(&rewrapped), WemCodec::Pcm);
+        assert_eq!(wem_to_wav(&rewrapped).unwrap(), wav);
+
+        let mut vorbis = wem.clone();
+        vorbis[20..22].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        assert_eq!(detect_codec(&vorbis), WemCodec::Vorbis);
+        assert!(to_playable(&vorbis).is_none());
+    }
+}
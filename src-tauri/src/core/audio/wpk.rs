@@ -0,0 +1,117 @@
+//! Riot WPK package reading
+//!
+//! WPKs hold the WEMs a bank streams (voice-over mostly): `r3d2`, a
+//! version, the entry count and an offset per entry. Each entry is the data
+//! offset and size followed by its UTF-16 file name, usually `{wem id}.wem`.
+
+use crate::error::{Error, Result};
+use byteorder::{ReadBytesExt, LE};
+use std::io::{Read, Seek, SeekFrom};
+
+pub const WPK_MAGIC: &[u8; 4] = b"r3d2";
+
+/// A WEM stored in a WPK
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageWem {
+    pub name: String,
+    pub offset: u64,
+    pub size: u32,
+}
+
+/// The entries of a WPK
+#[derive(Debug, Clone, Default)]
+pub struct PackageIndex {
+    pub version: u32,
+    pub wems: Vec<PackageWem>,
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::InvalidInput(format!("Invalid WPK: {}", message.into()))
+}
+
+/// Reads the entry table of a WPK file
+pub fn read_package<R: Read + Seek>(reader: &mut R) -> Result<PackageIndex> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != WPK_MAGIC {
+        return Err(invalid("missing r3d2 magic"));
+    }
+    let version = reader.read_u32::<LE>()?;
+    let count = reader.read_u32::<LE>()? as u64;
+    if 12 + count * 4 > file_len {
+        return Err(invalid(format!("{} entries don't fit in the file", count)));
+    }
+    let offsets = (0..count).map(|_| reader.read_u32::<LE>()).collect::<std::io::Result<Vec<_>>>()?;
+
+    let mut wems = Vec::with_capacity(offsets.len());
+    // Removed entries keep a zero offset
+    for offset in offsets.into_iter().filter(|offset| *offset != 0) {
+        reader.seek(SeekFrom::Start(offset as u64))?;
+        let data_offset = reader.read_u32::<LE>()? as u64;
+        let size = reader.read_u32::<LE>()?;
+        let name_len = reader.read_u32::<LE>()? as usize;
+        if data_offset + size as u64 > file_len || name_len > 1024 {
+            return Err(invalid(format!("entry at 0x{:x} is out of bounds", offset)));
+        }
+        let name = (0..name_len).map(|_| reader.read_u16::<LE>()).collect::<std::io::Result<Vec<_>>>()?;
+
+        wems.push(PackageWem {
+            name: String::from_utf16_lossy(&name),
+            offset: data_offset,
+            size,
+        });
+    }
+
+    Ok(PackageIndex { version, wems })
+}
+
+/// Rebuilds a package with some of its WEMs replaced
+///
+/// Entries keep their order and names; data is laid out after the entry
+/// table, 8-byte aligned.
+///
+/// # Arguments
+/// * `package` - The whole original package
+/// * `replace` - New data for an entry name, or None to keep the WEM
+pub fn rebuild_package(package: &[u8], replace: impl Fn(&str) -> Option<Vec<u8>>) -> Result<Vec<u8>> {
+    let index = read_package(&mut std::io::Cursor::new(package))?;
+
+    let header_len = 12 + 4 * index.wems.len();
+    let table_len: usize = index.wems.iter().map(|wem| 12 + wem.name.encode_utf16().count() * 2).sum();
+    let mut data_offset = (header_len + table_len).next_multiple_of(8);
+
+    let mut offsets = Vec::with_capacity(index.wems.len());
+    let mut table = Vec::with_capacity(table_len);
+    let mut data = Vec::new();
+    for wem in &index.wems {
+        let bytes = match replace(&wem.name) {
+            Some(new) => new,
+            None => package[wem.offset as usize..wem.offset as usize + wem.size as usize].to_vec(),
+        };
+        offsets.push((header_len + table.len()) as u32);
+        table.extend_from_slice(&(data_offset as u32).to_le_bytes());
+        table.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        table.extend_from_slice(&(wem.name.encode_utf16().count() as u32).to_le_bytes());
+        table.extend(wem.name.encode_utf16().flat_map(u16::to_le_bytes));
+
+        let padded = bytes.len().next_multiple_of(8);
+        data.extend_from_slice(&bytes);
+        data.resize(data.len() + padded - bytes.len(), 0);
+        data_offset += padded;
+    }
+
+    let mut out = Vec::with_capacity(header_len + table_len + 8 + data.len());
+    out.extend_from_slice(WPK_MAGIC);
+    out.extend_from_slice(&index.version.to_le_bytes());
+    out.extend_from_slice(&(index.wems.len() as u32).to_le_bytes());
+    for offset in offsets {
+        out.extend_from_slice(&offset.to_le_bytes());
+    }
+    out.extend_from_slice(&table);
+    out.resize(out.len().next_multiple_of(8), 0);
+    out.extend_from_slice(&data);
+    Ok(out)
+}
@@ -0,0 +1,284 @@
+//! Performance benchmarks for regression reports
+//!
+//! When a user reports that loading got slow, their numbers only mean
+//! something next to a known machine. The benchmarks generate the same
+//! sample data on every run (a hash list, a WAD, a BIN), time the hot paths
+//! on it and report the machine they ran on. They run from the hidden
+//! `run_benchmarks` command and from the `benchmarks` binary.
+
+use crate::core::bin::ltk_bridge::{read_bin, text_to_tree, tree_to_text, write_bin};
+use crate::core::hash::Hashtable;
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use league_toolkit::wad::{WadBuilder, WadChunkBuilder};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+use ts_rs::TS;
+use xxhash_rust::xxh64::xxh64;
+
+/// Entries in the sample hash list
+const HASH_ENTRIES: usize = 200_000;
+/// Chunks in the sample WAD
+const WAD_CHUNKS: usize = 256;
+/// Size of each sample WAD chunk
+const WAD_CHUNK_SIZE: usize = 64 * 1024;
+/// Objects in the sample BIN
+const BIN_OBJECTS: usize = 2_000;
+
+/// The machine a benchmark ran on
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MachineProfile {
+    pub os: String,
+    pub arch: String,
+    pub cpu_model: Option<String>,
+    pub logical_cores: usize,
+    pub app_version: String,
+    /// Debug builds are several times slower; their numbers don't compare
+    pub release_build: bool,
+}
+
+impl MachineProfile {
+    pub fn current() -> Self {
+        Self {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpu_model: cpu_model(),
+            logical_cores: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            release_build: !cfg!(debug_assertions),
+        }
+    }
+}
+
+/// CPU name from /proc/cpuinfo, where available
+fn cpu_model() -> Option<String> {
+    let cpuinfo = fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .find(|line| line.starts_with("model name"))
+        .and_then(|line| line.split_once(':'))
+        .map(|(_, name)| name.trim().to_string())
+}
+
+/// Timings of one benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BenchmarkResult {
+    pub name: String,
+    /// What one iteration processes
+    pub workload: String,
+    pub iterations: u32,
+    pub mean_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BenchmarkReport {
+    pub machine: MachineProfile,
+    pub results: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkReport {
+    /// Plain-text table for pasting into an issue
+    pub fn to_text(&self) -> String {
+        let m = &self.machine;
+        let mut text = format!(
+            "Flint {} ({} build) on {} {}, {} cores, {}\n",
+            m.app_version,
+            if m.release_build { "release" } else { "debug" },
+            m.os,
+            m.arch,
+            m.logical_cores,
+            m.cpu_model.as_deref().unwrap_or("unknown CPU"),
+        );
+        for r in &self.results {
+            let _ = writeln!(
+                text,
+                "{:<16} {:>9.2} ms mean {:>9.2} min {:>9.2} max  ({} x {})",
+                r.name, r.mean_ms, r.min_ms, r.max_ms, r.iterations, r.workload
+            );
+        }
+        text
+    }
+}
+
+/// Runs `f` once to warm up, then `iterations` times
+fn measure(name: &str, workload: String, iterations: u32, mut f: impl FnMut() -> Result<()>) -> Result<BenchmarkResult> {
+    f()?;
+    let mut times = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        f()?;
+        times.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    Ok(BenchmarkResult {
+        name: name.to_string(),
+        workload,
+        iterations,
+        mean_ms: times.iter().sum::<f64>() / times.len().max(1) as f64,
+        min_ms: times.iter().copied().fold(f64::INFINITY, f64::min),
+        max_ms: times.iter().copied().fold(0.0, f64::max),
+    })
+}
+
+fn sample_path(i: usize) -> String {
+    format!("assets/characters/sample{}/skins/skin{:02}/particles/sample_{:06}.dds", i % 170, i % 60, i)
+}
+
+fn write_hash_list(dir: &Path, entries: usize) -> Result<()> {
+    let path = dir.join("hashes.game.txt");
+    let file = fs::File::create(&path).map_err(|e| Error::io_with_path(e, &path))?;
+    let mut out = BufWriter::new(file);
+    for i in 0..entries {
+        let name = sample_path(i);
+        writeln!(out, "{:016x} {}", xxh64(name.as_bytes(), 0), name).map_err(|e| Error::io_with_path(e, &path))?;
+    }
+    out.flush().map_err(|e| Error::io_with_path(e, &path))
+}
+
+/// Chunk contents that compress about as well as textures do
+fn sample_chunk(i: usize, size: usize) -> Vec<u8> {
+    let mut state = xxh64(&i.to_le_bytes(), 0);
+    (0..size)
+        .map(|n| {
+            if n % 4 == 0 {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            }
+            (state >> 56) as u8 & 0x3f
+        })
+        .collect()
+}
+
+fn write_wad(path: &Path, chunks: usize, chunk_size: usize) -> Result<()> {
+    let names: Vec<String> = (0..chunks).map(sample_path).collect();
+    let mut builder = WadBuilder::default();
+    for name in &names {
+        builder = builder.with_chunk(WadChunkBuilder::default().with_path(name));
+    }
+    let file = fs::File::create(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mut out = BufWriter::new(file);
+    builder
+        .build_to_writer(&mut out, |hash, out| {
+            let index = names.iter().position(|name| xxh64(name.as_bytes(), 0) == hash).unwrap_or(0);
+            out.write_all(&sample_chunk(index, chunk_size))?;
+            Ok(())
+        })
+        .map_err(|e| Error::wad_with_path(format!("Failed to build sample WAD: {}", e), path))?;
+    out.flush().map_err(|e| Error::io_with_path(e, path))
+}
+
+/// Ritobin text for a BIN shaped like a particle-heavy skin BIN
+fn sample_bin_text(objects: usize) -> String {
+    let mut text = String::from("#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nlinked: list[string] = {}\nentries: map[hash,embed] = {\n");
+    for i in 0..objects {
+        let _ = write!(
+            text,
+            r#"    "Characters/Sample/Skins/Skin0/Particles/System{i}" = VfxSystemDefinitionData {{
+        particleName: string = "Sample_System{i}"
+        complexEmitterDefinitionData: list[pointer] = {{
+            VfxEmitterDefinitionData {{
+                emitterName: string = "emitter{i}"
+                rate: embed = ValueFloat {{
+                    constantValue: f32 = {rate}
+                }}
+                color: embed = ValueColor {{
+                    constantValue: vec4 = {{ 1, 0.5, 0.25, 1 }}
+                }}
+                texture: string = "{texture}"
+            }}
+        }}
+    }}
+"#,
+            rate = i % 50,
+            texture = sample_path(i),
+        );
+    }
+    text.push_str("}\n");
+    text
+}
+
+/// Runs every benchmark
+///
+/// # Arguments
+/// * `iterations` - Timed runs per benchmark, after one warm-up run
+pub fn run_benchmarks(iterations: u32) -> Result<BenchmarkReport> {
+    let dir = std::env::temp_dir().join(format!("flint-bench-{}", std::process::id()));
+    fs::create_dir_all(&dir).map_err(|e| Error::io_with_path(e, &dir))?;
+    let report = run_in(&dir, iterations.max(1));
+    let _ = fs::remove_dir_all(&dir);
+    report
+}
+
+fn run_in(dir: &Path, iterations: u32) -> Result<BenchmarkReport> {
+    let mut results = Vec::new();
+
+    // Hashtable load
+    let hash_dir = dir.join("hashes");
+    fs::create_dir_all(&hash_dir).map_err(|e| Error::io_with_path(e, &hash_dir))?;
+    write_hash_list(&hash_dir, HASH_ENTRIES)?;
+    results.push(measure("hashtable_load", format!("{} paths", HASH_ENTRIES), iterations, || {
+        Hashtable::from_directory(&hash_dir).map(drop)
+    })?);
+
+    // WAD chunk decode
+    let wad_path = dir.join("sample.wad.client");
+    write_wad(&wad_path, WAD_CHUNKS, WAD_CHUNK_SIZE)?;
+    let workload = format!("{} chunks of {} KiB", WAD_CHUNKS, WAD_CHUNK_SIZE / 1024);
+    results.push(measure("wad_decode", workload, iterations, || {
+        let mut reader = WadReader::open(&wad_path)?;
+        let chunks: Vec<_> = reader.chunks().values().copied().collect();
+        let (mut decoder, _) = reader.wad_mut().decode();
+        for chunk in &chunks {
+            decoder
+                .load_chunk_decompressed(chunk)
+                .map_err(|e| Error::wad_with_path(format!("Failed to decompress chunk: {}", e), &wad_path))?;
+        }
+        Ok(())
+    })?);
+
+    // BIN parse and text conversion
+    let text = sample_bin_text(BIN_OBJECTS);
+    let tree = text_to_tree(&text).map_err(|e| Error::InvalidInput(e.to_string()))?;
+    let data = write_bin(&tree).map_err(|e| Error::InvalidInput(e.to_string()))?;
+    let workload = format!("{} objects, {} KiB", BIN_OBJECTS, data.len() / 1024);
+    results.push(measure("bin_parse", workload.clone(), iterations, || {
+        read_bin(&data).map(drop).map_err(|e| Error::InvalidInput(e.to_string()))
+    })?);
+    results.push(measure("bin_to_text", workload.clone(), iterations, || {
+        tree_to_text(&tree).map(drop).map_err(|e| Error::InvalidInput(e.to_string()))
+    })?);
+    results.push(measure("text_to_bin", workload, iterations, || {
+        text_to_tree(&text).map(drop).map_err(|e| Error::InvalidInput(e.to_string()))
+    })?);
+
+    Ok(BenchmarkReport { machine: MachineProfile::current(), results })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_data_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        write_wad(&dir.path().join("sample.wad.client"), 4, 1024).unwrap();
+        let reader = WadReader::open(dir.path().join("sample.wad.client")).unwrap();
+        assert_eq!(reader.chunk_count(), 4);
+
+        let tree = text_to_tree(&sample_bin_text(3)).unwrap();
+        assert_eq!(tree.objects.len(), 3);
+
+        let report = BenchmarkReport {
+            machine: MachineProfile::current(),
+            results: vec![measure("noop", "nothing".into(), 2, || Ok(())).unwrap()],
+        };
+        assert!(report.to_text().contains("noop"));
+    }
+}
@@ -0,0 +1,103 @@
+//! Reconciliation of `.ritobin` text caches
+//!
+//! Converted BIN text is cached next to its source as `{file}.bin.ritobin`.
+//! When repath or cleanup moves or removes a BIN, the cache stays behind and
+//! could later be picked up for an unrelated file at the same path, so caches
+//! without a source are removed.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Extension of cached BIN text files
+pub const CACHE_EXTENSION: &str = "ritobin";
+
+/// Returns the BIN a cache was converted from (`foo.bin.ritobin` -> `foo.bin`)
+///
+/// Other `.ritobin` files are text the user wrote, not caches.
+fn cache_source(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    if !name.to_ascii_lowercase().ends_with(".bin.ritobin") {
+        return None;
+    }
+    let source = &name[..name.len() - CACHE_EXTENSION.len() - 1];
+    (source.len() > ".bin".len()).then(|| path.with_file_name(source))
+}
+
+/// Result of removing orphaned caches
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct OrphanCacheReport {
+    /// Removed cache files, relative to the scanned directory
+    pub removed: Vec<String>,
+    /// Total size of the removed files in bytes
+    #[ts(type = "number")]
+    pub reclaimed_bytes: u64,
+}
+
+/// Removes `.bin.ritobin` caches whose source BIN no longer exists
+///
+/// # Arguments
+/// * `dir` - Directory to scan recursively
+pub fn clean_orphan_caches(dir: &Path) -> Result<OrphanCacheReport> {
+    let mut report = OrphanCacheReport::default();
+
+    let caches = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file());
+
+    for entry in caches {
+        let cache = entry.path();
+        match cache_source(cache) {
+            Some(source) if !source.exists() => {}
+            _ => continue,
+        }
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        fs::remove_file(cache).map_err(|e| Error::io_with_path(e, cache))?;
+
+        let relative = cache.strip_prefix(dir).unwrap_or(cache);
+        tracing::debug!("Removed orphaned cache: {}", relative.display());
+        report.removed.push(relative.to_string_lossy().replace('\\', "/"));
+        report.reclaimed_bytes += size;
+    }
+
+    if !report.removed.is_empty() {
+        tracing::info!(
+            "Removed {} orphaned .ritobin caches ({} bytes)",
+            report.removed.len(),
+            report.reclaimed_bytes
+        );
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_orphan_caches() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("data");
+        fs::create_dir_all(&data).unwrap();
+        fs::write(data.join("kept.bin"), b"PROP").unwrap();
+        fs::write(data.join("kept.bin.ritobin"), b"#PROP_text").unwrap();
+        fs::write(data.join("moved.bin.ritobin"), b"#PROP_text\n").unwrap();
+        fs::write(data.join("notes.ritobin"), b"#PROP_text\n").unwrap();
+        fs::write(data.join(".bin.ritobin"), b"#PROP_text\n").unwrap();
+
+        let report = clean_orphan_caches(dir.path()).unwrap();
+        assert_eq!(report.removed, vec!["data/moved.bin.ritobin"]);
+        assert_eq!(report.reclaimed_bytes, 11);
+        assert!(data.join("kept.bin.ritobin").exists());
+        assert!(!data.join("moved.bin.ritobin").exists());
+        assert!(data.join("notes.ritobin").exists());
+        assert!(data.join(".bin.ritobin").exists());
+    }
+}
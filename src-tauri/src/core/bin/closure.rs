@@ -0,0 +1,457 @@
+//! Linked BIN closure resolution across WAD boundaries
+//!
+//! A skin BIN's linked list can reference BINs that live in other WADs (base
+//! champion data, shared companion BINs, ...). Those never get extracted with
+//! the champion WAD, so after repath the project points at files it doesn't
+//! contain. This module walks the full dependency closure starting at the
+//! main skin BIN, finds each dependency in the project or in the game WADs,
+//! and optionally extracts the ones the mod has to ship itself.
+//!
+//! Champion root and animation BINs are always left pointing at game data:
+//! the game loads them for the champion anyway, and shipping copies would
+//! only risk overriding live data.
+//!
+//! [`dependency_tree`] lays the same links out as a tree for display, showing
+//! what concatenation would merge.
+
+use crate::core::bin::concat::{classify_bin, BinCategory};
+use crate::core::bin::ltk_bridge::{read_bin, LazyBin};
+use crate::core::project::Project;
+use crate::core::repath::organizer::find_main_skin_bin;
+use crate::core::wad::explorer::{read_chunk_data, scan_game_wads};
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use xxhash_rust::xxh64::xxh64;
+use ts_rs::TS;
+
+/// Where a linked dependency was resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyStatus {
+    /// Already present in the project
+    InProject,
+    /// Copied from a game WAD into the project
+    Extracted,
+    /// Intentionally left pointing at game data
+    GameData,
+    /// Not found in the project or any game WAD
+    Missing,
+}
+
+/// A single dependency in the closure
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LinkedDependency {
+    /// Normalized dependency path (lowercase, forward slashes)
+    pub path: String,
+    /// BIN whose linked list references this dependency
+    pub required_by: String,
+    pub status: DependencyStatus,
+    /// Game WAD the dependency was found in, relative to DATA/FINAL when known
+    pub source_wad: Option<String>,
+}
+
+/// Result of resolving a linked BIN closure
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LinkedClosureReport {
+    pub dependencies: Vec<LinkedDependency>,
+    pub extracted_count: usize,
+    pub missing_count: usize,
+}
+
+/// A BIN in the dependency tree of a main skin BIN
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinDependencyNode {
+    /// Normalized path (lowercase, forward slashes), relative to the WAD folder
+    pub path: String,
+    pub category: BinCategory,
+    /// False if the BIN isn't in the project (game data or missing)
+    pub in_project: bool,
+    /// Merged into the concat BIN when the project is concatenated
+    pub concatenated: bool,
+    pub object_count: usize,
+    #[ts(type = "number")]
+    pub file_size: u64,
+    /// Already listed earlier in the tree (or links back to an ancestor);
+    /// its size and links are only filled in where it first appears
+    pub repeated: bool,
+    /// Why the BIN couldn't be read
+    pub error: Option<String>,
+    pub children: Vec<BinDependencyNode>,
+}
+
+/// Lazily indexed set of game WADs used to look up dependencies
+pub struct GameWadLookup {
+    wads: Vec<PathBuf>,
+    /// Chunk hashes per WAD, loaded on first lookup
+    indexed: HashMap<PathBuf, HashSet<u64>>,
+    final_dir: Option<PathBuf>,
+}
+
+impl GameWadLookup {
+    /// Creates a lookup over an explicit list of WADs
+    pub fn new(wads: Vec<PathBuf>) -> Self {
+        Self {
+            wads,
+            indexed: HashMap::new(),
+            final_dir: None,
+        }
+    }
+
+    /// Creates a lookup over every WAD in a League installation
+    pub fn from_league_path(league_path: &Path) -> Result<Self> {
+        let wads = scan_game_wads(league_path)?
+            .into_iter()
+            .map(|entry| entry.path)
+            .collect();
+        let mut lookup = Self::new(wads);
+        lookup.final_dir = Some(league_path.join("Game").join("DATA").join("FINAL"));
+        Ok(lookup)
+    }
+
+    /// Display name of a WAD (relative to DATA/FINAL when possible)
+    pub(crate) fn display_name(&self, wad: &Path) -> String {
+        self.final_dir
+            .as_ref()
+            .and_then(|dir| wad.strip_prefix(dir).ok())
+            .unwrap_or(wad)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    fn contains(&mut self, wad: &Path, path_hash: u64) -> bool {
+        if !self.indexed.contains_key(wad) {
+            let hashes = match WadReader::open(wad) {
+                Ok(reader) => reader.chunks().keys().copied().collect(),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable WAD {}: {}", wad.display(), e);
+                    HashSet::new()
+                }
+            };
+            self.indexed.insert(wad.to_path_buf(), hashes);
+        }
+        self.indexed[wad].contains(&path_hash)
+    }
+
+    /// Finds the WAD containing a path
+    ///
+    /// WADs named after the character folder of the path
+    /// (`data/characters/{name}/...` -> `{name}.wad.client`) are checked
+    /// first, so the common case only indexes one archive.
+    pub fn find(&mut self, path: &str) -> Option<PathBuf> {
+        let path_hash = xxh64(path.as_bytes(), 0);
+        let character = path
+            .strip_prefix("data/characters/")
+            .and_then(|rest| rest.split('/').next())
+            .map(|name| format!("{}.wad.client", name));
+
+        let mut candidates = self.wads.clone();
+        if let Some(ref preferred) = character {
+            candidates.sort_by_key(|wad| {
+                let name = wad.file_name().map(|n| n.to_string_lossy().to_lowercase());
+                name.as_deref() != Some(preferred.as_str())
+            });
+        }
+
+        candidates.into_iter().find(|wad| self.contains(wad, path_hash))
+    }
+}
+
+fn normalize(path: &str) -> String {
+    path.to_lowercase().replace('\\', "/")
+}
+
+/// Walks the linked BIN closure of a main BIN
+///
+/// # Arguments
+/// * `file_base` - Project directory BIN paths are relative to (the WAD folder)
+/// * `main_bin` - Path to the main skin BIN
+/// * `path_mappings` - Original to on-disk paths for hash-named files
+/// * `game` - Game WADs to search for dependencies missing from the project
+/// * `extract` - Copy linked data BINs found in game WADs into the project
+pub fn resolve_linked_closure(
+    file_base: &Path,
+    main_bin: &Path,
+    path_mappings: &HashMap<String, String>,
+    game: &mut GameWadLookup,
+    extract: bool,
+) -> Result<LinkedClosureReport> {
+    let main_rel = main_bin
+        .strip_prefix(file_base)
+        .map(|p| normalize(&p.to_string_lossy()))
+        .unwrap_or_else(|_| normalize(&main_bin.to_string_lossy()));
+
+    let mut report = LinkedClosureReport {
+        dependencies: Vec::new(),
+        extracted_count: 0,
+        missing_count: 0,
+    };
+    let mut visited: HashSet<String> = HashSet::from([main_rel.clone()]);
+    let mut queue: VecDeque<(String, PathBuf)> = VecDeque::from([(main_rel, main_bin.to_path_buf())]);
+
+    while let Some((rel_path, full_path)) = queue.pop_front() {
+        let data = fs::read(&full_path).map_err(|e| Error::io_with_path(e, &full_path))?;
+        let bin = match read_bin(&data) {
+            Ok(bin) => bin,
+            Err(e) => {
+                tracing::warn!("Failed to parse {} while resolving links: {}", rel_path, e);
+                continue;
+            }
+        };
+
+        for dependency in &bin.dependencies {
+            let dep_path = normalize(dependency);
+            if !visited.insert(dep_path.clone()) {
+                continue;
+            }
+
+            let actual = path_mappings.get(&dep_path).cloned().unwrap_or_else(|| dep_path.clone());
+            let dep_full = file_base.join(&actual);
+
+            let mut entry = LinkedDependency {
+                path: dep_path.clone(),
+                required_by: rel_path.clone(),
+                status: DependencyStatus::InProject,
+                source_wad: None,
+            };
+
+            if dep_full.exists() {
+                queue.push_back((dep_path, dep_full));
+            } else if let Some(wad) = game.find(&dep_path) {
+                entry.source_wad = Some(game.display_name(&wad));
+                let ships_with_mod = classify_bin(&dep_path) == BinCategory::LinkedData;
+
+                if extract && ships_with_mod {
+                    let data = read_chunk_data(&wad, xxh64(dep_path.as_bytes(), 0))?;
+                    if let Some(parent) = dep_full.parent() {
+                        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+                    }
+                    fs::write(&dep_full, data).map_err(|e| Error::io_with_path(e, &dep_full))?;
+                    tracing::info!("Extracted linked BIN {} from {}", dep_path, wad.display());
+
+                    entry.status = DependencyStatus::Extracted;
+                    report.extracted_count += 1;
+                    queue.push_back((dep_path, dep_full));
+                } else {
+                    entry.status = DependencyStatus::GameData;
+                }
+            } else {
+                tracing::warn!("Linked BIN not found in project or game: {}", dep_path);
+                entry.status = DependencyStatus::Missing;
+                report.missing_count += 1;
+            }
+
+            report.dependencies.push(entry);
+        }
+    }
+
+    tracing::info!(
+        "Resolved {} linked dependencies ({} extracted, {} missing)",
+        report.dependencies.len(),
+        report.extracted_count,
+        report.missing_count
+    );
+
+    Ok(report)
+}
+
+/// Builds the dependency tree of a main BIN from the files in the project
+///
+/// Each BIN is read in streaming mode, so only headers and linked lists are
+/// loaded. A BIN linked from several places is expanded the first time only.
+///
+/// # Arguments
+/// * `file_base` - Project directory BIN paths are relative to (the WAD folder)
+/// * `main_bin` - Path to the main skin BIN
+pub fn dependency_tree(file_base: &Path, main_bin: &Path) -> Result<BinDependencyNode> {
+    let main_rel = main_bin
+        .strip_prefix(file_base)
+        .map(|p| normalize(&p.to_string_lossy()))
+        .unwrap_or_else(|_| normalize(&main_bin.to_string_lossy()));
+    if !main_bin.is_file() {
+        return Err(Error::InvalidInput(format!("BIN not found: {}", main_bin.display())));
+    }
+
+    let mut seen = HashSet::new();
+    Ok(dependency_node(file_base, main_rel, main_bin.to_path_buf(), 0, &mut seen))
+}
+
+fn dependency_node(
+    file_base: &Path,
+    path: String,
+    full_path: PathBuf,
+    depth: usize,
+    seen: &mut HashSet<String>,
+) -> BinDependencyNode {
+    let category = classify_bin(&path);
+    let in_project = full_path.is_file();
+    let mut node = BinDependencyNode {
+        in_project,
+        // Concatenation merges the linked data BINs the main BIN links directly
+        concatenated: in_project && depth == 1 && category == BinCategory::LinkedData,
+        object_count: 0,
+        file_size: 0,
+        repeated: !seen.insert(path.clone()),
+        error: None,
+        children: Vec::new(),
+        category,
+        path,
+    };
+    if !node.in_project || node.repeated {
+        return node;
+    }
+
+    let bin = match LazyBin::open(&full_path) {
+        Ok(bin) => bin,
+        Err(e) => {
+            node.error = Some(e.to_string());
+            node.concatenated = false;
+            return node;
+        }
+    };
+    node.object_count = bin.objects().len();
+    node.file_size = bin.file_size;
+
+    for dependency in &bin.dependencies {
+        let dep_path = normalize(dependency);
+        let dep_full = file_base.join(&dep_path);
+        node.children.push(dependency_node(file_base, dep_path, dep_full, depth + 1, seen));
+    }
+    node
+}
+
+/// WAD folder of a project and its main skin BIN
+fn project_main_bin(project: &Project) -> Result<(PathBuf, PathBuf)> {
+    let content_base = project.assets_path();
+    let wad_base = content_base.join(format!("{}.wad.client", project.champion.to_lowercase()));
+    let file_base = if wad_base.exists() { wad_base } else { content_base };
+
+    let main_bin = find_main_skin_bin(&file_base, &project.champion, project.skin_id).ok_or_else(|| {
+        Error::InvalidInput(format!(
+            "Main skin BIN not found for {} skin {}",
+            project.champion, project.skin_id
+        ))
+    })?;
+    Ok((file_base, main_bin))
+}
+
+/// Resolves the linked BIN closure of a project's main skin BIN
+///
+/// # Arguments
+/// * `project` - Project with a League path set
+/// * `path_mappings` - Original to on-disk paths from extraction (may be empty)
+/// * `extract` - Copy linked data BINs found in game WADs into the project
+pub fn resolve_project_closure(
+    project: &Project,
+    path_mappings: &HashMap<String, String>,
+    extract: bool,
+) -> Result<LinkedClosureReport> {
+    let league_path = project.league_path.as_ref().ok_or_else(|| {
+        Error::InvalidInput("Project has no League installation path".to_string())
+    })?;
+
+    let (file_base, main_bin) = project_main_bin(project)?;
+    let mut game = GameWadLookup::from_league_path(league_path)?;
+    resolve_linked_closure(&file_base, &main_bin, path_mappings, &mut game, extract)
+}
+
+/// Builds the dependency tree of a project's main skin BIN
+pub fn project_dependency_tree(project: &Project) -> Result<BinDependencyNode> {
+    let (file_base, main_bin) = project_main_bin(project)?;
+    dependency_tree(&file_base, &main_bin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::write_bin;
+    use crate::core::wad::test_util::write_wad;
+    use ltk_meta::BinTree;
+
+    fn bin_with_links(links: &[&str]) -> Vec<u8> {
+        let mut tree = BinTree::default();
+        tree.dependencies = links.iter().map(|s| s.to_string()).collect();
+        write_bin(&tree).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_across_wads() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("project");
+        let main = base.join("data/characters/ahri/skins/skin3.bin");
+        fs::create_dir_all(main.parent().unwrap()).unwrap();
+        fs::write(
+            &main,
+            bin_with_links(&[
+                "data/characters/ahri/ahri.bin",
+                "DATA/Shared/Companion.bin",
+                "data/missing.bin",
+            ]),
+        )
+        .unwrap();
+
+        let wad = dir.path().join("Common.wad.client");
+        write_wad(&wad, &[
+            ("data/characters/ahri/ahri.bin", bin_with_links(&[])),
+            ("data/shared/companion.bin", bin_with_links(&["data/shared/nested.bin"])),
+            ("data/shared/nested.bin", bin_with_links(&[])),
+        ]);
+
+        let mut game = GameWadLookup::new(vec![wad]);
+        let report = resolve_linked_closure(&base, &main, &HashMap::new(), &mut game, true).unwrap();
+
+        let status = |path: &str| report.dependencies.iter().find(|d| d.path == path).unwrap().status;
+        assert_eq!(status("data/characters/ahri/ahri.bin"), DependencyStatus::GameData);
+        assert_eq!(status("data/shared/companion.bin"), DependencyStatus::Extracted);
+        assert_eq!(status("data/shared/nested.bin"), DependencyStatus::Extracted);
+        assert_eq!(status("data/missing.bin"), DependencyStatus::Missing);
+        assert_eq!(report.extracted_count, 2);
+        assert!(base.join("data/shared/nested.bin").exists());
+        assert!(!base.join("data/characters/ahri/ahri.bin").exists());
+    }
+
+    #[test]
+    fn test_dependency_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        let main = base.join("data/characters/ahri/skins/skin3.bin");
+        fs::create_dir_all(main.parent().unwrap()).unwrap();
+        fs::write(
+            &main,
+            bin_with_links(&[
+                "DATA/Characters/Ahri/Ahri.bin",
+                "DATA/Ahri_Skins_Skin3.bin",
+                "DATA/Shared.bin",
+            ]),
+        )
+        .unwrap();
+        fs::write(base.join("data/ahri_skins_skin3.bin"), bin_with_links(&["data/shared.bin"])).unwrap();
+        fs::write(base.join("data/shared.bin"), bin_with_links(&["data/ahri_skins_skin3.bin"])).unwrap();
+
+        let tree = dependency_tree(base, &main).unwrap();
+        assert_eq!(tree.path, "data/characters/ahri/skins/skin3.bin");
+        assert_eq!(tree.children.len(), 3);
+
+        let root = &tree.children[0];
+        assert_eq!(root.category, BinCategory::ChampionRoot);
+        assert!(!root.in_project && !root.concatenated);
+
+        let skin = &tree.children[1];
+        assert!(skin.concatenated);
+        assert!(skin.file_size > 0);
+        // The shared BIN is expanded below the first BIN linking it, and
+        // the cycle back to the skin BIN stops there
+        let nested = &skin.children[0];
+        assert_eq!(nested.path, "data/shared.bin");
+        assert!(!nested.repeated && !nested.concatenated);
+        assert!(nested.children[0].repeated);
+        assert!(tree.children[2].repeated && tree.children[2].concatenated);
+    }
+}
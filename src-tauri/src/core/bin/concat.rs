@@ -6,16 +6,30 @@
 //! 3. Updates the main BIN's linked list to reference the new concat BIN
 //!
 //! This prevents conflicts when multiple linked BINs reference the same assets.
+//! Objects defined by more than one BIN are settled by a [`ConcatMergePolicy`]
+//! and reported, so a collision never silently picks the wrong definition.
 
-use crate::core::bin::ltk_bridge::{read_bin, write_bin};
+use crate::core::bin::ltk_bridge::{get_cached_bin_hashes, read_bin, write_bin};
+use crate::core::repath::organizer::{report_progress, ProgressCallback};
+use crate::core::store::write_replacing;
+use crate::core::trash::TrashSession;
 use crate::error::{Error, Result};
-use ltk_meta::{BinTree, BinTreeBuilder, BinTreeObject};
-use std::collections::HashMap;
-use std::fs;
-use std::path::Path;
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use ltk_meta::{BinTree, BinTreeObject};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+
+/// PROP version written by `BinTree::to_writer`, and by the concat writer
+const PROP_VERSION: u32 = 3;
 
 /// Category of a BIN file based on its path pattern
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
 pub enum BinCategory {
     /// Type 1: Champion root BIN (DATA/Characters/{Champion}/{Champion}.bin)
     /// Never modify - contains core champion data
@@ -34,6 +48,52 @@ pub enum BinCategory {
     Ignore,
 }
 
+/// How concatenation settles an object defined by more than one BIN
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConcatMergePolicy {
+    /// The BIN later in the main BIN's linked list wins
+    #[default]
+    LastWins,
+    /// Objects the main skin BIN defines itself are left out of the concat;
+    /// collisions between linked BINs are settled last-wins
+    PreferMainSkin,
+    /// Any collision stops the concat
+    Error,
+    /// The user picked the BIN to keep per object, by path hash (e.g. from
+    /// the collisions a preview listed); collisions without a pick stop the concat
+    Resolve { choices: BTreeMap<u32, String> },
+}
+
+impl ConcatMergePolicy {
+    /// True for the default policy
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// An object defined by more than one BIN of a concatenation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ConcatCollision {
+    pub path_hash: u32,
+    /// Object name from the hash tables, if known
+    pub name: Option<String>,
+    /// BINs defining the object: the main skin BIN first if it does, then
+    /// the linked BINs in link order
+    pub sources: Vec<String>,
+    /// BIN whose definition ships; None if the policy leaves it unresolved
+    pub winner: Option<String>,
+}
+
+impl ConcatCollision {
+    /// Object name, or its hash in hex
+    fn label(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("0x{:08x}", self.path_hash))
+    }
+}
+
 /// Result of a concatenation operation
 #[derive(Debug, Clone)]
 pub struct ConcatResult {
@@ -44,9 +104,8 @@ pub struct ConcatResult {
     /// Total number of entries in the concat BIN
     #[allow(dead_code)] // Kept for diagnostic purposes
     pub entry_count: usize,
-    /// Number of hash collisions encountered (last-write-wins)
-    #[allow(dead_code)] // Kept for diagnostic purposes
-    pub collision_count: usize,
+    /// Objects defined by more than one BIN, and which definition was kept
+    pub collisions: Vec<ConcatCollision>,
     /// Paths of source BINs that were concatenated (for deletion)
     pub source_paths: Vec<String>,
 }
@@ -102,29 +161,28 @@ pub fn set_linked_paths(bin: &mut BinTree, paths: Vec<String>) {
 }
 
 /// Create a concatenated BIN from all Type 3 (LinkedData) BINs
+///
+/// Sources are merged in two passes (index, then append) straight into the
+/// output file, so peak memory stays around the size of the largest single
+/// source rather than the sum of all of them.
+///
+/// # Arguments
+/// * `main_bin_path` - Path of `main_bin` relative to `content_base`, to name
+///   it in collisions
+/// * `policy` - How objects defined by more than one BIN are settled
+#[allow(clippy::too_many_arguments)]
 pub fn create_concat_bin(
     main_bin: &BinTree,
+    main_bin_path: &str,
     project_name: &str,
     creator_name: &str,
-    _champion: &str,  // No longer used in path generation but kept for API compatibility
     content_base: &Path,
     path_mappings: &HashMap<String, String>,
+    policy: &ConcatMergePolicy,
+    progress: Option<ProgressCallback>,
 ) -> Result<ConcatResult> {
-    // 1. Get linked paths from main BIN
-    let linked_paths = get_linked_paths(main_bin);
-
-    // 2. Filter to only Type 3 (LinkedData) BINs
-    let type3_paths: Vec<String> = linked_paths
-        .iter()
-        .filter(|path| {
-            let cat = classify_bin(path);
-            if cat == BinCategory::Ignore {
-                tracing::warn!("Ignoring suspicious linked BIN: {}", path);
-            }
-            cat == BinCategory::LinkedData
-        })
-        .cloned()
-        .collect();
+    // 1-2. Get the Type 3 (LinkedData) BINs from the main BIN's linked list
+    let type3_paths = linked_data_paths(main_bin);
 
     tracing::info!(
         "Found {} Type 3 (LinkedData) BINs to concatenate",
@@ -137,13 +195,14 @@ pub fn create_concat_bin(
         ));
     }
 
-    // 3. Create new concat BIN - objects will be merged, dependencies empty
-    let mut all_objects: HashMap<u32, BinTreeObject> = HashMap::new();
-    let mut collision_count = 0;
-    let mut source_count = 0;
-    let mut processed_paths: Vec<String> = Vec::new();
+    // 3. Index pass: parse each source once and record the objects it
+    // defines, keeping only hashes in memory
+    let total_steps = type3_paths.len() * 2;
+    let mut sources: Vec<ConcatSource> = Vec::new();
+
+    for (index, bin_path) in type3_paths.iter().enumerate() {
+        report_progress(progress, "concat", index + 1, total_steps, Some(bin_path));
 
-    for bin_path in &type3_paths {
         let normalized_path = bin_path.to_lowercase().replace('\\', "/");
         
         let actual_path = path_mappings.get(&normalized_path)
@@ -157,37 +216,9 @@ pub fn create_concat_bin(
             continue;
         }
 
-        tracing::info!("Processing Type 3 BIN: {} (actual: {})", bin_path, actual_path);
-
-        // Load the source BIN using ltk_meta
-        let data = fs::read(&full_path).map_err(|e| Error::io_with_path(e, &full_path))?;
-        
-        let magic = if data.len() >= 4 {
-            String::from_utf8_lossy(&data[0..4]).to_string()
-        } else {
-            "SHORT".to_string()
-        };
-
-        tracing::info!(
-            "Processing Type 3 BIN: {} (actual: {}, size: {} bytes, magic: {})", 
-            bin_path, actual_path, data.len(), magic
-        );
-
-        // catch_unwind requires 'UnwindSafe'. references are usually fine.
-        let source_bin_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            read_bin(&data)
-        }));
-
-        let source_bin = match source_bin_result {
-            Ok(Ok(bin)) => bin,
-            Ok(Err(e)) => {
-                tracing::warn!("Failed to parse BIN {}: {}", actual_path, e);
-                continue;
-            }
-            Err(_) => {
-                tracing::error!("CRASH PREVENTED: Parser panicked/crashed on BIN {}. Skipping.", actual_path);
-                continue;
-            }
+        let source_bin = match load_source_bin(&full_path, &actual_path)? {
+            Some(bin) => bin,
+            None => continue,
         };
 
         // Validate that source is indeed a linked BIN (should have empty dependencies)
@@ -199,77 +230,321 @@ pub fn create_concat_bin(
             );
         }
 
-        // Merge objects from source into all_objects
-        for (path_hash, object) in source_bin.objects {
-            if all_objects.contains_key(&path_hash) {
-                collision_count += 1;
-                tracing::warn!("Hash collision detected for 0x{:08x} in {}, last-write-wins", path_hash, bin_path);
-            }
-            all_objects.insert(path_hash, object);
-        }
+        let objects = source_bin
+            .objects
+            .iter()
+            .map(|(path_hash, object)| (*path_hash, object.class_hash))
+            .collect();
+        sources.push(ConcatSource { actual_path, full_path, objects });
+    }
 
-        source_count += 1;
-        processed_paths.push(actual_path.clone());
+    let (winners, collisions) = settle_collisions(&sources, main_bin, main_bin_path, policy);
+    for collision in &collisions {
+        tracing::warn!(
+            "{} is defined by {}, keeping {}",
+            collision.label(),
+            collision.sources.join(", "),
+            collision.winner.as_deref().unwrap_or("none")
+        );
+    }
+    let unresolved: Vec<&ConcatCollision> = collisions.iter().filter(|c| c.winner.is_none()).collect();
+    if !unresolved.is_empty() {
+        let listed: Vec<String> = unresolved.iter().take(10).map(|c| c.label()).collect();
+        return Err(Error::InvalidInput(format!(
+            "{} objects are defined by more than one linked BIN ({}{}); choose which to keep",
+            unresolved.len(),
+            listed.join(", "),
+            if unresolved.len() > 10 { ", ..." } else { "" }
+        )));
     }
 
-    // 4. Create the concat BinTree using BinTreeBuilder for cleaner construction
-    let concat_bin = BinTreeBuilder::new()
-        .objects(all_objects.into_values())
-        .build();
-    let object_count = concat_bin.objects.len();
+    let source_count = sources.len();
+    let object_count = winners.len();
 
-    // 5. Generate concat path (sanitize names: replace spaces with dashes)
-    // New naming: data/{creator}_{project}__Concat.bin
-    // Champion is no longer in the folder hierarchy, so omit from filename for consistency
-    let creator_sanitized = creator_name.replace(' ', "-");
-    let project_sanitized = project_name.replace(' ', "-");
-    let concat_path = format!(
-        "data/{}_{}__Concat.bin",
-        creator_sanitized, project_sanitized
-    );
+    // 4. Generate concat path
+    let concat_path = concat_bin_path(creator_name, project_name);
 
-    // 6. Save the concat BIN immediately
     let concat_full_path = content_base.join(&concat_path);
     if let Some(parent) = concat_full_path.parent() {
         fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
     }
 
-    let concat_data = write_bin(&concat_bin)
-        .map_err(|e| Error::InvalidInput(format!("Failed to write concat BIN: {}", e)))?;
-
-    fs::write(&concat_full_path, &concat_data)
-        .map_err(|e| Error::io_with_path(e, &concat_full_path))?;
-
-    // Verify the written BIN can be read back
-    if let Err(e) = read_bin(&concat_data) {
-        // Try to cleanup the bad file
-        let _ = fs::remove_file(&concat_full_path);
-        return Err(Error::InvalidInput(format!(
-            "Generated concat BIN is corrupt and cannot be read back: {}", 
-            e
-        )));
+    // 5. Append pass: write the header from the index, then re-read each
+    // source and append the objects it won. Only one source is held in
+    // memory at a time.
+    let temp_path = concat_full_path.with_extension("bin.tmp");
+    let written = write_concat(&temp_path, &sources, &winners, object_count, |index, source| {
+        report_progress(progress, "concat", type3_paths.len() + index + 1, total_steps, Some(&source.actual_path));
+    })
+    .and_then(|_| verify_concat(&temp_path, object_count));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e);
     }
+    fs::rename(&temp_path, &concat_full_path).map_err(|e| Error::io_with_path(e, &concat_full_path))?;
 
     tracing::info!(
         "Created concat BIN with {} objects from {} sources ({} collisions)",
         object_count,
         source_count,
-        collision_count
+        collisions.len()
     );
 
     Ok(ConcatResult {
         concat_path,
         source_count,
         entry_count: object_count,
-        collision_count,
-        source_paths: processed_paths,
+        collisions,
+        source_paths: sources.into_iter().map(|source| source.actual_path).collect(),
     })
 }
 
+/// Picks the source each object of the concat is taken from
+///
+/// Objects left to the main skin BIN get no winner. Collisions are listed in
+/// the order their objects first appear.
+///
+/// # Returns
+/// The winning source index by path hash, and every object defined more than once
+fn settle_collisions(
+    sources: &[ConcatSource],
+    main_bin: &BinTree,
+    main_bin_path: &str,
+    policy: &ConcatMergePolicy,
+) -> (HashMap<u32, usize>, Vec<ConcatCollision>) {
+    let mut order: Vec<u32> = Vec::new();
+    let mut defined_in: HashMap<u32, Vec<usize>> = HashMap::new();
+    for (index, source) in sources.iter().enumerate() {
+        for (path_hash, _) in &source.objects {
+            let definitions = defined_in.entry(*path_hash).or_default();
+            if definitions.is_empty() {
+                order.push(*path_hash);
+            }
+            definitions.push(index);
+        }
+    }
+    let main_objects: HashSet<u32> = main_bin.objects.keys().copied().collect();
+
+    let mut winners = HashMap::with_capacity(order.len());
+    let mut collisions = Vec::new();
+    for path_hash in order {
+        let definitions = &defined_in[&path_hash];
+        let in_main = main_objects.contains(&path_hash);
+        let last = *definitions.last().expect("every listed object has a definition");
+        if definitions.len() == 1 && !in_main {
+            winners.insert(path_hash, last);
+            continue;
+        }
+
+        // Some(None): the main skin BIN's own definition is kept
+        let winner: Option<Option<usize>> = match policy {
+            ConcatMergePolicy::LastWins => Some(Some(last)),
+            ConcatMergePolicy::PreferMainSkin => Some((!in_main).then_some(last)),
+            ConcatMergePolicy::Error => None,
+            ConcatMergePolicy::Resolve { choices } => choices.get(&path_hash).and_then(|choice| {
+                if in_main && choice.eq_ignore_ascii_case(main_bin_path) {
+                    return Some(None);
+                }
+                definitions
+                    .iter()
+                    .find(|&&index| sources[index].actual_path.eq_ignore_ascii_case(choice))
+                    .map(|&index| Some(index))
+            }),
+        };
+        if let Some(Some(index)) = winner {
+            winners.insert(path_hash, index);
+        }
+
+        let mut names: Vec<String> = Vec::with_capacity(definitions.len() + 1);
+        if in_main {
+            names.push(main_bin_path.to_string());
+        }
+        names.extend(definitions.iter().map(|&index| sources[index].actual_path.clone()));
+        collisions.push(ConcatCollision {
+            path_hash,
+            name: None,
+            sources: names,
+            winner: winner.map(|index| match index {
+                Some(index) => sources[index].actual_path.clone(),
+                None => main_bin_path.to_string(),
+            }),
+        });
+    }
+
+    if !collisions.is_empty() {
+        let hashes = get_cached_bin_hashes().read();
+        for collision in &mut collisions {
+            collision.name = hashes.entries.get(&collision.path_hash).cloned();
+        }
+    }
+    (winners, collisions)
+}
+
+/// Linked paths of the main BIN that are Type 3 (LinkedData) BINs
+fn linked_data_paths(main_bin: &BinTree) -> Vec<String> {
+    get_linked_paths(main_bin)
+        .into_iter()
+        .filter(|path| {
+            let cat = classify_bin(path);
+            if cat == BinCategory::Ignore {
+                tracing::warn!("Ignoring suspicious linked BIN: {}", path);
+            }
+            cat == BinCategory::LinkedData
+        })
+        .collect()
+}
+
+/// Relative DATA path of the concat BIN
+///
+/// Names are sanitized (spaces become dashes). Champion is no longer in the
+/// folder hierarchy, so it's omitted from the filename for consistency.
+fn concat_bin_path(creator_name: &str, project_name: &str) -> String {
+    format!(
+        "data/{}_{}__Concat.bin",
+        creator_name.replace(' ', "-"),
+        project_name.replace(' ', "-")
+    )
+}
+
+/// A source BIN of the concat, with the objects it holds in file order
+struct ConcatSource {
+    actual_path: String,
+    full_path: PathBuf,
+    objects: Vec<(u32, u32)>,
+}
+
+/// Reads and parses one source BIN
+///
+/// Returns `None` for BINs that don't parse (or crash the parser), which
+/// the concat skips.
+fn load_source_bin(full_path: &Path, actual_path: &str) -> Result<Option<BinTree>> {
+    let data = fs::read(full_path).map_err(|e| Error::io_with_path(e, full_path))?;
+
+    let magic = if data.len() >= 4 {
+        String::from_utf8_lossy(&data[0..4]).to_string()
+    } else {
+        "SHORT".to_string()
+    };
+
+    tracing::info!(
+        "Processing Type 3 BIN: {} (size: {} bytes, magic: {})",
+        actual_path, data.len(), magic
+    );
+
+    // catch_unwind requires 'UnwindSafe'. references are usually fine.
+    let source_bin_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        read_bin(&data)
+    }));
+
+    match source_bin_result {
+        Ok(Ok(bin)) => Ok(Some(bin)),
+        Ok(Err(e)) => {
+            tracing::warn!("Failed to parse BIN {}: {}", actual_path, e);
+            Ok(None)
+        }
+        Err(_) => {
+            tracing::error!("CRASH PREVENTED: Parser panicked/crashed on BIN {}. Skipping.", actual_path);
+            Ok(None)
+        }
+    }
+}
+
+/// Writes the concat BIN in the PROP v3 layout `BinTree::to_writer` uses
+///
+/// The class hash table comes before the objects, so it's written from the
+/// index; objects follow in source order.
+fn write_concat(
+    path: &Path,
+    sources: &[ConcatSource],
+    winners: &HashMap<u32, usize>,
+    object_count: usize,
+    on_source: impl Fn(usize, &ConcatSource),
+) -> Result<()> {
+    let io_error = |e: std::io::Error| Error::io_with_path(e, path);
+    let file = File::create(path).map_err(io_error)?;
+    let mut writer = BufWriter::new(file);
+
+    writer.write_u32::<LE>(BinTree::PROP).map_err(io_error)?;
+    writer.write_u32::<LE>(PROP_VERSION).map_err(io_error)?;
+    writer.write_u32::<LE>(0).map_err(io_error)?;
+    writer.write_u32::<LE>(object_count as u32).map_err(io_error)?;
+    for (index, source) in sources.iter().enumerate() {
+        for (path_hash, class_hash) in &source.objects {
+            if winners.get(path_hash) == Some(&index) {
+                writer.write_u32::<LE>(*class_hash).map_err(io_error)?;
+            }
+        }
+    }
+
+    for (index, source) in sources.iter().enumerate() {
+        on_source(index, source);
+        if !source.objects.iter().any(|(path_hash, _)| winners.get(path_hash) == Some(&index)) {
+            continue;
+        }
+        let bin = load_source_bin(&source.full_path, &source.actual_path)?.ok_or_else(|| {
+            Error::bin_conversion_with_path("Source BIN changed while concatenating", &source.full_path)
+        })?;
+        for (path_hash, _) in &source.objects {
+            if winners.get(path_hash) != Some(&index) {
+                continue;
+            }
+            let object = bin.objects.get(path_hash).ok_or_else(|| {
+                Error::bin_conversion_with_path("Source BIN changed while concatenating", &source.full_path)
+            })?;
+            object.to_writer(&mut writer).map_err(io_error)?;
+        }
+    }
+
+    writer.flush().map_err(io_error)
+}
+
+/// Checks that a written concat BIN reads back, one object at a time
+fn verify_concat(path: &Path, object_count: usize) -> Result<()> {
+    let corrupt = |e: String| {
+        Error::InvalidInput(format!("Generated concat BIN is corrupt and cannot be read back: {}", e))
+    };
+    let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mut reader = BufReader::new(file);
+
+    let mut header = || -> std::io::Result<(u32, u32, u32, u32)> {
+        Ok((
+            reader.read_u32::<LE>()?,
+            reader.read_u32::<LE>()?,
+            reader.read_u32::<LE>()?,
+            reader.read_u32::<LE>()?,
+        ))
+    };
+    let (magic, _version, dependency_count, count) = header().map_err(|e| corrupt(e.to_string()))?;
+    if magic != BinTree::PROP || dependency_count != 0 || count as usize != object_count {
+        return Err(corrupt("unexpected header".to_string()));
+    }
+
+    let class_hashes = (0..count)
+        .map(|_| reader.read_u32::<LE>())
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(|e| corrupt(e.to_string()))?;
+    for class_hash in class_hashes {
+        BinTreeObject::from_reader(&mut reader, class_hash, false).map_err(|e| corrupt(e.to_string()))?;
+    }
+    Ok(())
+}
+
 /// Update the main BIN's linked list to use the concat BIN
 pub fn update_main_bin_links(main_bin: &mut BinTree, concat_path: String) -> Result<()> {
-    let current_links = get_linked_paths(main_bin);
+    let new_links = concat_links(&get_linked_paths(main_bin), concat_path);
+
+    tracing::info!("Updated main BIN linked list:");
+    for (i, link) in new_links.iter().enumerate() {
+        tracing::info!("  [{}] {}", i, link);
+    }
+
+    set_linked_paths(main_bin, new_links);
+
+    Ok(())
+}
 
+/// The main BIN's linked list once the concat BIN replaces the Type 3 BINs
+fn concat_links(current_links: &[String], concat_path: String) -> Vec<String> {
     // Find Type 1 (ChampionRoot)
     let type1_path = current_links
         .iter()
@@ -293,14 +568,122 @@ pub fn update_main_bin_links(main_bin: &mut BinTree, concat_path: String) -> Res
         new_links.push(path);
     }
 
-    tracing::info!("Updated main BIN linked list:");
-    for (i, link) in new_links.iter().enumerate() {
-        tracing::info!("  [{}] {}", i, link);
+    new_links
+}
+
+/// Moves the source BINs of a concatenation into the project trash
+///
+/// Must only run after everything that depends on the concat BIN (main BIN
+/// link update, repathing) has succeeded, so a failed step never leaves the
+/// project without the original BINs.
+///
+/// # Returns
+/// Number of source BINs moved
+pub fn trash_concat_sources(content_base: &Path, result: &ConcatResult, trash: &TrashSession) -> usize {
+    let mut trashed = 0;
+    tracing::info!("Moving {} concatenated source BINs to trash", result.source_paths.len());
+    for source_path in &result.source_paths {
+        match trash.stage(content_base, source_path, "concatenated") {
+            Ok(true) => {
+                tracing::debug!("Trashed concatenated source BIN: {}", source_path);
+                trashed += 1;
+            }
+            Ok(false) => {
+                // Already removed by repath cleanup
+                tracing::debug!("Source BIN no longer present: {}", source_path);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to trash source BIN {}: {}", source_path, e);
+            }
+        }
     }
+    tracing::info!("Moved {} original Type 3 BINs to trash after concatenation", trashed);
+    trashed
+}
 
-    set_linked_paths(main_bin, new_links);
+/// What concatenation would do, worked out without writing anything
+///
+/// Paths are relative to the folder the concat runs in, as in [`ConcatResult`].
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ConcatPlan {
+    /// Main skin BIN whose linked list is rewritten
+    pub main_bin: String,
+    /// Concat BIN that would be written
+    pub concat_path: String,
+    /// Linked BINs merged into the concat BIN, then moved to the trash
+    pub sources: Vec<String>,
+    /// The main BIN's linked list afterwards
+    pub new_links: Vec<String>,
+    /// Linked Type 3 BINs that aren't on disk
+    pub missing: Vec<String>,
+    /// Objects defined by more than one BIN; any without a winner would stop the concat
+    pub collisions: Vec<ConcatCollision>,
+}
 
-    Ok(())
+/// Path of the main BIN relative to the folder the concat runs in
+fn relative_main_path(main_bin_path: &Path, content_base: &Path) -> String {
+    main_bin_path
+        .strip_prefix(content_base)
+        .unwrap_or(main_bin_path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Works out what [`concatenate_linked_bins`] would do, without writing anything
+///
+/// Sources are parsed like the real run does, so BINs it would skip are left out.
+pub fn plan_concat(
+    main_bin_path: &Path,
+    project_name: &str,
+    creator_name: &str,
+    content_base: &Path,
+    path_mappings: &HashMap<String, String>,
+    policy: &ConcatMergePolicy,
+) -> Result<ConcatPlan> {
+    let data = fs::read(main_bin_path).map_err(|e| Error::io_with_path(e, main_bin_path))?;
+    let main_bin = read_bin(&data)
+        .map_err(|e| Error::InvalidInput(format!("Failed to parse main BIN: {}", e)))?;
+
+    let type3_paths = linked_data_paths(&main_bin);
+    if type3_paths.is_empty() {
+        return Err(Error::InvalidInput(
+            "No Type 3 (LinkedData) BINs found in linked list".to_string(),
+        ));
+    }
+
+    let mut sources = Vec::new();
+    let mut missing = Vec::new();
+    for bin_path in &type3_paths {
+        let normalized_path = bin_path.to_lowercase().replace('\\', "/");
+        let actual_path = path_mappings.get(&normalized_path)
+            .cloned()
+            .unwrap_or_else(|| normalized_path.clone());
+        let full_path = content_base.join(&actual_path);
+
+        if !full_path.exists() {
+            missing.push(normalized_path);
+        } else if let Some(source_bin) = load_source_bin(&full_path, &actual_path)? {
+            let objects = source_bin
+                .objects
+                .iter()
+                .map(|(path_hash, object)| (*path_hash, object.class_hash))
+                .collect();
+            sources.push(ConcatSource { actual_path, full_path, objects });
+        }
+    }
+
+    let main_bin_relative = relative_main_path(main_bin_path, content_base);
+    let (_, collisions) = settle_collisions(&sources, &main_bin, &main_bin_relative, policy);
+    let concat_path = concat_bin_path(creator_name, project_name);
+    Ok(ConcatPlan {
+        main_bin: main_bin_relative,
+        new_links: concat_links(&get_linked_paths(&main_bin), concat_path.clone()),
+        concat_path,
+        sources: sources.into_iter().map(|source| source.actual_path).collect(),
+        missing,
+        collisions,
+    })
 }
 
 /// Complete linked BIN concatenation workflow
@@ -308,9 +691,10 @@ pub fn concatenate_linked_bins(
     main_bin_path: &Path,
     project_name: &str,
     creator_name: &str,
-    champion: &str,
     content_base: &Path,
     path_mappings: &HashMap<String, String>,
+    policy: &ConcatMergePolicy,
+    progress: Option<ProgressCallback>,
 ) -> Result<ConcatResult> {
     tracing::info!(
         "Starting linked BIN concatenation for: {}",
@@ -329,7 +713,16 @@ pub fn concatenate_linked_bins(
     }
 
     // 2. Create and save concat BIN (create_concat_bin now saves the file)
-    let result = create_concat_bin(&main_bin, project_name, creator_name, champion, content_base, path_mappings)?;
+    let result = create_concat_bin(
+        &main_bin,
+        &relative_main_path(main_bin_path, content_base),
+        project_name,
+        creator_name,
+        content_base,
+        path_mappings,
+        policy,
+        progress,
+    )?;
 
     tracing::info!("Created concat BIN: {}", result.concat_path);
 
@@ -345,32 +738,13 @@ pub fn concatenate_linked_bins(
         let updated_data = write_bin(&main_bin)
             .map_err(|e| Error::InvalidInput(format!("Failed to write updated BIN: {}", e)))?;
         
-        fs::write(main_bin_path, updated_data).map_err(|e| Error::io_with_path(e, main_bin_path))?;
+        write_replacing(main_bin_path, &updated_data).map_err(|e| Error::io_with_path(e, main_bin_path))?;
         
         tracing::info!("Updated main BIN linked list: {}", main_bin_path.display());
     }
 
-    // 5. Delete the original Type 3 BINs that were concatenated
-    let mut deleted_count = 0;
-    tracing::info!("Deleting {} source BINs that were concatenated", result.source_paths.len());
-    for source_path in &result.source_paths {
-        let full_path = content_base.join(source_path);
-        tracing::debug!("Checking for deletion: {} -> {}", source_path, full_path.display());
-        if full_path.exists() {
-            match fs::remove_file(&full_path) {
-                Ok(_) => {
-                    tracing::info!("Deleted concatenated source BIN: {}", source_path);
-                    deleted_count += 1;
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to delete source BIN {}: {}", source_path, e);
-                }
-            }
-        } else {
-            tracing::warn!("Source BIN not found for deletion: {} (full path: {})", source_path, full_path.display());
-        }
-    }
-    tracing::info!("Deleted {} original Type 3 BINs after concatenation", deleted_count);
+    // Source BINs are left in place; the organizer moves them to the trash
+    // once the rest of the pipeline has succeeded (see `trash_concat_sources`)
 
     Ok(result)
 }
@@ -406,4 +780,110 @@ mod tests {
             BinCategory::LinkedData
         );
     }
+
+    fn write_source(content_base: &Path, path: &str, objects: &str) {
+        let text = format!(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nlinked: list[string] = {{}}\nentries: map[hash,embed] = {{\n{}}}\n",
+            objects
+        );
+        let tree = crate::core::bin::ltk_bridge::text_to_tree(&text).unwrap();
+        let full_path = content_base.join(path);
+        fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+        fs::write(full_path, write_bin(&tree).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_create_concat_bin_merges_sources_last_write_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        let content_base = dir.path();
+        write_source(content_base, "data/a.bin", "    \"A\" = VfxSystemDefinitionData {\n        particleName: string = \"a\"\n    }\n    \"Shared\" = VfxSystemDefinitionData {\n        particleName: string = \"old\"\n    }\n");
+        write_source(content_base, "data/b.bin", "    \"Shared\" = VfxSystemDefinitionData {\n        particleName: string = \"new\"\n    }\n    \"B\" = StaticMaterialDef {\n        name: string = \"b\"\n    }\n");
+        fs::write(content_base.join("data/broken.bin"), b"PROPjunk").unwrap();
+
+        let mut main_bin = BinTree::default();
+        main_bin.dependencies = vec![
+            "DATA/a.bin".to_string(),
+            "DATA/Characters/Ahri/Ahri.bin".to_string(),
+            "DATA/broken.bin".to_string(),
+            "DATA/missing.bin".to_string(),
+            "DATA/b.bin".to_string(),
+        ];
+
+        let result = create_concat_bin(
+            &main_bin,
+            "data/characters/ahri/skins/skin0.bin",
+            "My Mod",
+            "Me",
+            content_base,
+            &HashMap::new(),
+            &ConcatMergePolicy::LastWins,
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.concat_path, "data/Me_My-Mod__Concat.bin");
+        assert_eq!(result.source_count, 2);
+        assert_eq!(result.entry_count, 3);
+        assert_eq!(result.collisions.len(), 1);
+        assert_eq!(result.collisions[0].sources, ["data/a.bin", "data/b.bin"]);
+        assert_eq!(result.collisions[0].winner.as_deref(), Some("data/b.bin"));
+        assert_eq!(result.source_paths, vec!["data/a.bin", "data/b.bin"]);
+
+        let data = fs::read(content_base.join(&result.concat_path)).unwrap();
+        let concat = read_bin(&data).unwrap();
+        assert!(concat.dependencies.is_empty());
+        let shared = &concat.objects[&league_toolkit::hash::fnv1a::hash_lower("Shared")];
+        let value = shared.get_value(league_toolkit::hash::fnv1a::hash_lower("particleName")).unwrap();
+        assert!(matches!(value, ltk_meta::PropertyValueEnum::String(s) if s.0 == "new"));
+        assert!(!content_base.join("data/Me_My-Mod__Concat.bin.tmp").exists());
+    }
+
+    #[test]
+    fn test_concat_merge_policies() {
+        let dir = tempfile::tempdir().unwrap();
+        let content_base = dir.path();
+        write_source(content_base, "data/a.bin", "    \"Shared\" = VfxSystemDefinitionData {\n        particleName: string = \"a\"\n    }\n    \"Skin\" = VfxSystemDefinitionData {\n        particleName: string = \"a\"\n    }\n");
+        write_source(content_base, "data/b.bin", "    \"Shared\" = VfxSystemDefinitionData {\n        particleName: string = \"b\"\n    }\n");
+
+        let main_path = "data/characters/ahri/skins/skin0.bin";
+        let mut main_bin = crate::core::bin::ltk_bridge::text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nlinked: list[string] = {}\nentries: map[hash,embed] = {\n    \"Skin\" = VfxSystemDefinitionData {\n        particleName: string = \"main\"\n    }\n}\n",
+        )
+        .unwrap();
+        main_bin.dependencies = vec!["DATA/a.bin".to_string(), "DATA/b.bin".to_string()];
+
+        let shared = league_toolkit::hash::fnv1a::hash_lower("Shared");
+        let skin = league_toolkit::hash::fnv1a::hash_lower("Skin");
+        let concat = |policy: &ConcatMergePolicy| {
+            create_concat_bin(&main_bin, main_path, "My Mod", "Me", content_base, &HashMap::new(), policy, None)
+        };
+        let particle_name = |result: &ConcatResult, path_hash: u32| {
+            let concat = read_bin(&fs::read(content_base.join(&result.concat_path)).unwrap()).unwrap();
+            let object = concat.objects.get(&path_hash)?;
+            match object.get_value(league_toolkit::hash::fnv1a::hash_lower("particleName"))? {
+                ltk_meta::PropertyValueEnum::String(s) => Some(s.0.clone()),
+                _ => None,
+            }
+        };
+
+        // The main skin BIN's own definition is listed first
+        let result = concat(&ConcatMergePolicy::LastWins).unwrap();
+        let skin_collision = result.collisions.iter().find(|c| c.path_hash == skin).unwrap();
+        assert_eq!(skin_collision.sources, [main_path, "data/a.bin"]);
+        assert_eq!(particle_name(&result, skin).as_deref(), Some("a"));
+
+        let result = concat(&ConcatMergePolicy::PreferMainSkin).unwrap();
+        assert_eq!(particle_name(&result, skin), None);
+        assert_eq!(particle_name(&result, shared).as_deref(), Some("b"));
+
+        let err = concat(&ConcatMergePolicy::Error).unwrap_err();
+        assert!(err.to_string().contains("2 objects"));
+
+        let mut choices = BTreeMap::from([(shared, "data/a.bin".to_string())]);
+        assert!(concat(&ConcatMergePolicy::Resolve { choices: choices.clone() }).is_err());
+        choices.insert(skin, main_path.to_string());
+        let result = concat(&ConcatMergePolicy::Resolve { choices }).unwrap();
+        assert_eq!(particle_name(&result, shared).as_deref(), Some("a"));
+        assert_eq!(particle_name(&result, skin), None);
+        assert_eq!(result.entry_count, 1);
+    }
 }
@@ -3,7 +3,8 @@
 //! This module provides functionality to convert League of Legends .bin files
 //! between different formats using ltk_meta and ltk_ritobin.
 
-use crate::core::bin::ltk_bridge::{read_bin, write_bin, tree_to_text, text_to_tree};
+use crate::core::bin::ltk_bridge::{read_bin, write_bin, tree_to_text, text_to_tree, HashMapProvider};
+use crate::core::bin::strict::check_ritobin_strict;
 use crate::core::hash::Hashtable;
 use crate::error::{Error, Result};
 use ltk_meta::BinTree;
@@ -55,6 +56,33 @@ pub fn text_to_bin(text: &str, _hashtable: Option<&Hashtable>) -> Result<BinTree
         .map_err(|e| bin_error(format!("Failed to parse text: {}", e)))
 }
 
+/// Convert ritobin text to a BinTree, refusing anything strict mode flags
+///
+/// # Arguments
+/// * `text` - The ritobin text to parse
+/// * `hashes` - BIN hash lists used to check property names
+///
+/// # Returns
+/// A BinTree, or a BinConversion error listing every diagnostic
+pub fn text_to_bin_strict(text: &str, hashes: Option<&HashMapProvider>) -> Result<BinTree> {
+    let diagnostics = check_ritobin_strict(text, hashes);
+    if !diagnostics.is_empty() {
+        let details = diagnostics
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(bin_error(format!(
+            "Strict check failed with {} issue(s):\n{}",
+            diagnostics.len(),
+            details
+        )));
+    }
+
+    text_to_tree(text)
+        .map_err(|e| bin_error(format!("Failed to parse text: {}", e)))
+}
+
 /// Convert a BinTree to JSON format
 ///
 /// Uses serde serialization of the BinTree structure
@@ -102,4 +130,16 @@ mod tests {
         
         assert_eq!(tree.objects.len(), tree2.objects.len());
     }
+
+    #[test]
+    fn test_strict_rejects_lenient_text() {
+        let text = "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {\n  \"a\" = Foo {\n    0x12g4: u8 = 1\n  }\n}\n";
+
+        // The lenient parser accepts it (the bad name hashes to 0)...
+        assert!(text_to_bin(text, None).is_ok());
+
+        // ...strict mode points at the typo
+        let err = text_to_bin_strict(text, None).unwrap_err().to_string();
+        assert!(err.contains("line 6:5"), "{}", err);
+    }
 }
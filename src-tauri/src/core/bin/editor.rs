@@ -0,0 +1,739 @@
+//! Structured BIN editing
+//!
+//! The ritobin text editor rewrites the whole BIN on save, and a single typo
+//! anywhere fails the parse. This module exposes a BIN as a tree of typed
+//! nodes instead, so the frontend can edit one property at a time. Every edit
+//! is checked against the kind the property already has before the BIN is
+//! written back.
+//!
+//! Hashes are sent as numbers together with their resolved name, if known.
+//! Where the frontend names something (property paths, new objects), a
+//! `0x`-prefixed hex string is taken as the hash itself and anything else is
+//! hashed with FNV-1a like ritobin does.
+
+use crate::core::bin::ltk_bridge::{read_bin, write_bin, HashMapProvider};
+use crate::core::store::write_replacing;
+use crate::error::{Error, Result};
+use glam::{Mat4, Vec2, Vec3, Vec4};
+use league_toolkit::hash::fnv1a::hash_lower;
+use league_toolkit::primitives::Color;
+use ltk_meta::value::*;
+use ltk_meta::{BinProperty, BinPropertyKind, BinTree, BinTreeObject, PropertyValueEnum};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Kind of a BIN value, named as in ritobin text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum BinKind {
+    None,
+    Bool,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    Vec2,
+    Vec3,
+    Vec4,
+    Mtx44,
+    Rgba,
+    String,
+    Hash,
+    File,
+    List,
+    List2,
+    Pointer,
+    Embed,
+    Link,
+    Option,
+    Map,
+    Flag,
+}
+
+impl From<BinPropertyKind> for BinKind {
+    fn from(kind: BinPropertyKind) -> Self {
+        use BinPropertyKind as K;
+        match kind {
+            K::None => Self::None,
+            K::Bool => Self::Bool,
+            K::I8 => Self::I8,
+            K::U8 => Self::U8,
+            K::I16 => Self::I16,
+            K::U16 => Self::U16,
+            K::I32 => Self::I32,
+            K::U32 => Self::U32,
+            K::I64 => Self::I64,
+            K::U64 => Self::U64,
+            K::F32 => Self::F32,
+            K::Vector2 => Self::Vec2,
+            K::Vector3 => Self::Vec3,
+            K::Vector4 => Self::Vec4,
+            K::Matrix44 => Self::Mtx44,
+            K::Color => Self::Rgba,
+            K::String => Self::String,
+            K::Hash => Self::Hash,
+            K::WadChunkLink => Self::File,
+            K::Container => Self::List,
+            K::UnorderedContainer => Self::List2,
+            K::Struct => Self::Pointer,
+            K::Embedded => Self::Embed,
+            K::ObjectLink => Self::Link,
+            K::Optional => Self::Option,
+            K::Map => Self::Map,
+            K::BitBool => Self::Flag,
+        }
+    }
+}
+
+impl From<BinKind> for BinPropertyKind {
+    fn from(kind: BinKind) -> Self {
+        use BinPropertyKind as K;
+        match kind {
+            BinKind::None => K::None,
+            BinKind::Bool => K::Bool,
+            BinKind::I8 => K::I8,
+            BinKind::U8 => K::U8,
+            BinKind::I16 => K::I16,
+            BinKind::U16 => K::U16,
+            BinKind::I32 => K::I32,
+            BinKind::U32 => K::U32,
+            BinKind::I64 => K::I64,
+            BinKind::U64 => K::U64,
+            BinKind::F32 => K::F32,
+            BinKind::Vec2 => K::Vector2,
+            BinKind::Vec3 => K::Vector3,
+            BinKind::Vec4 => K::Vector4,
+            BinKind::Mtx44 => K::Matrix44,
+            BinKind::Rgba => K::Color,
+            BinKind::String => K::String,
+            BinKind::Hash => K::Hash,
+            BinKind::File => K::WadChunkLink,
+            BinKind::List => K::Container,
+            BinKind::List2 => K::UnorderedContainer,
+            BinKind::Pointer => K::Struct,
+            BinKind::Embed => K::Embedded,
+            BinKind::Link => K::ObjectLink,
+            BinKind::Option => K::Optional,
+            BinKind::Map => K::Map,
+            BinKind::Flag => K::BitBool,
+        }
+    }
+}
+
+/// A BIN value as the frontend sees it
+///
+/// `name` fields are filled from the hash lists when reading and ignored when
+/// writing; only the hashes are stored in the BIN.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum BinValue {
+    None,
+    Bool { value: bool },
+    I8 { value: i8 },
+    U8 { value: u8 },
+    I16 { value: i16 },
+    U16 { value: u16 },
+    I32 { value: i32 },
+    U32 { value: u32 },
+    I64 {
+        #[ts(type = "number")]
+        value: i64,
+    },
+    U64 {
+        #[ts(type = "number")]
+        value: u64,
+    },
+    F32 { value: f32 },
+    Vec2 { value: [f32; 2] },
+    Vec3 { value: [f32; 3] },
+    Vec4 { value: [f32; 4] },
+    /// Row by row, as ritobin prints it
+    Mtx44 { value: [f32; 16] },
+    Rgba { value: [u8; 4] },
+    String { value: String },
+    Hash {
+        value: u32,
+        #[serde(default)]
+        name: Option<String>,
+    },
+    /// Path hash of a WAD chunk
+    File {
+        #[ts(type = "number")]
+        value: u64,
+    },
+    List { item_kind: BinKind, items: Vec<BinValue> },
+    List2 { item_kind: BinKind, items: Vec<BinValue> },
+    Pointer {
+        class_hash: u32,
+        #[serde(default)]
+        class_name: Option<String>,
+        properties: Vec<BinField>,
+    },
+    Embed {
+        class_hash: u32,
+        #[serde(default)]
+        class_name: Option<String>,
+        properties: Vec<BinField>,
+    },
+    /// Path hash of another object
+    Link {
+        value: u32,
+        #[serde(default)]
+        name: Option<String>,
+    },
+    Option { item_kind: BinKind, value: Option<Box<BinValue>> },
+    Map { key_kind: BinKind, value_kind: BinKind, entries: Vec<BinMapEntry> },
+    Flag { value: bool },
+}
+
+/// A named property of an object or struct
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinField {
+    pub name_hash: u32,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub value: BinValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinMapEntry {
+    pub key: BinValue,
+    pub value: BinValue,
+}
+
+/// A top-level object of a BIN
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinObjectNode {
+    pub path_hash: u32,
+    pub path_name: Option<String>,
+    pub class_hash: u32,
+    pub class_name: Option<String>,
+    pub properties: Vec<BinField>,
+}
+
+/// A whole BIN as a tree of typed nodes
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinTreeNode {
+    pub version: u32,
+    pub is_override: bool,
+    pub dependencies: Vec<String>,
+    pub objects: Vec<BinObjectNode>,
+}
+
+/// Hash of a name from the frontend: `0x`-prefixed hex, or FNV-1a of the name
+pub fn parse_hash(name: &str) -> u32 {
+    name.strip_prefix("0x")
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .unwrap_or_else(|| hash_lower(name))
+}
+
+fn fields_to_nodes(properties: &indexmap::IndexMap<u32, BinProperty>, names: &HashMapProvider) -> Vec<BinField> {
+    properties
+        .values()
+        .map(|prop| BinField {
+            name_hash: prop.name_hash,
+            name: names.fields.get(&prop.name_hash).cloned(),
+            value: to_node(&prop.value, names),
+        })
+        .collect()
+}
+
+/// Converts a parsed value to its node, resolving hash names
+pub fn to_node(value: &PropertyValueEnum, names: &HashMapProvider) -> BinValue {
+    use PropertyValueEnum as P;
+    match value {
+        P::None(_) => BinValue::None,
+        P::Bool(v) => BinValue::Bool { value: v.0 },
+        P::I8(v) => BinValue::I8 { value: v.0 },
+        P::U8(v) => BinValue::U8 { value: v.0 },
+        P::I16(v) => BinValue::I16 { value: v.0 },
+        P::U16(v) => BinValue::U16 { value: v.0 },
+        P::I32(v) => BinValue::I32 { value: v.0 },
+        P::U32(v) => BinValue::U32 { value: v.0 },
+        P::I64(v) => BinValue::I64 { value: v.0 },
+        P::U64(v) => BinValue::U64 { value: v.0 },
+        P::F32(v) => BinValue::F32 { value: v.0 },
+        P::Vector2(v) => BinValue::Vec2 { value: v.0.to_array() },
+        P::Vector3(v) => BinValue::Vec3 { value: v.0.to_array() },
+        P::Vector4(v) => BinValue::Vec4 { value: v.0.to_array() },
+        P::Matrix44(v) => BinValue::Mtx44 { value: v.0.transpose().to_cols_array() },
+        P::Color(v) => BinValue::Rgba { value: [v.0.r, v.0.g, v.0.b, v.0.a] },
+        P::String(v) => BinValue::String { value: v.0.clone() },
+        P::Hash(v) => BinValue::Hash { value: v.0, name: names.hashes.get(&v.0).cloned() },
+        P::WadChunkLink(v) => BinValue::File { value: v.0 },
+        P::Container(c) => BinValue::List {
+            item_kind: c.item_kind.into(),
+            items: c.items.iter().map(|item| to_node(item, names)).collect(),
+        },
+        P::UnorderedContainer(c) => BinValue::List2 {
+            item_kind: c.0.item_kind.into(),
+            items: c.0.items.iter().map(|item| to_node(item, names)).collect(),
+        },
+        P::Struct(s) => BinValue::Pointer {
+            class_hash: s.class_hash,
+            class_name: names.types.get(&s.class_hash).cloned(),
+            properties: fields_to_nodes(&s.properties, names),
+        },
+        P::Embedded(e) => BinValue::Embed {
+            class_hash: e.0.class_hash,
+            class_name: names.types.get(&e.0.class_hash).cloned(),
+            properties: fields_to_nodes(&e.0.properties, names),
+        },
+        P::ObjectLink(v) => BinValue::Link { value: v.0, name: names.entries.get(&v.0).cloned() },
+        P::Optional(o) => BinValue::Option {
+            item_kind: o.kind.into(),
+            value: o.value.as_ref().map(|value| Box::new(to_node(value, names))),
+        },
+        P::Map(m) => BinValue::Map {
+            key_kind: m.key_kind.into(),
+            value_kind: m.value_kind.into(),
+            entries: m
+                .entries
+                .iter()
+                .map(|(key, value)| BinMapEntry { key: to_node(&key.0, names), value: to_node(value, names) })
+                .collect(),
+        },
+        P::BitBool(v) => BinValue::Flag { value: v.0 },
+    }
+}
+
+impl BinValue {
+    pub fn kind(&self) -> BinKind {
+        match self {
+            Self::None => BinKind::None,
+            Self::Bool { .. } => BinKind::Bool,
+            Self::I8 { .. } => BinKind::I8,
+            Self::U8 { .. } => BinKind::U8,
+            Self::I16 { .. } => BinKind::I16,
+            Self::U16 { .. } => BinKind::U16,
+            Self::I32 { .. } => BinKind::I32,
+            Self::U32 { .. } => BinKind::U32,
+            Self::I64 { .. } => BinKind::I64,
+            Self::U64 { .. } => BinKind::U64,
+            Self::F32 { .. } => BinKind::F32,
+            Self::Vec2 { .. } => BinKind::Vec2,
+            Self::Vec3 { .. } => BinKind::Vec3,
+            Self::Vec4 { .. } => BinKind::Vec4,
+            Self::Mtx44 { .. } => BinKind::Mtx44,
+            Self::Rgba { .. } => BinKind::Rgba,
+            Self::String { .. } => BinKind::String,
+            Self::Hash { .. } => BinKind::Hash,
+            Self::File { .. } => BinKind::File,
+            Self::List { .. } => BinKind::List,
+            Self::List2 { .. } => BinKind::List2,
+            Self::Pointer { .. } => BinKind::Pointer,
+            Self::Embed { .. } => BinKind::Embed,
+            Self::Link { .. } => BinKind::Link,
+            Self::Option { .. } => BinKind::Option,
+            Self::Map { .. } => BinKind::Map,
+            Self::Flag { .. } => BinKind::Flag,
+        }
+    }
+}
+
+/// Lists, options and maps can't hold each other
+fn check_item_kind(kind: BinKind, what: &str) -> Result<BinPropertyKind> {
+    let kind = BinPropertyKind::from(kind);
+    if kind.is_container() {
+        return Err(Error::InvalidInput(format!("{} can't be a {:?}", what, BinKind::from(kind))));
+    }
+    Ok(kind)
+}
+
+fn items_from_nodes(item_kind: BinKind, items: &[BinValue]) -> Result<ContainerValue> {
+    let kind = check_item_kind(item_kind, "List items")?;
+    let items = items
+        .iter()
+        .map(|item| {
+            if item.kind() != item_kind {
+                return Err(Error::InvalidInput(format!(
+                    "List of {:?} can't hold a {:?}",
+                    item_kind,
+                    item.kind()
+                )));
+            }
+            from_node(item)
+        })
+        .collect::<Result<_>>()?;
+    Ok(ContainerValue { item_kind: kind, items })
+}
+
+fn struct_from_nodes(class_hash: u32, properties: &[BinField]) -> Result<StructValue> {
+    let properties = properties
+        .iter()
+        .map(|field| Ok((field.name_hash, BinProperty { name_hash: field.name_hash, value: from_node(&field.value)? })))
+        .collect::<Result<_>>()?;
+    Ok(StructValue { class_hash, properties })
+}
+
+/// Converts a node back to a value, checking that containers hold what they declare
+pub fn from_node(node: &BinValue) -> Result<PropertyValueEnum> {
+    use PropertyValueEnum as P;
+    Ok(match node {
+        BinValue::None => P::None(NoneValue),
+        BinValue::Bool { value } => P::Bool(BoolValue(*value)),
+        BinValue::I8 { value } => P::I8(I8Value(*value)),
+        BinValue::U8 { value } => P::U8(U8Value(*value)),
+        BinValue::I16 { value } => P::I16(I16Value(*value)),
+        BinValue::U16 { value } => P::U16(U16Value(*value)),
+        BinValue::I32 { value } => P::I32(I32Value(*value)),
+        BinValue::U32 { value } => P::U32(U32Value(*value)),
+        BinValue::I64 { value } => P::I64(I64Value(*value)),
+        BinValue::U64 { value } => P::U64(U64Value(*value)),
+        BinValue::F32 { value } => P::F32(F32Value(*value)),
+        BinValue::Vec2 { value } => P::Vector2(Vector2Value(Vec2::from_array(*value))),
+        BinValue::Vec3 { value } => P::Vector3(Vector3Value(Vec3::from_array(*value))),
+        BinValue::Vec4 { value } => P::Vector4(Vector4Value(Vec4::from_array(*value))),
+        BinValue::Mtx44 { value } => P::Matrix44(Matrix44Value(Mat4::from_cols_array(value).transpose())),
+        BinValue::Rgba { value: [r, g, b, a] } => P::Color(ColorValue(Color::new(*r, *g, *b, *a))),
+        BinValue::String { value } => P::String(StringValue(value.clone())),
+        BinValue::Hash { value, .. } => P::Hash(HashValue(*value)),
+        BinValue::File { value } => P::WadChunkLink(WadChunkLinkValue(*value)),
+        BinValue::List { item_kind, items } => P::Container(items_from_nodes(*item_kind, items)?),
+        BinValue::List2 { item_kind, items } => {
+            P::UnorderedContainer(UnorderedContainerValue(items_from_nodes(*item_kind, items)?))
+        }
+        BinValue::Pointer { class_hash, properties, .. } => P::Struct(struct_from_nodes(*class_hash, properties)?),
+        BinValue::Embed { class_hash, properties, .. } => {
+            P::Embedded(EmbeddedValue(struct_from_nodes(*class_hash, properties)?))
+        }
+        BinValue::Link { value, .. } => P::ObjectLink(ObjectLinkValue(*value)),
+        BinValue::Option { item_kind, value } => {
+            let kind = check_item_kind(*item_kind, "Option values")?;
+            let value = match value {
+                Some(value) if value.kind() != *item_kind => {
+                    return Err(Error::InvalidInput(format!(
+                        "Option of {:?} can't hold a {:?}",
+                        item_kind,
+                        value.kind()
+                    )));
+                }
+                Some(value) => Some(Box::new(from_node(value)?)),
+                None => None,
+            };
+            P::Optional(OptionalValue { kind, value })
+        }
+        BinValue::Map { key_kind, value_kind, entries } => {
+            let key = BinPropertyKind::from(*key_kind);
+            if !key.is_primitive() {
+                return Err(Error::InvalidInput(format!("Map keys can't be a {:?}", key_kind)));
+            }
+            let value = check_item_kind(*value_kind, "Map values")?;
+            let entries = entries
+                .iter()
+                .map(|entry| {
+                    if entry.key.kind() != *key_kind || entry.value.kind() != *value_kind {
+                        return Err(Error::InvalidInput(format!(
+                            "Map of {:?} -> {:?} can't hold {:?} -> {:?}",
+                            key_kind,
+                            value_kind,
+                            entry.key.kind(),
+                            entry.value.kind()
+                        )));
+                    }
+                    Ok((PropertyValueUnsafeEq(from_node(&entry.key)?), from_node(&entry.value)?))
+                })
+                .collect::<Result<_>>()?;
+            P::Map(MapValue { key_kind: key, value_kind: value, entries })
+        }
+        BinValue::Flag { value } => P::BitBool(BitBoolValue(*value)),
+    })
+}
+
+/// Converts a whole BIN to nodes
+pub fn tree_to_node(tree: &BinTree, names: &HashMapProvider) -> BinTreeNode {
+    BinTreeNode {
+        version: tree.version,
+        is_override: tree.is_override,
+        dependencies: tree.dependencies.clone(),
+        objects: tree.objects.values().map(|object| object_to_node(object, names)).collect(),
+    }
+}
+
+fn object_to_node(object: &BinTreeObject, names: &HashMapProvider) -> BinObjectNode {
+    BinObjectNode {
+        path_hash: object.path_hash,
+        path_name: names.entries.get(&object.path_hash).cloned(),
+        class_hash: object.class_hash,
+        class_name: names.types.get(&object.class_hash).cloned(),
+        properties: fields_to_nodes(&object.properties, names),
+    }
+}
+
+/// Whether a map key matches a path segment
+fn key_matches(key: &PropertyValueEnum, segment: &str) -> bool {
+    use PropertyValueEnum as P;
+    match key {
+        P::String(s) => s.0 == segment,
+        P::Hash(HashValue(hash)) | P::ObjectLink(ObjectLinkValue(hash)) => *hash == parse_hash(segment),
+        P::I8(v) => segment.parse() == Ok(v.0),
+        P::U8(v) => segment.parse() == Ok(v.0),
+        P::I16(v) => segment.parse() == Ok(v.0),
+        P::U16(v) => segment.parse() == Ok(v.0),
+        P::I32(v) => segment.parse() == Ok(v.0),
+        P::U32(v) => segment.parse() == Ok(v.0),
+        P::I64(v) => segment.parse() == Ok(v.0),
+        P::U64(v) => segment.parse() == Ok(v.0),
+        P::WadChunkLink(v) => segment.parse() == Ok(v.0),
+        _ => false,
+    }
+}
+
+/// Steps from a value into one of its children
+///
+/// Structs take a field name, lists an index, options `0` and maps a key.
+fn child_mut<'a>(value: &'a mut PropertyValueEnum, segment: &str) -> Option<&'a mut PropertyValueEnum> {
+    use PropertyValueEnum as P;
+    match value {
+        P::Struct(s) | P::Embedded(EmbeddedValue(s)) => {
+            s.properties.get_mut(&parse_hash(segment)).map(|prop| &mut prop.value)
+        }
+        P::Container(c) | P::UnorderedContainer(UnorderedContainerValue(c)) => {
+            c.items.get_mut(segment.parse::<usize>().ok()?)
+        }
+        P::Optional(o) if segment == "0" => o.value.as_deref_mut(),
+        P::Map(m) => m
+            .entries
+            .iter_mut()
+            .find(|(key, _)| key_matches(&key.0, segment))
+            .map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// Same kind, and for lists, options and maps the same item kinds
+fn same_type(existing: &PropertyValueEnum, new: &PropertyValueEnum) -> bool {
+    use PropertyValueEnum as P;
+    match (existing, new) {
+        (P::Container(a), P::Container(b)) => a.item_kind == b.item_kind,
+        (P::UnorderedContainer(a), P::UnorderedContainer(b)) => a.0.item_kind == b.0.item_kind,
+        (P::Optional(a), P::Optional(b)) => a.kind == b.kind,
+        (P::Map(a), P::Map(b)) => a.key_kind == b.key_kind && a.value_kind == b.value_kind,
+        _ => existing.kind() == new.kind(),
+    }
+}
+
+/// Replaces the value at `property_path` inside an object
+///
+/// # Arguments
+/// * `tree` - BIN to edit
+/// * `object_hash` - Path hash of the object
+/// * `property_path` - Field of the object, then one segment per level below
+///   it: field names in structs, indices in lists, `0` in options, keys in maps
+/// * `value` - New value; must have the kind of the value it replaces
+pub fn set_property(
+    tree: &mut BinTree,
+    object_hash: u32,
+    property_path: &[String],
+    value: &BinValue,
+) -> Result<()> {
+    let (first, rest) = property_path
+        .split_first()
+        .ok_or_else(|| Error::InvalidInput("Property path is empty".into()))?;
+    let object = tree
+        .objects
+        .get_mut(&object_hash)
+        .ok_or_else(|| Error::InvalidInput(format!("No object with hash {:08x}", object_hash)))?;
+
+    let mut target = object
+        .properties
+        .get_mut(&parse_hash(first))
+        .map(|prop| &mut prop.value)
+        .ok_or_else(|| Error::InvalidInput(format!("Object has no property '{}'", first)))?;
+    for (depth, segment) in rest.iter().enumerate() {
+        target = child_mut(target, segment).ok_or_else(|| {
+            Error::InvalidInput(format!("Nothing at '{}'", property_path[..depth + 2].join(".")))
+        })?;
+    }
+
+    let new = from_node(value)?;
+    if !same_type(target, &new) {
+        return Err(Error::InvalidInput(format!(
+            "'{}' is a {:?}, not a {:?}",
+            property_path.join("."),
+            BinKind::from(target.kind()),
+            value.kind()
+        )));
+    }
+    *target = new;
+    Ok(())
+}
+
+/// Adds a new object to a BIN
+///
+/// # Returns
+/// The path hash of the new object
+pub fn add_object(tree: &mut BinTree, object_path: &str, class_name: &str, properties: &[BinField]) -> Result<u32> {
+    let path_hash = parse_hash(object_path);
+    if tree.objects.contains_key(&path_hash) {
+        return Err(Error::InvalidInput(format!("Object '{}' already exists", object_path)));
+    }
+    let body = struct_from_nodes(parse_hash(class_name), properties)?;
+    tree.objects.insert(
+        path_hash,
+        BinTreeObject { path_hash, class_hash: body.class_hash, properties: body.properties },
+    );
+    Ok(path_hash)
+}
+
+pub(crate) fn load(path: &Path) -> Result<BinTree> {
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    read_bin(&data).map_err(|e| Error::bin_conversion_with_path(e.to_string(), path))
+}
+
+/// Writes an edited BIN and drops its ritobin text cache, which is now stale
+pub(crate) fn save(path: &Path, tree: &BinTree) -> Result<()> {
+    let data = write_bin(tree).map_err(|e| Error::bin_conversion_with_path(e.to_string(), path))?;
+    write_replacing(path, &data).map_err(|e| Error::io_with_path(e, path))?;
+
+    let mut cache = path.as_os_str().to_owned();
+    cache.push(".ritobin");
+    let _ = fs::remove_file(cache);
+    Ok(())
+}
+
+/// Reads a BIN file as a tree of nodes
+pub fn read_bin_tree(path: &Path, names: &HashMapProvider) -> Result<BinTreeNode> {
+    Ok(tree_to_node(&load(path)?, names))
+}
+
+/// Edits one property of a BIN file
+///
+/// # Returns
+/// The object after the edit
+pub fn set_bin_property(
+    path: &Path,
+    object_hash: u32,
+    property_path: &[String],
+    value: &BinValue,
+    names: &HashMapProvider,
+) -> Result<BinObjectNode> {
+    let mut tree = load(path)?;
+    set_property(&mut tree, object_hash, property_path, value)?;
+    save(path, &tree)?;
+    Ok(object_to_node(&tree.objects[&object_hash], names))
+}
+
+/// Adds an object to a BIN file
+///
+/// # Returns
+/// The new object
+pub fn add_bin_object(
+    path: &Path,
+    object_path: &str,
+    class_name: &str,
+    properties: &[BinField],
+    names: &HashMapProvider,
+) -> Result<BinObjectNode> {
+    let mut tree = load(path)?;
+    let path_hash = add_object(&mut tree, object_path, class_name, properties)?;
+    save(path, &tree)?;
+    Ok(object_to_node(&tree.objects[&path_hash], names))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::text_to_tree;
+
+    const SKIN: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin0" = SkinCharacterDataProperties {
+        skinScale: f32 = 1.2
+        tint: rgba = { 255, 128, 0, 255 }
+        skinMeshProperties: embed = SkinMeshDataProperties {
+            texture: string = "ASSETS/Ahri.dds"
+        }
+        tags: list[string] = {
+            "a"
+            "b"
+        }
+        sounds: map[string,u32] = {
+            "attack" = 1
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_nodes_round_trip() {
+        let tree = text_to_tree(SKIN).unwrap();
+        let node = tree_to_node(&tree, &HashMapProvider::new());
+        let json = serde_json::to_string(&node).unwrap();
+        let node: BinTreeNode = serde_json::from_str(&json).unwrap();
+
+        let object = &node.objects[0];
+        let rebuilt: Vec<PropertyValueEnum> =
+            object.properties.iter().map(|field| from_node(&field.value).unwrap()).collect();
+        let original: Vec<PropertyValueEnum> =
+            tree.objects[&object.path_hash].properties.values().map(|p| p.value.clone()).collect();
+        assert_eq!(rebuilt, original);
+    }
+
+    #[test]
+    fn test_set_property_checks_types() {
+        let mut tree = text_to_tree(SKIN).unwrap();
+        let object = parse_hash("Characters/Ahri/Skins/Skin0");
+        let path = |segments: &[&str]| segments.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let texture = BinValue::String { value: "ASSETS/Other.dds".into() };
+        set_property(&mut tree, object, &path(&["skinMeshProperties", "texture"]), &texture).unwrap();
+        set_property(&mut tree, object, &path(&["tags", "1"]), &BinValue::String { value: "c".into() }).unwrap();
+        set_property(&mut tree, object, &path(&["sounds", "attack"]), &BinValue::U32 { value: 7 }).unwrap();
+
+        let node = object_to_node(&tree.objects[&object], &HashMapProvider::new());
+        let field = |name: &str| &node.properties.iter().find(|f| f.name_hash == hash_lower(name)).unwrap().value;
+        let BinValue::Embed { properties, .. } = field("skinMeshProperties") else { panic!("expected embed") };
+        assert_eq!(properties[0].value, texture);
+        let BinValue::List { items, .. } = field("tags") else { panic!("expected list") };
+        assert_eq!(items[1], BinValue::String { value: "c".into() });
+
+        // Wrong kinds and missing paths are refused without touching the tree
+        let before = tree.clone();
+        assert!(set_property(&mut tree, object, &path(&["skinScale"]), &BinValue::U32 { value: 1 }).is_err());
+        let wrong_list = BinValue::List { item_kind: BinKind::U32, items: vec![] };
+        assert!(set_property(&mut tree, object, &path(&["tags"]), &wrong_list).is_err());
+        assert!(set_property(&mut tree, object, &path(&["tags", "5"]), &texture).is_err());
+        assert!(set_property(&mut tree, object, &path(&["missing"]), &texture).is_err());
+        assert_eq!(tree, before);
+    }
+
+    #[test]
+    fn test_add_bin_object_writes_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("skin0.bin");
+        fs::write(&path, write_bin(&text_to_tree(SKIN).unwrap()).unwrap()).unwrap();
+        fs::write(dir.path().join("skin0.bin.ritobin"), "stale").unwrap();
+
+        let properties = vec![BinField {
+            name_hash: hash_lower("mValue"),
+            name: None,
+            value: BinValue::Vec3 { value: [1.0, 2.0, 3.0] },
+        }];
+        let names = HashMapProvider::new();
+        let object = add_bin_object(&path, "Characters/Ahri/Extra", "ExtraData", &properties, &names).unwrap();
+        assert_eq!(object.class_hash, hash_lower("ExtraData"));
+        assert!(!dir.path().join("skin0.bin.ritobin").exists());
+
+        let tree = read_bin_tree(&path, &names).unwrap();
+        assert_eq!(tree.objects.len(), 2);
+        assert_eq!(tree.objects[1].properties, properties);
+        assert!(add_bin_object(&path, "Characters/Ahri/Extra", "ExtraData", &[], &names).is_err());
+    }
+}
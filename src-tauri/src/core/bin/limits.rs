@@ -0,0 +1,72 @@
+//! User setting for the BIN size limit
+//!
+//! The limit protects against corrupt files whose parse would exhaust memory,
+//! but users working on map mods hit it with legitimate files. The setting is
+//! kept in the app data directory and applied to `ltk_bridge` on startup.
+
+use crate::core::bin::ltk_bridge::{set_bin_size_limit, MAX_BIN_SIZE};
+use crate::core::settings::{load_json_setting, save_json_setting};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use ts_rs::TS;
+
+/// Settings file inside the app data directory
+const SETTINGS_FILE: &str = "bin_settings.json";
+
+const MB: u64 = 1024 * 1024;
+
+/// Largest limit that can be configured; beyond this a full parse is not
+/// sensible and streaming mode should be used instead
+pub const MAX_CONFIGURABLE_MB: u64 = 1024;
+
+/// Persisted BIN parsing settings
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinSettings {
+    /// Files above this size are rejected by a full parse
+    #[serde(default = "default_max_size_mb")]
+    #[ts(type = "number")]
+    pub max_bin_size_mb: u64,
+}
+
+fn default_max_size_mb() -> u64 {
+    MAX_BIN_SIZE as u64 / MB
+}
+
+impl Default for BinSettings {
+    fn default() -> Self {
+        Self {
+            max_bin_size_mb: default_max_size_mb(),
+        }
+    }
+}
+
+impl BinSettings {
+    /// Checks the limit is within the supported range
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=MAX_CONFIGURABLE_MB).contains(&self.max_bin_size_mb) {
+            return Err(Error::InvalidInput(format!(
+                "BIN size limit must be between 1 and {} MB",
+                MAX_CONFIGURABLE_MB
+            )));
+        }
+        Ok(())
+    }
+
+    /// Makes this limit the one used by BIN parsing
+    pub fn apply(&self) {
+        set_bin_size_limit((self.max_bin_size_mb.clamp(1, MAX_CONFIGURABLE_MB) * MB) as usize);
+    }
+}
+
+/// Loads the BIN settings, falling back to defaults
+pub fn load_settings(app_data_dir: &Path) -> BinSettings {
+    load_json_setting(app_data_dir, SETTINGS_FILE)
+}
+
+/// Writes the BIN settings to the app data directory
+pub fn save_settings(app_data_dir: &Path, settings: &BinSettings) -> Result<()> {
+    settings.validate()?;
+    save_json_setting(app_data_dir, SETTINGS_FILE, settings)
+}
@@ -3,14 +3,34 @@
 //! This module provides a simplified interface to the League Toolkit libraries,
 //! wrapping their APIs for use throughout the application.
 
-use std::io::Cursor;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::OnceLock;
-use parking_lot::RwLock;
+use byteorder::{ReadBytesExt, LE};
+use parking_lot::{Mutex, RwLock};
 use ltk_meta::{BinTree, BinTreeObject};
 
-/// Maximum allowed BIN file size (50MB - no legitimate BIN should be larger)
+/// Default maximum BIN file size for a full parse (50MB). Champion BINs stay
+/// far below this; some map and global BINs don't, so the limit can be raised
+/// with [`set_bin_size_limit`] or such files opened with [`LazyBin`].
 pub const MAX_BIN_SIZE: usize = 50 * 1024 * 1024;
 
+/// Size limit currently applied by [`read_bin`]
+static BIN_SIZE_LIMIT: AtomicUsize = AtomicUsize::new(MAX_BIN_SIZE);
+
+/// Returns the size limit for fully parsed BIN files, in bytes
+pub fn bin_size_limit() -> usize {
+    BIN_SIZE_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Sets the size limit for fully parsed BIN files, in bytes
+pub fn set_bin_size_limit(bytes: usize) {
+    BIN_SIZE_LIMIT.store(bytes, Ordering::Relaxed);
+}
+
 /// Error type for BIN operations
 #[derive(Debug)]
 pub struct BinError(pub String);
@@ -36,7 +56,8 @@ pub type Result<T> = std::result::Result<T, BinError>;
 ///
 /// # Safety
 /// This function validates file size and magic bytes to prevent memory issues
-/// from corrupt files. Files larger than 50MB are rejected.
+/// from corrupt files. Files larger than [`bin_size_limit`] (50MB unless
+/// configured otherwise) are rejected.
 pub fn read_bin(data: &[u8]) -> Result<BinTree> {
     // DEFENSIVE: Log file info before parsing
     tracing::debug!(
@@ -46,16 +67,17 @@ pub fn read_bin(data: &[u8]) -> Result<BinTree> {
     );
 
     // Reject obviously corrupt files (too large)
-    if data.len() > MAX_BIN_SIZE {
+    let limit = bin_size_limit();
+    if data.len() > limit {
         tracing::error!(
             "BIN file rejected: {} bytes exceeds max size of {} bytes",
             data.len(),
-            MAX_BIN_SIZE
+            limit
         );
         return Err(BinError(format!(
-            "BIN file too large ({} bytes, max {} bytes) - likely corrupt",
+            "BIN file too large ({} bytes, max {} bytes) - likely corrupt; raise the BIN size limit or open it in streaming mode",
             data.len(),
-            MAX_BIN_SIZE
+            limit
         )));
     }
 
@@ -125,6 +147,187 @@ pub fn read_bin(data: &[u8]) -> Result<BinTree> {
     }
 }
 
+/// Header of one object in a [`LazyBin`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LazyBinObject {
+    pub path_hash: u32,
+    pub class_hash: u32,
+    /// Size of the object body in bytes
+    pub size: u32,
+    /// File offset of the object's size field
+    offset: u64,
+}
+
+/// A BIN file opened in streaming mode
+///
+/// Only the header, dependency list and object headers are read when the
+/// file is opened; object bodies stay on disk and are parsed on demand. This
+/// keeps memory proportional to what is actually looked at, which is what
+/// makes map and global BINs above [`bin_size_limit`] usable.
+pub struct LazyBin {
+    pub version: u32,
+    pub is_override: bool,
+    pub dependencies: Vec<String>,
+    /// Total size of the file in bytes
+    pub file_size: u64,
+    objects: Vec<LazyBinObject>,
+    index: HashMap<u32, usize>,
+    reader: Mutex<BufReader<File>>,
+}
+
+impl LazyBin {
+    /// Opens a BIN file and reads its object headers
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|e| BinError(format!("Failed to open '{}': {}", path.display(), e)))?;
+        let file_size = file
+            .metadata()
+            .map_err(|e| BinError(format!("Failed to read metadata of '{}': {}", path.display(), e)))?
+            .len();
+        let mut reader = BufReader::new(file);
+
+        let io_err = |e: std::io::Error| BinError(format!("Failed to read BIN header: {}", e));
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic).map_err(io_err)?;
+        let is_override = match &magic {
+            b"PROP" => false,
+            b"PTCH" => {
+                // Override version and object count, then the PROP section
+                reader.seek_relative(8).map_err(io_err)?;
+                reader.read_exact(&mut magic).map_err(io_err)?;
+                if &magic != b"PROP" {
+                    return Err(BinError(format!(
+                        "Expected PROP section after PTCH, got {:02x?}",
+                        magic
+                    )));
+                }
+                true
+            }
+            _ => {
+                return Err(BinError(format!(
+                    "Invalid BIN magic bytes: {:02x?} (expected PROP or PTCH)",
+                    magic
+                )))
+            }
+        };
+
+        let version = reader.read_u32::<LE>().map_err(io_err)?;
+        if !(1..=3).contains(&version) {
+            return Err(BinError(format!("Unsupported BIN version {}", version)));
+        }
+
+        let mut dependencies = Vec::new();
+        if version >= 2 {
+            let count = reader.read_u32::<LE>().map_err(io_err)?;
+            for _ in 0..count {
+                let len = reader.read_u16::<LE>().map_err(io_err)? as usize;
+                let mut buf = vec![0u8; len];
+                reader.read_exact(&mut buf).map_err(io_err)?;
+                dependencies.push(String::from_utf8_lossy(&buf).into_owned());
+            }
+        }
+
+        let object_count = reader.read_u32::<LE>().map_err(io_err)? as u64;
+        // Each object needs at least a class hash and a 4-byte size field
+        if object_count * 8 > file_size {
+            return Err(BinError(format!(
+                "BIN claims {} objects but is only {} bytes - likely corrupt",
+                object_count, file_size
+            )));
+        }
+        let class_hashes = (0..object_count)
+            .map(|_| reader.read_u32::<LE>())
+            .collect::<std::io::Result<Vec<u32>>>()
+            .map_err(io_err)?;
+
+        let mut objects = Vec::with_capacity(class_hashes.len());
+        let mut index = HashMap::with_capacity(class_hashes.len());
+        for class_hash in class_hashes {
+            let offset = reader.stream_position().map_err(io_err)?;
+            let size = reader.read_u32::<LE>().map_err(io_err)?;
+            if size < 4 || offset + 4 + size as u64 > file_size {
+                return Err(BinError(format!(
+                    "Object at offset {} has invalid size {} - likely corrupt",
+                    offset, size
+                )));
+            }
+            let path_hash = reader.read_u32::<LE>().map_err(io_err)?;
+            // The size counts the path hash that was just read
+            reader.seek_relative(size as i64 - 4).map_err(io_err)?;
+
+            index.insert(path_hash, objects.len());
+            objects.push(LazyBinObject { path_hash, class_hash, size, offset });
+        }
+
+        tracing::debug!(
+            "Opened {} in streaming mode: {} objects, {} dependencies",
+            path.display(),
+            objects.len(),
+            dependencies.len()
+        );
+
+        Ok(Self {
+            version,
+            is_override,
+            dependencies,
+            file_size,
+            objects,
+            index,
+            reader: Mutex::new(reader),
+        })
+    }
+
+    /// Object headers in file order
+    pub fn objects(&self) -> &[LazyBinObject] {
+        &self.objects
+    }
+
+    /// Reads and parses the body of one object
+    ///
+    /// # Returns
+    /// The object, or None if the file has no object with that path hash
+    pub fn load_object(&self, path_hash: u32) -> Result<Option<BinTreeObject>> {
+        let Some(&position) = self.index.get(&path_hash) else {
+            return Ok(None);
+        };
+        let header = self.objects[position];
+
+        let mut buf = vec![0u8; header.size as usize + 4];
+        {
+            let mut reader = self.reader.lock();
+            reader
+                .seek(SeekFrom::Start(header.offset))
+                .and_then(|_| reader.read_exact(&mut buf))
+                .map_err(|e| BinError(format!("Failed to read object {:08x}: {}", path_hash, e)))?;
+        }
+
+        // Old BINs use a different property type numbering; BinTree::from_reader
+        // makes the same fallback for the whole file
+        BinTreeObject::from_reader(&mut Cursor::new(&buf), header.class_hash, false)
+            .or_else(|_| BinTreeObject::from_reader(&mut Cursor::new(&buf), header.class_hash, true))
+            .map(Some)
+            .map_err(|e| BinError(format!("Failed to parse object {:08x}: {}", path_hash, e)))
+    }
+
+    /// Builds a tree holding only the requested objects, keeping the file's
+    /// dependencies so it converts to text like the full file would
+    ///
+    /// Unknown path hashes are skipped.
+    pub fn load_tree(&self, path_hashes: &[u32]) -> Result<BinTree> {
+        let mut objects = Vec::with_capacity(path_hashes.len());
+        for &path_hash in path_hashes {
+            if let Some(object) = self.load_object(path_hash)? {
+                objects.push(object);
+            }
+        }
+        let mut tree = BinTree::new(objects, self.dependencies.iter().cloned());
+        tree.is_override = self.is_override;
+        Ok(tree)
+    }
+}
+
 /// Write a BinTree to binary format.
 ///
 /// # Arguments
@@ -299,3 +502,52 @@ pub fn remove_object(tree: &mut BinTree, path_hash: u32) -> Option<BinTreeObject
 
 // Re-export ltk_ritobin types for hash provider support
 pub use ltk_ritobin::HashMapProvider;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltk_meta::value::StringValue;
+    use ltk_meta::PropertyValueEnum;
+
+    fn object(path_hash: u32, text: &str) -> BinTreeObject {
+        BinTreeObject::builder(path_hash, 0xC1A55)
+            .property(0xF1E1D, PropertyValueEnum::String(StringValue(text.to_string())))
+            .build()
+    }
+
+    #[test]
+    fn test_lazy_bin_loads_objects_on_demand() {
+        let tree = BinTree::new(
+            [object(1, "first"), object(2, "second"), object(3, "third")],
+            ["data/shared.bin"],
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("map.bin");
+        std::fs::write(&path, write_bin(&tree).unwrap()).unwrap();
+
+        let bin = LazyBin::open(&path).unwrap();
+        assert_eq!(bin.dependencies, vec!["data/shared.bin"]);
+        let hashes: Vec<u32> = bin.objects().iter().map(|o| o.path_hash).collect();
+        assert_eq!(hashes, vec![1, 2, 3]);
+        assert!(bin.objects().iter().all(|o| o.class_hash == 0xC1A55));
+
+        assert_eq!(bin.load_object(2).unwrap(), Some(object(2, "second")));
+        assert_eq!(bin.load_object(99).unwrap(), None);
+
+        let partial = bin.load_tree(&[3, 1]).unwrap();
+        assert_eq!(partial.objects.len(), 2);
+        assert_eq!(partial.objects.get(&3), Some(&object(3, "third")));
+        assert_eq!(partial.dependencies, tree.dependencies);
+    }
+
+    #[test]
+    fn test_lazy_bin_rejects_truncated_file() {
+        let tree = BinTree::new([object(1, "first")], Vec::<String>::new());
+        let data = write_bin(&tree).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.bin");
+        std::fs::write(&path, &data[..data.len() - 3]).unwrap();
+
+        assert!(LazyBin::open(&path).is_err());
+    }
+}
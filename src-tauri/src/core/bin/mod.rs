@@ -2,6 +2,12 @@
 pub mod ltk_bridge;
 pub mod converter;
 pub mod concat;
+pub mod strict;
+pub mod closure;
+pub mod cache;
+pub mod limits;
+pub mod editor;
+pub mod search;
 
 // Re-export ltk-based functions from bridge
 #[allow(unused_imports)]
@@ -14,6 +20,8 @@ pub use ltk_bridge::{
     get_cached_bin_hashes,
     text_to_tree,
     HashMapProvider,
+    LazyBin,
+    bin_size_limit,
     MAX_BIN_SIZE,
 };
 
@@ -26,7 +34,7 @@ pub use ltk_bridge::read_bin;
 pub use ltk_bridge::write_bin;
 
 // Re-export converter functions
-pub use converter::{bin_to_text, text_to_bin, bin_to_json, json_to_bin};
+pub use converter::{bin_to_text, text_to_bin, text_to_bin_strict, bin_to_json, json_to_bin};
 
 // Re-export concat utilities (used by refather)
 #[allow(unused_imports)]
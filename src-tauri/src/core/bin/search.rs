@@ -0,0 +1,592 @@
+//! Project-wide BIN search
+//!
+//! Skins spread their data over dozens of linked BINs, so finding the one
+//! that references a particular particle or texture means opening them one by
+//! one. The search parses every BIN of a project and reports each match with
+//! the object and property path it sits at. Paths use the segments the
+//! structured editor takes (`set_bin_property`), so a match can be opened and
+//! edited directly.
+
+use crate::core::bin::ltk_bridge::{read_bin, HashMapProvider};
+use crate::core::bin::editor::{self, parse_hash};
+use crate::error::{Error, Result};
+use ltk_meta::value::*;
+use ltk_meta::PropertyValueEnum;
+use rayon::prelude::*;
+use regex::{NoExpand, Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+use xxhash_rust::xxh64::xxh64;
+
+/// How the search query is interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum BinSearchMode {
+    /// Substring of strings and of resolved hash names
+    #[default]
+    Text,
+    /// A name or `0x` hash, compared with hash, link and file values
+    Hash,
+    /// Regular expression over strings and resolved hash names
+    Regex,
+}
+
+/// One place a query matched
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinSearchMatch {
+    /// BIN file, relative to the searched folder
+    pub file: String,
+    pub object_hash: u32,
+    pub object_name: Option<String>,
+    /// Empty when the object's own path matched
+    pub property_path: Vec<String>,
+    /// The matched value, as text
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinSearchResult {
+    pub matches: Vec<BinSearchMatch>,
+    pub files_searched: usize,
+    /// BINs that couldn't be parsed, with the reason
+    pub files_skipped: Vec<String>,
+    /// More matches were found than `max_results`
+    pub truncated: bool,
+}
+
+enum Matcher {
+    Text { needle: String, case_sensitive: bool },
+    Hash { fnv: u32, xxh: u64 },
+    Regex(Regex),
+}
+
+impl Matcher {
+    fn new(query: &str, mode: BinSearchMode, case_sensitive: bool) -> Result<Self> {
+        if query.is_empty() {
+            return Err(Error::InvalidInput("Search query is empty".into()));
+        }
+        Ok(match mode {
+            BinSearchMode::Text => Self::Text {
+                needle: if case_sensitive { query.to_string() } else { query.to_lowercase() },
+                case_sensitive,
+            },
+            BinSearchMode::Hash => Self::Hash {
+                fnv: parse_hash(query),
+                xxh: query
+                    .strip_prefix("0x")
+                    .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+                    .unwrap_or_else(|| xxh64(query.to_lowercase().as_bytes(), 0)),
+            },
+            BinSearchMode::Regex => Self::Regex(
+                RegexBuilder::new(query)
+                    .case_insensitive(!case_sensitive)
+                    .build()
+                    .map_err(|e| Error::InvalidInput(format!("Invalid regex: {}", e)))?,
+            ),
+        })
+    }
+
+    fn text(&self, text: &str) -> bool {
+        match self {
+            Self::Text { needle, case_sensitive: true } => text.contains(needle.as_str()),
+            Self::Text { needle, case_sensitive: false } => text.to_lowercase().contains(needle.as_str()),
+            Self::Regex(regex) => regex.is_match(text),
+            Self::Hash { .. } => false,
+        }
+    }
+
+    /// Matches a 32-bit hash by value, or by its resolved name
+    fn hash(&self, hash: u32, name: Option<&String>) -> bool {
+        match self {
+            Self::Hash { fnv, .. } => hash == *fnv,
+            _ => name.is_some_and(|name| self.text(name)),
+        }
+    }
+}
+
+/// Resolved name of a hash, or its `0x` hex form
+pub(crate) fn hash_text(hash: u32, name: Option<&String>) -> String {
+    name.cloned().unwrap_or_else(|| format!("0x{:08x}", hash))
+}
+
+/// Path segment of a struct field, as the structured editor takes it
+pub(crate) fn field_segment(name_hash: u32, names: &HashMapProvider) -> String {
+    hash_text(name_hash, names.fields.get(&name_hash))
+}
+
+/// Path segment of a map entry, as the structured editor takes it
+pub(crate) fn key_segment(key: &PropertyValueEnum, names: &HashMapProvider) -> String {
+    use PropertyValueEnum as P;
+    match key {
+        P::String(s) => s.0.clone(),
+        P::Hash(HashValue(hash)) => hash_text(*hash, names.hashes.get(hash)),
+        P::ObjectLink(ObjectLinkValue(hash)) => hash_text(*hash, names.entries.get(hash)),
+        P::U8(v) => v.0.to_string(),
+        P::U16(v) => v.0.to_string(),
+        P::U32(v) => v.0.to_string(),
+        P::U64(v) => v.0.to_string(),
+        P::I8(v) => v.0.to_string(),
+        P::I16(v) => v.0.to_string(),
+        P::I32(v) => v.0.to_string(),
+        P::I64(v) => v.0.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Walks one object's values, collecting (property path, value) matches
+struct Walker<'a> {
+    matcher: &'a Matcher,
+    names: &'a HashMapProvider,
+    path: Vec<String>,
+    found: Vec<(Vec<String>, String)>,
+}
+
+impl Walker<'_> {
+    fn hit(&mut self, value: String) {
+        self.found.push((self.path.clone(), value));
+    }
+
+    fn descend(&mut self, segment: String, value: &PropertyValueEnum) {
+        self.path.push(segment);
+        self.visit(value);
+        self.path.pop();
+    }
+
+    fn fields(&mut self, properties: &indexmap::IndexMap<u32, ltk_meta::BinProperty>) {
+        for prop in properties.values() {
+            self.descend(field_segment(prop.name_hash, self.names), &prop.value);
+        }
+    }
+
+    fn visit(&mut self, value: &PropertyValueEnum) {
+        use PropertyValueEnum as P;
+        match value {
+            P::String(s) if self.matcher.text(&s.0) => self.hit(s.0.clone()),
+            P::Hash(HashValue(hash)) => {
+                let name = self.names.hashes.get(hash);
+                if self.matcher.hash(*hash, name) {
+                    self.hit(hash_text(*hash, name));
+                }
+            }
+            P::ObjectLink(ObjectLinkValue(hash)) => {
+                let name = self.names.entries.get(hash);
+                if self.matcher.hash(*hash, name) {
+                    self.hit(hash_text(*hash, name));
+                }
+            }
+            P::WadChunkLink(WadChunkLinkValue(hash)) => {
+                if matches!(self.matcher, Matcher::Hash { xxh, .. } if xxh == hash) {
+                    self.hit(format!("0x{:016x}", hash));
+                }
+            }
+            P::Container(c) | P::UnorderedContainer(UnorderedContainerValue(c)) => {
+                for (i, item) in c.items.iter().enumerate() {
+                    self.descend(i.to_string(), item);
+                }
+            }
+            P::Struct(s) | P::Embedded(EmbeddedValue(s)) => self.fields(&s.properties),
+            P::Optional(o) => {
+                if let Some(value) = &o.value {
+                    self.descend("0".to_string(), value);
+                }
+            }
+            P::Map(m) => {
+                for (key, value) in &m.entries {
+                    let segment = key_segment(&key.0, self.names);
+                    // Keys are searched too; a match points at the entry
+                    self.path.push(segment);
+                    self.visit(&key.0);
+                    self.visit(value);
+                    self.path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Every BIN under `root`, as (path relative to `root`, full path), sorted
+fn list_bins(root: &Path) -> Result<Vec<(String, PathBuf)>> {
+    if !root.is_dir() {
+        return Err(Error::InvalidInput(format!("Folder not found: {}", root.display())));
+    }
+    let mut files: Vec<(String, PathBuf)> = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            e.file_type().is_file()
+                && e.path().extension().is_some_and(|ext| ext.eq_ignore_ascii_case("bin"))
+        })
+        .map(|e| {
+            let relative = e.path().strip_prefix(root).unwrap_or(e.path());
+            (relative.to_string_lossy().replace('\\', "/"), e.path().to_path_buf())
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Searches one BIN file
+fn search_file(path: &Path, file: &str, matcher: &Matcher, names: &HashMapProvider) -> Result<Vec<BinSearchMatch>> {
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    let tree = read_bin(&data).map_err(|e| Error::bin_conversion_with_path(e.to_string(), path))?;
+
+    let mut matches = Vec::new();
+    for object in tree.objects.values() {
+        let object_name = names.entries.get(&object.path_hash);
+        let mut push = |property_path: Vec<String>, value: String| {
+            matches.push(BinSearchMatch {
+                file: file.to_string(),
+                object_hash: object.path_hash,
+                object_name: object_name.cloned(),
+                property_path,
+                value,
+            })
+        };
+
+        if matcher.hash(object.path_hash, object_name) {
+            push(Vec::new(), hash_text(object.path_hash, object_name));
+        }
+        let mut walker = Walker { matcher, names, path: Vec::new(), found: Vec::new() };
+        walker.fields(&object.properties);
+        for (property_path, value) in walker.found {
+            push(property_path, value);
+        }
+    }
+    Ok(matches)
+}
+
+/// Searches every BIN under `root`
+///
+/// # Arguments
+/// * `root` - Folder to search, usually a project's `content` folder
+/// * `query` - Text, hash or regex, depending on `mode`
+/// * `mode` - How `query` is matched
+/// * `case_sensitive` - Applies to text and regex searches
+/// * `max_results` - Matches to return at most
+/// * `names` - Hash names, used for matching names and for the property paths
+pub fn search_bins(
+    root: &Path,
+    query: &str,
+    mode: BinSearchMode,
+    case_sensitive: bool,
+    max_results: usize,
+    names: &HashMapProvider,
+) -> Result<BinSearchResult> {
+    let matcher = Matcher::new(query, mode, case_sensitive)?;
+
+    let files = list_bins(root)?;
+
+    let results: Vec<_> = files
+        .par_iter()
+        .map(|(file, path)| (file, search_file(path, file, &matcher, names)))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut files_skipped = Vec::new();
+    for (file, result) in results {
+        match result {
+            Ok(found) => matches.extend(found),
+            Err(e) => files_skipped.push(format!("{}: {}", file, e)),
+        }
+    }
+    let truncated = matches.len() > max_results;
+    matches.truncate(max_results);
+
+    Ok(BinSearchResult {
+        matches,
+        files_searched: files.len(),
+        files_skipped,
+        truncated,
+    })
+}
+
+/// One string value rewritten by a replace (or, in a dry run, that would be)
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinReplacement {
+    /// BIN file, relative to the searched folder
+    pub file: String,
+    pub object_hash: u32,
+    pub object_name: Option<String>,
+    pub property_path: Vec<String>,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BinReplaceResult {
+    pub replacements: Vec<BinReplacement>,
+    /// BINs that were rewritten; empty in a dry run
+    pub files_changed: Vec<String>,
+    pub files_searched: usize,
+    /// BINs that couldn't be parsed or saved, with the reason
+    pub files_skipped: Vec<String>,
+    pub dry_run: bool,
+}
+
+/// Rewrites matching strings in place
+///
+/// Only string values are replaced. Map keys stay as they are, since a
+/// rewritten key could collide with another entry of the map.
+struct Rewriter<'a> {
+    pattern: &'a Regex,
+    /// Expands `$1`-style groups in the replacement (regex mode)
+    expand: bool,
+    replacement: &'a str,
+    names: &'a HashMapProvider,
+    path: Vec<String>,
+    found: Vec<(Vec<String>, String, String)>,
+}
+
+impl Rewriter<'_> {
+    fn descend(&mut self, segment: String, value: &mut PropertyValueEnum) {
+        self.path.push(segment);
+        self.visit(value);
+        self.path.pop();
+    }
+
+    fn fields(&mut self, properties: &mut indexmap::IndexMap<u32, ltk_meta::BinProperty>) {
+        for prop in properties.values_mut() {
+            self.descend(field_segment(prop.name_hash, self.names), &mut prop.value);
+        }
+    }
+
+    fn visit(&mut self, value: &mut PropertyValueEnum) {
+        use PropertyValueEnum as P;
+        match value {
+            P::String(s) => {
+                let new_value = if self.expand {
+                    self.pattern.replace_all(&s.0, self.replacement)
+                } else {
+                    self.pattern.replace_all(&s.0, NoExpand(self.replacement))
+                };
+                if new_value != s.0 {
+                    let new_value = new_value.into_owned();
+                    let old_value = std::mem::replace(&mut s.0, new_value.clone());
+                    self.found.push((self.path.clone(), old_value, new_value));
+                }
+            }
+            P::Container(c) | P::UnorderedContainer(UnorderedContainerValue(c)) => {
+                for (i, item) in c.items.iter_mut().enumerate() {
+                    self.descend(i.to_string(), item);
+                }
+            }
+            P::Struct(s) | P::Embedded(EmbeddedValue(s)) => self.fields(&mut s.properties),
+            P::Optional(o) => {
+                if let Some(value) = &mut o.value {
+                    self.descend("0".to_string(), value);
+                }
+            }
+            P::Map(m) => {
+                for (key, value) in m.entries.iter_mut() {
+                    self.descend(key_segment(&key.0, self.names), value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Replaces in one BIN file, saving it unless this is a dry run
+fn replace_in_file(
+    path: &Path,
+    file: &str,
+    template: &Rewriter,
+    dry_run: bool,
+) -> Result<Vec<BinReplacement>> {
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mut tree = read_bin(&data).map_err(|e| Error::bin_conversion_with_path(e.to_string(), path))?;
+
+    let mut replacements = Vec::new();
+    for object in tree.objects.values_mut() {
+        let mut rewriter = Rewriter { path: Vec::new(), found: Vec::new(), ..*template };
+        rewriter.fields(&mut object.properties);
+        let object_name = template.names.entries.get(&object.path_hash);
+        replacements.extend(rewriter.found.into_iter().map(|(property_path, old_value, new_value)| {
+            BinReplacement {
+                file: file.to_string(),
+                object_hash: object.path_hash,
+                object_name: object_name.cloned(),
+                property_path,
+                old_value,
+                new_value,
+            }
+        }));
+    }
+
+    if !replacements.is_empty() && !dry_run {
+        editor::save(path, &tree)?;
+    }
+    Ok(replacements)
+}
+
+/// Replaces matching text in the string values of every BIN under `root`
+///
+/// Rewritten BINs go through `write_bin` and lose their `.ritobin` cache,
+/// so the text view converts them again on the next open.
+///
+/// # Arguments
+/// * `root` - Folder to search, usually a project's `content` folder
+/// * `query` - Text or regex to replace; hash searches can't be replaced
+/// * `replacement` - Replacement text; `$1`-style groups expand in regex mode
+/// * `mode` - How `query` is matched
+/// * `case_sensitive` - Case-sensitive matching
+/// * `dry_run` - Report the replacements without saving anything
+/// * `names` - Hash names, used for the property paths
+pub fn replace_in_bins(
+    root: &Path,
+    query: &str,
+    replacement: &str,
+    mode: BinSearchMode,
+    case_sensitive: bool,
+    dry_run: bool,
+    names: &HashMapProvider,
+) -> Result<BinReplaceResult> {
+    if query.is_empty() {
+        return Err(Error::InvalidInput("Search query is empty".into()));
+    }
+    let pattern = match mode {
+        BinSearchMode::Text => regex::escape(query),
+        BinSearchMode::Regex => query.to_string(),
+        BinSearchMode::Hash => {
+            return Err(Error::InvalidInput("Hash searches can't be used to replace values".into()))
+        }
+    };
+    let pattern = RegexBuilder::new(&pattern)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map_err(|e| Error::InvalidInput(format!("Invalid regex: {}", e)))?;
+    let template = Rewriter {
+        pattern: &pattern,
+        expand: mode == BinSearchMode::Regex,
+        replacement,
+        names,
+        path: Vec::new(),
+        found: Vec::new(),
+    };
+
+    let files = list_bins(root)?;
+    let results: Vec<_> = files
+        .par_iter()
+        .map(|(file, path)| (file, replace_in_file(path, file, &template, dry_run)))
+        .collect();
+
+    let mut replacements = Vec::new();
+    let mut files_changed = Vec::new();
+    let mut files_skipped = Vec::new();
+    for (file, result) in results {
+        match result {
+            Ok(found) if found.is_empty() => {}
+            Ok(found) => {
+                if !dry_run {
+                    files_changed.push(file.clone());
+                }
+                replacements.extend(found);
+            }
+            Err(e) => files_skipped.push(format!("{}: {}", file, e)),
+        }
+    }
+
+    Ok(BinReplaceResult {
+        replacements,
+        files_changed,
+        files_searched: files.len(),
+        files_skipped,
+        dry_run,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+
+    const SKIN: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin0" = SkinCharacterDataProperties {
+        skinMeshProperties: embed = SkinMeshDataProperties {
+            texture: string = "ASSETS/Characters/Ahri/Ahri_TX_CM.dds"
+        }
+        effects: map[hash,string] = {
+            "Fire" = "ASSETS/Particles/Ahri_Fire.dds"
+        }
+        parent: link = "Characters/Ahri/Skins/Root"
+    }
+}
+"#;
+
+    #[test]
+    fn test_search_bins() {
+        let dir = tempfile::tempdir().unwrap();
+        let bins = dir.path().join("base/ahri.wad.client/data");
+        fs::create_dir_all(&bins).unwrap();
+        fs::write(bins.join("skin0.bin"), write_bin(&text_to_tree(SKIN).unwrap()).unwrap()).unwrap();
+        fs::write(bins.join("broken.bin"), b"nope").unwrap();
+
+        let names = HashMapProvider::new();
+        let result = search_bins(dir.path(), "particles/ahri", BinSearchMode::Text, false, 100, &names).unwrap();
+        assert_eq!(result.files_searched, 2);
+        assert_eq!(result.files_skipped.len(), 1);
+        assert_eq!(result.matches.len(), 1);
+        let found = &result.matches[0];
+        assert_eq!(found.file, "base/ahri.wad.client/data/skin0.bin");
+        assert_eq!(found.object_hash, parse_hash("Characters/Ahri/Skins/Skin0"));
+        let field = format!("0x{:08x}", parse_hash("effects"));
+        let key = format!("0x{:08x}", parse_hash("Fire"));
+        assert_eq!(found.property_path, vec![field, key]);
+
+        // Case-sensitive text, regex and hash searches
+        assert!(search_bins(dir.path(), "PARTICLES/AHRI", BinSearchMode::Text, true, 100, &names)
+            .unwrap()
+            .matches
+            .is_empty());
+        let result = search_bins(dir.path(), r"_tx_cm\.dds$", BinSearchMode::Regex, false, 100, &names).unwrap();
+        assert_eq!(result.matches.len(), 1);
+        let result = search_bins(dir.path(), "Characters/Ahri/Skins/Root", BinSearchMode::Hash, false, 100, &names).unwrap();
+        assert_eq!(result.matches.len(), 1);
+        let result = search_bins(dir.path(), "Characters/Ahri/Skins/Skin0", BinSearchMode::Hash, false, 100, &names).unwrap();
+        assert!(result.matches[0].property_path.is_empty());
+
+        // Limits and bad queries
+        let result = search_bins(dir.path(), "assets", BinSearchMode::Text, false, 1, &names).unwrap();
+        assert!(result.truncated);
+        assert!(search_bins(dir.path(), "(", BinSearchMode::Regex, false, 100, &names).is_err());
+    }
+
+    #[test]
+    fn test_replace_in_bins() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("skin0.bin");
+        let cache = dir.path().join("skin0.bin.ritobin");
+        fs::write(&bin, write_bin(&text_to_tree(SKIN).unwrap()).unwrap()).unwrap();
+        fs::write(&cache, "stale").unwrap();
+        let names = HashMapProvider::new();
+
+        // A dry run reports without touching the file
+        let result = replace_in_bins(dir.path(), "assets/", "ASSETS/Mod/", BinSearchMode::Text, false, true, &names).unwrap();
+        assert_eq!(result.replacements.len(), 2);
+        assert!(result.files_changed.is_empty());
+        assert!(cache.exists());
+
+        let result = replace_in_bins(dir.path(), r"Ahri_(\w+)\.dds", "Kai_$1.tex", BinSearchMode::Regex, true, false, &names).unwrap();
+        assert_eq!(result.files_changed, vec!["skin0.bin"]);
+        assert_eq!(result.replacements[0].old_value, "ASSETS/Characters/Ahri/Ahri_TX_CM.dds");
+        assert_eq!(result.replacements[0].new_value, "ASSETS/Characters/Ahri/Kai_TX_CM.tex");
+        assert!(!cache.exists());
+
+        let found = search_bins(dir.path(), "Kai_Fire.tex", BinSearchMode::Text, true, 100, &names).unwrap();
+        assert_eq!(found.matches.len(), 1);
+        assert!(replace_in_bins(dir.path(), "x", "y", BinSearchMode::Hash, false, true, &names).is_err());
+    }
+}
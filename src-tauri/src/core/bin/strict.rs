@@ -0,0 +1,724 @@
+//! Strict checking for ritobin text before conversion
+//!
+//! `ltk_ritobin` is lenient: a malformed hex name hashes to 0, a `map[hash]`
+//! without a value type defaults to `none`, top-level keys it doesn't know are
+//! dropped, and kind errors surface as a generic "expected ..." far from the
+//! real mistake. This module walks the same grammar without building a tree
+//! and reports every such problem with a line/column, so cautious users can
+//! refuse to write a .bin that doesn't say what they meant.
+
+use ltk_meta::BinPropertyKind;
+use ltk_ritobin::{type_name_to_kind, HashMapProvider, HashProvider};
+use league_toolkit::hash::fnv1a::hash_lower;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Category of a strict-mode problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum StrictIssueKind {
+    /// Property name not present in the loaded field hash list
+    UnknownProperty,
+    /// Type name that ritobin doesn't define
+    UnknownType,
+    /// Hex hash literal with invalid digits or a suspicious length
+    MalformedHash,
+    /// Value doesn't match its declared type
+    KindMismatch,
+    /// Container type missing a parameter that would be silently defaulted
+    MissingTypeHint,
+    /// Text the parser can't make sense of at all
+    Syntax,
+}
+
+/// A single strict-mode finding
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct StrictDiagnostic {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number
+    pub column: usize,
+    pub kind: StrictIssueKind,
+    pub message: String,
+}
+
+impl std::fmt::Display for StrictDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Word(String),
+    Str,
+    Punct(char),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+impl Token {
+    fn word(&self) -> Option<&str> {
+        match &self.kind {
+            TokenKind::Word(w) => Some(w),
+            _ => None,
+        }
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.kind == TokenKind::Punct(c)
+    }
+
+    fn describe(&self) -> String {
+        match &self.kind {
+            TokenKind::Word(w) => format!("'{}'", w),
+            TokenKind::Str => "a string".to_string(),
+            TokenKind::Punct(c) => format!("'{}'", c),
+        }
+    }
+}
+
+/// Same word alphabet as the ritobin parser
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '+' | '-' | '.' | '/')
+}
+
+fn tokenize(text: &str, diagnostics: &mut Vec<StrictDiagnostic>) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+
+    macro_rules! bump {
+        () => {{
+            let c = chars.next();
+            if c == Some('\n') {
+                line += 1;
+                column = 1;
+            } else if c.is_some() {
+                column += 1;
+            }
+            c
+        }};
+    }
+
+    while let Some(&c) = chars.peek() {
+        let (start_line, start_column) = (line, column);
+
+        if c.is_whitespace() {
+            bump!();
+        } else if c == '#' {
+            while chars.peek().is_some_and(|&c| c != '\n') {
+                bump!();
+            }
+        } else if c == '"' || c == '\'' {
+            bump!();
+            let mut closed = false;
+            while let Some(next) = bump!() {
+                if next == '\\' {
+                    bump!();
+                } else if next == c {
+                    closed = true;
+                    break;
+                }
+            }
+            if !closed {
+                diagnostics.push(StrictDiagnostic {
+                    line: start_line,
+                    column: start_column,
+                    kind: StrictIssueKind::Syntax,
+                    message: "Unclosed string".to_string(),
+                });
+            }
+            tokens.push(Token { kind: TokenKind::Str, line: start_line, column: start_column });
+        } else if matches!(c, '{' | '}' | '[' | ']' | ':' | '=' | ',') {
+            bump!();
+            tokens.push(Token { kind: TokenKind::Punct(c), line: start_line, column: start_column });
+        } else if is_word_char(c) {
+            let mut word = String::new();
+            while chars.peek().is_some_and(|&c| is_word_char(c)) {
+                word.push(bump!().unwrap());
+            }
+            tokens.push(Token { kind: TokenKind::Word(word), line: start_line, column: start_column });
+        } else {
+            bump!();
+            diagnostics.push(StrictDiagnostic {
+                line: start_line,
+                column: start_column,
+                kind: StrictIssueKind::Syntax,
+                message: format!("Unexpected character '{}'", c),
+            });
+        }
+    }
+
+    tokens
+}
+
+/// A declared property type
+#[derive(Debug, Clone, Copy)]
+struct TypeSpec {
+    kind: BinPropertyKind,
+    inner: BinPropertyKind,
+    value: BinPropertyKind,
+}
+
+/// Expected type for each top-level key the converter understands
+fn top_level_type(name: &str) -> Option<&'static str> {
+    match name {
+        "type" => Some("string"),
+        "version" => Some("u32"),
+        "linked" => Some("list[string]"),
+        "entries" => Some("map[hash,embed]"),
+        _ => None,
+    }
+}
+
+fn type_to_string(ty: &TypeSpec) -> String {
+    use ltk_ritobin::kind_to_type_name as name;
+    match ty.kind {
+        BinPropertyKind::Map => format!("map[{},{}]", name(ty.inner), name(ty.value)),
+        k if k.is_container() || k == BinPropertyKind::Optional => {
+            format!("{}[{}]", name(k), name(ty.inner))
+        }
+        k => name(k).to_string(),
+    }
+}
+
+/// Aborts the walk after a syntax error (the real parser would fail there too)
+struct Abort;
+
+type Step<T = ()> = std::result::Result<T, Abort>;
+
+struct Linter<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    fields: Option<&'a HashMapProvider>,
+    diagnostics: Vec<StrictDiagnostic>,
+}
+
+impl<'a> Linter<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn report(&mut self, token: &Token, kind: StrictIssueKind, message: String) {
+        self.diagnostics.push(StrictDiagnostic {
+            line: token.line,
+            column: token.column,
+            kind,
+            message,
+        });
+    }
+
+    fn syntax_error<T>(&mut self, expected: &str) -> Step<T> {
+        match self.peek().cloned() {
+            Some(token) => {
+                let message = format!("Expected {}, found {}", expected, token.describe());
+                self.report(&token, StrictIssueKind::Syntax, message);
+            }
+            None => {
+                let (line, column) = self
+                    .tokens
+                    .last()
+                    .map(|t| (t.line, t.column))
+                    .unwrap_or((1, 1));
+                self.diagnostics.push(StrictDiagnostic {
+                    line,
+                    column,
+                    kind: StrictIssueKind::Syntax,
+                    message: format!("Expected {}, found end of file", expected),
+                });
+            }
+        }
+        Err(Abort)
+    }
+
+    fn expect_punct(&mut self, c: char) -> Step {
+        if self.peek().is_some_and(|t| t.is_punct(c)) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.syntax_error(&format!("'{}'", c))
+        }
+    }
+
+    fn expect_word(&mut self, what: &str) -> Step<Token> {
+        if self.peek().and_then(|t| t.word()).is_some() {
+            Ok(self.next().unwrap())
+        } else {
+            self.syntax_error(what)
+        }
+    }
+
+    fn skip_comma(&mut self) {
+        if self.peek().is_some_and(|t| t.is_punct(',')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skips one value (a token or a balanced `{...}` block) after a mismatch
+    fn skip_value(&mut self) -> Step {
+        if !self.peek().is_some_and(|t| t.is_punct('{')) {
+            self.pos += 1;
+            return Ok(());
+        }
+        let mut depth = 0usize;
+        while let Some(token) = self.next() {
+            if token.is_punct('{') {
+                depth += 1;
+            } else if token.is_punct('}') {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(());
+                }
+            }
+        }
+        self.syntax_error("'}'")
+    }
+
+    fn run(&mut self) {
+        while self.peek().is_some() {
+            if self.entry().is_err() {
+                break;
+            }
+        }
+    }
+
+    fn entry(&mut self) -> Step {
+        let name = self.expect_word("a top-level key")?;
+        self.expect_punct(':')?;
+        let ty = self.type_spec()?;
+        self.expect_punct('=')?;
+
+        let key = name.word().unwrap_or_default().to_string();
+        match top_level_type(&key) {
+            Some(expected) if type_to_string(&ty) != expected => {
+                let message = format!(
+                    "Top-level '{}' must be {}, found {} (it would be ignored)",
+                    key,
+                    expected,
+                    type_to_string(&ty)
+                );
+                self.report(&name, StrictIssueKind::KindMismatch, message);
+            }
+            None => {
+                let message = format!("Unknown top-level key '{}' is dropped on conversion", key);
+                self.report(&name, StrictIssueKind::UnknownProperty, message);
+            }
+            _ => {}
+        }
+
+        self.value(ty)
+    }
+
+    fn field(&mut self) -> Step {
+        let name = self.expect_word("a property name")?;
+        let name_str = name.word().unwrap_or_default().to_string();
+
+        if name_str.starts_with("0x") || name_str.starts_with("0X") {
+            self.check_hash_literal(&name, 8);
+        } else if let Some(fields) = self.fields.filter(|f| !f.fields.is_empty()) {
+            if fields.lookup_field(hash_lower(&name_str)).is_none() {
+                let message = format!("Unknown property name '{}'", name_str);
+                self.report(&name, StrictIssueKind::UnknownProperty, message);
+            }
+        }
+
+        self.expect_punct(':')?;
+        let ty = self.type_spec()?;
+        self.expect_punct('=')?;
+        self.value(ty)
+    }
+
+    fn type_name(&mut self) -> Step<(Token, BinPropertyKind)> {
+        let token = self.expect_word("a type name")?;
+        match type_name_to_kind(token.word().unwrap_or_default()) {
+            Some(kind) => Ok((token, kind)),
+            None => {
+                let message = format!("Unknown type {}", token.describe());
+                self.report(&token, StrictIssueKind::UnknownType, message);
+                Err(Abort)
+            }
+        }
+    }
+
+    fn type_spec(&mut self) -> Step<TypeSpec> {
+        let (token, kind) = self.type_name()?;
+        let mut spec = TypeSpec {
+            kind,
+            inner: BinPropertyKind::None,
+            value: BinPropertyKind::None,
+        };
+
+        if !kind.is_container() && kind != BinPropertyKind::Optional {
+            return Ok(spec);
+        }
+
+        self.expect_punct('[')?;
+        let (inner_token, inner) = self.type_name()?;
+        spec.inner = inner;
+        self.check_item_kind(&inner_token, inner);
+
+        if self.peek().is_some_and(|t| t.is_punct(',')) {
+            self.pos += 1;
+            let (value_token, value) = self.type_name()?;
+            spec.value = value;
+            self.check_item_kind(&value_token, value);
+            if kind != BinPropertyKind::Map {
+                let message = format!("{} takes one type parameter", token.describe());
+                self.report(&value_token, StrictIssueKind::KindMismatch, message);
+            }
+        } else if kind == BinPropertyKind::Map {
+            let message = "map is missing its value type (it would default to none)".to_string();
+            self.report(&token, StrictIssueKind::MissingTypeHint, message);
+        }
+
+        self.expect_punct(']')?;
+        Ok(spec)
+    }
+
+    fn check_item_kind(&mut self, token: &Token, kind: BinPropertyKind) {
+        if kind.is_container() || kind == BinPropertyKind::Optional {
+            let message = format!("Nested container type {} is not supported", token.describe());
+            self.report(token, StrictIssueKind::KindMismatch, message);
+        }
+    }
+
+    fn value(&mut self, ty: TypeSpec) -> Step {
+        match ty.kind {
+            BinPropertyKind::Container | BinPropertyKind::UnorderedContainer => {
+                self.expect_punct('{')?;
+                while !self.peek().is_some_and(|t| t.is_punct('}')) {
+                    self.scalar(ty.inner)?;
+                    self.skip_comma();
+                }
+                self.expect_punct('}')
+            }
+            BinPropertyKind::Optional => {
+                self.expect_punct('{')?;
+                if !self.peek().is_some_and(|t| t.is_punct('}')) {
+                    self.scalar(ty.inner)?;
+                }
+                self.expect_punct('}')
+            }
+            BinPropertyKind::Map => {
+                self.expect_punct('{')?;
+                while !self.peek().is_some_and(|t| t.is_punct('}')) {
+                    self.scalar(ty.inner)?;
+                    self.expect_punct('=')?;
+                    self.scalar(ty.value)?;
+                    self.skip_comma();
+                }
+                self.expect_punct('}')
+            }
+            kind => self.scalar(kind),
+        }
+    }
+
+    fn mismatch(&mut self, kind: BinPropertyKind, detail: &str) -> Step {
+        let Some(token) = self.peek().cloned() else {
+            return self.syntax_error(ltk_ritobin::kind_to_type_name(kind));
+        };
+        let message = format!(
+            "Expected {} value{}, found {}",
+            ltk_ritobin::kind_to_type_name(kind),
+            detail,
+            token.describe()
+        );
+        self.report(&token, StrictIssueKind::KindMismatch, message);
+        self.skip_value()
+    }
+
+    fn scalar(&mut self, kind: BinPropertyKind) -> Step {
+        let Some(token) = self.peek().cloned() else {
+            return self.syntax_error("a value");
+        };
+        let word = token.word().map(str::to_string);
+
+        let ok = match kind {
+            BinPropertyKind::None => word.as_deref() == Some("null"),
+            BinPropertyKind::Bool | BinPropertyKind::BitBool => {
+                matches!(word.as_deref(), Some("true" | "false"))
+            }
+            BinPropertyKind::I8 => word.is_some_and(|w| w.parse::<i8>().is_ok()),
+            BinPropertyKind::U8 => word.is_some_and(|w| w.parse::<u8>().is_ok()),
+            BinPropertyKind::I16 => word.is_some_and(|w| w.parse::<i16>().is_ok()),
+            BinPropertyKind::U16 => word.is_some_and(|w| w.parse::<u16>().is_ok()),
+            BinPropertyKind::I32 => word.is_some_and(|w| w.parse::<i32>().is_ok()),
+            BinPropertyKind::I64 => word.is_some_and(|w| w.parse::<i64>().is_ok()),
+            BinPropertyKind::F32 => word.is_some_and(|w| w.parse::<f32>().is_ok()),
+            BinPropertyKind::U32 | BinPropertyKind::U64 | BinPropertyKind::Hash
+            | BinPropertyKind::ObjectLink | BinPropertyKind::WadChunkLink => {
+                return self.hash_or_number(kind);
+            }
+            BinPropertyKind::String => token.kind == TokenKind::Str,
+            BinPropertyKind::Vector2 => return self.numeric_block(kind, 2, false),
+            BinPropertyKind::Vector3 => return self.numeric_block(kind, 3, false),
+            BinPropertyKind::Vector4 => return self.numeric_block(kind, 4, false),
+            BinPropertyKind::Matrix44 => return self.numeric_block(kind, 16, false),
+            BinPropertyKind::Color => return self.numeric_block(kind, 4, true),
+            BinPropertyKind::Struct | BinPropertyKind::Embedded => return self.object(),
+            BinPropertyKind::Container
+            | BinPropertyKind::UnorderedContainer
+            | BinPropertyKind::Optional
+            | BinPropertyKind::Map => return self.mismatch(kind, " (nested containers are not supported)"),
+        };
+
+        if ok {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.mismatch(kind, "")
+        }
+    }
+
+    fn hash_or_number(&mut self, kind: BinPropertyKind) -> Step {
+        let token = self.peek().cloned().unwrap();
+        let is_hash = !matches!(kind, BinPropertyKind::U32 | BinPropertyKind::U64);
+        let digits = if matches!(kind, BinPropertyKind::U64 | BinPropertyKind::WadChunkLink) { 16 } else { 8 };
+
+        match &token.kind {
+            TokenKind::Str if is_hash => {
+                self.pos += 1;
+                Ok(())
+            }
+            TokenKind::Word(w) if w.starts_with("0x") || w.starts_with("0X") => {
+                self.pos += 1;
+                if is_hash {
+                    self.check_hash_literal(&token, digits);
+                } else if !w[2..].chars().all(|c| c.is_ascii_hexdigit()) || w.len() - 2 > digits {
+                    let message = format!("'{}' is not a valid {}", w, ltk_ritobin::kind_to_type_name(kind));
+                    self.report(&token, StrictIssueKind::KindMismatch, message);
+                }
+                Ok(())
+            }
+            TokenKind::Word(w) => {
+                let valid = if digits == 16 { w.parse::<u64>().is_ok() } else { w.parse::<u32>().is_ok() };
+                if valid {
+                    self.pos += 1;
+                    Ok(())
+                } else {
+                    self.mismatch(kind, "")
+                }
+            }
+            _ => self.mismatch(kind, ""),
+        }
+    }
+
+    fn numeric_block(&mut self, kind: BinPropertyKind, count: usize, bytes: bool) -> Step {
+        if !self.peek().is_some_and(|t| t.is_punct('{')) {
+            return self.mismatch(kind, "");
+        }
+        let open = self.next().unwrap();
+
+        let mut found = 0;
+        loop {
+            let Some(token) = self.next() else {
+                return self.syntax_error("'}'");
+            };
+            if token.is_punct('}') {
+                break;
+            }
+            if token.is_punct(',') {
+                continue;
+            }
+            let valid = match token.word() {
+                Some(w) if bytes => w.parse::<u8>().is_ok(),
+                Some(w) => w.parse::<f32>().is_ok(),
+                None => false,
+            };
+            if !valid {
+                let expected = if bytes { "a 0-255 integer" } else { "a number" };
+                let message = format!("Expected {}, found {}", expected, token.describe());
+                self.report(&token, StrictIssueKind::KindMismatch, message);
+            }
+            found += 1;
+        }
+
+        if found != count {
+            let message = format!(
+                "{} needs {} components, found {}",
+                ltk_ritobin::kind_to_type_name(kind),
+                count,
+                found
+            );
+            self.report(&open, StrictIssueKind::KindMismatch, message);
+        }
+        Ok(())
+    }
+
+    fn object(&mut self) -> Step {
+        let class = self.expect_word("a class name or null")?;
+        let class_name = class.word().unwrap_or_default().to_string();
+        if class_name == "null" {
+            return Ok(());
+        }
+        if class_name.starts_with("0x") || class_name.starts_with("0X") {
+            self.check_hash_literal(&class, 8);
+        }
+
+        self.expect_punct('{')?;
+        while !self.peek().is_some_and(|t| t.is_punct('}')) {
+            self.field()?;
+            self.skip_comma();
+        }
+        self.expect_punct('}')
+    }
+
+    /// Validates a `0x` hash literal against the width of its hash
+    ///
+    /// The writer drops leading zeros, so a hash one digit short is accepted
+    /// when it doesn't start with a zero itself. Anything shorter, longer, or
+    /// half-padded is reported as a likely typo.
+    fn check_hash_literal(&mut self, token: &Token, width: usize) {
+        let literal = token.word().unwrap_or_default();
+        let digits = &literal[2..];
+
+        let message = if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            format!("'{}' is not a valid hex hash", literal)
+        } else if digits.len() > width {
+            format!("'{}' has {} digits, more than a {}-bit hash holds", literal, digits.len(), width * 4)
+        } else if digits != "0"
+            && digits.len() < width
+            && (digits.len() < width - 1 || digits.starts_with('0'))
+        {
+            format!(
+                "'{}' has {} digits, expected {} (truncated or mistyped hash?)",
+                literal,
+                digits.len(),
+                width
+            )
+        } else {
+            return;
+        };
+
+        self.report(token, StrictIssueKind::MalformedHash, message);
+    }
+}
+
+/// Runs all strict checks over ritobin text
+///
+/// # Arguments
+/// * `text` - Ritobin text to check
+/// * `hashes` - BIN hash lists; property names are only checked when field hashes are loaded
+///
+/// # Returns
+/// * Every problem found, in source order (empty if the text is clean)
+pub fn check_ritobin_strict(text: &str, hashes: Option<&HashMapProvider>) -> Vec<StrictDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let tokens = tokenize(text, &mut diagnostics);
+
+    let mut linter = Linter {
+        tokens,
+        pos: 0,
+        fields: hashes,
+        diagnostics,
+    };
+    linter.run();
+
+    let mut diagnostics = linter.diagnostics;
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\n";
+
+    fn check(body: &str) -> Vec<StrictDiagnostic> {
+        check_ritobin_strict(&format!("{}{}", HEADER, body), None)
+    }
+
+    #[test]
+    fn test_clean_text() {
+        let text = r#"linked: list[string] = { "DATA/Characters/Ahri/Ahri.bin" }
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin1" = SkinCharacterDataProperties {
+        skinScale: f32 = 1.5
+        0xabc1234: vec3 = { 1, 2, 3 }
+        iconAvatar: string = "ASSETS/ahri.dds"
+        tint: rgba = { 255, 0, 0, 255 }
+        mesh: file = 0x1234567890abcdef
+        link: link = 0x0
+        flags: list[hash] = { 0xdeadbeef, "Name" }
+        opt: option[u8] = { 5 }
+        ptr: pointer = null
+    }
+}
+"#;
+        assert!(check(text).is_empty(), "{:?}", check(text));
+    }
+
+    #[test]
+    fn test_malformed_hashes() {
+        let diags = check("entries: map[hash,embed] = {\n  0xabc = Foo {\n    0x12g4: u8 = 1\n  }\n}\n");
+        assert_eq!(diags.len(), 2);
+        assert!(diags.iter().all(|d| d.kind == StrictIssueKind::MalformedHash));
+        assert_eq!((diags[0].line, diags[0].column), (5, 3));
+        assert!(diags[1].message.contains("0x12g4"));
+    }
+
+    #[test]
+    fn test_kind_mismatches() {
+        let diags = check(
+            "entries: map[hash,embed] = {\n  \"a\" = Foo {\n    a: u8 = 1.5\n    b: vec3 = { 1, 2 }\n    c: string = 4\n    d: bool = yes\n  }\n}\n",
+        );
+        let kinds: Vec<_> = diags.iter().map(|d| (d.line, d.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (6, StrictIssueKind::KindMismatch),
+                (7, StrictIssueKind::KindMismatch),
+                (8, StrictIssueKind::KindMismatch),
+                (9, StrictIssueKind::KindMismatch),
+            ]
+        );
+        assert!(diags[1].message.contains("needs 3 components, found 2"));
+    }
+
+    #[test]
+    fn test_missing_type_hint_and_top_level() {
+        let diags = check("entries: map[hash] = {}\nextra: u32 = 1\n");
+        assert_eq!(diags[0].kind, StrictIssueKind::KindMismatch);
+        assert!(diags.iter().any(|d| d.kind == StrictIssueKind::MissingTypeHint));
+        assert!(diags.iter().any(|d| d.kind == StrictIssueKind::UnknownProperty && d.line == 5));
+    }
+
+    #[test]
+    fn test_unknown_property_names() {
+        let mut hashes = HashMapProvider::new();
+        hashes.insert_field(hash_lower("skinScale"), "skinScale");
+
+        let text = format!(
+            "{}entries: map[hash,embed] = {{\n  \"a\" = Foo {{\n    skinScale: f32 = 1\n    skinScael: f32 = 1\n  }}\n}}\n",
+            HEADER
+        );
+        let diags = check_ritobin_strict(&text, Some(&hashes));
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].kind, StrictIssueKind::UnknownProperty);
+        assert!(diags[0].message.contains("skinScael"));
+
+        // Without field hashes the name check is skipped
+        assert!(check_ritobin_strict(&text, None).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_type_aborts() {
+        let diags = check("entries: map[hash,embed] = {\n  \"a\" = Foo {\n    a: f23 = 1\n  }\n}\n");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].kind, StrictIssueKind::UnknownType);
+    }
+}
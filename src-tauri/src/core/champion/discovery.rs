@@ -3,14 +3,25 @@
 //! This module provides functionality to scan League of Legends files
 //! and discover available champions and their skins.
 
+use super::preview::{path_hash, read_chunk, skin_bin_path};
+use crate::core::thumbnail::{encode_png, texture_data_thumbnail};
+use crate::core::wad::extractor::find_champion_wad;
+use crate::core::wad::reader::WadReader;
 use crate::error::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use ts_rs::TS;
+
+/// Skin numbers are the last three digits of a skin's game id
+const MAX_SKIN_ID: u32 = 1000;
 
 /// Represents a discovered champion
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct ChampionInfo {
     /// Display name of the champion
     pub name: String,
@@ -20,6 +31,12 @@ pub struct ChampionInfo {
     pub skins: Vec<SkinInfo>,
     /// Path to champion WAD file
     pub wad_path: Option<String>,
+    /// Champion title (e.g. "the Nine-Tailed Fox"), when roster metadata was requested
+    pub title: Option<String>,
+    /// Numeric champion id used by the game client (e.g. 103 for Ahri)
+    pub key: Option<u32>,
+    /// Square icon as base64-encoded PNG, when icons were requested
+    pub icon: Option<String>,
 }
 
 impl ChampionInfo {
@@ -31,6 +48,9 @@ impl ChampionInfo {
             internal_name: internal,
             skins: Vec::new(),
             wad_path: None,
+            title: None,
+            key: None,
+            icon: None,
         }
     }
 
@@ -42,14 +62,17 @@ impl ChampionInfo {
 }
 
 /// Represents a discovered skin
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct SkinInfo {
     /// Skin ID (0 = base skin)
     pub id: u32,
-    /// Skin name (may be resolved from hash or generated)
+    /// Skin name (from CommunityDragon metadata when available, otherwise generated)
     pub name: String,
     /// Internal folder name (e.g., "Skin0", "Skin1")
     pub folder_name: String,
+    /// Skin ID of the skin this one is a chroma of
+    pub chroma_of: Option<u32>,
 }
 
 impl SkinInfo {
@@ -63,6 +86,7 @@ impl SkinInfo {
                 format!("Skin {}", id)
             },
             folder_name: format!("Skin{}", id),
+            chroma_of: None,
         }
     }
 }
@@ -209,6 +233,37 @@ fn scan_for_champion_wads(dir: &Path, champions: &mut HashMap<String, ChampionIn
     Ok(())
 }
 
+/// Reads a champion's square icon from its WAD
+///
+/// # Returns
+/// * `Ok(Some(png))` - The icon scaled to fit `size`, as PNG bytes
+/// * `Ok(None)` - The WAD has no square icon for the champion
+pub fn read_champion_icon(wad_path: &Path, champion: &str, size: u32) -> Result<Option<Vec<u8>>> {
+    let mut reader = WadReader::open(wad_path)?;
+    let champion = champion.to_lowercase();
+    for extension in ["tex", "dds"] {
+        let path = format!("assets/characters/{0}/hud/{0}_square.{1}", champion, extension);
+        if let Some(data) = read_chunk(&mut reader, &path)? {
+            return encode_png(&texture_data_thumbnail(&data, size)?).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// Fills in [`ChampionInfo::icon`] for every champion with a WAD
+///
+/// WADs are read in parallel; a champion whose icon can't be read is
+/// logged and left without one.
+pub fn attach_champion_icons(champions: &mut [ChampionInfo], size: u32) {
+    champions.par_iter_mut().for_each(|champion| {
+        let Some(wad_path) = champion.wad_path.as_deref() else { return };
+        match read_champion_icon(Path::new(wad_path), &champion.internal_name, size) {
+            Ok(icon) => champion.icon = icon.map(|png| STANDARD.encode(png)),
+            Err(e) => tracing::warn!("Failed to read the icon of {}: {}", champion.internal_name, e),
+        }
+    });
+}
+
 /// Extracts champion name from a WAD filename
 ///
 /// Examples:
@@ -235,6 +290,10 @@ fn extract_champion_from_wad_name(filename: &str) -> Option<String> {
 
 /// Gets skins for a specific champion
 ///
+/// Skins come from the champion's `Skins` folders when the installation has
+/// them, otherwise from the skin BINs in the champion WAD. Names are
+/// generated; see [`super::metadata`] for display names.
+///
 /// # Arguments
 /// * `league_path` - Path to League installation
 /// * `champion` - Champion internal name
@@ -271,12 +330,13 @@ pub fn get_champion_skins(league_path: &Path, champion: &str) -> Result<Vec<Skin
                 }
             }
         }
-    } else {
-        // If we can't find the folder structure, add some default skins
-        // Most champions have at least a few skins
-        for i in 1..=5 {
-            skins.push(SkinInfo::new(i));
-        }
+    } else if let Some(wad_path) = find_champion_wad(league_path, champion) {
+        let reader = WadReader::open(&wad_path)?;
+        skins.extend(
+            (1..MAX_SKIN_ID)
+                .filter(|&id| reader.get_chunk(path_hash(&skin_bin_path(champion, id))).is_some())
+                .map(SkinInfo::new),
+        );
     }
 
     // Sort by skin ID
@@ -388,4 +448,39 @@ mod tests {
         assert_eq!(champion.skins[0].id, 0);
         assert_eq!(champion.skins[1].id, 1);
     }
+
+    #[test]
+    fn test_skins_and_icon_from_wad() {
+        use crate::core::wad::test_util::write_wad;
+
+        let tex = ltk_texture::Tex::encode_rgba_image(
+            &image::RgbaImage::from_pixel(128, 128, image::Rgba([255, 0, 0, 255])),
+            ltk_texture::tex::EncodeOptions::new(ltk_texture::tex::Format::Bc3).with_mipmaps(),
+        )
+        .unwrap();
+        let mut icon = Vec::new();
+        tex.write(&mut icon).unwrap();
+        let files: Vec<(&str, Vec<u8>)> = vec![
+            ("data/characters/ahri/skins/skin0.bin", vec![0; 8]),
+            ("data/characters/ahri/skins/skin1.bin", vec![0; 8]),
+            ("data/characters/ahri/skins/skin12.bin", vec![0; 8]),
+            ("assets/characters/ahri/hud/ahri_square.tex", icon),
+        ];
+
+        let dir = tempfile::tempdir().unwrap();
+        let champions_dir = dir.path().join("Game/DATA/FINAL/Champions");
+        fs::create_dir_all(&champions_dir).unwrap();
+        write_wad(&champions_dir.join("ahri.wad.client"), &files);
+
+        let ids: Vec<u32> = get_champion_skins(dir.path(), "Ahri").unwrap().iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![0, 1, 12]);
+
+        let mut champions = discover_champions(dir.path()).unwrap();
+        attach_champion_icons(&mut champions, 32);
+        let png = STANDARD.decode(champions[0].icon.as_ref().unwrap()).unwrap();
+        assert_eq!(image::load_from_memory(&png).unwrap().width(), 32);
+
+        // Without a WAD only the base skin is known
+        assert_eq!(get_champion_skins(dir.path(), "Zed").unwrap().len(), 1);
+    }
 }
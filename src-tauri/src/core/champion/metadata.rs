@@ -0,0 +1,288 @@
+//! Skin names and chromas from CommunityDragon
+//!
+//! Game files only number skins; their display names and which skins are
+//! chromas of which live in the client's game data. CommunityDragon mirrors
+//! it as JSON: `champion-summary.json` maps champion aliases to numeric ids,
+//! and `champions/{id}.json` lists the skins with their chromas. Skin ids
+//! there are `champion id * 1000 + skin number`.
+//!
+//! Champion titles come from Data Dragon's `champion.json`, which lists the
+//! whole roster in one file, so discovering every champion costs a single
+//! download.
+//!
+//! All files are cached under the app data directory and refreshed after
+//! a week. When the network is down a stale cache is still used.
+
+use super::discovery::{ChampionInfo, SkinInfo};
+use crate::error::{Error, Result};
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tokio::fs;
+
+/// Folder under the app data directory holding the cached JSON
+pub const CACHE_DIR: &str = "champion_metadata";
+const GAME_DATA_URL: &str = "https://raw.communitydragon.org/latest/plugins/rcp-be-lol-game-data/global/default/v1";
+const DDRAGON_URL: &str = "https://ddragon.leagueoflegends.com";
+const CACHE_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// A skin as CommunityDragon lists it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkinMetadata {
+    /// Skin number, as used in `Skin{N}` folders and BIN paths
+    pub id: u32,
+    pub name: String,
+    /// Skin number of the skin this one is a chroma of
+    pub chroma_of: Option<u32>,
+}
+
+/// A champion as Data Dragon lists it
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RosterEntry {
+    /// Internal name (e.g. "MonkeyKing")
+    #[serde(rename = "id")]
+    pub alias: String,
+    /// Numeric champion id, as a string in the JSON
+    #[serde(deserialize_with = "deserialize_key")]
+    pub key: u32,
+    /// Display name (e.g. "Wukong")
+    pub name: String,
+    pub title: String,
+}
+
+fn deserialize_key<'de, D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<u32, D::Error> {
+    let key = String::deserialize(deserializer)?;
+    key.parse().map_err(serde::de::Error::custom)
+}
+
+#[derive(Deserialize)]
+struct Roster {
+    data: HashMap<String, RosterEntry>,
+}
+
+#[derive(Deserialize)]
+struct SummaryEntry {
+    id: i64,
+    alias: String,
+}
+
+#[derive(Deserialize)]
+struct ChromaEntry {
+    id: u32,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SkinEntry {
+    id: u32,
+    name: String,
+    #[serde(default)]
+    chromas: Vec<ChromaEntry>,
+}
+
+#[derive(Deserialize)]
+struct ChampionEntry {
+    skins: Vec<SkinEntry>,
+}
+
+fn invalid_json(e: serde_json::Error) -> Error {
+    Error::InvalidInput(format!("Unexpected CommunityDragon data: {}", e))
+}
+
+/// Numeric champion id for an internal name, from `champion-summary.json`
+fn champion_id(summary: &[u8], champion: &str) -> Result<Option<i64>> {
+    let entries: Vec<SummaryEntry> = serde_json::from_slice(summary).map_err(invalid_json)?;
+    Ok(entries
+        .into_iter()
+        .find(|entry| entry.alias.eq_ignore_ascii_case(champion))
+        .map(|entry| entry.id))
+}
+
+/// Skins and chromas listed in a `champions/{id}.json` file
+pub fn parse_champion_skins(json: &[u8]) -> Result<Vec<SkinMetadata>> {
+    let champion: ChampionEntry = serde_json::from_slice(json).map_err(invalid_json)?;
+    let mut skins = Vec::new();
+    for skin in champion.skins {
+        let id = skin.id % 1000;
+        skins.push(SkinMetadata { id, name: skin.name.clone(), chroma_of: None });
+        skins.extend(skin.chromas.into_iter().map(|chroma| SkinMetadata {
+            id: chroma.id % 1000,
+            name: chroma.name,
+            chroma_of: Some(id),
+        }));
+    }
+    Ok(skins)
+}
+
+/// Puts display names and chroma parents on discovered skins
+///
+/// The base skin keeps its "Base" label; skins the metadata doesn't list
+/// keep their generated name.
+pub fn apply_skin_metadata(skins: &mut [SkinInfo], metadata: &[SkinMetadata]) {
+    let by_id: HashMap<u32, &SkinMetadata> = metadata.iter().map(|skin| (skin.id, skin)).collect();
+    for skin in skins.iter_mut().filter(|skin| skin.id != 0) {
+        if let Some(known) = by_id.get(&skin.id) {
+            skin.name = known.name.clone();
+            skin.chroma_of = known.chroma_of;
+        }
+    }
+}
+
+/// Champions listed in a Data Dragon `champion.json` file
+pub fn parse_roster(json: &[u8]) -> Result<Vec<RosterEntry>> {
+    let roster: Roster = serde_json::from_slice(json).map_err(invalid_json)?;
+    Ok(roster.data.into_values().collect())
+}
+
+/// Puts display names, titles and keys on discovered champions
+///
+/// Champions match by internal name, ignoring case.
+pub fn apply_roster(champions: &mut [ChampionInfo], roster: &[RosterEntry]) {
+    let by_alias: HashMap<String, &RosterEntry> =
+        roster.iter().map(|entry| (entry.alias.to_lowercase(), entry)).collect();
+    for champion in champions {
+        if let Some(entry) = by_alias.get(&champion.internal_name.to_lowercase()) {
+            champion.name = entry.name.clone();
+            champion.title = Some(entry.title.clone());
+            champion.key = Some(entry.key);
+        }
+    }
+}
+
+/// Returns a cached file, downloading it first when missing or older than a week
+async fn cached_download(client: &Client, cache_dir: &Path, name: &str, url: &str) -> Result<Vec<u8>> {
+    let path = cache_dir.join(name);
+    let fresh = fs::metadata(&path)
+        .await
+        .ok()
+        .and_then(|metadata| metadata.modified().ok())
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_AGE);
+    if fresh {
+        if let Ok(data) = fs::read(&path).await {
+            return Ok(data);
+        }
+    }
+
+    let download = async {
+        let response = client.get(url).send().await.map_err(Error::Network)?;
+        if !response.status().is_success() {
+            return Err(Error::InvalidInput(format!("{} returned status {}", url, response.status())));
+        }
+        Ok(response.bytes().await.map_err(Error::Network)?.to_vec())
+    };
+    match download.await {
+        Ok(data) => {
+            if let Err(e) = fs::create_dir_all(cache_dir).await {
+                tracing::warn!("Failed to create {}: {}", cache_dir.display(), e);
+            } else if let Err(e) = fs::write(&path, &data).await {
+                tracing::warn!("Failed to cache {}: {}", path.display(), e);
+            }
+            Ok(data)
+        }
+        Err(e) => match fs::read(&path).await {
+            Ok(stale) => {
+                tracing::warn!("Using cached {} after a failed refresh: {}", name, e);
+                Ok(stale)
+            }
+            Err(_) => Err(e),
+        },
+    }
+}
+
+/// Fetches the skins of a champion, through the cache in `cache_dir`
+///
+/// # Returns
+/// * `Ok(Some(skins))` - Skin and chroma names by skin number
+/// * `Ok(None)` - CommunityDragon doesn't list the champion
+pub async fn fetch_skin_metadata(cache_dir: &Path, champion: &str) -> Result<Option<Vec<SkinMetadata>>> {
+    let client = Client::builder().user_agent("flint").build().map_err(Error::Network)?;
+
+    let summary_url = format!("{}/champion-summary.json", GAME_DATA_URL);
+    let summary = cached_download(&client, cache_dir, "champion-summary.json", &summary_url).await?;
+    let Some(id) = champion_id(&summary, champion)? else {
+        return Ok(None);
+    };
+
+    let name = format!("{}.json", id);
+    let url = format!("{}/champions/{}", GAME_DATA_URL, name);
+    let champion_json = cached_download(&client, cache_dir, &name, &url).await?;
+    parse_champion_skins(&champion_json).map(Some)
+}
+
+/// Fetches the champion roster of the latest patch, through the cache in `cache_dir`
+pub async fn fetch_roster(cache_dir: &Path) -> Result<Vec<RosterEntry>> {
+    let client = Client::builder().user_agent("flint").build().map_err(Error::Network)?;
+
+    let versions_url = format!("{}/api/versions.json", DDRAGON_URL);
+    let versions = cached_download(&client, cache_dir, "versions.json", &versions_url).await?;
+    let versions: Vec<String> = serde_json::from_slice(&versions).map_err(invalid_json)?;
+    let version = versions
+        .first()
+        .ok_or_else(|| Error::InvalidInput("Data Dragon lists no versions".to_string()))?;
+
+    let name = format!("champion-{}.json", version);
+    let url = format!("{}/cdn/{}/data/en_US/champion.json", DDRAGON_URL, version);
+    let roster = cached_download(&client, cache_dir, &name, &url).await?;
+    parse_roster(&roster)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_apply_skin_metadata() {
+        let summary = br#"[{"id": -1, "alias": "None"}, {"id": 103, "alias": "Ahri", "name": "Ahri"}]"#;
+        assert_eq!(champion_id(summary, "ahri").unwrap(), Some(103));
+        assert_eq!(champion_id(summary, "Zed").unwrap(), None);
+
+        let json = br#"{"id": 103, "skins": [
+            {"id": 103000, "name": "Ahri", "isBase": true},
+            {"id": 103001, "name": "Dynasty Ahri", "chromas": [
+                {"id": 103005, "name": "Dynasty Ahri (Ruby)"}
+            ]},
+            {"id": 103037, "name": "Spirit Blossom Ahri"}
+        ]}"#;
+        let metadata = parse_champion_skins(json).unwrap();
+        assert_eq!(
+            metadata[2],
+            SkinMetadata { id: 5, name: "Dynasty Ahri (Ruby)".to_string(), chroma_of: Some(1) }
+        );
+
+        let mut skins: Vec<SkinInfo> = [0, 1, 5, 37, 99].into_iter().map(SkinInfo::new).collect();
+        apply_skin_metadata(&mut skins, &metadata);
+        let named: Vec<_> = skins.iter().map(|s| (s.name.as_str(), s.chroma_of)).collect();
+        assert_eq!(
+            named,
+            vec![
+                ("Base", None),
+                ("Dynasty Ahri", None),
+                ("Dynasty Ahri (Ruby)", Some(1)),
+                ("Spirit Blossom Ahri", None),
+                ("Skin 99", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_and_apply_roster() {
+        let json = br#"{"type": "champion", "data": {
+            "MonkeyKing": {"id": "MonkeyKing", "key": "62", "name": "Wukong", "title": "the Monkey King", "tags": []},
+            "Ahri": {"id": "Ahri", "key": "103", "name": "Ahri", "title": "the Nine-Tailed Fox"}
+        }}"#;
+        let roster = parse_roster(json).unwrap();
+        assert_eq!(roster.len(), 2);
+
+        let mut champions = vec![ChampionInfo::new("monkeyking"), ChampionInfo::new("Zed")];
+        apply_roster(&mut champions, &roster);
+        assert_eq!(champions[0].name, "Wukong");
+        assert_eq!(champions[0].title.as_deref(), Some("the Monkey King"));
+        assert_eq!(champions[0].key, Some(62));
+        assert_eq!((champions[1].name.as_str(), champions[1].key), ("Zed", None));
+
+        assert!(parse_roster(br#"{"data": {"Ahri": {"id": "Ahri", "key": "x", "name": "", "title": ""}}}"#).is_err());
+    }
+}
@@ -1,4 +1,7 @@
 // Champion discovery module exports
 pub mod discovery;
+pub mod metadata;
+pub mod preview;
 
 pub use discovery::{discover_champions, get_champion_skins, ChampionInfo, SkinInfo};
+pub use preview::{preview_skin_contents, SkinContentsPreview};
@@ -0,0 +1,258 @@
+//! Skin contents preview
+//!
+//! Creating a project extracts the whole champion WAD before anything is
+//! cleaned up, which can take a while for large champions. The preview reads
+//! the skin BIN (and the linked BINs shipped in the same WAD) straight from
+//! the archive, without writing anything, and summarizes what the skin
+//! references and how much extraction will write.
+
+use crate::core::bin::ltk_bridge::read_bin;
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::repath::refather::scan_tree_for_paths;
+use crate::core::validation::engine::infer_asset_type;
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use ts_rs::TS;
+use xxhash_rust::xxh64::xxh64;
+
+/// A BIN linked from the skin BIN
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LinkedBinInfo {
+    pub path: String,
+    /// Whether the champion WAD contains it; shared BINs usually live elsewhere
+    pub in_wad: bool,
+    #[ts(type = "number")]
+    pub size: u64,
+}
+
+/// Referenced assets of one type
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AssetTypeSummary {
+    /// Asset type based on extension (e.g. "Texture", "Model")
+    pub asset_type: String,
+    pub count: usize,
+    /// References not found in the champion WAD
+    pub missing: usize,
+    /// Uncompressed size of the referenced files found in the WAD
+    #[ts(type = "number")]
+    pub size: u64,
+}
+
+/// What a skin references and what extracting it will write
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SkinContentsPreview {
+    pub champion: String,
+    pub skin_id: u32,
+    /// Path of the skin BIN inside the WAD
+    pub skin_bin: String,
+    pub linked_bins: Vec<LinkedBinInfo>,
+    /// Referenced assets grouped by type, largest first
+    pub asset_types: Vec<AssetTypeSummary>,
+    /// Distinct asset paths referenced by the skin and its linked BINs
+    pub referenced_count: usize,
+    /// Uncompressed size of the referenced files found in the WAD
+    #[ts(type = "number")]
+    pub referenced_size: u64,
+    /// Files project creation extracts (everything under `assets/` and `data/`)
+    pub extraction_count: usize,
+    /// Uncompressed size of those files
+    #[ts(type = "number")]
+    pub extraction_size: u64,
+    /// Linked BINs in the WAD that couldn't be parsed
+    pub warnings: Vec<String>,
+}
+
+/// Path of a skin BIN inside a champion WAD
+pub fn skin_bin_path(champion: &str, skin_id: u32) -> String {
+    format!("data/characters/{}/skins/skin{}.bin", champion.to_lowercase(), skin_id)
+}
+
+pub(crate) fn path_hash(path: &str) -> u64 {
+    xxh64(path.to_lowercase().as_bytes(), 0)
+}
+
+/// Reads a chunk's decompressed data, None if the WAD doesn't have it
+pub(crate) fn read_chunk(reader: &mut WadReader, path: &str) -> Result<Option<Vec<u8>>> {
+    let Some(chunk) = reader.get_chunk(path_hash(path)).copied() else {
+        return Ok(None);
+    };
+    let (mut decoder, _) = reader.wad_mut().decode();
+    let data = decoder
+        .load_chunk_decompressed(&chunk)
+        .map_err(|e| Error::wad_with_path(format!("Failed to decompress chunk: {}", e), path))?;
+    Ok(Some(data.to_vec()))
+}
+
+/// Summarizes a skin's contents without extracting anything
+///
+/// # Arguments
+/// * `wad_path` - The champion WAD
+/// * `champion` - Champion internal name (e.g. "Ahri")
+/// * `skin_id` - Skin number (0 for the base skin)
+/// * `hashtable` - Used to count the files extraction would write; without
+///   it every chunk is counted
+pub fn preview_skin_contents(
+    wad_path: &Path,
+    champion: &str,
+    skin_id: u32,
+    hashtable: Option<&Hashtable>,
+) -> Result<SkinContentsPreview> {
+    let mut reader = WadReader::open(wad_path)?;
+    let skin_bin = skin_bin_path(champion, skin_id);
+
+    let data = read_chunk(&mut reader, &skin_bin)?.ok_or_else(|| {
+        Error::InvalidInput(format!("Skin {} of {} not found in {}", skin_id, champion, wad_path.display()))
+    })?;
+    let tree = read_bin(&data).map_err(|e| Error::bin_conversion_with_path(e.to_string(), &skin_bin))?;
+
+    let mut referenced: BTreeSet<String> = scan_tree_for_paths(&tree).into_iter().collect();
+
+    // Linked BINs shipped with the champion are extracted too, so their
+    // references count as well
+    let mut linked_bins = Vec::new();
+    let mut warnings = Vec::new();
+    for link in &tree.dependencies {
+        let link_path = link.to_lowercase().replace('\\', "/");
+        let chunk = reader.get_chunk(path_hash(&link_path)).copied();
+        linked_bins.push(LinkedBinInfo {
+            path: link_path.clone(),
+            in_wad: chunk.is_some(),
+            size: chunk.map(|c| c.uncompressed_size as u64).unwrap_or(0),
+        });
+        if chunk.is_none() {
+            continue;
+        }
+        match read_chunk(&mut reader, &link_path)?.map(|data| read_bin(&data)) {
+            Some(Ok(linked)) => {
+                referenced.extend(scan_tree_for_paths(&linked));
+            }
+            Some(Err(e)) => warnings.push(format!("Failed to parse linked BIN '{}': {}", link_path, e)),
+            None => {}
+        }
+    }
+
+    let mut by_type: BTreeMap<String, AssetTypeSummary> = BTreeMap::new();
+    let mut referenced_size = 0;
+    for path in &referenced {
+        let asset_type = infer_asset_type(path);
+        let summary = by_type.entry(asset_type.clone()).or_insert_with(|| AssetTypeSummary {
+            asset_type,
+            count: 0,
+            missing: 0,
+            size: 0,
+        });
+        summary.count += 1;
+        match reader.get_chunk(path_hash(path)) {
+            Some(chunk) => {
+                summary.size += chunk.uncompressed_size as u64;
+                referenced_size += chunk.uncompressed_size as u64;
+            }
+            None => summary.missing += 1,
+        }
+    }
+    let mut asset_types: Vec<AssetTypeSummary> = by_type.into_values().collect();
+    asset_types.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.asset_type.cmp(&b.asset_type)));
+
+    // Mirrors the filter of extract_skin_assets
+    let extracted = reader.chunks().iter().filter(|(hash, _)| match hashtable {
+        Some(hashtable) => hashtable
+            .lookup(**hash, HashVariant::Xxh64)
+            .map(|path| {
+                let path = path.to_lowercase();
+                path.starts_with("assets/") || path.starts_with("data/")
+            })
+            .unwrap_or(false),
+        None => true,
+    });
+    let (extraction_count, extraction_size) = extracted.fold((0, 0u64), |(count, size), (_, chunk)| {
+        (count + 1, size + chunk.uncompressed_size as u64)
+    });
+
+    Ok(SkinContentsPreview {
+        champion: champion.to_string(),
+        skin_id,
+        skin_bin,
+        linked_bins,
+        asset_types,
+        referenced_count: referenced.len(),
+        referenced_size,
+        extraction_count,
+        extraction_size,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+    use crate::core::wad::test_util::write_wad;
+
+    const SKIN: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {
+    "DATA/Characters/Ahri/Animations/Skin3.bin"
+    "DATA/Shared/Missing.bin"
+}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin3" = SkinCharacterDataProperties {
+        skinMeshProperties: embed = SkinMeshDataProperties {
+            simpleSkin: string = "ASSETS/Characters/Ahri/Skins/Skin03/Ahri_Skin03.skn"
+            texture: string = "ASSETS/Characters/Ahri/Skins/Skin03/Ahri_Skin03_TX_CM.dds"
+            glowTexture: string = "ASSETS/Shared/Glow.dds"
+        }
+    }
+}
+"#;
+
+    const ANIMATIONS: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+entries: map[hash,embed] = {
+    "Characters/Ahri/Animations/Skin3" = animationGraphData {
+        mAnimationFilePath: string = "ASSETS/Characters/Ahri/Skins/Skin03/Animations/Idle.anm"
+    }
+}
+"#;
+
+    #[test]
+    fn test_preview_skin_contents() {
+        let files: Vec<(&str, Vec<u8>)> = vec![
+            ("data/characters/ahri/skins/skin3.bin", write_bin(&text_to_tree(SKIN).unwrap()).unwrap()),
+            ("data/characters/ahri/animations/skin3.bin", write_bin(&text_to_tree(ANIMATIONS).unwrap()).unwrap()),
+            ("assets/characters/ahri/skins/skin03/ahri_skin03.skn", vec![1; 100]),
+            ("assets/characters/ahri/skins/skin03/ahri_skin03_tx_cm.dds", vec![2; 400]),
+            ("assets/characters/ahri/skins/skin03/animations/idle.anm", vec![3; 50]),
+            ("assets/characters/ahri/skins/skin07/unrelated.dds", vec![4; 10]),
+        ];
+
+        let dir = tempfile::tempdir().unwrap();
+        let wad_path = dir.path().join("ahri.wad.client");
+        write_wad(&wad_path, &files);
+
+        let preview = preview_skin_contents(&wad_path, "Ahri", 3, None).unwrap();
+        assert_eq!(preview.skin_bin, "data/characters/ahri/skins/skin3.bin");
+        assert_eq!(preview.linked_bins.len(), 2);
+        assert!(preview.linked_bins[0].in_wad);
+        assert!(!preview.linked_bins[1].in_wad);
+
+        // The animation comes from the linked BIN; the shared glow isn't in this WAD
+        assert_eq!(preview.referenced_count, 4);
+        assert_eq!(preview.referenced_size, 550);
+        let textures = preview.asset_types.iter().find(|t| t.asset_type == "Texture").unwrap();
+        assert_eq!((textures.count, textures.missing, textures.size), (2, 1, 400));
+        assert_eq!(preview.asset_types[0].asset_type, "Texture");
+
+        assert_eq!(preview.extraction_count, files.len());
+        assert!(preview.warnings.is_empty());
+
+        assert!(preview_skin_contents(&wad_path, "Ahri", 9, None).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core::store::write_replacing;
 use crate::error::{Error, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -7,8 +8,10 @@ use std::path::{Path, PathBuf};
 use sha2::{Sha256, Digest};
 use uuid::Uuid;
 use walkdir::WalkDir;
+use ts_rs::TS;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub enum AssetType {
     Texture,
     Model,
@@ -19,15 +22,18 @@ pub enum AssetType {
     Unknown,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct FileEntry {
     pub path: String,       // Relative to project root
     pub hash: String,      // SHA256 of content
+    #[ts(type = "number")]
     pub size: u64,
     pub asset_type: AssetType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct Checkpoint {
     pub id: String,
     pub timestamp: DateTime<Utc>,
@@ -38,7 +44,8 @@ pub struct Checkpoint {
 }
 
 /// Content types returned when reading a checkpoint file for preview
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 #[serde(tag = "type")]
 pub enum CheckpointFileContent {
     /// Base64-encoded PNG image data
@@ -49,20 +56,72 @@ pub enum CheckpointFileContent {
     Text { data: String },
     /// Binary file (only size returned)
     #[serde(rename = "binary")]
-    Binary { size: u64 },
+    Binary {
+        #[ts(type = "number")]
+        size: u64,
+    },
 }
 
 /// Progress information emitted during checkpoint creation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct CheckpointProgress {
     pub phase: String,
+    #[ts(type = "number")]
     pub current: u64,
+    #[ts(type = "number")]
     pub total: u64,
 }
 
+/// Tag of checkpoints that only cover the project's `content/` folder
+pub const SNAPSHOT_TAG: &str = "snapshot";
+
+/// Tag of checkpoints taken automatically before a restore
+const AUTO_BACKUP_TAG: &str = "auto-backup";
+
+/// Snapshot metadata, without the file manifest
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SnapshotInfo {
+    pub id: String,
+    pub timestamp: DateTime<Utc>,
+    pub message: String,
+    pub tags: Vec<String>,
+    pub file_count: usize,
+    /// Size of all files in the snapshot
+    #[ts(type = "number")]
+    pub total_size: u64,
+}
+
+impl From<&Checkpoint> for SnapshotInfo {
+    fn from(checkpoint: &Checkpoint) -> Self {
+        Self {
+            id: checkpoint.id.clone(),
+            timestamp: checkpoint.timestamp,
+            message: checkpoint.message.clone(),
+            tags: checkpoint.tags.clone(),
+            file_count: checkpoint.file_manifest.len(),
+            total_size: checkpoint.file_manifest.values().map(|entry| entry.size).sum(),
+        }
+    }
+}
+
+/// Collect every file under `content/`
+///
+/// Unlike [`collect_project_files`] nothing is skipped: a layer may well
+/// hold a folder named `output`.
+fn collect_content_files(project_path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(project_path.join("content"))
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect()
+}
+
 /// Directories/files to skip when scanning or cleaning
 fn should_skip_dir(name: &str) -> bool {
-    matches!(name, ".flint" | ".git" | "node_modules" | "output")
+    matches!(name, ".flint" | ".git" | ".trash" | "node_modules" | "output")
 }
 
 /// Collect all project files (excluding internal dirs), returning their paths
@@ -123,12 +182,26 @@ impl CheckpointManager {
             cb("Scanning files...", 0, 0);
         }
         let files = collect_project_files(&self.project_path);
+        self.record(&files, message, tags, progress.as_ref())
+    }
+
+    /// Hashes files into the object store and saves a checkpoint listing them
+    fn record<F>(
+        &self,
+        files: &[PathBuf],
+        message: String,
+        tags: Vec<String>,
+        progress: Option<&F>,
+    ) -> Result<Checkpoint>
+    where
+        F: Fn(&str, u64, u64),
+    {
         let total = files.len() as u64;
 
         // Phase 2: Hash and store each file
         let mut manifest = HashMap::new();
         for (i, full_path) in files.iter().enumerate() {
-            if let Some(ref cb) = progress {
+            if let Some(cb) = progress {
                 cb("Saving checkpoint...", (i + 1) as u64, total);
             }
 
@@ -167,6 +240,84 @@ impl CheckpointManager {
         self.create_checkpoint_with_progress(message, tags, None::<fn(&str, u64, u64)>)
     }
 
+    /// Snapshot the project's `content/` folder
+    ///
+    /// Snapshots share the object store with checkpoints, so files that didn't
+    /// change since an earlier snapshot take no extra space.
+    pub fn create_snapshot<F>(&self, message: String, progress: Option<F>) -> Result<Checkpoint>
+    where
+        F: Fn(&str, u64, u64),
+    {
+        self.init()?;
+        if let Some(ref cb) = progress {
+            cb("Scanning files...", 0, 0);
+        }
+        let files = collect_content_files(&self.project_path);
+        self.record(&files, message, vec![SNAPSHOT_TAG.to_string()], progress.as_ref())
+    }
+
+    /// List snapshots, newest first
+    pub fn list_snapshots(&self) -> Result<Vec<SnapshotInfo>> {
+        Ok(self
+            .list_checkpoints()?
+            .iter()
+            .filter(|cp| cp.tags.iter().any(|tag| tag == SNAPSHOT_TAG))
+            .map(SnapshotInfo::from)
+            .collect())
+    }
+
+    /// Roll `content/` back to a snapshot
+    ///
+    /// The current content is snapshotted first so the restore can be undone.
+    /// Files outside `content/` (project metadata, README, thumbnail) are left
+    /// alone. Restored files replace the existing ones instead of being written
+    /// into them, so files hardlinked into the asset store stay intact.
+    pub fn restore_snapshot(&self, id: &str) -> Result<SnapshotInfo> {
+        let snapshot = self.load_checkpoint(id)?;
+        if !snapshot.tags.iter().any(|tag| tag == SNAPSHOT_TAG) {
+            return Err(Error::InvalidInput(format!("Checkpoint {} is not a snapshot", id)));
+        }
+        // Check every object up front so a broken snapshot changes nothing
+        if let Some(entry) = snapshot
+            .file_manifest
+            .values()
+            .find(|entry| !self.object_store.join(&entry.hash[..2]).join(&entry.hash).is_file())
+        {
+            return Err(Error::InvalidInput(format!("Object not found for hash: {}", entry.hash)));
+        }
+
+        let backup = self.record(
+            &collect_content_files(&self.project_path),
+            format!("Before restoring: {}", snapshot.message),
+            vec![SNAPSHOT_TAG.to_string(), AUTO_BACKUP_TAG.to_string()],
+            None::<&fn(&str, u64, u64)>,
+        )?;
+
+        for (rel_path, entry) in &backup.file_manifest {
+            let restored = snapshot.file_manifest.get(rel_path);
+            if restored.is_none_or(|e| e.hash != entry.hash) {
+                let path = self.project_path.join(rel_path);
+                fs::remove_file(&path).map_err(|e| Error::io_with_path(e, &path))?;
+            }
+        }
+
+        for (rel_path, entry) in &snapshot.file_manifest {
+            if backup.file_manifest.get(rel_path).is_some_and(|e| e.hash == entry.hash) {
+                continue;
+            }
+            let target_path = self.project_path.join(rel_path);
+            let object_path = self.object_store.join(&entry.hash[..2]).join(&entry.hash);
+            if let Some(parent) = target_path.parent() {
+                fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+            }
+            fs::copy(&object_path, &target_path).map_err(|e| Error::io_with_path(e, &target_path))?;
+        }
+
+        self.cleanup_empty_dirs()?;
+        tracing::info!("Restored snapshot {} ({}) in {}", id, snapshot.message, self.project_path.display());
+        Ok(SnapshotInfo::from(&snapshot))
+    }
+
     fn hash_and_store_file(&self, path: &Path) -> Result<(String, u64)> {
         let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
         let size = data.len() as u64;
@@ -288,7 +439,9 @@ impl CheckpointManager {
                 fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
             }
 
-            fs::copy(&object_path, &target_path).map_err(|e| Error::io_with_path(e, &target_path))?;
+            // The file may still exist as a link into the asset store
+            let data = fs::read(&object_path).map_err(|e| Error::io_with_path(e, &object_path))?;
+            write_replacing(&target_path, &data).map_err(|e| Error::io_with_path(e, &target_path))?;
         }
 
         // 4. Clean up empty directories left after file deletion
@@ -437,9 +590,6 @@ impl CheckpointManager {
         let texture = Texture::from_reader(&mut cursor)
             .map_err(|e| format!("Failed to parse texture: {:?}", e))?;
 
-        let width = texture.width();
-        let height = texture.height();
-
         let surface = texture
             .decode_mipmap(0)
             .map_err(|e| format!("Failed to decode texture: {:?}", e))?;
@@ -447,6 +597,7 @@ impl CheckpointManager {
         let rgba_image = surface
             .into_rgba_image()
             .map_err(|e| format!("Failed to convert to RGBA: {:?}", e))?;
+        let (width, height) = rgba_image.dimensions();
 
         let mut png_data = Vec::new();
         {
@@ -462,9 +613,44 @@ impl CheckpointManager {
     }
 }
 
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct CheckpointDiff {
     pub added: Vec<FileEntry>,
     pub modified: Vec<(FileEntry, FileEntry)>, // (old, new)
     pub deleted: Vec<FileEntry>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_restores_content_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path();
+        let wad = project.join("content/base/Ahri.wad.client");
+        fs::create_dir_all(wad.join("data")).unwrap();
+        fs::write(wad.join("data/skin0.bin"), b"original").unwrap();
+        fs::write(project.join("README.md"), b"readme").unwrap();
+
+        let manager = CheckpointManager::new(project.to_path_buf());
+        let snapshot = manager.create_snapshot("Before repath".to_string(), None::<fn(&str, u64, u64)>).unwrap();
+        assert_eq!(snapshot.file_manifest.len(), 1);
+
+        fs::write(wad.join("data/skin0.bin"), b"repathed").unwrap();
+        fs::create_dir_all(wad.join("assets/bum")).unwrap();
+        fs::write(wad.join("assets/bum/new.dds"), b"moved").unwrap();
+        fs::write(project.join("README.md"), b"edited").unwrap();
+
+        manager.restore_snapshot(&snapshot.id).unwrap();
+        assert_eq!(fs::read(wad.join("data/skin0.bin")).unwrap(), b"original");
+        assert!(!wad.join("assets").exists());
+        assert_eq!(fs::read(project.join("README.md")).unwrap(), b"edited");
+
+        let snapshots = manager.list_snapshots().unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots.iter().any(|s| s.tags.iter().any(|t| t == AUTO_BACKUP_TAG) && s.file_count == 2));
+    }
+}
@@ -0,0 +1,405 @@
+//! Chroma variant generation
+//!
+//! A chroma is the same skin with recolored textures. Flint builds one as a
+//! project layer: the chosen textures are copied from the base layer into a
+//! new layer and recolored there, so the base skin stays untouched and the
+//! layer overrides it on export.
+
+use crate::core::project::{add_layer, Project};
+use crate::core::store::write_replacing;
+use crate::core::texture::{decode_top_mip, encode_like, read_texture};
+use crate::error::{Error, Result};
+use image::{Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Color transform applied to a texture
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ColorShift {
+    /// Rotate the hue and scale saturation and lightness
+    Hsl {
+        /// Degrees added to the hue
+        hue: f32,
+        /// Saturation multiplier (1.0 keeps it)
+        saturation: f32,
+        /// Lightness multiplier (1.0 keeps it)
+        brightness: f32,
+    },
+    /// Paint everything in one hue, keeping the original shading
+    Tint {
+        /// Target hue in degrees
+        hue: f32,
+        preserve_saturation: bool,
+    },
+}
+
+impl ColorShift {
+    /// Applies the transform to every pixel; alpha is never changed
+    pub fn apply(&self, img: &mut RgbaImage) {
+        match *self {
+            ColorShift::Hsl { hue, saturation, brightness } => shift_hsl(img, hue, saturation, brightness),
+            ColorShift::Tint { hue, preserve_saturation } => tint(img, hue, preserve_saturation),
+        }
+    }
+}
+
+/// A texture [`apply_color_shift`] couldn't recolor
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ColorShiftFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Outcome of recoloring the textures of a layer
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ColorShiftResult {
+    /// Recolored textures, relative to the layer folder
+    pub recolored: Vec<String>,
+    pub failed: Vec<ColorShiftFailure>,
+}
+
+// =============================================================================
+// HSL Color Transformation Helpers
+// =============================================================================
+
+fn rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    if max == min {
+        return (0.0, 0.0, l);
+    }
+
+    let d = max - min;
+    let s = if l > 0.5 { d / (2.0 - max - min) } else { d / (max + min) };
+
+    let mut h = if max == r {
+        (g - b) / d + (if g < b { 6.0 } else { 0.0 })
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    h /= 6.0;
+
+    (h * 360.0, s, l)
+}
+
+fn hue_to_rgb(p: f32, q: f32, mut t: f32) -> f32 {
+    if t < 0.0 { t += 1.0; }
+    if t > 1.0 { t -= 1.0; }
+    if t < 1.0/6.0 { return p + (q - p) * 6.0 * t; }
+    if t < 1.0/2.0 { return q; }
+    if t < 2.0/3.0 { return p + (q - p) * (2.0/3.0 - t) * 6.0; }
+    p
+}
+
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    if s == 0.0 {
+        return (l, l, l);
+    }
+
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+
+    let h = h / 360.0;
+    let r = hue_to_rgb(p, q, h + 1.0/3.0);
+    let g = hue_to_rgb(p, q, h);
+    let b = hue_to_rgb(p, q, h - 1.0/3.0);
+
+    (r, g, b)
+}
+
+fn shift_hsl(img: &mut RgbaImage, hue_shift: f32, sat_mult: f32, bri_mult: f32) {
+    for pixel in img.pixels_mut() {
+        let Rgba([r, g, b, a]) = *pixel;
+
+        // Convert to normalized float
+        let rf = r as f32 / 255.0;
+        let gf = g as f32 / 255.0;
+        let bf = b as f32 / 255.0;
+
+        // Convert to HSL
+        let (h, s, l) = rgb_to_hsl(rf, gf, bf);
+
+        // Apply shifts/multipliers
+        let new_h = (h + hue_shift) % 360.0;
+        let new_h = if new_h < 0.0 { new_h + 360.0 } else { new_h };
+        let new_s = (s * sat_mult).clamp(0.0, 1.0);
+        let new_l = (l * bri_mult).clamp(0.0, 1.0);
+
+        // Convert back to RGB
+        let (nr, ng, nb) = hsl_to_rgb(new_h, new_s, new_l);
+
+        // Update pixel
+        *pixel = Rgba([
+            (nr * 255.0).round() as u8,
+            (ng * 255.0).round() as u8,
+            (nb * 255.0).round() as u8,
+            a
+        ]);
+    }
+}
+
+/// Colorize mode: Set all pixels to a target hue while preserving lightness
+/// This makes everything "one color" while keeping the original shading
+/// Skips transparent pixels and very dark/black pixels to preserve backgrounds
+fn tint(img: &mut RgbaImage, target_hue: f32, preserve_saturation: bool) {
+    for pixel in img.pixels_mut() {
+        let Rgba([r, g, b, a]) = *pixel;
+
+        // Skip fully transparent pixels (preserve alpha)
+        if a == 0 {
+            continue;
+        }
+
+        // Convert to normalized float
+        let rf = r as f32 / 255.0;
+        let gf = g as f32 / 255.0;
+        let bf = b as f32 / 255.0;
+
+        // Convert to HSL
+        let (_h, s, l) = rgb_to_hsl(rf, gf, bf);
+
+        // Skip very dark pixels (black backgrounds) - threshold at ~10% lightness
+        if l < 0.10 {
+            continue;
+        }
+
+        // Set to target hue, optionally preserve original saturation
+        let new_s = if preserve_saturation { s } else { 0.7_f32.min(s.max(0.3)) };
+
+        // Convert back to RGB with target hue
+        let (nr, ng, nb) = hsl_to_rgb(target_hue, new_s, l);
+
+        // Update pixel (alpha is preserved)
+        *pixel = Rgba([
+            (nr * 255.0).round() as u8,
+            (ng * 255.0).round() as u8,
+            (nb * 255.0).round() as u8,
+            a
+        ]);
+    }
+}
+
+// =============================================================================
+// Texture recoloring
+// =============================================================================
+
+/// Returns true for DDS and TEX files (by extension)
+pub fn is_texture_path(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext.eq_ignore_ascii_case("dds") || ext.eq_ignore_ascii_case("tex"))
+        .unwrap_or(false)
+}
+
+/// Distortion textures drive UV effects rather than color, so recoloring
+/// them breaks the effect
+pub fn is_distortion_texture(path: &Path) -> bool {
+    let filename = path.file_name().map(|n| n.to_string_lossy().to_lowercase()).unwrap_or_default();
+    filename.contains("distortion") || filename.contains("distort")
+}
+
+/// Recolors a DDS or TEX texture, returning the re-encoded file
+///
+/// The top mip is decoded, transformed and encoded again in the same
+/// container and format with a regenerated mip chain.
+pub fn recolor_texture(data: &[u8], shift: &ColorShift) -> Result<Vec<u8>> {
+    let texture = read_texture(data)?;
+    let mut rgba_img = decode_top_mip(&texture)?;
+    shift.apply(&mut rgba_img);
+    encode_like(data, &texture, &rgba_img)
+}
+
+/// Recolors a texture file in place
+pub fn recolor_texture_file(path: &Path, shift: &ColorShift) -> Result<()> {
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    let recolored = recolor_texture(&data, shift)?;
+    write_replacing(path, &recolored).map_err(|e| Error::io_with_path(e, path))
+}
+
+// =============================================================================
+// Chroma layers
+// =============================================================================
+
+/// Lists the textures of a layer, relative to the layer folder
+fn layer_textures(layer_dir: &Path) -> Vec<String> {
+    let mut textures: Vec<String> = WalkDir::new(layer_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && is_texture_path(e.path()))
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(layer_dir)
+                .ok()
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+        })
+        .collect();
+    textures.sort();
+    textures
+}
+
+/// Creates a layer holding copies of the base layer's textures
+///
+/// # Arguments
+/// * `project` - Project to add the layer to
+/// * `layer` - Name of the new layer
+/// * `priority` - Layer priority; defaults to one above the current highest
+/// * `textures` - Textures to copy, relative to `content/base`; all of them if `None`
+///
+/// # Returns
+/// The copied textures, relative to the new layer folder
+pub fn create_chroma_layer(
+    project: &mut Project,
+    layer: &str,
+    priority: Option<i32>,
+    textures: Option<&[String]>,
+) -> Result<Vec<String>> {
+    let base = project.assets_path();
+    let selected = match textures {
+        Some(textures) => textures.iter().map(|t| t.replace('\\', "/")).collect(),
+        None => layer_textures(&base),
+    };
+
+    for texture in &selected {
+        let source = base.join(texture);
+        if !source.is_file() || !is_texture_path(&source) {
+            return Err(Error::InvalidInput(format!("Not a texture in the base layer: {}", texture)));
+        }
+    }
+
+    add_layer(project, layer, priority, Some("Chroma".to_string()))?;
+
+    let layer_dir = project.content_path(layer);
+    for texture in &selected {
+        let dest = layer_dir.join(texture);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        fs::copy(base.join(texture), &dest).map_err(|e| Error::io_with_path(e, &dest))?;
+    }
+
+    tracing::info!("Created chroma layer '{}' with {} textures", layer, selected.len());
+    Ok(selected)
+}
+
+/// Recolors textures of a layer in place
+///
+/// # Arguments
+/// * `project` - Project owning the layer
+/// * `layer` - Layer to recolor; the base layer is allowed but rarely wanted
+/// * `textures` - Textures relative to the layer folder; all textures (minus
+///   distortion maps) if empty
+/// * `shift` - Transform to apply
+pub fn apply_color_shift(
+    project: &Project,
+    layer: &str,
+    textures: &[String],
+    shift: &ColorShift,
+) -> Result<ColorShiftResult> {
+    if !project.layers.iter().any(|l| l.name == layer) {
+        return Err(Error::InvalidInput(format!("Layer '{}' not found", layer)));
+    }
+
+    let layer_dir = project.content_path(layer);
+    let targets: Vec<String> = if textures.is_empty() {
+        layer_textures(&layer_dir)
+            .into_iter()
+            .filter(|t| !is_distortion_texture(Path::new(t)))
+            .collect()
+    } else {
+        textures.iter().map(|t| t.replace('\\', "/")).collect()
+    };
+
+    let mut result = ColorShiftResult::default();
+    for texture in targets {
+        match recolor_texture_file(&layer_dir.join(&texture), shift) {
+            Ok(()) => result.recolored.push(texture),
+            Err(e) => {
+                tracing::warn!("Failed to recolor {}: {}", texture, e);
+                result.failed.push(ColorShiftFailure { path: texture, error: e.to_string() });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ltk_texture::Texture;
+    use std::io::Cursor;
+
+    fn red_tex() -> Vec<u8> {
+        let image = RgbaImage::from_pixel(8, 8, Rgba([200, 20, 20, 255]));
+        let tex = ltk_texture::Tex::encode_rgba_image(
+            &image,
+            ltk_texture::tex::EncodeOptions::new(ltk_texture::tex::Format::Bgra8),
+        )
+        .unwrap();
+        let mut out = Cursor::new(Vec::new());
+        tex.write(&mut out).unwrap();
+        out.into_inner()
+    }
+
+    fn top_pixel(data: &[u8]) -> Rgba<u8> {
+        let texture = Texture::from_reader(&mut Cursor::new(data)).unwrap();
+        *texture.decode_mipmap(0).unwrap().into_rgba_image().unwrap().get_pixel(0, 0)
+    }
+
+    #[test]
+    fn test_hsl_shift_rotates_hue() {
+        let mut img = RgbaImage::from_pixel(1, 1, Rgba([255, 0, 0, 128]));
+        ColorShift::Hsl { hue: 120.0, saturation: 1.0, brightness: 1.0 }.apply(&mut img);
+        assert_eq!(*img.get_pixel(0, 0), Rgba([0, 255, 0, 128]));
+    }
+
+    #[test]
+    fn test_recolor_texture_keeps_tex_container() {
+        let shift = ColorShift::Tint { hue: 240.0, preserve_saturation: true };
+        let recolored = recolor_texture(&red_tex(), &shift).unwrap();
+        assert_eq!(&recolored[0..4], b"TEX\0");
+
+        let Rgba([r, g, b, a]) = top_pixel(&recolored);
+        assert!(b > r && b > g, "expected blue, got {:?}", (r, g, b));
+        assert_eq!(a, 255);
+
+        assert!(recolor_texture(b"PROP", &shift).is_err());
+    }
+
+    #[test]
+    fn test_create_and_shift_chroma_layer() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut project = Project::new("Test", "Ahri", 0, dir.path().join("League"), dir.path(), None);
+        let base_tex = project.assets_path().join("ahri.wad.client/assets/ahri.tex");
+        fs::create_dir_all(base_tex.parent().unwrap()).unwrap();
+        fs::write(&base_tex, red_tex()).unwrap();
+        fs::write(base_tex.with_file_name("ahri_distortion.tex"), red_tex()).unwrap();
+
+        let copied = create_chroma_layer(&mut project, "chroma1", None, None).unwrap();
+        assert_eq!(copied.len(), 2);
+        assert_eq!(project.layers.last().unwrap().priority, 1);
+        assert!(create_chroma_layer(&mut project, "chroma1", None, None).is_err());
+
+        let shift = ColorShift::Hsl { hue: 120.0, saturation: 1.0, brightness: 1.0 };
+        let result = apply_color_shift(&project, "chroma1", &[], &shift).unwrap();
+        assert_eq!(result.recolored, vec!["ahri.wad.client/assets/ahri.tex"]);
+        assert!(result.failed.is_empty());
+
+        let chroma_tex = project.content_path("chroma1").join("ahri.wad.client/assets/ahri.tex");
+        let Rgba([r, g, _, _]) = top_pixel(&fs::read(chroma_tex).unwrap());
+        assert!(g > r);
+        // The base layer keeps the original colors
+        let Rgba([r, g, _, _]) = top_pixel(&fs::read(&base_tex).unwrap());
+        assert!(r > g);
+    }
+}
@@ -0,0 +1,402 @@
+//! Deploying a project straight into a mod manager for testing in game
+//!
+//! Seeing a change in game otherwise means exporting a .fantome, switching
+//! to cslol-manager and importing it again on every iteration. A deploy
+//! profile points Flint at a cslol-manager installation; deploying writes the
+//! project into its `installed/` folder the same way cslol lays out an
+//! imported fantome (`META/info.json` plus `WAD/`), enables the mod in a
+//! cslol profile and can rebuild and start the overlay with cslol's
+//! `mod-tools`.
+//!
+//! Profiles are stored as JSON in the app data directory.
+
+use crate::core::league::guard::ensure_outside_league;
+use crate::core::project::open_project;
+use crate::core::wad::packer::pack_wad;
+use crate::core::settings::{load_json_setting, save_json_setting};
+use crate::error::{Error, Result};
+use crate::state::CancelToken;
+use ltk_fantome::FantomeInfo;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Profiles file inside the app data directory
+const PROFILES_FILE: &str = "deploy_profiles.json";
+
+/// cslol profile used when a deploy profile names none
+const DEFAULT_CSLOL_PROFILE: &str = "Default Profile";
+
+/// Prefix of mod folders written by Flint, so they're never mistaken for
+/// mods the user imported
+const MOD_PREFIX: &str = "flint-";
+
+/// How the project's WAD folders end up in the manager
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum DeployFormat {
+    /// The unpacked fantome layout: WAD folders copied as they are
+    #[default]
+    Fantome,
+    /// Every WAD folder packed into a `.wad.client` file
+    Wad,
+}
+
+/// Where and how a project is deployed
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct DeployProfile {
+    /// Name the profile is saved under
+    pub name: String,
+    /// cslol-manager folder holding `installed/` and `profiles/`
+    pub manager_path: String,
+    /// cslol profile the mod is enabled in
+    #[serde(default)]
+    pub cslol_profile: Option<String>,
+    /// League `Game` folder for the overlay; the project's League
+    /// installation when not set
+    #[serde(default)]
+    pub game_path: Option<String>,
+    #[serde(default)]
+    pub format: DeployFormat,
+    /// Rebuild the overlay and start the runner after deploying
+    #[serde(default)]
+    pub run_overlay: bool,
+}
+
+/// Saved deploy profiles
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct DeploySettings {
+    #[serde(default)]
+    pub profiles: Vec<DeployProfile>,
+    /// Profile used by the last deploy
+    #[serde(default)]
+    pub last_profile: Option<String>,
+}
+
+/// Result of deploying a project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct DeployResult {
+    /// Folder name of the mod in the manager
+    pub mod_name: String,
+    pub mod_path: String,
+    /// WAD folders or files written
+    pub wad_count: usize,
+    /// Whether the overlay runner was started
+    pub overlay_started: bool,
+    pub warnings: Vec<String>,
+}
+
+/// Loads the deploy profiles, falling back to none
+pub fn load_settings(app_data_dir: &Path) -> DeploySettings {
+    load_json_setting(app_data_dir, PROFILES_FILE)
+}
+
+/// Writes the deploy profiles to the app data directory
+pub fn save_settings(app_data_dir: &Path, settings: &DeploySettings) -> Result<()> {
+    save_json_setting(app_data_dir, PROFILES_FILE, settings)
+}
+
+/// Adds a profile, replacing one saved under the same name
+pub fn save_profile(app_data_dir: &Path, profile: DeployProfile) -> Result<DeploySettings> {
+    if profile.name.trim().is_empty() {
+        return Err(Error::InvalidInput("A deploy profile needs a name".to_string()));
+    }
+    if !Path::new(&profile.manager_path).is_dir() {
+        return Err(Error::InvalidInput(format!(
+            "Mod manager folder does not exist: {}",
+            profile.manager_path
+        )));
+    }
+
+    let mut settings = load_settings(app_data_dir);
+    match settings.profiles.iter_mut().find(|p| p.name == profile.name) {
+        Some(existing) => *existing = profile,
+        None => settings.profiles.push(profile),
+    }
+    save_settings(app_data_dir, &settings)?;
+    Ok(settings)
+}
+
+/// Removes a saved profile; mods it deployed stay installed
+pub fn delete_profile(app_data_dir: &Path, name: &str) -> Result<DeploySettings> {
+    let mut settings = load_settings(app_data_dir);
+    let count = settings.profiles.len();
+    settings.profiles.retain(|p| p.name != name);
+    if settings.profiles.len() == count {
+        return Err(Error::InvalidInput(format!("No deploy profile named '{}'", name)));
+    }
+    if settings.last_profile.as_deref() == Some(name) {
+        settings.last_profile = None;
+    }
+    save_settings(app_data_dir, &settings)?;
+    Ok(settings)
+}
+
+/// Folder name of a project's mod in the manager
+fn mod_name(project_name: &str) -> String {
+    let slug: String = project_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    format!("{}{}", MOD_PREFIX, slug.trim_matches('-'))
+}
+
+/// The `.wad.client` folders of the project's base layer
+fn wad_folders(base: &Path) -> Result<Vec<PathBuf>> {
+    let mut folders: Vec<PathBuf> = fs::read_dir(base)
+        .map_err(|e| Error::io_with_path(e, base))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && path.to_string_lossy().to_lowercase().ends_with(".wad.client"))
+        .collect();
+    folders.sort();
+    Ok(folders)
+}
+
+fn copy_dir(source: &Path, target: &Path, cancel: &CancelToken) -> Result<()> {
+    for entry in WalkDir::new(source) {
+        if cancel.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        let entry = entry.map_err(|e| Error::InvalidInput(format!("Failed to read {}: {}", source.display(), e)))?;
+        let relative = entry.path().strip_prefix(source).unwrap_or(entry.path());
+        let destination = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&destination).map_err(|e| Error::io_with_path(e, &destination))?;
+        } else if entry.file_type().is_file() {
+            fs::copy(entry.path(), &destination).map_err(|e| Error::io_with_path(e, &destination))?;
+        }
+    }
+    Ok(())
+}
+
+/// Adds a mod to a cslol profile file (one enabled mod per line)
+fn enable_in_profile(profile_path: &Path, mod_name: &str) -> Result<()> {
+    let content = match fs::read_to_string(profile_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(Error::io_with_path(e, profile_path)),
+    };
+    if content.lines().any(|line| line.trim() == mod_name) {
+        return Ok(());
+    }
+
+    let mut content = content;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(mod_name);
+    content.push('\n');
+    if let Some(parent) = profile_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+    fs::write(profile_path, content).map_err(|e| Error::io_with_path(e, profile_path))
+}
+
+/// Builds the overlay for the profile's mods and starts the runner
+///
+/// `mkoverlay` is waited for; `runoverlay` keeps running until the game
+/// closes, so it's started and left alone.
+fn run_overlay(manager: &Path, profile_name: &str, game_path: &Path) -> Result<()> {
+    let mod_tools = ["cslol-tools/mod-tools.exe", "cslol-tools/mod-tools"]
+        .iter()
+        .map(|path| manager.join(path))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            Error::InvalidInput(format!("mod-tools was not found in {}/cslol-tools", manager.display()))
+        })?;
+
+    let profiles = manager.join("profiles");
+    let profile_dir = profiles.join(profile_name);
+    let mods: Vec<String> = fs::read_to_string(profiles.join(format!("{}.profile", profile_name)))
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    let output = Command::new(&mod_tools)
+        .arg("mkoverlay")
+        .arg(manager.join("installed"))
+        .arg(&profile_dir)
+        .arg(format!("--game:{}", game_path.display()))
+        .arg(format!("--mods:{}", mods.join("/")))
+        .arg("--noTFT")
+        .output()
+        .map_err(|e| Error::io_with_path(e, &mod_tools))?;
+    if !output.status.success() {
+        return Err(Error::InvalidInput(format!(
+            "mod-tools mkoverlay failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Command::new(&mod_tools)
+        .arg("runoverlay")
+        .arg(&profile_dir)
+        .arg(profiles.join(format!("{}.config", profile_name)))
+        .arg(format!("--game:{}", game_path.display()))
+        .arg("--opts:none")
+        .spawn()
+        .map_err(|e| Error::io_with_path(e, &mod_tools))?;
+    Ok(())
+}
+
+/// Deploys a project into a cslol-manager installation
+///
+/// The previous deploy of the same project is replaced. Only the base layer
+/// is deployed, as with a plain fantome export.
+///
+/// # Arguments
+/// * `project_path` - Project folder
+/// * `profile` - Manager and format to deploy with
+/// * `cancel` - Checked while copying or packing; a cancelled deploy
+///   removes the partial mod folder
+/// * `on_progress` - Called with (WAD folders done, total)
+pub fn deploy_project(
+    project_path: &Path,
+    profile: &DeployProfile,
+    cancel: &CancelToken,
+    on_progress: Option<&dyn Fn(usize, usize)>,
+) -> Result<DeployResult> {
+    let project = open_project(project_path)?;
+    let manager = PathBuf::from(&profile.manager_path);
+    if !manager.is_dir() {
+        return Err(Error::InvalidInput(format!(
+            "Mod manager folder does not exist: {}",
+            manager.display()
+        )));
+    }
+
+    let base = project_path.join("content").join("base");
+    let folders = wad_folders(&base)?;
+    if folders.is_empty() {
+        return Err(Error::InvalidInput(format!(
+            "{} has no WAD folders to deploy",
+            base.display()
+        )));
+    }
+
+    let mod_name = mod_name(&project.name);
+    let mod_path = manager.join("installed").join(&mod_name);
+    ensure_outside_league(&mod_path)?;
+    if mod_path.exists() {
+        fs::remove_dir_all(&mod_path).map_err(|e| Error::io_with_path(e, &mod_path))?;
+    }
+
+    let result = write_mod(&project, &folders, &mod_path, profile.format, cancel, on_progress);
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&mod_path);
+    }
+    let mut warnings = result?;
+
+    let cslol_profile = profile.cslol_profile.as_deref().unwrap_or(DEFAULT_CSLOL_PROFILE);
+    enable_in_profile(&manager.join("profiles").join(format!("{}.profile", cslol_profile)), &mod_name)?;
+
+    let mut overlay_started = false;
+    if profile.run_overlay {
+        let game_path = profile
+            .game_path
+            .as_ref()
+            .map(PathBuf::from)
+            .or_else(|| project.league_path.as_ref().map(|path| path.join("Game")));
+        match game_path {
+            Some(game_path) => {
+                run_overlay(&manager, cslol_profile, &game_path)?;
+                overlay_started = true;
+            }
+            None => warnings.push(
+                "The overlay was not started: set a game folder in the profile or relink the project's League installation"
+                    .to_string(),
+            ),
+        }
+    }
+
+    tracing::info!(
+        "Deployed {} to {} ({} WADs, overlay {})",
+        project_path.display(),
+        mod_path.display(),
+        folders.len(),
+        if overlay_started { "started" } else { "not started" }
+    );
+
+    Ok(DeployResult {
+        mod_name,
+        mod_path: mod_path.display().to_string(),
+        wad_count: folders.len(),
+        overlay_started,
+        warnings,
+    })
+}
+
+/// Writes `META/info.json` and the WADs of a mod folder; returns warnings
+fn write_mod(
+    project: &crate::core::project::Project,
+    folders: &[PathBuf],
+    mod_path: &Path,
+    format: DeployFormat,
+    cancel: &CancelToken,
+    on_progress: Option<&dyn Fn(usize, usize)>,
+) -> Result<Vec<String>> {
+    let meta = mod_path.join("META");
+    fs::create_dir_all(&meta).map_err(|e| Error::io_with_path(e, &meta))?;
+    let info = FantomeInfo {
+        name: project.display_name.clone(),
+        author: if project.authors.is_empty() {
+            "Unknown".to_string()
+        } else {
+            project.authors.iter().map(|a| a.name.as_str()).collect::<Vec<_>>().join(", ")
+        },
+        version: project.version.clone(),
+        description: project.description.clone(),
+    };
+    let info = serde_json::to_vec_pretty(&info).map_err(|e| Error::InvalidInput(e.to_string()))?;
+    let info_path = meta.join("info.json");
+    fs::write(&info_path, info).map_err(|e| Error::io_with_path(e, &info_path))?;
+
+    let wad_root = mod_path.join("WAD");
+    let mut warnings = Vec::new();
+    for (index, folder) in folders.iter().enumerate() {
+        let target = wad_root.join(folder.file_name().unwrap_or_default());
+        match format {
+            DeployFormat::Fantome => copy_dir(folder, &target, cancel)?,
+            DeployFormat::Wad => warnings.extend(pack_wad(folder, &target, cancel, None)?.warnings),
+        }
+        if let Some(on_progress) = on_progress {
+            on_progress(index + 1, folders.len());
+        }
+    }
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_mod_name() {
+        assert_eq!(mod_name("Ahri Spirit Blossom"), "flint-ahri-spirit-blossom");
+        assert_eq!(mod_name("../evil"), "flint-evil");
+    }
+
+    #[test]
+    fn test_enable_in_profile_is_idempotent() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile = temp_dir.path().join("profiles/Default Profile.profile");
+        fs::create_dir_all(profile.parent().unwrap()).unwrap();
+        fs::write(&profile, "other-mod").unwrap();
+
+        enable_in_profile(&profile, "flint-ahri").unwrap();
+        enable_in_profile(&profile, "flint-ahri").unwrap();
+        assert_eq!(fs::read_to_string(&profile).unwrap(), "other-mod\nflint-ahri\n");
+    }
+}
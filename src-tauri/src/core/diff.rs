@@ -0,0 +1,355 @@
+//! Line diff for text files
+//!
+//! Ritobin caches and exported JSON easily reach several MB, which is too
+//! much to diff in the webview. The diff runs here (Myers' algorithm on
+//! interned lines) and only the changed hunks, with a little context, are
+//! sent to the frontend.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use ts_rs::TS;
+
+/// Edit distance beyond which the remaining middle section is reported as
+/// one replaced block instead of searching for the minimal diff, bounding
+/// memory and time on unrelated files
+const MAX_EDIT_DISTANCE: usize = 2000;
+
+/// Default number of unchanged lines shown around each change
+pub const DEFAULT_CONTEXT: usize = 3;
+
+/// Kind of a line in a diff hunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// One line of a hunk
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// 1-based line number in the old text (not set for added lines)
+    pub old_line: Option<usize>,
+    /// 1-based line number in the new text (not set for removed lines)
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+/// A run of changes with surrounding context
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct DiffHunk {
+    /// 1-based first line of the hunk in the old text
+    pub old_start: usize,
+    pub old_count: usize,
+    /// 1-based first line of the hunk in the new text
+    pub new_start: usize,
+    pub new_count: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Structured line diff of two texts
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TextDiff {
+    pub hunks: Vec<DiffHunk>,
+    pub added: usize,
+    pub removed: usize,
+    pub old_line_count: usize,
+    pub new_line_count: usize,
+    /// The texts differed too much for a minimal diff; part of it is shown
+    /// as one removed block followed by one added block
+    pub approximate: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Maps each distinct line to a small id so comparisons are integer compares
+fn intern_lines<'a>(old: &[&'a str], new: &[&'a str]) -> (Vec<u32>, Vec<u32>) {
+    let mut ids: HashMap<&'a str, u32> = HashMap::new();
+    let mut intern = |line: &'a str| {
+        let next = ids.len() as u32;
+        *ids.entry(line).or_insert(next)
+    };
+    let a = old.iter().map(|l| intern(l)).collect();
+    let b = new.iter().map(|l| intern(l)).collect();
+    (a, b)
+}
+
+/// Shortest edit script between `a` and `b` (Myers), or `None` if it needs
+/// more than `MAX_EDIT_DISTANCE` edits
+fn myers(a: &[u32], b: &[u32]) -> Option<Vec<Op>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    // trace[d] holds v[-(d+1)..=(d+1)] as it was before step d
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    let mut found = false;
+    for d in 0..=(max.min(MAX_EDIT_DISTANCE) as isize) {
+        trace.push(v[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+        let mut k = -d;
+        while k <= d {
+            let idx = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                found = true;
+                break;
+            }
+            k += 2;
+        }
+        if found {
+            break;
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, snapshot) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| snapshot[(k + d + 1) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) { k + 1 } else { k - 1 };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            ops.push(Op::Equal);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            ops.push(if x == prev_x { Op::Insert } else { Op::Delete });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    Some(ops)
+}
+
+/// Edit script for the whole texts, with common prefix and suffix trimmed
+/// before running Myers on what's left
+fn edit_script(a: &[u32], b: &[u32]) -> (Vec<Op>, bool) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut ops = vec![Op::Equal; prefix];
+    let approximate = match myers(a_mid, b_mid) {
+        Some(middle) => {
+            ops.extend(middle);
+            false
+        }
+        None => {
+            ops.extend(std::iter::repeat_n(Op::Delete, a_mid.len()));
+            ops.extend(std::iter::repeat_n(Op::Insert, b_mid.len()));
+            true
+        }
+    };
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+    (ops, approximate)
+}
+
+/// Computes a line diff of two texts
+///
+/// # Arguments
+/// * `old` - Original text
+/// * `new` - Changed text
+/// * `context` - Unchanged lines kept around each change; changes closer
+///   than twice this share a hunk
+pub fn diff_lines(old: &str, new: &str, context: usize) -> TextDiff {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (a, b) = intern_lines(&old_lines, &new_lines);
+    let (ops, approximate) = edit_script(&a, &b);
+
+    // Position in both texts before each op
+    let mut positions = Vec::with_capacity(ops.len());
+    let (mut i, mut j) = (0, 0);
+    for op in &ops {
+        positions.push((i, j));
+        match op {
+            Op::Equal => {
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+
+    let mut diff = TextDiff {
+        hunks: Vec::new(),
+        added: ops.iter().filter(|op| **op == Op::Insert).count(),
+        removed: ops.iter().filter(|op| **op == Op::Delete).count(),
+        old_line_count: old_lines.len(),
+        new_line_count: new_lines.len(),
+        approximate,
+    };
+
+    let mut index = 0;
+    while index < ops.len() {
+        if ops[index] == Op::Equal {
+            index += 1;
+            continue;
+        }
+
+        // Extend the hunk while the next change is within 2 * context lines
+        let start = index.saturating_sub(context);
+        let mut last_change = index;
+        let mut scan = index;
+        while scan < ops.len() {
+            if ops[scan] != Op::Equal {
+                last_change = scan;
+            } else if scan - last_change > 2 * context {
+                break;
+            }
+            scan += 1;
+        }
+        let end = (last_change + context + 1).min(ops.len());
+
+        let lines: Vec<DiffLine> = (start..end)
+            .map(|op_index| {
+                let (i, j) = positions[op_index];
+                match ops[op_index] {
+                    Op::Equal => DiffLine {
+                        kind: DiffLineKind::Context,
+                        old_line: Some(i + 1),
+                        new_line: Some(j + 1),
+                        text: old_lines[i].to_string(),
+                    },
+                    Op::Delete => DiffLine {
+                        kind: DiffLineKind::Removed,
+                        old_line: Some(i + 1),
+                        new_line: None,
+                        text: old_lines[i].to_string(),
+                    },
+                    Op::Insert => DiffLine {
+                        kind: DiffLineKind::Added,
+                        old_line: None,
+                        new_line: Some(j + 1),
+                        text: new_lines[j].to_string(),
+                    },
+                }
+            })
+            .collect();
+
+        let (old_start, new_start) = positions[start];
+        diff.hunks.push(DiffHunk {
+            old_start: old_start + 1,
+            old_count: lines.iter().filter(|l| l.kind != DiffLineKind::Added).count(),
+            new_start: new_start + 1,
+            new_count: lines.iter().filter(|l| l.kind != DiffLineKind::Removed).count(),
+            lines,
+        });
+        index = end;
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(diff: &TextDiff) -> Vec<String> {
+        diff.hunks
+            .iter()
+            .flat_map(|h| h.lines.iter())
+            .map(|l| {
+                let sign = match l.kind {
+                    DiffLineKind::Context => ' ',
+                    DiffLineKind::Added => '+',
+                    DiffLineKind::Removed => '-',
+                };
+                format!("{}{}", sign, l.text)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_texts_have_no_hunks() {
+        let diff = diff_lines("a\nb\nc\n", "a\nb\nc\n", DEFAULT_CONTEXT);
+        assert!(diff.hunks.is_empty());
+        assert_eq!((diff.added, diff.removed), (0, 0));
+    }
+
+    #[test]
+    fn test_single_change_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        let diff = diff_lines(old, new, 2);
+
+        assert_eq!(diff.hunks.len(), 1);
+        let hunk = &diff.hunks[0];
+        assert_eq!((hunk.old_start, hunk.old_count, hunk.new_start, hunk.new_count), (3, 5, 3, 5));
+        assert_eq!(render(&diff), vec![" 3", " 4", "-5", "+five", " 6", " 7"]);
+        assert_eq!(hunk.lines[2].old_line, Some(5));
+        assert_eq!(hunk.lines[3].new_line, Some(5));
+    }
+
+    #[test]
+    fn test_distant_changes_split_into_hunks() {
+        let old: String = (1..=20).map(|i| format!("{}\n", i)).collect();
+        let new = old.replacen("2\n", "two\n", 1).replace("19\n", "");
+        let diff = diff_lines(&old, &new, 1);
+
+        assert_eq!(diff.hunks.len(), 2);
+        assert_eq!(render(&diff), vec![" 1", "-2", "+two", " 3", " 18", "-19", " 20"]);
+        assert_eq!((diff.added, diff.removed), (1, 2));
+        assert_eq!(diff.hunks[1].new_start, 18);
+    }
+
+    #[test]
+    fn test_insert_and_delete_at_edges() {
+        let diff = diff_lines("b\nc\n", "a\nb\n", 0);
+        assert_eq!(render(&diff), vec!["+a", "-c"]);
+        assert!(!diff.approximate);
+
+        let diff = diff_lines("", "x\n", DEFAULT_CONTEXT);
+        assert_eq!(diff.hunks[0].old_start, 1);
+        assert_eq!(render(&diff), vec!["+x"]);
+    }
+
+    #[test]
+    fn test_unrelated_texts_fall_back_to_block_replace() {
+        let old: String = (0..1001).map(|i| format!("old {}\n", i)).collect();
+        let new: String = (0..1001).map(|i| format!("new {}\n", i)).collect();
+        let diff = diff_lines(&format!("same\n{}same\n", old), &format!("same\n{}same\n", new), 1);
+
+        assert!(diff.approximate);
+        assert_eq!((diff.added, diff.removed), (1001, 1001));
+        assert_eq!(diff.hunks.len(), 1);
+        assert_eq!(diff.hunks[0].lines[1].text, "old 0");
+        assert_eq!(diff.hunks[0].lines[1002].text, "new 0");
+    }
+}
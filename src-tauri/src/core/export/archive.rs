@@ -0,0 +1,218 @@
+//! Zip writing with progress and cancellation
+//!
+//! Packages can hold thousands of files and several hundred megabytes, so
+//! packing reports after every entry and copies file contents in chunks,
+//! checking the cancel token between them. The caller plans every entry up
+//! front, which gives the progress events a fixed total.
+
+use crate::error::{Error, Result};
+use crate::messages::Message;
+use crate::state::CancelToken;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Bytes copied between cancellation checks
+const COPY_CHUNK: usize = 1 << 20;
+
+/// Where the contents of a zip entry come from
+pub enum EntrySource {
+    File(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+/// A file to write into the archive
+pub struct ZipEntry {
+    /// Path inside the archive, with `/` separators
+    pub name: String,
+    pub source: EntrySource,
+}
+
+impl ZipEntry {
+    pub fn file(name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self { name: name.into(), source: EntrySource::File(path.into()) }
+    }
+
+    pub fn bytes(name: impl Into<String>, data: Vec<u8>) -> Self {
+        Self { name: name.into(), source: EntrySource::Bytes(data) }
+    }
+
+    fn size(&self) -> u64 {
+        match &self.source {
+            EntrySource::File(path) => path.metadata().map(|m| m.len()).unwrap_or(0),
+            EntrySource::Bytes(data) => data.len() as u64,
+        }
+    }
+}
+
+/// A progress update sent after each entry is written
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PackProgress {
+    pub current: usize,
+    pub total: usize,
+    #[ts(type = "number")]
+    pub bytes_written: u64,
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+    /// Entry that was just written
+    pub item: String,
+}
+
+impl PackProgress {
+    /// Status line for progress events
+    pub fn message(&self) -> Message {
+        Message::new(
+            "progress.export.packing",
+            [("current", self.current.to_string()), ("total", self.total.to_string())],
+        )
+    }
+
+    /// Completed fraction, by size since a few large files dominate most mods
+    pub fn fraction(&self) -> f64 {
+        if self.total_bytes > 0 {
+            self.bytes_written as f64 / self.total_bytes as f64
+        } else if self.total > 0 {
+            self.current as f64 / self.total as f64
+        } else {
+            1.0
+        }
+    }
+}
+
+fn zip_error(e: zip::result::ZipError) -> Error {
+    Error::Io { source: std::io::Error::other(e), path: None }
+}
+
+/// Writes `entries` as a deflated zip archive
+///
+/// # Arguments
+/// * `writer` - Destination of the archive
+/// * `entries` - Files in the order they are written
+/// * `cancel` - Checked between entries and between chunks of large files
+/// * `on_progress` - Called after every entry
+///
+/// # Returns
+/// The writer, once the archive is finished. A cancelled pack returns
+/// [`Error::Cancelled`] and leaves a truncated archive behind.
+pub fn write_zip<W: Write + Seek>(
+    writer: W,
+    entries: &[ZipEntry],
+    cancel: &CancelToken,
+    on_progress: Option<&dyn Fn(PackProgress)>,
+) -> Result<W> {
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .unix_permissions(0o755);
+    let mut zip = ZipWriter::new(writer);
+    let mut progress = PackProgress {
+        current: 0,
+        total: entries.len(),
+        bytes_written: 0,
+        total_bytes: entries.iter().map(ZipEntry::size).sum(),
+        item: String::new(),
+    };
+    let mut buffer = vec![0; COPY_CHUNK];
+
+    for entry in entries {
+        if cancel.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        zip.start_file(entry.name.as_str(), options).map_err(zip_error)?;
+
+        match &entry.source {
+            EntrySource::File(path) => {
+                let mut file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+                loop {
+                    let read = file.read(&mut buffer).map_err(|e| Error::io_with_path(e, path))?;
+                    if read == 0 {
+                        break;
+                    }
+                    zip.write_all(&buffer[..read])?;
+                    progress.bytes_written += read as u64;
+                    if cancel.is_cancelled() {
+                        return Err(Error::Cancelled);
+                    }
+                }
+            }
+            EntrySource::Bytes(data) => {
+                zip.write_all(data)?;
+                progress.bytes_written += data.len() as u64;
+            }
+        }
+
+        progress.current += 1;
+        progress.item.clone_from(&entry.name);
+        if let Some(on_progress) = on_progress {
+            on_progress(progress.clone());
+        }
+    }
+
+    zip.finish().map_err(zip_error)
+}
+
+/// Zip entries for every file under `dir`, named `prefix/<relative path>`
+///
+/// Entries are sorted by name so packages come out the same on every run.
+pub fn directory_entries(dir: &Path, prefix: &str) -> Result<Vec<ZipEntry>> {
+    let mut entries = Vec::new();
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry.map_err(|e| Error::InvalidInput(format!("Failed to walk '{}': {}", dir.display(), e)))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+        let name = format!("{}/{}", prefix, relative.to_string_lossy().replace('\\', "/"));
+        entries.push(ZipEntry::file(name, entry.path()));
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::io::Cursor;
+    use zip::ZipArchive;
+
+    #[test]
+    fn test_write_zip_reports_and_cancels() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("data/sub")).unwrap();
+        std::fs::write(dir.path().join("data/a.bin"), vec![1; 3 * COPY_CHUNK / 2]).unwrap();
+        std::fs::write(dir.path().join("data/sub/b.bin"), b"bee").unwrap();
+
+        let mut entries = directory_entries(&dir.path().join("data"), "WAD").unwrap();
+        entries.push(ZipEntry::bytes("META/info.json", b"{}".to_vec()));
+
+        let updates = RefCell::new(Vec::new());
+        let on_progress = |p: PackProgress| updates.borrow_mut().push(p);
+        let cursor = write_zip(Cursor::new(Vec::new()), &entries, &CancelToken::default(), Some(&on_progress)).unwrap();
+
+        let updates = updates.into_inner();
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[0].item, "WAD/a.bin");
+        assert_eq!(updates[2].total_bytes, (3 * COPY_CHUNK / 2 + 5) as u64);
+        assert_eq!(updates[2].fraction(), 1.0);
+
+        let mut archive = ZipArchive::new(Cursor::new(cursor.into_inner())).unwrap();
+        let mut b = String::new();
+        archive.by_name("WAD/sub/b.bin").unwrap().read_to_string(&mut b).unwrap();
+        assert_eq!(b, "bee");
+        assert_eq!(archive.by_name("WAD/a.bin").unwrap().size(), (3 * COPY_CHUNK / 2) as u64);
+
+        // Cancelling from the progress callback stops before the next entry
+        let requests = crate::state::RequestState::default();
+        let request = requests.begin(Some("pack".to_string()));
+        let cancel_after_first = |_: PackProgress| {
+            requests.cancel("pack");
+        };
+        let result = write_zip(Cursor::new(Vec::new()), &entries, &request.token(), Some(&cancel_after_first));
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+}
@@ -0,0 +1,295 @@
+//! Chunk overlaps between a project and other mods
+//!
+//! A mod manager overlays every enabled mod onto the game's WADs; when two
+//! mods ship the same chunk of the same WAD, only one of them wins. This
+//! compares what the project would export with other packages (.fantome,
+//! .modpkg, or unpacked mod folders such as cslol-manager's `installed/`)
+//! so creators can see which files will fight before publishing.
+//!
+//! Chunks are compared by WAD name and the path hash the packer would use,
+//! so files still named by their hash match the same as named ones.
+
+use crate::core::export::layers::layer_files;
+use crate::core::export::verify::{read_fantome, read_modpkg};
+use crate::core::project::Project;
+use crate::core::wad::extractor::strip_ltk_suffix;
+use crate::core::wad::packer::chunk_hash;
+use crate::error::{Error, Result};
+use league_toolkit::wad::Wad;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read, Seek};
+use std::path::Path;
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// One mod compared against the project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CheckedMod {
+    /// File or folder name of the mod
+    pub name: String,
+    pub path: String,
+    /// Chunks the mod ships
+    pub chunk_count: usize,
+    /// Of those, chunks the project also ships
+    pub conflict_count: usize,
+    /// Why the mod couldn't be read
+    pub error: Option<String>,
+}
+
+/// A project file other mods also replace
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ChunkConflict {
+    /// WAD the file belongs to (e.g. "ahri.wad.client")
+    pub wad: String,
+    /// Game path of the file, or its hex path hash if no side knows the name
+    pub path: String,
+    /// Names of the mods shipping the same chunk
+    pub mods: Vec<String>,
+}
+
+/// Result of checking a project against other mods
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ConflictReport {
+    /// Chunks the project's export would contain
+    pub project_chunks: usize,
+    pub mods: Vec<CheckedMod>,
+    /// Overlapping files, sorted by WAD and path
+    pub conflicts: Vec<ChunkConflict>,
+}
+
+/// Chunks keyed by lowercase WAD name and path hash, with the path if known
+#[derive(Default)]
+struct ChunkSet(HashMap<(String, u64), Option<String>>);
+
+impl ChunkSet {
+    /// Adds a file given as `{wad}/{path}`
+    fn add_file(&mut self, wad_relative: &str) {
+        let wad_relative = wad_relative.replace('\\', "/").to_lowercase();
+        let Some((wad, path)) = wad_relative.split_once('/') else {
+            return;
+        };
+        let path_hash = chunk_hash(path);
+        // Hash-named files don't tell their path
+        let game_path = strip_ltk_suffix(path);
+        let name = (xxhash_rust::xxh64::xxh64(game_path.as_bytes(), 0) == path_hash).then(|| game_path.to_string());
+        self.0.insert((wad.to_string(), path_hash), name);
+    }
+
+    /// Adds every chunk of a packed WAD
+    fn add_packed_wad<R: Read + Seek>(&mut self, wad_name: &str, reader: R) -> Result<()> {
+        let wad = Wad::mount(reader)
+            .map_err(|e| Error::InvalidInput(format!("Failed to mount {}: {}", wad_name, e)))?;
+        let wad_name = wad_name.to_lowercase();
+        for path_hash in wad.chunks().keys() {
+            self.0.entry((wad_name.clone(), *path_hash)).or_insert(None);
+        }
+        Ok(())
+    }
+}
+
+fn is_packed_wad(name: &str) -> bool {
+    name.to_lowercase().ends_with(".wad.client")
+}
+
+/// Reads a .fantome or .modpkg; packed WADs in it are read chunk by chunk
+fn read_package(path: &Path, is_modpkg: bool) -> Result<ChunkSet> {
+    let is_wad = |name: &str| !name.contains('/') && is_packed_wad(name);
+    let contents = if is_modpkg { read_modpkg(path, is_wad)? } else { read_fantome(path, is_wad)? };
+
+    let mut chunks = ChunkSet::default();
+    for name in contents.sizes.keys() {
+        match contents.data.get(name) {
+            Some(data) => chunks.add_packed_wad(name, Cursor::new(data))?,
+            None => chunks.add_file(name),
+        }
+    }
+    Ok(chunks)
+}
+
+/// Reads an unpacked mod: a folder holding `WAD/` with WAD folders or packed WADs
+fn read_mod_folder(path: &Path) -> Result<ChunkSet> {
+    let wad_dir = path.join("WAD");
+    let mut chunks = ChunkSet::default();
+    for entry in WalkDir::new(&wad_dir).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(&wad_dir) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if !relative.contains('/') && is_packed_wad(&relative) {
+            let file = File::open(entry.path()).map_err(|e| Error::io_with_path(e, entry.path()))?;
+            chunks.add_packed_wad(&relative, BufReader::new(file))?;
+        } else {
+            chunks.add_file(&relative);
+        }
+    }
+    Ok(chunks)
+}
+
+/// Expands the given paths into the mods they hold
+///
+/// A folder with a `WAD/` subfolder is one mod; any other folder (such as
+/// cslol-manager's `installed/`) contributes each such subfolder.
+fn collect_mods(paths: &[impl AsRef<Path>]) -> Vec<std::path::PathBuf> {
+    let mut mods = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        if !path.is_dir() || path.join("WAD").is_dir() {
+            mods.push(path.to_path_buf());
+            continue;
+        }
+        let Ok(entries) = std::fs::read_dir(path) else {
+            mods.push(path.to_path_buf());
+            continue;
+        };
+        let mut folders: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.join("WAD").is_dir())
+            .collect();
+        folders.sort();
+        mods.extend(folders);
+    }
+    mods
+}
+
+fn read_mod(path: &Path) -> Result<ChunkSet> {
+    if path.is_dir() {
+        if !path.join("WAD").is_dir() {
+            return Err(Error::InvalidInput(format!("No WAD folder in {}", path.display())));
+        }
+        return read_mod_folder(path);
+    }
+    let is_modpkg = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("modpkg"));
+    read_package(path, is_modpkg)
+}
+
+/// Chunks the project's enabled layers would export
+fn project_chunks(project: &Project) -> ChunkSet {
+    let mut chunks = ChunkSet::default();
+    for layer in project.enabled_layers() {
+        for file in layer_files(&project.project_path, &layer.name) {
+            chunks.add_file(&file);
+        }
+    }
+    chunks
+}
+
+/// Checks which of the project's files other mods also replace
+///
+/// # Arguments
+/// * `project` - The project whose export is compared
+/// * `mod_paths` - .fantome or .modpkg files, mod folders, or folders of mod folders
+///
+/// # Returns
+/// A report with one entry per mod; mods that can't be read carry an error
+/// instead of failing the check.
+pub fn check_conflicts(project: &Project, mod_paths: &[impl AsRef<Path>]) -> ConflictReport {
+    let project = project_chunks(project);
+    let mut report = ConflictReport { project_chunks: project.0.len(), ..Default::default() };
+    let mut conflicts: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+
+    for path in collect_mods(mod_paths) {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        let mut checked = CheckedMod {
+            name: name.clone(),
+            path: path.display().to_string(),
+            chunk_count: 0,
+            conflict_count: 0,
+            error: None,
+        };
+
+        match read_mod(&path) {
+            Ok(chunks) => {
+                checked.chunk_count = chunks.0.len();
+                for (key, other_name) in &chunks.0 {
+                    let Some(project_name) = project.0.get(key) else {
+                        continue;
+                    };
+                    checked.conflict_count += 1;
+                    let label = project_name
+                        .clone()
+                        .or_else(|| other_name.clone())
+                        .unwrap_or_else(|| format!("{:016x}", key.1));
+                    conflicts.entry((key.0.clone(), label)).or_default().push(name.clone());
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Skipping unreadable mod {}: {}", path.display(), e);
+                checked.error = Some(e.to_string());
+            }
+        }
+        report.mods.push(checked);
+    }
+
+    report.conflicts = conflicts
+        .into_iter()
+        .map(|((wad, path), mods)| ChunkConflict { wad, path, mods })
+        .collect();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::export::test_util::write_fantome;
+    use crate::core::wad::test_util::wad_bytes;
+
+    fn write_file(path: &Path, data: &[u8]) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, data).unwrap();
+    }
+
+    #[test]
+    fn test_check_conflicts() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = Project::new("Test", "Ahri", 0, dir.path().join("league"), dir.path().join("project"), None);
+        let wad = project.assets_path().join("ahri.wad.client");
+        write_file(&wad.join("assets/characters/ahri/skins/base/ahri.dds"), b"DDS ");
+        write_file(&wad.join("data/characters/ahri/skins/skin0.bin"), b"PROP");
+        write_file(&wad.join("assets/mine/only.dds"), b"DDS ");
+        let hashed = format!("{:016x}.dds", xxhash_rust::xxh64::xxh64(b"assets/characters/ahri/hud/ahri_circle.dds", 0));
+        write_file(&wad.join(&hashed), b"DDS ");
+
+        // Same path in another WAD doesn't conflict
+        let fantome = dir.path().join("other.fantome");
+        write_fantome(&fantome, &[
+            ("META/info.json", b"{}"),
+            ("WAD/Ahri.wad.client/ASSETS/Characters/Ahri/Skins/Base/ahri.dds", b"DDS "),
+            ("WAD/map11.wad.client/assets/mine/only.dds", b"DDS "),
+        ]);
+
+        let installed = dir.path().join("installed");
+        write_file(&installed.join("packed/META/info.json"), b"{}");
+        write_file(
+            &installed.join("packed/WAD/Ahri.wad.client"),
+            &wad_bytes(&[("data/characters/ahri/skins/skin0.bin", b"DDS "), ("assets/characters/ahri/hud/ahri_circle.dds", b"DDS ")]),
+        );
+        write_file(&installed.join("broken/WAD/Ahri.wad.client"), b"not a wad");
+
+        let report = check_conflicts(&project, &[fantome, installed]);
+        assert_eq!(report.project_chunks, 4);
+        let summary: Vec<_> = report.mods.iter().map(|m| (m.name.as_str(), m.conflict_count, m.error.is_some())).collect();
+        assert_eq!(summary, vec![("other.fantome", 1, false), ("broken", 0, true), ("packed", 2, false)]);
+
+        let conflicts: Vec<_> = report.conflicts.iter().map(|c| (c.wad.as_str(), c.path.as_str(), c.mods.clone())).collect();
+        let mut expected = vec![
+            ("ahri.wad.client", "assets/characters/ahri/skins/base/ahri.dds", vec!["other.fantome".to_string()]),
+            ("ahri.wad.client", "data/characters/ahri/skins/skin0.bin", vec!["packed".to_string()]),
+            // Neither side knows the name of the hash-named file
+            ("ahri.wad.client", hashed.trim_end_matches(".dds"), vec!["packed".to_string()]),
+        ];
+        expected.sort();
+        assert_eq!(conflicts, expected);
+    }
+}
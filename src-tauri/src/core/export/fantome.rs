@@ -0,0 +1,150 @@
+//! Fantome packaging
+//!
+//! Writes the same layout as `ltk_fantome::pack_to_fantome` (every
+//! `.wad.client` folder of the base layer under `WAD/`, metadata under
+//! `META/`), but through [`write_zip`] so large mods report progress and
+//! can be cancelled.
+
+use crate::core::export::archive::{directory_entries, write_zip, PackProgress, ZipEntry};
+use crate::core::export::preview::{load_thumbnail, PreviewFormat};
+use crate::error::{Error, Result};
+use crate::state::CancelToken;
+use ltk_fantome::FantomeInfo;
+use ltk_mod_project::{ModProject, ModProjectAuthor};
+use std::io::{Seek, Write};
+use std::path::Path;
+
+fn format_authors(authors: &[ModProjectAuthor]) -> String {
+    if authors.is_empty() {
+        return "Unknown".to_string();
+    }
+    authors
+        .iter()
+        .map(|author| match author {
+            ModProjectAuthor::Name(name) => name.as_str(),
+            ModProjectAuthor::Role { name, .. } => name.as_str(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Plans the entries of a fantome package
+///
+/// `META/info.json`, the README and the thumbnail (converted to PNG) follow
+/// the WAD folders, as in packages built by ltk_fantome.
+pub fn fantome_entries(mod_project: &ModProject, project_root: &Path) -> Result<Vec<ZipEntry>> {
+    let base = project_root.join("content").join("base");
+    if !base.is_dir() {
+        return Err(Error::InvalidInput(format!("Base layer directory does not exist: {}", base.display())));
+    }
+
+    let mut wad_dirs: Vec<_> = std::fs::read_dir(&base)
+        .map_err(|e| Error::io_with_path(e, &base))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && path.to_string_lossy().ends_with(".wad.client"))
+        .collect();
+    wad_dirs.sort();
+
+    let mut entries = Vec::new();
+    for wad_dir in &wad_dirs {
+        let wad_name = wad_dir.file_name().unwrap_or_default().to_string_lossy();
+        entries.extend(directory_entries(wad_dir, &format!("WAD/{}", wad_name))?);
+    }
+
+    let info = FantomeInfo {
+        name: mod_project.display_name.clone(),
+        author: format_authors(&mod_project.authors),
+        version: mod_project.version.clone(),
+        description: mod_project.description.clone(),
+    };
+    let info = serde_json::to_vec_pretty(&info).map_err(|e| Error::InvalidInput(e.to_string()))?;
+    entries.push(ZipEntry::bytes("META/info.json", info));
+
+    let readme = project_root.join("README.md");
+    if readme.is_file() {
+        entries.push(ZipEntry::file("META/README.md", readme));
+    }
+
+    if let Some(thumbnail) = &mod_project.thumbnail {
+        let thumbnail = project_root.join(thumbnail);
+        if thumbnail.is_file() {
+            entries.push(ZipEntry::bytes("META/image.png", load_thumbnail(&thumbnail, PreviewFormat::Png)?));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Packs a mod project as a .fantome
+///
+/// # Arguments
+/// * `writer` - Destination of the package
+/// * `mod_project` - Metadata for `META/info.json` and the thumbnail
+/// * `project_root` - Folder with `content/base`
+/// * `cancel` - Stops packing with [`Error::Cancelled`]
+/// * `on_progress` - Called after every file written
+///
+/// # Returns
+/// The number of WAD files packed
+pub fn pack_fantome<W: Write + Seek>(
+    writer: W,
+    mod_project: &ModProject,
+    project_root: &Path,
+    cancel: &CancelToken,
+    on_progress: Option<&dyn Fn(PackProgress)>,
+) -> Result<usize> {
+    let entries = fantome_entries(mod_project, project_root)?;
+    write_zip(writer, &entries, cancel, on_progress)?.flush()?;
+    Ok(entries.iter().filter(|entry| entry.name.starts_with("WAD/")).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::{Cursor, Read};
+    use zip::ZipArchive;
+
+    #[test]
+    fn test_pack_fantome_matches_ltk_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let wad = root.join("content/base/Ahri.wad.client");
+        fs::create_dir_all(wad.join("assets/characters/ahri")).unwrap();
+        fs::write(wad.join("assets/characters/ahri/ahri.skn"), b"mesh").unwrap();
+        fs::write(wad.join("data.bin"), b"bin").unwrap();
+        fs::create_dir_all(root.join("content/base/notes")).unwrap();
+        fs::write(root.join("content/base/notes/todo.txt"), b"skip").unwrap();
+        fs::write(root.join("README.md"), b"# Readme").unwrap();
+
+        let mod_project = ModProject {
+            name: "ahri-mod".to_string(),
+            display_name: "Ahri Mod".to_string(),
+            version: "1.0.0".to_string(),
+            description: "Test".to_string(),
+            authors: vec![ModProjectAuthor::Name("Someone".to_string())],
+            license: None,
+            transformers: vec![],
+            layers: ltk_mod_project::default_layers(),
+            thumbnail: None,
+        };
+
+        let mut ours = Cursor::new(Vec::new());
+        let count = pack_fantome(&mut ours, &mod_project, root, &CancelToken::default(), None).unwrap();
+        assert_eq!(count, 2);
+
+        let mut theirs = Cursor::new(Vec::new());
+        ltk_fantome::pack_to_fantome(&mut theirs, &mod_project, root).unwrap();
+
+        let names = |data: Vec<u8>| {
+            let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+            let mut names: Vec<String> = archive.file_names().map(String::from).collect();
+            names.sort();
+            let mut info = String::new();
+            archive.by_name("META/info.json").unwrap().read_to_string(&mut info).unwrap();
+            (names, info)
+        };
+        assert_eq!(names(ours.into_inner()), names(theirs.into_inner()));
+    }
+}
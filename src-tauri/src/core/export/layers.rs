@@ -0,0 +1,324 @@
+//! Layer handling for exports
+//!
+//! `.modpkg` keeps layers as they are, but a `.fantome` package has no layer
+//! concept: ltk_fantome only packs `content/base`. To ship chroma or variant
+//! layers as a fantome, the enabled layers are flattened into a staged copy
+//! of the project where higher-priority layers overwrite lower ones.
+//!
+//! The export preview lists what either package would contain, with the
+//! size each file takes once packed into its WAD.
+
+use crate::core::wad::packer::{chunk_hash, compress};
+use crate::error::{Error, Result};
+use league_toolkit::file::LeagueFileKind;
+use ltk_mod_project::ModProjectLayer;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Folder (inside the project) where flattened fantome exports are staged
+pub const FLATTEN_STAGE_DIR: &str = ".flint/fantome_stage";
+
+/// Lists the files of one layer, relative to its content folder
+///
+/// Paths use `/` separators. A layer without a folder has no files.
+pub fn layer_files(project_path: &Path, layer: &str) -> Vec<String> {
+    let layer_dir = project_path.join("content").join(layer);
+    WalkDir::new(&layer_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(&layer_dir)
+                .ok()
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+        })
+        .collect()
+}
+
+/// Merges `layers` into `stage_root/content/base`
+///
+/// `layers` must be sorted lowest priority first (as returned by
+/// `Project::enabled_layers`); a file present in several layers ends up
+/// with the copy from the last one. The project's README.md is staged too,
+/// since ltk_fantome reads it from the project root. Any previous staging at
+/// `stage_root` is removed first.
+///
+/// # Returns
+/// The number of distinct files in the flattened base layer
+pub fn flatten_layers(
+    project_path: &Path,
+    layers: &[ModProjectLayer],
+    stage_root: &Path,
+) -> Result<usize> {
+    if stage_root.exists() {
+        fs::remove_dir_all(stage_root).map_err(|e| Error::io_with_path(e, stage_root))?;
+    }
+    let staged_base = stage_root.join("content").join("base");
+    fs::create_dir_all(&staged_base).map_err(|e| Error::io_with_path(e, &staged_base))?;
+
+    for layer in layers {
+        let layer_dir = project_path.join("content").join(&layer.name);
+        for relative in layer_files(project_path, &layer.name) {
+            stage_file(&layer_dir.join(&relative), &staged_base.join(&relative))?;
+        }
+    }
+
+    let readme = project_path.join("README.md");
+    if readme.exists() {
+        stage_file(&readme, &stage_root.join("README.md"))?;
+    }
+
+    Ok(WalkDir::new(&staged_base)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .count())
+}
+
+/// Returns the staging folder for a project's flattened fantome export
+pub fn flatten_stage_path(project_path: &Path) -> PathBuf {
+    project_path.join(FLATTEN_STAGE_DIR)
+}
+
+/// Places `source` at `dest`, replacing a file staged from a lower layer
+///
+/// Staged files are only read by the packer, so a hardlink is enough; it falls
+/// back to a copy across drives.
+fn stage_file(source: &Path, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+    if dest.exists() {
+        fs::remove_file(dest).map_err(|e| Error::io_with_path(e, dest))?;
+    }
+    if fs::hard_link(source, dest).is_err() {
+        fs::copy(source, dest).map_err(|e| Error::io_with_path(e, source))?;
+    }
+    Ok(())
+}
+
+/// A file an export would contain
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExportPreviewFile {
+    /// Path relative to `content/base`, prefixed with the layer name for other layers
+    pub path: String,
+    pub layer: String,
+    /// WAD folder the file is packed into (e.g. "ahri.wad.client"), None outside one
+    pub wad: Option<String>,
+    /// Hex path hash of the file's chunk in that WAD
+    pub chunk_hash: Option<String>,
+    /// Kind detected from the file's contents, or its extension
+    #[ts(type = "string")]
+    pub kind: LeagueFileKind,
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Size once compressed the way the WAD packer does
+    #[ts(type = "number")]
+    pub compressed_size: u64,
+    /// A higher-priority layer ships the same file, so this copy isn't seen in game
+    pub overridden: bool,
+}
+
+/// Totals for one WAD of an export
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExportPreviewWad {
+    pub name: String,
+    pub chunk_count: usize,
+    #[ts(type = "number")]
+    pub size: u64,
+    #[ts(type = "number")]
+    pub compressed_size: u64,
+}
+
+/// Everything an export would contain
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExportPreview {
+    /// Files of every enabled layer, lowest priority first
+    pub files: Vec<ExportPreviewFile>,
+    /// Per-WAD totals of the files that aren't overridden
+    pub wads: Vec<ExportPreviewWad>,
+    /// Uncompressed size of the files that aren't overridden
+    #[ts(type = "number")]
+    pub total_size: u64,
+    /// Estimated package size: the compressed files that aren't overridden
+    #[ts(type = "number")]
+    pub compressed_size: u64,
+}
+
+fn preview_file(layer_dir: &Path, layer: &str, relative: String) -> Result<ExportPreviewFile> {
+    let source = layer_dir.join(&relative);
+    let data = fs::read(&source).map_err(|e| Error::io_with_path(e, &source))?;
+    let (compressed, _) = compress(&data)?;
+
+    let kind = match LeagueFileKind::identify_from_bytes(&data) {
+        LeagueFileKind::Unknown => {
+            let extension = relative.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+            LeagueFileKind::from_extension(extension.to_ascii_lowercase())
+        }
+        kind => kind,
+    };
+    let (wad, chunk_hash) = match relative.split_once('/') {
+        Some((wad, inner)) if wad.to_lowercase().ends_with(".wad.client") => {
+            (Some(wad.to_lowercase()), Some(format!("{:016x}", chunk_hash(inner))))
+        }
+        _ => (None, None),
+    };
+
+    Ok(ExportPreviewFile {
+        path: if layer == "base" { relative } else { format!("{}/{}", layer, relative) },
+        layer: layer.to_string(),
+        wad,
+        chunk_hash,
+        kind,
+        size: data.len() as u64,
+        compressed_size: compressed.len() as u64,
+        overridden: false,
+    })
+}
+
+/// Lists what exporting `layers` would pack, with sizes and WAD layout
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory
+/// * `layers` - Enabled layers, lowest priority first (as returned by
+///   `Project::enabled_layers`)
+pub fn preview_export(project_path: &Path, layers: &[ModProjectLayer]) -> Result<ExportPreview> {
+    let mut files = Vec::new();
+    for layer in layers {
+        let layer_dir = project_path.join("content").join(&layer.name);
+        let mut relatives = layer_files(project_path, &layer.name);
+        relatives.sort();
+        let layer_preview: Result<Vec<_>> = relatives
+            .into_par_iter()
+            .map(|relative| preview_file(&layer_dir, &layer.name, relative))
+            .collect();
+        files.extend(layer_preview?);
+    }
+
+    // Later (higher-priority) layers win, as when flattening
+    let mut latest: HashMap<(Option<String>, String), usize> = HashMap::new();
+    let mut overridden = Vec::new();
+    for (index, file) in files.iter().enumerate() {
+        let key = match (&file.wad, &file.chunk_hash) {
+            (Some(wad), Some(hash)) => (Some(wad.clone()), hash.clone()),
+            _ => (None, file.path.strip_prefix(&format!("{}/", file.layer)).unwrap_or(&file.path).to_string()),
+        };
+        overridden.extend(latest.insert(key, index));
+    }
+    for index in overridden {
+        files[index].overridden = true;
+    }
+
+    let mut preview = ExportPreview::default();
+    let mut wads: BTreeMap<String, ExportPreviewWad> = BTreeMap::new();
+    for file in files.iter().filter(|f| !f.overridden) {
+        preview.total_size += file.size;
+        preview.compressed_size += file.compressed_size;
+        if let Some(wad) = &file.wad {
+            let entry = wads.entry(wad.clone()).or_insert_with(|| ExportPreviewWad {
+                name: wad.clone(),
+                chunk_count: 0,
+                size: 0,
+                compressed_size: 0,
+            });
+            entry.chunk_count += 1;
+            entry.size += file.size;
+            entry.compressed_size += file.compressed_size;
+        }
+    }
+    preview.files = files;
+    preview.wads = wads.into_values().collect();
+    Ok(preview)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(name: &str, priority: i32) -> ModProjectLayer {
+        ModProjectLayer {
+            name: name.to_string(),
+            priority,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_flatten_layers_prefers_higher_priority() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path();
+        let write = |rel: &str, data: &[u8]| {
+            let path = project.join("content").join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, data).unwrap();
+        };
+        write("base/ahri.wad.client/assets/a.dds", b"base a");
+        write("base/ahri.wad.client/assets/b.dds", b"base b");
+        write("chroma1/ahri.wad.client/assets/a.dds", b"red a");
+        write("chroma1/ahri.wad.client/assets/c.dds", b"red c");
+
+        let stage = flatten_stage_path(project);
+        let count = flatten_layers(project, &[layer("base", 0), layer("chroma1", 10)], &stage).unwrap();
+        assert_eq!(count, 3);
+
+        let staged = stage.join("content/base/ahri.wad.client/assets");
+        assert_eq!(fs::read(staged.join("a.dds")).unwrap(), b"red a");
+        assert_eq!(fs::read(staged.join("b.dds")).unwrap(), b"base b");
+        assert_eq!(fs::read(staged.join("c.dds")).unwrap(), b"red c");
+        // The project's own layers are left alone
+        assert_eq!(fs::read(project.join("content/base/ahri.wad.client/assets/a.dds")).unwrap(), b"base a");
+
+        let mut files = layer_files(project, "chroma1");
+        files.sort();
+        assert_eq!(files, vec!["ahri.wad.client/assets/a.dds", "ahri.wad.client/assets/c.dds"]);
+    }
+
+    #[test]
+    fn test_preview_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path();
+        let write = |rel: &str, data: &[u8]| {
+            let path = project.join("content").join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, data).unwrap();
+        };
+        let texture = [b"DDS ".as_slice(), &[0u8; 1020]].concat();
+        write("base/ahri.wad.client/assets/a.dds", &texture);
+        write("base/ahri.wad.client/data/characters/ahri/skins/skin0.bin", b"PROP");
+        write("base/readme.txt", b"hi");
+        write("chroma1/ahri.wad.client/assets/a.dds", b"DDS red");
+
+        let preview = preview_export(project, &[layer("base", 0), layer("chroma1", 10)]).unwrap();
+        let files: Vec<_> = preview.files.iter().map(|f| (f.path.as_str(), f.kind, f.overridden)).collect();
+        assert_eq!(
+            files,
+            vec![
+                ("ahri.wad.client/assets/a.dds", LeagueFileKind::TextureDds, true),
+                ("ahri.wad.client/data/characters/ahri/skins/skin0.bin", LeagueFileKind::PropertyBin, false),
+                ("readme.txt", LeagueFileKind::Unknown, false),
+                ("chroma1/ahri.wad.client/assets/a.dds", LeagueFileKind::TextureDds, false),
+            ]
+        );
+
+        let texture_file = &preview.files[0];
+        assert_eq!(texture_file.wad.as_deref(), Some("ahri.wad.client"));
+        assert_eq!(texture_file.chunk_hash, Some(format!("{:016x}", chunk_hash("assets/a.dds"))));
+        assert_eq!(texture_file.size, 1024);
+        assert!(texture_file.compressed_size < texture_file.size);
+        assert_eq!(preview.files[2].chunk_hash, None);
+
+        assert_eq!(preview.total_size, 4 + 2 + 7);
+        assert_eq!(preview.wads.len(), 1);
+        assert_eq!(preview.wads[0].chunk_count, 2);
+        assert_eq!(preview.wads[0].size, 4 + 7);
+    }
+}
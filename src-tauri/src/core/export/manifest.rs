@@ -0,0 +1,292 @@
+//! Export manifest (README) generation
+//!
+//! Builds a human-readable manifest from project metadata so creators don't
+//! have to retype champion, skin, version and install instructions for every
+//! release. The manifest is written as the project's README.md (which both
+//! ltk_fantome and the modpkg builder embed) and alongside the exported package.
+
+use crate::core::project::vanilla::VanillaManifest;
+use crate::core::project::Project;
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// First line of every generated README, used to tell it apart from a hand-written one
+const GENERATED_MARKER: &str = "<!-- Generated by Flint -->";
+
+/// Package format the manifest is written for (affects install instructions)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageFormat {
+    Fantome,
+    Modpkg,
+}
+
+impl PackageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            PackageFormat::Fantome => "fantome",
+            PackageFormat::Modpkg => "modpkg",
+        }
+    }
+}
+
+/// Information shown in the exported manifest
+#[derive(Debug, Clone)]
+pub struct ExportManifest {
+    pub display_name: String,
+    pub version: String,
+    pub description: String,
+    pub authors: Vec<String>,
+    pub champion: String,
+    pub skin_id: u32,
+    pub game_version: Option<String>,
+    /// Files modified or added over vanilla, relative to content/base
+    pub files: Vec<String>,
+}
+
+impl ExportManifest {
+    /// Builds a manifest from a project and the files in its base layer
+    ///
+    /// Files still identical to what the vanilla manifest recorded at
+    /// extraction are left out. Projects without a vanilla manifest list
+    /// every file, since there is nothing to compare against.
+    pub fn from_project(project: &Project) -> Result<Self> {
+        let content_base = project.assets_path();
+        if !content_base.exists() {
+            return Err(Error::InvalidInput(format!(
+                "Content directory not found: {}",
+                content_base.display()
+            )));
+        }
+
+        let vanilla: HashMap<String, u64> = VanillaManifest::load(&project.project_path)
+            .map(|manifest| {
+                manifest
+                    .files
+                    .into_values()
+                    .map(|entry| (entry.path.to_lowercase(), entry.content_hash))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let is_vanilla = |path: &Path, relative: &str| {
+            vanilla
+                .get(&relative.to_lowercase())
+                .is_some_and(|hash| fs::read(path).is_ok_and(|data| xxh3_64(&data) == *hash))
+        };
+
+        let mut files: Vec<String> = WalkDir::new(&content_base)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                let relative = e.path().strip_prefix(&content_base).ok()?.to_string_lossy().replace('\\', "/");
+                (!is_vanilla(e.path(), &relative)).then_some(relative)
+            })
+            .collect();
+        files.sort();
+
+        Ok(Self {
+            display_name: project.display_name.clone(),
+            version: project.version.clone(),
+            description: project.description.clone(),
+            authors: project.authors.iter().map(|a| a.name.clone()).collect(),
+            champion: project.champion.clone(),
+            skin_id: project.skin_id,
+            game_version: project.game_version.clone(),
+            files,
+        })
+    }
+
+    /// Renders the manifest as Markdown
+    pub fn to_markdown(&self, format: PackageFormat) -> String {
+        let mut out = String::new();
+        out.push_str(GENERATED_MARKER);
+        out.push('\n');
+        out.push_str(&format!("# {}\n\n", self.display_name));
+
+        if !self.description.is_empty() {
+            out.push_str(&format!("{}\n\n", self.description));
+        }
+
+        out.push_str(&format!("- **Version:** {}\n", self.version));
+        if !self.authors.is_empty() {
+            out.push_str(&format!("- **Author:** {}\n", self.authors.join(", ")));
+        }
+        if !self.champion.is_empty() {
+            let skin = if self.skin_id == 0 {
+                "Base".to_string()
+            } else {
+                format!("Skin {}", self.skin_id)
+            };
+            out.push_str(&format!("- **Champion:** {} ({})\n", self.champion, skin));
+        }
+        if let Some(game_version) = &self.game_version {
+            out.push_str(&format!("- **Made for game version:** {}\n", game_version));
+        }
+
+        out.push_str("\n## Installation\n\n");
+        match format {
+            PackageFormat::Fantome => {
+                out.push_str("1. Open cslol-manager (or another .fantome compatible mod manager).\n");
+                out.push_str("2. Click **Import** and select the `.fantome` file.\n");
+                out.push_str("3. Enable the mod and press **Run**.\n");
+            }
+            PackageFormat::Modpkg => {
+                out.push_str("1. Open a .modpkg compatible mod manager.\n");
+                out.push_str("2. Import the `.modpkg` file.\n");
+                out.push_str("3. Enable the mod and launch the game through the manager.\n");
+            }
+        }
+
+        out.push_str(&format!("\n## Changed files ({})\n\n", self.files.len()));
+        for file in &self.files {
+            out.push_str(&format!("- `{}`\n", file));
+        }
+
+        out
+    }
+}
+
+/// Returns true if the README at `path` was generated by Flint (or doesn't exist)
+fn is_replaceable_readme(path: &Path) -> bool {
+    match fs::read_to_string(path) {
+        Ok(content) => content.starts_with(GENERATED_MARKER),
+        Err(_) => true,
+    }
+}
+
+/// Writes the manifest as the project README.md and next to the package
+///
+/// A hand-written README.md in the project is left untouched (and still
+/// embedded by the packers); only the side-by-side copy is generated then.
+///
+/// # Arguments
+/// * `project` - The project being exported
+/// * `output_path` - Path of the package being exported
+/// * `format` - Package format, used for install instructions
+///
+/// # Returns
+/// * `Result<PathBuf>` - Path of the manifest written next to the package
+pub fn write_export_manifest(
+    project: &Project,
+    output_path: &Path,
+    format: PackageFormat,
+) -> Result<PathBuf> {
+    let manifest = ExportManifest::from_project(project)?;
+    let markdown = manifest.to_markdown(format);
+
+    let readme_path = project.project_path.join("README.md");
+    if is_replaceable_readme(&readme_path) {
+        fs::write(&readme_path, &markdown).map_err(|e| Error::io_with_path(e, &readme_path))?;
+        tracing::info!("Generated project README: {}", readme_path.display());
+    } else {
+        tracing::info!("Keeping hand-written README: {}", readme_path.display());
+    }
+
+    let side_path = sidecar_path(output_path, format);
+    fs::write(&side_path, &markdown).map_err(|e| Error::io_with_path(e, &side_path))?;
+
+    Ok(side_path)
+}
+
+/// Path of the manifest written alongside a package ("MyMod.fantome" -> "MyMod.README.md")
+fn sidecar_path(output_path: &Path, format: PackageFormat) -> PathBuf {
+    let file_name = output_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let suffix = format!(".{}", format.extension());
+    let stem = file_name.strip_suffix(&suffix).unwrap_or(&file_name);
+    output_path.with_file_name(format!("{}.README.md", stem))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::project::vanilla::record_vanilla_manifest;
+    use crate::core::project::{create_target_project, TargetKind};
+    use crate::core::wad::test_util::write_wad;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_manifest_markdown() {
+        let manifest = ExportManifest {
+            display_name: "Star Ahri".to_string(),
+            version: "1.2.0".to_string(),
+            description: "Blue recolor".to_string(),
+            authors: vec!["SirDexal".to_string()],
+            champion: "Ahri".to_string(),
+            skin_id: 3,
+            game_version: Some("14.23.636.7270".to_string()),
+            files: vec!["ahri.wad.client/data/characters/ahri/ahri.bin".to_string()],
+        };
+
+        let md = manifest.to_markdown(PackageFormat::Fantome);
+        assert!(md.starts_with(GENERATED_MARKER));
+        assert!(md.contains("# Star Ahri"));
+        assert!(md.contains("**Champion:** Ahri (Skin 3)"));
+        assert!(md.contains("14.23.636.7270"));
+        assert!(md.contains("## Changed files (1)"));
+        assert!(md.contains(".fantome"));
+    }
+
+    #[test]
+    fn test_sidecar_path() {
+        assert_eq!(
+            sidecar_path(Path::new("out/my-mod_1.0.0.fantome"), PackageFormat::Fantome),
+            PathBuf::from("out/my-mod_1.0.0.README.md")
+        );
+        assert_eq!(
+            sidecar_path(Path::new("out/mod.modpkg"), PackageFormat::Modpkg),
+            PathBuf::from("out/mod.README.md")
+        );
+    }
+
+    #[test]
+    fn test_write_keeps_handwritten_readme() {
+        let dir = tempdir().unwrap();
+        let project = create_target_project("Test", TargetKind::Champion, "Ahri", 0, dir.path(), dir.path(), None).unwrap();
+        fs::write(project.assets_path().join("file.bin"), b"x").unwrap();
+
+        let readme = project.project_path.join("README.md");
+        fs::write(&readme, "# My notes").unwrap();
+
+        let side = write_export_manifest(&project, &dir.path().join("test.fantome"), PackageFormat::Fantome).unwrap();
+        assert_eq!(fs::read_to_string(&readme).unwrap(), "# My notes");
+        assert!(fs::read_to_string(side).unwrap().contains("`file.bin`"));
+
+        // A previously generated README gets refreshed
+        fs::write(&readme, format!("{}\nold", GENERATED_MARKER)).unwrap();
+        write_export_manifest(&project, &dir.path().join("test.fantome"), PackageFormat::Fantome).unwrap();
+        assert!(fs::read_to_string(&readme).unwrap().contains("`file.bin`"));
+    }
+
+    #[test]
+    fn test_manifest_lists_only_changes_over_vanilla() {
+        let dir = tempdir().unwrap();
+        let project = create_target_project("Test", TargetKind::Champion, "Ahri", 0, dir.path(), dir.path(), None).unwrap();
+        let wad_folder = project.assets_path().join("ahri.wad.client");
+
+        let vanilla: [(&str, &[u8]); 2] = [("assets/ahri/edited.dds", b"vanilla"), ("assets/ahri/same.dds", b"same")];
+        for (path, data) in vanilla {
+            let target = wad_folder.join(path);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::write(target, data).unwrap();
+        }
+        let wad = dir.path().join("Ahri.wad.client");
+        write_wad(&wad, &vanilla);
+        record_vanilla_manifest(&project, &wad).unwrap();
+
+        fs::write(wad_folder.join("assets/ahri/edited.dds"), b"recolor").unwrap();
+        fs::write(wad_folder.join("assets/ahri/added.dds"), b"new").unwrap();
+
+        let manifest = ExportManifest::from_project(&project).unwrap();
+        assert_eq!(manifest.files, vec![
+            "ahri.wad.client/assets/ahri/added.dds",
+            "ahri.wad.client/assets/ahri/edited.dds",
+        ]);
+    }
+}
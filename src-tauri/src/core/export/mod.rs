@@ -4,6 +4,17 @@
 //! - `.fantome` format (legacy, widely supported) via ltk_fantome
 //! - `.modpkg` format (modern format) via ltk_modpkg
 
+pub mod archive;
+pub mod conflicts;
+pub mod fantome;
+pub mod layers;
+pub mod manifest;
+pub mod preview;
+#[cfg(test)]
+pub(crate) mod test_util;
+pub mod validate;
+pub mod verify;
+
 // Re-export from ltk crates for convenience
 #[allow(unused_imports)]
 pub use ltk_fantome::{pack_to_fantome, FantomeInfo, create_file_name, FantomeExtractor};
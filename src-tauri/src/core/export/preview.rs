@@ -0,0 +1,228 @@
+//! Preview image export
+//!
+//! Renders decoded textures (or frames captured from the 3D viewport) to
+//! PNG/GIF/WebP files for sharing on mod pages. Multiple frames become an
+//! animated GIF, or a side-by-side strip for the still formats.
+
+use crate::error::{Error, Result};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::{self, FilterType};
+use image::{Delay, ExtendedColorType, Frame, ImageEncoder, RgbaImage};
+use ltk_texture::Texture;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use ts_rs::TS;
+
+/// Output format for preview exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewFormat {
+    Png,
+    Gif,
+    Webp,
+}
+
+impl PreviewFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            PreviewFormat::Png => "png",
+            PreviewFormat::Gif => "gif",
+            PreviewFormat::Webp => "webp",
+        }
+    }
+}
+
+/// Decodes the top mipmap of a DDS or TEX texture to RGBA
+pub fn decode_texture_rgba(data: &[u8]) -> Result<RgbaImage> {
+    let mut cursor = Cursor::new(data);
+    let texture = Texture::from_reader(&mut cursor)
+        .map_err(|e| Error::InvalidInput(format!("Failed to parse texture: {:?}", e)))?;
+
+    let surface = texture
+        .decode_mipmap(0)
+        .map_err(|e| Error::InvalidInput(format!("Failed to decode texture: {:?}", e)))?;
+
+    surface
+        .into_rgba_image()
+        .map_err(|e| Error::InvalidInput(format!("Failed to convert to RGBA: {:?}", e)))
+}
+
+/// Scales an image so its longest side is at most `size`, keeping aspect ratio
+fn fit_to_size(image: RgbaImage, size: Option<u32>) -> RgbaImage {
+    match size {
+        Some(size) if size > 0 && (image.width() > size || image.height() > size) => {
+            let scale = size as f32 / image.width().max(image.height()) as f32;
+            let width = ((image.width() as f32 * scale).round() as u32).max(1);
+            let height = ((image.height() as f32 * scale).round() as u32).max(1);
+            imageops::resize(&image, width, height, FilterType::Triangle)
+        }
+        _ => image,
+    }
+}
+
+/// Places frames next to each other on a transparent canvas
+fn composite_strip(frames: &[RgbaImage]) -> RgbaImage {
+    let width = frames.iter().map(|f| f.width()).sum();
+    let height = frames.iter().map(|f| f.height()).max().unwrap_or(0);
+    let mut strip = RgbaImage::new(width, height);
+
+    let mut x = 0;
+    for frame in frames {
+        imageops::overlay(&mut strip, frame, x as i64, 0);
+        x += frame.width();
+    }
+    strip
+}
+
+/// Decodes an image file, reading DDS and TEX textures as well as common image formats
+pub fn decode_image_file(path: &std::path::Path) -> Result<RgbaImage> {
+    let data = std::fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    let is_texture = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("dds") || ext.eq_ignore_ascii_case("tex"));
+    if is_texture {
+        return decode_texture_rgba(&data);
+    }
+    image::load_from_memory(&data)
+        .map(|image| image.to_rgba8())
+        .map_err(|e| Error::InvalidInput(format!("Failed to open image '{}': {}", path.display(), e)))
+}
+
+/// Reads a thumbnail in the format a package stores it in
+///
+/// Files already in `format` are returned as they are; anything else is
+/// decoded and re-encoded.
+pub fn load_thumbnail(path: &std::path::Path, format: PreviewFormat) -> Result<Vec<u8>> {
+    let matches_format = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(format.extension()));
+    if matches_format {
+        return std::fs::read(path).map_err(|e| Error::io_with_path(e, path));
+    }
+    encode_preview(vec![decode_image_file(path)?], format, None, 0)
+}
+
+/// Encodes one or more frames into the requested preview format
+///
+/// # Arguments
+/// * `frames` - Decoded frames (one for a texture, N for a turnaround)
+/// * `format` - Output format
+/// * `size` - Optional maximum edge length per frame
+/// * `frame_delay_ms` - Delay between frames for animated GIFs
+pub fn encode_preview(
+    frames: Vec<RgbaImage>,
+    format: PreviewFormat,
+    size: Option<u32>,
+    frame_delay_ms: u32,
+) -> Result<Vec<u8>> {
+    if frames.is_empty() {
+        return Err(Error::InvalidInput("No frames to export".to_string()));
+    }
+
+    let frames: Vec<RgbaImage> = frames.into_iter().map(|f| fit_to_size(f, size)).collect();
+    let mut out = Vec::new();
+
+    if format == PreviewFormat::Gif {
+        let mut encoder = GifEncoder::new_with_speed(&mut out, 10);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|e| Error::InvalidInput(format!("Failed to encode GIF: {}", e)))?;
+        let delay = Delay::from_numer_denom_ms(frame_delay_ms.max(10), 1);
+        encoder
+            .encode_frames(frames.into_iter().map(|f| Frame::from_parts(f, 0, 0, delay)))
+            .map_err(|e| Error::InvalidInput(format!("Failed to encode GIF: {}", e)))?;
+        drop(encoder);
+        return Ok(out);
+    }
+
+    let image = if frames.len() == 1 {
+        frames.into_iter().next().unwrap()
+    } else {
+        composite_strip(&frames)
+    };
+
+    let result = match format {
+        PreviewFormat::Png => PngEncoder::new(&mut out).write_image(
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            ExtendedColorType::Rgba8,
+        ),
+        PreviewFormat::Webp => WebPEncoder::new_lossless(&mut out).write_image(
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            ExtendedColorType::Rgba8,
+        ),
+        PreviewFormat::Gif => unreachable!("GIF handled above"),
+    };
+    result.map_err(|e| {
+        Error::InvalidInput(format!("Failed to encode {}: {}", format.extension(), e))
+    })?;
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn solid(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba([255, 0, 0, 255]))
+    }
+
+    #[test]
+    fn test_fit_to_size() {
+        let image = fit_to_size(solid(400, 200), Some(100));
+        assert_eq!((image.width(), image.height()), (100, 50));
+
+        // Never upscales
+        let image = fit_to_size(solid(40, 20), Some(100));
+        assert_eq!((image.width(), image.height()), (40, 20));
+    }
+
+    #[test]
+    fn test_encode_formats() {
+        let png = encode_preview(vec![solid(8, 8)], PreviewFormat::Png, None, 100).unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+
+        let webp = encode_preview(vec![solid(8, 8)], PreviewFormat::Webp, None, 100).unwrap();
+        assert_eq!(&webp[8..12], b"WEBP");
+
+        let gif = encode_preview(vec![solid(8, 8), solid(8, 8)], PreviewFormat::Gif, None, 100).unwrap();
+        assert_eq!(&gif[0..3], b"GIF");
+    }
+
+    #[test]
+    fn test_multiple_frames_composited() {
+        let png = encode_preview(vec![solid(8, 8), solid(8, 4)], PreviewFormat::Png, None, 100).unwrap();
+        let decoded = image::load_from_memory(&png).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (16, 8));
+    }
+
+    #[test]
+    fn test_load_thumbnail_converts() {
+        let dir = tempfile::tempdir().unwrap();
+        let png_path = dir.path().join("thumb.png");
+        let png = encode_preview(vec![solid(8, 4)], PreviewFormat::Png, None, 100).unwrap();
+        std::fs::write(&png_path, &png).unwrap();
+
+        assert_eq!(load_thumbnail(&png_path, PreviewFormat::Png).unwrap(), png);
+        let webp = load_thumbnail(&png_path, PreviewFormat::Webp).unwrap();
+        assert_eq!(&webp[8..12], b"WEBP");
+
+        std::fs::write(dir.path().join("bad.jpg"), b"nope").unwrap();
+        assert!(load_thumbnail(&dir.path().join("bad.jpg"), PreviewFormat::Png).is_err());
+    }
+
+    #[test]
+    fn test_empty_frames_rejected() {
+        assert!(encode_preview(Vec::new(), PreviewFormat::Png, None, 100).is_err());
+    }
+}
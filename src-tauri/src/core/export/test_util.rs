@@ -0,0 +1,20 @@
+//! Package fixtures shared by tests
+
+use std::io::{Cursor, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+
+/// Zips `(name, data)` entries into an in-memory archive
+pub(crate) fn fantome_bytes(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in entries {
+        zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(data).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+/// Writes a .fantome with `(name, data)` entries to `path`
+pub(crate) fn write_fantome(path: &Path, entries: &[(&str, &[u8])]) {
+    std::fs::write(path, fantome_bytes(entries)).unwrap();
+}
@@ -0,0 +1,370 @@
+//! Structural validation of .fantome packages
+//!
+//! Mods built by other tools often fail to load in a mod manager for reasons
+//! that aren't visible from the outside: a missing or malformed
+//! `META/info.json`, WAD content placed outside a `{name}.wad.client` folder,
+//! lowercase `meta/`/`wad/` folders, or empty entries. This checks an
+//! arbitrary .fantome file for the problems Flint knows about.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use ts_rs::TS;
+use zip::ZipArchive;
+
+/// How serious a package issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum IssueSeverity {
+    /// The package will fail to load or load incorrectly
+    Error,
+    /// The package may work but is likely to cause problems
+    Warning,
+}
+
+/// A structural problem found in a package
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FantomeIssue {
+    pub severity: IssueSeverity,
+    /// Stable identifier for the kind of issue (e.g. "missing_info")
+    pub code: String,
+    /// Archive entry the issue applies to
+    pub entry: Option<String>,
+    pub message: String,
+}
+
+/// Metadata read from `META/info.json`
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FantomeMetadata {
+    pub name: String,
+    pub author: String,
+    pub version: String,
+    pub description: String,
+}
+
+/// Result of validating a .fantome package
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FantomeValidationReport {
+    /// True if no errors were found (warnings are allowed)
+    pub valid: bool,
+    pub entry_count: usize,
+    /// WAD folder or packed WAD names under `WAD/`
+    pub wads: Vec<String>,
+    pub metadata: Option<FantomeMetadata>,
+    pub issues: Vec<FantomeIssue>,
+}
+
+/// `META/info.json` as written by fantome tools (PascalCase keys)
+#[derive(Deserialize)]
+struct InfoJson {
+    #[serde(rename = "Name")]
+    name: Option<String>,
+    #[serde(rename = "Author", default)]
+    author: Option<String>,
+    #[serde(rename = "Version", default)]
+    version: Option<String>,
+    #[serde(rename = "Description", default)]
+    description: Option<String>,
+}
+
+const INFO_PATH: &str = "META/info.json";
+const WAD_EXTENSIONS: [&str; 3] = [".wad.client", ".wad.mobile", ".wad"];
+
+fn is_wad_name(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    WAD_EXTENSIONS.iter().any(|ext| lower.ends_with(ext))
+}
+
+struct Issues(Vec<FantomeIssue>);
+
+impl Issues {
+    fn push(&mut self, severity: IssueSeverity, code: &str, entry: Option<&str>, message: String) {
+        self.0.push(FantomeIssue {
+            severity,
+            code: code.to_string(),
+            entry: entry.map(str::to_string),
+            message,
+        });
+    }
+
+    fn error(&mut self, code: &str, entry: Option<&str>, message: String) {
+        self.push(IssueSeverity::Error, code, entry, message);
+    }
+
+    fn warning(&mut self, code: &str, entry: Option<&str>, message: String) {
+        self.push(IssueSeverity::Warning, code, entry, message);
+    }
+}
+
+fn check_metadata(content: &[u8], issues: &mut Issues) -> Option<FantomeMetadata> {
+    let mut content = content;
+    if let Some(rest) = content.strip_prefix(b"\xEF\xBB\xBF") {
+        issues.warning(
+            "info_bom",
+            Some(INFO_PATH),
+            "info.json starts with a UTF-8 byte order mark, which some managers can't parse".to_string(),
+        );
+        content = rest;
+    }
+
+    let info: InfoJson = match serde_json::from_slice(content) {
+        Ok(info) => info,
+        Err(e) => {
+            issues.error("invalid_info", Some(INFO_PATH), format!("info.json is not valid JSON: {}", e));
+            return None;
+        }
+    };
+
+    let name = info.name.unwrap_or_default();
+    if name.trim().is_empty() {
+        issues.error(
+            "invalid_info",
+            Some(INFO_PATH),
+            "info.json has no \"Name\" (keys are case-sensitive)".to_string(),
+        );
+    }
+    for (key, value) in [("Author", &info.author), ("Version", &info.version)] {
+        if value.is_none() {
+            issues.warning(
+                "incomplete_info",
+                Some(INFO_PATH),
+                format!("info.json has no \"{}\"", key),
+            );
+        }
+    }
+
+    Some(FantomeMetadata {
+        name,
+        author: info.author.unwrap_or_default(),
+        version: info.version.unwrap_or_default(),
+        description: info.description.unwrap_or_default(),
+    })
+}
+
+/// Validates the structure of a .fantome archive
+///
+/// # Arguments
+/// * `reader` - The archive contents
+pub fn validate_fantome_archive<R: Read + std::io::Seek>(reader: R) -> Result<FantomeValidationReport> {
+    let mut issues = Issues(Vec::new());
+    let mut archive = match ZipArchive::new(reader) {
+        Ok(archive) => archive,
+        Err(e) => {
+            issues.error("not_a_zip", None, format!("Not a valid zip archive: {}", e));
+            return Ok(FantomeValidationReport {
+                valid: false,
+                entry_count: 0,
+                wads: Vec::new(),
+                metadata: None,
+                issues: issues.0,
+            });
+        }
+    };
+
+    let mut metadata = None;
+    let mut has_info = false;
+    let mut wads: Vec<String> = Vec::new();
+    // Lowercased path -> first spelling seen, to catch entries that differ only in case
+    let mut seen: HashMap<String, String> = HashMap::new();
+
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| Error::InvalidInput(format!("Failed to read archive entry {}: {}", i, e)))?;
+        let name = file.name().to_string();
+        let entry = Some(name.as_str());
+
+        if name.contains('\\') {
+            issues.error(
+                "backslash_path",
+                entry,
+                "Entry uses backslashes; zip paths must use '/' separators".to_string(),
+            );
+        }
+        let name = name.replace('\\', "/");
+        if file.is_dir() {
+            continue;
+        }
+
+        if let Some(previous) = seen.insert(name.to_lowercase(), name.clone()) {
+            issues.error(
+                "case_collision",
+                entry,
+                format!("Differs from '{}' only in letter case; both map to the same game file", previous),
+            );
+        }
+
+        let (top, rest) = name.split_once('/').unwrap_or((name.as_str(), ""));
+        match top {
+            "META" => {
+                if name == INFO_PATH {
+                    has_info = true;
+                    let mut content = Vec::new();
+                    file.read_to_end(&mut content)
+                        .map_err(|e| Error::InvalidInput(format!("Failed to read {}: {}", INFO_PATH, e)))?;
+                    metadata = check_metadata(&content, &mut issues);
+                    continue;
+                }
+                if rest.eq_ignore_ascii_case("info.json") {
+                    issues.error(
+                        "path_case",
+                        entry,
+                        format!("Must be named exactly '{}'", INFO_PATH),
+                    );
+                }
+            }
+            "WAD" => {
+                let (wad, inner) = rest.split_once('/').unwrap_or((rest, ""));
+                if !is_wad_name(wad) {
+                    issues.error(
+                        "wad_layout",
+                        entry,
+                        "Files under WAD/ must be inside a '{name}.wad.client' folder or be a packed WAD"
+                            .to_string(),
+                    );
+                    continue;
+                }
+                if !wads.iter().any(|w| w == wad) {
+                    wads.push(wad.to_string());
+                }
+
+                if inner.is_empty() {
+                    // Packed WAD archive
+                    let mut magic = [0u8; 2];
+                    if file.read_exact(&mut magic).is_err() || &magic != b"RW" {
+                        issues.error("invalid_wad", entry, "Packed WAD doesn't start with the 'RW' magic".to_string());
+                    }
+                    continue;
+                }
+                if inner.chars().any(|c| c.is_ascii_uppercase()) {
+                    issues.warning(
+                        "path_case",
+                        entry,
+                        "Contains uppercase letters; game paths are lowercase and some tools hash them as-is"
+                            .to_string(),
+                    );
+                }
+            }
+            "RAW" => {
+                issues.error(
+                    "raw_unsupported",
+                    entry,
+                    "RAW/ folders aren't supported by league-mod tools".to_string(),
+                );
+                continue;
+            }
+            _ if top.eq_ignore_ascii_case("meta") || top.eq_ignore_ascii_case("wad") => {
+                issues.error(
+                    "path_case",
+                    entry,
+                    format!("Top-level folder must be '{}'", top.to_uppercase()),
+                );
+                continue;
+            }
+            _ => {
+                issues.warning(
+                    "unknown_entry",
+                    entry,
+                    "Outside META/ and WAD/; mod managers ignore it".to_string(),
+                );
+                continue;
+            }
+        }
+
+        if file.size() == 0 {
+            issues.warning("empty_entry", entry, "Zero-byte file".to_string());
+        }
+    }
+
+    if !has_info {
+        issues.error(
+            "missing_info",
+            None,
+            format!("Missing {}; managers can't read the mod's name or version", INFO_PATH),
+        );
+    }
+    if wads.is_empty() {
+        issues.error(
+            "no_wads",
+            None,
+            "No WAD content; expected 'WAD/{name}.wad.client/...' entries".to_string(),
+        );
+    }
+
+    Ok(FantomeValidationReport {
+        valid: !issues.0.iter().any(|i| i.severity == IssueSeverity::Error),
+        entry_count: archive.len(),
+        wads,
+        metadata,
+        issues: issues.0,
+    })
+}
+
+/// Validates the structure of a .fantome file
+///
+/// # Arguments
+/// * `path` - Path to the .fantome file
+pub fn validate_fantome(path: &Path) -> Result<FantomeValidationReport> {
+    let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    validate_fantome_archive(std::io::BufReader::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::export::test_util::fantome_bytes;
+    use std::io::Cursor;
+
+    fn build(entries: &[(&str, &[u8])]) -> Cursor<Vec<u8>> {
+        Cursor::new(fantome_bytes(entries))
+    }
+
+    fn codes(report: &FantomeValidationReport) -> Vec<&str> {
+        report.issues.iter().map(|i| i.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_valid_fantome() {
+        let archive = build(&[
+            ("META/info.json", br#"{"Name":"Mod","Author":"me","Version":"1.0.0","Description":""}"#),
+            ("WAD/ahri.wad.client/assets/ahri.tex", b"TEX\0"),
+        ]);
+        let report = validate_fantome_archive(archive).unwrap();
+        assert!(report.valid, "{:?}", report.issues);
+        assert!(report.issues.is_empty());
+        assert_eq!(report.wads, vec!["ahri.wad.client"]);
+        assert_eq!(report.metadata.unwrap().name, "Mod");
+    }
+
+    #[test]
+    fn test_structural_issues() {
+        let archive = build(&[
+            ("meta/info.json", b"{}"),
+            ("WAD/assets/ahri.tex", b"TEX\0"),
+            ("WAD/ahri.wad.client/data/empty.bin", b""),
+            ("WAD/ahri.wad.client/data/Skin0.bin", b"PROP"),
+            ("WAD/ahri.wad.client/data/skin0.bin", b"PROP"),
+        ]);
+        let report = validate_fantome_archive(archive).unwrap();
+        assert!(!report.valid);
+
+        let codes = codes(&report);
+        for expected in ["path_case", "wad_layout", "empty_entry", "case_collision", "missing_info"] {
+            assert!(codes.contains(&expected), "missing {} in {:?}", expected, codes);
+        }
+    }
+
+    #[test]
+    fn test_not_a_zip() {
+        let report = validate_fantome_archive(Cursor::new(b"not a zip".to_vec())).unwrap();
+        assert!(!report.valid);
+        assert_eq!(codes(&report), vec!["not_a_zip"]);
+    }
+}
@@ -0,0 +1,282 @@
+//! Smoke test of an exported package
+//!
+//! [`crate::core::export::validate`] checks that a .fantome is laid out the
+//! way mod managers expect. This goes further for packages Flint just wrote:
+//! the package is opened again and compared with the project files that went
+//! into it, every packed BIN is parsed, and the main skin BIN's linked BINs
+//! and asset references are followed to make sure they resolve inside the
+//! package (or in the game, when an installation is known).
+
+use crate::core::bin::closure::GameWadLookup;
+use crate::core::bin::ltk_bridge::read_bin;
+use crate::core::import::raw::GAME_ROOTS;
+use crate::core::repath::refather::scan_tree_for_paths;
+use crate::core::wad::extractor::strip_ltk_suffix;
+use crate::error::{Error, Result};
+use ltk_modpkg::Modpkg;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use ts_rs::TS;
+use zip::ZipArchive;
+
+/// Result of verifying an exported package
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PackageVerification {
+    /// True if nothing below is wrong
+    pub valid: bool,
+    /// Files found in the package
+    pub files_checked: usize,
+    /// Project files that didn't make it into the package
+    pub missing_files: Vec<String>,
+    /// Packed files with no content
+    pub empty_files: Vec<String>,
+    /// Packed BINs that don't parse, with the reason
+    pub invalid_bins: Vec<String>,
+    /// Main skin BIN in the package, if found
+    pub main_bin: Option<String>,
+    /// BINs reached from the main skin BIN, itself included
+    pub bins_followed: usize,
+    /// Paths the main skin BIN chain references that nothing provides
+    pub unresolved_references: Vec<String>,
+}
+
+/// What a package holds, keyed by `{wad}/{path}` (lowercase)
+#[derive(Default)]
+pub(crate) struct PackageContents {
+    pub(crate) sizes: BTreeMap<String, u64>,
+    /// Contents of the files the reader was asked to load
+    pub(crate) data: HashMap<String, Vec<u8>>,
+}
+
+impl PackageContents {
+    fn add(&mut self, path: String, size: u64, load: bool, data: impl FnOnce() -> Result<Vec<u8>>) -> Result<()> {
+        if load {
+            self.data.insert(path.clone(), data()?);
+        }
+        self.sizes.insert(path, size);
+        Ok(())
+    }
+}
+
+/// Lists the files under `WAD/` in a .fantome, loading those `load` accepts
+pub(crate) fn read_fantome(path: &Path, load: impl Fn(&str) -> bool) -> Result<PackageContents> {
+    let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mut archive = ZipArchive::new(BufReader::new(file))
+        .map_err(|e| Error::InvalidInput(format!("Not a fantome archive: {}", e)))?;
+
+    let mut contents = PackageContents::default();
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| Error::InvalidInput(format!("Failed to read archive entry {}: {}", i, e)))?;
+        let Some(inner) = entry.name().strip_prefix("WAD/").map(str::to_lowercase) else {
+            continue;
+        };
+        if entry.is_dir() {
+            continue;
+        }
+        let size = entry.size();
+        let wanted = load(&inner);
+        contents.add(inner, size, wanted, || {
+            let mut data = Vec::with_capacity(size as usize);
+            entry
+                .read_to_end(&mut data)
+                .map_err(|e| Error::InvalidInput(format!("Failed to read archive entry {}: {}", i, e)))?;
+            Ok(data)
+        })?;
+    }
+    Ok(contents)
+}
+
+/// Lists the content chunks of a .modpkg, loading those `load` accepts
+pub(crate) fn read_modpkg(path: &Path, load: impl Fn(&str) -> bool) -> Result<PackageContents> {
+    let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mut modpkg = Modpkg::mount_from_reader(BufReader::new(file))
+        .map_err(|e| Error::InvalidInput(format!("Not a modpkg: {}", e)))?;
+
+    let chunks: Vec<_> = modpkg.chunks.values().copied().collect();
+    let mut contents = PackageContents::default();
+    for chunk in chunks {
+        let Some(chunk_path) = modpkg.chunk_paths.get(&chunk.path_hash).map(|p| p.to_lowercase()) else {
+            continue;
+        };
+        if chunk_path.starts_with(ltk_modpkg::METADATA_FOLDER_NAME) {
+            continue;
+        }
+        let wanted = load(&chunk_path);
+        contents.add(chunk_path.clone(), chunk.uncompressed_size, wanted, || {
+            modpkg
+                .load_chunk_decompressed(&chunk)
+                .map(Vec::from)
+                .map_err(|e| Error::InvalidInput(format!("Failed to read {}: {}", chunk_path, e)))
+        })?;
+    }
+    Ok(contents)
+}
+
+/// True for an `assets/` path under a folder the game doesn't have
+///
+/// Without a League installation to check against, only these references are
+/// known not to come from the game.
+fn is_repathed(path: &str) -> bool {
+    path.strip_prefix("assets/")
+        .and_then(|rest| rest.split('/').next())
+        .is_some_and(|top| !GAME_ROOTS.contains(&top))
+}
+
+/// Verifies a freshly exported package
+///
+/// # Arguments
+/// * `package` - The `.fantome` or `.modpkg` file
+/// * `expected` - Project files that should be packed, as `{wad}/{path}`
+/// * `main_bin` - Main skin BIN path inside a WAD
+///   (`data/characters/{champion}/skins/skin{N}.bin`), if the chain should be checked
+/// * `game` - Game WADs that references may resolve to
+pub fn verify_package(
+    package: &Path,
+    expected: &[String],
+    main_bin: Option<&str>,
+    mut game: Option<&mut GameWadLookup>,
+) -> Result<PackageVerification> {
+    let is_modpkg = package
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("modpkg"));
+    let is_bin = |path: &str| path.ends_with(".bin");
+    let contents = if is_modpkg { read_modpkg(package, is_bin)? } else { read_fantome(package, is_bin)? };
+
+    let mut report = PackageVerification { files_checked: contents.sizes.len(), ..Default::default() };
+    report.missing_files = expected
+        .iter()
+        .map(|path| path.replace('\\', "/").to_lowercase())
+        .filter(|path| !contents.sizes.contains_key(path))
+        .collect();
+    report.empty_files = contents
+        .sizes
+        .iter()
+        .filter(|(_, size)| **size == 0)
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    let mut trees = HashMap::new();
+    for (path, data) in &contents.data {
+        match read_bin(data) {
+            Ok(tree) => {
+                trees.insert(path.as_str(), tree);
+            }
+            Err(e) => report.invalid_bins.push(format!("{}: {}", path, e)),
+        }
+    }
+    report.invalid_bins.sort();
+
+    // Game path -> packed path, across every WAD in the package
+    let packed: HashMap<&str, &str> = contents
+        .sizes
+        .keys()
+        .filter_map(|path| path.split_once('/').map(|(_, game_path)| (strip_ltk_suffix(game_path), path.as_str())))
+        .collect();
+
+    if let Some(main_bin) = main_bin.map(str::to_lowercase) {
+        report.main_bin = packed.get(main_bin.as_str()).map(|p| p.to_string());
+        if report.main_bin.is_none() {
+            report.unresolved_references.push(main_bin.clone());
+        }
+
+        let mut queue: VecDeque<String> = report.main_bin.iter().cloned().collect();
+        let mut visited: HashSet<String> = queue.iter().cloned().collect();
+        let mut unresolved = HashSet::new();
+        while let Some(bin) = queue.pop_front() {
+            let Some(tree) = trees.get(bin.as_str()) else {
+                continue;
+            };
+            report.bins_followed += 1;
+
+            let linked = tree.dependencies.iter().map(|d| d.to_lowercase().replace('\\', "/"));
+            let references = scan_tree_for_paths(tree);
+            for reference in linked.chain(references) {
+                if let Some(&packed_path) = packed.get(reference.as_str()) {
+                    if packed_path.ends_with(".bin") && visited.insert(packed_path.to_string()) {
+                        queue.push_back(packed_path.to_string());
+                    }
+                    continue;
+                }
+                let missing = match game.as_deref_mut() {
+                    Some(game) => game.find(&reference).is_none(),
+                    None => is_repathed(&reference),
+                };
+                if missing {
+                    unresolved.insert(reference);
+                }
+            }
+        }
+        report.unresolved_references.extend(unresolved);
+        report.unresolved_references.sort();
+    }
+
+    report.valid = report.missing_files.is_empty()
+        && report.empty_files.is_empty()
+        && report.invalid_bins.is_empty()
+        && report.unresolved_references.is_empty();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+    use crate::core::export::test_util::write_fantome;
+
+    fn skin_bin(linked: &str, texture: &str) -> Vec<u8> {
+        let text = format!(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nlinked: list[string] = {{ \"{}\" }}\nentries: map[hash,embed] = {{\n    \"Test\" = Foo {{\n        tex: string = \"{}\"\n    }}\n}}\n",
+            linked, texture
+        );
+        write_bin(&text_to_tree(&text).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_verify_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let package = dir.path().join("mod.fantome");
+        let main = skin_bin("data/mod_concat.bin", "ASSETS/Me/Mod/skin.dds");
+        let concat = skin_bin("data/characters/ahri/ahri.bin", "ASSETS/Me/Mod/gone.dds");
+        write_fantome(&package, &[
+            ("META/info.json", b"{}"),
+            ("WAD/ahri.wad.client/data/characters/ahri/skins/skin0.bin", &main),
+            ("WAD/ahri.wad.client/data/mod_concat.bin", &concat),
+            ("WAD/ahri.wad.client/assets/me/mod/skin.dds", b"DDS "),
+            ("WAD/ahri.wad.client/data/broken.bin", b"PROPxx"),
+            ("WAD/ahri.wad.client/assets/empty.dds", b""),
+        ]);
+
+        let expected = vec![
+            "ahri.wad.client/data/characters/ahri/skins/skin0.bin".to_string(),
+            "ahri.wad.client/assets/me/mod/skin.dds".to_string(),
+            "ahri.wad.client/assets/me/mod/lost.dds".to_string(),
+        ];
+        let report = verify_package(&package, &expected, Some("data/characters/ahri/skins/skin0.bin"), None).unwrap();
+
+        assert!(!report.valid);
+        assert_eq!(report.files_checked, 5);
+        assert_eq!(report.missing_files, vec!["ahri.wad.client/assets/me/mod/lost.dds"]);
+        assert_eq!(report.empty_files, vec!["ahri.wad.client/assets/empty.dds"]);
+        assert_eq!(report.invalid_bins.len(), 1);
+        assert!(report.invalid_bins[0].starts_with("ahri.wad.client/data/broken.bin"));
+        assert_eq!(report.main_bin.as_deref(), Some("ahri.wad.client/data/characters/ahri/skins/skin0.bin"));
+        assert_eq!(report.bins_followed, 2);
+        // The vanilla champion BIN comes from the game; the repathed texture can't
+        assert_eq!(report.unresolved_references, vec!["assets/me/mod/gone.dds"]);
+
+        let clean = dir.path().join("clean.fantome");
+        write_fantome(&clean, &[
+            ("META/info.json", b"{}"),
+            ("WAD/ahri.wad.client/data/characters/ahri/skins/skin0.bin", &skin_bin("data/characters/ahri/ahri.bin", "ASSETS/Me/Mod/skin.dds")),
+            ("WAD/ahri.wad.client/assets/me/mod/skin.dds", b"DDS "),
+        ]);
+        let report = verify_package(&clean, &expected[..2], Some("data/characters/ahri/skins/skin0.bin"), None).unwrap();
+        assert!(report.valid, "{:?}", report);
+    }
+}
@@ -8,10 +8,13 @@ use tauri::{AppHandle, Emitter};
 use tracing::{Event, Subscriber};
 use tracing_subscriber::layer::Context;
 use tracing_subscriber::Layer;
+use ts_rs::TS;
 
 /// Log entry sent to frontend
-#[derive(Clone, Serialize)]
+#[derive(Clone, Serialize, TS)]
+#[ts(export)]
 pub struct LogEvent {
+    #[ts(type = "number")]
     pub timestamp: i64,
     pub level: String,
     pub target: String,
@@ -0,0 +1,268 @@
+//! Hash resolution audit after a hash update
+//!
+//! CommunityDragon adds names for previously unknown chunks with every hash
+//! update. Chunks the old hashtable couldn't resolve show up as hex hashes in
+//! the WAD explorer and get extracted as `{hash}.{ext}` files, so after an
+//! update the user has no way to tell whether re-extracting would recover
+//! better names. The audit compares the hashtable before and after the update
+//! for a set of WADs and projects and reports how many of their unknown
+//! hashes now resolve.
+
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Number of newly resolved paths kept per source as examples
+const MAX_EXAMPLES: usize = 5;
+
+/// What kind of source was audited
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditSourceKind {
+    Wad,
+    Project,
+}
+
+/// Resolution changes for one WAD or project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SourceResolution {
+    pub kind: AuditSourceKind,
+    /// File name of the WAD or folder name of the project
+    pub name: String,
+    pub path: String,
+    /// Hashes the previous hashtable couldn't resolve
+    pub previously_unknown: usize,
+    /// Of those, how many the updated hashtable resolves
+    pub now_resolved: usize,
+    /// A few of the newly resolved paths
+    pub examples: Vec<String>,
+}
+
+impl SourceResolution {
+    /// One-line summary, e.g. "124 previously unknown files in Ahri.wad.client now resolved"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} previously unknown file{} in {} now resolved",
+            self.now_resolved,
+            if self.now_resolved == 1 { "" } else { "s" },
+            self.name
+        )
+    }
+}
+
+/// Result of auditing several sources after a hash update
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HashResolutionReport {
+    /// Sources with at least one newly resolved hash
+    pub improved: Vec<SourceResolution>,
+    /// Number of sources that were audited successfully
+    pub scanned: usize,
+    /// Sources that couldn't be read, with the reason
+    pub failed: Vec<String>,
+    /// Summary line per improved source
+    pub messages: Vec<String>,
+}
+
+impl HashResolutionReport {
+    /// Total number of newly resolved hashes across all sources
+    pub fn total_resolved(&self) -> usize {
+        self.improved.iter().map(|s| s.now_resolved).sum()
+    }
+}
+
+/// Counts which of `hashes` were unknown to `before` and are known to `after`
+fn compare_hashes(
+    hashes: impl Iterator<Item = u64>,
+    before: &Hashtable,
+    after: &Hashtable,
+) -> (usize, usize, Vec<String>) {
+    let mut unknown = 0;
+    let mut resolved = 0;
+    let mut examples = Vec::new();
+    for hash in hashes {
+        if before.lookup(hash, HashVariant::Xxh64).is_some() {
+            continue;
+        }
+        unknown += 1;
+        if let Some(path) = after.lookup(hash, HashVariant::Xxh64) {
+            resolved += 1;
+            if examples.len() < MAX_EXAMPLES {
+                examples.push(path.to_string());
+            }
+        }
+    }
+    (unknown, resolved, examples)
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Audits the chunk hashes of one WAD
+pub fn audit_wad(wad_path: &Path, before: &Hashtable, after: &Hashtable) -> Result<SourceResolution> {
+    let reader = WadReader::open(wad_path)?;
+    let (previously_unknown, now_resolved, examples) =
+        compare_hashes(reader.chunks().keys().copied(), before, after);
+
+    Ok(SourceResolution {
+        kind: AuditSourceKind::Wad,
+        name: display_name(wad_path),
+        path: wad_path.display().to_string(),
+        previously_unknown,
+        now_resolved,
+        examples,
+    })
+}
+
+/// Hash encoded in the name of a file extracted without a known path
+pub(crate) fn extracted_hash(path: &Path) -> Option<u64> {
+    let stem = path.file_name()?.to_str()?.split('.').next()?;
+    if stem.len() != 16 {
+        return None;
+    }
+    u64::from_str_radix(stem, 16).ok()
+}
+
+/// Audits the hash-named files in every layer of a project
+///
+/// A file only gets a hash name when the hashtable used for extraction didn't
+/// know its path, so every such file counts as previously unknown regardless
+/// of which hashtable is loaded now.
+pub fn audit_project(project_path: &Path, after: &Hashtable) -> Result<SourceResolution> {
+    let content = project_path.join("content");
+    if !content.is_dir() {
+        return Err(Error::InvalidInput(format!(
+            "Not a project folder: {}",
+            project_path.display()
+        )));
+    }
+
+    let hashes = WalkDir::new(&content)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| extracted_hash(e.path()));
+    let (previously_unknown, now_resolved, examples) =
+        compare_hashes(hashes, &Hashtable::empty(), after);
+
+    Ok(SourceResolution {
+        kind: AuditSourceKind::Project,
+        name: display_name(project_path),
+        path: project_path.display().to_string(),
+        previously_unknown,
+        now_resolved,
+        examples,
+    })
+}
+
+/// Audits WADs and projects against the hashtable before and after an update
+///
+/// WAD chunks count as previously unknown if `before` couldn't resolve them;
+/// for projects, see [`audit_project`].
+/// Sources that can't be read are listed in `failed` instead of aborting the
+/// audit; sources without newly resolved hashes are left out of `improved`.
+pub fn audit_hash_update(
+    wad_paths: &[String],
+    project_paths: &[String],
+    before: &Hashtable,
+    after: &Hashtable,
+) -> HashResolutionReport {
+    let mut report = HashResolutionReport::default();
+
+    let results = wad_paths
+        .iter()
+        .map(|p| (p, audit_wad(Path::new(p), before, after)))
+        .chain(
+            project_paths
+                .iter()
+                .map(|p| (p, audit_project(Path::new(p), after))),
+        );
+
+    for (path, result) in results {
+        match result {
+            Ok(source) => {
+                report.scanned += 1;
+                if source.now_resolved > 0 {
+                    report.improved.push(source);
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Hash audit skipped {}: {}", path, e);
+                report.failed.push(format!("{}: {}", path, e));
+            }
+        }
+    }
+
+    report.improved.sort_by_key(|s| std::cmp::Reverse(s.now_resolved));
+    report.messages = report.improved.iter().map(SourceResolution::summary).collect();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad_filled;
+    use std::fs;
+    use xxhash_rust::xxh64::xxh64;
+
+    fn hashtable(dir: &Path, paths: &[&str]) -> Hashtable {
+        fs::create_dir_all(dir).unwrap();
+        let lines: String = paths
+            .iter()
+            .map(|p| format!("{:016x} {}\n", xxh64(p.as_bytes(), 0), p))
+            .collect();
+        fs::write(dir.join("hashes.game.txt"), lines).unwrap();
+        Hashtable::from_directory(dir).unwrap()
+    }
+
+    #[test]
+    fn test_audit_reports_newly_resolved_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let known = "data/characters/ahri/ahri.bin";
+        let new_a = "assets/characters/ahri/skins/base/ahri_base_tx_cm.dds";
+        let new_b = "assets/characters/ahri/skins/base/ahri.skn";
+        let still_unknown = "assets/characters/ahri/mystery.dds";
+
+        let wad = dir.path().join("Ahri.wad.client");
+        write_wad_filled(&wad, &[known, new_a, new_b, still_unknown], b"data");
+
+        let project = dir.path().join("project");
+        let extracted = project.join("content/base/ahri.wad.client");
+        fs::create_dir_all(&extracted).unwrap();
+        fs::write(extracted.join(format!("{:016x}.dds", xxh64(new_a.as_bytes(), 0))), b"").unwrap();
+        fs::write(extracted.join("ahri.bin"), b"").unwrap();
+
+        let before = hashtable(&dir.path().join("old"), &[known]);
+        let after = hashtable(&dir.path().join("new"), &[known, new_a, new_b]);
+
+        let report = audit_hash_update(
+            &[wad.display().to_string(), dir.path().join("missing.wad.client").display().to_string()],
+            &[project.display().to_string()],
+            &before,
+            &after,
+        );
+
+        assert_eq!(report.scanned, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.total_resolved(), 3);
+
+        let wad_result = &report.improved[0];
+        assert_eq!(wad_result.kind, AuditSourceKind::Wad);
+        assert_eq!((wad_result.previously_unknown, wad_result.now_resolved), (3, 2));
+        assert_eq!(report.messages[0], "2 previously unknown files in Ahri.wad.client now resolved");
+
+        let project_result = &report.improved[1];
+        assert_eq!((project_result.previously_unknown, project_result.now_resolved), (1, 1));
+        assert_eq!(project_result.examples, vec![new_a.to_string()]);
+        assert_eq!(report.messages[1], "1 previously unknown file in project now resolved");
+    }
+}
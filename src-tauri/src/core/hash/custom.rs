@@ -0,0 +1,283 @@
+//! User-supplied hash lists
+//!
+//! Hashes cracked by the community reach the CommunityDragon lists with
+//! their next update. User lists live next to those lists in the RitoShark
+//! hash directory as `hashes.custom*.txt` files, are never touched by the
+//! downloader, and load after the CDragon lists so their names win on a
+//! conflicting hash.
+//!
+//! Names added one by one go to `hashes.custom.txt`; imported files are
+//! copied to `hashes.custom.<name>.txt` so they can be removed as a unit.
+
+use crate::core::hash::{parse_hash_value, HashVariant, Hashtable};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+
+/// Prefix shared by all user hash list file names
+const CUSTOM_PREFIX: &str = "hashes.custom";
+/// File holding hashes added through [`add_custom_hashes`]
+pub const CUSTOM_HASH_FILE: &str = "hashes.custom.txt";
+
+/// One hash list file in the hash directory
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HashSource {
+    /// File name, used to remove the source
+    pub name: String,
+    /// True for user lists, which take priority over CDragon lists
+    pub custom: bool,
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Number of mappings, counted for user lists only
+    pub entries: Option<usize>,
+}
+
+/// Result of adding hashes to the user list
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CustomHashResult {
+    /// Mappings newly written to the list
+    pub added: usize,
+    /// Mappings the list already held
+    pub duplicates: usize,
+    pub source: HashSource,
+}
+
+/// Returns true for user hash list files
+pub fn is_custom_hash_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.to_lowercase().starts_with(CUSTOM_PREFIX))
+}
+
+/// Orders hash files so that user lists load last and win over CDragon ones
+pub(crate) fn sort_by_priority(files: &mut [PathBuf]) {
+    files.sort_by_key(|path| (is_custom_hash_file(path), path.file_name().map(|n| n.to_os_string())));
+}
+
+/// Turns one user entry into a `<hash> <name>` line
+///
+/// Entries are either a line as written in hash files or a bare name. Bare
+/// names containing a `/` are hashed as WAD paths (xxh64), anything else as
+/// a BIN name (FNV-1a).
+fn entry_line(entry: &str) -> Result<Option<String>> {
+    let entry = entry.trim();
+    if entry.is_empty() || entry.starts_with('#') {
+        return Ok(None);
+    }
+
+    if let Some((hash_str, name)) = entry.split_once(' ') {
+        if HashVariant::detect(hash_str).is_some() {
+            let value = parse_hash_value(hash_str)
+                .map_err(|e| Error::InvalidInput(format!("Invalid hash value '{}': {}", hash_str, e)))?;
+            // Same rule the loader applies to lines in user lists
+            let variant = if value <= u32::MAX as u64 && HashVariant::Fnv1a32.hash(name.trim()) == value {
+                HashVariant::Fnv1a32
+            } else {
+                HashVariant::Xxh64
+            };
+            return Ok(Some(format!("{} {}", variant.format(value), name.trim())));
+        }
+    }
+
+    let name = entry.replace('\\', "/");
+    let variant = if name.contains('/') {
+        HashVariant::Xxh64
+    } else {
+        HashVariant::Fnv1a32
+    };
+    Ok(Some(format!("{} {}", variant.format(variant.hash(&name)), name)))
+}
+
+/// Appends hashes to the user list, skipping ones it already holds
+///
+/// # Arguments
+/// * `hash_dir` - RitoShark hash directory
+/// * `entries` - `<hash> <name>` lines or bare names to hash
+pub fn add_custom_hashes(hash_dir: &Path, entries: &[String]) -> Result<CustomHashResult> {
+    let path = hash_dir.join(CUSTOM_HASH_FILE);
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(Error::io_with_path(e, &path)),
+    };
+    let mut known: HashSet<String> = existing.lines().map(|l| l.trim().to_lowercase()).collect();
+
+    let mut added = Vec::new();
+    let mut duplicates = 0;
+    for entry in entries {
+        let Some(line) = entry_line(entry)? else { continue };
+        if known.insert(line.to_lowercase()) {
+            added.push(line);
+        } else {
+            duplicates += 1;
+        }
+    }
+
+    if !added.is_empty() {
+        let mut content = existing;
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        for line in &added {
+            content.push_str(line);
+            content.push('\n');
+        }
+        Hashtable::validate_hash_text(&content, &path)?;
+        std::fs::create_dir_all(hash_dir).map_err(|e| Error::io_with_path(e, hash_dir))?;
+        std::fs::write(&path, content).map_err(|e| Error::io_with_path(e, &path))?;
+    }
+
+    Ok(CustomHashResult {
+        added: added.len(),
+        duplicates,
+        source: describe_source(&path)?,
+    })
+}
+
+/// Copies a hash list into the hash directory as a user list
+///
+/// The file must parse as a hash file; a list that failed to parse would be
+/// skipped at load time. Importing a file with the same name again replaces
+/// the earlier copy.
+pub fn import_hash_file(hash_dir: &Path, source: &Path) -> Result<HashSource> {
+    let content = std::fs::read_to_string(source).map_err(|e| Error::io_with_path(e, source))?;
+
+    let stem = source
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default()
+        .trim_start_matches(CUSTOM_PREFIX)
+        .trim_start_matches("hashes")
+        .trim_matches('.');
+    let stem: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    if stem.is_empty() {
+        return Err(Error::InvalidInput(format!(
+            "Can't name an imported list after {}",
+            source.display()
+        )));
+    }
+    let target = hash_dir.join(format!("{}.{}.txt", CUSTOM_PREFIX, stem));
+
+    if Hashtable::validate_hash_text(&content, &target)? == 0 {
+        return Err(Error::InvalidInput(format!(
+            "{} holds no hash mappings",
+            source.display()
+        )));
+    }
+
+    std::fs::create_dir_all(hash_dir).map_err(|e| Error::io_with_path(e, hash_dir))?;
+    std::fs::write(&target, content).map_err(|e| Error::io_with_path(e, &target))?;
+    describe_source(&target)
+}
+
+/// Deletes a user hash list
+///
+/// CDragon lists can't be removed this way; the downloader would only
+/// bring them back.
+pub fn remove_hash_source(hash_dir: &Path, name: &str) -> Result<()> {
+    let path = hash_dir.join(name);
+    if Path::new(name).file_name().and_then(|n| n.to_str()) != Some(name) || !is_custom_hash_file(&path) {
+        return Err(Error::InvalidInput(format!("'{}' is not a user hash list", name)));
+    }
+    std::fs::remove_file(&path).map_err(|e| Error::io_with_path(e, &path))
+}
+
+/// Lists the hash files in the hash directory, user lists last
+pub fn list_hash_sources(hash_dir: &Path) -> Result<Vec<HashSource>> {
+    if !hash_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = std::fs::read_dir(hash_dir)
+        .map_err(|e| Error::io_with_path(e, hash_dir))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().and_then(|s| s.to_str()) == Some("txt"))
+        .collect();
+    sort_by_priority(&mut files);
+    files.iter().map(|path| describe_source(path)).collect()
+}
+
+fn describe_source(path: &Path) -> Result<HashSource> {
+    let custom = is_custom_hash_file(path);
+    let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let entries = if custom && size > 0 {
+        let content = std::fs::read_to_string(path).map_err(|e| Error::io_with_path(e, path))?;
+        Some(Hashtable::validate_hash_text(&content, path).unwrap_or(0))
+    } else if custom {
+        Some(0)
+    } else {
+        None
+    };
+    Ok(HashSource {
+        name: path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        custom,
+        size,
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_custom_hashes_take_priority() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+
+        let path = "assets/characters/ahri/skins/base/ahri.dds";
+        let hash = HashVariant::Xxh64.hash(path);
+        std::fs::write(
+            dir.join("hashes.game.txt"),
+            format!("{:016x} assets/wrong.dds\n", hash),
+        )
+        .unwrap();
+
+        let result = add_custom_hashes(
+            dir,
+            &[path.to_string(), "mClipDataMap".to_string(), path.to_uppercase()],
+        )
+        .unwrap();
+        assert_eq!(result.added, 2);
+        assert_eq!(result.duplicates, 1);
+        assert_eq!(result.source.entries, Some(2));
+
+        let hashtable = Hashtable::from_directory(dir).unwrap();
+        assert_eq!(hashtable.resolve(hash), path);
+        assert_eq!(
+            hashtable.lookup(HashVariant::Fnv1a32.hash("mClipDataMap"), HashVariant::Fnv1a32),
+            Some("mClipDataMap")
+        );
+    }
+
+    #[test]
+    fn test_import_and_remove_hash_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("hashes");
+        let source = temp_dir.path().join("cracked paths.txt");
+        std::fs::write(&source, "0x1a2b3c4d5e6f7a8b data/cracked.bin\n").unwrap();
+
+        let imported = import_hash_file(&dir, &source).unwrap();
+        assert_eq!(imported.name, "hashes.custom.cracked_paths.txt");
+        assert_eq!(imported.entries, Some(1));
+        assert_eq!(list_hash_sources(&dir).unwrap().len(), 1);
+
+        assert!(remove_hash_source(&dir, "../cracked paths.txt").is_err());
+        remove_hash_source(&dir, &imported.name).unwrap();
+        assert!(list_hash_sources(&dir).unwrap().is_empty());
+
+        std::fs::write(&source, "not a hash line\n").unwrap();
+        assert!(import_hash_file(&dir, &source).is_err());
+    }
+}
@@ -1,12 +1,250 @@
-use std::collections::HashMap;
 use std::fs;
+use std::num::ParseIntError;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use league_toolkit::hash::fnv1a::hash_lower;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use xxhash_rust::xxh64::xxh64;
 use crate::error::{Error, Result};
+use crate::core::hash::custom;
+
+/// Hash function a hash value was computed with
+///
+/// BIN entry, field and type names use 32-bit FNV-1a, WAD chunk paths use
+/// 64-bit xxHash. Both are computed over the lowercased string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum HashVariant {
+    Fnv1a32,
+    Xxh64,
+}
+
+impl HashVariant {
+    /// Hashes a name or path with this variant
+    pub fn hash(self, value: &str) -> u64 {
+        match self {
+            Self::Fnv1a32 => hash_lower(value) as u64,
+            Self::Xxh64 => xxh64(value.to_lowercase().as_bytes(), 0),
+        }
+    }
+
+    /// Formats a hash the way CommunityDragon lists write it (8 or 16 hex digits)
+    pub fn format(self, hash: u64) -> String {
+        match self {
+            Self::Fnv1a32 => format!("{:08x}", hash),
+            Self::Xxh64 => format!("{:016x}", hash),
+        }
+    }
+
+    /// Variant implied by a CommunityDragon hash file name
+    ///
+    /// `hashes.bin*.txt` hold FNV names, `hashes.game.txt` and `hashes.lcu.txt`
+    /// hold xxh64 paths. Other files are detected line by line.
+    pub fn for_hash_file(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_lowercase();
+        if name.starts_with("hashes.bin") {
+            Some(Self::Fnv1a32)
+        } else if name.starts_with("hashes.game") || name.starts_with("hashes.lcu") {
+            Some(Self::Xxh64)
+        } else {
+            None
+        }
+    }
+
+    /// Guesses the variant from how a hash is written
+    ///
+    /// Tools write FNV hashes with up to 8 hex digits and xxh64 hashes with 16,
+    /// so the digit count decides. Returns None for input that isn't a hash.
+    pub fn detect(hash_str: &str) -> Option<Self> {
+        let digits = strip_hex_prefix(hash_str.trim());
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        match digits.len() {
+            1..=8 => Some(Self::Fnv1a32),
+            9..=16 => Some(Self::Xxh64),
+            _ => None,
+        }
+    }
+}
+
+fn strip_hex_prefix(hash_str: &str) -> &str {
+    hash_str
+        .strip_prefix("0x")
+        .or_else(|| hash_str.strip_prefix("0X"))
+        .unwrap_or(hash_str)
+}
+
+/// Parses a hash value as written in hash files
+///
+/// CDragon format uses hex hashes (e.g., "e55245ad") without 0x prefix.
+/// Supports: 0x prefix, plain hex, or decimal.
+pub fn parse_hash_value(hash_str: &str) -> std::result::Result<u64, ParseIntError> {
+    if hash_str.starts_with("0x") || hash_str.starts_with("0X") {
+        // Explicit hex with prefix
+        u64::from_str_radix(&hash_str[2..], 16)
+    } else if hash_str.chars().all(|c| c.is_ascii_hexdigit()) {
+        // Plain hex (CDragon format) - try hex first
+        u64::from_str_radix(hash_str, 16)
+    } else {
+        // Fall back to decimal
+        hash_str.parse::<u64>()
+    }
+}
+
+/// Names keyed by hash, stored back to back in one buffer
+///
+/// A `HashMap<u64, String>` of the ~4M CommunityDragon paths costs a heap
+/// allocation and a 24 byte `String` per entry on top of the text. Here every
+/// entry is a sorted hash plus an end offset into `names`, found by binary
+/// search.
+#[derive(Clone)]
+struct NameTable<K> {
+    hashes: Vec<K>,
+    /// End of each name in `names`; a name starts where the previous one ends
+    ends: Vec<u32>,
+    names: String,
+    /// Entry indices ordered by lowercased name, built on the first reverse lookup
+    by_name: OnceLock<Vec<u32>>,
+}
+
+impl<K: Copy + Ord> NameTable<K> {
+    fn empty() -> Self {
+        Self {
+            hashes: Vec::new(),
+            ends: Vec::new(),
+            names: String::new(),
+            by_name: OnceLock::new(),
+        }
+    }
+
+    /// Merges what was read from several files; on duplicate hashes the last
+    /// file (and within a file the last line) wins
+    fn build(parts: Vec<NameBuffer<K>>) -> Result<Self> {
+        let mut order: Vec<(K, usize, usize)> = parts
+            .iter()
+            .enumerate()
+            .flat_map(|(part, buffer)| buffer.entries.iter().enumerate().map(move |(i, e)| (e.0, part, i)))
+            .collect();
+        // Stable, so entries with the same hash stay in load order
+        order.sort_by_key(|e| e.0);
+
+        let mut table = Self::empty();
+        table.hashes.reserve(order.len());
+        table.ends.reserve(order.len());
+        let mut deduped: Vec<(K, usize, usize)> = Vec::with_capacity(order.len());
+        for entry in order {
+            match deduped.last_mut() {
+                Some(last) if last.0 == entry.0 => *last = entry,
+                _ => deduped.push(entry),
+            }
+        }
+        table.names.reserve(deduped.iter().map(|&(_, part, i)| parts[part].entries[i].2 as usize).sum());
+        for (hash, part, i) in deduped {
+            table.hashes.push(hash);
+            table.names.push_str(parts[part].name(i));
+            table.ends.push(u32::try_from(table.names.len()).map_err(|_| {
+                Error::Hash("Hash files hold more than 4 GiB of names".to_string())
+            })?);
+        }
+        Ok(table)
+    }
+
+    fn get(&self, hash: K) -> Option<&str> {
+        let index = self.hashes.binary_search(&hash).ok()?;
+        Some(self.name(index))
+    }
+
+    fn name(&self, index: usize) -> &str {
+        let start = if index == 0 { 0 } else { self.ends[index - 1] as usize };
+        &self.names[start..self.ends[index] as usize]
+    }
+
+    /// Finds the hash of a name, ignoring ASCII case
+    fn find(&self, name: &str) -> Option<K> {
+        let by_name = self.by_name.get_or_init(|| {
+            let mut order: Vec<u32> = (0..self.hashes.len() as u32).collect();
+            order.sort_unstable_by(|&a, &b| cmp_ignore_case(self.name(a as usize), self.name(b as usize)));
+            order
+        });
+        let slot = by_name
+            .binary_search_by(|&i| cmp_ignore_case(self.name(i as usize), name))
+            .ok()?;
+        Some(self.hashes[by_name[slot] as usize])
+    }
+
+    fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (K, &str)> {
+        self.hashes.iter().enumerate().map(|(i, hash)| (*hash, self.name(i)))
+    }
+}
+
+fn cmp_ignore_case(a: &str, b: &str) -> std::cmp::Ordering {
+    a.bytes()
+        .map(|c| c.to_ascii_lowercase())
+        .cmp(b.bytes().map(|c| c.to_ascii_lowercase()))
+}
+
+/// Names read from one hash file, before they are merged into a [`NameTable`]
+struct NameBuffer<K> {
+    /// Hash, start and length of each name in `text`
+    entries: Vec<(K, u32, u32)>,
+    text: String,
+}
+
+impl<K> Default for NameBuffer<K> {
+    fn default() -> Self {
+        Self { entries: Vec::new(), text: String::new() }
+    }
+}
+
+impl<K> NameBuffer<K> {
+    fn push(&mut self, hash: K, name: &str) -> Result<()> {
+        let too_large = || Error::Hash("Hash file holds more than 4 GiB of names".to_string());
+        let start = u32::try_from(self.text.len()).map_err(|_| too_large())?;
+        let len = u32::try_from(name.len()).map_err(|_| too_large())?;
+        self.text.push_str(name);
+        self.entries.push((hash, start, len));
+        Ok(())
+    }
+
+    fn name(&self, index: usize) -> &str {
+        let (_, start, len) = self.entries[index];
+        &self.text[start as usize..(start + len) as usize]
+    }
+}
+
+/// Mappings read from one hash file, split by variant
+#[derive(Default)]
+struct LoadedHashes {
+    paths: NameBuffer<u64>,
+    bin_names: NameBuffer<u32>,
+}
+
+impl LoadedHashes {
+    fn len(&self) -> usize {
+        self.paths.entries.len() + self.bin_names.entries.len()
+    }
+}
+
+/// Merges per-file mappings in load order
+fn merge_loaded(parts: Vec<LoadedHashes>) -> Result<(NameTable<u64>, NameTable<u32>)> {
+    let (paths, bin_names): (Vec<_>, Vec<_>) = parts.into_iter().map(|l| (l.paths, l.bin_names)).unzip();
+    Ok((NameTable::build(paths)?, NameTable::build(bin_names)?))
+}
 
 #[derive(Clone)]
 pub struct Hashtable {
-    mappings: HashMap<u64, String>,
+    /// xxh64 WAD path hashes
+    mappings: NameTable<u64>,
+    /// FNV-1a BIN name hashes, kept apart so they never shadow a path entry
+    bin_mappings: NameTable<u32>,
     #[allow(dead_code)] // Kept for future reload functionality
     source_dir: PathBuf,
 }
@@ -15,7 +253,8 @@ impl Hashtable {
     /// Creates an empty Hashtable (for fallback when loading fails or not needed)
     pub fn empty() -> Self {
         Self {
-            mappings: HashMap::new(),
+            mappings: NameTable::empty(),
+            bin_mappings: NameTable::empty(),
             source_dir: PathBuf::new(),
         }
     }
@@ -30,7 +269,7 @@ impl Hashtable {
     /// 
     /// # Performance
     /// Uses parallel file loading with rayon for faster initialization.
-    /// Pre-allocates HashMap capacity for ~4 million entries (typical hash file size).
+    /// Names are packed into one buffer per variant rather than a `String` each.
     pub fn from_directory(dir: impl AsRef<Path>) -> Result<Self> {
         let dir_path = dir.as_ref().to_path_buf();
         
@@ -50,16 +289,17 @@ impl Hashtable {
         }
         
         // Collect all .txt file paths first
-        let txt_files: Vec<PathBuf> = fs::read_dir(&dir_path)?
+        let mut txt_files: Vec<PathBuf> = fs::read_dir(&dir_path)?
             .filter_map(|e| e.ok())
             .map(|e| e.path())
             .filter(|p| p.is_file() && p.extension().and_then(|s| s.to_str()) == Some("txt"))
             .collect();
+        custom::sort_by_priority(&mut txt_files);
         
         tracing::debug!("Loading {} hash files in parallel", txt_files.len());
         
         // Load files in parallel using rayon
-        let partial_maps: Vec<HashMap<u64, String>> = txt_files
+        let partial_maps: Vec<LoadedHashes> = txt_files
             .par_iter()
             .filter_map(|path| {
                 match Self::load_hash_file_to_map(path) {
@@ -75,48 +315,71 @@ impl Hashtable {
             })
             .collect();
         
-        // Pre-allocate HashMap with estimated capacity (~4 million entries typical)
-        let total_estimate: usize = partial_maps.iter().map(|m| m.len()).sum();
-        let mut mappings = HashMap::with_capacity(total_estimate);
+        let (mappings, bin_mappings) = merge_loaded(partial_maps)?;
         
-        // Merge all partial maps
-        for partial in partial_maps {
-            mappings.extend(partial);
-        }
-        
-        tracing::info!("Hashtable loaded: {} total hashes", mappings.len());
+        tracing::info!(
+            "Hashtable loaded: {} path hashes, {} BIN name hashes",
+            mappings.len(),
+            bin_mappings.len()
+        );
         
         Ok(Self {
             mappings,
+            bin_mappings,
             source_dir: dir_path,
         })
     }
     
-    /// Loads a single hash file and returns its mappings as a new HashMap
+    /// Loads a single hash file and returns its mappings
     /// This variant is used for parallel loading.
-    fn load_hash_file_to_map(path: &Path) -> Result<HashMap<u64, String>> {
+    fn load_hash_file_to_map(path: &Path) -> Result<LoadedHashes> {
         let content = fs::read_to_string(path)?;
         
         // Pre-allocate based on line count estimate (average ~50 chars per line)
         let estimated_lines = content.len() / 50;
-        let mut mappings = HashMap::with_capacity(estimated_lines);
+        let mut loaded = LoadedHashes::default();
+        match HashVariant::for_hash_file(path) {
+            Some(HashVariant::Fnv1a32) => {
+                loaded.bin_names.entries.reserve(estimated_lines);
+                loaded.bin_names.text.reserve(content.len());
+            }
+            _ => {
+                loaded.paths.entries.reserve(estimated_lines);
+                loaded.paths.text.reserve(content.len());
+            }
+        }
         
-        Self::parse_hash_content(&content, path, &mut mappings)?;
+        Self::parse_hash_content(&content, path, &mut loaded)?;
         
-        Ok(mappings)
+        Ok(loaded)
     }
 
-    /// Loads a single hash file and adds its mappings to the provided HashMap
+    /// Loads a single hash file and adds its mappings to the provided maps
     /// Used for sequential reload operations.
     #[allow(dead_code)] // Used by reload()
-    fn load_hash_file(path: &Path, mappings: &mut HashMap<u64, String>) -> Result<()> {
+    fn load_hash_file(path: &Path, loaded: &mut LoadedHashes) -> Result<()> {
         let content = fs::read_to_string(path)?;
-        Self::parse_hash_content(&content, path, mappings)
+        Self::parse_hash_content(&content, path, loaded)
     }
     
-    /// Parses hash file content and adds mappings to the provided HashMap
+    /// Checks that text parses as a hash file, returning how many mappings it holds
+    ///
+    /// `path` decides the variant the way the file's name would once written.
+    pub(crate) fn validate_hash_text(content: &str, path: &Path) -> Result<usize> {
+        let mut loaded = LoadedHashes::default();
+        Self::parse_hash_content(content, path, &mut loaded)?;
+        Ok(loaded.len())
+    }
+
+    /// Parses hash file content and adds mappings to the provided maps
     /// Shared parsing logic used by both parallel and sequential loading.
-    fn parse_hash_content(content: &str, path: &Path, mappings: &mut HashMap<u64, String>) -> Result<()> {
+    ///
+    /// Files named after a CDragon list go entirely to that variant's map. In
+    /// other files a line is treated as a BIN name only when its hash is the
+    /// FNV-1a of the name, everything else is a path hash.
+    fn parse_hash_content(content: &str, path: &Path, loaded: &mut LoadedHashes) -> Result<()> {
+        let file_variant = HashVariant::for_hash_file(path);
+
         for (line_num, line) in content.lines().enumerate() {
             let line = line.trim();
             
@@ -134,20 +397,8 @@ impl Hashtable {
                 continue;
             }
             
-            // Parse the hash value
-            // CDragon format uses hex hashes (e.g., "e55245ad") without 0x prefix
-            // Support: 0x prefix, plain hex, or decimal
             let hash_str = parts[0];
-            let hash = if hash_str.starts_with("0x") || hash_str.starts_with("0X") {
-                // Explicit hex with prefix
-                u64::from_str_radix(&hash_str[2..], 16)
-            } else if hash_str.chars().all(|c| c.is_ascii_hexdigit()) {
-                // Plain hex (CDragon format) - try hex first
-                u64::from_str_radix(hash_str, 16)
-            } else {
-                // Fall back to decimal
-                hash_str.parse::<u64>()
-            }
+            let hash = parse_hash_value(hash_str)
             .map_err(|e| Error::parse_with_path(
                 line_num + 1,
                 format!(
@@ -158,8 +409,28 @@ impl Hashtable {
                 path,
             ))?;
             
-            let path_str = parts[1].to_string();
-            mappings.insert(hash, path_str);
+            let path_str = parts[1];
+            let variant = file_variant.unwrap_or_else(|| {
+                if hash <= u32::MAX as u64 && HashVariant::Fnv1a32.hash(path_str) == hash {
+                    HashVariant::Fnv1a32
+                } else {
+                    HashVariant::Xxh64
+                }
+            });
+
+            match variant {
+                HashVariant::Fnv1a32 => {
+                    let hash = u32::try_from(hash).map_err(|_| Error::parse_with_path(
+                        line_num + 1,
+                        format!("Hash value '{}' doesn't fit a 32-bit FNV hash", hash_str),
+                        path,
+                    ))?;
+                    loaded.bin_names.push(hash, path_str)?;
+                }
+                HashVariant::Xxh64 => {
+                    loaded.paths.push(hash, path_str)?;
+                }
+            }
         }
         
         Ok(())
@@ -172,13 +443,39 @@ impl Hashtable {
     /// 
     /// # Returns
     /// * `Cow<str>` - The resolved path if found, or hex representation if not found
+    ///
+    /// Only xxh64 path hashes are consulted; use [`Hashtable::lookup`] for BIN names.
     pub fn resolve(&self, hash: u64) -> std::borrow::Cow<'_, str> {
         self.mappings
-            .get(&hash)
-            .map(|s| std::borrow::Cow::Borrowed(s.as_str()))
+            .get(hash)
+            .map(std::borrow::Cow::Borrowed)
             .unwrap_or_else(|| std::borrow::Cow::Owned(format!("{:016x}", hash)))
     }
 
+    /// Looks up a hash in the map for the given variant
+    ///
+    /// # Returns
+    /// * `Option<&str>` - The name or path, or None if the hash is unknown
+    pub fn lookup(&self, hash: u64, variant: HashVariant) -> Option<&str> {
+        match variant {
+            HashVariant::Fnv1a32 => u32::try_from(hash)
+                .ok()
+                .and_then(|hash| self.bin_mappings.get(hash)),
+            HashVariant::Xxh64 => self.mappings.get(hash),
+        }
+    }
+
+    /// Finds the xxh64 hash a known WAD path is listed under
+    ///
+    /// Matching ignores case. The reverse index is built on first use, so the
+    /// first call on a full hashtable takes a moment.
+    ///
+    /// # Returns
+    /// * `Option<u64>` - The path hash, or None if no hash file lists the path
+    pub fn lookup_path(&self, path: &str) -> Option<u64> {
+        self.mappings.find(path.trim().replace('\\', "/").as_str())
+    }
+
     /// Reloads all hash files from the source directory
     /// 
     /// This method clears the current mappings and reloads all .txt files
@@ -189,40 +486,54 @@ impl Hashtable {
     /// * `Result<()>` - Ok if reload succeeded, Err otherwise
     #[allow(dead_code)] // Kept for future use
     pub fn reload(&mut self) -> Result<()> {
-        // Clear existing mappings
-        self.mappings.clear();
+        let mut parts = Vec::new();
         
         // Read all .txt files in the directory
-        let entries = fs::read_dir(&self.source_dir)?;
-        
-        for entry in entries {
-            let entry = entry?;
-            let path = entry.path();
+        let mut txt_files = Vec::new();
+        for entry in fs::read_dir(&self.source_dir)? {
+            let path = entry?.path();
             
             // Only process .txt files
             if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("txt") {
-                Self::load_hash_file(&path, &mut self.mappings)?;
+                txt_files.push(path);
             }
         }
+        custom::sort_by_priority(&mut txt_files);
+
+        for path in txt_files {
+            let mut loaded = LoadedHashes::default();
+            Self::load_hash_file(&path, &mut loaded)?;
+            parts.push(loaded);
+        }
         
+        // Replace existing mappings
+        (self.mappings, self.bin_mappings) = merge_loaded(parts)?;
         Ok(())
     }
 
     /// Returns the number of hash mappings currently loaded
     pub fn len(&self) -> usize {
-        self.mappings.len()
+        self.mappings.len() + self.bin_mappings.len()
+    }
+
+    /// Returns the number of mappings loaded for one variant
+    pub fn count(&self, variant: HashVariant) -> usize {
+        match variant {
+            HashVariant::Fnv1a32 => self.bin_mappings.len(),
+            HashVariant::Xxh64 => self.mappings.len(),
+        }
     }
 
     /// Returns true if the hashtable contains no mappings
     #[allow(dead_code)] // Kept for API completeness
     pub fn is_empty(&self) -> bool {
-        self.mappings.is_empty()
+        self.mappings.len() == 0 && self.bin_mappings.len() == 0
     }
 
-    /// Returns an iterator over all hash mappings
+    /// Returns an iterator over all path hash mappings, ordered by hash
     #[allow(dead_code)] // Kept for future use
-    pub fn entries(&self) -> impl Iterator<Item = (u64, &String)> {
-        self.mappings.iter().map(|(k, v)| (*k, v))
+    pub fn entries(&self) -> impl Iterator<Item = (u64, &str)> {
+        self.mappings.iter()
     }
 }
 
@@ -302,6 +613,45 @@ mod tests {
         assert_eq!(resolved, "9999999999999999");
     }
 
+    #[test]
+    fn test_duplicate_hash_keeps_last_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir_path = temp_dir.path();
+
+        create_test_hash_file(
+            dir_path,
+            "hashes.txt",
+            "0x0000000300000000 c.bin\n0x0000000100000000 old.bin\n0x0000000100000000 new.bin\n",
+        )
+        .unwrap();
+
+        let hashtable = Hashtable::from_directory(dir_path).unwrap();
+        assert_eq!(hashtable.len(), 2);
+        assert_eq!(hashtable.resolve(0x0000000100000000), "new.bin");
+        let entries: Vec<_> = hashtable.entries().collect();
+        assert_eq!(entries, vec![(0x0000000100000000, "new.bin"), (0x0000000300000000, "c.bin")]);
+    }
+
+    #[test]
+    fn test_lookup_path_ignores_case() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir_path = temp_dir.path();
+
+        let path = "assets/characters/ahri/skins/base/ahri.dds";
+        let hash = HashVariant::Xxh64.hash(path);
+        create_test_hash_file(
+            dir_path,
+            "hashes.game.txt",
+            &format!("{:016x} {}\n0000000000000001 data/other.bin\n", hash, path),
+        )
+        .unwrap();
+
+        let hashtable = Hashtable::from_directory(dir_path).unwrap();
+        assert_eq!(hashtable.lookup_path("ASSETS\\Characters\\Ahri\\skins/base/ahri.dds"), Some(hash));
+        assert_eq!(hashtable.lookup_path("data/other.bin"), Some(1));
+        assert_eq!(hashtable.lookup_path("data/missing.bin"), None);
+    }
+
     #[test]
     fn test_reload_synchronizes_with_disk() {
         let temp_dir = TempDir::new().unwrap();
@@ -394,4 +744,69 @@ mod tests {
         let hashtable = Hashtable::from_directory(dir_path).unwrap();
         assert!(!hashtable.is_empty());
     }
+
+    #[test]
+    fn test_bin_hashes_do_not_shadow_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir_path = temp_dir.path();
+
+        create_test_hash_file(dir_path, "hashes.binentries.txt", "0000abcd Characters/Ahri\n").unwrap();
+        create_test_hash_file(dir_path, "hashes.game.txt", "000000000000abcd assets/ahri.dds\n").unwrap();
+
+        let hashtable = Hashtable::from_directory(dir_path).unwrap();
+        assert_eq!(hashtable.len(), 2);
+        assert_eq!(hashtable.resolve(0xabcd), "assets/ahri.dds");
+        assert_eq!(hashtable.lookup(0xabcd, HashVariant::Xxh64), Some("assets/ahri.dds"));
+        assert_eq!(hashtable.lookup(0xabcd, HashVariant::Fnv1a32), Some("Characters/Ahri"));
+    }
+
+    #[test]
+    fn test_custom_file_routes_fnv_lines_by_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir_path = temp_dir.path();
+
+        let field = "mClipDataMap";
+        let path = "assets/characters/ahri/skins/base/ahri.dds";
+        let content = format!(
+            "{} {}\n{} {}\n",
+            HashVariant::Fnv1a32.format(HashVariant::Fnv1a32.hash(field)),
+            field,
+            HashVariant::Xxh64.format(HashVariant::Xxh64.hash(path)),
+            path
+        );
+        create_test_hash_file(dir_path, "custom.txt", &content).unwrap();
+
+        let hashtable = Hashtable::from_directory(dir_path).unwrap();
+        assert_eq!(hashtable.count(HashVariant::Fnv1a32), 1);
+        assert_eq!(hashtable.count(HashVariant::Xxh64), 1);
+        assert_eq!(
+            hashtable.lookup(HashVariant::Fnv1a32.hash(field), HashVariant::Fnv1a32),
+            Some(field)
+        );
+        assert_eq!(hashtable.resolve(HashVariant::Xxh64.hash(path)), path);
+    }
+
+    #[test]
+    fn test_bin_hash_file_rejects_64_bit_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir_path = temp_dir.path();
+
+        let path = dir_path.join("hashes.binfields.txt");
+        fs::write(&path, "0123456789abcdef mSomething\n").unwrap();
+        let mut loaded = LoadedHashes::default();
+        assert!(Hashtable::load_hash_file(&path, &mut loaded).is_err());
+    }
+
+    #[test]
+    fn test_detect_variant() {
+        assert_eq!(HashVariant::detect("e55245ad"), Some(HashVariant::Fnv1a32));
+        assert_eq!(HashVariant::detect("0x1a2b"), Some(HashVariant::Fnv1a32));
+        assert_eq!(HashVariant::detect("0X0123456789ABCDEF"), Some(HashVariant::Xxh64));
+        assert_eq!(HashVariant::detect("0123456789abcdef0"), None);
+        assert_eq!(HashVariant::detect("not a hash"), None);
+        assert_eq!(
+            HashVariant::Xxh64.hash("DATA/Characters/Ahri/Ahri.bin"),
+            HashVariant::Xxh64.hash("data/characters/ahri/ahri.bin")
+        );
+    }
 }
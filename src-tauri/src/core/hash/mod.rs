@@ -1,6 +1,10 @@
 // Hash module exports
+pub mod audit;
+pub mod custom;
 pub mod downloader;
 pub mod hashtable;
+pub mod scheduler;
+pub mod unknown;
 
 pub use downloader::{download_hashes, get_ritoshark_hash_dir, DownloadStats};
-pub use hashtable::Hashtable;
+pub use hashtable::{parse_hash_value, HashVariant, Hashtable};
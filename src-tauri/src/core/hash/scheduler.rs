@@ -0,0 +1,196 @@
+//! Rate-limited hash update scheduling
+//!
+//! The launch-time update check hits the GitHub API every time the app
+//! starts. The scheduler records when the last check ran (in the hash
+//! directory) and skips the network entirely until the configured interval
+//! has passed. `force_hash_update` bypasses the interval.
+
+use crate::core::hash::downloader::{download_hashes, DownloadStats};
+use crate::core::settings::{load_json_setting, save_json_setting};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use ts_rs::TS;
+
+/// File in the hash directory holding the schedule
+const SCHEDULE_FILE: &str = "flint-hash-update.json";
+/// Default interval between update checks (daily)
+pub const DEFAULT_INTERVAL_HOURS: u64 = 24;
+
+/// Set while a check is running so overlapping triggers don't double-download
+static UPDATE_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Persisted hash update schedule
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HashUpdateSchedule {
+    /// Unix timestamp (seconds) of the last update check, successful or not
+    #[serde(default)]
+    #[ts(type = "number | null")]
+    pub last_check: Option<u64>,
+    /// Minimum hours between automatic checks
+    #[serde(default = "default_interval")]
+    #[ts(type = "number")]
+    pub interval_hours: u64,
+}
+
+fn default_interval() -> u64 {
+    DEFAULT_INTERVAL_HOURS
+}
+
+impl Default for HashUpdateSchedule {
+    fn default() -> Self {
+        Self {
+            last_check: None,
+            interval_hours: DEFAULT_INTERVAL_HOURS,
+        }
+    }
+}
+
+impl HashUpdateSchedule {
+    /// Returns true if an automatic check should run at `now` (unix seconds)
+    pub fn is_due(&self, now: u64) -> bool {
+        match self.last_check {
+            None => true,
+            // A clock that went backwards shouldn't block updates forever
+            Some(last) if last > now => true,
+            // Saturating, so a huge interval from a hand-edited file means "never"
+            Some(last) => now - last >= self.interval_hours.saturating_mul(60 * 60),
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Loads the schedule from the hash directory, falling back to defaults
+pub fn load_schedule(hash_dir: &Path) -> HashUpdateSchedule {
+    load_json_setting(hash_dir, SCHEDULE_FILE)
+}
+
+/// Writes the schedule to the hash directory
+pub fn save_schedule(hash_dir: &Path, schedule: &HashUpdateSchedule) -> Result<()> {
+    save_json_setting(hash_dir, SCHEDULE_FILE, schedule)
+}
+
+/// Sets the interval between automatic update checks
+///
+/// # Arguments
+/// * `hash_dir` - Hash directory holding the schedule
+/// * `hours` - Minimum hours between checks (must be at least 1)
+pub fn set_update_interval(hash_dir: &Path, hours: u64) -> Result<HashUpdateSchedule> {
+    if hours == 0 {
+        return Err(Error::InvalidInput(
+            "Update interval must be at least 1 hour".to_string(),
+        ));
+    }
+    let mut schedule = load_schedule(hash_dir);
+    schedule.interval_hours = hours;
+    save_schedule(hash_dir, &schedule)?;
+    Ok(schedule)
+}
+
+/// Downloads hashes and records the check time
+///
+/// The time is recorded even if the download fails, so an unreachable
+/// server is retried at the next interval rather than on every launch.
+async fn run_update(hash_dir: &Path, force: bool) -> Result<Option<DownloadStats>> {
+    if UPDATE_RUNNING.swap(true, Ordering::SeqCst) {
+        tracing::debug!("Hash update already in progress, skipping");
+        return Ok(None);
+    }
+
+    let result = download_hashes(hash_dir, force).await;
+    UPDATE_RUNNING.store(false, Ordering::SeqCst);
+
+    let mut schedule = load_schedule(hash_dir);
+    schedule.last_check = Some(now_secs());
+    if let Err(e) = save_schedule(hash_dir, &schedule) {
+        tracing::warn!("Failed to save hash update schedule: {}", e);
+    }
+
+    result.map(Some)
+}
+
+/// Runs a hash update check if the interval has passed
+///
+/// # Returns
+/// * `Ok(None)` if the check was skipped (not due or already running)
+/// * `Ok(Some(stats))` if a check ran
+pub async fn run_scheduled_update(hash_dir: &Path) -> Result<Option<DownloadStats>> {
+    let schedule = load_schedule(hash_dir);
+    if !schedule.is_due(now_secs()) {
+        tracing::debug!(
+            "Skipping hash update check (last check within {}h)",
+            schedule.interval_hours
+        );
+        return Ok(None);
+    }
+
+    tracing::info!("Checking for hash updates...");
+    run_update(hash_dir, false).await
+}
+
+/// Re-downloads all hash files now, ignoring the schedule and file ages
+///
+/// # Returns
+/// * `Ok(None)` if another update is already running
+pub async fn force_hash_update(hash_dir: &Path) -> Result<Option<DownloadStats>> {
+    tracing::info!("Forcing hash update");
+    run_update(hash_dir, true).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_due() {
+        let mut schedule = HashUpdateSchedule::default();
+        assert!(schedule.is_due(1_000_000));
+
+        schedule.last_check = Some(1_000_000);
+        assert!(!schedule.is_due(1_000_000 + 60 * 60));
+        assert!(schedule.is_due(1_000_000 + 24 * 60 * 60));
+        // Clock moved backwards
+        assert!(schedule.is_due(999_000));
+
+        schedule.interval_hours = u64::MAX;
+        assert!(!schedule.is_due(u64::MAX));
+    }
+
+    #[test]
+    fn test_schedule_persistence() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_schedule(dir.path()).last_check.is_none());
+
+        assert!(set_update_interval(dir.path(), 0).is_err());
+        set_update_interval(dir.path(), 72).unwrap();
+
+        let mut schedule = load_schedule(dir.path());
+        assert_eq!(schedule.interval_hours, 72);
+
+        schedule.last_check = Some(42);
+        save_schedule(dir.path(), &schedule).unwrap();
+        assert_eq!(load_schedule(dir.path()).last_check, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_update_skipped_when_not_due() {
+        let dir = tempfile::tempdir().unwrap();
+        let schedule = HashUpdateSchedule {
+            last_check: Some(now_secs()),
+            interval_hours: 24,
+        };
+        save_schedule(dir.path(), &schedule).unwrap();
+
+        // Not due, so this returns without touching the network
+        assert!(run_scheduled_update(dir.path()).await.unwrap().is_none());
+    }
+}
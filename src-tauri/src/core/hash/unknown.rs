@@ -0,0 +1,307 @@
+//! Listing chunks the hashtable can't name
+//!
+//! Unresolved chunks end up as `{hash}.{ext}` files or get left out of
+//! filtered extractions, so a user can't tell what is missing. The report
+//! lists every unknown hash of a WAD or project with the file kind read
+//! from its content and its size. Optionally it hashes the usual champion
+//! asset paths and names the chunks whose hash matches one; a match is
+//! exact, so guesses can be added to the user hash list as they are.
+
+use crate::core::hash::audit::extracted_hash;
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::project::{open_project, TargetKind};
+use crate::core::wad::identify::{identify_wad, WadTarget};
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use league_toolkit::file::LeagueFileKind;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Highest skin number paths are guessed for
+const MAX_GUESS_SKIN: u32 = 99;
+
+/// Texture extensions tried for texture templates
+const TEXTURE_EXTENSIONS: &[&str] = &["dds", "tex"];
+
+/// Champion paths that exist once per skin
+///
+/// `{c}` is the champion, `{n}` the skin number, `{s}` the skin folder
+/// (`base` or `skinNN`) and `{t}` a texture extension.
+const SKIN_TEMPLATES: &[&str] = &[
+    "data/characters/{c}/skins/skin{n}.bin",
+    "data/characters/{c}/animations/skin{n}.bin",
+    "assets/characters/{c}/skins/{s}/{c}.skn",
+    "assets/characters/{c}/skins/{s}/{c}.skl",
+    "assets/characters/{c}/skins/{s}/{c}_{s}.skn",
+    "assets/characters/{c}/skins/{s}/{c}_{s}.skl",
+    "assets/characters/{c}/skins/{s}/{c}.{t}",
+    "assets/characters/{c}/skins/{s}/{c}_tx_cm.{t}",
+    "assets/characters/{c}/skins/{s}/{c}_{s}_tx_cm.{t}",
+    "assets/characters/{c}/skins/{s}/{c}loadscreen.{t}",
+    "assets/characters/{c}/skins/{s}/{c}loadscreen_{n}.{t}",
+    "assets/characters/{c}/hud/{c}_circle_{n}.{t}",
+    "assets/characters/{c}/hud/{c}_square_{n}.{t}",
+];
+
+/// Champion paths that exist once per champion
+const CHAMPION_TEMPLATES: &[&str] = &[
+    "data/characters/{c}/{c}.bin",
+    "assets/characters/{c}/hud/{c}_circle.{t}",
+    "assets/characters/{c}/hud/{c}_square.{t}",
+];
+
+/// A chunk or extracted file without a known path
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UnknownChunk {
+    /// Path hash, 16 hex digits
+    pub hash: String,
+    /// Extension of the detected file kind, if the content was recognized
+    pub kind: Option<String>,
+    /// Uncompressed size in bytes
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Guessed path whose hash matches
+    pub guess: Option<String>,
+}
+
+/// Unknown hashes of one WAD or project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UnknownHashReport {
+    pub path: String,
+    /// Chunks in the WAD, or files in the project's content folder
+    pub total: usize,
+    /// Sorted by hash
+    pub unknown: Vec<UnknownChunk>,
+    /// Champions paths were guessed for
+    pub champions: Vec<String>,
+    /// Unknown chunks a guess named
+    pub guessed: usize,
+}
+
+fn expand(template: &str, champion: &str, skin: u32) -> Vec<String> {
+    let skin_folder = if skin == 0 {
+        "base".to_string()
+    } else {
+        format!("skin{:02}", skin)
+    };
+    let path = template
+        .replace("{c}", champion)
+        .replace("{n}", &skin.to_string())
+        .replace("{s}", &skin_folder);
+    if path.contains("{t}") {
+        TEXTURE_EXTENSIONS.iter().map(|ext| path.replace("{t}", ext)).collect()
+    } else {
+        vec![path]
+    }
+}
+
+/// Hashes the common asset paths of the given champions
+fn guess_candidates(champions: &[String]) -> HashMap<u64, String> {
+    let mut candidates = HashMap::new();
+    for champion in champions {
+        let champion = champion.to_lowercase();
+        let champion = champion.as_str();
+        let paths = CHAMPION_TEMPLATES
+            .iter()
+            .flat_map(|template| expand(template, champion, 0))
+            .chain((0..=MAX_GUESS_SKIN).flat_map(|skin| {
+                SKIN_TEMPLATES
+                    .iter()
+                    .flat_map(move |template| expand(template, champion, skin))
+            }));
+        for path in paths {
+            candidates.entry(HashVariant::Xxh64.hash(&path)).or_insert(path);
+        }
+    }
+    candidates
+}
+
+/// Lists the chunks of a WAD the hashtable can't resolve
+fn unknown_wad_chunks(path: &Path, hashtable: &Hashtable) -> Result<(usize, Vec<UnknownChunk>)> {
+    let mut reader = WadReader::open(path)?;
+    let total = reader.chunk_count();
+    let mut chunks: Vec<_> = reader
+        .chunks()
+        .iter()
+        .filter(|(hash, _)| hashtable.lookup(**hash, HashVariant::Xxh64).is_none())
+        .map(|(hash, chunk)| (*hash, *chunk))
+        .collect();
+    chunks.sort_by_key(|(hash, _)| *hash);
+
+    let (mut decoder, _) = reader.wad_mut().decode();
+    let mut unknown = Vec::with_capacity(chunks.len());
+    for (hash, chunk) in chunks {
+        let kind = match decoder.load_chunk_decompressed(&chunk) {
+            Ok(data) => LeagueFileKind::identify_from_bytes(&data).extension().map(str::to_string),
+            Err(e) => {
+                tracing::warn!("Failed to decompress chunk {:016x}: {}", hash, e);
+                None
+            }
+        };
+        unknown.push(UnknownChunk {
+            hash: HashVariant::Xxh64.format(hash),
+            kind,
+            size: chunk.uncompressed_size() as u64,
+            guess: None,
+        });
+    }
+    Ok((total, unknown))
+}
+
+/// Lists the hash-named files in a project's content folder
+fn unknown_project_files(project_path: &Path, hashtable: &Hashtable) -> Result<(usize, Vec<UnknownChunk>)> {
+    let content = project_path.join("content");
+    if !content.is_dir() {
+        return Err(Error::InvalidInput(format!(
+            "Not a project folder: {}",
+            project_path.display()
+        )));
+    }
+
+    let mut total = 0;
+    let mut unknown = Vec::new();
+    for entry in WalkDir::new(&content).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        total += 1;
+        let Some(hash) = extracted_hash(entry.path()) else { continue };
+        if hashtable.lookup(hash, HashVariant::Xxh64).is_some() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy();
+        let kind = name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_string())
+            .filter(|ext| ext != "ltk");
+        unknown.push(UnknownChunk {
+            hash: HashVariant::Xxh64.format(hash),
+            kind,
+            size: entry.metadata().map(|m| m.len()).unwrap_or(0),
+            guess: None,
+        });
+    }
+    unknown.sort_by(|a, b| a.hash.cmp(&b.hash));
+    Ok((total, unknown))
+}
+
+/// Champions a WAD or project holds, for guessing paths
+fn detect_champions(path: &Path, hashtable: &Hashtable) -> Vec<String> {
+    if path.is_dir() {
+        return match open_project(path) {
+            Ok(project) if project.target_kind == TargetKind::Champion => {
+                std::iter::once(project.champion)
+                    .chain(project.extra_targets.into_iter().map(|t| t.champion))
+                    .filter(|c| !c.is_empty())
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+    }
+    match identify_wad(path, Some(hashtable)) {
+        Ok(identity) if identity.target == WadTarget::Champion => identity.name.into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Reports the hashes of a WAD or project the hashtable can't resolve
+///
+/// # Arguments
+/// * `path` - A WAD file, or a project folder whose content holds hash-named files
+/// * `hashtable` - Hashes considered known
+/// * `guess` - Hash common champion paths and name the chunks they match
+/// * `champions` - Champions to guess paths for; detected from the WAD or
+///   project when empty
+pub fn report_unknown_hashes(
+    path: &Path,
+    hashtable: &Hashtable,
+    guess: bool,
+    champions: &[String],
+) -> Result<UnknownHashReport> {
+    let (total, mut unknown) = if path.is_dir() {
+        unknown_project_files(path, hashtable)?
+    } else {
+        unknown_wad_chunks(path, hashtable)?
+    };
+
+    let mut champions = champions.to_vec();
+    let mut guessed = 0;
+    if guess && !unknown.is_empty() {
+        if champions.is_empty() {
+            champions = detect_champions(path, hashtable);
+        }
+        let candidates = guess_candidates(&champions);
+        for chunk in &mut unknown {
+            let hash = u64::from_str_radix(&chunk.hash, 16).unwrap_or_default();
+            if let Some(path) = candidates.get(&hash) {
+                chunk.guess = Some(path.clone());
+                guessed += 1;
+            }
+        }
+    }
+
+    tracing::info!(
+        "{} of {} hashes unknown in {}, {} guessed",
+        unknown.len(),
+        total,
+        path.display(),
+        guessed
+    );
+
+    Ok(UnknownHashReport {
+        path: path.display().to_string(),
+        total,
+        unknown,
+        champions,
+        guessed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad_filled;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_report_lists_and_guesses_unknown_chunks() {
+        let temp_dir = TempDir::new().unwrap();
+        let wad_path = temp_dir.path().join("Ahri.wad.client");
+        let known = "data/characters/ahri/ahri.bin";
+        let guessable = "assets/characters/ahri/skins/skin03/ahri_skin03_tx_cm.dds";
+        let obscure = "assets/characters/ahri/skins/base/particles/ahri_base_z_glow.dds";
+        write_wad_filled(&wad_path, &[known, guessable, obscure], b"DDS data");
+
+        let hash_dir = temp_dir.path().join("hashes");
+        fs::create_dir_all(&hash_dir).unwrap();
+        fs::write(
+            hash_dir.join("hashes.game.txt"),
+            format!("{:016x} {}\n", HashVariant::Xxh64.hash(known), known),
+        )
+        .unwrap();
+        let hashtable = Hashtable::from_directory(&hash_dir).unwrap();
+
+        let report = report_unknown_hashes(&wad_path, &hashtable, true, &[]).unwrap();
+        assert_eq!(report.total, 3);
+        assert_eq!(report.unknown.len(), 2);
+        assert_eq!(report.champions, vec!["ahri".to_string()]);
+        assert_eq!(report.guessed, 1);
+        let guessed = report
+            .unknown
+            .iter()
+            .find(|c| c.hash == HashVariant::Xxh64.format(HashVariant::Xxh64.hash(guessable)))
+            .unwrap();
+        assert_eq!(guessed.guess.as_deref(), Some(guessable));
+        assert_eq!(guessed.size, 8);
+
+        let report = report_unknown_hashes(&wad_path, &hashtable, false, &[]).unwrap();
+        assert_eq!(report.guessed, 0);
+        assert!(report.unknown.iter().all(|c| c.guess.is_none()));
+    }
+}
@@ -0,0 +1,453 @@
+//! Annotated hex view of files
+//!
+//! Reads a window of a file as hex rows and labels the fields of the formats
+//! it recognises (WAD v3 headers and TOC, BIN headers and entries, SKN
+//! headers and ranges), so a broken asset can be inspected without leaving
+//! the app. Only the fields needed to reach the window are read; a structure
+//! that can't be followed to the end is reported instead of failing the view.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Bytes per hex row
+pub const ROW_LEN: usize = 16;
+/// Most bytes one view reads
+pub const MAX_LENGTH: u32 = 64 * 1024;
+
+const WAD_TOC_OFFSET: u64 = 4 + 256 + 8 + 4;
+const WAD_TOC_ENTRY_SIZE: u64 = 32;
+const SKN_MAGIC: u32 = 0x0011_2233;
+const SKN_RANGE_NAME_LEN: u64 = 64;
+/// BIN entries and linked files walked before giving up on reaching the window
+const MAX_WALK: u32 = 1 << 20;
+
+/// One row of the hex view
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HexRow {
+    #[ts(type = "number")]
+    pub offset: u64,
+    /// Space separated uppercase bytes
+    pub hex: String,
+    /// Printable ASCII, with `.` for everything else
+    pub ascii: String,
+}
+
+/// A labelled field of a recognised structure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HexAnnotation {
+    #[ts(type = "number")]
+    pub offset: u64,
+    #[ts(type = "number")]
+    pub length: u64,
+    /// Field path, e.g. `entries[3].path_hash`
+    pub label: String,
+    /// The field's decoded value
+    pub value: String,
+}
+
+/// A window of a file as hex, with the known fields inside it
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HexDump {
+    #[ts(type = "number")]
+    pub offset: u64,
+    #[ts(type = "number")]
+    pub file_size: u64,
+    /// Recognised format, e.g. "WAD v3.4"
+    pub structure: Option<String>,
+    pub rows: Vec<HexRow>,
+    /// Fields overlapping the window, in file order
+    pub annotations: Vec<HexAnnotation>,
+    /// Where the structure stopped making sense (truncated or corrupt data)
+    pub problem: Option<String>,
+}
+
+/// Reads fields of a file and keeps the ones inside the window
+struct Annotator<'a, R> {
+    source: &'a mut R,
+    size: u64,
+    window: Range<u64>,
+    structure: Option<String>,
+    annotations: Vec<HexAnnotation>,
+    problem: Option<String>,
+}
+
+impl<R: Read + Seek> Annotator<'_, R> {
+    fn bytes(&mut self, offset: u64, length: u64, label: &str) -> std::result::Result<Vec<u8>, String> {
+        if offset.checked_add(length).is_none_or(|end| end > self.size) {
+            return Err(format!("{} at 0x{:X} runs past the end of the file", label, offset));
+        }
+        let mut data = vec![0; length as usize];
+        self.source
+            .seek(SeekFrom::Start(offset))
+            .and_then(|_| self.source.read_exact(&mut data))
+            .map_err(|e| format!("Failed to read {} at 0x{:X}: {}", label, offset, e))?;
+        Ok(data)
+    }
+
+    fn annotate(&mut self, offset: u64, length: u64, label: String, value: String) {
+        if offset < self.window.end && offset + length > self.window.start {
+            self.annotations.push(HexAnnotation { offset, length, label, value });
+        }
+    }
+
+    fn past_window(&self, offset: u64) -> bool {
+        offset >= self.window.end
+    }
+
+    fn u8(&mut self, offset: u64, label: &str) -> std::result::Result<u8, String> {
+        let value = self.bytes(offset, 1, label)?[0];
+        self.annotate(offset, 1, label.to_string(), value.to_string());
+        Ok(value)
+    }
+
+    fn u16(&mut self, offset: u64, label: &str) -> std::result::Result<u16, String> {
+        let b = self.bytes(offset, 2, label)?;
+        let value = u16::from_le_bytes([b[0], b[1]]);
+        self.annotate(offset, 2, label.to_string(), value.to_string());
+        Ok(value)
+    }
+
+    fn u32(&mut self, offset: u64, label: &str) -> std::result::Result<u32, String> {
+        let b = self.bytes(offset, 4, label)?;
+        let value = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+        self.annotate(offset, 4, label.to_string(), value.to_string());
+        Ok(value)
+    }
+
+    /// A hash or checksum, shown in hex
+    fn hash(&mut self, offset: u64, length: u64, label: &str) -> std::result::Result<(), String> {
+        let b = self.bytes(offset, length, label)?;
+        let value = b.iter().rev().map(|byte| format!("{:02x}", byte)).collect::<String>();
+        self.annotate(offset, length, label.to_string(), format!("0x{}", value));
+        Ok(())
+    }
+
+    fn f32s(&mut self, offset: u64, count: u64, label: &str) -> std::result::Result<(), String> {
+        let b = self.bytes(offset, count * 4, label)?;
+        let values: Vec<String> = b
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]).to_string())
+            .collect();
+        self.annotate(offset, count * 4, label.to_string(), values.join(", "));
+        Ok(())
+    }
+}
+
+/// Recognises a format by its magic
+fn detect(magic: &[u8]) -> Option<&'static str> {
+    match magic {
+        [b'R', b'W', ..] => Some("WAD"),
+        [b'P', b'R', b'O', b'P', ..] | [b'P', b'T', b'C', b'H', ..] => Some("BIN"),
+        _ if magic.starts_with(&SKN_MAGIC.to_le_bytes()) => Some("SKN"),
+        _ => None,
+    }
+}
+
+fn annotate_wad<R: Read + Seek>(a: &mut Annotator<R>) -> std::result::Result<(), String> {
+    a.annotate(0, 2, "magic".to_string(), "RW".to_string());
+    let major = a.u8(2, "version.major")?;
+    let minor = a.u8(3, "version.minor")?;
+    a.structure = Some(format!("WAD v{}.{}", major, minor));
+    if major != 3 {
+        return Err("Only v3 WAD layouts are annotated".to_string());
+    }
+
+    a.annotate(4, 256, "signature".to_string(), "ECDSA signature".to_string());
+    a.hash(260, 8, "checksum")?;
+    let count = a.u32(268, "entry_count")? as u64;
+    if WAD_TOC_OFFSET + count * WAD_TOC_ENTRY_SIZE > a.size {
+        a.problem = Some(format!("TOC of {} entries runs past the end of the file", count));
+    }
+    let count = count.min(a.size.saturating_sub(WAD_TOC_OFFSET) / WAD_TOC_ENTRY_SIZE);
+
+    let first = a.window.start.saturating_sub(WAD_TOC_OFFSET) / WAD_TOC_ENTRY_SIZE;
+    for index in first..count {
+        let entry = WAD_TOC_OFFSET + index * WAD_TOC_ENTRY_SIZE;
+        if a.past_window(entry) {
+            break;
+        }
+        let field = |name: &str| format!("entries[{}].{}", index, name);
+        a.hash(entry, 8, &field("path_hash"))?;
+        a.u32(entry + 8, &field("data_offset"))?;
+        a.u32(entry + 12, &field("compressed_size"))?;
+        a.u32(entry + 16, &field("uncompressed_size"))?;
+        let kind = a.bytes(entry + 20, 1, &field("type"))?[0];
+        a.annotate(
+            entry + 20,
+            1,
+            field("type"),
+            format!("compression {}, {} subchunk(s)", kind & 0xF, kind >> 4),
+        );
+        a.u8(entry + 21, &field("is_duplicated"))?;
+        a.u16(entry + 22, &field("first_subchunk"))?;
+        a.hash(entry + 24, 8, &field("checksum"))?;
+    }
+    Ok(())
+}
+
+fn annotate_bin<R: Read + Seek>(a: &mut Annotator<R>) -> std::result::Result<(), String> {
+    let mut offset = 0;
+    if a.bytes(0, 4, "magic")? == b"PTCH" {
+        a.annotate(0, 4, "magic".to_string(), "PTCH".to_string());
+        a.hash(4, 8, "patch_header")?;
+        offset = 12;
+    }
+    if a.bytes(offset, 4, "magic")? != b"PROP" {
+        return Err(format!("Expected PROP at 0x{:X}", offset));
+    }
+    a.annotate(offset, 4, "magic".to_string(), "PROP".to_string());
+    let version = a.u32(offset + 4, "version")?;
+    offset += 8;
+    a.structure = Some(format!("BIN v{}", version));
+
+    if version >= 2 {
+        let linked = a.u32(offset, "linked_count")?;
+        offset += 4;
+        for index in 0..linked.min(MAX_WALK) {
+            if a.past_window(offset) {
+                return Ok(());
+            }
+            let length = a.u16(offset, &format!("linked[{}].length", index))? as u64;
+            let text = a.bytes(offset + 2, length, &format!("linked[{}]", index))?;
+            a.annotate(offset + 2, length, format!("linked[{}]", index), String::from_utf8_lossy(&text).into_owned());
+            offset += 2 + length;
+        }
+    }
+
+    let count = a.u32(offset, "entry_count")?;
+    offset += 4;
+    a.bytes(offset, count as u64 * 4, "entry_types")?;
+    a.annotate(offset, count as u64 * 4, "entry_types".to_string(), format!("{} class hash(es)", count));
+    offset += count as u64 * 4;
+
+    for index in 0..count.min(MAX_WALK) {
+        if a.past_window(offset) {
+            break;
+        }
+        let length = a.u32(offset, &format!("entries[{}].length", index))? as u64;
+        a.hash(offset + 4, 4, &format!("entries[{}].path_hash", index))?;
+        a.u16(offset + 8, &format!("entries[{}].property_count", index))?;
+        offset += 4 + length;
+    }
+    Ok(())
+}
+
+fn annotate_skn<R: Read + Seek>(a: &mut Annotator<R>) -> std::result::Result<(), String> {
+    a.hash(0, 4, "magic")?;
+    let major = a.u16(4, "version.major")?;
+    let minor = a.u16(6, "version.minor")?;
+    a.structure = Some(format!("SKN v{}.{}", major, minor));
+    if major == 0 {
+        return Ok(());
+    }
+
+    let count = a.u32(8, "range_count")?;
+    let mut offset = 12;
+    for index in 0..count.min(MAX_WALK) {
+        if a.past_window(offset) {
+            return Ok(());
+        }
+        let field = |name: &str| format!("ranges[{}].{}", index, name);
+        let name = a.bytes(offset, SKN_RANGE_NAME_LEN, &field("name"))?;
+        let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+        a.annotate(offset, SKN_RANGE_NAME_LEN, field("name"), String::from_utf8_lossy(&name[..end]).into_owned());
+        a.u32(offset + 64, &field("start_vertex"))?;
+        a.u32(offset + 68, &field("vertex_count"))?;
+        a.u32(offset + 72, &field("start_index"))?;
+        a.u32(offset + 76, &field("index_count"))?;
+        offset += SKN_RANGE_NAME_LEN + 16;
+    }
+
+    if major >= 4 {
+        a.u32(offset, "flags")?;
+        offset += 4;
+    }
+    a.u32(offset, "index_count")?;
+    a.u32(offset + 4, "vertex_count")?;
+    offset += 8;
+    if major >= 4 {
+        a.u32(offset, "vertex_size")?;
+        a.u32(offset + 4, "vertex_type")?;
+        a.f32s(offset + 8, 6, "bounding_box")?;
+        a.f32s(offset + 32, 4, "bounding_sphere")?;
+    }
+    Ok(())
+}
+
+fn hex_rows(offset: u64, data: &[u8]) -> Vec<HexRow> {
+    data.chunks(ROW_LEN)
+        .enumerate()
+        .map(|(index, row)| HexRow {
+            offset: offset + (index * ROW_LEN) as u64,
+            hex: row.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" "),
+            ascii: row
+                .iter()
+                .map(|&b| if (32..=126).contains(&b) { b as char } else { '.' })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Builds the hex view of `length` bytes at `offset` from any seekable source
+pub fn hex_dump<R: Read + Seek>(source: &mut R, offset: u64, length: u32) -> Result<HexDump> {
+    if length == 0 || length > MAX_LENGTH {
+        return Err(Error::InvalidInput(format!("Length must be between 1 and {} bytes", MAX_LENGTH)));
+    }
+    let size = source.seek(SeekFrom::End(0))?;
+    let end = offset.saturating_add(length as u64).min(size);
+    let start = offset.min(end);
+
+    let mut data = vec![0; (end - start) as usize];
+    source.seek(SeekFrom::Start(start))?;
+    source.read_exact(&mut data)?;
+
+    let mut magic = [0u8; 4];
+    let magic_len = size.min(4) as usize;
+    source.seek(SeekFrom::Start(0))?;
+    source.read_exact(&mut magic[..magic_len])?;
+
+    let format = detect(&magic[..magic_len]);
+    let mut annotator = Annotator {
+        source,
+        size,
+        window: start..end,
+        structure: format.map(str::to_string),
+        annotations: Vec::new(),
+        problem: None,
+    };
+    let outcome = match format {
+        Some("WAD") => annotate_wad(&mut annotator),
+        Some("BIN") => annotate_bin(&mut annotator),
+        Some("SKN") => annotate_skn(&mut annotator),
+        _ => Ok(()),
+    };
+    if let Err(problem) = outcome {
+        annotator.problem = Some(problem);
+    }
+
+    let mut annotations = annotator.annotations;
+    annotations.sort_by_key(|annotation| annotation.offset);
+    Ok(HexDump {
+        offset: start,
+        file_size: size,
+        structure: annotator.structure,
+        rows: hex_rows(start, &data),
+        annotations,
+        problem: annotator.problem,
+    })
+}
+
+/// Builds the hex view of a file
+pub fn read_file_hex(path: &Path, offset: u64, length: u32) -> Result<HexDump> {
+    let mut file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+    hex_dump(&mut file, offset, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn wad(entries: u32) -> Vec<u8> {
+        let mut data = b"RW\x03\x04".to_vec();
+        data.extend_from_slice(&[0; 264]);
+        data.extend_from_slice(&entries.to_le_bytes());
+        for index in 0..entries as u64 {
+            data.extend_from_slice(&(0xAB00 + index).to_le_bytes());
+            data.extend_from_slice(&[0; 12]);
+            data.push(0x13);
+            data.extend_from_slice(&[0; 11]);
+        }
+        data
+    }
+
+    fn label<'a>(dump: &'a HexDump, label: &str) -> Option<&'a HexAnnotation> {
+        dump.annotations.iter().find(|a| a.label == label)
+    }
+
+    #[test]
+    fn test_hex_rows() {
+        let dump = hex_dump(&mut Cursor::new(b"Hello\x00world, this is hex".to_vec()), 0, 64).unwrap();
+        assert_eq!(dump.file_size, 24);
+        assert_eq!(dump.rows.len(), 2);
+        assert_eq!(dump.rows[0].ascii, "Hello.world, thi");
+        assert!(dump.rows[0].hex.starts_with("48 65 6C 6C 6F 00"));
+        assert_eq!(dump.rows[1].offset, 16);
+        assert!(dump.structure.is_none() && dump.annotations.is_empty());
+
+        assert!(hex_dump(&mut Cursor::new(vec![0; 8]), 0, 0).is_err());
+        assert!(hex_dump(&mut Cursor::new(vec![0; 8]), 100, 16).unwrap().rows.is_empty());
+    }
+
+    #[test]
+    fn test_wad_annotations() {
+        let data = wad(3);
+        let dump = hex_dump(&mut Cursor::new(data.clone()), 0, 512).unwrap();
+        assert_eq!(dump.structure.as_deref(), Some("WAD v3.4"));
+        assert!(dump.problem.is_none());
+        assert_eq!(label(&dump, "entry_count").unwrap().value, "3");
+        let hash = label(&dump, "entries[1].path_hash").unwrap();
+        assert_eq!((hash.offset, hash.value.as_str()), (WAD_TOC_OFFSET + 32, "0x000000000000ab01"));
+        assert_eq!(label(&dump, "entries[2].type").unwrap().value, "compression 3, 1 subchunk(s)");
+
+        // A window deep in the TOC only carries the entries it shows
+        let dump = hex_dump(&mut Cursor::new(data.clone()), WAD_TOC_OFFSET + 64, 32).unwrap();
+        assert!(label(&dump, "entry_count").is_none());
+        assert!(label(&dump, "entries[1].path_hash").is_none());
+        assert!(label(&dump, "entries[2].checksum").is_some());
+
+        // Claiming more entries than the file holds is reported, not fatal
+        let mut truncated = wad(1);
+        truncated[268..272].copy_from_slice(&5u32.to_le_bytes());
+        let dump = hex_dump(&mut Cursor::new(truncated), 0, 512).unwrap();
+        assert!(dump.problem.as_deref().unwrap().contains("TOC of 5 entries"));
+        assert!(label(&dump, "entries[0].checksum").is_some());
+    }
+
+    #[test]
+    fn test_bin_and_skn_annotations() {
+        let mut bin = b"PROP".to_vec();
+        bin.extend_from_slice(&3u32.to_le_bytes());
+        bin.extend_from_slice(&1u32.to_le_bytes());
+        bin.extend_from_slice(&4u16.to_le_bytes());
+        bin.extend_from_slice(b"a.bin");
+        let dump = hex_dump(&mut Cursor::new(bin.clone()), 0, 256).unwrap();
+        assert_eq!(dump.structure.as_deref(), Some("BIN v3"));
+        assert_eq!(label(&dump, "linked[0]").unwrap().value, "a.bi");
+        assert!(dump.problem.is_some());
+
+        bin.truncate(bin.len() - 1);
+        bin.extend_from_slice(&1u32.to_le_bytes());
+        bin.extend_from_slice(&0x1234u32.to_le_bytes());
+        bin.extend_from_slice(&6u32.to_le_bytes());
+        bin.extend_from_slice(&0xCAFEu32.to_le_bytes());
+        bin.extend_from_slice(&0u16.to_le_bytes());
+        let dump = hex_dump(&mut Cursor::new(bin), 0, 256).unwrap();
+        assert!(dump.problem.is_none(), "{:?}", dump.problem);
+        assert_eq!(label(&dump, "entries[0].path_hash").unwrap().value, "0x0000cafe");
+
+        let mut skn = SKN_MAGIC.to_le_bytes().to_vec();
+        skn.extend_from_slice(&1u16.to_le_bytes());
+        skn.extend_from_slice(&1u16.to_le_bytes());
+        skn.extend_from_slice(&1u32.to_le_bytes());
+        let mut name = [0u8; 64];
+        name[..4].copy_from_slice(b"Body");
+        skn.extend_from_slice(&name);
+        for value in [0u32, 3, 0, 3, 3, 3] {
+            skn.extend_from_slice(&value.to_le_bytes());
+        }
+        let dump = hex_dump(&mut Cursor::new(skn), 0, 256).unwrap();
+        assert_eq!(dump.structure.as_deref(), Some("SKN v1.1"));
+        assert!(dump.problem.is_none(), "{:?}", dump.problem);
+        assert_eq!(label(&dump, "ranges[0].name").unwrap().value, "Body");
+        assert_eq!(label(&dump, "vertex_count").unwrap().value, "3");
+    }
+}
@@ -0,0 +1,206 @@
+//! Turning `.fantome` packages back into Flint projects
+//!
+//! `FantomeExtractor` unpacks the WAD folders into `content/base` and writes a
+//! `mod.config.json` from `META/info.json`. A fantome carries no Flint data,
+//! so the champion and skin are inferred from the extracted skin BINs and
+//! `flint.json` is written from that.
+
+use crate::core::league::detect_game_version;
+use crate::core::project::metadata::ProjectAuthor;
+use crate::core::project::project::{sanitize_filename, THUMBNAIL_FILE};
+use crate::core::project::{save_project, Project, TargetKind};
+use crate::core::repath::refather::RepathOptions;
+use crate::error::{Error, Result};
+use chrono::Utc;
+use ltk_fantome::{FantomeExtractor, WadHashtable};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Returns true if the archive ships packed `.wad.client` files instead of WAD folders
+///
+/// Only those need a hashtable to get readable file names back.
+fn has_packed_wads(fantome_path: &Path) -> Result<bool> {
+    let file = File::open(fantome_path).map_err(|e| Error::io_with_path(e, fantome_path))?;
+    let archive = zip::ZipArchive::new(file)
+        .map_err(|e| Error::InvalidInput(format!("Not a fantome archive: {}", e)))?;
+
+    let packed = archive.file_names().any(|name| {
+        name.strip_prefix("WAD/").is_some_and(|rest| {
+            !rest.contains('/') && (rest.ends_with(".wad.client") || rest.ends_with(".wad"))
+        })
+    });
+    Ok(packed)
+}
+
+/// Infers the champion and skin from the extracted WAD folders
+///
+/// Looks for `data/characters/{champion}/skins/skin{N}.bin` inside a
+/// `{champion}.wad.client` folder and picks the lowest skin number found.
+pub fn infer_champion_and_skin(content_base: &Path) -> Option<(String, u32)> {
+    let mut found: Option<(String, u32)> = None;
+
+    for entry in fs::read_dir(content_base).ok()?.flatten() {
+        let folder = entry.file_name().to_string_lossy().to_lowercase();
+        let Some(champion) = folder.strip_suffix(".wad.client") else {
+            continue;
+        };
+        let skins_dir = entry.path().join("data").join("characters").join(champion).join("skins");
+
+        let skin_ids = WalkDir::new(&skins_dir)
+            .max_depth(1)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                let name = e.file_name().to_string_lossy().to_lowercase();
+                name.strip_prefix("skin")?.strip_suffix(".bin")?.parse::<u32>().ok()
+            });
+
+        if let Some(skin_id) = skin_ids.min() {
+            if found.as_ref().is_none_or(|(_, best)| skin_id < *best) {
+                found = Some((champion.to_string(), skin_id));
+            }
+        }
+    }
+
+    found
+}
+
+/// Creates a new project from a `.fantome` package
+///
+/// # Arguments
+/// * `fantome_path` - The `.fantome` archive to import
+/// * `output_dir` - Directory where the project folder will be created
+/// * `league_path` - League installation to link the project to, if known
+/// * `hash_dir` - Hash directory for naming files inside packed WADs
+///
+/// # Returns
+/// * `Result<Project>` - The imported project
+pub fn import_fantome(
+    fantome_path: &Path,
+    output_dir: &Path,
+    league_path: Option<&Path>,
+    hash_dir: Option<&Path>,
+) -> Result<Project> {
+    let file = File::open(fantome_path).map_err(|e| Error::io_with_path(e, fantome_path))?;
+    let mut extractor = FantomeExtractor::new(file)
+        .map_err(|e| Error::InvalidInput(format!("Not a fantome archive: {}", e)))?;
+    let info = extractor
+        .read_metadata()
+        .map_err(|e| Error::InvalidInput(format!("Failed to read fantome metadata: {}", e)))?;
+
+    let project_path = output_dir.join(sanitize_filename(&info.name));
+    if project_path.exists() {
+        return Err(Error::InvalidInput(format!(
+            "Project already exists at: {}",
+            project_path.display()
+        )));
+    }
+
+    if has_packed_wads(fantome_path)? {
+        let hashtable = hash_dir
+            .and_then(|dir| dir.to_str())
+            .and_then(|dir| WadHashtable::from_directory(dir).ok());
+        if hashtable.is_none() {
+            tracing::warn!("No hashtable for packed WADs; their files will keep hex names");
+        }
+        extractor = extractor.with_hashtable_opt(hashtable);
+    }
+
+    tracing::info!("Importing {} into {}", fantome_path.display(), project_path.display());
+    let extracted = match extractor.extract_to(&project_path) {
+        Ok(extracted) => extracted,
+        Err(e) => {
+            if let Err(cleanup_err) = fs::remove_dir_all(&project_path) {
+                tracing::error!("Failed to clean up project directory: {}", cleanup_err);
+            }
+            return Err(Error::InvalidInput(format!("Failed to extract fantome: {}", e)));
+        }
+    };
+    let mod_project = extracted.mod_project;
+
+    let mut project = Project {
+        name: mod_project.name,
+        display_name: mod_project.display_name,
+        version: mod_project.version,
+        description: mod_project.description,
+        layers: mod_project.layers,
+        authors: mod_project
+            .authors
+            .into_iter()
+            .map(ProjectAuthor::from)
+            .filter(|author| !author.name.is_empty())
+            .collect(),
+        license: mod_project.license.map(Into::into),
+        // ltk_fantome converts META/image.png but doesn't record it
+        thumbnail: project_path.join(THUMBNAIL_FILE).is_file().then(|| THUMBNAIL_FILE.to_string()),
+        champion: String::new(),
+        target_kind: TargetKind::Champion,
+        skin_id: 0,
+        extra_targets: Vec::new(),
+        league_path: league_path.map(Path::to_path_buf),
+        game_version: league_path.and_then(detect_game_version),
+        variables: BTreeMap::new(),
+        collapse_variables: false,
+        disabled_layers: BTreeSet::new(),
+        repath: RepathOptions::default(),
+        project_path: project_path.clone(),
+        created_at: Utc::now(),
+        modified_at: Utc::now(),
+    };
+
+    match infer_champion_and_skin(&project.assets_path()) {
+        Some((champion, skin_id)) => {
+            tracing::info!("Imported mod targets {} skin {}", champion, skin_id);
+            project.champion = champion;
+            project.skin_id = skin_id;
+        }
+        None => tracing::warn!("Couldn't tell which champion the imported mod is for"),
+    }
+
+    fs::create_dir_all(project.output_path()).map_err(|e| Error::io_with_path(e, project.output_path()))?;
+    save_project(&project)?;
+
+    Ok(project)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::project::open_project;
+    use ltk_fantome::pack_to_fantome;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_fantome_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut source = Project::new("Star Ahri", "Ahri", 3, dir.path(), dir.path().join("src"), Some("me".into()));
+        source.version = "1.2.0".to_string();
+        let skins = source.assets_path().join("ahri.wad.client/data/characters/ahri/skins");
+        fs::create_dir_all(&skins).unwrap();
+        fs::write(skins.join("skin3.bin"), b"PROP").unwrap();
+        fs::write(skins.join("skin7.bin"), b"PROP").unwrap();
+        image::RgbaImage::new(4, 4).save(source.project_path.join("cover.png")).unwrap();
+        source.thumbnail = Some("cover.png".to_string());
+
+        let fantome: PathBuf = dir.path().join("star.fantome");
+        pack_to_fantome(File::create(&fantome).unwrap(), &source.to_mod_project(), &source.project_path).unwrap();
+
+        let output = dir.path().join("projects");
+        let imported = import_fantome(&fantome, &output, None, None).unwrap();
+        assert_eq!(imported.project_path, output.join("Star Ahri"));
+        assert_eq!((imported.champion.as_str(), imported.skin_id), ("ahri", 3));
+
+        let reopened = open_project(&imported.project_path).unwrap();
+        assert_eq!(reopened.display_name, "Star Ahri");
+        assert_eq!(reopened.version, "1.2.0");
+        assert_eq!(reopened.authors, vec![ProjectAuthor::new("me")]);
+        assert_eq!(reopened.champion, "ahri");
+        assert_eq!(reopened.thumbnail.as_deref(), Some(THUMBNAIL_FILE));
+        assert!(reopened.assets_path().join("ahri.wad.client/data/characters/ahri/skins/skin7.bin").exists());
+
+        // A second import of the same mod must not clobber the first
+        assert!(import_fantome(&fantome, &output, None, None).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+// Import module exports
+pub mod fantome;
+pub mod raw;
+
+pub use fantome::import_fantome;
+pub use raw::{import_raw_mod, RawImportResult};
@@ -0,0 +1,375 @@
+//! Turning loose `ASSETS`/`DATA` folder mods into Flint projects
+//!
+//! Older mods were often shared as a bare dump of the game folders instead of
+//! a packaged `.fantome`. Such a dump is copied into a WAD folder under
+//! `content/base`, lowercased so it lines up with extracted game files, and the
+//! champion is read back from the paths. Mods that were repathed under an
+//! `ASSETS/{prefix}/` folder can have that prefix taken off again.
+
+use crate::core::bin::ltk_bridge::HashMapProvider;
+use crate::core::bin::search::{replace_in_bins, BinSearchMode};
+use crate::core::league::detect_game_version;
+use crate::core::project::project::sanitize_filename;
+use crate::core::project::{save_project, Project};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Folders that sit directly under `assets/` in the game's own paths
+///
+/// Anything between `assets/` and the first of these is a repath prefix.
+pub(crate) const GAME_ROOTS: &[&str] = &[
+    "characters",
+    "companions",
+    "gameplay",
+    "items",
+    "loadouts",
+    "loadscreen",
+    "maps",
+    "particles",
+    "perks",
+    "shared",
+    "sounds",
+    "spells",
+    "summonericons",
+    "ux",
+];
+
+/// How the files of a raw mod are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum RawModLayout {
+    /// `ASSETS/`, `DATA/` and similar folders with no WAD around them
+    Loose,
+    /// Already split into `{name}.wad.client` folders
+    WadFolders,
+}
+
+/// Outcome of [`import_raw_mod`]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RawImportResult {
+    pub project: Project,
+    pub layout: RawModLayout,
+    pub files_copied: usize,
+    /// Repath prefix found under `assets/`, e.g. `bob/ahrimod`
+    pub detected_prefix: Option<String>,
+    /// Whether the prefix was taken off the files and BIN references
+    pub prefix_removed: bool,
+    /// BIN strings that were pointed back at the un-prefixed paths
+    pub references_restored: usize,
+    pub warnings: Vec<String>,
+}
+
+fn is_game_folder(name: &str) -> bool {
+    matches!(name, "assets" | "data" | "levels")
+}
+
+fn is_wad_folder(name: &str) -> bool {
+    name.ends_with(".wad.client") || name.ends_with(".wad")
+}
+
+/// Finds the folder holding the mod and how it is laid out
+///
+/// Archives often unpack into one extra folder named after the mod, so a
+/// single subfolder is looked into as well.
+fn detect_layout(folder: &Path) -> Option<(PathBuf, RawModLayout)> {
+    let classify = |dir: &Path| {
+        let names: Vec<String> = fs::read_dir(dir)
+            .ok()?
+            .flatten()
+            .filter(|e| e.path().is_dir())
+            .map(|e| e.file_name().to_string_lossy().to_lowercase())
+            .collect();
+        if names.iter().any(|n| is_wad_folder(n)) {
+            Some(RawModLayout::WadFolders)
+        } else if names.iter().any(|n| is_game_folder(n)) {
+            Some(RawModLayout::Loose)
+        } else {
+            None
+        }
+    };
+
+    if let Some(layout) = classify(folder) {
+        return Some((folder.to_path_buf(), layout));
+    }
+    let subdirs: Vec<PathBuf> = fs::read_dir(folder)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    match subdirs.as_slice() {
+        [only] => classify(only).map(|layout| (only.clone(), layout)),
+        _ => None,
+    }
+}
+
+/// Finds the most common repath prefix among `assets/` paths
+fn detect_prefix<'a>(paths: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for path in paths {
+        let Some(rest) = path.strip_prefix("assets/") else {
+            continue;
+        };
+        let segments: Vec<&str> = rest.split('/').collect();
+        let root = segments[..segments.len() - 1]
+            .iter()
+            .position(|s| GAME_ROOTS.contains(s));
+        if let Some(index) = root.filter(|&i| i > 0) {
+            *counts.entry(segments[..index].join("/")).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+        .map(|(prefix, _)| prefix)
+}
+
+/// Infers the champion and skin from lowercased mod paths
+///
+/// Skin BINs (`data/characters/{champion}/skins/skin{N}.bin`) decide it when
+/// present; otherwise the champion with the most files under
+/// `characters/{champion}/` is taken, for skin 0.
+fn infer_from_paths<'a>(paths: impl Iterator<Item = &'a str> + Clone) -> Option<(String, u32)> {
+    let from_bins = paths
+        .clone()
+        .filter_map(|path| {
+            let rest = path.strip_prefix("data/characters/")?;
+            let (champion, file) = rest.split_once("/skins/")?;
+            let skin_id = file.strip_prefix("skin")?.strip_suffix(".bin")?.parse::<u32>().ok()?;
+            (!champion.contains('/')).then(|| (champion.to_string(), skin_id))
+        })
+        .min_by_key(|(_, skin_id)| *skin_id);
+    if from_bins.is_some() {
+        return from_bins;
+    }
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for path in paths {
+        let rest = path.strip_prefix("assets/").or_else(|| path.strip_prefix("data/")).unwrap_or(path);
+        if let Some((champion, _)) = rest.strip_prefix("characters/").and_then(|r| r.split_once('/')) {
+            *counts.entry(champion).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(champion, _)| (champion.to_string(), 0))
+}
+
+/// Creates a new project from a loose folder mod
+///
+/// # Arguments
+/// * `folder` - Folder holding `ASSETS`/`DATA` (or `.wad.client` folders)
+/// * `output_dir` - Directory where the project folder will be created
+/// * `name` - Project name; the folder name when not given
+/// * `league_path` - League installation to link the project to, if known
+/// * `unrepath` - Move files out of a detected `ASSETS/{prefix}/` folder and
+///   point BIN references back at the game paths. Champion folders a repath
+///   renamed are left as they are.
+pub fn import_raw_mod(
+    folder: &Path,
+    output_dir: &Path,
+    name: Option<&str>,
+    league_path: Option<&Path>,
+    unrepath: bool,
+) -> Result<RawImportResult> {
+    let (root, layout) = detect_layout(folder).ok_or_else(|| {
+        Error::InvalidInput(format!(
+            "No ASSETS, DATA or .wad.client folders found in: {}",
+            folder.display()
+        ))
+    })?;
+
+    let name = name
+        .map(str::to_string)
+        .or_else(|| root.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "Imported Mod".to_string());
+    let project_path = output_dir.join(sanitize_filename(&name));
+    if project_path.exists() {
+        return Err(Error::InvalidInput(format!(
+            "Project already exists at: {}",
+            project_path.display()
+        )));
+    }
+
+    // (source file, lowercased path relative to the mod root)
+    let files: Vec<(PathBuf, String)> = WalkDir::new(&root)
+        .min_depth(2)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let relative = e.path().strip_prefix(&root).ok()?.to_string_lossy().replace('\\', "/").to_lowercase();
+            let top = relative.split('/').next()?;
+            let wanted = match layout {
+                RawModLayout::Loose => is_game_folder(top) || top == "ux",
+                RawModLayout::WadFolders => is_wad_folder(top),
+            };
+            wanted.then(|| (e.path().to_path_buf(), relative))
+        })
+        .collect();
+    if files.is_empty() {
+        return Err(Error::InvalidInput(format!("No mod files found in: {}", root.display())));
+    }
+
+    let mut warnings = Vec::new();
+    let wad_relative = |relative: &str| match layout {
+        RawModLayout::Loose => relative.to_string(),
+        RawModLayout::WadFolders => relative.split_once('/').map(|(_, rest)| rest.to_string()).unwrap_or_default(),
+    };
+    let wad_paths: Vec<String> = files.iter().map(|(_, r)| wad_relative(r)).collect();
+    let detected_prefix = detect_prefix(wad_paths.iter().map(String::as_str));
+    let strip = detected_prefix.as_ref().filter(|_| unrepath).map(|p| format!("assets/{}/", p));
+    let unprefixed = |relative: &str| -> String {
+        match strip.as_deref().and_then(|s| relative.strip_prefix(s)) {
+            Some(rest) => format!("assets/{}", rest),
+            None => relative.to_string(),
+        }
+    };
+
+    let game_paths: Vec<String> = wad_paths.iter().map(|p| unprefixed(p)).collect();
+    let (champion, skin_id) = match infer_from_paths(game_paths.iter().map(String::as_str)) {
+        Some(found) => found,
+        None => {
+            warnings.push("Couldn't tell which champion the mod is for".to_string());
+            (String::new(), 0)
+        }
+    };
+
+    let mut project = Project::new(&name, &champion, skin_id, PathBuf::new(), &project_path, None);
+    project.league_path = league_path.map(Path::to_path_buf);
+    project.game_version = league_path.and_then(detect_game_version);
+    let content_base = project.assets_path();
+    let loose_wad = if champion.is_empty() {
+        format!("{}.wad.client", project.name)
+    } else {
+        format!("{}.wad.client", champion)
+    };
+
+    tracing::info!("Importing raw mod {} into {}", root.display(), project_path.display());
+    let mut copied: HashMap<PathBuf, &Path> = HashMap::new();
+    let copy_result = files.iter().try_for_each(|(source, relative)| {
+        let target = match layout {
+            RawModLayout::Loose => content_base.join(&loose_wad).join(unprefixed(relative)),
+            RawModLayout::WadFolders => {
+                let (wad, rest) = relative.split_once('/').unwrap_or((relative.as_str(), ""));
+                content_base.join(wad).join(unprefixed(rest))
+            }
+        };
+        if let Some(first) = copied.get(&target) {
+            warnings.push(format!("{} clashes with {}; kept the first", source.display(), first.display()));
+            return Ok(());
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        fs::copy(source, &target).map_err(|e| Error::io_with_path(e, source))?;
+        copied.insert(target, source);
+        Ok(())
+    });
+    if let Err(e) = copy_result {
+        if let Err(cleanup_err) = fs::remove_dir_all(&project_path) {
+            tracing::error!("Failed to clean up project directory: {}", cleanup_err);
+        }
+        return Err(e);
+    }
+
+    let mut references_restored = 0;
+    if let Some(prefix) = detected_prefix.as_ref().filter(|_| unrepath) {
+        let pattern = format!(r"\bassets/{}/", regex::escape(prefix));
+        let replaced = replace_in_bins(
+            &content_base,
+            &pattern,
+            "assets/",
+            BinSearchMode::Regex,
+            false,
+            false,
+            &HashMapProvider::new(),
+        )?;
+        references_restored = replaced.replacements.len();
+        warnings.extend(replaced.files_skipped);
+    }
+
+    fs::create_dir_all(project.output_path()).map_err(|e| Error::io_with_path(e, project.output_path()))?;
+    save_project(&project)?;
+
+    Ok(RawImportResult {
+        project,
+        layout,
+        files_copied: copied.len(),
+        prefix_removed: strip.is_some(),
+        detected_prefix,
+        references_restored,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{read_bin, text_to_tree, tree_to_text, write_bin};
+    use crate::core::project::open_project;
+
+    const SKIN: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin2" = SkinCharacterDataProperties {
+        skinMeshProperties: embed = SkinMeshDataProperties {
+            texture: string = "ASSETS/bob/ahrimod/Characters/Ahri/Skins/Skin2/Ahri_TX_CM.dds"
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_import_raw_mod() {
+        let dir = tempfile::tempdir().unwrap();
+        // Unpacked from an archive, so the dump sits one folder down
+        let mod_root = dir.path().join("download").join("Old Ahri");
+        let textures = mod_root.join("ASSETS/bob/ahrimod/Characters/Ahri/Skins/Skin2");
+        let skins = mod_root.join("DATA/Characters/Ahri/Skins");
+        fs::create_dir_all(&textures).unwrap();
+        fs::create_dir_all(&skins).unwrap();
+        fs::write(textures.join("Ahri_TX_CM.dds"), b"DDS ").unwrap();
+        fs::write(skins.join("Skin2.bin"), write_bin(&text_to_tree(SKIN).unwrap()).unwrap()).unwrap();
+        fs::write(mod_root.join("readme.txt"), b"install with cslol").unwrap();
+
+        let output = dir.path().join("projects");
+        let result = import_raw_mod(&dir.path().join("download"), &output, None, None, true).unwrap();
+        assert_eq!(result.layout, RawModLayout::Loose);
+        assert_eq!(result.files_copied, 2);
+        assert_eq!(result.detected_prefix.as_deref(), Some("bob/ahrimod"));
+        assert!(result.prefix_removed);
+        assert_eq!(result.references_restored, 1);
+
+        let project = open_project(&result.project.project_path).unwrap();
+        assert_eq!(project.project_path, output.join("Old Ahri"));
+        assert_eq!((project.champion.as_str(), project.skin_id), ("ahri", 2));
+        let wad = project.assets_path().join("ahri.wad.client");
+        assert!(wad.join("assets/characters/ahri/skins/skin2/ahri_tx_cm.dds").exists());
+        let text = tree_to_text(&read_bin(&fs::read(wad.join("data/characters/ahri/skins/skin2.bin")).unwrap()).unwrap()).unwrap();
+        assert!(text.contains("\"assets/Characters/Ahri/Skins/Skin2/Ahri_TX_CM.dds\""));
+
+        // Without unrepath the prefix is only reported
+        let kept = import_raw_mod(&mod_root, &output, Some("Kept"), None, false).unwrap();
+        assert_eq!(kept.detected_prefix.as_deref(), Some("bob/ahrimod"));
+        assert!(!kept.prefix_removed);
+        assert!(kept
+            .project
+            .assets_path()
+            .join("ahri.wad.client/assets/bob/ahrimod/characters/ahri/skins/skin2/ahri_tx_cm.dds")
+            .exists());
+
+        assert!(import_raw_mod(&mod_root, &output, None, None, true).is_err());
+        assert!(import_raw_mod(&textures, &output, Some("Nothing"), None, true).is_err());
+    }
+}
@@ -1,16 +1,25 @@
 //! League of Legends installation detection
 //!
 //! This module provides functionality to automatically detect and validate
-//! League of Legends installations. Uses ltk_mod_core for detection.
+//! League of Legends installations. Detection goes through
+//! [`super::installs`], which ranks every install it can find.
 
+use crate::core::league::guard::check_game_data_readable;
+use crate::core::league::installs::find_league_installs;
 use crate::error::{Error, Result};
-use ltk_mod_core::{auto_detect_league_path, is_valid_league_path};
+use ltk_mod_core::is_valid_league_path;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use ts_rs::TS;
 
-/// Files that should exist in a valid League installation
+/// Files of which at least one should exist in a valid League installation
+///
+/// Riot installs keep the client at the root; Tencent and Garena installs
+/// keep it in a `LeagueClient` folder, or ship only the game.
 const REQUIRED_FILES: &[&str] = &[
     "LeagueClient.exe",
+    "LeagueClient/LeagueClient.exe",
+    "Game/League of Legends.exe",
 ];
 
 /// Directories that should exist in a valid League installation
@@ -19,7 +28,8 @@ const REQUIRED_DIRS: &[&str] = &[
 ];
 
 /// Represents a detected League of Legends installation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct LeagueInstallation {
     /// Path to the League of Legends installation directory
     pub path: PathBuf,
@@ -54,35 +64,28 @@ impl LeagueInstallation {
 }
 
 /// Attempts to detect a League of Legends installation automatically
-/// using ltk_mod_core detection methods.
 ///
-/// Detection order (via ltk_mod_core):
-/// 1. RiotClientInstalls.json
-/// 2. Running League processes
-/// 3. Common installation paths
-/// 4. Windows Registry
+/// Picks the best valid install found by [`find_league_installs`].
 ///
 /// # Returns
 /// * `Ok(LeagueInstallation)` - If a valid installation was found
 /// * `Err(Error)` - If no valid installation was found
 pub fn detect_league_installation() -> Result<LeagueInstallation> {
-    tracing::info!("Attempting to detect League of Legends installation via ltk_mod_core");
+    tracing::info!("Attempting to detect League of Legends installation");
 
-    if let Some(exe_path) = auto_detect_league_path() {
-        tracing::info!("ltk_mod_core found League at: {}", exe_path);
-        
-        // ltk_mod_core returns path to Game/League of Legends.exe
-        // Navigate up to installation root
-        if let Some(game_path) = exe_path.parent() {
-            if let Some(root_path) = game_path.parent() {
-                let root_buf = PathBuf::from(root_path.as_str());
-                tracing::info!("League installation root: {}", root_buf.display());
-                return Ok(LeagueInstallation::new(root_buf, true));
-            }
-        }
+    let candidates = find_league_installs();
+    if let Some(best) = candidates.iter().find(|candidate| candidate.valid) {
+        tracing::info!("League installation root: {} ({:?})", best.path.display(), best.source);
+        return Ok(LeagueInstallation::new(best.path.clone(), true));
+    }
+
+    // A folder that exists but fails validation explains more than "not found"
+    if let Some(problem) = candidates.iter().find_map(|candidate| candidate.problem.clone()) {
+        tracing::warn!("League installation found but unusable: {}", problem);
+        return Err(Error::InvalidInput(problem));
     }
 
-    tracing::warn!("No League of Legends installation found via ltk_mod_core");
+    tracing::warn!("No League of Legends installation found");
     Err(Error::InvalidInput(
         "Could not detect League of Legends installation. Please specify the path manually.".to_string()
     ))
@@ -113,15 +116,12 @@ fn validate_and_create(path: &Path, auto_detected: bool) -> Result<LeagueInstall
     }
 
     // Check required files
-    for file in REQUIRED_FILES {
-        let file_path = path.join(file);
-        if !file_path.exists() {
-            return Err(Error::InvalidInput(format!(
-                "Required file not found: {} (expected at {})",
-                file,
-                file_path.display()
-            )));
-        }
+    if !REQUIRED_FILES.iter().any(|file| path.join(file).exists()) {
+        return Err(Error::InvalidInput(format!(
+            "Required file not found: expected one of {} in {}",
+            REQUIRED_FILES.join(", "),
+            path.display()
+        )));
     }
 
     // Check required directories
@@ -136,6 +136,9 @@ fn validate_and_create(path: &Path, auto_detected: bool) -> Result<LeagueInstall
         }
     }
 
+    // Game files are read-only for Flint, but they must at least be readable
+    check_game_data_readable(path)?;
+
     // Also validate with ltk_mod_core if the exe exists
     let exe_path = path.join("Game").join("League of Legends.exe");
     if exe_path.exists() {
@@ -150,6 +153,80 @@ fn validate_and_create(path: &Path, auto_detected: bool) -> Result<LeagueInstall
     Ok(LeagueInstallation::new(path.to_path_buf(), auto_detected))
 }
 
+/// Reads the game client version from Game/content-metadata.json, falling
+/// back to the file version of `Game/League of Legends.exe`
+///
+/// # Arguments
+/// * `league_path` - Path to the League installation root
+///
+/// # Returns
+/// * `Some(version)` - e.g. "14.23.636.7270" if either source is readable
+/// * `None` - If both are missing or malformed
+pub fn detect_game_version(league_path: impl AsRef<Path>) -> Option<String> {
+    let game_path = league_path.as_ref().join("Game");
+    content_metadata_version(&game_path.join("content-metadata.json"))
+        .or_else(|| exe_file_version(&game_path.join("League of Legends.exe")))
+}
+
+/// Version from `content-metadata.json`, which Riot's patcher writes
+fn content_metadata_version(metadata_path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(metadata_path).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    // Version strings look like "14.23.636.7270+branch.releases-14-23..."
+    let version = json.get("version")?.as_str()?;
+    let version = version.split('+').next().unwrap_or(version).trim();
+
+    if version.is_empty() {
+        None
+    } else {
+        tracing::debug!("Detected game version {} from {}", version, metadata_path.display());
+        Some(version.to_string())
+    }
+}
+
+/// Signature of the `VS_FIXEDFILEINFO` block in a PE version resource
+const FIXED_FILE_INFO_SIGNATURE: [u8; 4] = 0xFEEF04BDu32.to_le_bytes();
+/// `dwStrucVersion` of every `VS_FIXEDFILEINFO` written since Windows 3.1
+const FIXED_FILE_INFO_VERSION: u32 = 0x0001_0000;
+
+/// File version from the version resource of the game executable
+///
+/// Installs patched by third-party launchers (Garena, Tencent) don't always
+/// ship `content-metadata.json`, but the executable is always stamped.
+fn exe_file_version(exe_path: &Path) -> Option<String> {
+    let data = std::fs::read(exe_path).ok()?;
+    let version = fixed_file_version(&data)?;
+    tracing::debug!("Detected game version {} from {}", version, exe_path.display());
+    Some(version)
+}
+
+/// Reads `dwFileVersionMS`/`dwFileVersionLS` from the `VS_FIXEDFILEINFO` in `data`
+///
+/// Resources sit at the end of the image, so the search runs backwards.
+fn fixed_file_version(data: &[u8]) -> Option<String> {
+    let read_u32 = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    data.windows(4)
+        .enumerate()
+        .rev()
+        .filter(|(_, window)| *window == FIXED_FILE_INFO_SIGNATURE)
+        .find_map(|(offset, _)| {
+            if read_u32(offset + 4)? != FIXED_FILE_INFO_VERSION {
+                return None;
+            }
+            let high = read_u32(offset + 8)?;
+            let low = read_u32(offset + 12)?;
+            if high == 0 && low == 0 {
+                return None;
+            }
+            Some(format!("{}.{}.{}.{}", high >> 16, high & 0xFFFF, low >> 16, low & 0xFFFF))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,4 +270,37 @@ mod tests {
         assert!(!REQUIRED_FILES.is_empty());
         assert!(REQUIRED_FILES.contains(&"LeagueClient.exe"));
     }
+
+    #[test]
+    fn test_detect_game_version() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(detect_game_version(dir.path()), None);
+
+        std::fs::create_dir_all(dir.path().join("Game")).unwrap();
+        std::fs::write(
+            dir.path().join("Game").join("content-metadata.json"),
+            r#"{"version": "14.23.636.7270+branch.releases-14-23.code.public"}"#,
+        ).unwrap();
+        assert_eq!(detect_game_version(dir.path()).as_deref(), Some("14.23.636.7270"));
+    }
+
+    #[test]
+    fn test_detect_game_version_from_exe() {
+        let mut exe = b"MZ\0\0VS_VERSION_INFO".to_vec();
+        // A stray signature with the wrong structure version is skipped
+        exe.extend_from_slice(&FIXED_FILE_INFO_SIGNATURE);
+        exe.extend_from_slice(&[0; 12]);
+        exe.extend_from_slice(&FIXED_FILE_INFO_SIGNATURE);
+        exe.extend_from_slice(&FIXED_FILE_INFO_VERSION.to_le_bytes());
+        exe.extend_from_slice(&((14u32 << 16) | 23).to_le_bytes());
+        exe.extend_from_slice(&((636u32 << 16) | 7270).to_le_bytes());
+        exe.extend_from_slice(&[0; 40]);
+        assert_eq!(fixed_file_version(&exe).as_deref(), Some("14.23.636.7270"));
+        assert_eq!(fixed_file_version(&exe[..20]), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("Game")).unwrap();
+        std::fs::write(dir.path().join("Game").join("League of Legends.exe"), &exe).unwrap();
+        assert_eq!(detect_game_version(dir.path()).as_deref(), Some("14.23.636.7270"));
+    }
 }
@@ -0,0 +1,106 @@
+//! Permission guards for League installation directories
+//!
+//! League is often installed under Program Files, where normal users can read
+//! but not write. Flint only ever reads game files; these helpers make that
+//! explicit by rejecting output paths inside an installation up front and by
+//! turning permission failures into messages that say what to do.
+
+use crate::error::{Error, Result};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Returns true if `dir` is a League installation root or its Game directory
+fn is_league_dir(dir: &Path) -> bool {
+    dir.join("Game").join("DATA").join("FINAL").is_dir()
+        || (dir.join("DATA").join("FINAL").is_dir() && dir.join("League of Legends.exe").exists())
+}
+
+/// Finds the League installation (or Game directory) containing `path`
+///
+/// Works for paths that don't exist yet by checking their existing ancestors.
+pub fn find_league_root(path: &Path) -> Option<PathBuf> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(path)
+    };
+
+    absolute
+        .ancestors()
+        .find(|dir| is_league_dir(dir))
+        .map(Path::to_path_buf)
+}
+
+/// Rejects output paths inside a League installation
+///
+/// # Arguments
+/// * `path` - File or directory Flint is about to write
+pub fn ensure_outside_league(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    match find_league_root(path) {
+        Some(root) => Err(Error::InvalidInput(format!(
+            "Refusing to write to {}: it is inside the League installation ({}). \
+             Flint never modifies game files; choose an output folder outside the game directory, \
+             such as Documents.",
+            path.display(),
+            root.display()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the game data directory of an installation can be read
+///
+/// # Arguments
+/// * `league_path` - League installation root
+pub fn check_game_data_readable(league_path: &Path) -> Result<()> {
+    let final_dir = league_path.join("Game").join("DATA").join("FINAL");
+
+    match std::fs::read_dir(&final_dir) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => Err(Error::InvalidInput(format!(
+            "Permission denied reading game data at {}. Make sure your user account can read the \
+             League folder (right-click > Properties > Security), or that antivirus software isn't \
+             blocking Flint.",
+            final_dir.display()
+        ))),
+        Err(e) if e.kind() == ErrorKind::NotFound => Err(Error::InvalidInput(format!(
+            "Game data folder not found at {}. The installation may be incomplete; \
+             run the Riot Client to repair it.",
+            final_dir.display()
+        ))),
+        Err(e) => Err(Error::io_with_path(e, &final_dir)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::league::test_util::fake_install;
+
+    #[test]
+    fn test_ensure_outside_league() {
+        let install = tempfile::tempdir().unwrap();
+        fake_install(install.path(), "LeagueClient.exe", None);
+        let root = install.path();
+
+        assert_eq!(find_league_root(&root.join("Game/DATA/FINAL/Champions/Ahri.wad.client")), Some(root.to_path_buf()));
+        assert!(ensure_outside_league(root.join("Game/DATA/FINAL/out")).is_err());
+        // Not-yet-existing folders directly under the root count too
+        assert!(ensure_outside_league(root.join("mods/new")).is_err());
+
+        let elsewhere = tempfile::tempdir().unwrap();
+        assert!(ensure_outside_league(elsewhere.path().join("project")).is_ok());
+    }
+
+    #[test]
+    fn test_check_game_data_readable() {
+        let install = tempfile::tempdir().unwrap();
+        fake_install(install.path(), "LeagueClient.exe", None);
+        assert!(check_game_data_readable(install.path()).is_ok());
+
+        let empty = tempfile::tempdir().unwrap();
+        let err = check_game_data_readable(empty.path()).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+}
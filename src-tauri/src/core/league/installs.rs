@@ -0,0 +1,310 @@
+//! League of Legends install discovery
+//!
+//! Installs are found through several independent sources: the Riot
+//! Client's `RiotClientInstalls.json` and product settings, the Windows
+//! uninstall registry keys, a running game (via ltk_mod_core), and the
+//! usual Riot, Garena and Tencent (WeGame) folders on every drive. Each
+//! candidate is validated and ranked, so a broken or outdated install never
+//! hides a working one.
+
+use super::detector::{detect_game_version, validate_league_path};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+
+/// Install folders relative to a drive root or a home directory
+const COMMON_FOLDERS: &[(&str, InstallSource)] = &[
+    ("Riot Games/League of Legends", InstallSource::CommonPath),
+    ("Program Files/Riot Games/League of Legends", InstallSource::CommonPath),
+    ("Program Files (x86)/Riot Games/League of Legends", InstallSource::CommonPath),
+    ("Games/Riot Games/League of Legends", InstallSource::CommonPath),
+    ("Games/League of Legends", InstallSource::CommonPath),
+    ("Garena/Games/32775", InstallSource::Garena),
+    ("Program Files (x86)/Garena/Games/32775", InstallSource::Garena),
+    ("Program Files/Garena/Games/32775", InstallSource::Garena),
+    ("WeGameApps/英雄联盟", InstallSource::Tencent),
+    ("Program Files (x86)/Tencent/英雄联盟", InstallSource::Tencent),
+    ("Program Files/Tencent/英雄联盟", InstallSource::Tencent),
+    ("腾讯游戏/英雄联盟", InstallSource::Tencent),
+];
+
+/// Where an install was found; earlier variants rank higher
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallSource {
+    /// `RiotClientInstalls.json` or the Riot Client's product settings
+    RiotClient,
+    /// A running game client
+    Running,
+    /// Windows uninstall registry entry
+    Registry,
+    /// Garena's game folder
+    Garena,
+    /// Tencent (WeGame) install for the Chinese servers
+    Tencent,
+    /// A usual install folder on one of the drives
+    CommonPath,
+}
+
+/// A possible League install
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct InstallCandidate {
+    /// Install root, the folder holding `Game`
+    pub path: PathBuf,
+    pub source: InstallSource,
+    /// Game version from `Game/content-metadata.json` or the game executable
+    pub version: Option<String>,
+    /// Whether the folder passes the same checks as a manually set path
+    pub valid: bool,
+    /// Why the folder isn't valid
+    pub problem: Option<String>,
+}
+
+/// Install root for a path that may point at the `Game` folder or the game executable
+fn install_root(path: &Path) -> PathBuf {
+    let path = if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("exe")) {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+    let is_game_dir = path.file_name().is_some_and(|name| name.eq_ignore_ascii_case("Game"))
+        && path.join("League of Legends.exe").is_file();
+    match path.parent() {
+        Some(parent) if is_game_dir => parent.to_path_buf(),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// League install folders listed in `RiotClientInstalls.json`
+///
+/// `associated_client` maps each product's install folder to the Riot
+/// Client that manages it.
+pub fn installs_from_riot_client_json(content: &str) -> Vec<PathBuf> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+    json.get("associated_client")
+        .and_then(|clients| clients.as_object())
+        .map(|clients| {
+            clients
+                .keys()
+                .filter(|path| path.to_lowercase().contains("league of legends") || path.contains("英雄联盟"))
+                .map(|path| PathBuf::from(path.trim_end_matches(['/', '\\'])))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Install folder from a Riot Client `*.product_settings.yaml`
+pub fn install_from_product_settings(content: &str) -> Option<PathBuf> {
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("product_install_full_path:")?;
+        let value = value.trim().trim_matches(['"', '\'']);
+        (!value.is_empty()).then(|| PathBuf::from(value))
+    })
+}
+
+/// Folder the Riot Client keeps its shared files in
+fn riot_data_dir() -> PathBuf {
+    if cfg!(target_os = "macos") {
+        PathBuf::from("/Users/Shared/Riot Games")
+    } else {
+        let program_data = std::env::var_os("ProgramData").unwrap_or_else(|| "C:\\ProgramData".into());
+        PathBuf::from(program_data).join("Riot Games")
+    }
+}
+
+fn riot_client_installs(riot_dir: &Path) -> Vec<PathBuf> {
+    let mut installs = fs::read_to_string(riot_dir.join("RiotClientInstalls.json"))
+        .map(|content| installs_from_riot_client_json(&content))
+        .unwrap_or_default();
+
+    let metadata = riot_dir.join("Metadata");
+    for product in ["league_of_legends.live", "league_of_legends.pbe"] {
+        let settings = metadata.join(product).join(format!("{}.product_settings.yaml", product));
+        if let Some(path) = fs::read_to_string(settings).ok().and_then(|c| install_from_product_settings(&c)) {
+            installs.push(path);
+        }
+    }
+    installs
+}
+
+#[cfg(windows)]
+fn registry_installs() -> Vec<PathBuf> {
+    use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+    use winreg::RegKey;
+
+    const UNINSTALL: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall";
+    const KEYS: &[&str] = &["Riot Game league_of_legends.live", "Riot Game league_of_legends.pbe"];
+
+    let mut installs = Vec::new();
+    for hive in [HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE] {
+        for key in KEYS {
+            let location = RegKey::predef(hive)
+                .open_subkey(format!("{}\\{}", UNINSTALL, key))
+                .and_then(|key| key.get_value::<String, _>("InstallLocation"));
+            if let Ok(location) = location {
+                installs.push(PathBuf::from(location));
+            }
+        }
+    }
+    installs
+}
+
+#[cfg(not(windows))]
+fn registry_installs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// Roots the common folders are looked for under: every drive on Windows,
+/// the home folder elsewhere
+fn search_roots() -> Vec<PathBuf> {
+    if cfg!(windows) {
+        ('C'..='Z')
+            .map(|drive| PathBuf::from(format!("{}:\\", drive)))
+            .filter(|root| root.is_dir())
+            .collect()
+    } else {
+        let mut roots = vec![PathBuf::from("/Applications/League of Legends.app/Contents/LoL")];
+        if let Some(home) = std::env::var_os("HOME").map(PathBuf::from) {
+            // Wine prefixes set up by Lutris or by hand
+            roots.push(home.join("Games/league-of-legends/drive_c"));
+            roots.push(home.join(".wine/drive_c"));
+        }
+        roots
+    }
+}
+
+/// Existing common install folders under the given roots
+pub fn installs_in_roots(roots: &[PathBuf]) -> Vec<(PathBuf, InstallSource)> {
+    let mut installs = Vec::new();
+    for root in roots {
+        if root.join("Game").is_dir() {
+            installs.push((root.clone(), InstallSource::CommonPath));
+        }
+        for (folder, source) in COMMON_FOLDERS {
+            let path = root.join(folder);
+            if path.is_dir() {
+                installs.push((path, *source));
+            }
+        }
+    }
+    installs
+}
+
+/// Validates candidates, drops duplicates and puts the most likely install first
+///
+/// Valid installs come first, then those with a known game version, then by
+/// source. A folder found by several sources keeps its best-ranked one.
+pub fn rank_candidates(found: Vec<(PathBuf, InstallSource)>) -> Vec<InstallCandidate> {
+    let mut seen = HashSet::new();
+    let mut found: Vec<(PathBuf, InstallSource)> =
+        found.into_iter().map(|(path, source)| (install_root(&path), source)).collect();
+    found.sort_by_key(|(_, source)| *source);
+
+    let mut candidates: Vec<InstallCandidate> = found
+        .into_iter()
+        .filter(|(path, _)| {
+            let key = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+            seen.insert(key.to_string_lossy().to_lowercase())
+        })
+        .filter(|(path, _)| path.is_dir())
+        .map(|(path, source)| {
+            let problem = validate_league_path(&path).err().map(|e| e.to_string());
+            InstallCandidate {
+                version: detect_game_version(&path),
+                valid: problem.is_none(),
+                problem,
+                source,
+                path,
+            }
+        })
+        .collect();
+
+    candidates.sort_by_key(|candidate| (!candidate.valid, candidate.version.is_none(), candidate.source));
+    candidates
+}
+
+/// Looks for League installs through every known source
+///
+/// # Returns
+/// Every existing candidate folder, best first; see [`rank_candidates`]
+pub fn find_league_installs() -> Vec<InstallCandidate> {
+    let mut found: Vec<(PathBuf, InstallSource)> = riot_client_installs(&riot_data_dir())
+        .into_iter()
+        .map(|path| (path, InstallSource::RiotClient))
+        .collect();
+
+    if let Some(exe) = ltk_mod_core::auto_detect_league_path() {
+        found.push((PathBuf::from(exe.as_str()), InstallSource::Running));
+    }
+    found.extend(registry_installs().into_iter().map(|path| (path, InstallSource::Registry)));
+    found.extend(installs_in_roots(&search_roots()));
+
+    let candidates = rank_candidates(found);
+    tracing::info!(
+        "Found {} League install candidate(s), {} valid",
+        candidates.len(),
+        candidates.iter().filter(|c| c.valid).count()
+    );
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::league::test_util::fake_install;
+
+    #[test]
+    fn test_riot_client_sources() {
+        let json = r#"{
+            "associated_client": {
+                "C:/Riot Games/League of Legends/": "C:/Riot Games/Riot Client/RiotClientServices.exe",
+                "C:/Riot Games/VALORANT/live/": "C:/Riot Games/Riot Client/RiotClientServices.exe"
+            },
+            "rc_default": "C:/Riot Games/Riot Client/RiotClientServices.exe"
+        }"#;
+        assert_eq!(installs_from_riot_client_json(json), vec![PathBuf::from("C:/Riot Games/League of Legends")]);
+        assert!(installs_from_riot_client_json("not json").is_empty());
+
+        let yaml = "product_install_full_path: \"D:/Games/League of Legends\"\nshould_repair: false\n";
+        assert_eq!(install_from_product_settings(yaml), Some(PathBuf::from("D:/Games/League of Legends")));
+        assert_eq!(install_from_product_settings("product_install_full_path: \"\""), None);
+    }
+
+    #[test]
+    fn test_rank_candidates() {
+        let dir = tempfile::tempdir().unwrap();
+        let riot = dir.path().join("Riot Games/League of Legends");
+        let garena = dir.path().join("Garena/Games/32775");
+        let tencent = dir.path().join("WeGameApps/英雄联盟");
+        fake_install(&riot, "LeagueClient.exe", None);
+        fake_install(&garena, "Game/League of Legends.exe", Some("14.1.1"));
+        fake_install(&tencent, "LeagueClient/LeagueClient.exe", Some("14.2.1"));
+        let broken = dir.path().join("Games/League of Legends");
+        fs::create_dir_all(&broken).unwrap();
+
+        let mut found = installs_in_roots(&[dir.path().to_path_buf()]);
+        // The same install reached through its Game folder is listed once
+        found.push((garena.join("Game"), InstallSource::Registry));
+        found.push((dir.path().join("missing"), InstallSource::RiotClient));
+
+        let ranked = rank_candidates(found);
+        let order: Vec<_> = ranked.iter().map(|c| (c.path.clone(), c.source, c.valid)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (garena.clone(), InstallSource::Registry, true),
+                (tencent.clone(), InstallSource::Tencent, true),
+                (riot.clone(), InstallSource::CommonPath, true),
+                (broken.clone(), InstallSource::CommonPath, false),
+            ]
+        );
+        assert_eq!(ranked[0].version.as_deref(), Some("14.1.1"));
+        assert!(ranked[3].problem.is_some());
+    }
+}
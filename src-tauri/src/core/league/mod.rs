@@ -1,4 +1,9 @@
 // League detection module exports
 pub mod detector;
+pub mod guard;
+pub mod installs;
+#[cfg(test)]
+pub(crate) mod test_util;
 
-pub use detector::{detect_league_installation, validate_league_path, LeagueInstallation};
+pub use detector::{detect_game_version, detect_league_installation, validate_league_path, LeagueInstallation};
+pub use installs::{find_league_installs, InstallCandidate};
@@ -0,0 +1,20 @@
+//! League installation fixtures shared by tests
+
+use std::fs;
+use std::path::Path;
+
+/// Lays out a League installation at `path`
+///
+/// # Arguments
+/// * `client` - Client executable, relative to `path`
+/// * `version` - Game version written to the content metadata, if any
+pub(crate) fn fake_install(path: &Path, client: &str, version: Option<&str>) {
+    fs::create_dir_all(path.join("Game/DATA/FINAL/Champions")).unwrap();
+    let client = path.join(client);
+    fs::create_dir_all(client.parent().unwrap()).unwrap();
+    fs::write(client, b"").unwrap();
+    if let Some(version) = version {
+        let metadata = format!(r#"{{"version": "{}+branch"}}"#, version);
+        fs::write(path.join("Game/content-metadata.json"), metadata).unwrap();
+    }
+}
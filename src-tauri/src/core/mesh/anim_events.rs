@@ -0,0 +1,260 @@
+//! Animation event timeline
+//!
+//! Reads the event tracks of an `AtomicClipData` (sound, particle and other
+//! events keyed by frame) from an animation BIN and lays them out on a time
+//! axis, so the previewer can show when sounds fire during a clip.
+//!
+//! Sound events carry the Wwise event name and its short ID (FNV-1 of the
+//! lowercased name), which is what sound banks key events by.
+
+use crate::core::bin::ltk_bridge;
+use league_toolkit::hash::fnv1a::hash_lower;
+use ltk_meta::{BinTree, PropertyValueEnum};
+use ltk_meta::value::StructValue;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Event frames in animation BINs are authored at 30 fps
+const EVENT_FPS: f32 = 30.0;
+
+/// Kind of an animation event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+pub enum AnimationEventKind {
+    Sound,
+    Particle,
+    Other,
+}
+
+/// A single event on the clip timeline
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TimelineEvent {
+    pub kind: AnimationEventKind,
+    /// Sound event name, particle effect key, or event class hash for others
+    pub name: String,
+    pub start_frame: f32,
+    pub start_time: f32,
+    pub end_time: Option<f32>,
+    pub is_loop: bool,
+    /// Wwise short ID of the sound event (FNV-1 of the lowercased name)
+    pub wwise_event_id: Option<u32>,
+}
+
+/// Animation events of one clip, sorted by start time
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct AnimationAudioTimeline {
+    pub clip: String,
+    /// .anm path referenced by the clip
+    pub animation_path: Option<String>,
+    pub events: Vec<TimelineEvent>,
+}
+
+/// Computes a Wwise short ID (32-bit FNV-1 of the lowercased name)
+pub fn wwise_short_id(name: &str) -> u32 {
+    name.to_lowercase().bytes().fold(0x811c9dc5u32, |hash, byte| {
+        hash.wrapping_mul(0x01000193) ^ byte as u32
+    })
+}
+
+fn field<'a>(value: &'a StructValue, name: &str) -> Option<&'a PropertyValueEnum> {
+    value.properties.get(&hash_lower(name)).map(|p| &p.value)
+}
+
+fn as_struct(value: &PropertyValueEnum) -> Option<&StructValue> {
+    match value {
+        PropertyValueEnum::Struct(s) => Some(s),
+        PropertyValueEnum::Embedded(e) => Some(&e.0),
+        _ => None,
+    }
+}
+
+fn as_f32(value: Option<&PropertyValueEnum>) -> Option<f32> {
+    match value? {
+        PropertyValueEnum::F32(v) => Some(v.0),
+        _ => None,
+    }
+}
+
+fn animation_path(clip: &StructValue) -> Option<String> {
+    let resource = as_struct(field(clip, "mAnimationResourceData")?)?;
+    match field(resource, "mAnimationFilePath")? {
+        PropertyValueEnum::String(s) => Some(s.0.clone()),
+        _ => None,
+    }
+}
+
+fn read_event(event: &StructValue) -> TimelineEvent {
+    let start_frame = as_f32(field(event, "mStartFrame")).unwrap_or(0.0);
+    let end_time = as_f32(field(event, "mEndFrame"))
+        .filter(|end| *end > start_frame)
+        .map(|end| end / EVENT_FPS);
+    let is_loop = matches!(field(event, "mIsLoop"), Some(PropertyValueEnum::Bool(b)) if b.0);
+
+    let (kind, name) = if event.class_hash == hash_lower("SoundEventData") {
+        let name = match field(event, "mSoundName") {
+            Some(PropertyValueEnum::String(s)) => s.0.clone(),
+            _ => String::new(),
+        };
+        (AnimationEventKind::Sound, name)
+    } else if event.class_hash == hash_lower("ParticleEventData") {
+        let name = match field(event, "mEffectKey") {
+            Some(PropertyValueEnum::Hash(h)) => format!("{:#010x}", h.0),
+            Some(PropertyValueEnum::String(s)) => s.0.clone(),
+            _ => String::new(),
+        };
+        (AnimationEventKind::Particle, name)
+    } else {
+        (AnimationEventKind::Other, format!("{:#010x}", event.class_hash))
+    };
+
+    TimelineEvent {
+        wwise_event_id: (kind == AnimationEventKind::Sound && !name.is_empty())
+            .then(|| wwise_short_id(&name)),
+        kind,
+        name,
+        start_frame,
+        start_time: start_frame / EVENT_FPS,
+        end_time,
+        is_loop,
+    }
+}
+
+/// Returns true if a clip map entry matches the requested clip
+///
+/// Clips can be requested by clip name, .anm file stem (what the animation
+/// list shows) or the full .anm path.
+pub(crate) fn clip_matches(key_hash: u32, clip: &StructValue, requested: &str) -> bool {
+    if key_hash == hash_lower(requested) {
+        return true;
+    }
+    animation_path(clip).is_some_and(|path| {
+        let stem = Path::new(&path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        path.eq_ignore_ascii_case(requested) || stem == requested.to_lowercase()
+    })
+}
+
+/// Builds the event timeline of one clip from a parsed animation BIN
+pub fn timeline_from_tree(tree: &BinTree, clip_name: &str) -> Option<AnimationAudioTimeline> {
+    for object in tree.objects.values() {
+        let Some(PropertyValueEnum::Map(clips)) =
+            object.properties.get(&hash_lower("mClipDataMap")).map(|p| &p.value)
+        else {
+            continue;
+        };
+
+        for (key, value) in &clips.entries {
+            let key_hash = match &key.0 {
+                PropertyValueEnum::Hash(h) => h.0,
+                _ => continue,
+            };
+            let Some(clip) = as_struct(value) else { continue };
+            if !clip_matches(key_hash, clip, clip_name) {
+                continue;
+            }
+
+            let mut events: Vec<TimelineEvent> = match field(clip, "mEventDataMap") {
+                Some(PropertyValueEnum::Map(map)) => map
+                    .entries
+                    .values()
+                    .filter_map(as_struct)
+                    .map(read_event)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            events.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+
+            return Some(AnimationAudioTimeline {
+                clip: clip_name.to_string(),
+                animation_path: animation_path(clip),
+                events,
+            });
+        }
+    }
+
+    None
+}
+
+/// Reads an animation BIN and builds the event timeline of one clip
+///
+/// # Arguments
+/// * `bin_path` - Path to the animation BIN
+/// * `clip_name` - Clip name, .anm file stem, or .anm path
+pub fn get_animation_audio_timeline(bin_path: &Path, clip_name: &str) -> anyhow::Result<AnimationAudioTimeline> {
+    let data = fs::read(bin_path)?;
+    let tree = ltk_bridge::read_bin(&data)
+        .map_err(|e| anyhow::anyhow!("Failed to parse animation BIN: {}", e))?;
+
+    timeline_from_tree(&tree, clip_name)
+        .ok_or_else(|| anyhow::anyhow!("Clip '{}' not found in {}", clip_name, bin_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRAPH: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+entries: map[hash,embed] = {
+    "Characters/Ahri/Animations/Skin3" = animationGraphData {
+        mClipDataMap: map[hash,pointer] = {
+            "Dance" = AtomicClipData {
+                mEventDataMap: map[hash,pointer] = {
+                    "music" = SoundEventData {
+                        mStartFrame: f32 = 15
+                        mSoundName: string = "Play_sfx_Ahri_Dance3D_buffactivate"
+                        mIsLoop: bool = true
+                    }
+                    "fx" = ParticleEventData {
+                        mStartFrame: f32 = 3
+                        mEndFrame: f32 = 30
+                        mEffectKey: hash = 0x12345678
+                    }
+                }
+                mAnimationResourceData: embed = AnimationResourceData {
+                    mAnimationFilePath: string = "ASSETS/Characters/Ahri/Skins/Skin03/Animations/Ahri_Skin03_Dance.anm"
+                }
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_wwise_short_id() {
+        // Case-insensitive, standard FNV-1 offset basis and reference value
+        assert_eq!(wwise_short_id("Play"), wwise_short_id("play"));
+        assert_eq!(wwise_short_id(""), 0x811c9dc5);
+        assert_eq!(wwise_short_id("a"), 0x050c5d7e);
+    }
+
+    #[test]
+    fn test_timeline_from_tree() {
+        let tree = ltk_bridge::text_to_tree(GRAPH).unwrap();
+
+        let timeline = timeline_from_tree(&tree, "Dance").unwrap();
+        assert_eq!(timeline.events.len(), 2);
+
+        let fx = &timeline.events[0];
+        assert_eq!(fx.kind, AnimationEventKind::Particle);
+        assert_eq!(fx.start_time, 0.1);
+        assert_eq!(fx.end_time, Some(1.0));
+
+        let sound = &timeline.events[1];
+        assert_eq!(sound.kind, AnimationEventKind::Sound);
+        assert_eq!(sound.start_time, 0.5);
+        assert!(sound.is_loop);
+        assert_eq!(sound.wwise_event_id, Some(wwise_short_id("play_sfx_ahri_dance3d_buffactivate")));
+
+        // The animation list shows clips by .anm stem
+        assert!(timeline_from_tree(&tree, "ahri_skin03_dance").is_some());
+        assert!(timeline_from_tree(&tree, "Idle1").is_none());
+    }
+}
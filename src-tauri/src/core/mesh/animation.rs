@@ -1,5 +1,6 @@
 //! Animation BIN parsing and ANM file loading
 //! Discovers animation BINs from skin dependencies and loads ANM files.
+//! Animations can also be baked to per-frame skinning matrices for playback.
 
 use std::collections::HashMap;
 use std::fs::{self, File};
@@ -7,12 +8,16 @@ use std::io::BufReader;
 use std::path::{Path, PathBuf};
 
 use crate::core::bin::ltk_bridge;
+use crate::core::mesh::render::skinning_matrices;
+use crate::core::mesh::skl::SklData;
 use ltk_anim::{AnimationAsset, Animation};
 use ltk_meta::PropertyValueEnum;
 use serde::Serialize;
+use ts_rs::TS;
 
 /// Information about a single animation clip
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct AnimationClipInfo {
     /// Name/ID of the clip (from hash or derived from path)
     pub name: String,
@@ -23,14 +28,16 @@ pub struct AnimationClipInfo {
 }
 
 /// List of animations extracted from animation BIN file
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct AnimationList {
     /// All animation clips found
     pub clips: Vec<AnimationClipInfo>,
 }
 
 /// Parsed animation data from an ANM file
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
 pub struct AnimationData {
     pub duration: f32,
     pub fps: f32,
@@ -39,7 +46,8 @@ pub struct AnimationData {
 }
 
 /// Transform data for a single joint at a specific time
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct JointTransform {
     /// Rotation quaternion (x, y, z, w)
     pub rotation: [f32; 4],
@@ -50,7 +58,8 @@ pub struct JointTransform {
 }
 
 /// Animation pose containing all joint transforms at a specific time
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
 pub struct AnimationPose {
     /// Time in seconds
     pub time: f32,
@@ -58,6 +67,25 @@ pub struct AnimationPose {
     pub joints: HashMap<u32, JointTransform>,
 }
 
+/// Highest sample rate [`bake_animation`] accepts
+pub const MAX_BAKE_FPS: f32 = 120.0;
+
+/// An animation sampled at a fixed rate against a skeleton
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct BakedAnimation {
+    /// Samples per second
+    pub fps: f32,
+    pub duration: f32,
+    pub frame_count: usize,
+    /// Matrices per frame, the skeleton's bone count
+    pub joint_count: usize,
+    /// Skinning matrices (world * inverse bind) as column-major 4x4s, in
+    /// SKL bone order within a frame and frame after frame, so frame `f`
+    /// joint `j` starts at `(f * joint_count + j) * 16`
+    pub matrices: Vec<f32>,
+}
+
 /// Extract animation BIN path from skin BIN's dependencies list.
 /// Animation BINs are Type 2 (contain "/animations/" in path).
 pub fn extract_animation_graph_path(skin_bin_path: &Path) -> Option<PathBuf> {
@@ -327,16 +355,17 @@ pub fn parse_animation_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Animation
     })
 }
 
-/// Evaluate animation at a specific time and return joint poses
-/// 
-/// Returns a map of joint hash → (rotation, translation, scale) for all joints.
-pub fn evaluate_animation_at<P: AsRef<Path>>(path: P, time: f32) -> anyhow::Result<AnimationPose> {
+/// Load an ANM file for repeated evaluation
+pub fn load_animation_asset<P: AsRef<Path>>(path: P) -> anyhow::Result<AnimationAsset> {
     let file = File::open(path.as_ref())?;
     let mut reader = BufReader::new(file);
     
-    let asset = AnimationAsset::from_reader(&mut reader)
-        .map_err(|e| anyhow::anyhow!("Failed to parse ANM file: {:?}", e))?;
-    
+    AnimationAsset::from_reader(&mut reader)
+        .map_err(|e| anyhow::anyhow!("Failed to parse ANM file: {:?}", e))
+}
+
+/// Evaluate a loaded animation at a specific time
+pub fn evaluate_pose(asset: &AnimationAsset, time: f32) -> AnimationPose {
     // Evaluate at the given time - uses Animation trait's evaluate method
     let pose = asset.evaluate(time);
     
@@ -351,7 +380,50 @@ pub fn evaluate_animation_at<P: AsRef<Path>>(path: P, time: f32) -> anyhow::Resu
         })
         .collect();
     
-    Ok(AnimationPose { time, joints })
+    AnimationPose { time, joints }
+}
+
+/// Evaluate animation at a specific time and return joint poses
+/// 
+/// Returns a map of joint hash → (rotation, translation, scale) for all joints.
+pub fn evaluate_animation_at<P: AsRef<Path>>(path: P, time: f32) -> anyhow::Result<AnimationPose> {
+    let asset = load_animation_asset(path)?;
+    Ok(evaluate_pose(&asset, time))
+}
+
+/// Samples a loaded animation at a fixed rate and skins it against a skeleton
+///
+/// Frames are taken every `1 / fps` seconds from 0, with a last frame at the
+/// end of the clip. Joints the animation doesn't track keep their bind pose.
+///
+/// # Arguments
+/// * `fps` - Sample rate; defaults to the animation's own rate, capped at [`MAX_BAKE_FPS`]
+pub fn bake_animation(asset: &AnimationAsset, skeleton: &SklData, fps: Option<f32>) -> anyhow::Result<BakedAnimation> {
+    let fps = fps.unwrap_or_else(|| asset.fps().min(MAX_BAKE_FPS));
+    if !fps.is_finite() || fps <= 0.0 || fps > MAX_BAKE_FPS {
+        anyhow::bail!("Sample rate must be between 0 and {} fps, got {}", MAX_BAKE_FPS, fps);
+    }
+
+    let duration = asset.duration().max(0.0);
+    let frame_count = (duration * fps).ceil() as usize + 1;
+    let joint_count = skeleton.bones.len();
+
+    let mut matrices = Vec::with_capacity(frame_count * joint_count * 16);
+    for frame in 0..frame_count {
+        let time = (frame as f32 / fps).min(duration);
+        let pose = evaluate_pose(asset, time);
+        for matrix in skinning_matrices(skeleton, Some(&pose)) {
+            matrices.extend_from_slice(&matrix.to_cols_array());
+        }
+    }
+
+    Ok(BakedAnimation {
+        fps,
+        duration,
+        frame_count,
+        joint_count,
+        matrices,
+    })
 }
 
 /// Resolve animation path relative to project directory
@@ -419,6 +491,59 @@ pub fn resolve_animation_path(base_dir: &Path, anim_path: &str) -> Option<PathBu
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::core::mesh::render::elf_hash;
+    use crate::core::mesh::skl::BoneData;
+    use glam::{Mat4, Quat, Vec3};
+    use ltk_anim::asset::UncompressedFrame;
+    use ltk_anim::Uncompressed;
+
+    #[test]
+    fn test_bake_animation() {
+        let bone = |name: &str, id: i16| BoneData {
+            name: name.to_string(),
+            id,
+            parent_id: id - 1,
+            local_translation: [0.0; 3],
+            local_rotation: [0.0, 0.0, 0.0, 1.0],
+            local_scale: [1.0; 3],
+            world_position: [0.0; 3],
+            inverse_bind_matrix: Mat4::IDENTITY.to_cols_array_2d(),
+        };
+        let skeleton = SklData {
+            name: "test".to_string(),
+            asset_name: "test".to_string(),
+            bones: vec![bone("Root", 0), bone("Spine", 1)],
+            influences: Vec::new(),
+        };
+
+        // Root moves up by 2 over two frames at 2 fps; Spine isn't animated
+        let vectors = vec![Vec3::ZERO, Vec3::new(0.0, 2.0, 0.0), Vec3::ONE];
+        let joint_frames = HashMap::from([(
+            elf_hash("Root"),
+            vec![
+                UncompressedFrame { translation_id: 0, scale_id: 2, rotation_id: 0 },
+                UncompressedFrame { translation_id: 1, scale_id: 2, rotation_id: 0 },
+            ],
+        )]);
+        let asset = AnimationAsset::Uncompressed(Uncompressed::new(2.0, vectors, vec![Quat::IDENTITY], joint_frames));
+
+        let baked = bake_animation(&asset, &skeleton, Some(4.0)).unwrap();
+        assert_eq!(baked.joint_count, 2);
+        assert_eq!(baked.frame_count, (baked.duration * 4.0).ceil() as usize + 1);
+        assert_eq!(baked.matrices.len(), baked.frame_count * 2 * 16);
+
+        // Column-major: translation is in elements 12..15; the child follows its parent
+        let translation_y = |frame: usize, joint: usize| baked.matrices[(frame * 2 + joint) * 16 + 13];
+        let last = baked.frame_count - 1;
+        assert!(translation_y(0, 0).abs() < 1e-4);
+        assert!((translation_y(last, 0) - 2.0).abs() < 1e-4);
+        assert!((translation_y(last, 1) - 2.0).abs() < 1e-4);
+
+        assert!(bake_animation(&asset, &skeleton, Some(0.0)).is_err());
+        assert!(bake_animation(&asset, &skeleton, Some(MAX_BAKE_FPS + 1.0)).is_err());
+    }
+
     #[test]
     fn test_find_animation_bin() {
         // Test would require actual files
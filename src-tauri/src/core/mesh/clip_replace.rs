@@ -0,0 +1,363 @@
+//! Animation clip replacement
+//!
+//! Swapping an animation by hand means copying the .anm into the repathed
+//! animations folder, editing the clip's `mAnimationFilePath` in the animation
+//! BIN and hoping the new file was made for the same skeleton. This does all
+//! three in one step and reports tracks that don't line up with the skeleton.
+
+use crate::core::bin::cache::CACHE_EXTENSION;
+use crate::core::bin::ltk_bridge;
+use crate::core::mesh::anim_events::clip_matches;
+use crate::core::mesh::animation::{load_animation_asset, resolve_animation_path};
+use crate::core::mesh::render::elf_hash;
+use crate::core::mesh::skl::parse_skl_file;
+use crate::core::store::write_replacing;
+use league_toolkit::hash::fnv1a::hash_lower;
+use ltk_anim::Animation;
+use ltk_meta::value::StructValue;
+use ltk_meta::{BinTree, PropertyValueEnum};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+
+/// Result of replacing an animation clip
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ClipReplacement {
+    pub clip: String,
+    /// Animation BIN that was updated
+    pub bin_path: String,
+    /// Previous `mAnimationFilePath`
+    pub old_animation_path: String,
+    /// New `mAnimationFilePath`
+    pub new_animation_path: String,
+    /// Where the .anm was copied to on disk
+    pub copied_to: String,
+    /// Skeleton the animation was checked against, if one was found
+    pub skeleton: Option<String>,
+    /// Joint hashes animated by the clip that the skeleton doesn't have
+    pub unmatched_tracks: Vec<u32>,
+    /// Number of skeleton joints the clip doesn't animate (they stay in bind pose)
+    pub unanimated_joints: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Outcome of comparing an animation's tracks with a skeleton
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JointCompatibility {
+    pub matched: usize,
+    pub unmatched_tracks: Vec<u32>,
+    pub unanimated_joints: usize,
+}
+
+/// Compares the joint hashes animated by a clip with a skeleton's joint names
+pub fn check_joint_compatibility(tracks: &[u32], joint_names: &[String]) -> JointCompatibility {
+    let joints: HashSet<u32> = joint_names.iter().map(|name| elf_hash(name)).collect();
+    let tracks: HashSet<u32> = tracks.iter().copied().collect();
+
+    let mut unmatched_tracks: Vec<u32> = tracks.difference(&joints).copied().collect();
+    unmatched_tracks.sort_unstable();
+
+    JointCompatibility {
+        matched: tracks.intersection(&joints).count(),
+        unmatched_tracks,
+        unanimated_joints: joints.difference(&tracks).count(),
+    }
+}
+
+fn field_mut<'a>(value: &'a mut StructValue, name: &str) -> Option<&'a mut PropertyValueEnum> {
+    value.properties.get_mut(&hash_lower(name)).map(|p| &mut p.value)
+}
+
+fn as_struct_mut(value: &mut PropertyValueEnum) -> Option<&mut StructValue> {
+    match value {
+        PropertyValueEnum::Struct(s) => Some(s),
+        PropertyValueEnum::Embedded(e) => Some(&mut e.0),
+        _ => None,
+    }
+}
+
+/// Finds the `mAnimationFilePath` of a clip in a parsed animation BIN
+///
+/// The clip can be given by clip name, .anm file stem, or .anm path.
+pub fn clip_animation_path_mut<'a>(tree: &'a mut BinTree, clip_name: &str) -> Option<&'a mut String> {
+    for object in tree.objects.values_mut() {
+        let Some(PropertyValueEnum::Map(clips)) =
+            object.properties.get_mut(&hash_lower("mClipDataMap")).map(|p| &mut p.value)
+        else {
+            continue;
+        };
+
+        for (key, value) in clips.entries.iter_mut() {
+            let key_hash = match &key.0 {
+                PropertyValueEnum::Hash(h) => h.0,
+                _ => continue,
+            };
+            let Some(clip) = as_struct_mut(value) else { continue };
+            if !clip_matches(key_hash, clip, clip_name) {
+                continue;
+            }
+
+            let resource = as_struct_mut(field_mut(clip, "mAnimationResourceData")?)?;
+            return match field_mut(resource, "mAnimationFilePath")? {
+                PropertyValueEnum::String(s) => Some(&mut s.0),
+                _ => None,
+            };
+        }
+    }
+
+    None
+}
+
+/// Returns true for BINs under an `animations` folder
+fn is_animation_bin(path: &Path) -> bool {
+    let lower = path.to_string_lossy().to_lowercase().replace('\\', "/");
+    lower.ends_with(".bin") && lower.contains("/animations/")
+}
+
+/// Returns the `{name}.wad.client` folder (or other asset root) containing a file
+fn asset_root(path: &Path, content_dir: &Path) -> PathBuf {
+    path.ancestors()
+        .find(|dir| {
+            dir.file_name()
+                .is_some_and(|n| n.to_string_lossy().to_lowercase().contains(".wad"))
+        })
+        .unwrap_or(content_dir)
+        .to_path_buf()
+}
+
+fn find_skeleton(content_dir: &Path) -> Option<PathBuf> {
+    let mut skeletons: Vec<PathBuf> = WalkDir::new(content_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("skl")))
+        .collect();
+    skeletons.sort();
+    skeletons.into_iter().next()
+}
+
+/// Replaces the .anm used by an animation clip
+///
+/// # Arguments
+/// * `content_dir` - Project content directory to search (e.g. `content/base`)
+/// * `clip_name` - Clip name, .anm file stem, or .anm path
+/// * `new_anm` - The replacement .anm file
+pub fn replace_animation_clip(
+    content_dir: &Path,
+    clip_name: &str,
+    new_anm: &Path,
+) -> anyhow::Result<ClipReplacement> {
+    let file_name = new_anm
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .filter(|n| n.to_lowercase().ends_with(".anm"))
+        .ok_or_else(|| anyhow::anyhow!("Not an .anm file: {}", new_anm.display()))?;
+    let asset = load_animation_asset(new_anm)?;
+
+    let mut bins: Vec<PathBuf> = WalkDir::new(content_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && is_animation_bin(e.path()))
+        .map(|e| e.into_path())
+        .collect();
+    bins.sort();
+
+    for bin_path in bins {
+        let data = fs::read(&bin_path)?;
+        let Ok(mut tree) = ltk_bridge::read_bin(&data) else {
+            tracing::warn!("Skipping unreadable animation BIN: {}", bin_path.display());
+            continue;
+        };
+        let Some(anim_path) = clip_animation_path_mut(&mut tree, clip_name) else {
+            continue;
+        };
+
+        let old_animation_path = anim_path.clone();
+        let mut warnings = Vec::new();
+
+        // Keep the repathed folder, swap the file name
+        let new_animation_path = match old_animation_path.rfind(['/', '\\']) {
+            Some(split) => format!("{}/{}", &old_animation_path[..split], file_name),
+            None => file_name.clone(),
+        };
+
+        let bin_dir = bin_path.parent().unwrap_or(content_dir);
+        let old_file = resolve_animation_path(bin_dir, &old_animation_path);
+        let destination = match &old_file {
+            Some(old) => old.with_file_name(&file_name),
+            None => {
+                warnings.push(format!(
+                    "Previous animation '{}' wasn't found on disk",
+                    old_animation_path
+                ));
+                asset_root(&bin_path, content_dir).join(new_animation_path.replace('\\', "/"))
+            }
+        };
+
+        let overwrites_other = destination.exists() && old_file.as_deref() != Some(destination.as_path());
+        if overwrites_other && fs::read(&destination)? != fs::read(new_anm)? {
+            anyhow::bail!(
+                "{} already exists and is a different animation; rename the new .anm first",
+                destination.display()
+            );
+        }
+
+        // Joint compatibility against the project skeleton
+        let skeleton_path = find_skeleton(content_dir);
+        let (unmatched_tracks, unanimated_joints) = match &skeleton_path {
+            Some(skl) => {
+                let skeleton = parse_skl_file(skl)?;
+                let names: Vec<String> = skeleton.bones.into_iter().map(|b| b.name).collect();
+                let compat = check_joint_compatibility(&asset.joints(), &names);
+                if compat.matched == 0 {
+                    anyhow::bail!(
+                        "{} doesn't animate any joint of {}; it was made for a different skeleton",
+                        new_anm.display(),
+                        skl.display()
+                    );
+                }
+                if !compat.unmatched_tracks.is_empty() {
+                    warnings.push(format!(
+                        "{} animation tracks have no matching joint in the skeleton and will be ignored",
+                        compat.unmatched_tracks.len()
+                    ));
+                }
+                (compat.unmatched_tracks, compat.unanimated_joints)
+            }
+            None => {
+                warnings.push("No skeleton found in the project; joint compatibility wasn't checked".to_string());
+                (Vec::new(), 0)
+            }
+        };
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Copying a file onto itself would truncate it
+        let same_file = fs::canonicalize(new_anm).ok() == fs::canonicalize(&destination).ok();
+        if !same_file {
+            write_replacing(&destination, &fs::read(new_anm)?)?;
+        }
+
+        *anim_path = new_animation_path.clone();
+        write_replacing(&bin_path, &ltk_bridge::write_bin(&tree)?)?;
+
+        // The text cache still shows the old path
+        let cache = PathBuf::from(format!("{}.{}", bin_path.display(), CACHE_EXTENSION));
+        if cache.exists() {
+            fs::remove_file(&cache)?;
+        }
+
+        tracing::info!(
+            "Replaced clip '{}' in {}: {} -> {}",
+            clip_name,
+            bin_path.display(),
+            old_animation_path,
+            new_animation_path
+        );
+
+        return Ok(ClipReplacement {
+            clip: clip_name.to_string(),
+            bin_path: bin_path.to_string_lossy().to_string(),
+            old_animation_path,
+            new_animation_path,
+            copied_to: destination.to_string_lossy().to_string(),
+            skeleton: skeleton_path.map(|p| p.to_string_lossy().to_string()),
+            unmatched_tracks,
+            unanimated_joints,
+            warnings,
+        });
+    }
+
+    anyhow::bail!("Clip '{}' not found in any animation BIN of the project", clip_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::store::AssetStore;
+    use glam::{Quat, Vec3};
+    use ltk_anim::asset::UncompressedFrame;
+    use ltk_anim::Uncompressed;
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    const GRAPH: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+entries: map[hash,embed] = {
+    "Characters/Ahri/Animations/Skin3" = animationGraphData {
+        mClipDataMap: map[hash,pointer] = {
+            "Dance" = AtomicClipData {
+                mAnimationResourceData: embed = AnimationResourceData {
+                    mAnimationFilePath: string = "ASSETS/me/mod/Characters/Ahri/Skins/Skin03/Animations/Ahri_Skin03_Dance.anm"
+                }
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_clip_animation_path_mut() {
+        let mut tree = ltk_bridge::text_to_tree(GRAPH).unwrap();
+        let path = clip_animation_path_mut(&mut tree, "Dance").unwrap();
+        *path = "ASSETS/me/mod/Characters/Ahri/Skins/Skin03/Animations/New_Dance.anm".to_string();
+
+        // Now reachable by the new file stem, not the old one
+        assert!(clip_animation_path_mut(&mut tree, "new_dance").is_some());
+        assert!(clip_animation_path_mut(&mut tree, "ahri_skin03_dance").is_none());
+        assert!(clip_animation_path_mut(&mut tree, "Idle1").is_none());
+    }
+
+    #[test]
+    fn test_check_joint_compatibility() {
+        let names = vec!["Root".to_string(), "Pelvis".to_string(), "Head".to_string()];
+        let tracks = [elf_hash("root"), elf_hash("pelvis"), elf_hash("Tail")];
+
+        let compat = check_joint_compatibility(&tracks, &names);
+        assert_eq!(compat.matched, 2);
+        assert_eq!(compat.unmatched_tracks, vec![elf_hash("Tail")]);
+        assert_eq!(compat.unanimated_joints, 1);
+    }
+
+    #[test]
+    fn test_replace_leaves_store_objects_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path().join("content/base");
+        let wad = content.join("ahri.wad.client");
+        let bin_path = wad.join("data/characters/ahri/animations/skin3.bin");
+        fs::create_dir_all(bin_path.parent().unwrap()).unwrap();
+        let tree = ltk_bridge::text_to_tree(GRAPH).unwrap();
+        fs::write(&bin_path, ltk_bridge::write_bin(&tree).unwrap()).unwrap();
+
+        // The old clip is linked from the store, as is an identical file elsewhere
+        let old_anm = wad.join("assets/me/mod/Characters/Ahri/Skins/Skin03/Animations/Ahri_Skin03_Dance.anm");
+        let other = wad.join("assets/me/mod/other.anm");
+        fs::create_dir_all(old_anm.parent().unwrap()).unwrap();
+        let store = AssetStore::new(dir.path().join("store"));
+        store.place(b"old animation", &old_anm).unwrap();
+        store.place(b"old animation", &other).unwrap();
+
+        let joint_frames = HashMap::from([(
+            elf_hash("Root"),
+            vec![UncompressedFrame { translation_id: 0, scale_id: 0, rotation_id: 0 }],
+        )]);
+        let mut anm = Cursor::new(Vec::new());
+        Uncompressed::new(30.0, vec![Vec3::ONE], vec![Quat::IDENTITY], joint_frames)
+            .to_writer(&mut anm)
+            .unwrap();
+        let new_anm = dir.path().join("Ahri_Skin03_Dance.anm");
+        fs::write(&new_anm, anm.get_ref()).unwrap();
+
+        let replaced = replace_animation_clip(&content, "Dance", &new_anm).unwrap();
+        assert_eq!(PathBuf::from(&replaced.copied_to), old_anm);
+        assert_eq!(fs::read(&old_anm).unwrap(), *anm.get_ref());
+        assert_eq!(fs::read(&other).unwrap(), b"old animation");
+    }
+}
@@ -0,0 +1,260 @@
+//! StaticMaterialDef parameter editing
+//!
+//! A material's shader constants are `StaticMaterialShaderParamDef` entries
+//! in its `paramValues` list: a `name` and a `vec4 value`. Scalar params
+//! (`Emissive_Intensity`, `Fresnel_Power`) only use the first component.
+//! [`crate::core::mesh::texture`] reads materials for the viewer; this module
+//! edits them on the tree so a change can be written back without a text
+//! round trip.
+
+use crate::core::bin::editor;
+use crate::core::bin::ltk_bridge::HashMapProvider;
+use crate::error::{Error, Result};
+use indexmap::IndexMap;
+use league_toolkit::hash::fnv1a::hash_lower;
+use ltk_meta::value::*;
+use ltk_meta::{BinProperty, BinPropertyKind, BinTree, BinTreeObject, PropertyValueEnum};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use ts_rs::TS;
+
+const MATERIAL_CLASS: &str = "StaticMaterialDef";
+const PARAM_CLASS: &str = "StaticMaterialShaderParamDef";
+
+/// One shader constant of a material
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MaterialParam {
+    pub name: String,
+    pub value: [f32; 4],
+}
+
+/// The shader constants of a `StaticMaterialDef`
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MaterialParams {
+    pub object_hash: u32,
+    pub object_name: Option<String>,
+    pub params: Vec<MaterialParam>,
+}
+
+/// Finds a material by object name, the end of its path, or hex hash
+///
+/// Names are matched through their hash first, so a full material path works
+/// without the hash list loaded.
+fn find_material<'a>(tree: &'a mut BinTree, names: &HashMapProvider, material: &str) -> Result<&'a mut BinTreeObject> {
+    let class_hash = hash_lower(MATERIAL_CLASS);
+    let wanted = material.to_lowercase();
+    let by_hex = u32::from_str_radix(wanted.trim_start_matches("0x"), 16).ok();
+    let suffix = format!("/{}", wanted);
+
+    let matches = |hash: u32| {
+        hash == hash_lower(material)
+            || Some(hash) == by_hex
+            || names.entries.get(&hash).is_some_and(|name| {
+                let name = name.to_lowercase();
+                name == wanted || name.ends_with(&suffix)
+            })
+    };
+    let hash = tree
+        .objects
+        .values()
+        .filter(|object| object.class_hash == class_hash)
+        .map(|object| object.path_hash)
+        .find(|hash| matches(*hash))
+        .ok_or_else(|| Error::InvalidInput(format!("No StaticMaterialDef named '{}'", material)))?;
+    Ok(tree.objects.get_mut(&hash).expect("hash was just found"))
+}
+
+fn param_values(object: &mut BinTreeObject) -> Option<&mut Vec<PropertyValueEnum>> {
+    match &mut object.properties.get_mut(&hash_lower("paramValues"))?.value {
+        PropertyValueEnum::Container(c) | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(c)) => {
+            Some(&mut c.items)
+        }
+        _ => None,
+    }
+}
+
+/// The `name` and `value` of a param definition, if it has both
+fn param_fields(item: &mut PropertyValueEnum) -> Option<(&str, &mut Vector4Value)> {
+    let (PropertyValueEnum::Embedded(EmbeddedValue(def)) | PropertyValueEnum::Struct(def)) = item else {
+        return None;
+    };
+    let mut name = None;
+    let mut value = None;
+    for prop in def.properties.values_mut() {
+        match &mut prop.value {
+            PropertyValueEnum::String(s) if prop.name_hash == hash_lower("name") => name = Some(s.0.as_str()),
+            PropertyValueEnum::Vector4(v) if prop.name_hash == hash_lower("value") => value = Some(v),
+            _ => {}
+        }
+    }
+    Some((name?, value?))
+}
+
+fn new_param(name: &str, value: [f32; 4]) -> PropertyValueEnum {
+    let properties = IndexMap::from([
+        (
+            hash_lower("name"),
+            BinProperty { name_hash: hash_lower("name"), value: PropertyValueEnum::String(StringValue(name.to_string())) },
+        ),
+        (
+            hash_lower("value"),
+            BinProperty { name_hash: hash_lower("value"), value: PropertyValueEnum::Vector4(Vector4Value(value.into())) },
+        ),
+    ]);
+    PropertyValueEnum::Embedded(EmbeddedValue(StructValue { class_hash: hash_lower(PARAM_CLASS), properties }))
+}
+
+fn collect_params(object: &mut BinTreeObject, names: &HashMapProvider) -> MaterialParams {
+    let params = param_values(object)
+        .map(|items| {
+            items
+                .iter_mut()
+                .filter_map(param_fields)
+                .map(|(name, value)| MaterialParam { name: name.to_string(), value: value.0.to_array() })
+                .collect()
+        })
+        .unwrap_or_default();
+    MaterialParams {
+        object_hash: object.path_hash,
+        object_name: names.entries.get(&object.path_hash).cloned(),
+        params,
+    }
+}
+
+/// Sets a param of a material in a tree
+///
+/// `value` holds one to four components and replaces that many leading
+/// components of the param, so a scalar param can be set from one number.
+/// A param the material doesn't list yet is added (it was using the shader
+/// default until now).
+pub fn set_material_param_in_tree(
+    tree: &mut BinTree,
+    names: &HashMapProvider,
+    material: &str,
+    param: &str,
+    value: &[f32],
+) -> Result<MaterialParams> {
+    if value.is_empty() || value.len() > 4 || value.iter().any(|x| !x.is_finite()) {
+        return Err(Error::InvalidInput(format!(
+            "Material param value needs 1 to 4 finite numbers, got {:?}",
+            value
+        )));
+    }
+    let object = find_material(tree, names, material)?;
+    if param_values(object).is_none() {
+        let list = PropertyValueEnum::Container(ContainerValue { item_kind: BinPropertyKind::Embedded, items: Vec::new() });
+        object.properties.insert(hash_lower("paramValues"), BinProperty { name_hash: hash_lower("paramValues"), value: list });
+    }
+    let items = param_values(object).ok_or_else(|| Error::InvalidInput("paramValues isn't a list".to_string()))?;
+
+    let existing = items
+        .iter_mut()
+        .filter_map(param_fields)
+        .find(|(name, _)| name.eq_ignore_ascii_case(param));
+    match existing {
+        Some((_, current)) => {
+            let mut components = current.0.to_array();
+            components[..value.len()].copy_from_slice(value);
+            current.0 = components.into();
+        }
+        None => {
+            let mut components = [0.0; 4];
+            components[..value.len()].copy_from_slice(value);
+            items.push(new_param(param, components));
+        }
+    }
+    Ok(collect_params(object, names))
+}
+
+/// Lists the params of a material in a BIN file
+///
+/// # Arguments
+/// * `path` - BIN file with the `StaticMaterialDef`
+/// * `names` - Hash names, for matching materials by short name
+/// * `material` - Material path, the end of it (`Body_inst`), or its hex hash
+pub fn get_material_params(path: &Path, names: &HashMapProvider, material: &str) -> Result<MaterialParams> {
+    let mut tree = editor::load(path)?;
+    let object = find_material(&mut tree, names, material)?;
+    Ok(collect_params(object, names))
+}
+
+/// Sets a param of a material in a BIN file and saves it
+///
+/// See [`set_material_param_in_tree`] for how `value` is applied.
+pub fn set_material_param(
+    path: &Path,
+    names: &HashMapProvider,
+    material: &str,
+    param: &str,
+    value: &[f32],
+) -> Result<MaterialParams> {
+    let mut tree = editor::load(path)?;
+    let params = set_material_param_in_tree(&mut tree, names, material, param, value)?;
+    editor::save(path, &tree)?;
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+    use std::fs;
+
+    const MATERIALS: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin0/Materials/Body_inst" = StaticMaterialDef {
+        name: string = "Characters/Ahri/Skins/Skin0/Materials/Body_inst"
+        paramValues: list2[embed] = {
+            StaticMaterialShaderParamDef {
+                name: string = "Emissive_Intensity"
+                value: vec4 = { 1, 0, 0, 0 }
+            }
+            StaticMaterialShaderParamDef {
+                name: string = "Emissive_Color"
+                value: vec4 = { 1, 0.5, 0.25, 1 }
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_set_material_param() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("skin0.bin");
+        fs::write(&path, write_bin(&text_to_tree(MATERIALS).unwrap()).unwrap()).unwrap();
+        let names = HashMapProvider::new();
+        let material = "Characters/Ahri/Skins/Skin0/Materials/Body_inst";
+
+        let params = get_material_params(&path, &names, material).unwrap();
+        assert_eq!(params.params.len(), 2);
+        assert_eq!(params.params[1].value, [1.0, 0.5, 0.25, 1.0]);
+
+        // A scalar only replaces the first component
+        set_material_param(&path, &names, material, "emissive_intensity", &[4.5]).unwrap();
+        // Colors replace as many components as given
+        set_material_param(&path, &names, material, "Emissive_Color", &[0.0, 1.0, 0.0]).unwrap();
+        // Missing params are added
+        set_material_param(&path, &names, &format!("{:08x}", hash_lower(material)), "Fresnel_Power", &[2.0]).unwrap();
+
+        let params = get_material_params(&path, &names, material).unwrap();
+        let values: Vec<_> = params.params.iter().map(|p| (p.name.as_str(), p.value)).collect();
+        assert_eq!(
+            values,
+            vec![
+                ("Emissive_Intensity", [4.5, 0.0, 0.0, 0.0]),
+                ("Emissive_Color", [0.0, 1.0, 0.0, 1.0]),
+                ("Fresnel_Power", [2.0, 0.0, 0.0, 0.0]),
+            ]
+        );
+
+        assert!(set_material_param(&path, &names, "Missing_inst", "Emissive_Intensity", &[1.0]).is_err());
+        assert!(set_material_param(&path, &names, material, "Emissive_Intensity", &[]).is_err());
+        assert!(set_material_param(&path, &names, material, "Emissive_Intensity", &[f32::NAN]).is_err());
+    }
+}
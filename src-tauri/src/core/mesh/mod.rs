@@ -8,5 +8,12 @@ pub mod skn;
 pub mod texture;
 pub mod skl;
 pub mod animation;
+pub mod anim_events;
 pub mod scb;
+pub mod shader;
+pub mod render;
+pub mod clip_replace;
+pub mod retarget;
+pub mod material;
+pub mod submesh;
 
@@ -0,0 +1,424 @@
+//! Offscreen software rendering of skinned meshes
+//!
+//! Renders turntable sequences of an SKN (optionally posed by an animation)
+//! without a GPU or window, so preview images for mod pages come out the
+//! same size and framing every time. Skinning mirrors the 3D preview in
+//! `ModelPreview.tsx`; shading is a simple two-sided Lambert over the
+//! diffuse texture.
+
+use std::collections::HashMap;
+
+use glam::{Mat4, Quat, Vec2, Vec3};
+use image::{Rgba, RgbaImage};
+use ltk_anim::Animation;
+use league_toolkit::hash::elf::elf;
+
+use crate::core::mesh::animation::{evaluate_pose, AnimationPose};
+use crate::core::mesh::skl::SklData;
+use crate::core::mesh::skn::SknMeshData;
+
+/// Largest supported frame edge in pixels
+pub const MAX_RESOLUTION: u32 = 4096;
+/// Largest supported number of frames per turntable
+pub const MAX_FRAMES: u32 = 360;
+
+/// Color used for materials without a texture
+const UNTEXTURED: [u8; 3] = [180, 180, 180];
+/// Fraction of the frame left empty around the model
+const MARGIN: f32 = 0.08;
+const AMBIENT: f32 = 0.35;
+
+/// Diffuse texture of a material with its UV transform
+pub struct MaterialTexture {
+    pub image: RgbaImage,
+    pub uv_scale: Option<[f32; 2]>,
+    pub uv_offset: Option<[f32; 2]>,
+}
+
+impl MaterialTexture {
+    fn sample(&self, uv: Vec2) -> [u8; 4] {
+        let scale = self.uv_scale.map(Vec2::from).unwrap_or(Vec2::ONE);
+        let offset = self.uv_offset.map(Vec2::from).unwrap_or(Vec2::ZERO);
+        let uv = uv * scale + offset;
+
+        let (w, h) = self.image.dimensions();
+        let x = (uv.x.rem_euclid(1.0) * w as f32) as u32;
+        let y = (uv.y.rem_euclid(1.0) * h as f32) as u32;
+        self.image.get_pixel(x.min(w - 1), y.min(h - 1)).0
+    }
+}
+
+/// Skeleton and animation used to pose the mesh
+pub struct Posing<'a> {
+    pub skeleton: &'a SklData,
+    pub animation: Option<&'a ltk_anim::AnimationAsset>,
+}
+
+/// Riot's ELF hash of a joint name, as ANM files key their tracks
+///
+/// Names are lowercased first so skeletons and animations that differ in
+/// case still line up. Bits above 32 never feed back into the low ones, so
+/// truncating the library's `usize` hash is exact.
+pub fn elf_hash(name: &str) -> u32 {
+    elf(name.to_lowercase()) as u32
+}
+
+/// Computes per-bone skinning matrices (world * inverse bind), indexed like `skeleton.bones`
+///
+/// Bones without a track in `pose` keep their bind transform.
+pub fn skinning_matrices(skeleton: &SklData, pose: Option<&AnimationPose>) -> Vec<Mat4> {
+    let mut matrices = vec![Mat4::IDENTITY; skeleton.bones.len()];
+    let mut world_by_id: HashMap<i16, Mat4> = HashMap::new();
+
+    // Parents have lower IDs than their children
+    let mut order: Vec<usize> = (0..skeleton.bones.len()).collect();
+    order.sort_by_key(|&i| skeleton.bones[i].id);
+
+    for index in order {
+        let bone = &skeleton.bones[index];
+        let local = match pose.and_then(|p| p.joints.get(&elf_hash(&bone.name))) {
+            Some(t) => Mat4::from_scale_rotation_translation(
+                Vec3::from(t.scale),
+                Quat::from_array(t.rotation),
+                Vec3::from(t.translation),
+            ),
+            None => Mat4::from_scale_rotation_translation(
+                Vec3::from(bone.local_scale),
+                Quat::from_array(bone.local_rotation),
+                Vec3::from(bone.local_translation),
+            ),
+        };
+
+        let world = match world_by_id.get(&bone.parent_id) {
+            Some(parent) if bone.parent_id >= 0 => *parent * local,
+            _ => local,
+        };
+        world_by_id.insert(bone.id, world);
+        matrices[index] = world * Mat4::from_cols_array_2d(&bone.inverse_bind_matrix);
+    }
+
+    matrices
+}
+
+/// Applies skinning to mesh positions and normals
+pub fn skin_vertices(mesh: &SknMeshData, skeleton: &SklData, matrices: &[Mat4]) -> (Vec<Vec3>, Vec<Vec3>) {
+    let index_of_id: HashMap<i16, usize> = skeleton
+        .bones
+        .iter()
+        .enumerate()
+        .map(|(i, bone)| (bone.id, i))
+        .collect();
+
+    mesh.positions
+        .iter()
+        .enumerate()
+        .map(|(v, position)| {
+            let position = Vec3::from(*position);
+            let normal = Vec3::from(mesh.normals.get(v).copied().unwrap_or([0.0, 1.0, 0.0]));
+            let (Some(weights), Some(bones)) = (mesh.bone_weights.get(v), mesh.bone_indices.get(v)) else {
+                return (position, normal);
+            };
+
+            let mut skinned = Vec3::ZERO;
+            let mut skinned_normal = Vec3::ZERO;
+            let mut total = 0.0;
+            for (weight, influence) in weights.iter().zip(bones) {
+                if *weight <= 0.0001 {
+                    continue;
+                }
+                // Vertex bone index -> influences -> bone ID -> bone array index
+                let influence = *influence as usize;
+                let bone = skeleton
+                    .influences
+                    .get(influence)
+                    .and_then(|id| index_of_id.get(id).copied())
+                    .unwrap_or(influence);
+                if let Some(matrix) = matrices.get(bone) {
+                    skinned += matrix.transform_point3(position) * *weight;
+                    skinned_normal += matrix.transform_vector3(normal) * *weight;
+                    total += weight;
+                }
+            }
+
+            if total > 0.0001 {
+                (skinned / total, skinned_normal.normalize_or_zero())
+            } else {
+                (position, normal)
+            }
+        })
+        .unzip()
+}
+
+/// Axis-aligned bounds of a set of points
+fn bounds(points: &[Vec3]) -> (Vec3, Vec3) {
+    points.iter().fold((Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)), |(min, max), p| {
+        (min.min(*p), max.max(*p))
+    })
+}
+
+/// Color and depth targets for one frame
+struct Target {
+    color: RgbaImage,
+    depth: Vec<f32>,
+}
+
+impl Target {
+    fn new(size: u32) -> Self {
+        Self {
+            color: RgbaImage::from_pixel(size, size, Rgba([0, 0, 0, 0])),
+            depth: vec![f32::MIN; (size * size) as usize],
+        }
+    }
+
+    /// Fills a triangle given screen-space positions (z = depth, larger is closer)
+    fn triangle(&mut self, screen: [Vec3; 3], shade: impl Fn(Vec3) -> [u8; 4]) {
+        let size = self.color.width() as f32;
+        let [a, b, c] = screen;
+        let area = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+        if area.abs() < f32::EPSILON {
+            return;
+        }
+
+        let min_x = a.x.min(b.x).min(c.x).floor().max(0.0) as u32;
+        let max_x = a.x.max(b.x).max(c.x).ceil().min(size - 1.0).max(0.0) as u32;
+        let min_y = a.y.min(b.y).min(c.y).floor().max(0.0) as u32;
+        let max_y = a.y.max(b.y).max(c.y).ceil().min(size - 1.0).max(0.0) as u32;
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let p = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                let w0 = ((b.x - p.x) * (c.y - p.y) - (b.y - p.y) * (c.x - p.x)) / area;
+                let w1 = ((c.x - p.x) * (a.y - p.y) - (c.y - p.y) * (a.x - p.x)) / area;
+                let w2 = 1.0 - w0 - w1;
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                    continue;
+                }
+
+                let depth = w0 * a.z + w1 * b.z + w2 * c.z;
+                let slot = (y * self.color.width() + x) as usize;
+                if depth <= self.depth[slot] {
+                    continue;
+                }
+
+                let color = shade(Vec3::new(w0, w1, w2));
+                if color[3] < 8 {
+                    // Cut-out pixels (hair cards, alpha-tested trims) don't occlude
+                    continue;
+                }
+                self.depth[slot] = depth;
+                self.color.put_pixel(x, y, Rgba([color[0], color[1], color[2], 255]));
+            }
+        }
+    }
+}
+
+/// Camera framing shared by every frame of a turntable so they line up
+#[derive(Clone, Copy)]
+struct Framing {
+    center: Vec3,
+    radius: f32,
+    resolution: u32,
+}
+
+/// Renders one frame of the mesh rotated by `yaw` radians around the vertical axis
+fn render_frame(
+    mesh: &SknMeshData,
+    positions: &[Vec3],
+    normals: &[Vec3],
+    textures: &HashMap<String, MaterialTexture>,
+    yaw: f32,
+    framing: &Framing,
+) -> RgbaImage {
+    let Framing { center, radius, resolution } = *framing;
+    let mut target = Target::new(resolution);
+    let rotation = Quat::from_rotation_y(yaw);
+    let light = Vec3::new(0.3, 0.5, 1.0).normalize();
+    let half = resolution as f32 / 2.0;
+    let scale = half * (1.0 - MARGIN) / radius.max(f32::EPSILON);
+
+    let screen: Vec<Vec3> = positions
+        .iter()
+        .map(|p| {
+            let p = rotation * (*p - center);
+            Vec3::new(half + p.x * scale, half - p.y * scale, p.z)
+        })
+        .collect();
+
+    for material in &mesh.materials {
+        let texture = textures.get(&material.name);
+        let start = material.start_index.max(0) as usize;
+        let end = (start + material.index_count.max(0) as usize).min(mesh.indices.len());
+
+        for tri in mesh.indices[start..end].chunks_exact(3) {
+            let [i0, i1, i2] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            if i0.max(i1).max(i2) >= screen.len() {
+                continue;
+            }
+
+            let uv = |i: usize| Vec2::from(mesh.uvs.get(i).copied().unwrap_or_default());
+            let normal = |i: usize| rotation * normals.get(i).copied().unwrap_or(Vec3::Y);
+            let (uvs, ns) = ([uv(i0), uv(i1), uv(i2)], [normal(i0), normal(i1), normal(i2)]);
+
+            target.triangle([screen[i0], screen[i1], screen[i2]], |w| {
+                let n = (ns[0] * w.x + ns[1] * w.y + ns[2] * w.z).normalize_or_zero();
+                let lit = AMBIENT + (1.0 - AMBIENT) * n.dot(light).abs();
+                let base = match texture {
+                    Some(texture) => texture.sample(uvs[0] * w.x + uvs[1] * w.y + uvs[2] * w.z),
+                    None => [UNTEXTURED[0], UNTEXTURED[1], UNTEXTURED[2], 255],
+                };
+                [
+                    (base[0] as f32 * lit).min(255.0) as u8,
+                    (base[1] as f32 * lit).min(255.0) as u8,
+                    (base[2] as f32 * lit).min(255.0) as u8,
+                    base[3],
+                ]
+            });
+        }
+    }
+
+    target.color
+}
+
+/// Renders a full turn of the model as `frames` square images
+///
+/// With an animation, the clip plays once over the turn.
+///
+/// # Arguments
+/// * `mesh` - Parsed SKN mesh
+/// * `textures` - Diffuse textures by material name
+/// * `posing` - Skeleton (and animation) to pose the mesh with
+/// * `frames` - Number of frames in the turn
+/// * `resolution` - Edge length of each frame in pixels
+pub fn render_turntable(
+    mesh: &SknMeshData,
+    textures: &HashMap<String, MaterialTexture>,
+    posing: Option<Posing>,
+    frames: u32,
+    resolution: u32,
+) -> anyhow::Result<Vec<RgbaImage>> {
+    if frames == 0 || frames > MAX_FRAMES {
+        anyhow::bail!("Frame count must be between 1 and {}", MAX_FRAMES);
+    }
+    if !(16..=MAX_RESOLUTION).contains(&resolution) {
+        anyhow::bail!("Resolution must be between 16 and {} pixels", MAX_RESOLUTION);
+    }
+    if mesh.positions.is_empty() {
+        anyhow::bail!("Mesh has no vertices");
+    }
+
+    let bind_normals: Vec<Vec3> = mesh.normals.iter().map(|n| Vec3::from(*n)).collect();
+    let pose_at = |time: f32| -> (Vec<Vec3>, Vec<Vec3>) {
+        match &posing {
+            Some(posing) => {
+                let pose = posing.animation.map(|anim| evaluate_pose(anim, time));
+                let matrices = skinning_matrices(posing.skeleton, pose.as_ref());
+                skin_vertices(mesh, posing.skeleton, &matrices)
+            }
+            None => (mesh.positions.iter().map(|p| Vec3::from(*p)).collect(), bind_normals.clone()),
+        }
+    };
+
+    // Frame on the first pose so the camera doesn't drift with the animation
+    let (first_positions, first_normals) = pose_at(0.0);
+    let (min, max) = bounds(&first_positions);
+    let center = (min + max) / 2.0;
+    let radius = first_positions
+        .iter()
+        .map(|p| Vec2::new(p.x - center.x, p.z - center.z).length().max((p.y - center.y).abs()))
+        .fold(0.0_f32, f32::max);
+    let framing = Framing { center, radius, resolution };
+
+    let duration = posing
+        .as_ref()
+        .and_then(|p| p.animation)
+        .map(|anim| anim.duration())
+        .unwrap_or(0.0);
+
+    let images = (0..frames)
+        .map(|frame| {
+            let t = frame as f32 / frames as f32;
+            let animated = (duration > 0.0).then(|| pose_at(duration * t));
+            let (positions, normals) = match &animated {
+                Some((positions, normals)) => (positions, normals),
+                None => (&first_positions, &first_normals),
+            };
+            render_frame(
+                mesh,
+                positions,
+                normals,
+                textures,
+                std::f32::consts::TAU * t,
+                &framing,
+            )
+        })
+        .collect();
+
+    Ok(images)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mesh::skn::MaterialRange;
+
+    fn quad() -> SknMeshData {
+        SknMeshData {
+            materials: vec![MaterialRange {
+                name: "body".to_string(),
+                start_index: 0,
+                index_count: 6,
+                start_vertex: 0,
+                vertex_count: 4,
+            }],
+            positions: vec![[-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0]],
+            normals: vec![[0.0, 0.0, 1.0]; 4],
+            uvs: vec![[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            bounding_box: [[-1.0, -1.0, 0.0], [1.0, 1.0, 0.0]],
+            textures: HashMap::new(),
+            material_data: HashMap::new(),
+            bone_weights: Vec::new(),
+            bone_indices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_elf_hash() {
+        assert_eq!(elf_hash(""), 0);
+        assert_eq!(elf_hash("a"), 0x61);
+        assert_eq!(elf_hash("Root"), elf_hash("root"));
+    }
+
+    #[test]
+    fn test_render_turntable_frames() {
+        let mut textures = HashMap::new();
+        textures.insert(
+            "body".to_string(),
+            MaterialTexture {
+                image: RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255])),
+                uv_scale: None,
+                uv_offset: None,
+            },
+        );
+
+        let frames = render_turntable(&quad(), &textures, None, 4, 64).unwrap();
+        assert_eq!(frames.len(), 4);
+
+        let front = &frames[0];
+        assert_eq!(front.dimensions(), (64, 64));
+        let center = front.get_pixel(32, 32).0;
+        assert_eq!(center[3], 255);
+        assert!(center[0] > 0 && center[1] == 0);
+        assert_eq!(front.get_pixel(0, 0).0[3], 0);
+
+        // Quarter turn: the quad is seen edge-on and covers almost nothing
+        let covered = frames[1].pixels().filter(|p| p.0[3] > 0).count();
+        assert!(covered < 64 * 4);
+    }
+
+    #[test]
+    fn test_render_turntable_rejects_bad_options() {
+        assert!(render_turntable(&quad(), &HashMap::new(), None, 0, 64).is_err());
+        assert!(render_turntable(&quad(), &HashMap::new(), None, 1, MAX_RESOLUTION + 1).is_err());
+    }
+}
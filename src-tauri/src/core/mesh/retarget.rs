@@ -0,0 +1,288 @@
+//! Animation retargeting between skeletons
+//!
+//! ANM tracks are keyed by the ELF hash of the joint name, so an animation
+//! plays on any skeleton whose joints share those names. Retargeting maps each
+//! joint of the target skeleton to a source joint (same name, or an explicit
+//! rename), resamples the source animation and writes the remapped tracks as
+//! a new uncompressed `.anm`.
+//!
+//! Rotations and scales are copied as they are. Translations are shifted by
+//! the difference between the two bind poses, so bones keep the target
+//! skeleton's lengths instead of stretching to the source's proportions.
+
+use crate::core::mesh::animation::load_animation_asset;
+use crate::core::mesh::render::elf_hash;
+use crate::core::mesh::skl::{parse_skl_file, SklData};
+use crate::core::store::write_replacing;
+use glam::{Quat, Vec3};
+use ltk_anim::asset::UncompressedFrame;
+use ltk_anim::{Animation, Uncompressed};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Outcome of retargeting an animation
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct RetargetReport {
+    pub output_path: String,
+    pub frame_count: usize,
+    /// Target joints that received a track
+    pub mapped_joints: usize,
+    /// Target joints with no source joint; they stay in bind pose
+    pub unmapped_joints: Vec<String>,
+    /// Source tracks that no target joint uses
+    pub dropped_tracks: usize,
+}
+
+/// Source joint for every target joint that has one, as (target, source) indices
+fn map_joints(
+    source: &SklData,
+    target: &SklData,
+    bone_map: &HashMap<String, String>,
+) -> Vec<(usize, usize)> {
+    let source_by_hash: HashMap<u32, usize> = source
+        .bones
+        .iter()
+        .enumerate()
+        .map(|(i, bone)| (elf_hash(&bone.name), i))
+        .collect();
+    let renames: HashMap<String, &String> = bone_map
+        .iter()
+        .map(|(target, source)| (target.to_lowercase(), source))
+        .collect();
+
+    target
+        .bones
+        .iter()
+        .enumerate()
+        .filter_map(|(i, bone)| {
+            let source_name = renames.get(&bone.name.to_lowercase()).copied().unwrap_or(&bone.name);
+            source_by_hash.get(&elf_hash(source_name)).map(|&s| (i, s))
+        })
+        .collect()
+}
+
+/// Palette builder that deduplicates values by their bit patterns
+struct Palettes {
+    vectors: Vec<Vec3>,
+    quats: Vec<Quat>,
+    vector_ids: HashMap<[u32; 3], u16>,
+    quat_ids: HashMap<[u32; 4], u16>,
+}
+
+impl Palettes {
+    fn vector(&mut self, v: Vec3) -> anyhow::Result<u16> {
+        let key = v.to_array().map(f32::to_bits);
+        if let Some(&id) = self.vector_ids.get(&key) {
+            return Ok(id);
+        }
+        let id = u16::try_from(self.vectors.len())
+            .map_err(|_| anyhow::anyhow!("Animation has too many distinct translations and scales"))?;
+        self.vectors.push(v);
+        self.vector_ids.insert(key, id);
+        Ok(id)
+    }
+
+    fn quat(&mut self, q: Quat) -> anyhow::Result<u16> {
+        let key = q.to_array().map(f32::to_bits);
+        if let Some(&id) = self.quat_ids.get(&key) {
+            return Ok(id);
+        }
+        let id = u16::try_from(self.quats.len())
+            .map_err(|_| anyhow::anyhow!("Animation has too many distinct rotations"))?;
+        self.quats.push(q);
+        self.quat_ids.insert(key, id);
+        Ok(id)
+    }
+}
+
+/// Resamples `animation` onto the joints of `target`
+///
+/// # Arguments
+/// * `animation` - Animation made for `source`
+/// * `source` - Skeleton the animation was authored for
+/// * `target` - Skeleton to retarget to
+/// * `bone_map` - Target joint name -> source joint name, for joints that were renamed
+///
+/// # Returns
+/// The new animation and a report with an empty `output_path`
+pub fn retarget(
+    animation: &impl Animation,
+    source: &SklData,
+    target: &SklData,
+    bone_map: &HashMap<String, String>,
+) -> anyhow::Result<(Uncompressed, RetargetReport)> {
+    let fps = if animation.fps() > 0.0 { animation.fps() } else { 30.0 };
+    let frame_count = ((animation.duration() * fps).round() as usize).max(1);
+
+    let tracks: HashSet<u32> = animation.joints().iter().copied().collect();
+    let pairs: Vec<(usize, usize)> = map_joints(source, target, bone_map)
+        .into_iter()
+        .filter(|(_, s)| tracks.contains(&elf_hash(&source.bones[*s].name)))
+        .collect();
+
+    let mut palettes = Palettes {
+        vectors: Vec::new(),
+        quats: Vec::new(),
+        vector_ids: HashMap::new(),
+        quat_ids: HashMap::new(),
+    };
+    let mut joint_frames: HashMap<u32, Vec<UncompressedFrame>> = HashMap::new();
+
+    for frame in 0..frame_count {
+        let time = (frame as f32 / fps).min(animation.duration());
+        let pose = animation.evaluate(time);
+
+        for &(t, s) in &pairs {
+            let source_bone = &source.bones[s];
+            let target_bone = &target.bones[t];
+            let Some(&(rotation, translation, scale)) = pose.get(&elf_hash(&source_bone.name)) else {
+                continue;
+            };
+
+            let bind_offset = Vec3::from(target_bone.local_translation) - Vec3::from(source_bone.local_translation);
+            joint_frames
+                .entry(elf_hash(&target_bone.name))
+                .or_default()
+                .push(UncompressedFrame {
+                    translation_id: palettes.vector(translation + bind_offset)?,
+                    scale_id: palettes.vector(scale)?,
+                    rotation_id: palettes.quat(rotation)?,
+                });
+        }
+    }
+
+    let used_sources: HashSet<u32> =
+        pairs.iter().map(|(_, s)| elf_hash(&source.bones[*s].name)).collect();
+    let mapped: HashSet<usize> = pairs.iter().map(|(t, _)| *t).collect();
+    let report = RetargetReport {
+        output_path: String::new(),
+        frame_count,
+        mapped_joints: joint_frames.len(),
+        unmapped_joints: target
+            .bones
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !mapped.contains(i))
+            .map(|(_, bone)| bone.name.clone())
+            .collect(),
+        dropped_tracks: tracks.difference(&used_sources).count(),
+    };
+
+    Ok((Uncompressed::new(fps, palettes.vectors, palettes.quats, joint_frames), report))
+}
+
+/// Retargets an .anm file from one skeleton to another and writes the result
+///
+/// # Arguments
+/// * `anm_path` - Animation to retarget
+/// * `source_skl` - Skeleton the animation was made for
+/// * `target_skl` - Skeleton to retarget to
+/// * `output_path` - Where to write the new .anm
+/// * `bone_map` - Target joint name -> source joint name, for renamed joints
+pub fn retarget_animation(
+    anm_path: &Path,
+    source_skl: &Path,
+    target_skl: &Path,
+    output_path: &Path,
+    bone_map: &HashMap<String, String>,
+) -> anyhow::Result<RetargetReport> {
+    let animation = load_animation_asset(anm_path)?;
+    let source = parse_skl_file(source_skl)?;
+    let target = parse_skl_file(target_skl)?;
+
+    let (retargeted, mut report) = retarget(&animation, &source, &target, bone_map)?;
+    if report.mapped_joints == 0 {
+        anyhow::bail!("No joints of the target skeleton match the animation");
+    }
+
+    let mut data = Cursor::new(Vec::new());
+    retargeted.to_writer(&mut data)?;
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    write_replacing(output_path, data.get_ref())?;
+
+    report.output_path = output_path.display().to_string();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mesh::skl::BoneData;
+    use glam::Mat4;
+    use ltk_anim::AnimationAsset;
+
+    fn skeleton(bones: &[(&str, [f32; 3])]) -> SklData {
+        SklData {
+            name: "test".to_string(),
+            asset_name: "test".to_string(),
+            bones: bones
+                .iter()
+                .enumerate()
+                .map(|(i, (name, translation))| BoneData {
+                    name: name.to_string(),
+                    id: i as i16,
+                    parent_id: i as i16 - 1,
+                    local_translation: *translation,
+                    local_rotation: [0.0, 0.0, 0.0, 1.0],
+                    local_scale: [1.0; 3],
+                    world_position: [0.0; 3],
+                    inverse_bind_matrix: Mat4::IDENTITY.to_cols_array_2d(),
+                })
+                .collect(),
+            influences: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_retarget_remaps_tracks() {
+        let source = skeleton(&[("Root", [0.0; 3]), ("Spine", [0.0, 1.0, 0.0]), ("Tail", [0.0, 0.0, -1.0])]);
+        let target = skeleton(&[("root", [0.0; 3]), ("Spine", [0.0, 2.0, 0.0]), ("Head", [0.0, 1.0, 0.0])]);
+
+        // Two frames: spine rotates and bobs up by 0.5
+        let turn = Quat::from_rotation_y(1.0);
+        let frames = |t0: u16, t1: u16, r1: u16| {
+            vec![
+                UncompressedFrame { translation_id: t0, scale_id: 3, rotation_id: 0 },
+                UncompressedFrame { translation_id: t1, scale_id: 3, rotation_id: r1 },
+            ]
+        };
+        let vectors = vec![Vec3::ZERO, Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 1.5, 0.0), Vec3::ONE];
+        let joint_frames = HashMap::from([
+            (elf_hash("Root"), frames(0, 0, 0)),
+            (elf_hash("Spine"), frames(1, 2, 1)),
+            (elf_hash("Tail"), frames(0, 0, 0)),
+        ]);
+        let animation = Uncompressed::new(2.0, vectors, vec![Quat::IDENTITY, turn], joint_frames);
+
+        let (retargeted, report) = retarget(&animation, &source, &target, &HashMap::new()).unwrap();
+        assert_eq!(report.frame_count, 2);
+        assert_eq!(report.mapped_joints, 2);
+        assert_eq!(report.unmapped_joints, vec!["Head".to_string()]);
+        assert_eq!(report.dropped_tracks, 1);
+
+        // Survives a write and read as an .anm
+        let mut data = Cursor::new(Vec::new());
+        retargeted.to_writer(&mut data).unwrap();
+        data.set_position(0);
+        let AnimationAsset::Uncompressed(read) = AnimationAsset::from_reader(&mut data).unwrap() else {
+            panic!("expected an uncompressed animation");
+        };
+
+        let (rotation, translation, _) = read.evaluate_frame(elf_hash("spine"), 1).unwrap();
+        assert!(rotation.angle_between(turn) < 1e-3);
+        // Bob kept, on top of the target's longer bone
+        assert!((translation - Vec3::new(0.0, 2.5, 0.0)).length() < 1e-4);
+
+        // A renamed joint can be mapped explicitly
+        let bone_map = HashMap::from([("Head".to_string(), "Tail".to_string())]);
+        let (_, report) = retarget(&animation, &source, &target, &bone_map).unwrap();
+        assert_eq!(report.mapped_joints, 3);
+        assert_eq!(report.dropped_tracks, 0);
+    }
+}
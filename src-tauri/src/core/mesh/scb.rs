@@ -11,9 +11,11 @@ use glam::Vec3;
 use serde::Serialize;
 
 use std::collections::HashMap;
+use ts_rs::TS;
 
 /// Complete static mesh data serializable to JSON for frontend
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
 pub struct ScbMeshData {
     /// Mesh name from file
     pub name: String,
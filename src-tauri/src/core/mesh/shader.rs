@@ -0,0 +1,338 @@
+//! Material/shader reference catalog
+//!
+//! Scans StaticMaterialDef blocks in extracted BIN data and records which
+//! samplers and params each shader is used with, along with human-friendly
+//! labels so the material editor doesn't have to show raw sampler names.
+
+use crate::core::bin::ltk_bridge;
+use crate::core::mesh::texture::extract_braced_block;
+use league_toolkit::hash::fnv1a;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+use ts_rs::TS;
+
+/// Shader name used for materials without a shader link
+const UNKNOWN_SHADER: &str = "Unknown";
+
+/// Known sampler/param names (lowercased) and their labels
+const KNOWN_LABELS: &[(&str, &str)] = &[
+    ("diffuse_texture", "Diffuse (base color)"),
+    ("diffuse_color", "Diffuse (base color)"),
+    ("diffusetexture", "Diffuse (base color)"),
+    ("mask_textures", "Mask (packed channels)"),
+    ("mask_texture", "Mask (packed channels)"),
+    ("glow_texture", "Glow mask (G channel)"),
+    ("glowmask", "Glow mask (G channel)"),
+    ("emissive_texture", "Emissive map"),
+    ("normal_texture", "Normal map"),
+    ("normal_map", "Normal map"),
+    ("matcap_texture", "Matcap reflection"),
+    ("matcap", "Matcap reflection"),
+    ("cubemap", "Reflection cubemap"),
+    ("gradient_texture", "Color ramp"),
+    ("ramp_texture", "Color ramp"),
+    ("noise_texture", "Noise texture"),
+    ("distortion_texture", "Distortion map"),
+    ("fresnel_color", "Fresnel rim color"),
+    ("fresnel", "Fresnel strength"),
+    ("emissive_color", "Emissive color"),
+    ("emissive_intensity", "Emissive intensity"),
+    ("bloom_intensity", "Bloom intensity"),
+    ("alpha_test_value", "Alpha test threshold"),
+    ("uv_scale", "UV scale"),
+    ("uv_offset", "UV offset"),
+    ("specular_intensity", "Specular intensity"),
+    ("color_mult", "Color multiplier"),
+];
+
+/// A sampler or param observed on a shader
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CatalogField {
+    /// Raw name as it appears in the BIN (e.g., "Diffuse_Texture")
+    pub name: String,
+    /// Human-friendly label (e.g., "Diffuse (base color)")
+    pub label: String,
+    /// Number of materials using this field
+    pub count: usize,
+}
+
+/// Conventions observed for a single shader
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ShaderEntry {
+    /// Shader link path (e.g., "Shaders/SkinnedMesh/Default")
+    pub shader: String,
+    /// Number of StaticMaterialDefs using this shader
+    pub material_count: usize,
+    pub samplers: Vec<CatalogField>,
+    pub params: Vec<CatalogField>,
+}
+
+/// Catalog of every shader observed in a set of BIN files
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ShaderCatalog {
+    pub shaders: Vec<ShaderEntry>,
+    /// Number of BIN files that contained material definitions
+    pub bins_scanned: usize,
+}
+
+/// Returns a human-friendly label for a sampler or param name
+///
+/// Falls back to splitting the raw name on underscores/camelCase.
+pub fn label_for(name: &str) -> String {
+    let lower = name.to_lowercase();
+    if let Some((_, label)) = KNOWN_LABELS.iter().find(|(key, _)| *key == lower) {
+        return label.to_string();
+    }
+
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for c in name.chars() {
+        if c == '_' || c == ' ' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else if c.is_uppercase() && current.chars().last().is_some_and(|p| p.is_lowercase()) {
+            words.push(std::mem::take(&mut current));
+            current.push(c);
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let mut label = words.join(" ").to_lowercase();
+    if let Some(first) = label.get(0..1) {
+        label = first.to_uppercase() + &label[1..];
+    }
+    label
+}
+
+#[derive(Default)]
+struct ShaderAccumulator {
+    material_count: usize,
+    samplers: BTreeMap<String, usize>,
+    params: BTreeMap<String, usize>,
+}
+
+/// Collects quoted string values of `field: string = "..."` inside the embeds of a list
+fn collect_list_names(block: &str, list_regex: &Regex, name_regex: &Regex) -> Vec<String> {
+    let Some(list_match) = list_regex.find(block) else {
+        return Vec::new();
+    };
+    let Some(list_block) = extract_braced_block(block, list_match.end() - 1) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = name_regex
+        .captures_iter(&list_block)
+        .filter_map(|c| c.get(1).map(|m| m.as_str().to_string()))
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Builds a shader catalog from ritobin text content
+pub fn catalog_from_texts<'a>(texts: impl IntoIterator<Item = &'a str>) -> ShaderCatalog {
+    let def_regex = Regex::new(r"=\s*StaticMaterialDef\s*\{").unwrap();
+    let shader_regex = Regex::new(r#"(?i)shader:\s*link\s*=\s*"([^"]+)""#).unwrap();
+    let sampler_list_regex = Regex::new(r"(?i)samplerValues:\s*list2?\[embed\]\s*=\s*\{").unwrap();
+    let sampler_name_regex = Regex::new(r#"(?i)(?:TextureName|samplerName):\s*string\s*=\s*"([^"]+)""#).unwrap();
+    let param_list_regex = Regex::new(r"(?i)paramValues:\s*list2?\[embed\]\s*=\s*\{").unwrap();
+    let param_name_regex = Regex::new(r#"(?i)\bname:\s*string\s*=\s*"([^"]+)""#).unwrap();
+
+    let mut shaders: BTreeMap<String, ShaderAccumulator> = BTreeMap::new();
+    let mut bins_scanned = 0;
+
+    for text in texts {
+        bins_scanned += 1;
+
+        for def in def_regex.find_iter(text) {
+            let Some(block) = extract_braced_block(text, def.end() - 1) else {
+                continue;
+            };
+
+            let shader = shader_regex
+                .captures(&block)
+                .and_then(|c| c.get(1))
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_else(|| UNKNOWN_SHADER.to_string());
+
+            let entry = shaders.entry(shader).or_default();
+            entry.material_count += 1;
+
+            for name in collect_list_names(&block, &sampler_list_regex, &sampler_name_regex) {
+                *entry.samplers.entry(name).or_default() += 1;
+            }
+            for name in collect_list_names(&block, &param_list_regex, &param_name_regex) {
+                *entry.params.entry(name).or_default() += 1;
+            }
+        }
+    }
+
+    let to_fields = |map: BTreeMap<String, usize>| -> Vec<CatalogField> {
+        let mut fields: Vec<CatalogField> = map
+            .into_iter()
+            .map(|(name, count)| CatalogField { label: label_for(&name), name, count })
+            .collect();
+        fields.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+        fields
+    };
+
+    let mut entries: Vec<ShaderEntry> = shaders
+        .into_iter()
+        .map(|(shader, acc)| ShaderEntry {
+            shader,
+            material_count: acc.material_count,
+            samplers: to_fields(acc.samplers),
+            params: to_fields(acc.params),
+        })
+        .collect();
+    entries.sort_by(|a, b| b.material_count.cmp(&a.material_count).then_with(|| a.shader.cmp(&b.shader)));
+
+    ShaderCatalog { shaders: entries, bins_scanned }
+}
+
+/// Builds a shader catalog from every BIN file under a directory
+///
+/// # Arguments
+/// * `root` - Directory to scan (e.g., a project's content/base folder)
+pub fn build_shader_catalog(root: &Path) -> anyhow::Result<ShaderCatalog> {
+    if !root.is_dir() {
+        anyhow::bail!("Directory not found: {}", root.display());
+    }
+
+    let mut texts = Vec::new();
+    for entry in WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let is_bin = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("bin"));
+        if !entry.file_type().is_file() || !is_bin {
+            continue;
+        }
+
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!("Failed to read {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        // Most BINs have no materials; skip those before paying for text conversion
+        if !data.windows(b"StaticMaterialDef".len()).any(|w| w == b"StaticMaterialDef")
+            && !contains_material_class_hash(&data)
+        {
+            continue;
+        }
+
+        match ltk_bridge::read_bin(&data).and_then(|tree| ltk_bridge::tree_to_text_cached(&tree)) {
+            Ok(text) => texts.push(text),
+            Err(e) => tracing::warn!("Failed to convert {}: {}", path.display(), e),
+        }
+    }
+
+    tracing::info!("Building shader catalog from {} material BINs", texts.len());
+    Ok(catalog_from_texts(texts.iter().map(|t| t.as_str())))
+}
+
+/// Checks for the StaticMaterialDef class hash (BINs store class names hashed)
+fn contains_material_class_hash(data: &[u8]) -> bool {
+    let hash = fnv1a::hash_lower("StaticMaterialDef").to_le_bytes();
+    data.windows(4).any(|w| w == hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+    "Characters/Test/Skins/Skin0/Materials/Body" = StaticMaterialDef {
+        name: string = "Characters/Test/Skins/Skin0/Materials/Body"
+        samplerValues: list2[embed] = {
+            StaticMaterialShaderSamplerDef {
+                TextureName: string = "Diffuse_Texture"
+                texturePath: string = "ASSETS/Characters/Test/Body.tex"
+            }
+            StaticMaterialShaderSamplerDef {
+                TextureName: string = "Glow_Texture"
+                texturePath: string = "ASSETS/Characters/Test/Glow.tex"
+            }
+        }
+        paramValues: list2[embed] = {
+            StaticMaterialShaderParamDef {
+                name: string = "Fresnel_Color"
+                value: vec4 = { 1, 0, 0, 1 }
+            }
+        }
+        techniques: list[embed] = {
+            StaticMaterialTechniqueDef {
+                passes: list[embed] = {
+                    StaticMaterialPassDef {
+                        shader: link = "Shaders/SkinnedMesh/Emissive"
+                    }
+                }
+            }
+        }
+    }
+    "Characters/Test/Skins/Skin0/Materials/Eyes" = StaticMaterialDef {
+        samplerValues: list2[embed] = {
+            StaticMaterialShaderSamplerDef {
+                TextureName: string = "Diffuse_Texture"
+            }
+        }
+        techniques: list[embed] = {
+            StaticMaterialTechniqueDef {
+                passes: list[embed] = {
+                    StaticMaterialPassDef {
+                        shader: link = "Shaders/SkinnedMesh/Emissive"
+                    }
+                }
+            }
+        }
+    }
+    "#;
+
+    #[test]
+    fn test_catalog_from_text() {
+        let catalog = catalog_from_texts([SAMPLE]);
+        assert_eq!(catalog.bins_scanned, 1);
+        assert_eq!(catalog.shaders.len(), 1);
+
+        let shader = &catalog.shaders[0];
+        assert_eq!(shader.shader, "Shaders/SkinnedMesh/Emissive");
+        assert_eq!(shader.material_count, 2);
+        assert_eq!(shader.samplers[0].name, "Diffuse_Texture");
+        assert_eq!(shader.samplers[0].count, 2);
+        assert_eq!(shader.samplers[1].label, "Glow mask (G channel)");
+        assert_eq!(shader.params.len(), 1);
+        assert_eq!(shader.params[0].label, "Fresnel rim color");
+    }
+
+    #[test]
+    fn test_label_fallback() {
+        assert_eq!(label_for("Diffuse_Texture"), "Diffuse (base color)");
+        assert_eq!(label_for("Dissolve_Edge_Width"), "Dissolve edge width");
+        assert_eq!(label_for("RimLightColor"), "Rim light color");
+    }
+
+    #[test]
+    fn test_material_class_hash() {
+        let hash = fnv1a::hash_lower("StaticMaterialDef").to_le_bytes();
+        let mut data = b"PROP....".to_vec();
+        assert!(!contains_material_class_hash(&data));
+        data.extend_from_slice(&hash);
+        assert!(contains_material_class_hash(&data));
+    }
+}
@@ -8,9 +8,11 @@ use std::path::Path;
 
 use ltk_anim::RigResource;
 use serde::Serialize;
+use ts_rs::TS;
 
 /// Bone data for a single joint in the skeleton
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct BoneData {
     pub name: String,
     pub id: i16,
@@ -29,7 +31,8 @@ pub struct BoneData {
 }
 
 /// Complete skeleton data serializable to JSON for frontend
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
 pub struct SklData {
     pub name: String,
     pub asset_name: String,
@@ -16,9 +16,11 @@ use glam::{Vec2, Vec3, Vec4};
 use serde::Serialize;
 
 use std::collections::HashMap;
+use ts_rs::TS;
 
 /// Material range data for frontend consumption
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct MaterialRange {
     pub name: String,
     pub start_index: i32,
@@ -40,7 +42,8 @@ impl From<&SkinnedMeshRange> for MaterialRange {
 }
 
 /// Material data including texture and UV parameters for frontend consumption
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct MaterialData {
     /// Base64-encoded PNG texture data
     pub texture: String,
@@ -59,7 +62,8 @@ pub struct MaterialData {
 }
 
 /// Complete mesh data serializable to JSON for frontend
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
 pub struct SknMeshData {
     /// Material ranges for visibility control
     pub materials: Vec<MaterialRange>,
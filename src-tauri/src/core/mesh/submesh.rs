@@ -0,0 +1,467 @@
+//! SKN submesh metadata editing
+//!
+//! A submesh is named by its range in the SKN. The skin BIN's
+//! `skinMeshProperties` refers to it by that name: `materialOverride` entries
+//! pick the texture or material it's drawn with, and `initialSubmeshToHide`
+//! lists submeshes hidden until an animation shows them.
+//! [`crate::core::mesh::texture`] reads these for the viewer; this module
+//! edits them through the BIN tree, and renames ranges in place in the SKN.
+
+use crate::core::bin::editor;
+use crate::core::bin::ltk_bridge::HashMapProvider;
+use crate::core::store::write_replacing;
+use crate::error::{Error, Result};
+use indexmap::IndexMap;
+use league_toolkit::hash::fnv1a::hash_lower;
+use ltk_meta::value::*;
+use ltk_meta::{BinProperty, BinPropertyKind, BinTree, PropertyValueEnum};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+
+const SKN_MAGIC: u32 = 0x0011_2233;
+/// Range names are stored in fixed 64 byte fields, NUL padded
+const RANGE_NAME_LEN: usize = 64;
+/// Name field plus start vertex, vertex count, start index and index count
+const RANGE_LEN: usize = RANGE_NAME_LEN + 16;
+/// Magic, version and range count
+const RANGES_OFFSET: usize = 12;
+
+const OVERRIDE_CLASS: &str = "SkinMeshDataProperties_MaterialOverride";
+/// Fields listing submeshes by name, separated by spaces
+const HIDE_FIELDS: &[&str] = &["initialSubmeshToHide", "initialSubmeshShadowsToHide"];
+
+/// A submesh of an SKN with what the skin BIN sets for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SubmeshInfo {
+    pub name: String,
+    /// Texture of its `materialOverride` entry
+    pub texture: Option<String>,
+    /// Material linked by its `materialOverride` entry (path, or hex hash if unknown)
+    pub material: Option<String>,
+    /// Listed in `initialSubmeshToHide`
+    pub hidden: bool,
+}
+
+/// The submeshes of an SKN and the skin BIN's defaults
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SkinSubmeshes {
+    /// Texture of submeshes without an override
+    pub default_texture: Option<String>,
+    /// Material of submeshes without an override
+    pub default_material: Option<String>,
+    /// In SKN range order
+    pub submeshes: Vec<SubmeshInfo>,
+}
+
+/// Name of an SKN range and where its name field is in the file
+struct SknRange {
+    name: String,
+    offset: usize,
+}
+
+/// Reads the range names of an SKN
+fn read_ranges(data: &[u8]) -> Result<Vec<SknRange>> {
+    let u32_at = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .ok_or_else(|| Error::InvalidInput("SKN file is truncated".to_string()))
+    };
+    if u32_at(0)? != SKN_MAGIC {
+        return Err(Error::InvalidInput("Not an SKN file".to_string()));
+    }
+    // Major version in the low half
+    if u32_at(4)? & 0xFFFF == 0 {
+        return Err(Error::InvalidInput("Version 0 SKN files have no named submeshes".to_string()));
+    }
+
+    let count = u32_at(8)? as usize;
+    let mut ranges = Vec::with_capacity(count.min(256));
+    for index in 0..count {
+        let offset = RANGES_OFFSET + index * RANGE_LEN;
+        let field = data
+            .get(offset..offset + RANGE_NAME_LEN)
+            .ok_or_else(|| Error::InvalidInput("SKN file is truncated".to_string()))?;
+        let end = field.iter().position(|&b| b == 0).unwrap_or(RANGE_NAME_LEN);
+        let name = std::str::from_utf8(&field[..end])
+            .map_err(|_| Error::InvalidInput("SKN submesh name isn't UTF-8".to_string()))?;
+        ranges.push(SknRange { name: name.to_string(), offset });
+    }
+    Ok(ranges)
+}
+
+/// Name of a submesh as the SKN spells it
+fn skn_name(ranges: &[SknRange], submesh: &str) -> Result<String> {
+    ranges
+        .iter()
+        .find(|range| range.name.eq_ignore_ascii_case(submesh))
+        .map(|range| range.name.clone())
+        .ok_or_else(|| Error::InvalidInput(format!("SKN has no submesh named '{}'", submesh)))
+}
+
+/// The `skinMeshProperties` struct of a skin BIN
+fn skin_mesh_properties(tree: &mut BinTree) -> Result<&mut StructValue> {
+    let field = hash_lower("skinMeshProperties");
+    tree.objects
+        .values_mut()
+        .filter_map(|object| object.properties.get_mut(&field))
+        .find_map(|prop| match &mut prop.value {
+            PropertyValueEnum::Embedded(EmbeddedValue(s)) | PropertyValueEnum::Struct(s) => Some(s),
+            _ => None,
+        })
+        .ok_or_else(|| Error::InvalidInput("BIN has no skinMeshProperties".to_string()))
+}
+
+fn string_field<'a>(value: &'a StructValue, name: &str) -> Option<&'a str> {
+    match &value.properties.get(&hash_lower(name))?.value {
+        PropertyValueEnum::String(s) => Some(s.0.as_str()),
+        _ => None,
+    }
+}
+
+fn set_string_field(value: &mut StructValue, name: &str, text: String) {
+    let name_hash = hash_lower(name);
+    value.properties.insert(name_hash, BinProperty { name_hash, value: PropertyValueEnum::String(StringValue(text)) });
+}
+
+fn link_field(value: &StructValue, name: &str, names: &HashMapProvider) -> Option<String> {
+    match &value.properties.get(&hash_lower(name))?.value {
+        PropertyValueEnum::ObjectLink(link) => Some(
+            names.entries.get(&link.0).cloned().unwrap_or_else(|| format!("0x{:08x}", link.0)),
+        ),
+        _ => None,
+    }
+}
+
+fn override_list(props: &mut StructValue) -> Option<&mut Vec<PropertyValueEnum>> {
+    match &mut props.properties.get_mut(&hash_lower("materialOverride"))?.value {
+        PropertyValueEnum::Container(c) | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(c)) => {
+            Some(&mut c.items)
+        }
+        _ => None,
+    }
+}
+
+fn override_struct(item: &mut PropertyValueEnum) -> Option<&mut StructValue> {
+    match item {
+        PropertyValueEnum::Embedded(EmbeddedValue(s)) | PropertyValueEnum::Struct(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Splits a hide list; names are separated by spaces (some BINs use commas)
+fn hidden_names(props: &StructValue, field: &str) -> Vec<String> {
+    string_field(props, field)
+        .map(|list| {
+            list.split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn set_hidden_names(props: &mut StructValue, field: &str, hidden: Vec<String>) {
+    if hidden.is_empty() {
+        props.properties.shift_remove(&hash_lower(field));
+    } else {
+        set_string_field(props, field, hidden.join(" "));
+    }
+}
+
+fn collect_submeshes(tree: &mut BinTree, ranges: &[SknRange], names: &HashMapProvider) -> Result<SkinSubmeshes> {
+    let props = skin_mesh_properties(tree)?;
+    let hidden = hidden_names(props, HIDE_FIELDS[0]);
+    let default_texture = string_field(props, "texture").map(str::to_string);
+    let default_material = link_field(props, "material", names);
+
+    let mut overrides: Vec<(String, Option<String>, Option<String>)> = Vec::new();
+    for item in override_list(props).into_iter().flatten() {
+        let Some(entry) = override_struct(item) else { continue };
+        if let Some(submesh) = string_field(entry, "submesh") {
+            overrides.push((
+                submesh.to_string(),
+                string_field(entry, "texture").map(str::to_string),
+                link_field(entry, "material", names),
+            ));
+        }
+    }
+
+    let submeshes = ranges
+        .iter()
+        .map(|range| {
+            let name = &range.name;
+            let entry = overrides.iter().find(|(submesh, _, _)| submesh.eq_ignore_ascii_case(name));
+            SubmeshInfo {
+                name: name.clone(),
+                texture: entry.and_then(|(_, texture, _)| texture.clone()),
+                material: entry.and_then(|(_, _, material)| material.clone()),
+                hidden: hidden.iter().any(|h| h.eq_ignore_ascii_case(name)),
+            }
+        })
+        .collect();
+
+    Ok(SkinSubmeshes { default_texture, default_material, submeshes })
+}
+
+/// Points a submesh at a texture in a skin BIN tree
+///
+/// An override linking a material is switched to the texture, since the
+/// material would win otherwise. `None` removes the submesh's override, so it
+/// falls back to the default texture.
+pub fn set_submesh_texture_in_tree(tree: &mut BinTree, submesh: &str, texture: Option<&str>) -> Result<()> {
+    let props = skin_mesh_properties(tree)?;
+    if override_list(props).is_none() {
+        let list = PropertyValueEnum::Container(ContainerValue { item_kind: BinPropertyKind::Embedded, items: Vec::new() });
+        let name_hash = hash_lower("materialOverride");
+        props.properties.insert(name_hash, BinProperty { name_hash, value: list });
+    }
+    let items = override_list(props).ok_or_else(|| Error::InvalidInput("materialOverride isn't a list".to_string()))?;
+
+    let position = items.iter_mut().position(|item| {
+        override_struct(item)
+            .and_then(|entry| string_field(entry, "submesh"))
+            .is_some_and(|name| name.eq_ignore_ascii_case(submesh))
+    });
+    match (position, texture) {
+        (Some(index), Some(texture)) => {
+            let entry = override_struct(&mut items[index]).expect("matched entries are structs");
+            entry.properties.shift_remove(&hash_lower("material"));
+            set_string_field(entry, "texture", texture.to_string());
+        }
+        (None, Some(texture)) => {
+            let mut entry = StructValue { class_hash: hash_lower(OVERRIDE_CLASS), properties: IndexMap::new() };
+            set_string_field(&mut entry, "texture", texture.to_string());
+            set_string_field(&mut entry, "submesh", submesh.to_string());
+            items.push(PropertyValueEnum::Embedded(EmbeddedValue(entry)));
+        }
+        (Some(index), None) => {
+            items.remove(index);
+        }
+        (None, None) => {}
+    }
+    Ok(())
+}
+
+/// Adds a submesh to or removes it from `initialSubmeshToHide` in a skin BIN tree
+pub fn set_submesh_hidden_in_tree(tree: &mut BinTree, submesh: &str, hidden: bool) -> Result<()> {
+    let props = skin_mesh_properties(tree)?;
+    let mut names = hidden_names(props, HIDE_FIELDS[0]);
+    let listed = names.iter().any(|name| name.eq_ignore_ascii_case(submesh));
+    if hidden && !listed {
+        names.push(submesh.to_string());
+    } else if !hidden && listed {
+        names.retain(|name| !name.eq_ignore_ascii_case(submesh));
+    } else {
+        return Ok(());
+    }
+    set_hidden_names(props, HIDE_FIELDS[0], names);
+    Ok(())
+}
+
+/// Renames the references to a submesh in a skin BIN tree
+///
+/// Covers `materialOverride` entries and the hide lists.
+pub fn rename_submesh_in_tree(tree: &mut BinTree, old_name: &str, new_name: &str) -> Result<()> {
+    let props = skin_mesh_properties(tree)?;
+    for item in override_list(props).into_iter().flatten() {
+        let Some(entry) = override_struct(item) else { continue };
+        if string_field(entry, "submesh").is_some_and(|name| name.eq_ignore_ascii_case(old_name)) {
+            set_string_field(entry, "submesh", new_name.to_string());
+        }
+    }
+    for field in HIDE_FIELDS {
+        let names = hidden_names(props, field);
+        if names.iter().any(|name| name.eq_ignore_ascii_case(old_name)) {
+            let renamed = names
+                .into_iter()
+                .map(|name| if name.eq_ignore_ascii_case(old_name) { new_name.to_string() } else { name })
+                .collect();
+            set_hidden_names(props, field, renamed);
+        }
+    }
+    Ok(())
+}
+
+fn read_skn_ranges(skn_path: &Path) -> Result<(Vec<u8>, Vec<SknRange>)> {
+    let data = fs::read(skn_path).map_err(|e| Error::io_with_path(e, skn_path))?;
+    let ranges = read_ranges(&data)?;
+    Ok((data, ranges))
+}
+
+/// Lists the submeshes of an SKN with their texture, material and visibility
+///
+/// # Arguments
+/// * `skn_path` - The mesh
+/// * `bin_path` - Skin BIN with its `skinMeshProperties`
+/// * `names` - Hash names, to show linked materials by path
+pub fn get_submeshes(skn_path: &Path, bin_path: &Path, names: &HashMapProvider) -> Result<SkinSubmeshes> {
+    let (_, ranges) = read_skn_ranges(skn_path)?;
+    let mut tree = editor::load(bin_path)?;
+    collect_submeshes(&mut tree, &ranges, names)
+}
+
+/// Sets the texture of a submesh in the skin BIN and saves it
+///
+/// See [`set_submesh_texture_in_tree`] for how overrides are changed.
+pub fn set_submesh_texture(
+    skn_path: &Path,
+    bin_path: &Path,
+    names: &HashMapProvider,
+    submesh: &str,
+    texture: Option<&str>,
+) -> Result<SkinSubmeshes> {
+    let (_, ranges) = read_skn_ranges(skn_path)?;
+    let submesh = skn_name(&ranges, submesh)?;
+    let mut tree = editor::load(bin_path)?;
+    set_submesh_texture_in_tree(&mut tree, &submesh, texture.filter(|t| !t.is_empty()))?;
+    editor::save(bin_path, &tree)?;
+    collect_submeshes(&mut tree, &ranges, names)
+}
+
+/// Hides or shows a submesh by default and saves the skin BIN
+pub fn set_submesh_hidden(
+    skn_path: &Path,
+    bin_path: &Path,
+    names: &HashMapProvider,
+    submesh: &str,
+    hidden: bool,
+) -> Result<SkinSubmeshes> {
+    let (_, ranges) = read_skn_ranges(skn_path)?;
+    let submesh = skn_name(&ranges, submesh)?;
+    let mut tree = editor::load(bin_path)?;
+    set_submesh_hidden_in_tree(&mut tree, &submesh, hidden)?;
+    editor::save(bin_path, &tree)?;
+    collect_submeshes(&mut tree, &ranges, names)
+}
+
+/// Renames a submesh in the SKN and every reference to it in the skin BIN
+///
+/// The name is rewritten in place, so the rest of the mesh is kept byte for
+/// byte. Names can't contain whitespace or commas (the hide lists are
+/// separated by them) and must fit the SKN's 64 byte field.
+pub fn rename_submesh(
+    skn_path: &Path,
+    bin_path: &Path,
+    names: &HashMapProvider,
+    old_name: &str,
+    new_name: &str,
+) -> Result<SkinSubmeshes> {
+    if new_name.is_empty()
+        || new_name.len() >= RANGE_NAME_LEN
+        || new_name.chars().any(|c| c.is_whitespace() || c == ',' || c == '\0')
+    {
+        return Err(Error::InvalidInput(format!(
+            "Invalid submesh name '{}': use up to {} characters without spaces or commas",
+            new_name,
+            RANGE_NAME_LEN - 1
+        )));
+    }
+
+    let (mut data, mut ranges) = read_skn_ranges(skn_path)?;
+    let old_name = skn_name(&ranges, old_name)?;
+    if !old_name.eq_ignore_ascii_case(new_name) && skn_name(&ranges, new_name).is_ok() {
+        return Err(Error::InvalidInput(format!("SKN already has a submesh named '{}'", new_name)));
+    }
+
+    let mut tree = editor::load(bin_path)?;
+    rename_submesh_in_tree(&mut tree, &old_name, new_name)?;
+
+    for range in ranges.iter_mut().filter(|range| range.name == old_name) {
+        let field = &mut data[range.offset..range.offset + RANGE_NAME_LEN];
+        field.fill(0);
+        field[..new_name.len()].copy_from_slice(new_name.as_bytes());
+        range.name = new_name.to_string();
+    }
+    write_replacing(skn_path, &data).map_err(|e| Error::io_with_path(e, skn_path))?;
+    editor::save(bin_path, &tree)?;
+
+    tracing::info!("Renamed submesh {} to {} in {}", old_name, new_name, skn_path.display());
+    collect_submeshes(&mut tree, &ranges, names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+
+    const SKIN: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin0" = SkinCharacterDataProperties {
+        skinMeshProperties: embed = SkinMeshDataProperties {
+            texture: string = "ASSETS/Ahri_Base_TX_CM.dds"
+            initialSubmeshToHide: string = "Tails Orb"
+            materialOverride: list[embed] = {
+                SkinMeshDataProperties_MaterialOverride {
+                    material: link = "Characters/Ahri/Skins/Skin0/Materials/Orb_inst"
+                    submesh: string = "Orb"
+                }
+            }
+        }
+    }
+}
+"#;
+
+    /// SKN header and ranges only; enough for the name edits
+    fn skn(names: &[&str]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&SKN_MAGIC.to_le_bytes());
+        data.extend_from_slice(&4u16.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&(names.len() as u32).to_le_bytes());
+        for (i, name) in names.iter().enumerate() {
+            let mut field = [0u8; RANGE_NAME_LEN];
+            field[..name.len()].copy_from_slice(name.as_bytes());
+            data.extend_from_slice(&field);
+            for value in [i as i32 * 10, 10, i as i32 * 30, 30] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_edit_submeshes() {
+        let dir = tempfile::tempdir().unwrap();
+        let skn_path = dir.path().join("ahri.skn");
+        let bin_path = dir.path().join("skin0.bin");
+        fs::write(&skn_path, skn(&["Body", "Tails", "Orb"])).unwrap();
+        fs::write(&bin_path, write_bin(&text_to_tree(SKIN).unwrap()).unwrap()).unwrap();
+        let names = HashMapProvider::new();
+
+        let listed = get_submeshes(&skn_path, &bin_path, &names).unwrap();
+        assert_eq!(listed.default_texture.as_deref(), Some("ASSETS/Ahri_Base_TX_CM.dds"));
+        let orb = &listed.submeshes[2];
+        assert!(orb.hidden && orb.texture.is_none());
+        assert_eq!(orb.material, Some(format!("0x{:08x}", hash_lower("Characters/Ahri/Skins/Skin0/Materials/Orb_inst"))));
+
+        // Retexturing replaces the material link; new overrides are added
+        set_submesh_texture(&skn_path, &bin_path, &names, "orb", Some("ASSETS/Orb.dds")).unwrap();
+        let listed = set_submesh_texture(&skn_path, &bin_path, &names, "Body", Some("ASSETS/Body.dds")).unwrap();
+        assert_eq!(listed.submeshes[2].texture.as_deref(), Some("ASSETS/Orb.dds"));
+        assert!(listed.submeshes[2].material.is_none());
+        assert_eq!(listed.submeshes[0].texture.as_deref(), Some("ASSETS/Body.dds"));
+        let listed = set_submesh_texture(&skn_path, &bin_path, &names, "Body", None).unwrap();
+        assert!(listed.submeshes[0].texture.is_none());
+
+        let listed = set_submesh_hidden(&skn_path, &bin_path, &names, "Tails", false).unwrap();
+        assert!(!listed.submeshes[1].hidden && listed.submeshes[2].hidden);
+        assert!(set_submesh_hidden(&skn_path, &bin_path, &names, "Missing", true).is_err());
+
+        let listed = rename_submesh(&skn_path, &bin_path, &names, "Orb", "FoxFire").unwrap();
+        assert_eq!(listed.submeshes[2].name, "FoxFire");
+        assert!(listed.submeshes[2].hidden);
+        assert_eq!(listed.submeshes[2].texture.as_deref(), Some("ASSETS/Orb.dds"));
+        // Only the name field changed
+        assert_eq!(fs::read(&skn_path).unwrap(), skn(&["Body", "Tails", "FoxFire"]));
+
+        assert!(rename_submesh(&skn_path, &bin_path, &names, "Body", "Tails").is_err());
+        assert!(rename_submesh(&skn_path, &bin_path, &names, "Body", "Two words").is_err());
+    }
+}
@@ -2,18 +2,28 @@
 //! 
 //! Parses skin0.bin to extract texture mappings for SKN materials.
 //! Supports SkinMeshDataProperties with texture and materialOverride fields.
+//!
+//! The parsed tree is walked by property hash, so unresolved hashes or
+//! ritobin formatting don't matter. Matching ritobin text with regexes is
+//! kept as a fallback for BINs the walk can't read.
 
 // Imports from original file
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::core::bin::ltk_bridge;
+use crate::core::bin::ltk_bridge::{self, get_cached_bin_hashes, HashMapProvider};
+use indexmap::IndexMap;
+use league_toolkit::hash::fnv1a::hash_lower;
+use ltk_meta::value::*;
+use ltk_meta::{BinProperty, BinTree, BinTreeObject, PropertyValueEnum};
 use serde::Serialize;
 use regex::Regex;
+use ts_rs::TS;
 
 /// Extended material properties including UV transformations
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default, TS)]
+#[ts(export)]
 pub struct MaterialProperties {
     /// Diffuse texture path
     pub texture_path: String,
@@ -36,7 +46,8 @@ pub struct MaterialProperties {
 }
 
 /// Texture mapping extracted from BIN file with UV transform parameters
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default, TS)]
+#[ts(export)]
 pub struct TextureMapping {
     /// Default texture path for meshes without specific override
     pub default_texture: Option<String>,
@@ -48,9 +59,67 @@ pub struct TextureMapping {
     /// Static material references that couldn't be resolved (for debugging)
     pub static_materials: Vec<String>,
     
-    /// Raw ritobin content for late lookups
+    /// Raw ritobin content for late lookups; only kept by the text fallback
     #[serde(skip)]
     pub ritobin_content: String,
+
+    /// Every StaticMaterialDef of the BIN with a texture, for late lookups
+    #[serde(skip)]
+    #[ts(skip)]
+    materials: Vec<TreeMaterial>,
+}
+
+/// A StaticMaterialDef found by the tree walk
+#[derive(Debug, Clone)]
+struct TreeMaterial {
+    path_hash: u32,
+    /// Lowercased object path, if the hash is known
+    name: Option<String>,
+    properties: MaterialProperties,
+}
+
+/// Sampler names that usually hold the diffuse texture
+const DIFFUSE_SAMPLER_NAMES: &[&str] = &[
+    "diffuse_color",
+    "diffuse_texture",
+    "diffuse",
+    "base_color",
+    "basecolor",
+    "albedo",
+    "color",
+    "_cm",  // Common suffix for color maps
+];
+
+/// Texture path parts that rule a sampler out as the diffuse fallback
+const NON_DIFFUSE_HINTS: &[&str] = &["normal", "_nm", "mask", "noise", "ramp"];
+
+impl TextureMapping {
+    /// Look up MaterialProperties for a material that no override names
+    ///
+    /// Matches StaticMaterialDef paths exactly (by hash), then by their last
+    /// segment, then anywhere in the path, ignoring case.
+    pub fn lookup_material(&self, material_name: &str) -> Option<MaterialProperties> {
+        let hash = hash_lower(material_name);
+        let wanted = material_name.to_lowercase();
+        let suffix = format!("/{}", wanted);
+        let named = |test: &dyn Fn(&str) -> bool| {
+            self.materials.iter().find(|m| m.name.as_deref().is_some_and(test))
+        };
+
+        self.materials
+            .iter()
+            .find(|m| m.path_hash == hash)
+            .or_else(|| named(&|name| name.ends_with(&suffix)))
+            .or_else(|| named(&|name| name.contains(&wanted)))
+            .map(|m| m.properties.clone())
+            .or_else(|| {
+                if self.ritobin_content.is_empty() {
+                    None
+                } else {
+                    lookup_material_texture_by_name(&self.ritobin_content, material_name)
+                }
+            })
+    }
 }
 
 /// Find skin0.bin (or skinN.bin) relative to an SKN file
@@ -212,13 +281,24 @@ pub fn find_skin_bin(skn_path: &Path) -> Option<PathBuf> {
 
 /// Extract texture mappings from a skin0.bin file
 /// 
-/// Parses the BIN file by converting it to Ritobin text format and using regex
-/// to find skinMeshProperties and material overrides.
+/// Walks the parsed BIN for skinMeshProperties and material overrides. If the
+/// tree has no skinMeshProperties, falls back to converting it to Ritobin text
+/// and matching with regex.
 pub fn extract_texture_mapping(bin_path: &Path) -> anyhow::Result<TextureMapping> {
     let data = fs::read(bin_path)?;
     let tree = ltk_bridge::read_bin(&data)
         .map_err(|e| anyhow::anyhow!("Failed to parse BIN: {}", e))?;
     
+    // The text conversion takes the hash cache lock again, so release it first
+    let mapping = {
+        let names = get_cached_bin_hashes().read();
+        extract_texture_mapping_from_tree(&tree, &names)
+    };
+    if let Some(mapping) = mapping {
+        return Ok(mapping);
+    }
+    tracing::debug!("No skinMeshProperties found in tree, falling back to ritobin text");
+    
     // Convert to text using cached hashes for better readability/matching
     let textual_content = ltk_bridge::tree_to_text_cached(&tree)
         .map_err(|e| anyhow::anyhow!("Failed to convert BIN to text: {}", e))?;
@@ -226,6 +306,143 @@ pub fn extract_texture_mapping(bin_path: &Path) -> anyhow::Result<TextureMapping
     extract_texture_mapping_from_text(&textual_content)
 }
 
+fn struct_value(value: &PropertyValueEnum) -> Option<&StructValue> {
+    match value {
+        PropertyValueEnum::Embedded(EmbeddedValue(s)) | PropertyValueEnum::Struct(s) => Some(s),
+        _ => None,
+    }
+}
+
+fn prop_value<'a>(props: &'a IndexMap<u32, BinProperty>, name: &str) -> Option<&'a PropertyValueEnum> {
+    props.get(&hash_lower(name)).map(|prop| &prop.value)
+}
+
+/// A non-empty string field
+fn string_prop<'a>(props: &'a IndexMap<u32, BinProperty>, name: &str) -> Option<&'a str> {
+    match prop_value(props, name)? {
+        PropertyValueEnum::String(s) if !s.0.is_empty() => Some(s.0.as_str()),
+        _ => None,
+    }
+}
+
+/// The structs of a list[embed] or list2[embed] field
+fn struct_list<'a>(props: &'a IndexMap<u32, BinProperty>, name: &str) -> impl Iterator<Item = &'a StructValue> {
+    let items: &[PropertyValueEnum] = match prop_value(props, name) {
+        Some(PropertyValueEnum::Container(c) | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(c))) => {
+            &c.items
+        }
+        _ => &[],
+    };
+    items.iter().filter_map(struct_value)
+}
+
+/// Texture and UV transforms of a StaticMaterialDef object
+///
+/// Picks the diffuse sampler like [`extract_diffuse_texture_from_block`] and
+/// reads the same paramValues as [`extract_param_values`].
+fn tree_material_properties(object: &BinTreeObject) -> Option<MaterialProperties> {
+    let samplers: Vec<(String, &str)> = struct_list(&object.properties, "samplerValues")
+        .filter_map(|sampler| {
+            let path = string_prop(&sampler.properties, "texturePath")?;
+            let name = string_prop(&sampler.properties, "textureName").unwrap_or_default();
+            Some((format!("{} {}", name, path).to_lowercase(), path))
+        })
+        .collect();
+    let texture_path = samplers
+        .iter()
+        .find(|(text, _)| DIFFUSE_SAMPLER_NAMES.iter().any(|name| text.contains(name)))
+        .or_else(|| {
+            samplers.iter().find(|(_, path)| {
+                let lower_path = path.to_lowercase();
+                !NON_DIFFUSE_HINTS.iter().any(|hint| lower_path.contains(hint))
+            })
+        })
+        .map(|(_, path)| path.to_string())?;
+
+    let mut props = MaterialProperties { texture_path, ..Default::default() };
+    for param in struct_list(&object.properties, "paramValues") {
+        let (Some(name), Some(PropertyValueEnum::Vector4(value))) =
+            (string_prop(&param.properties, "name"), prop_value(&param.properties, "value"))
+        else {
+            continue;
+        };
+        let [x, y, z, w] = value.0.to_array();
+        match name {
+            "UVScaleAndOffset" => {
+                props.uv_scale = Some([x, y]);
+                props.uv_offset = Some([z, w]);
+            }
+            "FlipbookSize" => props.flipbook_size = Some([x as u32, y as u32]),
+            "FrameIndex" => props.flipbook_frame = Some(x),
+            _ => {}
+        }
+    }
+    Some(props)
+}
+
+/// Walk a parsed BIN to extract texture mappings
+///
+/// Material links are followed by path hash, so they resolve without the
+/// hash list. Returns `None` if no object has skinMeshProperties.
+fn extract_texture_mapping_from_tree(tree: &BinTree, names: &HashMapProvider) -> Option<TextureMapping> {
+    let skin_mesh = tree
+        .objects
+        .values()
+        .find_map(|object| prop_value(&object.properties, "skinMeshProperties").and_then(struct_value))?;
+
+    let material_class = hash_lower("StaticMaterialDef");
+    let mut mapping = TextureMapping {
+        default_texture: string_prop(&skin_mesh.properties, "texture").map(str::to_string),
+        materials: tree
+            .objects
+            .values()
+            .filter(|object| object.class_hash == material_class)
+            .filter_map(|object| {
+                Some(TreeMaterial {
+                    path_hash: object.path_hash,
+                    name: names.entries.get(&object.path_hash).map(|name| name.to_lowercase()),
+                    properties: tree_material_properties(object)?,
+                })
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    for (idx, entry) in struct_list(&skin_mesh.properties, "materialOverride").enumerate() {
+        let Some(submesh_name) = string_prop(&entry.properties, "submesh") else { continue };
+        tracing::info!("Found materialOverride[{}]: submesh='{}'", idx, submesh_name);
+
+        if let Some(tex_path) = string_prop(&entry.properties, "texture") {
+            tracing::info!("  -> Direct texture: {}", tex_path);
+            let props = MaterialProperties { texture_path: tex_path.to_string(), ..Default::default() };
+            mapping.material_properties.insert(submesh_name.to_string(), props);
+            continue;
+        }
+
+        let Some(PropertyValueEnum::ObjectLink(link)) = prop_value(&entry.properties, "material") else {
+            tracing::warn!("  -> No texture or material link found for submesh");
+            continue;
+        };
+        match mapping.materials.iter().find(|m| m.path_hash == link.0) {
+            Some(material) => {
+                tracing::info!("  -> RESOLVED to: {}", material.properties.texture_path);
+                mapping.material_properties.insert(submesh_name.to_string(), material.properties.clone());
+            }
+            None => {
+                tracing::warn!("  -> FAILED to resolve material link 0x{:08x}!", link.0);
+                let entry = match names.entries.get(&link.0) {
+                    Some(name) => format!("Link: {} -> {}", submesh_name, name),
+                    None => format!("Hash: {} -> 0x{:08x}", submesh_name, link.0),
+                };
+                mapping.static_materials.push(entry);
+            }
+        }
+    }
+
+    tracing::info!("Final material_properties count: {}", mapping.material_properties.len());
+    Some(mapping)
+}
+
 /// Parse Ritobin text to extract texture mappings
 /// 
 /// Uses regex to find:
@@ -602,7 +819,7 @@ fn resolve_material_texture_by_hash(content: &str, hash: &str) -> Option<Materia
 
 /// Extract content between matched braces starting at the given position
 /// The position should point to (or before) the opening '{'
-fn extract_braced_block(content: &str, start_after: usize) -> Option<String> {
+pub(crate) fn extract_braced_block(content: &str, start_after: usize) -> Option<String> {
     let bytes = content.as_bytes();
     let mut brace_count = 0;
     let mut block_start = None;
@@ -649,22 +866,11 @@ fn extract_diffuse_texture_from_block(block: &str) -> Option<String> {
         let samplers: Vec<&str> = sampler_block.split("StaticMaterialShaderSamplerDef").collect();
         
         // First pass: look for known diffuse texture names
-        let diffuse_names = [
-            "diffuse_color",
-            "diffuse_texture", 
-            "diffuse",
-            "base_color",
-            "basecolor",
-            "albedo",
-            "color",
-            "_cm",  // Common suffix for color maps
-        ];
-        
         for sampler in &samplers {
             let lower_sampler = sampler.to_lowercase();
             
             // Check if this sampler has a known diffuse-like name
-            let is_diffuse = diffuse_names.iter().any(|name| lower_sampler.contains(name));
+            let is_diffuse = DIFFUSE_SAMPLER_NAMES.iter().any(|name| lower_sampler.contains(name));
             
             if is_diffuse {
                 // Extract texturePath
@@ -685,11 +891,7 @@ fn extract_diffuse_texture_from_block(block: &str) -> Option<String> {
                 let texture_path = path_match.get(1).unwrap().as_str().to_string();
                 // Skip obvious non-diffuse textures
                 let lower_path = texture_path.to_lowercase();
-                if !lower_path.contains("normal") && 
-                   !lower_path.contains("_nm") && 
-                   !lower_path.contains("mask") &&
-                   !lower_path.contains("noise") &&
-                   !lower_path.contains("ramp") {
+                if !NON_DIFFUSE_HINTS.iter().any(|hint| lower_path.contains(hint)) {
                     tracing::debug!("Using first valid texture as fallback: {}", texture_path);
                     return Some(texture_path);
                 }
@@ -801,6 +1003,87 @@ mod tests {
         assert!(mapping.static_materials.is_empty());
     }
 
+    #[test]
+    fn test_extract_texture_mapping_from_tree() {
+        let tree = crate::core::bin::ltk_bridge::text_to_tree(r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Test/Skins/Skin0" = SkinCharacterDataProperties {
+        skinMeshProperties: embed = SkinMeshDataProperties {
+            texture: string = "ASSETS/Default.tex"
+            materialOverride: list[embed] = {
+                SkinMeshDataProperties_MaterialOverride {
+                    texture: string = "ASSETS/Direct.tex"
+                    submesh: string = "DirectMesh"
+                }
+                SkinMeshDataProperties_MaterialOverride {
+                    material: link = "Characters/Test/Skins/Skin0/Materials/Linked"
+                    submesh: string = "LinkedMesh"
+                }
+                SkinMeshDataProperties_MaterialOverride {
+                    material: link = 0x12345678
+                    submesh: string = "MissingMesh"
+                }
+            }
+        }
+    }
+    "Characters/Test/Skins/Skin0/Materials/Linked" = StaticMaterialDef {
+        samplerValues: list2[embed] = {
+            StaticMaterialShaderSamplerDef {
+                textureName: string = "Normal_Map"
+                texturePath: string = "ASSETS/Linked_NM.tex"
+            }
+            StaticMaterialShaderSamplerDef {
+                textureName: string = "Diffuse_Color"
+                texturePath: string = "ASSETS/Linked.tex"
+            }
+        }
+        paramValues: list2[embed] = {
+            StaticMaterialShaderParamDef {
+                name: string = "UVScaleAndOffset"
+                value: vec4 = { 2, 2, 0.5, 0 }
+            }
+        }
+    }
+    0xabcdef12 = StaticMaterialDef {
+        samplerValues: list2[embed] = {
+            StaticMaterialShaderSamplerDef {
+                texturePath: string = "ASSETS/Unnamed.tex"
+            }
+        }
+    }
+}
+"#).unwrap();
+
+        // No hash names: links still resolve through their path hash
+        let mapping = extract_texture_mapping_from_tree(&tree, &HashMapProvider::new()).unwrap();
+        assert_eq!(mapping.default_texture.as_deref(), Some("ASSETS/Default.tex"));
+        assert_eq!(mapping.material_properties["DirectMesh"].texture_path, "ASSETS/Direct.tex");
+        let linked = &mapping.material_properties["LinkedMesh"];
+        assert_eq!(linked.texture_path, "ASSETS/Linked.tex");
+        assert_eq!(linked.uv_scale, Some([2.0, 2.0]));
+        assert_eq!(linked.uv_offset, Some([0.5, 0.0]));
+        assert_eq!(mapping.static_materials, vec!["Hash: MissingMesh -> 0x12345678".to_string()]);
+
+        let found = mapping.lookup_material("Characters/Test/Skins/Skin0/Materials/Linked").unwrap();
+        assert_eq!(found.texture_path, "ASSETS/Linked.tex");
+        assert!(mapping.lookup_material("Linked").is_none());
+
+        // Named objects are also found by the end of their path
+        let mut names = HashMapProvider::new();
+        names.entries.insert(0xabcdef12, "Characters/Test/Skins/Skin0/Materials/Body_inst".to_string());
+        let mapping = extract_texture_mapping_from_tree(&tree, &names).unwrap();
+        assert_eq!(mapping.lookup_material("body_inst").unwrap().texture_path, "ASSETS/Unnamed.tex");
+
+        let empty = crate::core::bin::ltk_bridge::text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nlinked: list[string] = {}\nentries: map[hash,embed] = {}\n",
+        )
+        .unwrap();
+        assert!(extract_texture_mapping_from_tree(&empty, &names).is_none());
+    }
+
     #[test]
     fn test_extract_braced_block() {
         let content = r#"outer { inner { nested } more } end"#;
@@ -10,4 +10,20 @@ pub mod repath;
 pub mod export;
 pub mod mesh;
 pub mod checkpoint;
+pub mod trash;
 pub mod frontend_log;
+pub mod import;
+pub mod store;
+pub mod settings;
+pub mod chroma;
+pub mod texture;
+pub mod diff;
+pub mod onboarding;
+pub mod vfx;
+pub mod audio;
+pub mod bench;
+pub mod deploy;
+pub mod vcs;
+pub mod thumbnail;
+pub mod hexview;
+pub mod stringtable;
@@ -0,0 +1,298 @@
+//! First-run setup progress
+//!
+//! The guided first-run flow walks through a fixed sequence of steps. Each
+//! step is completed with a value (validated here, not in the frontend) or
+//! skipped, and the frontend always resumes at the first step still pending.
+//! Progress and the chosen values are kept in the app data directory so the
+//! flow survives restarts.
+
+use crate::core::league::guard::ensure_outside_league;
+use crate::core::league::validate_league_path;
+use crate::core::settings::{load_json_setting, save_json_setting};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Settings file inside the app data directory
+const SETTINGS_FILE: &str = "onboarding.json";
+
+/// Longest accepted creator name
+const MAX_CREATOR_NAME_LEN: usize = 64;
+
+/// One step of the first-run flow, in the order they are presented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    LeaguePath,
+    HashDownload,
+    CreatorName,
+    OutputDirectory,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 4] = [
+        Self::LeaguePath,
+        Self::HashDownload,
+        Self::CreatorName,
+        Self::OutputDirectory,
+    ];
+
+    /// Human-readable name used in error messages
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::LeaguePath => "the League path step",
+            Self::HashDownload => "the hash download step",
+            Self::CreatorName => "the creator name step",
+            Self::OutputDirectory => "the output directory step",
+        }
+    }
+}
+
+/// Progress of one step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum StepState {
+    Pending,
+    Complete,
+    Skipped,
+}
+
+/// Persisted onboarding progress and the values chosen along the way
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct OnboardingSettings {
+    #[serde(default)]
+    pub league_path: Option<String>,
+    #[serde(default)]
+    pub creator_name: Option<String>,
+    #[serde(default)]
+    pub output_directory: Option<String>,
+    #[serde(default)]
+    pub completed: Vec<OnboardingStep>,
+    #[serde(default)]
+    pub skipped: Vec<OnboardingStep>,
+}
+
+impl OnboardingSettings {
+    pub fn state(&self, step: OnboardingStep) -> StepState {
+        if self.completed.contains(&step) {
+            StepState::Complete
+        } else if self.skipped.contains(&step) {
+            StepState::Skipped
+        } else {
+            StepState::Pending
+        }
+    }
+
+    /// First step that is neither completed nor skipped
+    pub fn current_step(&self) -> Option<OnboardingStep> {
+        OnboardingStep::ALL
+            .into_iter()
+            .find(|step| self.state(*step) == StepState::Pending)
+    }
+
+    fn mark(&mut self, step: OnboardingStep, state: StepState) {
+        self.completed.retain(|s| *s != step);
+        self.skipped.retain(|s| *s != step);
+        match state {
+            StepState::Complete => self.completed.push(step),
+            StepState::Skipped => self.skipped.push(step),
+            StepState::Pending => {}
+        }
+    }
+}
+
+/// State of one step as reported to the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct OnboardingStepStatus {
+    pub step: OnboardingStep,
+    pub state: StepState,
+}
+
+/// Full onboarding status
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct OnboardingStatus {
+    pub steps: Vec<OnboardingStepStatus>,
+    /// Step the flow should show next, None once every step is done
+    pub current_step: Option<OnboardingStep>,
+    pub complete: bool,
+    pub settings: OnboardingSettings,
+    /// Whether hash files are already present, so the download step can be
+    /// presented as optional
+    pub hashes_present: bool,
+}
+
+/// Loads onboarding progress, falling back to a fresh start
+pub fn load_settings(app_data_dir: &Path) -> OnboardingSettings {
+    load_json_setting(app_data_dir, SETTINGS_FILE)
+}
+
+/// Writes onboarding progress to the app data directory
+pub fn save_settings(app_data_dir: &Path, settings: &OnboardingSettings) -> Result<()> {
+    save_json_setting(app_data_dir, SETTINGS_FILE, settings)
+}
+
+/// True if the hash directory holds downloaded hash files
+pub fn hashes_present(hash_dir: Option<&Path>) -> bool {
+    let Some(entries) = hash_dir.and_then(|dir| fs::read_dir(dir).ok()) else {
+        return false;
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .any(|e| e.file_name().to_string_lossy().starts_with("hashes."))
+}
+
+/// Builds the status reported to the frontend
+pub fn status(settings: OnboardingSettings, hash_dir: Option<&Path>) -> OnboardingStatus {
+    let steps = OnboardingStep::ALL
+        .into_iter()
+        .map(|step| OnboardingStepStatus {
+            step,
+            state: settings.state(step),
+        })
+        .collect();
+    let current_step = settings.current_step();
+
+    OnboardingStatus {
+        steps,
+        current_step,
+        complete: current_step.is_none(),
+        settings,
+        hashes_present: hashes_present(hash_dir),
+    }
+}
+
+fn required_value(step: OnboardingStep, value: Option<&str>) -> Result<String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| Error::InvalidInput(format!("A value is required to complete {}", step.label())))
+}
+
+/// Completes or skips one step
+///
+/// Steps must be done in order; a step can be redone later to change its
+/// value. The value is validated for the step: a League installation path, a
+/// creator name, or an output directory (created if missing, and never inside
+/// the League installation). The hash download step takes no value and
+/// requires the hash files to be present unless skipped.
+///
+/// # Arguments
+/// * `settings` - Progress to update
+/// * `step` - Step to complete
+/// * `value` - Value chosen in the step
+/// * `skip` - Mark the step skipped instead of validating a value
+/// * `hash_dir` - Hash directory, checked by the hash download step
+pub fn complete_step(
+    settings: &mut OnboardingSettings,
+    step: OnboardingStep,
+    value: Option<&str>,
+    skip: bool,
+    hash_dir: Option<&Path>,
+) -> Result<()> {
+    if let Some(pending) = OnboardingStep::ALL
+        .into_iter()
+        .take_while(|s| *s != step)
+        .find(|s| settings.state(*s) == StepState::Pending)
+    {
+        return Err(Error::InvalidInput(format!(
+            "Complete {} before {}",
+            pending.label(),
+            step.label()
+        )));
+    }
+
+    if skip {
+        settings.mark(step, StepState::Skipped);
+        return Ok(());
+    }
+
+    match step {
+        OnboardingStep::LeaguePath => {
+            let path = required_value(step, value)?;
+            let installation = validate_league_path(&path)?;
+            settings.league_path = Some(installation.path.display().to_string());
+        }
+        OnboardingStep::HashDownload => {
+            if !hashes_present(hash_dir) {
+                return Err(Error::InvalidInput(
+                    "Hash files have not been downloaded yet".to_string(),
+                ));
+            }
+        }
+        OnboardingStep::CreatorName => {
+            let name = required_value(step, value)?;
+            if name.chars().count() > MAX_CREATOR_NAME_LEN {
+                return Err(Error::InvalidInput(format!(
+                    "Creator name must be at most {} characters",
+                    MAX_CREATOR_NAME_LEN
+                )));
+            }
+            settings.creator_name = Some(name);
+        }
+        OnboardingStep::OutputDirectory => {
+            let dir = required_value(step, value)?;
+            ensure_outside_league(&dir)?;
+            fs::create_dir_all(&dir).map_err(|e| Error::io_with_path(e, &dir))?;
+            settings.output_directory = Some(dir);
+        }
+    }
+
+    settings.mark(step, StepState::Complete);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_advance_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let hash_dir = dir.path().join("hashes");
+        let mut settings = OnboardingSettings::default();
+        assert_eq!(settings.current_step(), Some(OnboardingStep::LeaguePath));
+
+        // Later steps can't jump the queue
+        assert!(complete_step(&mut settings, OnboardingStep::CreatorName, Some("me"), false, None).is_err());
+
+        complete_step(&mut settings, OnboardingStep::LeaguePath, None, true, None).unwrap();
+        assert_eq!(settings.current_step(), Some(OnboardingStep::HashDownload));
+
+        // No hash files yet
+        assert!(complete_step(&mut settings, OnboardingStep::HashDownload, None, false, Some(&hash_dir)).is_err());
+        fs::create_dir_all(&hash_dir).unwrap();
+        fs::write(hash_dir.join("hashes.game.txt.0"), "").unwrap();
+        complete_step(&mut settings, OnboardingStep::HashDownload, None, false, Some(&hash_dir)).unwrap();
+
+        assert!(complete_step(&mut settings, OnboardingStep::CreatorName, Some("   "), false, None).is_err());
+        complete_step(&mut settings, OnboardingStep::CreatorName, Some(" Sona "), false, None).unwrap();
+        assert_eq!(settings.creator_name.as_deref(), Some("Sona"));
+
+        let output = dir.path().join("mods");
+        complete_step(&mut settings, OnboardingStep::OutputDirectory, output.to_str(), false, None).unwrap();
+        assert!(output.is_dir());
+
+        let status = status(settings.clone(), Some(&hash_dir));
+        assert!(status.complete);
+        assert!(status.hashes_present);
+        assert_eq!(status.steps[0].state, StepState::Skipped);
+        assert_eq!(status.steps[3].state, StepState::Complete);
+
+        // Redoing a step replaces its value and keeps the flow complete
+        complete_step(&mut settings, OnboardingStep::CreatorName, Some("Lux"), false, None).unwrap();
+        assert_eq!(settings.current_step(), None);
+
+        save_settings(dir.path(), &settings).unwrap();
+        let loaded = load_settings(dir.path());
+        assert_eq!(loaded.creator_name.as_deref(), Some("Lux"));
+        assert_eq!(loaded.skipped, vec![OnboardingStep::LeaguePath]);
+    }
+}
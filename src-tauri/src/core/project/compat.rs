@@ -0,0 +1,154 @@
+//! Checking a project against the installed game version
+//!
+//! Projects record the game version they were extracted from. After a patch
+//! the extracted files can reference assets the new WADs renamed or dropped,
+//! which later shows up as confusing missing-asset validation errors. This
+//! compares the recorded version with the linked install so the frontend can
+//! suggest updating the project's assets first.
+
+use crate::core::league::detect_game_version;
+use crate::core::project::Project;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// How a project's game version relates to the installed one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatibilityStatus {
+    /// Same build
+    Match,
+    /// Same patch, different build (a hotfix)
+    Hotfix,
+    /// The install is on a later patch than the project
+    NewerPatch,
+    /// The install is on an earlier patch than the project (e.g. the project came from PBE)
+    OlderPatch,
+    /// One of the versions isn't known
+    Unknown,
+}
+
+/// Result of comparing a project with its League installation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ProjectCompatibility {
+    /// Game version recorded when the project was extracted
+    pub project_version: Option<String>,
+    /// Game version of the linked League installation
+    pub install_version: Option<String>,
+    pub status: CompatibilityStatus,
+    /// Whether updating the project's assets before validating or exporting is advised
+    pub update_advised: bool,
+    /// Explanation to show the user, None when the versions match
+    pub warning: Option<String>,
+}
+
+/// Numeric parts of a version such as "14.23.636.7270"
+fn version_parts(version: &str) -> Option<Vec<u32>> {
+    version.trim().split('.').map(|part| part.parse().ok()).collect()
+}
+
+/// Compares two game versions by patch (the first two parts), then by build
+pub fn compare_game_versions(project: Option<&str>, install: Option<&str>) -> CompatibilityStatus {
+    let (Some(project), Some(install)) = (project.and_then(version_parts), install.and_then(version_parts)) else {
+        return CompatibilityStatus::Unknown;
+    };
+    if project.len() < 2 || install.len() < 2 {
+        return CompatibilityStatus::Unknown;
+    }
+
+    match install[..2].cmp(&project[..2]) {
+        std::cmp::Ordering::Greater => CompatibilityStatus::NewerPatch,
+        std::cmp::Ordering::Less => CompatibilityStatus::OlderPatch,
+        std::cmp::Ordering::Equal if install == project => CompatibilityStatus::Match,
+        std::cmp::Ordering::Equal => CompatibilityStatus::Hotfix,
+    }
+}
+
+/// Checks whether a project was extracted from the game version it's linked to
+///
+/// Never fails: a missing install or unrecorded version gives
+/// [`CompatibilityStatus::Unknown`].
+pub fn check_project_compatibility(project: &Project) -> ProjectCompatibility {
+    let project_version = project.game_version.clone();
+    let install_version = project
+        .league_path
+        .as_deref()
+        .filter(|path| path.exists())
+        .and_then(detect_game_version);
+    let status = compare_game_versions(project_version.as_deref(), install_version.as_deref());
+
+    let unknown = "unknown";
+    let (update_advised, warning) = match status {
+        CompatibilityStatus::Match => (false, None),
+        CompatibilityStatus::Hotfix => (false, Some(format!(
+            "The game was hotfixed since extraction ({} -> {}). Assets rarely change in a hotfix, \
+             but update the project if validation reports missing files.",
+            project_version.as_deref().unwrap_or(unknown),
+            install_version.as_deref().unwrap_or(unknown)
+        ))),
+        CompatibilityStatus::NewerPatch => (true, Some(format!(
+            "The project was extracted from patch {} but the game is on {}. \
+             Update the project's assets before validating or exporting.",
+            project_version.as_deref().unwrap_or(unknown),
+            install_version.as_deref().unwrap_or(unknown)
+        ))),
+        CompatibilityStatus::OlderPatch => (true, Some(format!(
+            "The project was extracted from patch {}, newer than the installed {}. \
+             Assets it uses may not exist in this install.",
+            project_version.as_deref().unwrap_or(unknown),
+            install_version.as_deref().unwrap_or(unknown)
+        ))),
+        CompatibilityStatus::Unknown if project_version.is_none() => (false, Some(
+            "The project doesn't record which game version it was extracted from.".to_string()
+        )),
+        CompatibilityStatus::Unknown => (false, Some(
+            "The game version of the linked League installation couldn't be read.".to_string()
+        )),
+    };
+
+    ProjectCompatibility { project_version, install_version, status, update_advised, warning }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_game_versions() {
+        let compare = |project, install| compare_game_versions(Some(project), Some(install));
+        assert_eq!(compare("14.23.636.7270", "14.23.636.7270"), CompatibilityStatus::Match);
+        assert_eq!(compare("14.23.636.7270", "14.23.640.1"), CompatibilityStatus::Hotfix);
+        assert_eq!(compare("14.23.636.7270", "14.24.1.1"), CompatibilityStatus::NewerPatch);
+        assert_eq!(compare("14.23.636.7270", "15.1.1.1"), CompatibilityStatus::NewerPatch);
+        assert_eq!(compare("14.9.1.1", "14.10.1.1"), CompatibilityStatus::NewerPatch);
+        assert_eq!(compare("15.1.1.1", "14.24.1.1"), CompatibilityStatus::OlderPatch);
+        assert_eq!(compare("14.23.x", "14.23.1.1"), CompatibilityStatus::Unknown);
+        assert_eq!(compare_game_versions(None, Some("14.23.1.1")), CompatibilityStatus::Unknown);
+    }
+
+    #[test]
+    fn test_check_project_compatibility() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("Game")).unwrap();
+        std::fs::write(
+            dir.path().join("Game").join("content-metadata.json"),
+            r#"{"version": "14.24.1.1+branch"}"#,
+        ).unwrap();
+
+        let mut project = Project::new("Test", "Ahri", 0, dir.path(), dir.path().join("project"), None);
+        let report = check_project_compatibility(&project);
+        assert_eq!(report.status, CompatibilityStatus::Unknown);
+        assert_eq!(report.install_version.as_deref(), Some("14.24.1.1"));
+        assert!(!report.update_advised);
+
+        project.game_version = Some("14.23.636.7270".to_string());
+        let report = check_project_compatibility(&project);
+        assert_eq!(report.status, CompatibilityStatus::NewerPatch);
+        assert!(report.update_advised);
+        assert!(report.warning.unwrap().contains("14.24.1.1"));
+
+        project.game_version = Some("14.24.1.1".to_string());
+        assert_eq!(check_project_compatibility(&project).warning, None);
+    }
+}
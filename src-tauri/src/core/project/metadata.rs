@@ -0,0 +1,371 @@
+//! Editing a project's mod metadata
+//!
+//! Name, display name, version, description, authors and license are stored
+//! in mod.config.json and are what fantome and modpkg exports write into the
+//! package. Edits go through [`update_project_metadata`], which applies the
+//! league-mod rules (slug names, semver versions) before saving, so exports
+//! always read metadata that the package formats accept.
+
+use crate::core::project::{save_project, Project};
+use crate::error::{Error, Result};
+use chrono::Utc;
+use ltk_mod_project::{ModProjectAuthor, ModProjectLicense};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// An author of the mod, optionally credited with a role (e.g. "Textures")
+///
+/// Reads both league-mod forms: a plain name string or `{ name, role }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(from = "AuthorEntry")]
+pub struct ProjectAuthor {
+    pub name: String,
+    pub role: Option<String>,
+}
+
+impl ProjectAuthor {
+    /// An author without a role
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), role: None }
+    }
+}
+
+/// mod.config.json form of an author
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AuthorEntry {
+    Name(String),
+    Role {
+        name: String,
+        #[serde(default)]
+        role: Option<String>,
+    },
+}
+
+impl From<AuthorEntry> for ProjectAuthor {
+    fn from(entry: AuthorEntry) -> Self {
+        match entry {
+            AuthorEntry::Name(name) => Self { name, role: None },
+            AuthorEntry::Role { name, role } => Self { name, role: role.filter(|r| !r.is_empty()) },
+        }
+    }
+}
+
+impl From<ModProjectAuthor> for ProjectAuthor {
+    fn from(author: ModProjectAuthor) -> Self {
+        match author {
+            ModProjectAuthor::Name(name) => Self { name, role: None },
+            ModProjectAuthor::Role { name, role } => Self { name, role: Some(role).filter(|r| !r.is_empty()) },
+        }
+    }
+}
+
+impl From<&ProjectAuthor> for ModProjectAuthor {
+    fn from(author: &ProjectAuthor) -> Self {
+        match &author.role {
+            Some(role) => ModProjectAuthor::Role { name: author.name.clone(), role: role.clone() },
+            None => ModProjectAuthor::Name(author.name.clone()),
+        }
+    }
+}
+
+/// License of the mod: an SPDX identifier (e.g. "MIT"), or a custom
+/// license name with a link to its text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(from = "LicenseEntry")]
+pub struct ProjectLicense {
+    pub name: String,
+    /// Link to the license text; None for SPDX identifiers
+    pub url: Option<String>,
+}
+
+/// mod.config.json form of a license
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LicenseEntry {
+    Spdx(String),
+    Custom {
+        name: String,
+        #[serde(default)]
+        url: Option<String>,
+    },
+}
+
+impl From<LicenseEntry> for ProjectLicense {
+    fn from(entry: LicenseEntry) -> Self {
+        match entry {
+            LicenseEntry::Spdx(name) => Self { name, url: None },
+            LicenseEntry::Custom { name, url } => Self { name, url: url.filter(|u| !u.is_empty()) },
+        }
+    }
+}
+
+impl From<ModProjectLicense> for ProjectLicense {
+    fn from(license: ModProjectLicense) -> Self {
+        match license {
+            ModProjectLicense::Spdx(name) => Self { name, url: None },
+            ModProjectLicense::Custom { name, url } => Self { name, url: Some(url).filter(|u| !u.is_empty()) },
+        }
+    }
+}
+
+impl From<&ProjectLicense> for ModProjectLicense {
+    fn from(license: &ProjectLicense) -> Self {
+        match &license.url {
+            Some(url) => ModProjectLicense::Custom { name: license.name.clone(), url: url.clone() },
+            None => ModProjectLicense::Spdx(license.name.clone()),
+        }
+    }
+}
+
+/// Part of a semver version to increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+/// The mod.config.json metadata of a project
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ProjectMetadata {
+    /// Slug used for package file names and repath prefixes
+    pub name: String,
+    pub display_name: String,
+    /// Semver version
+    pub version: String,
+    pub description: String,
+    pub authors: Vec<ProjectAuthor>,
+    pub license: Option<ProjectLicense>,
+    /// Thumbnail image, relative to the project folder
+    pub thumbnail: Option<String>,
+}
+
+impl From<&Project> for ProjectMetadata {
+    fn from(project: &Project) -> Self {
+        Self {
+            name: project.name.clone(),
+            display_name: project.display_name.clone(),
+            version: project.version.clone(),
+            description: project.description.clone(),
+            authors: project.authors.clone(),
+            license: project.license.clone(),
+            thumbnail: project.thumbnail.clone(),
+        }
+    }
+}
+
+/// Changes to a project's metadata; fields left as None are kept
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(default)]
+pub struct MetadataUpdate {
+    /// New slug: letters, digits, `_` and `-` only
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    /// New semver version; can't be combined with `bump`
+    pub version: Option<String>,
+    /// Increment the current version instead of setting one
+    pub bump: Option<VersionBump>,
+    pub description: Option<String>,
+    /// Replaces the author list
+    pub authors: Option<Vec<ProjectAuthor>>,
+    /// New license; an empty name removes it
+    pub license: Option<ProjectLicense>,
+}
+
+/// Checks a mod name against the league-mod slug rules
+pub fn validate_mod_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(Error::InvalidInput(format!(
+            "Invalid mod name '{}': use letters, digits, '_' or '-'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Parses a mod version, which must be semver (e.g. "1.2.0" or "1.0.0-beta.1")
+pub fn validate_version(version: &str) -> Result<semver::Version> {
+    semver::Version::parse(version.trim()).map_err(|e| Error::InvalidInput(format!(
+        "Invalid version '{}': {}. Use the form MAJOR.MINOR.PATCH, e.g. 1.0.0",
+        version, e
+    )))
+}
+
+/// Increments a semver version, dropping any pre-release or build suffix
+///
+/// A pre-release of the bumped version is released as-is, like cargo and npm
+/// do: bumping the patch of "1.2.0-beta.1" gives "1.2.0".
+pub fn bump_version(version: &str, bump: VersionBump) -> Result<String> {
+    let current = validate_version(version)?;
+    let released = current.pre.is_empty();
+    let next = match bump {
+        VersionBump::Major if !released && current.minor == 0 && current.patch == 0 => {
+            semver::Version::new(current.major, 0, 0)
+        }
+        VersionBump::Major => semver::Version::new(current.major + 1, 0, 0),
+        VersionBump::Minor if !released && current.patch == 0 => {
+            semver::Version::new(current.major, current.minor, 0)
+        }
+        VersionBump::Minor => semver::Version::new(current.major, current.minor + 1, 0),
+        VersionBump::Patch if !released => semver::Version::new(current.major, current.minor, current.patch),
+        VersionBump::Patch => semver::Version::new(current.major, current.minor, current.patch + 1),
+    };
+    Ok(next.to_string())
+}
+
+/// Validates and applies metadata changes, then saves the project
+///
+/// Nothing is changed if any field is invalid.
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `update` - Fields to change
+pub fn update_project_metadata(project: &mut Project, update: MetadataUpdate) -> Result<()> {
+    if let Some(name) = &update.name {
+        validate_mod_name(name)?;
+    }
+    let display_name = update.display_name.map(|d| d.trim().to_string());
+    if display_name.as_deref() == Some("") {
+        return Err(Error::InvalidInput("Display name can't be empty".to_string()));
+    }
+    let version = match (update.version, update.bump) {
+        (Some(_), Some(_)) => {
+            return Err(Error::InvalidInput("Set a version or bump it, not both".to_string()));
+        }
+        (Some(version), None) => Some(validate_version(&version)?.to_string()),
+        (None, Some(bump)) => Some(bump_version(&project.version, bump)?),
+        (None, None) => None,
+    };
+    let authors = update
+        .authors
+        .map(|authors| {
+            authors
+                .into_iter()
+                .map(|author| {
+                    let name = author.name.trim().to_string();
+                    if name.is_empty() {
+                        return Err(Error::InvalidInput("Author names can't be empty".to_string()));
+                    }
+                    let role = author.role.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+                    Ok(ProjectAuthor { name, role })
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
+    let license = update.license.map(|license| {
+        let name = license.name.trim().to_string();
+        let url = license.url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+        (!name.is_empty()).then_some(ProjectLicense { name, url })
+    });
+
+    if let Some(name) = update.name {
+        project.name = name;
+    }
+    if let Some(display_name) = display_name {
+        project.display_name = display_name;
+    }
+    if let Some(version) = version {
+        project.version = version;
+    }
+    if let Some(description) = update.description {
+        project.description = description;
+    }
+    if let Some(authors) = authors {
+        project.authors = authors;
+    }
+    if let Some(license) = license {
+        project.license = license;
+    }
+
+    project.modified_at = Utc::now();
+    save_project(project)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::project::open_project;
+
+    #[test]
+    fn test_bump_version() {
+        assert_eq!(bump_version("1.2.3", VersionBump::Patch).unwrap(), "1.2.4");
+        assert_eq!(bump_version("1.2.3", VersionBump::Minor).unwrap(), "1.3.0");
+        assert_eq!(bump_version("1.2.3+build.5", VersionBump::Major).unwrap(), "2.0.0");
+        assert_eq!(bump_version("1.2.0-beta.1", VersionBump::Patch).unwrap(), "1.2.0");
+        assert_eq!(bump_version("1.2.0-beta.1", VersionBump::Minor).unwrap(), "1.2.0");
+        assert_eq!(bump_version("1.2.1-beta.1", VersionBump::Minor).unwrap(), "1.3.0");
+        assert!(bump_version("1.2", VersionBump::Patch).is_err());
+    }
+
+    #[test]
+    fn test_authors_and_license_from_config() {
+        let json = r#"{
+            "name": "test-mod",
+            "display_name": "Test Mod",
+            "version": "1.0.0",
+            "description": "",
+            "authors": ["Solo", { "name": "Crauzer", "role": "Contributor" }],
+            "license": { "name": "Custom", "url": "https://example.com/license" }
+        }"#;
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(project.authors, vec![
+            ProjectAuthor::new("Solo"),
+            ProjectAuthor { name: "Crauzer".to_string(), role: Some("Contributor".to_string()) },
+        ]);
+        assert_eq!(project.license.as_ref().unwrap().url.as_deref(), Some("https://example.com/license"));
+
+        let mod_project = project.to_mod_project();
+        assert_eq!(mod_project.authors[1], ModProjectAuthor::Role {
+            name: "Crauzer".to_string(),
+            role: "Contributor".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_update_project_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut project = Project::new("Test", "Ahri", 0, dir.path(), dir.path(), Some("me".to_string()));
+        save_project(&project).unwrap();
+
+        update_project_metadata(&mut project, MetadataUpdate {
+            display_name: Some("  Star Guardian Ahri ".to_string()),
+            bump: Some(VersionBump::Minor),
+            authors: Some(vec![ProjectAuthor { name: "me".to_string(), role: Some("VFX".to_string()) }]),
+            license: Some(ProjectLicense { name: "MIT".to_string(), url: None }),
+            ..Default::default()
+        }).unwrap();
+
+        let reopened = open_project(dir.path()).unwrap();
+        let metadata = ProjectMetadata::from(&reopened);
+        assert_eq!(metadata.display_name, "Star Guardian Ahri");
+        assert_eq!(metadata.version, "0.2.0");
+        assert_eq!(metadata.authors[0].role.as_deref(), Some("VFX"));
+        assert_eq!(metadata.license, Some(ProjectLicense { name: "MIT".to_string(), url: None }));
+
+        // Invalid fields leave the project untouched
+        let invalid = MetadataUpdate {
+            description: Some("changed".to_string()),
+            name: Some("has spaces".to_string()),
+            ..Default::default()
+        };
+        assert!(update_project_metadata(&mut project, invalid).is_err());
+        assert!(update_project_metadata(&mut project, MetadataUpdate {
+            version: Some("1.0".to_string()),
+            ..Default::default()
+        }).is_err());
+        assert_ne!(project.description, "changed");
+
+        update_project_metadata(&mut project, MetadataUpdate {
+            license: Some(ProjectLicense { name: String::new(), url: None }),
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(open_project(dir.path()).unwrap().license, None);
+    }
+}
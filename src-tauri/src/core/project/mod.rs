@@ -1,6 +1,10 @@
 // Project management module exports
 #[allow(clippy::module_inception)]
 pub mod project;
+pub mod variables;
+pub mod vanilla;
+pub mod compat;
+pub mod metadata;
 
 // Re-export from ltk_mod_project for league-mod compatibility
 #[allow(unused_imports)]
@@ -9,4 +13,4 @@ pub use ltk_mod_project::{
     ModProjectLicense, FileTransformer, default_layers
 };
 #[allow(unused_imports)]
-pub use project::{create_project, open_project, save_project, Project, FlintMetadata};
+pub use project::{add_layer, create_target_project, find_target_wad, open_project, relink_league_path, save_project, set_layer_metadata, set_project_thumbnail, Project, ChampionTarget, FlintMetadata, TargetKind};
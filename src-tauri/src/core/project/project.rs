@@ -3,33 +3,114 @@
 //! This module provides data structures and logic for creating, loading,
 //! and saving Flint mod projects using the league-mod compatible format.
 
+use crate::core::export::preview::{decode_image_file, encode_preview, PreviewFormat};
+use crate::core::league::{detect_game_version, validate_league_path};
+use crate::core::project::metadata::{ProjectAuthor, ProjectLicense};
+use crate::core::repath::refather::RepathOptions;
+use crate::core::wad::extractor::find_champion_wad;
+use crate::core::wad::identify::WadTarget;
 use crate::error::{Error, Result};
 use chrono::{DateTime, Utc};
-use ltk_mod_project::{ModProject, ModProjectAuthor, ModProjectLayer, default_layers};
+use ltk_mod_project::{ModProject, ModProjectLayer, default_layers};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, File};
 use std::io::{BufReader, BufWriter};
 use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
 
 /// Project config file name (league-mod compatible)
 const PROJECT_FILE: &str = "mod.config.json";
 
+/// Thumbnail written by [`set_project_thumbnail`]; ltk_modpkg and the
+/// fantome importer use the same name
+pub const THUMBNAIL_FILE: &str = "thumbnail.webp";
+
+/// Longest edge of a stored thumbnail
+const THUMBNAIL_MAX_SIZE: u32 = 1024;
+
 /// Flint metadata file name
 const FLINT_FILE: &str = "flint.json";
 
+/// What a project modifies
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetKind {
+    /// A champion skin, from `DATA/FINAL/Champions`
+    #[default]
+    Champion,
+    /// A map skin, from `DATA/FINAL/Maps/Shipping`
+    Map,
+    /// Any other WAD: UI, HUD, global data
+    Global,
+}
+
+impl From<WadTarget> for TargetKind {
+    fn from(target: WadTarget) -> Self {
+        match target {
+            WadTarget::Champion => TargetKind::Champion,
+            WadTarget::Map => TargetKind::Map,
+            WadTarget::Ui | WadTarget::Global | WadTarget::Unknown => TargetKind::Global,
+        }
+    }
+}
+
+/// Another champion skin a project pulls assets from
+///
+/// Its files live in their own `{champion}.wad.client` folder next to the
+/// primary champion's.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ChampionTarget {
+    pub champion: String,
+    pub skin_id: u32,
+}
+
 /// Flint-specific metadata (stored separately from mod.config.json)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct FlintMetadata {
-    /// Champion internal name (e.g., "Ahri")
+    /// Champion internal name (e.g., "Ahri"), or the WAD name for map and
+    /// global projects (e.g., "map11")
     pub champion: String,
 
+    /// What the project modifies; projects from before map support are champions
+    #[serde(default)]
+    pub target_kind: TargetKind,
+
     /// Skin ID (0 for base skin)
     pub skin_id: u32,
 
+    /// Champion skins the project uses besides the primary one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_targets: Vec<ChampionTarget>,
+
     /// Path to League of Legends installation
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub league_path: Option<PathBuf>,
 
+    /// Game client version detected at the League path (e.g., "14.23.636.7270")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_version: Option<String>,
+
+    /// User-defined variables substituted in BIN text (`${NAME}`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+
+    /// Replace variable values with `${NAME}` when converting BIN to text
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub collapse_variables: bool,
+
+    /// Layers left out of exports (league-mod has no per-layer toggle)
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub disabled_layers: BTreeSet<String>,
+
+    /// Repath prefix template and strategy
+    #[serde(default, skip_serializing_if = "RepathOptions::is_default")]
+    pub repath: RepathOptions,
+
     /// When the project was created (ISO 8601)
     pub created_at: DateTime<Utc>,
 
@@ -44,8 +125,15 @@ impl FlintMetadata {
         let now = Utc::now();
         Self {
             champion: champion.into(),
+            target_kind: TargetKind::Champion,
             skin_id,
+            extra_targets: Vec::new(),
             league_path,
+            game_version: None,
+            variables: BTreeMap::new(),
+            collapse_variables: false,
+            disabled_layers: BTreeSet::new(),
+            repath: RepathOptions::default(),
             created_at: now,
             modified_at: now,
         }
@@ -56,7 +144,8 @@ impl FlintMetadata {
 /// 
 /// This struct combines league-mod compatible ModProject with Flint-specific
 /// champion/skin data needed for the extraction workflow.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct Project {
     // ===== League-mod compatible fields (from mod.config.json) =====
     
@@ -74,26 +163,64 @@ pub struct Project {
     
     /// Layers of the mod project
     #[serde(default = "default_layers")]
+    #[ts(type = "Array<{ name: string, priority: number, description?: string }>")]
     pub layers: Vec<ModProjectLayer>,
     
-    /// Authors of the mod (stored as strings for Clone compatibility)
+    /// Authors of the mod, with optional roles
+    #[serde(default)]
+    pub authors: Vec<ProjectAuthor>,
+
+    /// License of the mod
+    #[serde(default)]
+    pub license: Option<ProjectLicense>,
+
+    /// Thumbnail image, relative to the project folder
     #[serde(default)]
-    pub authors: Vec<String>,
+    pub thumbnail: Option<String>,
     
     // ===== Flint-specific fields (from flint.json, populated at runtime) =====
     
-    /// Champion internal name (e.g., "Ahri") - Flint specific
+    /// Champion internal name (e.g., "Ahri"), or WAD name for map and
+    /// global projects - Flint specific
     #[serde(default)]
     pub champion: String,
     
+    /// Champion, map or global WAD project - Flint specific
+    #[serde(default)]
+    pub target_kind: TargetKind,
+    
     /// Skin ID (0 for base skin) - Flint specific
     #[serde(default)]
     pub skin_id: u32,
     
+    /// Champion skins used besides the primary one - Flint specific
+    #[serde(default)]
+    pub extra_targets: Vec<ChampionTarget>,
+    
     /// Path to League of Legends installation - Flint specific
     #[serde(skip)]
     pub league_path: Option<PathBuf>,
     
+    /// Game client version the project was extracted from - Flint specific
+    #[serde(default)]
+    pub game_version: Option<String>,
+    
+    /// User-defined BIN text variables - Flint specific
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    
+    /// Whether BIN to text conversion collapses variable values - Flint specific
+    #[serde(default)]
+    pub collapse_variables: bool,
+    
+    /// Layers excluded from export - Flint specific
+    #[serde(default)]
+    pub disabled_layers: BTreeSet<String>,
+    
+    /// Repath prefix template and strategy - Flint specific
+    #[serde(default)]
+    pub repath: RepathOptions,
+    
     /// Path to the project directory
     #[serde(default)]
     pub project_path: PathBuf,
@@ -128,8 +255,7 @@ impl Project {
             format!("{} Skin {}", champion_str, skin_id)
         };
         
-        // Store author as simple string
-        let authors = author.into_iter().collect::<Vec<_>>();
+        let authors = author.into_iter().map(ProjectAuthor::new).collect::<Vec<_>>();
         
         Self {
             name: slugify(&name_str),
@@ -138,9 +264,18 @@ impl Project {
             description: format!("Mod for {} skin {}", champion_str, skin_id),
             layers: default_layers(),
             authors,
+            license: None,
+            thumbnail: None,
             champion: champion_str,
+            target_kind: TargetKind::Champion,
             skin_id,
+            extra_targets: Vec::new(),
             league_path: Some(league_path.into()),
+            game_version: None,
+            variables: BTreeMap::new(),
+            collapse_variables: false,
+            disabled_layers: BTreeSet::new(),
+            repath: RepathOptions::default(),
             project_path: project_path.into(),
             created_at: now,
             modified_at: now,
@@ -154,11 +289,11 @@ impl Project {
             display_name: self.display_name.clone(),
             version: self.version.clone(),
             description: self.description.clone(),
-            authors: self.authors.iter().map(|a| ModProjectAuthor::Name(a.clone())).collect(),
-            license: None,
+            authors: self.authors.iter().map(Into::into).collect(),
+            license: self.license.as_ref().map(Into::into),
             transformers: vec![],
             layers: self.layers.clone(),
-            thumbnail: None,
+            thumbnail: self.thumbnail.clone(),
         }
     }
     
@@ -166,8 +301,15 @@ impl Project {
     pub fn to_flint_metadata(&self) -> FlintMetadata {
         FlintMetadata {
             champion: self.champion.clone(),
+            target_kind: self.target_kind,
             skin_id: self.skin_id,
+            extra_targets: self.extra_targets.clone(),
             league_path: self.league_path.clone(),
+            game_version: self.game_version.clone(),
+            variables: self.variables.clone(),
+            collapse_variables: self.collapse_variables,
+            disabled_layers: self.disabled_layers.clone(),
+            repath: self.repath.clone(),
             created_at: self.created_at,
             modified_at: self.modified_at,
         }
@@ -199,38 +341,121 @@ impl Project {
         self.project_path.join("output")
     }
 
+    /// Returns the League path if it still points to an existing installation
+    ///
+    /// Gives extraction-dependent callers a clear error to surface (and a hint
+    /// to relink) instead of failing later on a missing WAD.
+    pub fn require_league_path(&self) -> Result<&Path> {
+        match self.league_path.as_deref() {
+            Some(path) if path.exists() => Ok(path),
+            Some(path) => Err(Error::InvalidInput(format!(
+                "League installation not found at {}. Relink the project to the new League path.",
+                path.display()
+            ))),
+            None => Err(Error::InvalidInput(
+                "Project has no League path. Relink the project to a League installation.".to_string()
+            )),
+        }
+    }
+
+    /// Adds another champion skin to the project
+    ///
+    /// Only champion projects can have extra targets, and each champion only
+    /// once, since all of its files go into one `{champion}.wad.client` folder.
+    pub fn add_champion_target(&mut self, champion: &str, skin_id: u32) -> Result<()> {
+        if self.target_kind != TargetKind::Champion {
+            return Err(Error::InvalidInput("Only champion projects can target more champions".to_string()));
+        }
+        let taken = std::iter::once(self.champion.as_str())
+            .chain(self.extra_targets.iter().map(|t| t.champion.as_str()))
+            .any(|c| c.eq_ignore_ascii_case(champion));
+        if taken {
+            return Err(Error::InvalidInput(format!("The project already targets {}", champion)));
+        }
+        self.extra_targets.push(ChampionTarget { champion: champion.to_string(), skin_id });
+        Ok(())
+    }
+
     /// Returns the layer names
-    #[allow(dead_code)] // Kept for API completeness
     pub fn layer_names(&self) -> Vec<String> {
         self.layers.iter().map(|l| l.name.clone()).collect()
     }
+
+    /// Returns the layers to export, lowest priority first
+    pub fn enabled_layers(&self) -> Vec<&ModProjectLayer> {
+        let mut layers: Vec<&ModProjectLayer> = self
+            .layers
+            .iter()
+            .filter(|l| !self.disabled_layers.contains(&l.name))
+            .collect();
+        layers.sort_by_key(|l| l.priority);
+        layers
+    }
+}
+
+/// Finds the WAD a project extracts from in a League installation
+///
+/// Champion WADs use [`find_champion_wad`]. Map WADs are looked up in
+/// `DATA/FINAL/Maps/Shipping`, global ones anywhere under `DATA/FINAL`;
+/// both match the file name case-insensitively (`Map11.wad.client`).
+///
+/// # Arguments
+/// * `league_path` - Path to League installation
+/// * `target_kind` - Kind of WAD to look for
+/// * `name` - Champion name, or WAD name without `.wad.client`
+pub fn find_target_wad(league_path: &Path, target_kind: TargetKind, name: &str) -> Option<PathBuf> {
+    let final_dir = league_path.join("Game").join("DATA").join("FINAL");
+    let file_name = format!("{}.wad.client", name.to_lowercase());
+    let (search_dir, depth) = match target_kind {
+        TargetKind::Champion => return find_champion_wad(league_path, name),
+        TargetKind::Map => (final_dir.join("Maps").join("Shipping"), 1),
+        TargetKind::Global => (final_dir, 3),
+    };
+
+    let found = WalkDir::new(&search_dir)
+        .max_depth(depth)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .find(|e| e.file_type().is_file() && e.file_name().to_string_lossy().to_lowercase() == file_name)
+        .map(|e| e.into_path());
+    match &found {
+        Some(path) => tracing::info!("Found WAD: {}", path.display()),
+        None => tracing::warn!("WAD {} not found under {}", file_name, search_dir.display()),
+    }
+    found
 }
 
 /// Creates a new project with the required directory structure
 ///
 /// # Arguments
 /// * `name` - Project name (used as folder name)
-/// * `champion` - Champion internal name
+/// * `target_kind` - What the project modifies (champion, map or global WAD)
+/// * `target` - Champion internal name, or WAD name for maps and global WADs
 /// * `skin_id` - Skin ID
 /// * `league_path` - Path to League installation
 /// * `output_dir` - Directory where project folder will be created
 /// * `author` - Optional author/creator name
-pub fn create_project(
+pub fn create_target_project(
     name: &str,
-    champion: &str,
+    target_kind: TargetKind,
+    target: &str,
     skin_id: u32,
     league_path: &Path,
     output_dir: &Path,
     author: Option<String>,
 ) -> Result<Project> {
-    tracing::info!("Creating project '{}' for {} skin {}", name, champion, skin_id);
+    tracing::info!("Creating project '{}' for {:?} {} skin {}", name, target_kind, target, skin_id);
 
     // Validate inputs
     if name.is_empty() {
         return Err(Error::InvalidInput("Project name cannot be empty".to_string()));
     }
-    if champion.is_empty() {
-        return Err(Error::InvalidInput("Champion name cannot be empty".to_string()));
+    if target.is_empty() {
+        let message = match target_kind {
+            TargetKind::Champion => "Champion name cannot be empty",
+            TargetKind::Map | TargetKind::Global => "WAD name cannot be empty",
+        };
+        return Err(Error::InvalidInput(message.to_string()));
     }
     if !league_path.exists() {
         return Err(Error::InvalidInput(format!(
@@ -259,14 +484,19 @@ pub fn create_project(
     }
 
     // Create project
-    let project = Project::new(
+    let mut project = Project::new(
         name,
-        champion,
+        target,
         skin_id,
         league_path,
         &project_path,
         author,
     );
+    project.target_kind = target_kind;
+    if target_kind != TargetKind::Champion {
+        project.description = format!("Mod for {}", target);
+    }
+    project.game_version = detect_game_version(league_path);
 
     // Create directories
     fs::create_dir_all(&project_path)
@@ -326,18 +556,195 @@ pub fn open_project(path: &Path) -> Result<Project> {
             let reader = BufReader::new(file);
             if let Ok(flint) = serde_json::from_reader::<_, FlintMetadata>(reader) {
                 project.champion = flint.champion;
+                project.target_kind = flint.target_kind;
                 project.skin_id = flint.skin_id;
+                project.extra_targets = flint.extra_targets;
                 project.league_path = flint.league_path;
+                project.game_version = flint.game_version;
+                project.variables = flint.variables;
+                project.collapse_variables = flint.collapse_variables;
+                project.disabled_layers = flint.disabled_layers;
+                project.repath = flint.repath;
                 project.created_at = flint.created_at;
                 project.modified_at = flint.modified_at;
             }
         }
     }
 
+    if let Some(league_path) = &project.league_path {
+        if !league_path.exists() {
+            tracing::warn!(
+                "League path for project '{}' no longer exists: {}",
+                project.name,
+                league_path.display()
+            );
+        }
+    }
+
     tracing::info!("Project '{}' loaded successfully", project.name);
     Ok(project)
 }
 
+/// Points a project at a new League installation
+///
+/// Used when the stored league_path no longer exists (e.g., the game was moved).
+/// The new path must be a valid installation that contains the project's
+/// champion WAD. The game version is re-detected and flint.json is rewritten.
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `new_league_path` - Path to the new League installation root
+pub fn relink_league_path(project: &mut Project, new_league_path: &Path) -> Result<()> {
+    tracing::info!(
+        "Relinking project '{}' to League path: {}",
+        project.name,
+        new_league_path.display()
+    );
+
+    let installation = validate_league_path(new_league_path)?;
+
+    if find_target_wad(&installation.path, project.target_kind, &project.champion).is_none() {
+        return Err(Error::InvalidInput(format!(
+            "WAD for '{}' not found in {}",
+            project.champion,
+            installation.path.display()
+        )));
+    }
+
+    let previous_version = project.game_version.take();
+    project.game_version = detect_game_version(&installation.path);
+    project.league_path = Some(installation.path);
+    project.modified_at = Utc::now();
+
+    if previous_version.is_some() && previous_version != project.game_version {
+        tracing::info!(
+            "Game version changed: {} -> {}",
+            previous_version.as_deref().unwrap_or("unknown"),
+            project.game_version.as_deref().unwrap_or("unknown")
+        );
+    }
+
+    save_project(project)
+}
+
+/// Updates the priority, description or export toggle of a layer
+///
+/// Priority and description are stored in mod.config.json; the toggle is
+/// Flint-only and stored in flint.json. The base layer can't be disabled.
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `layer` - Name of an existing layer
+/// * `priority` - New priority (higher overrides lower), if changing
+/// * `enabled` - Whether the layer is exported, if changing
+/// * `description` - New description (empty clears it), if changing
+pub fn set_layer_metadata(
+    project: &mut Project,
+    layer: &str,
+    priority: Option<i32>,
+    enabled: Option<bool>,
+    description: Option<String>,
+) -> Result<()> {
+    let entry = project
+        .layers
+        .iter_mut()
+        .find(|l| l.name == layer)
+        .ok_or_else(|| Error::InvalidInput(format!("Layer '{}' not found", layer)))?;
+
+    if let Some(priority) = priority {
+        entry.priority = priority;
+    }
+    if let Some(description) = description {
+        entry.description = (!description.is_empty()).then_some(description);
+    }
+    match enabled {
+        Some(false) if layer == "base" => {
+            return Err(Error::InvalidInput("The base layer can't be disabled".to_string()));
+        }
+        Some(false) => {
+            project.disabled_layers.insert(layer.to_string());
+        }
+        Some(true) => {
+            project.disabled_layers.remove(layer);
+        }
+        None => {}
+    }
+
+    project.modified_at = Utc::now();
+    save_project(project)
+}
+
+/// Sets or clears the image shown for the mod in mod managers
+///
+/// The image (PNG, JPEG, WebP, DDS or TEX) is scaled down to fit 1024 pixels
+/// and stored as `thumbnail.webp` in the project folder; exports embed it in
+/// the modpkg metadata and as the fantome's `META/image.png`.
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `image_path` - Image to use, or None to remove the thumbnail
+pub fn set_project_thumbnail(project: &mut Project, image_path: Option<&Path>) -> Result<()> {
+    let thumbnail_path = project.project_path.join(THUMBNAIL_FILE);
+    match image_path {
+        Some(image_path) => {
+            let image = decode_image_file(image_path)?;
+            let webp = encode_preview(vec![image], PreviewFormat::Webp, Some(THUMBNAIL_MAX_SIZE), 0)?;
+            fs::write(&thumbnail_path, webp).map_err(|e| Error::io_with_path(e, &thumbnail_path))?;
+            project.thumbnail = Some(THUMBNAIL_FILE.to_string());
+        }
+        None => {
+            // A thumbnail the author pointed at elsewhere is theirs to keep
+            if project.thumbnail.as_deref() == Some(THUMBNAIL_FILE) && thumbnail_path.exists() {
+                fs::remove_file(&thumbnail_path).map_err(|e| Error::io_with_path(e, &thumbnail_path))?;
+            }
+            project.thumbnail = None;
+        }
+    }
+
+    project.modified_at = Utc::now();
+    save_project(project)
+}
+
+/// Adds a new, empty layer to a project
+///
+/// Layer names follow league-mod rules: letters, digits, `_` and `-` only.
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `layer` - Name of the new layer
+/// * `priority` - Priority of the layer; defaults to one above the current highest
+/// * `description` - Optional description
+pub fn add_layer(
+    project: &mut Project,
+    layer: &str,
+    priority: Option<i32>,
+    description: Option<String>,
+) -> Result<()> {
+    if layer.is_empty() || !layer.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(Error::InvalidInput(format!(
+            "Invalid layer name '{}': use letters, digits, '_' or '-'",
+            layer
+        )));
+    }
+    if project.layers.iter().any(|l| l.name.eq_ignore_ascii_case(layer)) {
+        return Err(Error::InvalidInput(format!("Layer '{}' already exists", layer)));
+    }
+
+    let priority = priority
+        .unwrap_or_else(|| project.layers.iter().map(|l| l.priority).max().unwrap_or(0) + 1);
+    project.layers.push(ModProjectLayer {
+        name: layer.to_string(),
+        priority,
+        description: description.filter(|d| !d.is_empty()),
+    });
+
+    let content = project.content_path(layer);
+    fs::create_dir_all(&content).map_err(|e| Error::io_with_path(e, &content))?;
+
+    project.modified_at = Utc::now();
+    save_project(project)
+}
+
 /// Saves a project to disk
 /// Writes both mod.config.json (league-mod compatible) and flint.json (Flint metadata)
 pub fn save_project(project: &Project) -> Result<()> {
@@ -366,7 +773,7 @@ pub fn save_project(project: &Project) -> Result<()> {
 }
 
 /// Sanitizes a filename to remove invalid characters
-fn sanitize_filename(name: &str) -> String {
+pub(crate) fn sanitize_filename(name: &str) -> String {
     name.chars()
         .map(|c| {
             if c.is_alphanumeric() || c == '-' || c == '_' || c == ' ' {
@@ -492,8 +899,9 @@ mod tests {
         let league_dir = temp_dir.path().join("League");
         fs::create_dir_all(&league_dir).unwrap();
 
-        let project = create_project(
+        let project = create_target_project(
             "Test Project",
+            TargetKind::Champion,
             "Ahri",
             0,
             &league_dir,
@@ -521,14 +929,119 @@ mod tests {
     #[test]
     fn test_create_project_empty_name() {
         let temp_dir = tempdir().unwrap();
-        let result = create_project("", "Ahri", 0, temp_dir.path(), temp_dir.path(), None);
+        let result = create_target_project("", TargetKind::Champion, "Ahri", 0, temp_dir.path(), temp_dir.path(), None);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_create_project_empty_champion() {
         let temp_dir = tempdir().unwrap();
-        let result = create_project("Test", "", 0, temp_dir.path(), temp_dir.path(), None);
+        let result = create_target_project("Test", TargetKind::Champion, "", 0, temp_dir.path(), temp_dir.path(), None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_relink_league_path() {
+        let temp_dir = tempdir().unwrap();
+        let old_league = temp_dir.path().join("old_league");
+        fs::create_dir_all(&old_league).unwrap();
+
+        let mut project = create_target_project("Relink", TargetKind::Champion, "Ahri", 0, &old_league, temp_dir.path(), None).unwrap();
+
+        // New install without the champion WAD is rejected
+        let new_league = temp_dir.path().join("new_league");
+        let champions = new_league.join("Game").join("DATA").join("FINAL").join("Champions");
+        fs::create_dir_all(&champions).unwrap();
+        fs::write(new_league.join("LeagueClient.exe"), b"").unwrap();
+        assert!(relink_league_path(&mut project, &new_league).is_err());
+
+        fs::write(champions.join("ahri.wad.client"), b"").unwrap();
+        fs::write(
+            new_league.join("Game").join("content-metadata.json"),
+            r#"{"version": "14.23.636.7270"}"#,
+        ).unwrap();
+        relink_league_path(&mut project, &new_league).unwrap();
+
+        let loaded = open_project(&project.project_path).unwrap();
+        assert_eq!(loaded.league_path.as_deref(), Some(new_league.as_path()));
+        assert_eq!(loaded.game_version.as_deref(), Some("14.23.636.7270"));
+    }
+
+    #[test]
+    fn test_create_map_project() {
+        let temp_dir = tempdir().unwrap();
+        let league = temp_dir.path().join("League");
+        let final_dir = league.join("Game").join("DATA").join("FINAL");
+        fs::create_dir_all(final_dir.join("Maps").join("Shipping")).unwrap();
+        fs::write(final_dir.join("Maps").join("Shipping").join("Map11.wad.client"), b"").unwrap();
+        fs::write(final_dir.join("UI.wad.client"), b"").unwrap();
+
+        let project = create_target_project("Rift", TargetKind::Map, "map11", 0, &league, temp_dir.path(), None).unwrap();
+        let loaded = open_project(&project.project_path).unwrap();
+        assert_eq!(loaded.target_kind, TargetKind::Map);
+        assert_eq!(loaded.champion, "map11");
+
+        assert!(find_target_wad(&league, TargetKind::Map, "Map11").is_some_and(|p| p.ends_with("Map11.wad.client")));
+        assert!(find_target_wad(&league, TargetKind::Global, "ui").is_some());
+        assert!(find_target_wad(&league, TargetKind::Map, "map12").is_none());
+        assert!(create_target_project("Empty", TargetKind::Global, "", 0, &league, temp_dir.path(), None).is_err());
+
+        // flint.json from before target kinds were stored opens as a champion project
+        let legacy: FlintMetadata = serde_json::from_str(
+            r#"{"champion":"Ahri","skin_id":0,"created_at":"2024-01-01T00:00:00Z","modified_at":"2024-01-01T00:00:00Z"}"#,
+        ).unwrap();
+        assert_eq!(legacy.target_kind, TargetKind::Champion);
+    }
+
+    #[test]
+    fn test_set_layer_metadata() {
+        let temp_dir = tempdir().unwrap();
+        let league_dir = temp_dir.path().join("League");
+        fs::create_dir_all(&league_dir).unwrap();
+
+        let mut project = create_target_project("Layers", TargetKind::Champion, "Ahri", 0, &league_dir, temp_dir.path(), None).unwrap();
+        project.layers.push(ModProjectLayer {
+            name: "chroma1".to_string(),
+            priority: 1,
+            description: None,
+        });
+
+        assert!(set_layer_metadata(&mut project, "base", None, Some(false), None).is_err());
+        assert!(set_layer_metadata(&mut project, "missing", Some(1), None, None).is_err());
+
+        set_layer_metadata(&mut project, "chroma1", Some(10), Some(false), Some("Red".to_string())).unwrap();
+        let loaded = open_project(&project.project_path).unwrap();
+        assert_eq!(loaded.layers[1].priority, 10);
+        assert_eq!(loaded.layers[1].description.as_deref(), Some("Red"));
+        assert!(loaded.disabled_layers.contains("chroma1"));
+        assert_eq!(loaded.enabled_layers().len(), 1);
+
+        set_layer_metadata(&mut project, "chroma1", None, Some(true), None).unwrap();
+        let names: Vec<&str> = project.enabled_layers().iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, vec!["base", "chroma1"]);
+    }
+
+    #[test]
+    fn test_set_project_thumbnail() {
+        let temp_dir = tempdir().unwrap();
+        let league_dir = temp_dir.path().join("League");
+        fs::create_dir_all(&league_dir).unwrap();
+        let mut project = create_target_project("Thumb", TargetKind::Champion, "Ahri", 0, &league_dir, temp_dir.path(), None).unwrap();
+
+        let image_path = temp_dir.path().join("cover.png");
+        image::RgbaImage::from_pixel(2048, 1024, image::Rgba([0, 128, 255, 255])).save(&image_path).unwrap();
+        set_project_thumbnail(&mut project, Some(&image_path)).unwrap();
+
+        let stored = project.project_path.join(THUMBNAIL_FILE);
+        let thumbnail = image::open(&stored).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (1024, 512));
+        // Kept in mod.config.json, where exports read it from
+        let loaded = open_project(&project.project_path).unwrap();
+        assert_eq!(loaded.thumbnail.as_deref(), Some(THUMBNAIL_FILE));
+        assert_eq!(loaded.to_mod_project().thumbnail.as_deref(), Some(THUMBNAIL_FILE));
+
+        set_project_thumbnail(&mut project, None).unwrap();
+        assert!(!stored.exists());
+        assert_eq!(open_project(&project.project_path).unwrap().thumbnail, None);
+    }
 }
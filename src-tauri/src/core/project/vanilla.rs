@@ -0,0 +1,431 @@
+//! Updating a project's vanilla assets after a League patch
+//!
+//! A project starts as a copy of the champion WAD. When a patch changes those
+//! files, the project keeps the old versions until they are re-extracted, but
+//! blindly re-extracting would throw away the author's edits. At creation the
+//! project records a manifest of what each extracted chunk looked like (its
+//! WAD header stamp and a hash of the written file). On update, chunks the
+//! patch changed are re-extracted only where the project still holds the
+//! untouched original; edited files are kept and reported.
+//!
+//! Changed chunks are found by comparing the recorded chunk stamps with the
+//! new WAD, the same way [`crate::core::wad::diff`] compares two WADs. Repath
+//! reports the files it moves and the manifest follows them, so each entry
+//! always names the one file extracted from its chunk. Projects created before
+//! the manifest existed fall back to comparing file modification times
+//! against the project creation time.
+
+use crate::core::project::Project;
+use crate::core::repath::refather::PathChange;
+use crate::core::store::write_replacing;
+use crate::core::wad::diff::{chunk_stamps, compare_chunks, ChunkStamp};
+use crate::core::wad::packer::chunk_hash;
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use chrono::{DateTime, Duration, Utc};
+use league_toolkit::wad::WadChunk;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// File name of the manifest inside a project's `.flint` folder
+pub const VANILLA_MANIFEST_FILE: &str = "vanilla_manifest.json";
+
+/// How long after project creation extraction and repath may still be
+/// writing files; later modifications are treated as the author's edits when
+/// there is no manifest
+const EXTRACTION_GRACE_MINUTES: i64 = 15;
+
+/// What one chunk looked like when it was extracted into the project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VanillaEntry {
+    /// Path of the file relative to the base layer, following repath moves
+    pub path: String,
+    /// What the WAD stored for the chunk
+    #[serde(flatten)]
+    pub stamp: ChunkStamp,
+    /// xxh3 of the extracted file
+    pub content_hash: u64,
+}
+
+/// Vanilla state of a project's extracted chunks, keyed by hex path hash
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VanillaManifest {
+    pub files: BTreeMap<String, VanillaEntry>,
+}
+
+impl VanillaManifest {
+    pub fn path(project_path: &Path) -> PathBuf {
+        project_path.join(".flint").join(VANILLA_MANIFEST_FILE)
+    }
+
+    /// Loads the manifest, or None for projects created without one
+    pub fn load(project_path: &Path) -> Option<Self> {
+        fs::read_to_string(Self::path(project_path))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+    }
+
+    pub fn save(&self, project_path: &Path) -> Result<()> {
+        let path = Self::path(project_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        let content = serde_json::to_string(self)
+            .map_err(|e| Error::InvalidInput(format!("Failed to serialize vanilla manifest: {}", e)))?;
+        fs::write(&path, content).map_err(|e| Error::io_with_path(e, &path))
+    }
+}
+
+/// Outcome of updating a project to the installed patch
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ProjectUpdateReport {
+    /// Game version the project was extracted from before the update
+    pub previous_game_version: Option<String>,
+    /// Game version of the League installation now
+    pub game_version: Option<String>,
+    /// Files replaced with the new vanilla version
+    pub updated: Vec<String>,
+    /// Files whose vanilla version changed but which the author edited, left as they were
+    pub preserved: Vec<String>,
+    /// BINs whose vanilla version changed; preserved ones need their edits merged by hand
+    pub flagged_bins: Vec<String>,
+    /// Extracted chunks the new WAD no longer contains
+    pub removed_upstream: Vec<String>,
+    /// Extracted chunks the patch didn't touch
+    pub unchanged: usize,
+    /// False if the project had no manifest and edits were detected by modification time
+    pub used_manifest: bool,
+}
+
+/// Relative path with `/` separators
+fn relative(path: &Path, root: &Path) -> Option<String> {
+    path.strip_prefix(root)
+        .ok()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+}
+
+fn is_bin(path: &str) -> bool {
+    path.to_lowercase().ends_with(".bin")
+}
+
+fn project_wad_folder(project: &Project) -> PathBuf {
+    project
+        .assets_path()
+        .join(format!("{}.wad.client", project.champion.to_lowercase()))
+}
+
+/// Records the vanilla state of every file in the project's champion WAD folder
+///
+/// Call right after extraction, before anything edits or moves the files.
+/// Files that don't correspond to a chunk of `wad_path` (renamed on collision,
+/// or pulled in from other WADs) are left out.
+///
+/// # Returns
+/// The number of files recorded
+pub fn record_vanilla_manifest(project: &Project, wad_path: &Path) -> Result<usize> {
+    let base = project.assets_path();
+    let wad_folder = project_wad_folder(project);
+    let reader = WadReader::open(wad_path)?;
+
+    let mut manifest = VanillaManifest::default();
+    for entry in WalkDir::new(&wad_folder).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let (Some(in_wad), Some(in_base)) = (relative(entry.path(), &wad_folder), relative(entry.path(), &base)) else {
+            continue;
+        };
+        let hash = chunk_hash(&in_wad);
+        let Some(chunk) = reader.get_chunk(hash) else {
+            continue;
+        };
+        let data = fs::read(entry.path()).map_err(|e| Error::io_with_path(e, entry.path()))?;
+        manifest.files.insert(
+            format!("{:016x}", hash),
+            VanillaEntry {
+                path: in_base,
+                stamp: ChunkStamp::from(chunk),
+                content_hash: xxh3_64(&data),
+            },
+        );
+    }
+
+    manifest.save(&project.project_path)?;
+    tracing::info!("Recorded vanilla manifest with {} files", manifest.files.len());
+    Ok(manifest.files.len())
+}
+
+/// Points manifest entries at the files repath moved
+///
+/// # Arguments
+/// * `content_base` - The project's `content/base` folder
+/// * `file_base` - Folder the moves are relative to, inside `content_base`
+/// * `moves` - Files moved, from their old path to their new one
+pub fn follow_relocations(content_base: &Path, file_base: &Path, moves: &[PathChange]) -> Result<()> {
+    let (Some(project_path), Some(folder)) = (
+        content_base.parent().and_then(Path::parent),
+        relative(file_base, content_base),
+    ) else {
+        return Ok(());
+    };
+    let Some(mut manifest) = VanillaManifest::load(project_path) else {
+        return Ok(());
+    };
+
+    let in_base = |path: &str| if folder.is_empty() { path.to_string() } else { format!("{}/{}", folder, path) };
+    let moved: HashMap<String, String> = moves
+        .iter()
+        .map(|change| (in_base(&change.from).to_lowercase(), in_base(&change.to)))
+        .collect();
+
+    let mut followed = 0;
+    for entry in manifest.files.values_mut() {
+        if let Some(to) = moved.get(&entry.path.to_lowercase()) {
+            entry.path = to.clone();
+            followed += 1;
+        }
+    }
+    if followed > 0 {
+        manifest.save(project_path)?;
+    }
+    Ok(())
+}
+
+/// Re-extracts chunks a patch changed into a project, keeping edited files
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `wad_path` - The champion WAD of the current installation
+pub fn update_project_assets(project: &Project, wad_path: &Path) -> Result<ProjectUpdateReport> {
+    let mut reader = WadReader::open(wad_path)?;
+    let chunks: HashMap<u64, WadChunk> = reader.chunks().clone();
+    let stamps = chunk_stamps(&reader);
+    let (mut decoder, _) = reader.wad_mut().decode();
+    let mut load = |chunk: &WadChunk| {
+        decoder
+            .load_chunk_decompressed(chunk)
+            .map(|data| data.to_vec())
+            .map_err(|e| Error::wad_with_path(format!("Failed to decompress chunk: {}", e), wad_path))
+    };
+
+    let base = project.assets_path();
+    let mut report = ProjectUpdateReport::default();
+
+    let mut manifest = match VanillaManifest::load(&project.project_path) {
+        Some(manifest) => {
+            report.used_manifest = true;
+            manifest
+        }
+        None => VanillaManifest::default(),
+    };
+
+    if report.used_manifest {
+        let recorded: HashMap<u64, ChunkStamp> = manifest
+            .files
+            .iter()
+            .filter_map(|(hash, entry)| Some((u64::from_str_radix(hash, 16).ok()?, entry.stamp)))
+            .collect();
+        let changes = compare_chunks(&recorded, &stamps);
+        report.unchanged = changes.unchanged.len();
+
+        for hash in &changes.removed {
+            report.removed_upstream.push(manifest.files[&format!("{:016x}", hash)].path.clone());
+        }
+        for hash in &changes.modified {
+            let entry = manifest.files.get_mut(&format!("{:016x}", hash)).expect("recorded chunk");
+            let file = base.join(&entry.path);
+            // Removed by the author or by cleanup
+            let Ok(current) = fs::read(&file) else {
+                continue;
+            };
+            if xxh3_64(&current) != entry.content_hash {
+                report.preserved.push(entry.path.clone());
+                if is_bin(&entry.path) {
+                    report.flagged_bins.push(entry.path.clone());
+                }
+                continue;
+            }
+
+            let chunk = &chunks[hash];
+            let data = load(chunk)?;
+            write_replacing(&file, &data).map_err(|e| Error::io_with_path(e, &file))?;
+            if is_bin(&entry.path) {
+                report.flagged_bins.push(entry.path.clone());
+            }
+            report.updated.push(entry.path.clone());
+            entry.stamp = ChunkStamp::from(chunk);
+            entry.content_hash = xxh3_64(&data);
+        }
+    } else {
+        let wad_folder = project_wad_folder(project);
+        let edited_after = project.created_at + Duration::minutes(EXTRACTION_GRACE_MINUTES);
+
+        for file in WalkDir::new(&wad_folder).into_iter().filter_map(|e| e.ok()) {
+            if !file.file_type().is_file() {
+                continue;
+            }
+            let (Some(in_wad), Some(shown)) = (relative(file.path(), &wad_folder), relative(file.path(), &base)) else {
+                continue;
+            };
+            let hash = chunk_hash(&in_wad);
+            let Some(chunk) = chunks.get(&hash) else {
+                continue;
+            };
+
+            let current = fs::read(file.path()).map_err(|e| Error::io_with_path(e, file.path()))?;
+            let data = load(chunk)?;
+            if current == data {
+                report.unchanged += 1;
+            } else if modified_after(file.path(), edited_after) {
+                if is_bin(&shown) {
+                    report.flagged_bins.push(shown.clone());
+                }
+                report.preserved.push(shown);
+                // Not recorded: its vanilla state is unknown
+                continue;
+            } else {
+                write_replacing(file.path(), &data).map_err(|e| Error::io_with_path(e, file.path()))?;
+                if is_bin(&shown) {
+                    report.flagged_bins.push(shown.clone());
+                }
+                report.updated.push(shown.clone());
+            }
+
+            manifest.files.insert(
+                format!("{:016x}", hash),
+                VanillaEntry {
+                    path: shown,
+                    stamp: ChunkStamp::from(chunk),
+                    content_hash: xxh3_64(&data),
+                },
+            );
+        }
+    }
+
+    manifest.save(&project.project_path)?;
+    report.updated.sort();
+    report.preserved.sort();
+    report.flagged_bins.sort();
+    report.removed_upstream.sort();
+
+    tracing::info!(
+        "Project update: {} updated, {} preserved, {} BINs flagged, {} removed upstream, {} unchanged",
+        report.updated.len(),
+        report.preserved.len(),
+        report.flagged_bins.len(),
+        report.removed_upstream.len(),
+        report.unchanged
+    );
+    Ok(report)
+}
+
+/// True if the file was last written after `time`
+fn modified_after(path: &Path, time: DateTime<Utc>) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(|modified| DateTime::<Utc>::from(modified) > time)
+        .unwrap_or(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad;
+
+    #[test]
+    fn test_update_keeps_edits_and_follows_moved_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = Project::new("Test", "Ahri", 0, dir.path(), dir.path().join("project"), None);
+        let wad_folder = project_wad_folder(&project);
+
+        let old_files: [(&str, &[u8]); 4] = [
+            ("assets/ahri/moved.dds", b"old moved texture"),
+            ("assets/ahri/edited.dds", b"old edited texture"),
+            ("data/characters/ahri/skins/skin0.bin", b"PROP old skin bin"),
+            ("assets/ahri/same.dds", b"same texture data"),
+        ];
+        for (path, data) in old_files {
+            let target = wad_folder.join(path);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::write(target, data).unwrap();
+        }
+        let old_wad = dir.path().join("old.wad.client");
+        write_wad(&old_wad, &old_files);
+        assert_eq!(record_vanilla_manifest(&project, &old_wad).unwrap(), 4);
+
+        // The author repaths one texture, edits another and the skin BIN
+        let moved = project.assets_path().join("ahri.wad.client/assets/mymod/moved.dds");
+        fs::create_dir_all(moved.parent().unwrap()).unwrap();
+        fs::rename(wad_folder.join("assets/ahri/moved.dds"), &moved).unwrap();
+        follow_relocations(&project.assets_path(), &wad_folder, &[PathChange {
+            from: "assets/ahri/moved.dds".to_string(),
+            to: "assets/mymod/moved.dds".to_string(),
+        }])
+        .unwrap();
+        fs::write(wad_folder.join("assets/ahri/edited.dds"), b"author texture").unwrap();
+        fs::write(wad_folder.join("data/characters/ahri/skins/skin0.bin"), b"PROP author bin").unwrap();
+
+        let new_wad = dir.path().join("new.wad.client");
+        write_wad::<&[u8]>(&new_wad, &[
+            ("assets/ahri/moved.dds", b"new moved texture"),
+            ("assets/ahri/edited.dds", b"new edited texture"),
+            ("data/characters/ahri/skins/skin0.bin", b"PROP new skin bin"),
+            ("assets/ahri/same.dds", b"same texture data"),
+        ]);
+
+        let report = update_project_assets(&project, &new_wad).unwrap();
+        assert!(report.used_manifest);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.updated, vec!["ahri.wad.client/assets/mymod/moved.dds"]);
+        assert_eq!(fs::read(&moved).unwrap(), b"new moved texture");
+        assert_eq!(report.preserved, vec![
+            "ahri.wad.client/assets/ahri/edited.dds",
+            "ahri.wad.client/data/characters/ahri/skins/skin0.bin",
+        ]);
+        assert_eq!(fs::read(wad_folder.join("assets/ahri/edited.dds")).unwrap(), b"author texture");
+        assert_eq!(report.flagged_bins, vec!["ahri.wad.client/data/characters/ahri/skins/skin0.bin"]);
+
+        // The manifest now describes the new patch, so a second run finds nothing to do
+        let again = update_project_assets(&project, &new_wad).unwrap();
+        assert!(again.updated.is_empty());
+        assert_eq!(again.unchanged, 2);
+    }
+
+    #[test]
+    fn test_update_leaves_files_sharing_vanilla_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = Project::new("Test", "Ahri", 0, dir.path(), dir.path().join("project"), None);
+        let wad_folder = project_wad_folder(&project);
+
+        let old_files: [(&str, &[u8]); 2] = [
+            ("assets/ahri/changed.dds", b"shared texture"),
+            ("assets/ahri/kept.dds", b"shared texture"),
+        ];
+        for (path, data) in old_files {
+            let target = wad_folder.join(path);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::write(target, data).unwrap();
+        }
+        let old_wad = dir.path().join("old.wad.client");
+        write_wad(&old_wad, &old_files);
+        record_vanilla_manifest(&project, &old_wad).unwrap();
+
+        let new_wad = dir.path().join("new.wad.client");
+        write_wad::<&[u8]>(&new_wad, &[
+            ("assets/ahri/changed.dds", b"patched texture"),
+            ("assets/ahri/kept.dds", b"shared texture"),
+        ]);
+
+        let report = update_project_assets(&project, &new_wad).unwrap();
+        assert_eq!(report.updated, vec!["ahri.wad.client/assets/ahri/changed.dds"]);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(fs::read(wad_folder.join("assets/ahri/changed.dds")).unwrap(), b"patched texture");
+        assert_eq!(fs::read(wad_folder.join("assets/ahri/kept.dds")).unwrap(), b"shared texture");
+    }
+}
@@ -0,0 +1,333 @@
+//! Project variable substitution for BIN text
+//!
+//! Projects can use `${NAME}` placeholders in ritobin text. They are expanded
+//! when text is converted to a BIN and, if the project opts in, collapsed back
+//! when a BIN is converted to text, so snippets shared between projects don't
+//! hard-code another creator's paths.
+//!
+//! Built-in variables are `${CHAMPION}`, `${SKIN_ID}` and `${PREFIX}` (the
+//! repath prefix, e.g. `ASSETS/SirDexal/Star-Ahri`). User variables from
+//! flint.json override built-ins of the same name. `$${NAME}` is written out
+//! as a literal `${NAME}`.
+
+use crate::core::project::{open_project, save_project, Project};
+use crate::core::repath::refather::{RepathConfig, RepathOptions};
+use crate::error::{Error, Result};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+fn placeholder_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\$(\$)?\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap())
+}
+
+/// Resolved variables for one project
+#[derive(Debug, Clone, Default)]
+pub struct ProjectVariables {
+    /// (name, value) pairs, built-ins first, user overrides applied
+    values: Vec<(String, String)>,
+    /// Whether BIN to text conversion should collapse values
+    collapse: bool,
+}
+
+impl ProjectVariables {
+    /// Builds the variable set for a project
+    pub fn from_project(project: &Project) -> Self {
+        let mut values: Vec<(String, String)> = vec![
+            ("CHAMPION".to_string(), project.champion.clone()),
+            ("SKIN_ID".to_string(), project.skin_id.to_string()),
+        ];
+
+        // The repath prefix with the first author as the creator
+        if let Some(creator) = project.authors.first().map(|a| &a.name).filter(|a| !a.is_empty()) {
+            let config = RepathConfig {
+                creator_name: creator.clone(),
+                project_name: project.display_name.clone(),
+                champion: project.champion.clone(),
+                target_skin_id: project.skin_id,
+                cleanup_unused: false,
+                include_conventional_assets: true,
+                version: project.version.clone(),
+                options: project.repath.clone(),
+                target_kind: project.target_kind,
+            };
+            values.push(("PREFIX".to_string(), format!("ASSETS/{}", config.prefix())));
+        }
+
+        for (name, value) in &project.variables {
+            match values.iter_mut().find(|(n, _)| n == name) {
+                Some(existing) => existing.1 = value.clone(),
+                None => values.push((name.clone(), value.clone())),
+            }
+        }
+
+        Self {
+            values,
+            collapse: project.collapse_variables,
+        }
+    }
+
+    /// Loads the variables of the project containing `path`, if any
+    pub fn for_file(path: &Path) -> Option<Self> {
+        let root = find_project_root(path)?;
+        match open_project(&root) {
+            Ok(project) => Some(Self::from_project(&project)),
+            Err(e) => {
+                tracing::warn!("Failed to load project variables from {}: {}", root.display(), e);
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Replaces `${NAME}` placeholders with their values
+    ///
+    /// # Returns
+    /// * `Err` naming the line of the first undefined variable
+    pub fn expand(&self, text: &str) -> Result<String> {
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+
+        for caps in placeholder_regex().captures_iter(text) {
+            let whole = caps.get(0).unwrap();
+            out.push_str(&text[last..whole.start()]);
+            last = whole.end();
+
+            let name = &caps[2];
+            if caps.get(1).is_some() {
+                // Escaped: $${NAME} -> ${NAME}
+                out.push_str(&whole.as_str()[1..]);
+                continue;
+            }
+
+            match self.get(name) {
+                Some(value) => out.push_str(value),
+                None => {
+                    let line = text[..whole.start()].matches('\n').count() + 1;
+                    return Err(Error::InvalidInput(format!(
+                        "Undefined project variable '${{{}}}' on line {}",
+                        name, line
+                    )));
+                }
+            }
+        }
+
+        out.push_str(&text[last..]);
+        Ok(out)
+    }
+
+    /// Replaces variable values inside string literals with `${NAME}`
+    ///
+    /// Does nothing unless the project enabled collapsing. Only whole-word
+    /// matches of non-numeric values are collapsed (longest first), and
+    /// existing placeholders are escaped, so `expand(collapse(text))`
+    /// always returns the original text.
+    pub fn collapse(&self, text: &str) -> String {
+        if !self.collapse {
+            return text.to_string();
+        }
+
+        let mut candidates: Vec<&(String, String)> = self
+            .values
+            .iter()
+            .filter(|(_, v)| !v.is_empty() && !v.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        candidates.sort_by_key(|(_, v)| std::cmp::Reverse(v.len()));
+
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+
+        // Walk string literals only; everything outside quotes is copied as-is
+        while let Some(open) = rest.find('"') {
+            out.push_str(&rest[..=open]);
+            let after = &rest[open + 1..];
+            let close = find_closing_quote(after).unwrap_or(after.len());
+            out.push_str(&collapse_literal(&after[..close], &candidates));
+            rest = &after[close..];
+            if let Some(stripped) = rest.strip_prefix('"') {
+                out.push('"');
+                rest = stripped;
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Index of the closing quote of a string literal body (skipping escapes)
+fn find_closing_quote(body: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in body.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return Some(i),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+fn collapse_literal(literal: &str, candidates: &[&(String, String)]) -> String {
+    static ESCAPE: OnceLock<Regex> = OnceLock::new();
+    let escape = ESCAPE.get_or_init(|| Regex::new(r"\$\{[A-Za-z_][A-Za-z0-9_]*\}").unwrap());
+    let escaped = escape.replace_all(literal, "$$$0");
+    let mut out = String::with_capacity(escaped.len());
+    let mut i = 0;
+
+    'outer: while i < escaped.len() {
+        // A preceding '$' would turn the placeholder into an escape
+        let prev_blocks = escaped[..i]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '$');
+        if !prev_blocks {
+            for (name, value) in candidates {
+                let end = i + value.len();
+                if escaped[i..].starts_with(value.as_str())
+                    && !escaped[end..].chars().next().is_some_and(|c| c.is_alphanumeric())
+                {
+                    out.push_str(&format!("${{{}}}", name));
+                    i = end;
+                    continue 'outer;
+                }
+            }
+        }
+        let c = escaped[i..].chars().next().unwrap();
+        out.push(c);
+        i += c.len_utf8();
+    }
+
+    out
+}
+
+/// Replaces a project's user variables and collapse setting, then saves it
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `variables` - New user variables (names must be identifiers)
+/// * `collapse` - Whether BIN to text conversion collapses values
+pub fn set_project_variables(
+    project: &mut Project,
+    variables: BTreeMap<String, String>,
+    collapse: bool,
+) -> Result<()> {
+    let name_re = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+    if let Some(bad) = variables.keys().find(|name| !name_re.is_match(name)) {
+        return Err(Error::InvalidInput(format!(
+            "Invalid variable name '{}': use letters, digits and underscores",
+            bad
+        )));
+    }
+
+    project.variables = variables;
+    project.collapse_variables = collapse;
+    save_project(project)
+}
+
+/// Replaces a project's repath prefix template and strategy, then saves it
+///
+/// # Arguments
+/// * `project` - The project to update
+/// * `options` - New repath options; the template must use known placeholders
+pub fn set_repath_options(project: &mut Project, options: RepathOptions) -> Result<()> {
+    options.validate()?;
+    project.repath = options;
+    save_project(project)
+}
+
+/// Finds the project directory (containing mod.config.json) above a file
+pub fn find_project_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|dir| dir.join("mod.config.json").is_file())
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(collapse: bool) -> ProjectVariables {
+        let mut project = Project::new("Star Ahri", "Ahri", 3, "C:\\League", "C:\\test", Some("SirDexal".to_string()));
+        project.variables = BTreeMap::from([("VFX".to_string(), "ASSETS/Shared/Particles".to_string())]);
+        project.collapse_variables = collapse;
+        ProjectVariables::from_project(&project)
+    }
+
+    #[test]
+    fn test_builtins() {
+        let vars = variables(false);
+        assert_eq!(vars.get("CHAMPION"), Some("Ahri"));
+        assert_eq!(vars.get("SKIN_ID"), Some("3"));
+        assert_eq!(vars.get("PREFIX"), Some("ASSETS/SirDexal/Star-Ahri"));
+    }
+
+    #[test]
+    fn test_expand() {
+        let vars = variables(false);
+        let text = r#"path: string = "${PREFIX}/${CHAMPION}/skin${SKIN_ID}.dds" # $${LITERAL}"#;
+        assert_eq!(
+            vars.expand(text).unwrap(),
+            r#"path: string = "ASSETS/SirDexal/Star-Ahri/Ahri/skin3.dds" # ${LITERAL}"#
+        );
+
+        let err = vars.expand("a: string = \"\"\nb: string = \"${MISSING}\"").unwrap_err();
+        assert!(err.to_string().contains("MISSING"));
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn test_collapse_roundtrip() {
+        let vars = variables(true);
+        let text = "entries: map[hash,embed] = {\n  \"Characters/Ahri/Skins/Skin3\" = Foo {\n    Ahri: string = \"ASSETS/SirDexal/Star-Ahri/Ahri_3.dds\"\n    other: string = \"ASSETS/Shared/Particles/x.dds ${raw} $${esc} $Ahri Ahrix\"\n  }\n}\n";
+
+        let collapsed = vars.collapse(text);
+        assert!(collapsed.contains("\"Characters/${CHAMPION}/Skins/Skin3\""));
+        assert!(collapsed.contains("\"${PREFIX}/${CHAMPION}_3.dds\""));
+        assert!(collapsed.contains("\"${VFX}/x.dds $${raw} $$${esc} $Ahri Ahrix\""));
+        // Outside string literals nothing changes
+        assert!(collapsed.contains("    Ahri: string"));
+
+        assert_eq!(vars.expand(&collapsed).unwrap(), text);
+    }
+
+    #[test]
+    fn test_collapse_disabled() {
+        let vars = variables(false);
+        assert_eq!(vars.collapse("a: string = \"Ahri\""), "a: string = \"Ahri\"");
+    }
+
+    #[test]
+    fn test_set_project_variables() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut project = crate::core::project::create_target_project("Test", crate::core::project::TargetKind::Champion, "Ahri", 0, dir.path(), dir.path(), None).unwrap();
+
+        let bad = BTreeMap::from([("bad name".to_string(), "x".to_string())]);
+        assert!(set_project_variables(&mut project, bad, true).is_err());
+
+        let vars = BTreeMap::from([("VFX".to_string(), "ASSETS/Shared".to_string())]);
+        set_project_variables(&mut project, vars, true).unwrap();
+
+        let reopened = open_project(&project.project_path).unwrap();
+        assert_eq!(reopened.variables.get("VFX").map(String::as_str), Some("ASSETS/Shared"));
+        assert!(reopened.collapse_variables);
+    }
+
+    #[test]
+    fn test_find_project_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("content/base/ahri.wad.client/data");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(dir.path().join("mod.config.json"), "{}").unwrap();
+
+        assert_eq!(find_project_root(&nested.join("skin3.bin")), Some(dir.path().to_path_buf()));
+        assert_eq!(find_project_root(Path::new("/nonexistent/file.bin")), None);
+    }
+}
@@ -1,7 +1,8 @@
 //! Repathing module for modifying asset paths in BIN files
 //!
 //! This module implements the "bumpath" algorithm that prefixes asset paths
-//! with a unique identifier (ASSETS/{creator}/{project}) to prevent conflicts between mods.
+//! with a unique identifier (ASSETS/{creator}/{project} by default, or the
+//! project's own prefix template) to prevent conflicts between mods.
 //!
 //! The module is organized as follows:
 //! - `refather`: Core path modification logic
@@ -11,6 +12,9 @@ pub mod refather;
 pub mod organizer;
 
 #[allow(unused_imports)]
-pub use refather::{repath_project, RepathConfig, RepathResult};
+pub use refather::{repath_layer, repath_project, RepathConfig, RepathOptions, RepathResult};
 #[allow(unused_imports)]
-pub use organizer::{organize_project, OrganizerConfig, OrganizerResult};
+pub use organizer::{
+    organize_layers, organize_project, plan_organize, OrganizePlan, OrganizeProgress, OrganizerConfig, OrganizerResult,
+    ProgressCallback,
+};
@@ -3,14 +3,24 @@
 //! This module provides a central entry point for project organization tasks,
 //! allowing independent control over concat and repathing operations.
 
+use crate::core::bin::cache::{clean_orphan_caches, CACHE_EXTENSION};
 use crate::core::bin::concat::{
-    concatenate_linked_bins, ConcatResult,
+    concatenate_linked_bins, plan_concat, trash_concat_sources, ConcatPlan, ConcatResult,
+};
+use crate::core::project::vanilla::follow_relocations;
+use crate::core::project::{ChampionTarget, TargetKind};
+use crate::core::trash::TrashSession;
+use crate::core::repath::refather::{
+    plan_repath, repath_layer, repath_project, wad_file_base, RepathConfig, RepathOptions, RepathPlan,
+    RepathResult,
 };
-use crate::core::repath::refather::{repath_project, RepathConfig, RepathResult};
 use crate::error::Result;
-use std::collections::HashMap;
+use crate::messages::Message;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
+use ts_rs::TS;
 
 /// Configuration for project organization operations
 #[derive(Debug, Clone)]
@@ -29,6 +39,16 @@ pub struct OrganizerConfig {
     pub target_skin_id: u32,
     /// Clean up unused/orphaned files after processing
     pub cleanup_unused: bool,
+    /// Keep loading screen / HUD icons that are found by path convention, not BIN references
+    pub include_conventional_assets: bool,
+    /// Further champion skins, each organized inside its own WAD folder
+    pub extra_targets: Vec<ChampionTarget>,
+    /// Mod version, for the `{version}` prefix placeholder
+    pub version: String,
+    /// The project's prefix template and repath strategy
+    pub repath_options: RepathOptions,
+    /// What the project modifies; concat and cleanup only apply to champions
+    pub target_kind: TargetKind,
 }
 
 impl OrganizerConfig {
@@ -48,6 +68,11 @@ impl OrganizerConfig {
             champion,
             target_skin_id,
             cleanup_unused: true,
+            include_conventional_assets: true,
+            extra_targets: Vec::new(),
+            version: String::new(),
+            repath_options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
         }
     }
 
@@ -67,6 +92,11 @@ impl OrganizerConfig {
             champion,
             target_skin_id,
             cleanup_unused: false,
+            include_conventional_assets: true,
+            extra_targets: Vec::new(),
+            version: String::new(),
+            repath_options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
         }
     }
 
@@ -86,7 +116,110 @@ impl OrganizerConfig {
             champion,
             target_skin_id,
             cleanup_unused: true,
+            include_conventional_assets: true,
+            extra_targets: Vec::new(),
+            version: String::new(),
+            repath_options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
+        }
+    }
+
+    /// Configs for the extra champion targets
+    ///
+    /// Each is named `{project}_{champion}`: the WADs are loaded side by side,
+    /// so repathed assets and concat BINs of two champions must not share paths.
+    /// A prefix template using neither name gets the champion appended for the
+    /// same reason.
+    fn extra_configs(&self) -> Vec<OrganizerConfig> {
+        let mut repath_options = self.repath_options.clone();
+        if let Some(template) = &mut repath_options.prefix_template {
+            if !template.contains("{project}") && !template.contains("{champion}") {
+                template.push_str("/{champion}");
+            }
         }
+        self.extra_targets
+            .iter()
+            .map(|target| OrganizerConfig {
+                project_name: format!("{}_{}", self.project_name, target.champion.to_lowercase()),
+                champion: target.champion.clone(),
+                target_skin_id: target.skin_id,
+                extra_targets: Vec::new(),
+                repath_options: repath_options.clone(),
+                target_kind: TargetKind::Champion,
+                ..self.clone()
+            })
+            .collect()
+    }
+
+    /// Builds the RepathConfig for the repath step
+    fn repath_config(&self, cleanup_unused: bool) -> RepathConfig {
+        RepathConfig {
+            creator_name: self.creator_name.clone(),
+            project_name: self.project_name.clone(),
+            champion: self.champion.clone(),
+            target_skin_id: self.target_skin_id,
+            cleanup_unused,
+            include_conventional_assets: self.include_conventional_assets,
+            version: self.version.clone(),
+            options: self.repath_options.clone(),
+            target_kind: self.target_kind,
+        }
+    }
+}
+
+/// A progress update from one of the organize steps
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct OrganizeProgress {
+    /// "concat", "scan", "repath" or "relocate"
+    pub phase: &'static str,
+    #[ts(type = "number")]
+    pub current: u64,
+    #[ts(type = "number")]
+    pub total: u64,
+    /// BIN or asset being processed
+    pub item: Option<String>,
+}
+
+impl OrganizeProgress {
+    /// Status line for progress events
+    pub fn message(&self) -> Message {
+        Message::new(
+            &format!("progress.organize.{}", self.phase),
+            [("current", self.current.to_string()), ("total", self.total.to_string())],
+        )
+    }
+
+    /// Completed fraction of the current phase
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.current as f64 / self.total as f64
+        }
+    }
+}
+
+/// Progress callback threaded through concat and repath
+///
+/// Must be `Sync` since BINs are scanned and repathed in parallel.
+pub type ProgressCallback<'a> = &'a (dyn Fn(OrganizeProgress) + Sync);
+
+/// Sends a progress update if a callback was provided
+pub(crate) fn report_progress(
+    progress: Option<ProgressCallback>,
+    phase: &'static str,
+    current: usize,
+    total: usize,
+    item: Option<&str>,
+) {
+    if let Some(cb) = progress {
+        cb(OrganizeProgress {
+            phase,
+            current: current as u64,
+            total: total as u64,
+            item: item.map(str::to_string),
+        });
     }
 }
 
@@ -95,8 +228,14 @@ impl OrganizerConfig {
 pub struct OrganizerResult {
     /// Result of concatenation operation (if enabled)
     pub concat_result: Option<ConcatResult>,
+    /// Why concatenation was skipped, if it failed (e.g. unresolved collisions)
+    pub concat_error: Option<String>,
     /// Result of repathing operation (if enabled)
     pub repath_result: Option<RepathResult>,
+    /// Repath results of the non-base layers, by layer name
+    pub layer_results: Vec<(String, RepathResult)>,
+    /// Results of the extra champion targets, by champion
+    pub target_results: Vec<(String, OrganizerResult)>,
 }
 
 impl OrganizerResult {
@@ -105,7 +244,9 @@ impl OrganizerResult {
     pub fn total_bins_processed(&self) -> usize {
         let concat_count = self.concat_result.as_ref().map(|r| r.source_count).unwrap_or(0);
         let repath_count = self.repath_result.as_ref().map(|r| r.bins_processed).unwrap_or(0);
-        concat_count + repath_count
+        let layer_count: usize = self.layer_results.iter().map(|(_, r)| r.bins_processed).sum();
+        let target_count: usize = self.target_results.iter().map(|(_, r)| r.total_bins_processed()).sum();
+        concat_count + repath_count + layer_count + target_count
     }
 }
 
@@ -115,15 +256,22 @@ impl OrganizerResult {
 /// Operations are run in the following order:
 /// 1. Concat (if enabled) - Merge linked Type 3 BINs
 /// 2. Repath (if enabled) - Prefix asset paths
+/// 3. Trash the concatenated source BINs, only if every step above succeeded
+/// 4. Remove `.ritobin` caches left behind by moved or removed BINs
+///
+/// Steps 1-3 run for the primary champion and then for each extra target,
+/// every one inside its own `{champion}.wad.client` folder.
 ///
 /// # Arguments
 /// * `content_base` - Path to the content/base directory of the project
 /// * `config` - Configuration controlling which operations to run
 /// * `path_mappings` - Mappings from original paths to actual paths (for hash-named files)
+/// * `progress` - Optional callback receiving per-BIN and per-file updates
 pub fn organize_project(
     content_base: &Path,
     config: &OrganizerConfig,
     path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
 ) -> Result<OrganizerResult> {
     tracing::info!(
         "Starting project organization (concat: {}, repath: {})",
@@ -131,45 +279,59 @@ pub fn organize_project(
         config.enable_repath
     );
 
+    let mut result = organize_target(content_base, config, path_mappings, progress);
+    for extra in config.extra_configs() {
+        tracing::info!("Organizing additional champion {}", extra.champion);
+        let target_result = organize_target(content_base, &extra, path_mappings, progress);
+        result.target_results.push((extra.champion, target_result));
+    }
+
+    // Step 4: Drop caches whose BIN was moved or removed above
+    if let Err(e) = clean_orphan_caches(content_base) {
+        tracing::warn!("Failed to clean orphaned .ritobin caches: {}", e);
+    }
+
+    tracing::info!("Project organization complete");
+    Ok(result)
+}
+
+/// Runs concat and repath for one champion's WAD folder
+fn organize_target(
+    content_base: &Path,
+    config: &OrganizerConfig,
+    path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
+) -> OrganizerResult {
     let mut result = OrganizerResult {
         concat_result: None,
+        concat_error: None,
         repath_result: None,
+        layer_results: Vec::new(),
+        target_results: Vec::new(),
     };
 
-    // Compute the WAD folder path: content_base/{champion}.wad.client/
-    // This is required for league-mod compatible project structure
-    let champion_lower = config.champion.to_lowercase();
-    let wad_folder_name = format!("{}.wad.client", champion_lower);
-    let wad_base = content_base.join(&wad_folder_name);
-    
-    // Determine which base to use for file operations
-    // Use WAD folder if it exists (new structure), otherwise fall back to content_base (legacy)
-    let file_base = if wad_base.exists() {
-        tracing::info!("Using WAD folder structure: {}", wad_base.display());
-        wad_base.clone()
-    } else {
-        tracing::info!("Using legacy folder structure (no WAD folder found)");
-        content_base.to_path_buf()
-    };
+    let file_base = wad_file_base(content_base, &config.champion);
 
-    // Step 1: Find the main skin BIN (needed for both concat and repath)
-    let main_bin_path = if !config.champion.is_empty() {
+    // Find the main skin BIN (needed for both concat and repath)
+    let main_bin_path = if config.target_kind == TargetKind::Champion && !config.champion.is_empty() {
         find_main_skin_bin(&file_base, &config.champion, config.target_skin_id)
     } else {
         None
     };
 
-    // Step 2: Run concat if enabled
-    if config.enable_concat {
+    // Step 1: Run concat if enabled; map and global WADs have no skin BIN
+    // whose links could be merged
+    if config.enable_concat && config.target_kind == TargetKind::Champion {
         if let Some(ref main_path) = main_bin_path {
             tracing::info!("Running BIN concatenation...");
             match concatenate_linked_bins(
                 main_path,
                 &config.project_name,
                 &config.creator_name,
-                &config.champion,
                 &file_base,
                 path_mappings,
+                &config.repath_options.concat_policy,
+                progress,
             ) {
                 Ok(concat_result) => {
                     tracing::info!(
@@ -182,6 +344,7 @@ pub fn organize_project(
                 Err(e) => {
                     tracing::warn!("Concatenation failed: {}", e);
                     // Continue with repath even if concat fails
+                    result.concat_error = Some(e.to_string());
                 }
             }
         } else {
@@ -189,41 +352,251 @@ pub fn organize_project(
         }
     }
 
-    // Step 3: Run repath if enabled
+    // Step 2: Run repath if enabled
+    let mut repath_ok = true;
     if config.enable_repath {
         tracing::info!("Running asset repathing...");
         
-        // Build RepathConfig from OrganizerConfig
-        let repath_config = RepathConfig {
-            creator_name: config.creator_name.clone(),
-            project_name: config.project_name.clone(),
-            champion: config.champion.clone(),
-            target_skin_id: config.target_skin_id,
-            cleanup_unused: config.cleanup_unused,
-        };
+        let repath_config = config.repath_config(config.cleanup_unused);
 
-        match repath_project(content_base, &repath_config, path_mappings) {
+        match repath_project(content_base, &repath_config, path_mappings, progress) {
             Ok(repath_result) => {
                 tracing::info!(
                     "Repathing complete: {} paths modified, {} files relocated",
                     repath_result.paths_modified,
                     repath_result.files_relocated
                 );
+                if let Err(e) = follow_relocations(content_base, &file_base, &repath_result.relocations) {
+                    tracing::warn!("Failed to update vanilla manifest after repath: {}", e);
+                }
                 result.repath_result = Some(repath_result);
             }
             Err(e) => {
                 tracing::warn!("Repathing failed: {}", e);
+                repath_ok = false;
             }
         }
     }
 
-    tracing::info!("Project organization complete");
+    // Step 3: Remove concat sources now that nothing depends on them
+    if let Some(ref concat_result) = result.concat_result {
+        if repath_ok {
+            trash_concat_sources(&file_base, concat_result, &TrashSession::for_base(content_base));
+        } else {
+            tracing::warn!("Keeping concatenated source BINs because repathing failed");
+        }
+    }
+
+    result
+}
+
+/// Organizes every layer of a project
+///
+/// The non-base layers are repathed first, with the same prefix as the base
+/// layer, so their BINs keep pointing at base-layer assets once those move.
+/// Concat and cleanup only run on the base layer: variant layers are sparse
+/// overrides, and everything they don't contain is expected to come from base.
+///
+/// # Arguments
+/// * `content_dir` - Path to the project's `content` directory
+/// * `layers` - Names of the layers to organize; missing folders are skipped
+/// * `config` - Configuration controlling which operations to run
+/// * `path_mappings` - Mappings from original paths to actual paths (for hash-named files)
+/// * `progress` - Optional callback receiving per-BIN and per-file updates
+pub fn organize_layers(
+    content_dir: &Path,
+    layers: &[String],
+    config: &OrganizerConfig,
+    path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
+) -> Result<OrganizerResult> {
+    let content_base = content_dir.join("base");
+    let mut layer_results = Vec::new();
+
+    if config.enable_repath {
+        let targets = std::iter::once(config.clone()).chain(config.extra_configs());
+        for repath_config in targets.map(|target| target.repath_config(false)) {
+            for layer in layers.iter().filter(|l| l.as_str() != "base") {
+                let layer_content = content_dir.join(layer);
+                if !layer_content.is_dir() {
+                    continue;
+                }
+
+                tracing::info!("Repathing layer '{}' for {}...", layer, repath_config.champion);
+                match repath_layer(&layer_content, &content_base, &repath_config, path_mappings, progress) {
+                    Ok(layer_result) => layer_results.push((layer.clone(), layer_result)),
+                    Err(e) => tracing::warn!("Repathing layer '{}' failed: {}", layer, e),
+                }
+            }
+        }
+    }
+
+    let mut result = organize_project(&content_base, config, path_mappings, progress)?;
+    result.layer_results = layer_results;
     Ok(result)
 }
 
+/// What organizing one champion's WAD folder would do
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TargetPlan {
+    pub champion: String,
+    /// Folder the plan's paths are relative to, inside `content/base`
+    pub folder: String,
+    pub concat: Option<ConcatPlan>,
+    pub repath: Option<RepathPlan>,
+    /// Steps that would be skipped or fail
+    pub warnings: Vec<String>,
+}
+
+/// What repathing one non-base layer for one champion would do
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct LayerPlan {
+    pub layer: String,
+    pub champion: String,
+    pub repath: RepathPlan,
+}
+
+/// What [`organize_layers`] would do, worked out without writing anything
+///
+/// When concatenation runs, the repath plan lists rewrites in the source BINs;
+/// the real run makes the same rewrites in the concat BIN built from them.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct OrganizePlan {
+    pub layers: Vec<LayerPlan>,
+    /// The primary champion first, then the extra targets
+    pub targets: Vec<TargetPlan>,
+    /// `.ritobin` caches removed with their BIN, relative to `content/base`
+    pub orphan_caches: Vec<String>,
+}
+
+/// Works out what [`organize_layers`] would do, without touching the project
+///
+/// # Arguments
+/// * `content_dir` - Path to the project's `content` directory
+/// * `layers` - Names of the layers to organize; missing folders are skipped
+/// * `config` - Configuration controlling which operations to run
+/// * `path_mappings` - Mappings from original paths to actual paths (for hash-named files)
+pub fn plan_organize(
+    content_dir: &Path,
+    layers: &[String],
+    config: &OrganizerConfig,
+    path_mappings: &HashMap<String, String>,
+) -> OrganizePlan {
+    let content_base = content_dir.join("base");
+    let mut plan = OrganizePlan {
+        layers: Vec::new(),
+        targets: Vec::new(),
+        orphan_caches: Vec::new(),
+    };
+
+    if config.enable_repath {
+        let targets = std::iter::once(config.clone()).chain(config.extra_configs());
+        for repath_config in targets.map(|target| target.repath_config(false)) {
+            for layer in layers.iter().filter(|l| l.as_str() != "base") {
+                let layer_content = content_dir.join(layer);
+                if !layer_content.is_dir() {
+                    continue;
+                }
+                match plan_repath(&layer_content, &[&content_base], &repath_config, path_mappings) {
+                    Ok(repath) => plan.layers.push(LayerPlan {
+                        layer: layer.clone(),
+                        champion: repath_config.champion.clone(),
+                        repath,
+                    }),
+                    Err(e) => tracing::warn!("Planning layer '{}' failed: {}", layer, e),
+                }
+            }
+        }
+    }
+
+    for target in std::iter::once(config.clone()).chain(config.extra_configs()) {
+        plan.targets.push(plan_target(&content_base, &target, path_mappings));
+    }
+
+    // Caches of removed BINs go too (see `clean_orphan_caches`), unless
+    // unused-file cleanup already takes them
+    let mut caches = BTreeSet::new();
+    for target in &plan.targets {
+        let deleted: Vec<&String> = target.repath.iter().flat_map(|r| r.deletions.iter().map(|d| &d.path)).collect();
+        let trashed = target.concat.iter().flat_map(|concat| concat.sources.iter());
+        for bin in deleted.iter().copied().chain(trashed).filter(|path| path.to_lowercase().ends_with(".bin")) {
+            let cache = format!("{}.{}", bin, CACHE_EXTENSION);
+            if deleted.contains(&&cache) {
+                continue;
+            }
+            let cache = if target.folder.is_empty() { cache } else { format!("{}/{}", target.folder, cache) };
+            if content_base.join(&cache).is_file() {
+                caches.insert(cache);
+            }
+        }
+    }
+    plan.orphan_caches = caches.into_iter().collect();
+
+    plan
+}
+
+/// Plans concat and repath for one champion's WAD folder
+fn plan_target(
+    content_base: &Path,
+    config: &OrganizerConfig,
+    path_mappings: &HashMap<String, String>,
+) -> TargetPlan {
+    let file_base = wad_file_base(content_base, &config.champion);
+    let mut plan = TargetPlan {
+        champion: config.champion.clone(),
+        folder: file_base
+            .strip_prefix(content_base)
+            .unwrap_or(&file_base)
+            .to_string_lossy()
+            .replace('\\', "/"),
+        concat: None,
+        repath: None,
+        warnings: Vec::new(),
+    };
+
+    if config.enable_concat && config.target_kind == TargetKind::Champion {
+        let main_bin_path = if !config.champion.is_empty() {
+            find_main_skin_bin(&file_base, &config.champion, config.target_skin_id)
+        } else {
+            None
+        };
+        match main_bin_path {
+            Some(main_path) => {
+                let policy = &config.repath_options.concat_policy;
+                match plan_concat(&main_path, &config.project_name, &config.creator_name, &file_base, path_mappings, policy) {
+                    Ok(concat) => {
+                        let unresolved = concat.collisions.iter().filter(|c| c.winner.is_none()).count();
+                        if unresolved > 0 {
+                            plan.warnings.push(format!(
+                                "Concatenation would fail: {} objects are defined by more than one linked BIN",
+                                unresolved
+                            ));
+                        }
+                        plan.concat = Some(concat);
+                    }
+                    Err(e) => plan.warnings.push(format!("Concatenation would be skipped: {}", e)),
+                }
+            }
+            None => plan.warnings.push("Concatenation would be skipped: main skin BIN not found".to_string()),
+        }
+    }
+
+    if config.enable_repath {
+        match plan_repath(content_base, &[], &config.repath_config(config.cleanup_unused), path_mappings) {
+            Ok(repath) => plan.repath = Some(repath),
+            Err(e) => plan.warnings.push(format!("Repathing would fail: {}", e)),
+        }
+    }
+
+    plan
+}
+
 /// Find the main skin BIN file for a champion
 /// Now searches inside {champion}.wad.client/ folder for league-mod compatibility
-fn find_main_skin_bin(content_base: &Path, champion: &str, skin_id: u32) -> Option<PathBuf> {
+pub(crate) fn find_main_skin_bin(content_base: &Path, champion: &str, skin_id: u32) -> Option<PathBuf> {
     let champion_lower = champion.to_lowercase();
     
     // WAD folder path: content/base/{champion}.wad.client/
@@ -346,4 +719,265 @@ mod tests {
         assert!(!config.enable_concat);
         assert!(config.enable_repath);
     }
+
+    #[test]
+    fn test_organize_reports_progress() {
+        use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+        use std::sync::Mutex;
+
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        let tree = text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {\n    \"Test\" = Foo {\n        tex: string = \"assets/a.dds\"\n    }\n}\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(base.join("data")).unwrap();
+        std::fs::create_dir_all(base.join("assets")).unwrap();
+        std::fs::write(base.join("data/test.bin"), write_bin(&tree).unwrap()).unwrap();
+        std::fs::write(base.join("assets/a.dds"), b"DDS ").unwrap();
+
+        let config = OrganizerConfig::repath_only("Me".to_string(), "Mod".to_string(), String::new(), 0);
+        let updates = Mutex::new(Vec::new());
+        let on_progress = |p: OrganizeProgress| updates.lock().unwrap().push(p);
+        let result = organize_project(base, &config, &HashMap::new(), Some(&on_progress)).unwrap();
+        assert_eq!(result.repath_result.unwrap().files_relocated, 1);
+
+        let updates = updates.into_inner().unwrap();
+        let phases: Vec<&str> = updates.iter().map(|p| p.phase).collect();
+        assert_eq!(phases, vec!["scan", "repath", "relocate"]);
+        assert!(updates.iter().all(|p| p.current == 1 && p.total == 1 && p.fraction() == 1.0));
+        assert_eq!(updates[2].item.as_deref(), Some("assets/a.dds"));
+        assert_eq!(updates[2].message().message, "Relocating assets (1/1)");
+    }
+
+    #[test]
+    fn test_organize_with_repath_options() {
+        use crate::core::bin::ltk_bridge::{read_bin, text_to_tree, tree_to_text, write_bin};
+
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        let tree = text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {\n    \"Test\" = Foo {\n        tex: string = \"assets/a.dds\"\n        sfx: string = \"assets/b.wpk\"\n    }\n}\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(base.join("data")).unwrap();
+        std::fs::create_dir_all(base.join("assets")).unwrap();
+        std::fs::write(base.join("data/test.bin"), write_bin(&tree).unwrap()).unwrap();
+        std::fs::write(base.join("assets/a.dds"), b"DDS ").unwrap();
+        std::fs::write(base.join("assets/b.wpk"), b"r3d2").unwrap();
+
+        let mut config = OrganizerConfig::repath_only("Me".to_string(), "Mod".to_string(), String::new(), 0);
+        config.version = "2.0".to_string();
+        config.repath_options = RepathOptions {
+            prefix_template: Some("{creator}/v{version}".to_string()),
+            skip_extensions: vec![".WPK".to_string()],
+            bins_only: true,
+            ..Default::default()
+        };
+        let result = organize_project(base, &config, &HashMap::new(), None).unwrap();
+        let repath = result.repath_result.unwrap();
+        assert_eq!((repath.paths_modified, repath.files_relocated, repath.files_removed), (1, 0, 0));
+
+        let text = tree_to_text(&read_bin(&std::fs::read(base.join("data/test.bin")).unwrap()).unwrap()).unwrap();
+        assert!(text.contains("ASSETS/Me/v2.0/a.dds"), "{}", text);
+        assert!(text.contains("\"assets/b.wpk\""), "{}", text);
+        assert!(base.join("assets/a.dds").is_file());
+        assert!(base.join("assets/b.wpk").is_file());
+    }
+
+    #[test]
+    fn test_plan_organize_matches_real_run() {
+        use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path();
+        let write = |rel: &str, data: &[u8]| {
+            let path = content.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, data).unwrap();
+        };
+        let tree = text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {\n    \"Test\" = Foo {\n        tex: string = \"assets/characters/test/a.dds\"\n    }\n}\n",
+        )
+        .unwrap();
+        let main_bin = write_bin(&tree).unwrap();
+        write("base/test.wad.client/data/characters/test/skins/skin0.bin", &main_bin);
+        write("base/test.wad.client/data/other.bin", &main_bin);
+        write("base/test.wad.client/data/other.bin.ritobin", b"#PROP_text\n");
+        write("base/test.wad.client/assets/characters/test/a.dds", b"DDS ");
+        write("base/test.wad.client/assets/characters/test/b.dds", b"DDS ");
+
+        let config = OrganizerConfig::new("Me".to_string(), "Mod".to_string(), "Test".to_string(), 0);
+        let layers = vec!["base".to_string()];
+        let plan = plan_organize(content, &layers, &config, &HashMap::new());
+
+        assert_eq!(plan.targets.len(), 1);
+        let target = &plan.targets[0];
+        assert_eq!(target.folder, "test.wad.client");
+        assert!(target.concat.is_none());
+        assert_eq!(target.warnings.len(), 1, "{:?}", target.warnings);
+        let repath = target.repath.as_ref().unwrap();
+        assert_eq!(repath.prefix, "ASSETS/Me/Mod");
+        assert_eq!(repath.bins.len(), 1);
+        assert_eq!(repath.rewrites[0].to, "ASSETS/Me/Mod/characters/Mod/a.dds");
+        assert_eq!(repath.relocations.len(), 1);
+        assert_eq!(repath.relocations[0].from, "assets/characters/test/a.dds");
+        let deleted: Vec<(&str, &str)> = repath.deletions.iter().map(|d| (d.path.as_str(), d.reason)).collect();
+        assert_eq!(
+            deleted,
+            vec![
+                ("assets/characters/test/b.dds", "unreferenced"),
+                ("data/other.bin", "unreferenced"),
+                ("data/other.bin.ritobin", "unreferenced"),
+            ]
+        );
+        assert!(plan.orphan_caches.is_empty());
+
+        // Nothing was touched
+        let wad = content.join("base/test.wad.client");
+        assert_eq!(std::fs::read(wad.join("data/characters/test/skins/skin0.bin")).unwrap(), main_bin);
+        assert!(wad.join("assets/characters/test/b.dds").is_file());
+        assert!(wad.join("data/other.bin.ritobin").is_file());
+
+        let result = organize_layers(content, &layers, &config, &HashMap::new(), None).unwrap();
+        let repathed = result.repath_result.unwrap();
+        assert_eq!(repathed.files_relocated, repath.relocations.len());
+        assert!(wad.join("ASSETS/Me/Mod/characters/Mod/a.dds").is_file());
+        assert!(!wad.join("assets/characters/test/b.dds").exists());
+        assert!(!wad.join("data/other.bin").exists());
+        assert!(!wad.join("data/other.bin.ritobin").exists());
+    }
+
+    #[test]
+    fn test_organize_map_project() {
+        use crate::core::bin::ltk_bridge::{read_bin, text_to_tree, tree_to_text, write_bin};
+
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path();
+        let wad = content.join("base/map11.wad.client");
+        let write = |rel: &str, data: &[u8]| {
+            let path = wad.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, data).unwrap();
+        };
+        let tree = text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {\n    \"Baron\" = Foo {\n        tex: string = \"ASSETS/Maps/KitPieces/SRX/baron_skin2_tx.dds\"\n        glow: string = \"assets/shared/particles/glow.dds\"\n        geo: string = \"data/maps/mapgeometry/map11/base_srx.mapgeo\"\n    }\n}\n",
+        )
+        .unwrap();
+        write("data/maps/shipping/map11/map11.bin", &write_bin(&tree).unwrap());
+        write("data/maps/mapgeometry/map11/base_srx.mapgeo", b"OEGM");
+        write("assets/maps/kitpieces/srx/baron_skin2_tx.dds", b"DDS ");
+        write("assets/shared/particles/glow.dds", b"DDS ");
+        // Only referenced by the map geometry, which isn't a BIN
+        write("assets/maps/lightmaps/srx/lightmap.dds", b"DDS ");
+
+        let mut config = OrganizerConfig::new("Me".to_string(), "Rift".to_string(), "map11".to_string(), 0);
+        config.target_kind = TargetKind::Map;
+        let layers = vec!["base".to_string()];
+
+        let plan = plan_organize(content, &layers, &config, &HashMap::new());
+        let target = &plan.targets[0];
+        assert_eq!(target.folder, "map11.wad.client");
+        assert!(target.concat.is_none() && target.warnings.is_empty(), "{:?}", target.warnings);
+        let repath = target.repath.as_ref().unwrap();
+        assert_eq!(repath.relocations.len(), 2);
+        assert!(repath.deletions.is_empty(), "{:?}", repath.deletions);
+
+        let result = organize_layers(content, &layers, &config, &HashMap::new(), None).unwrap();
+        assert!(result.concat_result.is_none());
+        let repathed = result.repath_result.unwrap();
+        assert_eq!((repathed.paths_modified, repathed.files_relocated, repathed.files_removed), (2, 2, 0));
+
+        let text = tree_to_text(&read_bin(&std::fs::read(wad.join("data/maps/shipping/map11/map11.bin")).unwrap()).unwrap()).unwrap();
+        // Skin numbers tell map assets apart, so they're kept
+        assert!(text.contains("ASSETS/Me/Rift/Maps/KitPieces/SRX/baron_skin2_tx.dds"), "{}", text);
+        assert!(text.contains("ASSETS/Me/Rift/shared/particles/glow.dds"), "{}", text);
+        assert!(text.contains("\"data/maps/mapgeometry/map11/base_srx.mapgeo\""), "{}", text);
+        assert!(wad.join("ASSETS/Me/Rift/maps/kitpieces/srx/baron_skin2_tx.dds").is_file());
+        assert!(wad.join("data/maps/mapgeometry/map11/base_srx.mapgeo").is_file());
+        assert!(wad.join("assets/maps/lightmaps/srx/lightmap.dds").is_file());
+    }
+
+    #[test]
+    fn test_organize_layers_repaths_base_references() {
+        use crate::core::bin::ltk_bridge::{read_bin, text_to_tree, tree_to_text, write_bin};
+
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path();
+        let bin_text = |tex: &str| {
+            format!(
+                "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {{\n    \"Test\" = Foo {{\n        tex: string = \"{}\"\n    }}\n}}\n",
+                tex
+            )
+        };
+        let write = |rel: &str, data: &[u8]| {
+            let path = content.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, data).unwrap();
+        };
+        // The chroma BIN points at a texture that only exists in the base layer
+        write("base/data/characters/test/skins/skin0.bin", &write_bin(&text_to_tree(&bin_text("assets/a.dds")).unwrap()).unwrap());
+        write("base/assets/a.dds", b"DDS ");
+        write("chroma1/data/characters/test/skins/skin0.bin", &write_bin(&text_to_tree(&bin_text("assets/a.dds")).unwrap()).unwrap());
+
+        let config = OrganizerConfig::repath_only("Me".to_string(), "Mod".to_string(), String::new(), 0);
+        let layers = vec!["base".to_string(), "chroma1".to_string(), "missing".to_string()];
+        let result = organize_layers(content, &layers, &config, &HashMap::new(), None).unwrap();
+
+        assert_eq!(result.layer_results.len(), 1);
+        let (name, chroma) = &result.layer_results[0];
+        assert_eq!(name, "chroma1");
+        assert_eq!(chroma.paths_modified, 1);
+        assert_eq!(chroma.files_relocated, 0);
+        assert_eq!(result.repath_result.unwrap().files_relocated, 1);
+
+        let chroma_bin = read_bin(&std::fs::read(content.join("chroma1/data/characters/test/skins/skin0.bin")).unwrap()).unwrap();
+        let text = tree_to_text(&chroma_bin).unwrap();
+        assert!(text.contains("ASSETS/Me/Mod/a.dds"), "{}", text);
+        assert!(!content.join("base/assets/a.dds").exists());
+    }
+
+    #[test]
+    fn test_organize_project_extra_targets() {
+        use crate::core::bin::ltk_bridge::{read_bin, text_to_tree, tree_to_text, write_bin};
+
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        let write_skin = |rel: &str, tex: &str| {
+            let text = format!(
+                "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {{\n    \"Test\" = Foo {{\n        tex: string = \"{}\"\n    }}\n}}\n",
+                tex
+            );
+            let path = base.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, write_bin(&text_to_tree(&text).unwrap()).unwrap()).unwrap();
+        };
+        let write = |rel: &str| {
+            let path = base.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"DDS ").unwrap();
+        };
+        write_skin("ahri.wad.client/data/characters/ahri/skins/skin0.bin", "assets/characters/ahri/a.dds");
+        write("ahri.wad.client/assets/characters/ahri/a.dds");
+        write_skin("annie.wad.client/data/characters/annie/skins/skin2.bin", "assets/characters/annie/b.dds");
+        write("annie.wad.client/assets/characters/annie/b.dds");
+
+        let mut config = OrganizerConfig::repath_only("Me".to_string(), "Mod".to_string(), "Ahri".to_string(), 0);
+        config.extra_targets = vec![ChampionTarget { champion: "Annie".to_string(), skin_id: 2 }];
+        let result = organize_project(base, &config, &HashMap::new(), None).unwrap();
+
+        assert_eq!(result.repath_result.unwrap().files_relocated, 1);
+        assert_eq!(result.target_results.len(), 1);
+        let (champion, annie) = &result.target_results[0];
+        assert_eq!(champion, "Annie");
+        assert_eq!(annie.repath_result.as_ref().unwrap().files_relocated, 1);
+
+        let bin_text = |rel: &str| tree_to_text(&read_bin(&std::fs::read(base.join(rel)).unwrap()).unwrap()).unwrap();
+        let ahri = bin_text("ahri.wad.client/data/characters/ahri/skins/skin0.bin");
+        let annie = bin_text("annie.wad.client/data/characters/annie/skins/skin2.bin");
+        // Each champion gets its own prefix so the two WADs don't overlap
+        assert!(ahri.contains("ASSETS/Me/Mod/characters/Mod/a.dds"), "{}", ahri);
+        assert!(annie.contains("ASSETS/Me/Mod_annie/characters/Mod_annie/b.dds"), "{}", annie);
+        assert!(!base.join("annie.wad.client/assets/characters/annie/b.dds").exists());
+    }
 }
@@ -2,13 +2,27 @@
 //!
 //! This module implements the "bumpath" algorithm that:
 //! 1. Scans BIN files for string values containing asset paths (assets/, data/)
-//! 2. Prefixes those paths with a unique identifier (ASSETS/{creator}/{project})
+//! 2. Prefixes those paths with a unique identifier (ASSETS/{creator}/{project}
+//!    unless the project sets its own [`RepathOptions::prefix_template`])
 //! 3. Relocates the actual asset files to match the new paths
 //! 4. Optionally combines linked BINs into a single concat BIN
+//!
+//! Champion projects follow the `characters/{champion}/skins/skin{N}` layout:
+//! the champion folder is renamed and skin numbers are remapped, and cleanup
+//! keeps only the target skin's BINs. Map and global projects keep their
+//! `data/` files (map BINs, map geometry) where the game loads them from and
+//! only move the assets those reference.
 
+use crate::core::bin::concat::ConcatMergePolicy;
 use crate::core::bin::ltk_bridge::{read_bin, write_bin};
+use crate::core::project::TargetKind;
+use crate::core::store::write_replacing;
+use crate::core::trash::{TrashSession, TRASH_DIR};
+use crate::core::repath::organizer::{report_progress, ProgressCallback};
+use crate::core::validation::conventional::is_conventional_asset;
+use crate::core::wad::extractor::strip_ltk_suffix;
 use crate::error::{Error, Result};
-use ltk_meta::PropertyValueEnum;
+use ltk_meta::{BinTree, PropertyValueEnum};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -17,6 +31,83 @@ use walkdir::WalkDir;
 use rayon::prelude::*;
 use dashmap::DashSet;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Prefix used when a project doesn't set a template
+pub const DEFAULT_PREFIX_TEMPLATE: &str = "{creator}/{project}";
+
+/// Placeholders a prefix template may use
+const PREFIX_PLACEHOLDERS: &[&str] = &["{creator}", "{project}", "{champion}", "{skin}", "{version}"];
+
+/// How a project wants its assets repathed, stored in flint.json
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RepathOptions {
+    /// Folder under `ASSETS/` that repathed assets move to, e.g.
+    /// `{creator}/{champion}_skin{skin}`. Placeholders: `{creator}`,
+    /// `{project}`, `{champion}`, `{skin}` and `{version}` (the mod version).
+    /// Defaults to `{creator}/{project}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_template: Option<String>,
+    /// Extensions (e.g. `wpk`, `bnk`) whose files keep their original path;
+    /// BIN references to them are left alone too
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skip_extensions: Vec<String>,
+    /// Only rewrite the paths in BINs; no file is moved or cleaned up
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub bins_only: bool,
+    /// How concatenation settles objects defined by more than one linked BIN
+    #[serde(default, skip_serializing_if = "ConcatMergePolicy::is_default")]
+    pub concat_policy: ConcatMergePolicy,
+}
+
+impl RepathOptions {
+    /// True when nothing differs from the default behaviour
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Rejects templates that would produce unusable paths
+    pub fn validate(&self) -> Result<()> {
+        let Some(template) = &self.prefix_template else {
+            return Ok(());
+        };
+        if template.trim().is_empty() {
+            return Err(Error::InvalidInput("The repath prefix can't be empty".to_string()));
+        }
+        if template.contains('\\') || template.split('/').any(|segment| segment == "..") {
+            return Err(Error::InvalidInput(format!(
+                "Repath prefix '{}' must be a relative path with '/' separators",
+                template
+            )));
+        }
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}').map(|end| start + end + 1).unwrap_or(rest.len());
+            let placeholder = &rest[start..end];
+            if !PREFIX_PLACEHOLDERS.contains(&placeholder) {
+                return Err(Error::InvalidInput(format!(
+                    "Unknown placeholder '{}' in repath prefix; use {}",
+                    placeholder,
+                    PREFIX_PLACEHOLDERS.join(", ")
+                )));
+            }
+            rest = &rest[end..];
+        }
+        Ok(())
+    }
+
+    /// Whether files with this path stay where they are
+    fn skips(&self, path: &str) -> bool {
+        let Some((_, extension)) = path.rsplit_once('.') else {
+            return false;
+        };
+        self.skip_extensions
+            .iter()
+            .any(|skip| skip.trim_start_matches('.').eq_ignore_ascii_case(extension))
+    }
+}
 
 /// Configuration for repathing operations
 /// 
@@ -29,13 +120,48 @@ pub struct RepathConfig {
     pub champion: String,
     pub target_skin_id: u32,
     pub cleanup_unused: bool,
+    /// Keep conventional-path assets (loading screen, HUD icons) during cleanup
+    pub include_conventional_assets: bool,
+    /// Mod version, for the `{version}` placeholder
+    pub version: String,
+    pub options: RepathOptions,
+    /// Layout of the WAD being repathed; unused-file and BIN cleanup only run
+    /// for champions
+    pub target_kind: TargetKind,
 }
 
 impl RepathConfig {
+    /// Whether paths follow the champion layout (`characters/{champion}/skins/...`)
+    fn is_character(&self) -> bool {
+        self.target_kind == TargetKind::Champion
+    }
+
+    /// Whether a referenced (normalized) path moves under the prefix
+    ///
+    /// Map and global WADs are loaded through their `data/` files at the
+    /// paths the game ships them at, so only the assets those reference move.
+    fn repaths(&self, path: &str) -> bool {
+        !self.options.skips(path) && (self.is_character() || !path.starts_with("data/"))
+    }
+
+    /// Folder under `ASSETS/` that assets are moved to
+    ///
+    /// Placeholders that come out empty (no champion for map projects) drop
+    /// their path segment.
     pub fn prefix(&self) -> String {
-        let creator = self.creator_name.replace(' ', "-");
-        let project = self.project_name.replace(' ', "-");
-        format!("{}/{}", creator, project)
+        let template = self.options.prefix_template.as_deref().unwrap_or(DEFAULT_PREFIX_TEMPLATE);
+        let rendered = template
+            .replace("{creator}", &self.creator_name)
+            .replace("{project}", &self.project_name)
+            .replace("{champion}", &self.champion.to_lowercase())
+            .replace("{skin}", &self.target_skin_id.to_string())
+            .replace("{version}", &self.version);
+        rendered
+            .split('/')
+            .map(|segment| segment.trim().replace(' ', "-"))
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("/")
     }
 }
 
@@ -47,58 +173,344 @@ pub struct RepathResult {
     pub files_relocated: usize,
     pub files_removed: usize,
     pub missing_paths: Vec<String>,
+    /// Files moved, relative to the WAD folder they are in
+    pub relocations: Vec<PathChange>,
+}
+
+/// A path before and after repathing
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PathChange {
+    pub from: String,
+    pub to: String,
+}
+
+/// A BIN whose references a repath would rewrite
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PlannedBinRewrite {
+    pub bin: String,
+    pub paths_modified: usize,
+}
+
+/// A file an organize step would delete
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PlannedDeletion {
+    pub path: String,
+    /// Why, e.g. "unreferenced" or "wrong skin"
+    pub reason: &'static str,
+}
+
+/// What repathing one content folder would do, worked out without writing anything
+///
+/// File paths are relative to `folder`, with `/` separators.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct RepathPlan {
+    /// Folder holding the files, relative to the content folder
+    /// (`{champion}.wad.client`, or empty for legacy projects)
+    pub folder: String,
+    /// Where assets move, e.g. `ASSETS/SirDexal/Star-Ahri`
+    pub prefix: String,
+    /// BINs whose references change
+    pub bins: Vec<PlannedBinRewrite>,
+    /// Referenced asset paths and what the BINs say instead
+    pub rewrites: Vec<PathChange>,
+    /// Files moved to their repathed location
+    pub relocations: Vec<PathChange>,
+    /// Files removed by cleanup
+    pub deletions: Vec<PlannedDeletion>,
+    /// Paths referenced in BINs that aren't on disk
+    pub missing_paths: Vec<String>,
 }
 
 /// Repath all assets in a project directory
+///
+/// `progress` receives an update per scanned BIN, per repathed BIN and per
+/// relocated asset.
 pub fn repath_project(
     content_base: &Path,
     config: &RepathConfig,
     path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
+) -> Result<RepathResult> {
+    repath_content(content_base, &[], config, path_mappings, progress)
+}
+
+/// Repath a non-base layer (e.g. a chroma) of a project
+///
+/// Variant layers usually only override a few files and point at everything
+/// else in the base layer, so paths that exist in `base_content` are rewritten
+/// too. Must run before the base layer itself is repathed, while its assets are
+/// still at their original paths. Only files inside the layer are moved.
+pub fn repath_layer(
+    layer_content: &Path,
+    base_content: &Path,
+    config: &RepathConfig,
+    path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
+) -> Result<RepathResult> {
+    repath_content(layer_content, &[base_content], config, path_mappings, progress)
+}
+
+/// Works out what [`repath_project`] (or, with `shared_bases`, [`repath_layer`])
+/// would do, without writing anything
+///
+/// BINs are rewritten in memory only. Files that relocation would move and
+/// cleanup would then remove are listed as deletions, not as moves.
+pub fn plan_repath(
+    content_base: &Path,
+    shared_bases: &[&Path],
+    config: &RepathConfig,
+    path_mappings: &HashMap<String, String>,
+) -> Result<RepathPlan> {
+    let ContentScan {
+        file_base,
+        bin_files,
+        linked_animations,
+        existing_paths,
+        suffixed_files,
+        mut missing_paths,
+    } = scan_content(content_base, shared_bases, config, path_mappings, None)?;
+    let file_base = file_base.as_path();
+    let prefix = config.prefix();
+    let relative = |path: &Path| normalize_separators(&path.strip_prefix(file_base).unwrap_or(path).to_string_lossy());
+
+    // Step 4: rewrite each BIN in memory
+    let mut bins: Vec<PlannedBinRewrite> = bin_files
+        .par_iter()
+        .filter_map(|bin_path| {
+            let data = fs::read(bin_path).ok()?;
+            let mut bin = read_bin(&data).ok()?;
+            let paths_modified = repath_tree(&mut bin, &existing_paths, &prefix, config);
+            (paths_modified > 0).then(|| PlannedBinRewrite { bin: relative(bin_path), paths_modified })
+        })
+        .collect();
+    bins.sort_by(|a, b| a.bin.cmp(&b.bin));
+
+    let mut rewrites: Vec<PathChange> = existing_paths
+        .iter()
+        .map(|path| PathChange {
+            from: path.to_string(),
+            to: apply_prefix_to_path(path, &prefix, config),
+        })
+        .collect();
+    rewrites.sort_by(|a, b| a.from.cmp(&b.from));
+    missing_paths.sort();
+
+    let mut plan = RepathPlan {
+        folder: relative_folder(content_base, file_base),
+        prefix: format!("ASSETS/{}", prefix),
+        bins,
+        rewrites,
+        relocations: Vec::new(),
+        deletions: Vec::new(),
+        missing_paths,
+    };
+    if config.options.bins_only {
+        return Ok(plan);
+    }
+
+    // Step 5: moves, by the normalized path of the file that moves
+    let mut moves: HashMap<String, PathChange> = HashMap::new();
+    for path in &existing_paths {
+        if let Some((source, new_path)) = relocation_for(file_base, path, &suffixed_files, &prefix, config) {
+            let from = relative(&source);
+            moves.insert(normalize_path(&from), PathChange { from, to: new_path });
+        }
+    }
+
+    // Step 6: cleanup judges files by where they are after relocation
+    if config.cleanup_unused && config.is_character() {
+        let expected_paths: HashSet<String> = existing_paths
+            .iter()
+            .map(|p| normalize_path(&apply_prefix_to_path(p, &prefix, config)))
+            .collect();
+        for entry in WalkDir::new(file_base)
+            .into_iter()
+            .filter_entry(|e| e.file_name() != TRASH_DIR)
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            let is_bin = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("bin"));
+            if !entry.file_type().is_file() || is_bin {
+                continue;
+            }
+            let current = relative(path);
+            let normalized = normalize_path(&current);
+            let after = moves.get(&normalized).map_or_else(|| normalized.clone(), |change| normalize_path(&change.to));
+            if let Some(reason) = unused_file_reason(&after, &expected_paths, config) {
+                moves.remove(&normalized);
+                plan.deletions.push(PlannedDeletion { path: current, reason });
+            }
+        }
+    }
+    plan.relocations = moves.into_values().collect();
+    plan.relocations.sort_by(|a, b| a.from.cmp(&b.from));
+
+    // Step 7: BIN cleanup
+    if config.is_character() {
+        for (path, rel_str) in relative_bins(file_base) {
+            if let Some(reason) = irrelevant_bin_reason(&rel_str, &config.champion, config.target_skin_id, linked_animations.as_ref()) {
+                plan.deletions.push(PlannedDeletion { path: relative(&path), reason });
+            }
+        }
+    }
+    plan.deletions.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(plan)
+}
+
+/// Path of `folder` relative to `content_base`, empty if they're the same
+fn relative_folder(content_base: &Path, folder: &Path) -> String {
+    normalize_separators(&folder.strip_prefix(content_base).unwrap_or(folder).to_string_lossy())
+}
+
+/// Shared implementation of [`repath_project`] and [`repath_layer`]
+fn repath_content(
+    content_base: &Path,
+    shared_bases: &[&Path],
+    config: &RepathConfig,
+    path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
 ) -> Result<RepathResult> {
     tracing::info!(
         "Starting repathing for project with prefix: ASSETS/{}",
         config.prefix()
     );
 
-    if !content_base.exists() {
-        return Err(Error::InvalidInput(format!(
-            "Content base directory not found: {}",
-            content_base.display()
-        )));
-    }
-
-    // Compute the WAD folder path: content_base/{champion}.wad.client/
-    // This is required for league-mod compatible project structure
-    let champion_lower = config.champion.to_lowercase();
-    let wad_folder_name = format!("{}.wad.client", champion_lower);
-    let wad_base = content_base.join(&wad_folder_name);
-    
-    // Determine which base to use for file operations
-    // Use WAD folder if it exists (new structure), otherwise fall back to content_base (legacy)
-    let file_base = if wad_base.exists() {
-        tracing::info!("Using WAD folder structure: {}", wad_base.display());
-        &wad_base
-    } else {
-        tracing::info!("Using legacy folder structure (no WAD folder found)");
-        content_base
-    };
+    let ContentScan {
+        file_base,
+        bin_files,
+        linked_animations,
+        existing_paths,
+        suffixed_files,
+        missing_paths,
+    } = scan_content(content_base, shared_bases, config, path_mappings, progress)?;
+    let file_base = file_base.as_path();
 
     let mut result = RepathResult {
         bins_processed: 0,
         paths_modified: 0,
         files_relocated: 0,
         files_removed: 0,
-        missing_paths: Vec::new(),
+        missing_paths,
+        relocations: Vec::new(),
     };
 
+    // Step 4: Repath BIN files (PARALLEL)
+    let prefix = config.prefix();
+    let bins_processed = AtomicUsize::new(0);
+    let paths_modified = AtomicUsize::new(0);
+    let bins_done = AtomicUsize::new(0);
+
+    bin_files.par_iter().for_each(|bin_path| {
+        match repath_bin_file(bin_path, &existing_paths, &prefix, config) {
+            Ok(modified_count) => {
+                bins_processed.fetch_add(1, Ordering::Relaxed);
+                paths_modified.fetch_add(modified_count, Ordering::Relaxed);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to repath {}: {}", bin_path.display(), e);
+            }
+        }
+        let done = bins_done.fetch_add(1, Ordering::Relaxed) + 1;
+        report_progress(progress, "repath", done, bin_files.len(), Some(&bin_path.to_string_lossy()));
+    });
+
+    result.bins_processed = bins_processed.load(Ordering::Relaxed);
+    result.paths_modified = paths_modified.load(Ordering::Relaxed);
+
+    // Steps 5 to 8 move and delete files, which BIN-only repathing leaves to the user
+    if config.options.bins_only {
+        tracing::info!("BIN-only repathing: files are left where they are");
+    } else {
+        // Step 5: Relocate asset files
+        result.relocations = relocate_assets(file_base, &existing_paths, &suffixed_files, &prefix, config, progress)?;
+        result.files_relocated = result.relocations.len();
+
+        // Steps 6 and 7 move what they remove into the project trash. Map and
+        // global WADs hold files only the game's own data references (map
+        // geometry textures), so nothing there counts as unused.
+        if config.is_character() {
+            let trash = TrashSession::for_base(file_base);
+
+            // Step 6: Clean up unused files
+            if config.cleanup_unused {
+                result.files_removed = cleanup_unused_files(file_base, &existing_paths, &prefix, config, &trash)?;
+            }
+
+            // Step 7: Clean up irrelevant extracted BINs
+            cleanup_irrelevant_bins(file_base, &config.champion, config.target_skin_id, linked_animations.as_ref(), &trash)?;
+        }
+
+        // Step 8: Clean up empty directories
+        cleanup_empty_dirs(file_base)?;
+    }
+
+    tracing::info!(
+        "Repathing complete: {} bins, {} paths modified, {} files relocated",
+        result.bins_processed,
+        result.paths_modified,
+        result.files_relocated
+    );
+
+    Ok(result)
+}
+
+/// What steps 0-3 of a repath found in a content folder
+struct ContentScan {
+    /// Folder holding the files (the WAD folder, or the content folder itself)
+    file_base: PathBuf,
+    /// BINs whose references are rewritten
+    bin_files: Vec<PathBuf>,
+    /// Animation BINs the main skin BIN links to; None if it couldn't be read
+    linked_animations: Option<HashSet<String>>,
+    /// Referenced paths that exist and will be repathed
+    existing_paths: HashSet<String>,
+    /// Extension-less assets stored with a `.ltk` suffix, by referenced path
+    suffixed_files: HashMap<String, String>,
+    /// Referenced paths with no file on disk
+    missing_paths: Vec<String>,
+}
+
+/// Finds the BINs to repath and the referenced assets that exist
+///
+/// Read-only, so [`plan_repath`] can share it with the real run. Paths found
+/// under any of `shared_bases` count as existing, so BINs referencing them are
+/// rewritten even though the file lives elsewhere.
+fn scan_content(
+    content_base: &Path,
+    shared_bases: &[&Path],
+    config: &RepathConfig,
+    path_mappings: &HashMap<String, String>,
+    progress: Option<ProgressCallback>,
+) -> Result<ContentScan> {
+    if !content_base.exists() {
+        return Err(Error::InvalidInput(format!(
+            "Content base directory not found: {}",
+            content_base.display()
+        )));
+    }
+
+    let wad_base = wad_file_base(content_base, &config.champion);
+    let file_base = wad_base.as_path();
+    let shared_file_bases: Vec<PathBuf> = shared_bases
+        .iter()
+        .map(|base| wad_file_base(base, &config.champion))
+        .collect();
+
     // Step 0: Find the main skin BIN (now using file_base)
-    let main_bin_path = if !config.champion.is_empty() {
+    let main_bin_path = if config.is_character() && !config.champion.is_empty() {
         find_main_skin_bin(file_base, &config.champion, config.target_skin_id)
     } else {
         None
     };
 
     let mut bin_files: Vec<PathBuf> = Vec::new();
+    // Animation BINs the main skin BIN links to; None if it couldn't be read
+    let mut linked_animations: Option<HashSet<String>> = None;
 
     if let Some(ref main_path) = main_bin_path {
         tracing::info!("Found main skin BIN: {}", main_path.display());
@@ -108,14 +520,18 @@ pub fn repath_project(
         if let Ok(data) = fs::read(main_path) {
             if let Ok(bin) = read_bin(&data) {
                 tracing::info!("Main skin BIN has {} dependencies", bin.dependencies.len());
-                
+                let animations = linked_animations.insert(HashSet::new());
+
                 for dep_path in &bin.dependencies {
                     let normalized_path = dep_path.to_lowercase().replace('\\', "/");
 
                     let actual_path = path_mappings.get(&normalized_path)
                         .cloned()
                         .unwrap_or_else(|| normalized_path.clone());
-                    
+                    if actual_path.contains("/animations/") {
+                        animations.insert(actual_path.to_lowercase());
+                    }
+
                     let full_path = file_base.join(&actual_path);
                     if full_path.exists() {
                         bin_files.push(full_path);
@@ -126,7 +542,11 @@ pub fn repath_project(
             }
         }
     } else {
-        tracing::warn!("No main skin BIN found, falling back to scanning all BINs");
+        if config.is_character() {
+            tracing::warn!("No main skin BIN found, falling back to scanning all BINs");
+        } else {
+            tracing::info!("Scanning all BINs of {:?} WAD {}", config.target_kind, config.champion);
+        }
         bin_files = WalkDir::new(file_base)
             .into_iter()
             .filter_map(|e| e.ok())
@@ -147,12 +567,15 @@ pub fn repath_project(
 
     // Step 2: Scan BINs to collect referenced asset paths (PARALLEL)
     let all_asset_paths_set: DashSet<String> = DashSet::new();
+    let bins_scanned = AtomicUsize::new(0);
     bin_files.par_iter().for_each(|bin_path| {
         if let Ok(paths) = scan_bin_for_paths(bin_path) {
             for path in paths {
                 all_asset_paths_set.insert(path);
             }
         }
+        let done = bins_scanned.fetch_add(1, Ordering::Relaxed) + 1;
+        report_progress(progress, "scan", done, bin_files.len(), Some(&bin_path.to_string_lossy()));
     });
     tracing::info!("Found {} unique asset paths in BINs", all_asset_paths_set.len());
 
@@ -160,36 +583,24 @@ pub fn repath_project(
     let all_asset_paths: HashSet<String> = all_asset_paths_set.into_iter().collect();
 
     // Step 3: Determine which paths actually exist
-    // Use case-insensitive matching since Windows filesystem is case-insensitive
-    let existing_paths: HashSet<String> = all_asset_paths
-        .iter()
-        .filter(|path| {
-            let full_path = file_base.join(path);
-            if full_path.exists() {
-                return true;
-            }
-            
-            // Try case-insensitive lookup by checking parent directory
-            if let Some(parent) = full_path.parent() {
-                if parent.exists() {
-                    if let Some(filename) = full_path.file_name() {
-                        let filename_lower = filename.to_string_lossy().to_lowercase();
-                        if let Ok(entries) = std::fs::read_dir(parent) {
-                            for entry in entries.filter_map(|e| e.ok()) {
-                                let entry_name = entry.file_name().to_string_lossy().to_lowercase();
-                                if entry_name == filename_lower {
-                                    return true;
-                                }
-                            }
-                        }
-                    }
-                }
+    // Use case-insensitive matching since Windows filesystem is case-insensitive.
+    // Extension-less assets are on disk with a `.ltk` suffix; remember where so
+    // relocation can give them back the name the BINs use.
+    let mut existing_paths: HashSet<String> = HashSet::new();
+    let mut suffixed_files: HashMap<String, String> = HashMap::new();
+    for path in &all_asset_paths {
+        if let Some(actual) = find_extracted(file_base, path, path_mappings) {
+            if actual != *path {
+                suffixed_files.insert(path.clone(), actual);
             }
-            
-            false
-        })
-        .cloned()
-        .collect();
+            existing_paths.insert(path.clone());
+        } else if shared_file_bases
+            .iter()
+            .any(|base| find_extracted(base, path, path_mappings).is_some())
+        {
+            existing_paths.insert(path.clone());
+        }
+    }
 
     // Log missing paths for debugging
     let missing_count = all_asset_paths.len() - existing_paths.len();
@@ -203,60 +614,105 @@ pub fn repath_project(
         }
     }
 
-    for path in all_asset_paths.difference(&existing_paths) {
-        result.missing_paths.push(path.clone());
-    }
-
-    // Step 4: Repath BIN files (PARALLEL)
-    let prefix = config.prefix();
-    let bins_processed = AtomicUsize::new(0);
-    let paths_modified = AtomicUsize::new(0);
+    let missing_paths = all_asset_paths.difference(&existing_paths).map(|path| path.to_string()).collect();
 
-    bin_files.par_iter().for_each(|bin_path| {
-        match repath_bin_file(bin_path, &existing_paths, &prefix, config) {
-            Ok(modified_count) => {
-                bins_processed.fetch_add(1, Ordering::Relaxed);
-                paths_modified.fetch_add(modified_count, Ordering::Relaxed);
-            }
-            Err(e) => {
-                tracing::warn!("Failed to repath {}: {}", bin_path.display(), e);
-            }
-        }
-    });
-
-    result.bins_processed = bins_processed.load(Ordering::Relaxed);
-    result.paths_modified = paths_modified.load(Ordering::Relaxed);
+    // Files of skipped kinds, and the data files of map and global WADs, stay
+    // put, so their references must not change either
+    let before = existing_paths.len();
+    existing_paths.retain(|path| config.repaths(path));
+    if existing_paths.len() < before {
+        tracing::info!("Leaving {} referenced files at their original path", before - existing_paths.len());
+    }
 
-    // Step 5: Relocate asset files
-    result.files_relocated = relocate_assets(file_base, &existing_paths, &prefix, config)?;
+    Ok(ContentScan {
+        file_base: wad_base,
+        bin_files,
+        linked_animations,
+        existing_paths,
+        suffixed_files,
+        missing_paths,
+    })
+}
 
-    // Step 6: Clean up unused files
-    if config.cleanup_unused {
-        result.files_removed = cleanup_unused_files(file_base, &existing_paths, &prefix, config)?;
+/// Returns the folder holding a content directory's files
+///
+/// This is `{champion}.wad.client/` for league-mod compatible projects, or
+/// the content directory itself for legacy projects without a WAD folder.
+pub(crate) fn wad_file_base(content_base: &Path, champion: &str) -> PathBuf {
+    let wad_base = content_base.join(format!("{}.wad.client", champion.to_lowercase()));
+    if wad_base.exists() {
+        tracing::info!("Using WAD folder structure: {}", wad_base.display());
+        wad_base
+    } else {
+        tracing::info!("Using legacy folder structure (no WAD folder found)");
+        content_base.to_path_buf()
     }
+}
 
-    // Step 7: Clean up irrelevant extracted BINs
-    cleanup_irrelevant_bins(file_base, &config.champion, config.target_skin_id)?;
+/// Checks whether a file exists, ignoring the case of its file name
+///
+/// League paths are case-insensitive, and so is the Windows filesystem.
+fn exists_case_insensitive(full_path: &Path) -> bool {
+    if full_path.exists() {
+        return true;
+    }
 
-    // Step 8: Clean up empty directories
-    cleanup_empty_dirs(file_base)?;
+    let (Some(parent), Some(filename)) = (full_path.parent(), full_path.file_name()) else {
+        return false;
+    };
+    let filename_lower = filename.to_string_lossy().to_lowercase();
+    fs::read_dir(parent)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|entry| entry.file_name().to_string_lossy().to_lowercase() == filename_lower)
+        })
+        .unwrap_or(false)
+}
 
-    tracing::info!(
-        "Repathing complete: {} bins, {} paths modified, {} files relocated",
-        result.bins_processed,
-        result.paths_modified,
-        result.files_relocated
-    );
+/// Finds the file a referenced path was extracted to, relative to `base`
+///
+/// Chunks without an extension are written as `{name}.ltk` or
+/// `{name}.ltk.{ext}`. Extraction records those in `path_mappings`; for
+/// projects extracted before it did, the folder is searched instead.
+fn find_extracted(base: &Path, path: &str, path_mappings: &HashMap<String, String>) -> Option<String> {
+    if exists_case_insensitive(&base.join(path)) {
+        return Some(path.to_string());
+    }
+    if Path::new(path).extension().is_some() {
+        return None;
+    }
+    if let Some(mapped) = path_mappings.get(path) {
+        if base.join(mapped).exists() {
+            return Some(mapped.clone());
+        }
+    }
 
-    Ok(result)
+    let (parent, name) = path.rsplit_once('/').unwrap_or(("", path));
+    fs::read_dir(base.join(parent))
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .find(|file_name| {
+            let lower = file_name.to_lowercase();
+            lower.strip_prefix(name).is_some_and(|rest| strip_ltk_suffix(rest).is_empty())
+        })
+        .map(|file_name| if parent.is_empty() { file_name } else { format!("{}/{}", parent, file_name) })
 }
 
 /// Scan a BIN file for asset path references
-fn scan_bin_for_paths(bin_path: &Path) -> Result<Vec<String>> {
+///
+/// Returned paths are normalized (lowercase, '/' separators).
+pub fn scan_bin_for_paths(bin_path: &Path) -> Result<Vec<String>> {
     let data = fs::read(bin_path).map_err(|e| Error::io_with_path(e, bin_path))?;
     let bin = read_bin(&data)
         .map_err(|e| Error::InvalidInput(format!("Failed to parse BIN: {}", e)))?;
 
+    Ok(scan_tree_for_paths(&bin))
+}
+
+/// Collects the asset paths referenced by an already parsed BIN
+pub fn scan_tree_for_paths(bin: &BinTree) -> Vec<String> {
     let mut paths = Vec::new();
 
     for object in bin.objects.values() {
@@ -265,7 +721,7 @@ fn scan_bin_for_paths(bin_path: &Path) -> Result<Vec<String>> {
         }
     }
 
-    Ok(paths)
+    paths
 }
 
 /// Recursively collect asset paths from a PropertyValueEnum
@@ -311,19 +767,25 @@ fn collect_paths_from_value(value: &PropertyValueEnum, paths: &mut Vec<String>)
     }
 }
 
+/// UI data (HUD scenes, fonts) lives under `ux/` rather than `assets/` or `data/`
 fn is_asset_path(s: &str) -> bool {
     let lower = s.to_lowercase();
-    lower.starts_with("assets/") || lower.starts_with("data/")
+    lower.starts_with("assets/") || lower.starts_with("data/") || lower.starts_with("ux/")
 }
 
 fn normalize_path(s: &str) -> String {
     s.to_lowercase().replace('\\', "/")
 }
 
+/// `/` separators, case kept (for paths shown to the user)
+fn normalize_separators(s: &str) -> String {
+    s.replace('\\', "/")
+}
+
 fn apply_prefix_to_path(path: &str, prefix: &str, config: &RepathConfig) -> String {
     let lower = path.to_lowercase();
 
-    // Strip the original prefix (assets/ or data/)
+    // Strip the original prefix (assets/ or data/); ux/ paths keep their folder
     let stripped = if lower.starts_with("assets/") {
         &path[7..]  // Skip "assets/"
     } else if lower.starts_with("data/") {
@@ -332,12 +794,18 @@ fn apply_prefix_to_path(path: &str, prefix: &str, config: &RepathConfig) -> Stri
         path
     };
 
-    // Step 1: Replace champion folder with project folder
-    // Path format: characters/{champion}/... → characters/{project}/...
-    let champion_replaced = replace_champion_with_project(stripped, config);
+    // Steps 1-2 only apply to champion layouts: map and global assets have no
+    // champion folder, and their skin numbers tell different files apart
+    let remapped = if config.is_character() {
+        // Step 1: Replace champion folder with project folder
+        // Path format: characters/{champion}/... → characters/{project}/...
+        let champion_replaced = replace_champion_with_project(stripped, config);
 
-    // Step 2: Remap skin IDs: Replace ALL skin references with target_skin_id
-    let remapped = remap_skin_ids(&champion_replaced, config.target_skin_id);
+        // Step 2: Remap skin IDs: Replace ALL skin references with target_skin_id
+        remap_skin_ids(&champion_replaced, config.target_skin_id)
+    } else {
+        stripped.to_string()
+    };
 
     // Step 3: Add new prefix: ASSETS/{creator}/...
     format!("ASSETS/{}/{}", prefix, remapped)
@@ -409,25 +877,33 @@ fn repath_bin_file(bin_path: &Path, existing_paths: &HashSet<String>, prefix: &s
     let mut bin = read_bin(&data)
         .map_err(|e| Error::InvalidInput(format!("Failed to parse BIN: {}", e)))?;
 
-    let mut modified_count = 0;
-
-    for object in bin.objects.values_mut() {
-        for prop in object.properties.values_mut() {
-            modified_count += repath_value(&mut prop.value, existing_paths, prefix, config);
-        }
-    }
+    let modified_count = repath_tree(&mut bin, existing_paths, prefix, config);
 
     if modified_count > 0 {
         let new_data = write_bin(&bin)
             .map_err(|e| Error::InvalidInput(format!("Failed to write BIN: {}", e)))?;
 
-        fs::write(bin_path, new_data).map_err(|e| Error::io_with_path(e, bin_path))?;
+        write_replacing(bin_path, &new_data).map_err(|e| Error::io_with_path(e, bin_path))?;
         tracing::debug!("Repathed {} paths in {}", modified_count, bin_path.display());
     }
 
     Ok(modified_count)
 }
 
+/// Repaths the references of a parsed BIN in memory
+///
+/// # Returns
+/// Number of paths rewritten
+fn repath_tree(bin: &mut BinTree, existing_paths: &HashSet<String>, prefix: &str, config: &RepathConfig) -> usize {
+    let mut modified_count = 0;
+    for object in bin.objects.values_mut() {
+        for prop in object.properties.values_mut() {
+            modified_count += repath_value(&mut prop.value, existing_paths, prefix, config);
+        }
+    }
+    modified_count
+}
+
 /// Recursively repath string values in a PropertyValueEnum
 fn repath_value(value: &mut PropertyValueEnum, existing_paths: &HashSet<String>, prefix: &str, config: &RepathConfig) -> usize {
     let mut count = 0;
@@ -480,26 +956,30 @@ fn repath_value(value: &mut PropertyValueEnum, existing_paths: &HashSet<String>,
     count
 }
 
-fn relocate_assets(content_base: &Path, existing_paths: &HashSet<String>, prefix: &str, config: &RepathConfig) -> Result<usize> {
-    let mut relocated = 0;
-
-    for path in existing_paths {
-        // Skip BIN files EXCEPT concat.bin (which needs to move to match its repathed reference)
-        if path.to_lowercase().ends_with(".bin") {
-            // Allow concat.bin to be relocated
-            if !path.to_lowercase().contains("__concat") {
-                continue;
-            }
-        }
+/// Moves the referenced files to their repathed location
+///
+/// Files in `suffixed_files` (referenced path to on-disk path) lose their
+/// `.ltk` suffix on the way, so they keep matching the rewritten references.
+///
+/// # Returns
+/// The moves made, from the file's old path on disk to its new one
+fn relocate_assets(
+    content_base: &Path,
+    existing_paths: &HashSet<String>,
+    suffixed_files: &HashMap<String, String>,
+    prefix: &str,
+    config: &RepathConfig,
+    progress: Option<ProgressCallback>,
+) -> Result<Vec<PathChange>> {
+    let mut relocated = Vec::new();
 
-        let source = content_base.join(path);
-        let new_path = apply_prefix_to_path(path, prefix, config);
-        let dest = content_base.join(&new_path);
+    for (index, path) in existing_paths.iter().enumerate() {
+        report_progress(progress, "relocate", index + 1, existing_paths.len(), Some(path));
 
-        // Skip if source doesn't exist
-        if !source.exists() {
+        let Some((source, new_path)) = relocation_for(content_base, path, suffixed_files, prefix, config) else {
             continue;
-        }
+        };
+        let dest = content_base.join(&new_path);
 
         // Create destination directory
         if let Some(parent) = dest.parent() {
@@ -510,22 +990,79 @@ fn relocate_assets(content_base: &Path, existing_paths: &HashSet<String>, prefix
         match fs::rename(&source, &dest) {
             Ok(_) => {
                 tracing::debug!("Renamed (fast): {} -> {}", source.display(), dest.display());
-                relocated += 1;
             }
             Err(_) => {
                 // Cross-device move, fallback to copy+remove
                 fs::copy(&source, &dest).map_err(|e| Error::io_with_path(e, &source))?;
                 fs::remove_file(&source).map_err(|e| Error::io_with_path(e, &source))?;
                 tracing::debug!("Copied (cross-device): {} -> {}", source.display(), dest.display());
-                relocated += 1;
             }
         }
+        let from = source.strip_prefix(content_base).unwrap_or(&source).to_string_lossy().replace('\\', "/");
+        relocated.push(PathChange { from, to: new_path });
     }
 
     Ok(relocated)
 }
 
-fn cleanup_unused_files(content_base: &Path, referenced_paths: &HashSet<String>, prefix: &str, config: &RepathConfig) -> Result<usize> {
+/// Where relocation moves a referenced file, if it moves at all
+///
+/// # Returns
+/// The file on disk and its new path relative to `content_base`
+fn relocation_for(
+    content_base: &Path,
+    path: &str,
+    suffixed_files: &HashMap<String, String>,
+    prefix: &str,
+    config: &RepathConfig,
+) -> Option<(PathBuf, String)> {
+    // Skip BIN files EXCEPT concat.bin (which needs to move to match its repathed reference)
+    let lower = path.to_lowercase();
+    if lower.ends_with(".bin") && !lower.contains("__concat") {
+        return None;
+    }
+
+    let source = content_base.join(suffixed_files.get(path).map_or(path, String::as_str));
+    // Skip if source doesn't exist
+    if !source.exists() {
+        return None;
+    }
+    Some((source, apply_prefix_to_path(path, prefix, config)))
+}
+
+/// Why unused-file cleanup removes the (non-BIN) file at `normalized`, if it does
+///
+/// `expected_paths` are the normalized repathed references.
+fn unused_file_reason(
+    normalized: &str,
+    expected_paths: &HashSet<String>,
+    config: &RepathConfig,
+) -> Option<&'static str> {
+    // Skipped kinds were never moved into the new tree
+    if config.options.skips(normalized) {
+        return None;
+    }
+
+    // Conventional assets aren't referenced by any BIN but the game still loads them
+    if config.include_conventional_assets
+        && is_conventional_asset(normalized, &config.champion, config.target_skin_id)
+    {
+        tracing::debug!("Keeping conventional asset: {}", normalized);
+        return None;
+    }
+
+    // Referenced shared particles and UI files moved under the prefix too,
+    // outside the champion's own folder, so being referenced is what counts
+    (!expected_paths.contains(normalized)).then_some("unreferenced")
+}
+
+fn cleanup_unused_files(
+    content_base: &Path,
+    referenced_paths: &HashSet<String>,
+    prefix: &str,
+    config: &RepathConfig,
+    trash: &TrashSession,
+) -> Result<usize> {
     let mut removed = 0;
 
     let expected_paths: HashSet<String> = referenced_paths
@@ -533,8 +1070,10 @@ fn cleanup_unused_files(content_base: &Path, referenced_paths: &HashSet<String>,
         .map(|p| normalize_path(&apply_prefix_to_path(p, prefix, config)))
         .collect();
 
+    // Without a project root the trash lives in `content_base` itself
     for entry in WalkDir::new(content_base)
         .into_iter()
+        .filter_entry(|e| e.file_name() != TRASH_DIR)
         .filter_map(|e| e.ok())
     {
         let path = entry.path();
@@ -551,19 +1090,15 @@ fn cleanup_unused_files(content_base: &Path, referenced_paths: &HashSet<String>,
 
         if let Ok(rel_path) = path.strip_prefix(content_base) {
             let normalized = normalize_path(&rel_path.to_string_lossy());
-
-            // Also remove files NOT in the new ASSETS/{creator}/characters/{project}/ tree
-            let in_new_tree = normalized.to_lowercase().starts_with(&format!(
-                "assets/{}/characters/",
-                prefix.to_lowercase()
-            ));
-
-            if !expected_paths.contains(&normalized) || !in_new_tree {
-                if let Err(e) = fs::remove_file(path) {
-                    tracing::warn!("Failed to remove {}: {}", path.display(), e);
-                } else {
-                    tracing::debug!("Removed unused file: {}", normalized);
-                    removed += 1;
+            if let Some(reason) = unused_file_reason(&normalized, &expected_paths, config) {
+                let rel_str = normalize_separators(&rel_path.to_string_lossy());
+                match trash.stage(content_base, &rel_str, reason) {
+                    Ok(false) => {}
+                    Ok(true) => {
+                        tracing::debug!("Trashed {} file: {}", reason, normalized);
+                        removed += 1;
+                    }
+                    Err(e) => tracing::warn!("Failed to remove {}: {}", path.display(), e),
                 }
             }
         }
@@ -572,28 +1107,76 @@ fn cleanup_unused_files(content_base: &Path, referenced_paths: &HashSet<String>,
     Ok(removed)
 }
 
-/// Remove all extracted BINs except:
+/// Why BIN cleanup removes the BIN at `rel_str` (normalized, relative to the
+/// file base), if it does
+///
+/// BINs kept:
 /// 1. Main skin BIN (skins/skin{ID}.bin)
-/// 2. Animation BIN (animations/skin{ID}.bin) 
+/// 2. Animation BIN the main skin BIN links to
 /// 3. Concat BIN (__Concat.bin)
-/// 
+///
+/// Many skins reuse the base skin's animations and link `animations/skin0.bin`
+/// instead of their own, so the animation BIN is chosen from
+/// `linked_animations` (paths relative to the file base). Only when the main
+/// BIN links no animation BIN does the skin number decide (animations/skin{ID}.bin).
+///
 /// This uses a whitelist approach - everything else is deleted.
-fn cleanup_irrelevant_bins(content_base: &Path, champion: &str, target_skin_id: u32) -> Result<usize> {
-    let mut removed = 0;
-    let champion_lower = champion.to_lowercase();
-    
-    // Patterns for BINs we want to KEEP
+fn irrelevant_bin_reason(
+    rel_str: &str,
+    champion: &str,
+    target_skin_id: u32,
+    linked_animations: Option<&HashSet<String>>,
+) -> Option<&'static str> {
+    let filename = rel_str.rsplit('/').next().unwrap_or(rel_str);
     let target_skin_name = format!("skin{}.bin", target_skin_id);
     let target_skin_name_padded = format!("skin{:02}.bin", target_skin_id);
 
-    tracing::info!(
-        "Cleaning up BINs (keeping only: {}, {}, and __Concat.bin)",
-        target_skin_name,
-        target_skin_name_padded
-    );
+    // === WHITELIST: BINs we KEEP ===
 
-    for entry in WalkDir::new(content_base)
+    // 1. Keep the concatenated BIN
+    if filename.contains("__concat") {
+        tracing::debug!("Keeping concat BIN: {}", rel_str);
+        return None;
+    }
+
+    // 2. Keep the main skin BIN in skins folder
+    if rel_str.contains("/skins/") &&
+       (filename == target_skin_name || filename == target_skin_name_padded) {
+        tracing::debug!("Keeping main skin BIN: {}", rel_str);
+        return None;
+    }
+
+    // 3. Keep the animation BIN the skin actually uses
+    if rel_str.contains("/animations/") {
+        let keep = match linked_animations.filter(|linked| !linked.is_empty()) {
+            Some(linked) => linked.contains(rel_str),
+            None => filename == target_skin_name || filename == target_skin_name_padded,
+        };
+        if keep {
+            tracing::debug!("Keeping animation BIN: {}", rel_str);
+            return None;
+        }
+    }
+
+    // === EVERYTHING ELSE IS DELETED ===
+    Some(if rel_str.contains("/animations/") {
+        "wrong animation"
+    } else if rel_str.contains("/skins/") {
+        "wrong skin"
+    } else if filename == format!("{}.bin", champion.to_lowercase()) {
+        "champion root"
+    } else if filename.contains("_skins_") || filename.contains("_skin") {
+        "linked data"
+    } else {
+        "unreferenced"
+    })
+}
+
+/// Lists the BINs under `content_base`, relative and normalized
+fn relative_bins(content_base: &Path) -> impl Iterator<Item = (PathBuf, String)> + '_ {
+    WalkDir::new(content_base)
         .into_iter()
+        .filter_entry(|e| e.file_name() != TRASH_DIR)
         .filter_map(|e| e.ok())
         .filter(|e| {
             e.path()
@@ -601,53 +1184,39 @@ fn cleanup_irrelevant_bins(content_base: &Path, champion: &str, target_skin_id:
                 .map(|ext| ext.eq_ignore_ascii_case("bin"))
                 .unwrap_or(false)
         })
-    {
-        let path = entry.path();
-        if let Ok(rel_path) = path.strip_prefix(content_base) {
-            let rel_str = rel_path.to_string_lossy().to_lowercase().replace('\\', "/");
-            let filename = path.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
-
-            // === WHITELIST: BINs we KEEP ===
-            
-            // 1. Keep the concatenated BIN
-            if filename.contains("__concat") {
-                tracing::debug!("Keeping concat BIN: {}", rel_str);
-                continue;
-            }
-
-            // 2. Keep the main skin BIN in skins folder
-            if rel_str.contains("/skins/") && 
-               (filename == target_skin_name || filename == target_skin_name_padded) {
-                tracing::debug!("Keeping main skin BIN: {}", rel_str);
-                continue;
-            }
+        .filter_map(move |entry| {
+            let rel_str = normalize_path(&entry.path().strip_prefix(content_base).ok()?.to_string_lossy());
+            Some((entry.into_path(), rel_str))
+        })
+}
 
-            // 3. Keep the animation BIN for the target skin
-            if rel_str.contains("/animations/") && 
-               (filename == target_skin_name || filename == target_skin_name_padded) {
-                tracing::debug!("Keeping animation BIN: {}", rel_str);
-                continue;
-            }
+/// Remove all extracted BINs except the ones [`irrelevant_bin_reason`] keeps
+fn cleanup_irrelevant_bins(
+    content_base: &Path,
+    champion: &str,
+    target_skin_id: u32,
+    linked_animations: Option<&HashSet<String>>,
+    trash: &TrashSession,
+) -> Result<usize> {
+    let mut removed = 0;
 
-            // === EVERYTHING ELSE IS DELETED ===
-            let reason = if rel_str.contains("/animations/") {
-                "wrong animation"
-            } else if rel_str.contains("/skins/") {
-                "wrong skin"
-            } else if filename == format!("{}.bin", champion_lower) {
-                "champion root"
-            } else if filename.contains("_skins_") || filename.contains("_skin") {
-                "linked data"
-            } else {
-                "unreferenced"
-            };
+    tracing::info!(
+        "Cleaning up BINs (keeping only: skin{}.bin, linked animations and __Concat.bin)",
+        target_skin_id
+    );
 
-            if let Err(e) = fs::remove_file(path) {
-                tracing::warn!("Failed to remove {} BIN {}: {}", reason, path.display(), e);
-            } else {
-                tracing::debug!("Removed {} BIN: {}", reason, rel_str);
+    for (path, rel_str) in relative_bins(content_base) {
+        let Some(reason) = irrelevant_bin_reason(&rel_str, champion, target_skin_id, linked_animations) else {
+            continue;
+        };
+        let stored = normalize_separators(&path.strip_prefix(content_base).unwrap_or(&path).to_string_lossy());
+        match trash.stage(content_base, &stored, reason) {
+            Ok(false) => {}
+            Ok(true) => {
+                tracing::debug!("Trashed {} BIN: {}", reason, rel_str);
                 removed += 1;
             }
+            Err(e) => tracing::warn!("Failed to remove {} BIN {}: {}", reason, path.display(), e),
         }
     }
     
@@ -724,6 +1293,7 @@ mod tests {
     fn test_is_asset_path() {
         assert!(is_asset_path("assets/characters/ahri/skin0.bin"));
         assert!(is_asset_path("data/effects.bin"));
+        assert!(is_asset_path("UX/Fonts/beaufort.ttf"));
         assert!(!is_asset_path("some/other/path.txt"));
     }
 
@@ -754,6 +1324,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_prefix_template() {
+        let mut config = RepathConfig {
+            creator_name: "Sir Dexal".to_string(),
+            project_name: "Star Ahri".to_string(),
+            champion: "Ahri".to_string(),
+            target_skin_id: 3,
+            cleanup_unused: true,
+            include_conventional_assets: true,
+            version: "1.2.0".to_string(),
+            options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
+        };
+        assert_eq!(config.prefix(), "Sir-Dexal/Star-Ahri");
+
+        config.options.prefix_template = Some("{creator}/{champion}_skin{skin}/{version}".to_string());
+        assert!(config.options.validate().is_ok());
+        assert_eq!(config.prefix(), "Sir-Dexal/ahri_skin3/1.2.0");
+
+        // Map projects have no champion, so the segment disappears
+        config.champion.clear();
+        config.options.prefix_template = Some("{creator}/{champion}/{project}".to_string());
+        assert_eq!(config.prefix(), "Sir-Dexal/Star-Ahri");
+
+        for bad in ["", "{creator}/../x", "{creator}\\x", "{creator}/{skinid}", "{creator}/{project"] {
+            let options = RepathOptions {
+                prefix_template: Some(bad.to_string()),
+                ..Default::default()
+            };
+            assert!(options.validate().is_err(), "{}", bad);
+        }
+
+        let options = RepathOptions {
+            skip_extensions: vec!["wpk".to_string(), ".BNK".to_string()],
+            ..Default::default()
+        };
+        assert!(options.skips("assets/sounds/ahri_vo.wpk"));
+        assert!(options.skips("assets/sounds/ahri_sfx.bnk"));
+        assert!(!options.skips("assets/ahri/a.dds"));
+        assert!(!options.skips("assets/ahri/bare"));
+    }
+
     #[test]
     fn test_replace_champion_with_project() {
         let config = RepathConfig {
@@ -762,6 +1374,10 @@ mod tests {
             champion: "Renekton".to_string(),
             target_skin_id: 42,
             cleanup_unused: true,
+            include_conventional_assets: true,
+            version: "1.0.0".to_string(),
+            options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
         };
 
         // Test champion replacement
@@ -791,6 +1407,10 @@ mod tests {
             champion: "Renekton".to_string(),
             target_skin_id: 42,
             cleanup_unused: true,
+            include_conventional_assets: true,
+            version: "1.0.0".to_string(),
+            options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
         };
 
         // Test new structure: ASSETS/{creator}/characters/{project}/...
@@ -814,5 +1434,109 @@ mod tests {
             ),
             "ASSETS/SirDexal/Renny/characters/Renny/skins/skin42.bin"
         );
+
+        // UI paths keep their ux/ folder
+        assert_eq!(
+            apply_prefix_to_path("ux/hud/hud_atlas.tex", "SirDexal/Renny", &config),
+            "ASSETS/SirDexal/Renny/ux/hud/hud_atlas.tex"
+        );
+    }
+
+    #[test]
+    fn test_cleanup_keeps_linked_animation_bin() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        let bins = [
+            "data/characters/ahri/skins/skin3.bin",
+            "data/characters/ahri/skins/skin0.bin",
+            "data/characters/ahri/animations/skin0.bin",
+            "data/characters/ahri/animations/skin3.bin",
+        ];
+        let create = || {
+            for bin in bins {
+                fs::create_dir_all(base.join(bin).parent().unwrap()).unwrap();
+                fs::write(base.join(bin), b"PROP").unwrap();
+            }
+        };
+
+        // Skin 3 plays the base skin's animations
+        create();
+        let linked = HashSet::from(["data/characters/ahri/animations/skin0.bin".to_string()]);
+        let trash = TrashSession::for_base(base);
+        assert_eq!(cleanup_irrelevant_bins(base, "Ahri", 3, Some(&linked), &trash).unwrap(), 2);
+        assert!(base.join("data/characters/ahri/skins/skin3.bin").exists());
+        assert!(base.join("data/characters/ahri/animations/skin0.bin").exists());
+        assert!(!base.join("data/characters/ahri/animations/skin3.bin").exists());
+        let staged = &crate::core::trash::list_sessions(base).unwrap()[0].files;
+        assert!(staged.iter().any(|f| f.original == "data/characters/ahri/animations/skin3.bin"));
+
+        // Without a linked animation BIN the skin number decides
+        create();
+        let trash = TrashSession::for_base(base);
+        assert_eq!(cleanup_irrelevant_bins(base, "Ahri", 3, Some(&HashSet::new()), &trash).unwrap(), 2);
+        assert!(base.join("data/characters/ahri/animations/skin3.bin").exists());
+        assert!(!base.join("data/characters/ahri/animations/skin0.bin").exists());
+    }
+
+    #[test]
+    fn test_unused_file_reason() {
+        let config = RepathConfig {
+            creator_name: "SirDexal".to_string(),
+            project_name: "Renny".to_string(),
+            champion: "Renekton".to_string(),
+            target_skin_id: 42,
+            cleanup_unused: true,
+            include_conventional_assets: false,
+            version: "1.0.0".to_string(),
+            options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
+        };
+        let expected: HashSet<String> = ["assets/characters/renekton/a.dds", "assets/shared/particles/glow.dds", "ux/hud/icon.tex"]
+            .into_iter()
+            .map(|path| normalize_path(&apply_prefix_to_path(path, "SirDexal/Renny", &config)))
+            .collect();
+
+        // Shared particles and UI files moved outside the champion folder stay
+        for kept in &expected {
+            assert_eq!(unused_file_reason(kept, &expected, &config), None, "{}", kept);
+        }
+        assert_eq!(unused_file_reason("assets/shared/particles/other.dds", &expected, &config), Some("unreferenced"));
+    }
+
+    #[test]
+    fn test_find_extracted_follows_ltk_suffix() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        fs::create_dir_all(base.join("assets/ahri")).unwrap();
+        fs::write(base.join("assets/ahri/a.dds"), b"").unwrap();
+        fs::write(base.join("assets/ahri/bare.ltk.dds"), b"").unwrap();
+        fs::write(base.join("assets/ahri/mapped.ltk"), b"").unwrap();
+
+        let mappings = HashMap::from([("assets/ahri/mapped".to_string(), "assets/ahri/mapped.ltk".to_string())]);
+        assert_eq!(find_extracted(base, "assets/ahri/a.dds", &mappings).as_deref(), Some("assets/ahri/a.dds"));
+        assert_eq!(find_extracted(base, "assets/ahri/mapped", &mappings).as_deref(), Some("assets/ahri/mapped.ltk"));
+        assert_eq!(find_extracted(base, "assets/ahri/bare", &mappings).as_deref(), Some("assets/ahri/bare.ltk.dds"));
+        assert_eq!(find_extracted(base, "assets/ahri/b", &mappings), None);
+        assert_eq!(find_extracted(base, "assets/ahri/bare.dds", &mappings), None);
+
+        // Relocation gives the file the name the BIN references
+        let config = RepathConfig {
+            creator_name: "me".to_string(),
+            project_name: "proj".to_string(),
+            champion: "Ahri".to_string(),
+            target_skin_id: 0,
+            cleanup_unused: false,
+            include_conventional_assets: false,
+            version: "1.0.0".to_string(),
+            options: RepathOptions::default(),
+            target_kind: TargetKind::Champion,
+        };
+        let path = "assets/ahri/bare".to_string();
+        let suffixed = HashMap::from([(path.clone(), "assets/ahri/bare.ltk.dds".to_string())]);
+        let moved = relocate_assets(base, &HashSet::from([path.clone()]), &suffixed, &config.prefix(), &config, None).unwrap();
+        assert_eq!(moved.len(), 1);
+        assert_eq!(moved[0].from, "assets/ahri/bare.ltk.dds");
+        assert!(base.join(apply_prefix_to_path(&path, &config.prefix(), &config)).is_file());
+        assert!(!base.join("assets/ahri/bare.ltk.dds").exists());
     }
 }
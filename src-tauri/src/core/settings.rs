@@ -0,0 +1,52 @@
+//! JSON settings files
+//!
+//! Store, deploy, onboarding and BIN settings (and the hash update schedule)
+//! each live in their own small JSON file. A missing or unreadable file means
+//! defaults, so a corrupt settings file never stops the app from starting.
+
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Loads `dir/file_name`, falling back to defaults if it's missing or invalid
+pub fn load_json_setting<T: DeserializeOwned + Default>(dir: &Path, file_name: &str) -> T {
+    fs::read_to_string(dir.join(file_name))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Writes `value` as pretty JSON to `dir/file_name`, creating `dir` if needed
+pub fn save_json_setting<T: Serialize>(dir: &Path, file_name: &str, value: &T) -> Result<()> {
+    fs::create_dir_all(dir).map_err(|e| Error::io_with_path(e, dir))?;
+    let path = dir.join(file_name);
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| Error::InvalidInput(format!("Failed to serialize {}: {}", file_name, e)))?;
+    fs::write(&path, content).map_err(|e| Error::io_with_path(e, &path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Example {
+        enabled: bool,
+    }
+
+    #[test]
+    fn test_json_setting_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("app");
+        assert_eq!(load_json_setting::<Example>(&nested, "example.json"), Example::default());
+
+        save_json_setting(&nested, "example.json", &Example { enabled: true }).unwrap();
+        assert_eq!(load_json_setting::<Example>(&nested, "example.json"), Example { enabled: true });
+
+        fs::write(nested.join("example.json"), "{ not json").unwrap();
+        assert_eq!(load_json_setting::<Example>(&nested, "example.json"), Example::default());
+    }
+}
@@ -0,0 +1,195 @@
+//! Content-addressed store for extracted assets
+//!
+//! Every project for the same champion starts from the same extracted WAD, so
+//! users with many projects keep many identical copies of the same textures
+//! and meshes. With the store enabled, extraction writes each distinct file
+//! once under `objects/` and hardlinks it into the project.
+//!
+//! A hardlink shares its data with every other link, so a project file must
+//! never be modified in place: anything that rewrites an existing project file
+//! goes through [`write_replacing`], which swaps in a new file and leaves the
+//! shared one untouched (copy-on-write at the file level).
+
+use crate::core::settings::{load_json_setting, save_json_setting};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+use xxhash_rust::xxh3::xxh3_128;
+
+/// Folder of the store inside the app data directory
+pub const STORE_DIR_NAME: &str = "asset_store";
+
+/// Settings file inside the app data directory
+const SETTINGS_FILE: &str = "asset_store.json";
+
+/// User setting for the asset store
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AssetStoreSettings {
+    /// Place extracted files into projects through the shared store
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Size of the store on disk
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AssetStoreStats {
+    pub enabled: bool,
+    pub path: String,
+    pub object_count: usize,
+    /// Bytes held by the store; each object is counted once however many
+    /// projects link to it
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+}
+
+/// How a file ended up in a project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// Hardlinked to the store object
+    Linked,
+    /// Written as a standalone file (the filesystem can't link to the store)
+    Copied,
+}
+
+/// Loads the store settings, falling back to defaults (disabled)
+pub fn load_settings(app_data_dir: &Path) -> AssetStoreSettings {
+    load_json_setting(app_data_dir, SETTINGS_FILE)
+}
+
+/// Writes the store settings to the app data directory
+pub fn save_settings(app_data_dir: &Path, settings: &AssetStoreSettings) -> Result<()> {
+    save_json_setting(app_data_dir, SETTINGS_FILE, settings)
+}
+
+/// Replaces a file's contents by writing a sibling file and renaming it over
+///
+/// Unlike `fs::write` this never writes into the existing file, so when the
+/// file is a hardlink into the asset store the store object and every other
+/// project linking it keep their data.
+pub fn write_replacing(path: &Path, data: &[u8]) -> io::Result<()> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".flint-tmp");
+    let tmp = path.with_file_name(tmp_name);
+
+    fs::write(&tmp, data)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+/// Shared object store under the app data directory
+#[derive(Debug, Clone)]
+pub struct AssetStore {
+    root: PathBuf,
+}
+
+impl AssetStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the store if the user enabled it
+    pub fn from_settings(app_data_dir: &Path) -> Option<Self> {
+        load_settings(app_data_dir)
+            .enabled
+            .then(|| Self::new(app_data_dir.join(STORE_DIR_NAME)))
+    }
+
+    fn object_path(&self, hash: u128) -> PathBuf {
+        let name = format!("{:032x}", hash);
+        self.root.join("objects").join(&name[..2]).join(name)
+    }
+
+    /// Stores `data` once and places it at `dest`
+    ///
+    /// An existing file at `dest` is replaced. If linking fails (e.g. the
+    /// project is on another drive than the app data directory) the data is
+    /// written as a plain file instead.
+    pub fn place(&self, data: &[u8], dest: &Path) -> Result<Placement> {
+        let object = self.object_path(xxh3_128(data));
+
+        // An object whose content drifted (something wrote into a linked file
+        // in place) is replaced; projects linking the old one keep their copy
+        let intact = fs::read(&object).is_ok_and(|existing| existing == data);
+        if !intact {
+            if let Some(parent) = object.parent() {
+                fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+            }
+            write_replacing(&object, data).map_err(|e| Error::io_with_path(e, &object))?;
+        }
+
+        match fs::remove_file(dest) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(Error::io_with_path(e, dest)),
+        }
+
+        match fs::hard_link(&object, dest) {
+            Ok(()) => Ok(Placement::Linked),
+            Err(e) => {
+                tracing::debug!("Hardlink to store failed for {}: {}", dest.display(), e);
+                fs::write(dest, data).map_err(|e| Error::io_with_path(e, dest))?;
+                Ok(Placement::Copied)
+            }
+        }
+    }
+
+    /// Counts the objects held by the store
+    pub fn stats(&self, enabled: bool) -> AssetStoreStats {
+        let (object_count, total_bytes) = WalkDir::new(self.root.join("objects"))
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| e.metadata().ok())
+            .fold((0, 0), |(count, bytes), meta| (count + 1, bytes + meta.len()));
+
+        AssetStoreStats {
+            enabled,
+            path: self.root.to_string_lossy().to_string(),
+            object_count,
+            total_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_place_shares_identical_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = AssetStore::new(dir.path().join("store"));
+        let a = dir.path().join("a.dds");
+        let b = dir.path().join("b.dds");
+
+        assert_eq!(store.place(b"DDS texture", &a).unwrap(), Placement::Linked);
+        assert_eq!(store.place(b"DDS texture", &b).unwrap(), Placement::Linked);
+        store.place(b"other", &dir.path().join("c.dds")).unwrap();
+
+        let stats = store.stats(true);
+        assert_eq!(stats.object_count, 2);
+        assert_eq!(stats.total_bytes, ("DDS texture".len() + "other".len()) as u64);
+
+        // Editing one project's copy must not leak into the other
+        write_replacing(&a, b"edited").unwrap();
+        assert_eq!(fs::read(&a).unwrap(), b"edited");
+        assert_eq!(fs::read(&b).unwrap(), b"DDS texture");
+    }
+
+    #[test]
+    fn test_settings_default_to_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(AssetStore::from_settings(dir.path()).is_none());
+
+        save_settings(dir.path(), &AssetStoreSettings { enabled: true }).unwrap();
+        let store = AssetStore::from_settings(dir.path()).unwrap();
+        assert_eq!(store.root, dir.path().join(STORE_DIR_NAME));
+    }
+}
@@ -0,0 +1,264 @@
+//! RiotStringTable (`.stringtable`) reading and writing
+//!
+//! String tables hold the game's localized text (skin names, tooltips,
+//! item descriptions). The layout is `RST`, a version byte, an optional
+//! font config (version 2 only), the entry count, one packed u64 per entry
+//! and a block of NUL-terminated UTF-8 strings. Each packed entry keeps the
+//! key hash in its low bits and the string's offset in the high bits; how
+//! many bits the hash gets depends on the version.
+//!
+//! Keys are stored only as hashes: xxh64 of the lowercased key, or xxh3
+//! from version 5 on, truncated to the hash bits.
+
+use crate::core::store::write_replacing;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+use xxhash_rust::{xxh3::xxh3_64, xxh64::xxh64};
+
+const MAGIC: &[u8; 3] = b"RST";
+const MIN_VERSION: u8 = 2;
+const MAX_VERSION: u8 = 5;
+
+/// One string of a table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct StringTableEntry {
+    /// Key hash, truncated to the table's hash bits
+    #[ts(type = "number")]
+    pub hash: u64,
+    /// Key name; when set on write, the entry is stored under its hash instead of `hash`
+    #[serde(default)]
+    pub key: Option<String>,
+    pub text: String,
+}
+
+/// A parsed string table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct StringTable {
+    pub version: u8,
+    /// Font config string of version 2 tables
+    pub font_config: Option<String>,
+    /// Flag byte of versions before 5, kept as read
+    pub mode: u8,
+    pub entries: Vec<StringTableEntry>,
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::InvalidInput(format!("Invalid string table: {}", message.into()))
+}
+
+/// Bits of a packed entry that hold the key hash
+fn hash_bits(version: u8) -> u32 {
+    match version {
+        ..=3 => 40,
+        4 => 39,
+        _ => 38,
+    }
+}
+
+/// Hash a key is stored under in a table of the given version
+pub fn key_hash(key: &str, version: u8) -> u64 {
+    let key = key.to_lowercase();
+    let hash = if version >= 5 { xxh3_64(key.as_bytes()) } else { xxh64(key.as_bytes(), 0) };
+    hash & ((1u64 << hash_bits(version)) - 1)
+}
+
+fn take<'a>(data: &'a [u8], position: &mut usize, len: usize) -> Result<&'a [u8]> {
+    let bytes = data
+        .get(*position..*position + len)
+        .ok_or_else(|| invalid("unexpected end of file"))?;
+    *position += len;
+    Ok(bytes)
+}
+
+fn take_u32(data: &[u8], position: &mut usize) -> Result<u32> {
+    Ok(u32::from_le_bytes(take(data, position, 4)?.try_into().unwrap_or_default()))
+}
+
+/// Parses a string table from its bytes
+pub fn parse_stringtable(data: &[u8]) -> Result<StringTable> {
+    if !data.starts_with(MAGIC) || data.len() < 4 {
+        return Err(invalid("missing RST magic"));
+    }
+    let version = data[3];
+    if !(MIN_VERSION..=MAX_VERSION).contains(&version) {
+        return Err(invalid(format!("unsupported version {}", version)));
+    }
+    let mut position = 4;
+
+    let mut font_config = None;
+    if version == 2 && take(data, &mut position, 1)?[0] != 0 {
+        let len = take_u32(data, &mut position)? as usize;
+        font_config = Some(String::from_utf8_lossy(take(data, &mut position, len)?).to_string());
+    }
+
+    let count = take_u32(data, &mut position)? as usize;
+    if count.saturating_mul(8) > data.len() - position {
+        return Err(invalid(format!("{} entries don't fit in the file", count)));
+    }
+    let packed: Vec<u64> = (0..count)
+        .map(|i| u64::from_le_bytes(data[position + i * 8..position + i * 8 + 8].try_into().unwrap_or_default()))
+        .collect();
+    position += count * 8;
+    let mode = if version < 5 { take(data, &mut position, 1)?[0] } else { 0 };
+
+    let strings = &data[position..];
+    let bits = hash_bits(version);
+    let entries = packed
+        .into_iter()
+        .map(|value| {
+            let offset = (value >> bits) as usize;
+            let text = strings
+                .get(offset..)
+                .ok_or_else(|| invalid(format!("string offset {} is out of bounds", offset)))?;
+            let end = text.iter().position(|&b| b == 0).unwrap_or(text.len());
+            Ok(StringTableEntry {
+                hash: value & ((1u64 << bits) - 1),
+                key: None,
+                text: String::from_utf8_lossy(&text[..end]).to_string(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(StringTable { version, font_config, mode, entries })
+}
+
+/// Serializes a string table
+///
+/// Identical strings are stored once. Fails if two entries end up under the
+/// same hash or a hash doesn't fit the version's hash bits.
+pub fn serialize_stringtable(table: &StringTable) -> Result<Vec<u8>> {
+    let version = table.version;
+    if !(MIN_VERSION..=MAX_VERSION).contains(&version) {
+        return Err(invalid(format!("unsupported version {}", version)));
+    }
+    let bits = hash_bits(version);
+
+    let mut strings = Vec::new();
+    let mut offsets: HashMap<&str, u64> = HashMap::new();
+    let mut seen = HashSet::new();
+    let mut packed = Vec::with_capacity(table.entries.len());
+    for entry in &table.entries {
+        let hash = entry.key.as_deref().map_or(entry.hash, |key| key_hash(key, version));
+        if hash >> bits != 0 {
+            return Err(invalid(format!("hash {:x} is wider than {} bits", hash, bits)));
+        }
+        if !seen.insert(hash) {
+            return Err(invalid(format!("duplicate entry for hash {:x}", hash)));
+        }
+        let offset = *offsets.entry(entry.text.as_str()).or_insert_with(|| {
+            let offset = strings.len() as u64;
+            strings.extend_from_slice(entry.text.as_bytes());
+            strings.push(0);
+            offset
+        });
+        if offset >> (64 - bits) != 0 {
+            return Err(invalid("string data is too large"));
+        }
+        packed.push(offset << bits | hash);
+    }
+
+    let mut out = Vec::with_capacity(16 + packed.len() * 8 + strings.len());
+    out.extend_from_slice(MAGIC);
+    out.push(version);
+    if version == 2 {
+        match &table.font_config {
+            Some(config) => {
+                out.push(1);
+                out.extend_from_slice(&(config.len() as u32).to_le_bytes());
+                out.extend_from_slice(config.as_bytes());
+            }
+            None => out.push(0),
+        }
+    }
+    out.extend_from_slice(&(packed.len() as u32).to_le_bytes());
+    for value in packed {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+    if version < 5 {
+        out.push(table.mode);
+    }
+    out.extend_from_slice(&strings);
+    Ok(out)
+}
+
+/// Reads a `.stringtable` file
+pub fn read_stringtable(path: &Path) -> Result<StringTable> {
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    parse_stringtable(&data)
+}
+
+/// Writes a `.stringtable` file, replacing it atomically
+pub fn write_stringtable(path: &Path, table: &StringTable) -> Result<()> {
+    let data = serialize_stringtable(table)?;
+    write_replacing(path, &data).map_err(|e| Error::io_with_path(e, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, text: &str) -> StringTableEntry {
+        StringTableEntry { hash: 0, key: Some(key.to_string()), text: text.to_string() }
+    }
+
+    #[test]
+    fn test_stringtable_round_trip() {
+        for version in MIN_VERSION..=MAX_VERSION {
+            let table = StringTable {
+                version,
+                font_config: (version == 2).then(|| "fonts.cfg".to_string()),
+                mode: 0,
+                entries: vec![
+                    entry("game_character_skin_displayname_Ahri_1", "Dynasty Ahri"),
+                    entry("game_character_skin_displayname_Ahri_2", "Midnight Ahri"),
+                    entry("tooltip_shared", "Dynasty Ahri"),
+                    entry("empty", ""),
+                ],
+            };
+            let data = serialize_stringtable(&table).unwrap();
+            let parsed = parse_stringtable(&data).unwrap();
+
+            assert_eq!(parsed.version, version);
+            assert_eq!(parsed.font_config, table.font_config);
+            let expected: Vec<_> = table
+                .entries
+                .iter()
+                .map(|e| (key_hash(e.key.as_deref().unwrap(), version), e.text.as_str()))
+                .collect();
+            let got: Vec<_> = parsed.entries.iter().map(|e| (e.hash, e.text.as_str())).collect();
+            assert_eq!(got, expected, "version {}", version);
+
+            // Rewriting by hash gives the same bytes, with shared text stored once
+            assert_eq!(serialize_stringtable(&parsed).unwrap(), data);
+            assert_eq!(data.windows(12).filter(|w| w == b"Dynasty Ahri").count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_key_hash() {
+        assert_eq!(key_hash("Key", 3), key_hash("key", 3));
+        assert_eq!(key_hash("key", 3), xxh64(b"key", 0) & 0xFF_FFFF_FFFF);
+        assert!(key_hash("key", 5) < 1 << 38);
+    }
+
+    #[test]
+    fn test_invalid_stringtables() {
+        assert!(parse_stringtable(b"RST").is_err());
+        assert!(parse_stringtable(b"RST\x09\0\0\0\0").is_err());
+        assert!(parse_stringtable(b"RST\x03\xff\0\0\0").is_err());
+
+        let duplicate = StringTable {
+            version: 3,
+            font_config: None,
+            mode: 0,
+            entries: vec![entry("a", "x"), entry("A", "y")],
+        };
+        assert!(serialize_stringtable(&duplicate).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+//! Conversion to League `.tex`
+//!
+//! Some asset slots only accept TEX, so textures authored as PNG or DDS are
+//! converted here. Only the formats the encoder supports are offered: BC1 for
+//! opaque textures, BC3 when alpha matters and uncompressed BGRA8.
+
+use crate::core::store::write_replacing;
+use crate::core::texture::tex::{self, TexHeader};
+use crate::core::texture::{decode_top_mip, read_texture};
+use crate::error::{Error, Result};
+use image::RgbaImage;
+use ltk_texture::tex::Format;
+use ltk_texture::Texture;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+
+/// TEX formats Flint can write
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum TexFormat {
+    Bc1,
+    Bc3,
+    Bgra8,
+}
+
+impl TexFormat {
+    fn format(self) -> Format {
+        match self {
+            Self::Bc1 => Format::Bc1,
+            Self::Bc3 => Format::Bc3,
+            Self::Bgra8 => Format::Bgra8,
+        }
+    }
+
+    /// BC3 if any pixel is translucent, BC1 otherwise
+    pub fn for_image(image: &RgbaImage) -> Self {
+        if image.pixels().any(|p| p.0[3] < 255) {
+            Self::Bc3
+        } else {
+            Self::Bc1
+        }
+    }
+}
+
+/// Encodes an RGBA image as a `.tex` file
+///
+/// # Arguments
+/// * `image` - Top mip level
+/// * `format` - Pixel format of every mip
+/// * `mipmaps` - Generate the full mip chain down to 1x1
+pub fn encode_tex(image: &RgbaImage, format: TexFormat, mipmaps: bool) -> Result<Vec<u8>> {
+    let (width, height) = image.dimensions();
+    if width > u16::MAX as u32 || height > u16::MAX as u32 {
+        return Err(Error::InvalidInput(format!("Image is too large for TEX: {}x{}", width, height)));
+    }
+    if format != TexFormat::Bgra8 && (width % 4 != 0 || height % 4 != 0) {
+        return Err(Error::InvalidInput(format!(
+            "Image is {}x{}, but {:?} needs dimensions that are multiples of 4",
+            width, height, format
+        )));
+    }
+
+    tex::encode_with_header(&TexHeader::new(0, 0, format.format(), mipmaps), image)
+}
+
+/// TEX format closest to an existing texture's encoding
+fn matching_format(texture: &Texture, data: &[u8]) -> TexFormat {
+    match texture {
+        Texture::Tex(tex) => match tex.format {
+            Format::Bc1 => TexFormat::Bc1,
+            Format::Bgra8 => TexFormat::Bgra8,
+            _ => TexFormat::Bc3,
+        },
+        Texture::Dds(_) => match ddsfile::Dds::read(&mut std::io::Cursor::new(data)) {
+            Ok(dds) => match dds.header.spf.fourcc {
+                Some(fourcc) if fourcc.0 == u32::from_le_bytes(*b"DXT1") => TexFormat::Bc1,
+                Some(_) => TexFormat::Bc3,
+                None => TexFormat::Bgra8,
+            },
+            Err(_) => TexFormat::Bc3,
+        },
+    }
+}
+
+/// Result of a conversion to TEX
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TexConversion {
+    pub output_path: String,
+    pub format: TexFormat,
+    pub width: u32,
+    pub height: u32,
+    pub mip_count: u32,
+}
+
+fn write_tex(
+    image: &RgbaImage,
+    format: TexFormat,
+    mipmaps: bool,
+    output: &Path,
+) -> Result<TexConversion> {
+    let encoded = encode_tex(image, format, mipmaps)?;
+    let mip_count = read_texture(&encoded)?.mip_count();
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+    write_replacing(output, &encoded).map_err(|e| Error::io_with_path(e, output))?;
+
+    Ok(TexConversion {
+        output_path: output.display().to_string(),
+        format,
+        width: image.width(),
+        height: image.height(),
+        mip_count,
+    })
+}
+
+/// Converts a DDS file to TEX
+///
+/// Without an explicit `format` the DDS compression is kept: DXT1 becomes
+/// BC1, other block formats BC3 and uncompressed DDS BGRA8.
+pub fn convert_dds_to_tex(
+    input: &Path,
+    output: &Path,
+    format: Option<TexFormat>,
+    mipmaps: bool,
+) -> Result<TexConversion> {
+    let data = fs::read(input).map_err(|e| Error::io_with_path(e, input))?;
+    if !data.starts_with(b"DDS ") {
+        return Err(Error::InvalidInput(format!("Not a DDS file: {}", input.display())));
+    }
+    let texture = read_texture(&data)?;
+    let image = decode_top_mip(&texture)?;
+    let format = format.unwrap_or_else(|| matching_format(&texture, &data));
+    write_tex(&image, format, mipmaps, output)
+}
+
+/// Converts a PNG (or any image `image` can open) to TEX
+///
+/// Without an explicit `format`, BC3 is used if the image has transparency
+/// and BC1 otherwise.
+pub fn convert_image_to_tex(
+    input: &Path,
+    output: &Path,
+    format: Option<TexFormat>,
+    mipmaps: bool,
+) -> Result<TexConversion> {
+    let image = image::open(input)
+        .map_err(|e| Error::InvalidInput(format!("Failed to open image '{}': {}", input.display(), e)))?
+        .to_rgba8();
+    let format = format.unwrap_or_else(|| TexFormat::for_image(&image));
+    write_tex(&image, format, mipmaps, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_convert_image_to_tex() {
+        let dir = tempfile::tempdir().unwrap();
+        let png = dir.path().join("icon.png");
+        let mut image = RgbaImage::from_pixel(16, 8, Rgba([0, 255, 0, 255]));
+        image.save(&png).unwrap();
+
+        let output = dir.path().join("out/icon.tex");
+        let result = convert_image_to_tex(&png, &output, None, true).unwrap();
+        assert_eq!(result.format, TexFormat::Bc1);
+        assert_eq!(result.mip_count, 5);
+
+        let data = fs::read(&output).unwrap();
+        let Texture::Tex(tex) = read_texture(&data).unwrap() else { panic!("expected TEX") };
+        assert_eq!((tex.width, tex.height), (16, 8));
+        assert_eq!(tex.format, Format::Bc1);
+        assert!(tex.has_mipmaps());
+
+        // Transparency picks BC3; mipmaps can be left out
+        image.put_pixel(0, 0, Rgba([0, 0, 0, 0]));
+        image.save(&png).unwrap();
+        let result = convert_image_to_tex(&png, &output, None, false).unwrap();
+        assert_eq!(result.format, TexFormat::Bc3);
+        assert_eq!(result.mip_count, 1);
+
+        // Uncompressed round-trips exactly
+        let result = convert_image_to_tex(&png, &output, Some(TexFormat::Bgra8), false).unwrap();
+        assert_eq!(result.format, TexFormat::Bgra8);
+        let data = fs::read(&output).unwrap();
+        let decoded = decode_top_mip(&read_texture(&data).unwrap()).unwrap();
+        assert_eq!(decoded, image);
+    }
+}
@@ -0,0 +1,163 @@
+//! Texture channel inspection
+//!
+//! Masks, glow maps and normal maps pack unrelated data into each channel,
+//! so a plain RGBA preview hides most of what's in them. A [`TextureView`]
+//! turns a decoded mip into something that shows one aspect at a time.
+
+use crate::error::{Error, Result};
+use image::RgbaImage;
+use ltk_texture::Texture;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Edge length of the checkerboard squares behind transparent pixels
+const CHECKER_SIZE: u32 = 8;
+
+/// How to show a decoded texture
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum TextureView {
+    /// The pixels as stored
+    #[default]
+    Rgba,
+    /// Color only, fully opaque
+    Rgb,
+    /// One channel as grayscale
+    Red,
+    Green,
+    Blue,
+    Alpha,
+    /// Tangent-space normal map: X and Y from red and green, Z rebuilt
+    Normal,
+    /// Color over a checkerboard, blended by alpha
+    Checkerboard,
+}
+
+/// Mip levels a texture holds (at least the top level)
+pub fn mip_count(texture: &Texture) -> u32 {
+    texture.mip_count().max(1)
+}
+
+/// Decodes one mip level; 0 is full resolution
+pub fn decode_mip(texture: &Texture, level: u32) -> Result<RgbaImage> {
+    let count = mip_count(texture);
+    if level >= count {
+        return Err(Error::InvalidInput(format!(
+            "Mip level {} is out of range; the texture has {} level(s)",
+            level, count
+        )));
+    }
+
+    let surface = texture
+        .decode_mipmap(level)
+        .map_err(|e| Error::InvalidInput(format!("Failed to decode mipmap: {:?}", e)))?;
+    surface
+        .into_rgba_image()
+        .map_err(|e| Error::InvalidInput(format!("Failed to get RGBA image: {:?}", e)))
+}
+
+/// Rewrites an image in place to show it the way `view` asks
+pub fn apply_view(image: &mut RgbaImage, view: TextureView) {
+    let gray = |value: u8| [value, value, value, 255];
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let [r, g, b, a] = pixel.0;
+        pixel.0 = match view {
+            TextureView::Rgba => continue,
+            TextureView::Rgb => [r, g, b, 255],
+            TextureView::Red => gray(r),
+            TextureView::Green => gray(g),
+            TextureView::Blue => gray(b),
+            TextureView::Alpha => gray(a),
+            TextureView::Normal => {
+                let nx = r as f32 / 127.5 - 1.0;
+                let ny = g as f32 / 127.5 - 1.0;
+                let nz = (1.0 - nx * nx - ny * ny).max(0.0).sqrt();
+                [r, g, ((nz * 0.5 + 0.5) * 255.0).round() as u8, 255]
+            }
+            TextureView::Checkerboard => {
+                let light = (x / CHECKER_SIZE + y / CHECKER_SIZE).is_multiple_of(2);
+                let back = if light { 255.0 } else { 204.0 };
+                let alpha = a as f32 / 255.0;
+                let blend = |c: u8| (c as f32 * alpha + back * (1.0 - alpha)).round() as u8;
+                [blend(r), blend(g), blend(b), 255]
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_texture_views() {
+        let source = RgbaImage::from_pixel(16, 16, Rgba([255, 128, 10, 0]));
+        let view = |view| {
+            let mut image = source.clone();
+            apply_view(&mut image, view);
+            image
+        };
+
+        assert_eq!(view(TextureView::Rgba), source);
+        assert_eq!(view(TextureView::Rgb).get_pixel(0, 0).0, [255, 128, 10, 255]);
+        assert_eq!(view(TextureView::Green).get_pixel(0, 0).0, [128, 128, 128, 255]);
+        assert_eq!(view(TextureView::Alpha).get_pixel(0, 0).0, [0, 0, 0, 255]);
+
+        // A flat normal points straight out
+        let mut flat = RgbaImage::from_pixel(1, 1, Rgba([128, 128, 0, 0]));
+        apply_view(&mut flat, TextureView::Normal);
+        assert_eq!(flat.get_pixel(0, 0).0, [128, 128, 255, 255]);
+
+        // Fully transparent pixels show only the checkerboard
+        let checker = view(TextureView::Checkerboard);
+        assert_eq!(checker.get_pixel(0, 0).0, [255, 255, 255, 255]);
+        assert_eq!(checker.get_pixel(CHECKER_SIZE, 0).0, [204, 204, 204, 255]);
+    }
+
+    #[test]
+    fn test_decode_block_compressed_dds() {
+        use crate::core::texture::{read_texture, texture_size};
+        use image_dds::ImageFormat;
+
+        let source = RgbaImage::from_pixel(16, 8, Rgba([200, 100, 50, 128]));
+        for (format, expected) in [
+            (ImageFormat::BC4RUnorm, [200, 200, 200, 255]),
+            (ImageFormat::BC5RgUnorm, [200, 100, 0, 255]),
+            (ImageFormat::BC7RgbaUnorm, [200, 100, 50, 128]),
+        ] {
+            let dds = image_dds::dds_from_image(&source, format, image_dds::Quality::Fast, image_dds::Mipmaps::Disabled)
+                .unwrap();
+            let mut data = Vec::new();
+            dds.write(&mut data).unwrap();
+
+            let texture = read_texture(&data).unwrap();
+            assert_eq!(texture_size(&texture, &data), (16, 8), "{:?}", format);
+            let image = decode_mip(&texture, 0).unwrap();
+            assert_eq!(image.dimensions(), (16, 8), "{:?}", format);
+            let pixel = image.get_pixel(3, 3).0;
+            assert!(
+                pixel.iter().zip(expected).all(|(&got, want)| got.abs_diff(want) <= 4),
+                "{:?} decoded to {:?}",
+                format,
+                pixel
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_mip() {
+        let tex = ltk_texture::Tex::encode_rgba_image(
+            &RgbaImage::from_pixel(16, 8, Rgba([0, 0, 255, 255])),
+            ltk_texture::tex::EncodeOptions::new(ltk_texture::tex::Format::Bc3).with_mipmaps(),
+        )
+        .unwrap();
+        let texture = Texture::Tex(tex);
+
+        let count = mip_count(&texture);
+        assert!(count > 1);
+        assert_eq!(decode_mip(&texture, 1).unwrap().dimensions(), (8, 4));
+        assert!(decode_mip(&texture, count).is_err());
+    }
+}
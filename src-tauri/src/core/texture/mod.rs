@@ -0,0 +1,222 @@
+//! Texture re-encoding
+//!
+//! Edited or user-supplied images are written back into a DDS or TEX file in
+//! the same compression the file already uses, so the game loads the result
+//! exactly like the original. Mips are regenerated from the new top level;
+//! TEX files keep their header as it was, so a TEX without mips stays without.
+//! The TEX container itself is written by [`tex`], and conversions into TEX
+//! from other formats live in [`encode`]. Channel previews for inspecting
+//! textures are in [`inspect`].
+
+pub mod encode;
+pub mod inspect;
+pub mod tex;
+
+use crate::core::store::write_replacing;
+use crate::error::{Error, Result};
+use image::RgbaImage;
+use ltk_texture::Texture;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Parses a DDS or TEX file
+pub fn read_texture(data: &[u8]) -> Result<Texture> {
+    if data.len() < 4 {
+        return Err(Error::InvalidInput("File too small".into()));
+    }
+    if &data[0..4] != b"TEX\0" && &data[0..4] != b"DDS " {
+        return Err(Error::InvalidInput("Not a supported texture format (DDS or TEX)".into()));
+    }
+
+    Texture::from_reader(&mut Cursor::new(data))
+        .map_err(|e| Error::InvalidInput(format!("Failed to parse texture: {:?}", e)))
+}
+
+/// Width and height of a parsed texture
+///
+/// `ltk_texture` reports a DDS's width as its height too, so non-square DDS
+/// sizes are read from the header of `data` instead.
+pub fn texture_size(texture: &Texture, data: &[u8]) -> (u32, u32) {
+    let header_u32 = |offset: usize| {
+        data.get(offset..offset + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    match texture {
+        // Magic, header size and flags come before height and width
+        Texture::Dds(_) => match (header_u32(16), header_u32(12)) {
+            (Some(width), Some(height)) => (width, height),
+            _ => (texture.width(), texture.height()),
+        },
+        Texture::Tex(_) => (texture.width(), texture.height()),
+    }
+}
+
+/// Decodes the top mip of a texture
+pub fn decode_top_mip(texture: &Texture) -> Result<RgbaImage> {
+    let surface = texture.decode_mipmap(0)
+        .map_err(|e| Error::InvalidInput(format!("Failed to decode mipmap: {:?}", e)))?;
+    surface.into_rgba_image()
+        .map_err(|e| Error::InvalidInput(format!("Failed to get RGBA image: {:?}", e)))
+}
+
+/// DDS encoding matching the FourCC of an existing file
+///
+/// DXT1 stays BC1, DXT3 stays BC2, other block formats become BC3 and
+/// uncompressed files are written as BGRA8.
+fn dds_format(original: &[u8]) -> Result<image_dds::ImageFormat> {
+    let dds = ddsfile::Dds::read(&mut Cursor::new(original))
+        .map_err(|e| Error::InvalidInput(format!("Failed to parse DDS: {}", e)))?;
+
+    Ok(match dds.header.spf.fourcc {
+        Some(fourcc) if fourcc.0 == u32::from_le_bytes(*b"DXT1") => image_dds::ImageFormat::BC1RgbaUnorm,
+        Some(fourcc) if fourcc.0 == u32::from_le_bytes(*b"DXT3") => image_dds::ImageFormat::BC2RgbaUnorm,
+        Some(_) => image_dds::ImageFormat::BC3RgbaUnorm,
+        None => image_dds::ImageFormat::Bgra8Unorm,
+    })
+}
+
+/// Encodes an image in the container and compression of `original`
+///
+/// # Arguments
+/// * `original` - Raw bytes of the DDS or TEX file being replaced
+/// * `texture` - `original`, already parsed
+/// * `image` - New top-level image; its size may differ from the original
+pub fn encode_like(original: &[u8], texture: &Texture, image: &RgbaImage) -> Result<Vec<u8>> {
+    if let Texture::Tex(_) = texture {
+        return tex::encode_with_header(&tex::TexHeader::read(original)?, image);
+    }
+
+    let new_dds = image_dds::dds_from_image(
+        image,
+        dds_format(original)?,
+        image_dds::Quality::Normal,
+        image_dds::Mipmaps::GeneratedAutomatic,
+    ).map_err(|e| Error::InvalidInput(format!("Failed to encode DDS: {:?}", e)))?;
+
+    let mut output = Cursor::new(Vec::new());
+    new_dds.write(&mut output)
+        .map_err(|e| Error::InvalidInput(format!("Failed to write DDS: {}", e)))?;
+    Ok(output.into_inner())
+}
+
+/// Container and compression of a texture file, as shown to the user
+fn describe_format(texture: &Texture, original: &[u8]) -> String {
+    match texture {
+        Texture::Tex(tex) => format!("TEX {:?}", tex.format),
+        Texture::Dds(_) => match dds_format(original) {
+            Ok(format) => format!("DDS {:?}", format),
+            Err(_) => "DDS".to_string(),
+        },
+    }
+}
+
+/// Outcome of replacing a texture
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TextureReplacement {
+    /// Encoding the file was written in, e.g. "TEX Bc3"
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
+    pub mip_count: u32,
+    /// Size of the original texture
+    pub previous_width: u32,
+    pub previous_height: u32,
+}
+
+/// Replaces a DDS or TEX file with an image from disk
+///
+/// The image (PNG, JPG, TGA or anything else `image` can open) is encoded in
+/// the target's existing format with a full mip chain and written over the
+/// target. Its dimensions are kept; block-compressed targets need them to be
+/// multiples of 4.
+///
+/// # Arguments
+/// * `source` - Image supplied by the user
+/// * `target` - Texture file to overwrite
+pub fn replace_texture_file(source: &Path, target: &Path) -> Result<TextureReplacement> {
+    let original = fs::read(target).map_err(|e| Error::io_with_path(e, target))?;
+    let texture = read_texture(&original)?;
+
+    let (previous_width, previous_height) = texture_size(&texture, &original);
+
+    let image = image::open(source)
+        .map_err(|e| Error::InvalidInput(format!("Failed to open image '{}': {}", source.display(), e)))?
+        .to_rgba8();
+    let (width, height) = image.dimensions();
+
+    let block_compressed = match &texture {
+        Texture::Tex(tex) => tex.format.block_size() != (1, 1),
+        Texture::Dds(_) => dds_format(&original)? != image_dds::ImageFormat::Bgra8Unorm,
+    };
+    if block_compressed && (width % 4 != 0 || height % 4 != 0) {
+        return Err(Error::InvalidInput(format!(
+            "Image is {}x{}, but {} needs dimensions that are multiples of 4",
+            width,
+            height,
+            describe_format(&texture, &original)
+        )));
+    }
+
+    let encoded = encode_like(&original, &texture, &image)?;
+    let written = read_texture(&encoded)?;
+    let (width, height) = texture_size(&written, &encoded);
+    write_replacing(target, &encoded).map_err(|e| Error::io_with_path(e, target))?;
+
+    Ok(TextureReplacement {
+        format: describe_format(&texture, &original),
+        width,
+        height,
+        mip_count: written.mip_count(),
+        previous_width,
+        previous_height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn write_bc3_tex(path: &Path, size: u32) {
+        let tex = ltk_texture::Tex::encode_rgba_image(
+            &RgbaImage::from_pixel(size, size, Rgba([0, 0, 255, 255])),
+            ltk_texture::tex::EncodeOptions::new(ltk_texture::tex::Format::Bc3).with_mipmaps(),
+        )
+        .unwrap();
+        let mut out = Vec::new();
+        tex.write(&mut out).unwrap();
+        fs::write(path, out).unwrap();
+    }
+
+    #[test]
+    fn test_replace_texture_keeps_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("skin.tex");
+        write_bc3_tex(&target, 8);
+
+        let source = dir.path().join("new.png");
+        RgbaImage::from_pixel(32, 16, Rgba([255, 0, 0, 255])).save(&source).unwrap();
+
+        let result = replace_texture_file(&source, &target).unwrap();
+        assert_eq!(result.format, "TEX Bc3");
+        assert_eq!((result.width, result.height), (32, 16));
+        assert_eq!((result.previous_width, result.previous_height), (8, 8));
+        assert!(result.mip_count > 1);
+
+        let data = fs::read(&target).unwrap();
+        let texture = read_texture(&data).unwrap();
+        let Texture::Tex(tex) = &texture else { panic!("expected TEX") };
+        assert_eq!(tex.format, ltk_texture::tex::Format::Bc3);
+        let pixel = decode_top_mip(&texture).unwrap().get_pixel(5, 5).0;
+        assert!(pixel[0] > 200 && pixel[2] < 50);
+
+        // Odd sizes can't be block compressed; the target is left alone
+        let odd = dir.path().join("odd.png");
+        RgbaImage::new(30, 16).save(&odd).unwrap();
+        assert!(replace_texture_file(&odd, &target).is_err());
+        assert_eq!(fs::read(&target).unwrap(), data);
+    }
+}
@@ -0,0 +1,269 @@
+//! TEX container writing
+//!
+//! A `.tex` file is a 12-byte header followed by the pixel data of every mip,
+//! smallest (1x1) first and full size last:
+//!
+//! | offset | size | field                                        |
+//! |--------|------|----------------------------------------------|
+//! | 0      | 4    | magic `TEX\0`                                |
+//! | 4      | 2    | width                                        |
+//! | 6      | 2    | height                                       |
+//! | 8      | 1    | unknown, usually 1                           |
+//! | 9      | 1    | format (1 ETC1, 2 ETC2, 10 BC1, 12 BC3, 20 BGRA8) |
+//! | 10     | 1    | resource type (0 texture, 1 cubemap, ...)    |
+//! | 11     | 1    | flags (1 has mipmaps)                        |
+//!
+//! Block-compressed mips are stored as whole 4x4 blocks, so the 2x2 and 1x1
+//! levels still take one block each. The header is kept byte for byte when a
+//! texture is re-encoded, so only the pixel data changes.
+
+use crate::error::{Error, Result};
+use image::imageops::FilterType;
+use image::RgbaImage;
+use ltk_texture::tex::{encode_rgba, Format};
+
+/// Size of the TEX header, magic included
+pub const HEADER_SIZE: usize = 12;
+
+/// Flag bit marking a full mip chain
+pub const FLAG_HAS_MIPMAPS: u8 = 1;
+
+/// The header fields of a `.tex` file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TexHeader {
+    pub width: u16,
+    pub height: u16,
+    pub unknown: u8,
+    pub format: u8,
+    pub resource_type: u8,
+    pub flags: u8,
+}
+
+impl TexHeader {
+    /// Header for a new 2D texture
+    pub fn new(width: u16, height: u16, format: Format, mipmaps: bool) -> Self {
+        Self {
+            width,
+            height,
+            unknown: 1,
+            format: format.to_u8(),
+            resource_type: 0,
+            flags: if mipmaps { FLAG_HAS_MIPMAPS } else { 0 },
+        }
+    }
+
+    /// Reads the header at the start of a `.tex` file
+    pub fn read(data: &[u8]) -> Result<Self> {
+        if data.len() < HEADER_SIZE || &data[0..4] != b"TEX\0" {
+            return Err(Error::InvalidInput("Not a TEX file".into()));
+        }
+        Ok(Self {
+            width: u16::from_le_bytes([data[4], data[5]]),
+            height: u16::from_le_bytes([data[6], data[7]]),
+            unknown: data[8],
+            format: data[9],
+            resource_type: data[10],
+            flags: data[11],
+        })
+    }
+
+    pub fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0; HEADER_SIZE];
+        bytes[0..4].copy_from_slice(b"TEX\0");
+        bytes[4..6].copy_from_slice(&self.width.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.height.to_le_bytes());
+        bytes[8] = self.unknown;
+        bytes[9] = self.format;
+        bytes[10] = self.resource_type;
+        bytes[11] = self.flags;
+        bytes
+    }
+
+    pub fn pixel_format(&self) -> Result<Format> {
+        Format::from_u8(self.format)
+            .map_err(|_| Error::InvalidInput(format!("Unknown TEX format: {}", self.format)))
+    }
+
+    pub fn has_mipmaps(&self) -> bool {
+        self.flags & FLAG_HAS_MIPMAPS != 0
+    }
+
+    /// Number of mips stored, down to 1x1 when the texture has mipmaps
+    pub fn mip_count(&self) -> u32 {
+        if self.has_mipmaps() {
+            u32::from(self.width.max(self.height).max(1)).ilog2() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Dimensions of a mip level, 0 being full size
+    pub fn mip_dimensions(&self, level: u32) -> (u32, u32) {
+        (
+            (u32::from(self.width) >> level).max(1),
+            (u32::from(self.height) >> level).max(1),
+        )
+    }
+}
+
+/// Bytes taken by one mip of the given dimensions
+pub fn mip_size(format: Format, width: u32, height: u32) -> usize {
+    let (block_w, block_h) = format.block_size();
+    (width as usize).div_ceil(block_w) * (height as usize).div_ceil(block_h) * format.bytes_per_block()
+}
+
+/// Assembles a `.tex` file from encoded mips
+///
+/// # Arguments
+/// * `header` - Header to write; its format and dimensions must match the mips
+/// * `mips` - Encoded pixel data, full size first
+pub fn write_tex(header: &TexHeader, mips: &[Vec<u8>]) -> Result<Vec<u8>> {
+    let format = header.pixel_format()?;
+    if mips.len() != header.mip_count() as usize {
+        return Err(Error::InvalidInput(format!(
+            "TEX header expects {} mips, got {}",
+            header.mip_count(),
+            mips.len()
+        )));
+    }
+
+    let mut output = Vec::with_capacity(HEADER_SIZE + mips.iter().map(Vec::len).sum::<usize>());
+    output.extend_from_slice(&header.to_bytes());
+    for (level, mip) in mips.iter().enumerate().rev() {
+        let (width, height) = header.mip_dimensions(level as u32);
+        let expected = mip_size(format, width, height);
+        if mip.len() != expected {
+            return Err(Error::InvalidInput(format!(
+                "Mip {} ({}x{}) is {} bytes, expected {}",
+                level,
+                width,
+                height,
+                mip.len(),
+                expected
+            )));
+        }
+        output.extend_from_slice(mip);
+    }
+    Ok(output)
+}
+
+/// Encodes one mip, padding it to whole blocks by repeating edge pixels
+fn encode_mip(image: &RgbaImage, format: Format) -> Result<Vec<u8>> {
+    let (block_w, block_h) = format.block_size();
+    let (width, height) = image.dimensions();
+    let padded_w = width.div_ceil(block_w as u32) * block_w as u32;
+    let padded_h = height.div_ceil(block_h as u32) * block_h as u32;
+
+    let padded;
+    let image = if (padded_w, padded_h) == (width, height) {
+        image
+    } else {
+        padded = RgbaImage::from_fn(padded_w, padded_h, |x, y| {
+            *image.get_pixel(x.min(width - 1), y.min(height - 1))
+        });
+        &padded
+    };
+
+    encode_rgba(image.width(), image.height(), image.as_raw(), format)
+        .map_err(|e| Error::InvalidInput(format!("Failed to encode TEX: {:?}", e)))
+}
+
+/// Encodes an image under an existing header
+///
+/// The header's dimensions are replaced with the image's; everything else,
+/// including whether mips are stored, is kept.
+pub fn encode_with_header(header: &TexHeader, image: &RgbaImage) -> Result<Vec<u8>> {
+    let (width, height) = image.dimensions();
+    let header = TexHeader {
+        width: u16::try_from(width)
+            .map_err(|_| Error::InvalidInput(format!("Image is too wide for TEX: {}", width)))?,
+        height: u16::try_from(height)
+            .map_err(|_| Error::InvalidInput(format!("Image is too tall for TEX: {}", height)))?,
+        ..*header
+    };
+    let format = header.pixel_format()?;
+
+    let mut mips = Vec::with_capacity(header.mip_count() as usize);
+    let mut level_image = image.clone();
+    for level in 0..header.mip_count() {
+        if level > 0 {
+            let (w, h) = header.mip_dimensions(level);
+            level_image = image::imageops::resize(&level_image, w, h, FilterType::Triangle);
+        }
+        mips.push(encode_mip(&level_image, format)?);
+    }
+
+    write_tex(&header, &mips)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::texture::{decode_top_mip, read_texture};
+    use image::Rgba;
+    use ltk_texture::Texture;
+
+    /// Red left half, blue right half
+    fn split_image(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |x, _| {
+            if x < width / 2 {
+                Rgba([255, 0, 0, 255])
+            } else {
+                Rgba([0, 0, 255, 255])
+            }
+        })
+    }
+
+    #[test]
+    fn test_tex_round_trip() {
+        let image = split_image(16, 8);
+        for format in [Format::Bc1, Format::Bc3, Format::Bgra8] {
+            let header = TexHeader::new(0, 0, format, true);
+            let data = encode_with_header(&header, &image).unwrap();
+
+            let written = TexHeader::read(&data).unwrap();
+            assert_eq!((written.width, written.height), (16, 8));
+            assert_eq!(written.mip_count(), 5);
+            let expected: usize = (0..5)
+                .map(|level| {
+                    let (w, h) = written.mip_dimensions(level);
+                    mip_size(format, w, h)
+                })
+                .sum();
+            assert_eq!(data.len(), HEADER_SIZE + expected);
+
+            // The decoder finds the full-size mip after the smaller ones
+            let texture = read_texture(&data).unwrap();
+            let decoded = decode_top_mip(&texture).unwrap();
+            assert_eq!(decoded.get_pixel(1, 1).0, [255, 0, 0, 255], "{:?}", format);
+            assert_eq!(decoded.get_pixel(14, 6).0, [0, 0, 255, 255], "{:?}", format);
+
+            let Texture::Tex(tex) = &texture else { panic!("expected TEX") };
+            let smallest = tex.decode_mipmap(4).unwrap().into_rgba_image().unwrap();
+            assert_eq!(smallest.dimensions(), (1, 1));
+        }
+    }
+
+    #[test]
+    fn test_reencode_keeps_header() {
+        let original = TexHeader {
+            width: 8,
+            height: 8,
+            unknown: 1,
+            format: Format::Bc3.to_u8(),
+            resource_type: 0,
+            flags: 2,
+        };
+        let data = encode_with_header(&original, &split_image(8, 8)).unwrap();
+        let reencoded = encode_with_header(&TexHeader::read(&data).unwrap(), &split_image(4, 4)).unwrap();
+
+        let header = TexHeader::read(&reencoded).unwrap();
+        assert_eq!(header, TexHeader { width: 4, height: 4, ..original });
+        assert_eq!(header.mip_count(), 1);
+        assert_eq!(reencoded.len(), HEADER_SIZE + mip_size(Format::Bc3, 4, 4));
+
+        // Mip data that doesn't fit the header is refused
+        let header = TexHeader::new(8, 8, Format::Bc1, false);
+        assert!(write_tex(&header, &[vec![0; 8]]).is_err());
+    }
+}
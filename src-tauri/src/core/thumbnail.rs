@@ -0,0 +1,256 @@
+//! Thumbnail cache for the project tree
+//!
+//! Thumbnails are small PNGs kept under the app data directory, named by a
+//! hash of the source path, its modification time and length, and the
+//! requested size. An edited file gets a new name, so entries never need
+//! invalidating; stale ones are just never read again.
+//!
+//! Textures decode only the smallest mip that still covers the requested
+//! size, so a grid of 4K textures costs a few small decodes. Meshes get a
+//! single untextured front view from [`crate::core::mesh::render`].
+
+use crate::core::mesh::render::{render_turntable, MaterialTexture};
+use crate::core::mesh::scb::{parse_scb_file, ScbMeshData};
+use crate::core::mesh::skn::{parse_skn_file, MaterialRange, SknMeshData};
+use crate::core::store::write_replacing;
+use crate::core::texture::{read_texture, texture_size};
+use crate::core::texture::inspect::decode_mip;
+use crate::error::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::imageops::{self, FilterType};
+use image::RgbaImage;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use ts_rs::TS;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Folder under the app data directory holding the cache
+pub const CACHE_DIR: &str = "thumbnails";
+pub const MIN_SIZE: u32 = 16;
+pub const MAX_SIZE: u32 = 512;
+/// Bumped when thumbnails should be re-rendered after an update
+const CACHE_VERSION: u32 = 1;
+
+/// A thumbnail, no larger than the requested size on either side
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Thumbnail {
+    /// Base64-encoded PNG data
+    pub data: String,
+    pub width: u32,
+    pub height: u32,
+    /// Served from the cache without decoding the source
+    pub cached: bool,
+}
+
+/// Name of the cache entry for a file in its current state
+fn cache_key(path: &Path, size: u32) -> Result<String> {
+    let metadata = fs::metadata(path).map_err(|e| Error::io_with_path(e, path))?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|time| time.as_nanos())
+        .unwrap_or_default();
+    let key = format!(
+        "{}|{}|{}|{}|{}",
+        CACHE_VERSION,
+        path.to_string_lossy(),
+        modified,
+        metadata.len(),
+        size
+    );
+    Ok(format!("{:016x}.png", xxh3_64(key.as_bytes())))
+}
+
+/// Scales an image down to fit in a `size` square, keeping its aspect ratio
+fn fit(image: RgbaImage, size: u32) -> RgbaImage {
+    let (width, height) = image.dimensions();
+    if width <= size && height <= size {
+        return image;
+    }
+    let scale = size as f32 / width.max(height) as f32;
+    let width = ((width as f32 * scale).round() as u32).max(1);
+    let height = ((height as f32 * scale).round() as u32).max(1);
+    imageops::resize(&image, width, height, FilterType::Triangle)
+}
+
+/// Decodes the smallest mip of a DDS or TEX that is at least `size` across
+fn texture_thumbnail(path: &Path, size: u32) -> Result<RgbaImage> {
+    let data = fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+    texture_data_thumbnail(&data, size)
+}
+
+/// Like [`texture_thumbnail`], for DDS or TEX bytes already in memory
+pub fn texture_data_thumbnail(data: &[u8], size: u32) -> Result<RgbaImage> {
+    let texture = read_texture(data)?;
+    let (width, height) = texture_size(&texture, data);
+
+    let mut level = 0;
+    while level + 1 < texture.mip_count() && (width.max(height) >> (level + 1)) >= size {
+        level += 1;
+    }
+    Ok(fit(decode_mip(&texture, level)?, size))
+}
+
+/// A static mesh as a single-material SKN so the renderer can draw it
+fn scb_as_skn(scb: ScbMeshData) -> Result<SknMeshData> {
+    if scb.positions.len() > u16::MAX as usize + 1 {
+        return Err(Error::InvalidInput("Mesh has too many vertices to preview".to_string()));
+    }
+    Ok(SknMeshData {
+        materials: vec![MaterialRange {
+            name: scb.name,
+            start_index: 0,
+            index_count: scb.indices.len() as i32,
+            start_vertex: 0,
+            vertex_count: scb.positions.len() as i32,
+        }],
+        indices: scb.indices.iter().map(|&i| i as u16).collect(),
+        positions: scb.positions,
+        normals: scb.normals,
+        uvs: scb.uvs,
+        bounding_box: scb.bounding_box,
+        textures: HashMap::new(),
+        material_data: HashMap::new(),
+        bone_weights: Vec::new(),
+        bone_indices: Vec::new(),
+    })
+}
+
+/// Renders the front of a mesh in its bind pose
+fn mesh_thumbnail(mesh: &SknMeshData, size: u32) -> Result<RgbaImage> {
+    let textures: HashMap<String, MaterialTexture> = HashMap::new();
+    render_turntable(mesh, &textures, None, 1, size)
+        .map_err(|e| Error::InvalidInput(format!("Failed to render mesh: {}", e)))?
+        .pop()
+        .ok_or_else(|| Error::InvalidInput("Renderer returned no frame".to_string()))
+}
+
+/// Encodes an image as PNG bytes
+pub fn encode_png(image: &RgbaImage) -> Result<Vec<u8>> {
+    let mut png = Vec::new();
+    image
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| Error::InvalidInput(format!("Failed to encode thumbnail: {}", e)))?;
+    Ok(png)
+}
+
+/// Produces a thumbnail from the source file, ignoring the cache
+pub fn render_thumbnail(path: &Path, size: u32) -> Result<RgbaImage> {
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "dds" | "tex" => texture_thumbnail(path, size),
+        "png" | "jpg" | "jpeg" | "tga" | "webp" | "gif" => {
+            let image = image::open(path)
+                .map_err(|e| Error::InvalidInput(format!("Failed to open image '{}': {}", path.display(), e)))?;
+            Ok(image.thumbnail(size, size).to_rgba8())
+        }
+        "skn" => {
+            let mesh = parse_skn_file(path).map_err(|e| Error::InvalidInput(format!("Failed to parse SKN: {}", e)))?;
+            mesh_thumbnail(&mesh, size)
+        }
+        "scb" | "sco" => {
+            let scb = parse_scb_file(path).map_err(|e| Error::InvalidInput(format!("Failed to parse SCB: {}", e)))?;
+            mesh_thumbnail(&scb_as_skn(scb)?, size)
+        }
+        _ => Err(Error::InvalidInput(format!("No thumbnail for '{}' files", extension))),
+    }
+}
+
+/// Returns the cached thumbnail of a file, rendering and storing it on a miss
+///
+/// # Arguments
+/// * `cache_dir` - Folder holding the cache (created if needed)
+/// * `path` - DDS, TEX, PNG/JPG/TGA, SKN or SCB/SCO file
+/// * `size` - Longest side in pixels, between [`MIN_SIZE`] and [`MAX_SIZE`]
+pub fn get_thumbnail(cache_dir: &Path, path: &Path, size: u32) -> Result<Thumbnail> {
+    if !(MIN_SIZE..=MAX_SIZE).contains(&size) {
+        return Err(Error::InvalidInput(format!(
+            "Thumbnail size must be between {} and {} pixels",
+            MIN_SIZE, MAX_SIZE
+        )));
+    }
+
+    let entry: PathBuf = cache_dir.join(cache_key(path, size)?);
+    if let Ok(png) = fs::read(&entry) {
+        if let Ok(image) = image::load_from_memory(&png) {
+            return Ok(Thumbnail {
+                data: STANDARD.encode(&png),
+                width: image.width(),
+                height: image.height(),
+                cached: true,
+            });
+        }
+        tracing::warn!("Discarding unreadable thumbnail {}", entry.display());
+    }
+
+    let image = render_thumbnail(path, size)?;
+    let png = encode_png(&image)?;
+
+    // A thumbnail that can't be cached is still worth returning
+    if let Err(e) = fs::create_dir_all(cache_dir).and_then(|_| write_replacing(&entry, &png)) {
+        tracing::warn!("Failed to cache thumbnail {}: {}", entry.display(), e);
+    }
+
+    Ok(Thumbnail {
+        data: STANDARD.encode(&png),
+        width: image.width(),
+        height: image.height(),
+        cached: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_thumbnail_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join(CACHE_DIR);
+        let source = dir.path().join("wide.png");
+        RgbaImage::from_pixel(64, 32, Rgba([255, 0, 0, 255])).save(&source).unwrap();
+
+        let first = get_thumbnail(&cache, &source, 16).unwrap();
+        assert_eq!((first.width, first.height, first.cached), (16, 8, false));
+        let again = get_thumbnail(&cache, &source, 16).unwrap();
+        assert!(again.cached);
+        assert_eq!(again.data, first.data);
+
+        // Another size, or an edited file, is a separate entry
+        assert!(!get_thumbnail(&cache, &source, 32).unwrap().cached);
+        RgbaImage::from_pixel(40, 40, Rgba([0, 255, 0, 255])).save(&source).unwrap();
+        let edited = get_thumbnail(&cache, &source, 16).unwrap();
+        assert_eq!((edited.width, edited.height), (16, 16));
+        assert_eq!(fs::read_dir(&cache).unwrap().count(), 3);
+
+        assert!(get_thumbnail(&cache, &source, 4).is_err());
+        assert!(get_thumbnail(&cache, &dir.path().join("notes.txt"), 16).is_err());
+    }
+
+    #[test]
+    fn test_texture_thumbnail_uses_small_mip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.tex");
+        let tex = ltk_texture::Tex::encode_rgba_image(
+            &RgbaImage::from_pixel(256, 128, Rgba([0, 0, 255, 255])),
+            ltk_texture::tex::EncodeOptions::new(ltk_texture::tex::Format::Bc3).with_mipmaps(),
+        )
+        .unwrap();
+        let mut data = Vec::new();
+        tex.write(&mut data).unwrap();
+        fs::write(&path, data).unwrap();
+
+        let image = texture_thumbnail(&path, 32).unwrap();
+        assert_eq!(image.dimensions(), (32, 16));
+        assert!(image.get_pixel(4, 4).0[2] > 200);
+    }
+}
@@ -0,0 +1,321 @@
+//! Project-local trash for files removed by automated cleanup
+//!
+//! Instead of deleting files outright, cleanup steps move them into
+//! `{project}/.trash/{session}/`, keeping their path relative to the base
+//! directory they were removed from. Each session keeps a manifest of where
+//! its files came from and why they were removed, so a misfiring step (the
+//! BIN whitelist guessing the wrong skin, say) can be undone with
+//! [`restore_files`].
+
+use crate::core::project::variables::find_project_root;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use ts_rs::TS;
+
+/// Name of the trash directory in the project root
+pub const TRASH_DIR: &str = ".trash";
+
+/// Manifest of a session, one JSON [`TrashedFile`] per line
+const MANIFEST_FILE: &str = "manifest.jsonl";
+
+/// A file moved into the trash
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TrashedFile {
+    /// Where the file was, relative to the project root, with `/` separators
+    pub original: String,
+    /// Where it is kept, relative to the session folder
+    pub stored: String,
+    /// Why cleanup removed it, e.g. "wrong skin"
+    pub reason: String,
+}
+
+/// A trash session as listed for the user
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TrashSessionInfo {
+    /// Session folder name, also the time it was created (`YYYYMMDD-HHMMSS.mmm`)
+    pub id: String,
+    pub files: Vec<TrashedFile>,
+}
+
+/// Outcome of [`restore_files`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RestoreResult {
+    /// Files moved back, relative to the project root
+    pub restored: Vec<String>,
+    /// Files left in the trash because their original path is taken again
+    pub conflicts: Vec<String>,
+    /// Files no longer in the trash; dropped from the manifest
+    pub missing: Vec<String>,
+}
+
+/// One trash session: all files removed by a single operation
+#[derive(Debug, Clone)]
+pub struct TrashSession {
+    root: PathBuf,
+    dir: PathBuf,
+}
+
+impl TrashSession {
+    /// Starts a new session in the project containing `base`
+    ///
+    /// Falls back to `base` itself when no project root is found.
+    pub fn for_base(base: &Path) -> Self {
+        let root = find_project_root(base).unwrap_or_else(|| base.to_path_buf());
+        let session = chrono::Local::now().format("%Y%m%d-%H%M%S%.3f").to_string();
+        Self {
+            dir: root.join(TRASH_DIR).join(session),
+            root,
+        }
+    }
+
+    /// Moves `base/relative_path` into the trash and records it in the manifest
+    ///
+    /// # Arguments
+    /// * `reason` - Why the file is removed, shown when restoring
+    ///
+    /// # Returns
+    /// * `Ok(false)` if the file doesn't exist
+    pub fn stage(&self, base: &Path, relative_path: &str, reason: &str) -> Result<bool> {
+        let source = base.join(relative_path);
+        if !source.is_file() {
+            return Ok(false);
+        }
+
+        let dest = self.dir.join(relative_path);
+        move_file(&source, &dest)?;
+
+        let original = source.strip_prefix(&self.root).unwrap_or(&source);
+        let entry = TrashedFile {
+            original: original.to_string_lossy().replace('\\', "/"),
+            stored: relative_path.replace('\\', "/"),
+            reason: reason.to_string(),
+        };
+        // Appending keeps staging cheap for cleanups removing thousands of files
+        let manifest = self.dir.join(MANIFEST_FILE);
+        let mut line = serde_json::to_string(&entry)
+            .map_err(|e| Error::InvalidInput(format!("Failed to serialize trash manifest: {}", e)))?;
+        line.push('\n');
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&manifest)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| Error::io_with_path(e, &manifest))?;
+
+        Ok(true)
+    }
+}
+
+/// Moves a file, creating the destination's parent folders
+fn move_file(source: &Path, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+
+    // Rename is instant on the same volume; fall back to copy+remove otherwise
+    if fs::rename(source, dest).is_err() {
+        fs::copy(source, dest).map_err(|e| Error::io_with_path(e, source))?;
+        fs::remove_file(source).map_err(|e| Error::io_with_path(e, source))?;
+    }
+    Ok(())
+}
+
+/// Rejects paths that would leave the folder they're joined to
+fn check_relative(path: &str) -> Result<()> {
+    let escapes = Path::new(path)
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir));
+    if path.is_empty() || escapes {
+        return Err(Error::InvalidInput(format!("Invalid trash path: {}", path)));
+    }
+    Ok(())
+}
+
+fn read_manifest(session_dir: &Path) -> Result<Vec<TrashedFile>> {
+    let path = session_dir.join(MANIFEST_FILE);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io_with_path(e, &path)),
+    };
+
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                tracing::warn!("Skipping bad trash manifest line in {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect())
+}
+
+fn write_manifest(session_dir: &Path, files: &[TrashedFile]) -> Result<()> {
+    let path = session_dir.join(MANIFEST_FILE);
+    let mut content = String::new();
+    for file in files {
+        let line = serde_json::to_string(file)
+            .map_err(|e| Error::InvalidInput(format!("Failed to serialize trash manifest: {}", e)))?;
+        content.push_str(&line);
+        content.push('\n');
+    }
+    fs::write(&path, content).map_err(|e| Error::io_with_path(e, &path))
+}
+
+/// Lists the trash sessions of a project, newest first
+///
+/// Sessions from before manifests were kept have no files listed and can only
+/// be restored by hand.
+pub fn list_sessions(project_path: &Path) -> Result<Vec<TrashSessionInfo>> {
+    let trash_dir = project_path.join(TRASH_DIR);
+    let entries = match fs::read_dir(&trash_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(Error::io_with_path(e, &trash_dir)),
+    };
+
+    let mut sessions = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        if !entry.path().is_dir() {
+            continue;
+        }
+        sessions.push(TrashSessionInfo {
+            id: entry.file_name().to_string_lossy().to_string(),
+            files: read_manifest(&entry.path())?,
+        });
+    }
+    // Session ids are timestamps, so they sort chronologically
+    sessions.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(sessions)
+}
+
+/// Moves files of a trash session back where they were
+///
+/// A file whose original path exists again is left in the trash rather than
+/// overwriting the newer one. The session folder is removed once it's empty.
+///
+/// # Arguments
+/// * `session_id` - Session to restore from
+/// * `originals` - Original paths to restore (as in [`TrashedFile::original`]);
+///   `None` restores the whole session
+pub fn restore_files(project_path: &Path, session_id: &str, originals: Option<&[String]>) -> Result<RestoreResult> {
+    check_relative(session_id)?;
+    let session_dir = project_path.join(TRASH_DIR).join(session_id);
+    if !session_dir.is_dir() {
+        return Err(Error::InvalidInput(format!("Trash session not found: {}", session_id)));
+    }
+
+    let wanted: Option<HashSet<&str>> = originals.map(|paths| paths.iter().map(String::as_str).collect());
+    let mut result = RestoreResult::default();
+    let mut remaining = Vec::new();
+
+    for file in read_manifest(&session_dir)? {
+        if wanted.as_ref().is_some_and(|wanted| !wanted.contains(file.original.as_str())) {
+            remaining.push(file);
+            continue;
+        }
+        check_relative(&file.original)?;
+        check_relative(&file.stored)?;
+
+        let stored = session_dir.join(&file.stored);
+        let original = project_path.join(&file.original);
+        if !stored.is_file() {
+            result.missing.push(file.original);
+        } else if original.exists() {
+            result.conflicts.push(file.original.clone());
+            remaining.push(file);
+        } else {
+            move_file(&stored, &original)?;
+            tracing::debug!("Restored {} from trash ({})", file.original, file.reason);
+            result.restored.push(file.original);
+        }
+    }
+
+    if remaining.is_empty() {
+        fs::remove_dir_all(&session_dir).map_err(|e| Error::io_with_path(e, &session_dir))?;
+    } else {
+        write_manifest(&session_dir, &remaining)?;
+    }
+
+    tracing::info!(
+        "Restored {} files from trash session {} ({} conflicts, {} missing)",
+        result.restored.len(),
+        session_id,
+        result.conflicts.len(),
+        result.missing.len()
+    );
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_moves_into_project_trash() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("mod.config.json"), "{}").unwrap();
+        let base = dir.path().join("content/base");
+        fs::create_dir_all(base.join("data")).unwrap();
+        fs::write(base.join("data/a.bin"), b"bin").unwrap();
+
+        let trash = TrashSession::for_base(&base);
+        assert!(trash.dir.starts_with(dir.path().join(TRASH_DIR)));
+
+        assert!(trash.stage(&base, "data/a.bin", "wrong skin").unwrap());
+        assert!(!base.join("data/a.bin").exists());
+        assert_eq!(fs::read(trash.dir.join("data/a.bin")).unwrap(), b"bin");
+
+        assert!(!trash.stage(&base, "data/missing.bin", "wrong skin").unwrap());
+    }
+
+    #[test]
+    fn test_restore_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("mod.config.json"), "{}").unwrap();
+        let base = dir.path().join("content/base");
+        fs::create_dir_all(base.join("data")).unwrap();
+        for name in ["a.bin", "b.bin", "c.bin"] {
+            fs::write(base.join("data").join(name), name).unwrap();
+        }
+
+        let trash = TrashSession::for_base(&base);
+        for name in ["a.bin", "b.bin", "c.bin"] {
+            trash.stage(&base, &format!("data/{}", name), "unreferenced").unwrap();
+        }
+
+        let sessions = list_sessions(dir.path()).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].files[0].original, "content/base/data/a.bin");
+        let id = sessions[0].id.clone();
+
+        // Only the requested file comes back
+        let only_a = ["content/base/data/a.bin".to_string()];
+        let result = restore_files(dir.path(), &id, Some(&only_a)).unwrap();
+        assert_eq!(result.restored, only_a);
+        assert_eq!(fs::read(base.join("data/a.bin")).unwrap(), b"a.bin");
+
+        // A file recreated since is not overwritten
+        fs::write(base.join("data/b.bin"), b"new").unwrap();
+        let result = restore_files(dir.path(), &id, None).unwrap();
+        assert_eq!(result.restored, ["content/base/data/c.bin"]);
+        assert_eq!(result.conflicts, ["content/base/data/b.bin"]);
+        assert_eq!(fs::read(base.join("data/b.bin")).unwrap(), b"new");
+        assert_eq!(list_sessions(dir.path()).unwrap()[0].files.len(), 1);
+
+        fs::remove_file(base.join("data/b.bin")).unwrap();
+        restore_files(dir.path(), &id, None).unwrap();
+        assert!(list_sessions(dir.path()).unwrap().is_empty());
+
+        assert!(restore_files(dir.path(), "../content", None).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Weak-reference scan for conventional skin assets
+//!
+//! Some 2D assets (loading screen art, HUD square/circle icons used for the
+//! minimap) are never referenced from the skin BIN - the game finds them by
+//! path convention. Mods that only keep BIN-referenced files silently lose
+//! them, so this module knows those conventions and can check for them.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::OnceLock;
+use walkdir::WalkDir;
+use ts_rs::TS;
+
+/// Kinds of assets the game loads by path convention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum ConventionalAssetKind {
+    LoadScreen,
+    SquareIcon,
+    CircleIcon,
+}
+
+impl ConventionalAssetKind {
+    pub const ALL: [ConventionalAssetKind; 3] = [
+        ConventionalAssetKind::LoadScreen,
+        ConventionalAssetKind::SquareIcon,
+        ConventionalAssetKind::CircleIcon,
+    ];
+
+    /// Human-readable name for reports
+    pub fn label(self) -> &'static str {
+        match self {
+            ConventionalAssetKind::LoadScreen => "Loading screen",
+            ConventionalAssetKind::SquareIcon => "Champion square icon",
+            ConventionalAssetKind::CircleIcon => "Minimap icon",
+        }
+    }
+
+    /// Shape of this asset's path (lowercase, forward slashes)
+    ///
+    /// The champion and skin parts are captured so one compiled pattern
+    /// serves every champion; [`Self::matches`] checks them.
+    fn pattern(self) -> &'static Regex {
+        static LOAD_SCREEN: OnceLock<Regex> = OnceLock::new();
+        static SQUARE_ICON: OnceLock<Regex> = OnceLock::new();
+        static CIRCLE_ICON: OnceLock<Regex> = OnceLock::new();
+
+        let (cell, pattern) = match self {
+            ConventionalAssetKind::LoadScreen => (
+                &LOAD_SCREEN,
+                r"^assets/characters/(?P<champ>[^/]+)/skins/(?P<folder>base|skin\d+)/(?P<name>[^/]+)loadscreen(?:_(?P<skin>\d+))?\.(?:tex|dds)$",
+            ),
+            ConventionalAssetKind::SquareIcon => (
+                &SQUARE_ICON,
+                r"^assets/characters/(?P<champ>[^/]+)/hud/(?P<name>[^/]+)_square(?:_(?P<skin>\d+))?\.(?:tex|dds)$",
+            ),
+            ConventionalAssetKind::CircleIcon => (
+                &CIRCLE_ICON,
+                r"^assets/characters/(?P<champ>[^/]+)/hud/(?P<name>[^/]+)_circle(?:_(?P<skin>\d+))?\.(?:tex|dds)$",
+            ),
+        };
+        cell.get_or_init(|| Regex::new(pattern).expect("conventional asset pattern is valid"))
+    }
+
+    /// Whether a normalized path is this asset for a champion/skin
+    ///
+    /// The base skin has no number suffix and lives in `base`, `skin0` or
+    /// `skin00`; other skins carry their number, possibly zero-padded.
+    fn matches(self, path: &str, champion: &str, skin_id: u32) -> bool {
+        let Some(caps) = self.pattern().captures(path) else {
+            return false;
+        };
+        let champ = champion.to_lowercase();
+        let is_skin = |digits: &str| skin_id != 0 && digits.parse::<u32>().ok() == Some(skin_id);
+
+        let suffix_ok = caps.name("skin").map_or(skin_id == 0, |digits| is_skin(digits.as_str()));
+        let folder_ok = caps.name("folder").is_none_or(|folder| match folder.as_str() {
+            "base" | "skin0" | "skin00" => skin_id == 0,
+            other => other.strip_prefix("skin").is_some_and(is_skin),
+        });
+        caps["champ"] == champ && caps["name"] == champ && suffix_ok && folder_ok
+    }
+}
+
+/// Presence of one conventional asset in a project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ConventionalAssetStatus {
+    pub kind: ConventionalAssetKind,
+    pub label: String,
+    /// Path of the matching file (relative to its WAD folder), if present
+    pub found_path: Option<String>,
+}
+
+/// Strips a leading `{name}.wad.client/` folder and normalizes separators
+fn normalize_relative(path: &str) -> String {
+    let normalized = path.replace('\\', "/").to_lowercase();
+    match normalized.split_once('/') {
+        Some((first, rest)) if first.ends_with(".wad.client") => rest.to_string(),
+        _ => normalized,
+    }
+}
+
+/// Returns true if the path is a conventional asset for the given champion/skin
+///
+/// # Arguments
+/// * `path` - Asset path relative to the WAD root (or content base)
+pub fn is_conventional_asset(path: &str, champion: &str, skin_id: u32) -> bool {
+    if champion.is_empty() {
+        return false;
+    }
+    let normalized = normalize_relative(path);
+    ConventionalAssetKind::ALL
+        .iter()
+        .any(|kind| kind.matches(&normalized, champion, skin_id))
+}
+
+/// Validation rule: checks which conventional assets exist in a content directory
+///
+/// # Arguments
+/// * `content_base` - The project's content/base directory
+/// * `champion` - Champion internal name
+/// * `skin_id` - Skin ID the project targets
+pub fn check_conventional_assets(
+    content_base: &Path,
+    champion: &str,
+    skin_id: u32,
+) -> Vec<ConventionalAssetStatus> {
+    let files: Vec<String> = WalkDir::new(content_base)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(content_base)
+                .ok()
+                .map(|p| normalize_relative(&p.to_string_lossy()))
+        })
+        .collect();
+
+    ConventionalAssetKind::ALL
+        .iter()
+        .map(|kind| ConventionalAssetStatus {
+            kind: *kind,
+            label: kind.label().to_string(),
+            found_path: files.iter().find(|f| kind.matches(f, champion, skin_id)).cloned(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_is_conventional_asset() {
+        assert!(is_conventional_asset(
+            "assets/characters/ahri/skins/skin01/ahriloadscreen_1.tex",
+            "Ahri",
+            1
+        ));
+        assert!(is_conventional_asset(
+            "ahri.wad.client/ASSETS/Characters/Ahri/HUD/Ahri_Circle_1.dds",
+            "Ahri",
+            1
+        ));
+        assert!(is_conventional_asset("assets/characters/ahri/skins/base/ahriloadscreen.tex", "Ahri", 0));
+        assert!(is_conventional_asset("assets/characters/ahri/hud/ahri_square.tex", "ahri", 0));
+        assert!(is_conventional_asset("assets/characters/ahri/skins/skin00/ahriloadscreen.dds", "Ahri", 0));
+
+        // Wrong skin, wrong champion, or BIN-referenced asset
+        assert!(!is_conventional_asset("assets/characters/ahri/hud/ahri_circle_2.tex", "Ahri", 1));
+        assert!(!is_conventional_asset("assets/characters/ahri/hud/ahri_circle_11.tex", "Ahri", 1));
+        assert!(!is_conventional_asset("assets/characters/ahri/hud/ahri_circle.tex", "Annie", 0));
+        assert!(!is_conventional_asset("assets/characters/ahri/skins/skin01/ahri_body.tex", "Ahri", 1));
+        assert!(!is_conventional_asset("assets/characters/ahri/skins/base/ahriloadscreen_1.tex", "Ahri", 1));
+    }
+
+    #[test]
+    fn test_check_conventional_assets() {
+        let dir = tempfile::tempdir().unwrap();
+        let hud = dir.path().join("ahri.wad.client/assets/characters/ahri/hud");
+        fs::create_dir_all(&hud).unwrap();
+        fs::write(hud.join("ahri_circle_3.tex"), b"x").unwrap();
+
+        let report = check_conventional_assets(dir.path(), "Ahri", 3);
+        assert_eq!(report.len(), 3);
+
+        let circle = report.iter().find(|s| s.kind == ConventionalAssetKind::CircleIcon).unwrap();
+        assert_eq!(circle.found_path.as_deref(), Some("assets/characters/ahri/hud/ahri_circle_3.tex"));
+
+        let loadscreen = report.iter().find(|s| s.kind == ConventionalAssetKind::LoadScreen).unwrap();
+        assert!(loadscreen.found_path.is_none());
+    }
+}
@@ -3,11 +3,14 @@
 //! This module provides functionality to validate that assets referenced in BIN files
 //! actually exist in WAD archives.
 
+use crate::core::bin::closure::GameWadLookup;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use ts_rs::TS;
 
 /// Validation report for asset references
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct ValidationReport {
     /// Total number of asset references found
     pub total_references: usize,
@@ -15,6 +18,9 @@ pub struct ValidationReport {
     pub valid_references: usize,
     /// List of missing assets
     pub missing_assets: Vec<MissingAsset>,
+    /// Valid references the mod doesn't ship, loaded from vanilla game WADs
+    #[serde(default)]
+    pub provided_by_game: Vec<GameProvidedAsset>,
     /// Summary statistics by asset type
     pub stats_by_type: HashMap<String, AssetTypeStats>,
 }
@@ -26,6 +32,7 @@ impl ValidationReport {
             total_references: 0,
             valid_references: 0,
             missing_assets: Vec::new(),
+            provided_by_game: Vec::new(),
             stats_by_type: HashMap::new(),
         }
     }
@@ -50,6 +57,21 @@ impl ValidationReport {
             (self.valid_references as f32 / self.total_references as f32) * 100.0
         }
     }
+
+    /// Adds the counts and missing assets of another report (e.g. another BIN)
+    pub fn merge(&mut self, other: ValidationReport) {
+        self.total_references += other.total_references;
+        self.valid_references += other.valid_references;
+        self.missing_assets.extend(other.missing_assets);
+        self.provided_by_game.extend(other.provided_by_game);
+        for (asset_type, stats) in other.stats_by_type {
+            let entry = self.stats_by_type.entry(asset_type).or_default();
+            entry.total += stats.total;
+            entry.valid += stats.valid;
+            entry.missing += stats.missing;
+            entry.provided_by_game += stats.provided_by_game;
+        }
+    }
 }
 
 impl Default for ValidationReport {
@@ -59,7 +81,8 @@ impl Default for ValidationReport {
 }
 
 /// Statistics for a specific asset type
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct AssetTypeStats {
     /// Total references of this type
     pub total: usize,
@@ -67,14 +90,19 @@ pub struct AssetTypeStats {
     pub valid: usize,
     /// Missing references of this type
     pub missing: usize,
+    /// Valid references of this type found only in game WADs
+    #[serde(default)]
+    pub provided_by_game: usize,
 }
 
 /// Represents a missing asset reference
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct MissingAsset {
     /// The path that was referenced
     pub path: String,
     /// Hash of the path (if available)
+    #[ts(type = "number | null")]
     pub path_hash: Option<u64>,
     /// Source file that contains this reference
     pub source_file: String,
@@ -97,12 +125,28 @@ impl MissingAsset {
     }
 }
 
+/// A reference the mod doesn't ship but the game does
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct GameProvidedAsset {
+    /// The path that was referenced
+    pub path: String,
+    /// Source file that contains this reference
+    pub source_file: String,
+    /// Asset type based on file extension
+    pub asset_type: String,
+    /// WAD holding the path, relative to `DATA/FINAL` when possible
+    pub wad: String,
+}
+
 /// Represents an asset reference found in a file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
 pub struct AssetReference {
     /// The referenced path
     pub path: String,
     /// Hash of the path
+    #[ts(type = "number")]
     pub path_hash: u64,
     /// Asset type based on extension
     pub asset_type: String,
@@ -137,6 +181,50 @@ pub fn validate_assets(
     references: &[AssetReference],
     available_hashes: &HashSet<u64>,
     source_file: &str,
+) -> ValidationReport {
+    classify_references(references, source_file, |reference| {
+        available_hashes.contains(&reference.path_hash).then_some(Availability::Shipped)
+    })
+}
+
+/// Validates asset references against the mod's files, then the game's WADs
+///
+/// References the mod doesn't ship but a game WAD holds are valid and listed
+/// in `provided_by_game`, instead of being reported missing. Without a game
+/// lookup this is the same as [`validate_assets`].
+///
+/// # Arguments
+/// * `references` - List of asset references to validate
+/// * `project_hashes` - Path hashes of the files the mod ships
+/// * `game` - Game WADs of the League installation, if known
+/// * `source_file` - Name of the source file containing references
+pub fn validate_assets_with_game(
+    references: &[AssetReference],
+    project_hashes: &HashSet<u64>,
+    mut game: Option<&mut GameWadLookup>,
+    source_file: &str,
+) -> ValidationReport {
+    classify_references(references, source_file, |reference| {
+        if project_hashes.contains(&reference.path_hash) {
+            return Some(Availability::Shipped);
+        }
+        let game = game.as_deref_mut()?;
+        let wad = game.find(&reference.path)?;
+        Some(Availability::Game(game.display_name(&wad)))
+    })
+}
+
+/// Where a valid reference was found
+enum Availability {
+    Shipped,
+    /// Display name of the game WAD
+    Game(String),
+}
+
+fn classify_references(
+    references: &[AssetReference],
+    source_file: &str,
+    mut find: impl FnMut(&AssetReference) -> Option<Availability>,
 ) -> ValidationReport {
     tracing::debug!("Validating {} asset references from {}", references.len(), source_file);
 
@@ -144,7 +232,7 @@ pub fn validate_assets(
     report.total_references = references.len();
 
     for reference in references {
-        let is_valid = available_hashes.contains(&reference.path_hash);
+        let availability = find(reference);
 
         // Update stats by type
         let stats = report.stats_by_type
@@ -152,25 +240,38 @@ pub fn validate_assets(
             .or_default();
         stats.total += 1;
 
-        if is_valid {
-            report.valid_references += 1;
-            stats.valid += 1;
-        } else {
-            stats.missing += 1;
-            report.missing_assets.push(MissingAsset {
-                path: reference.path.clone(),
-                path_hash: Some(reference.path_hash),
-                source_file: source_file.to_string(),
-                asset_type: reference.asset_type.clone(),
-            });
+        match availability {
+            Some(availability) => {
+                report.valid_references += 1;
+                stats.valid += 1;
+                if let Availability::Game(wad) = availability {
+                    stats.provided_by_game += 1;
+                    report.provided_by_game.push(GameProvidedAsset {
+                        path: reference.path.clone(),
+                        source_file: source_file.to_string(),
+                        asset_type: reference.asset_type.clone(),
+                        wad,
+                    });
+                }
+            }
+            None => {
+                stats.missing += 1;
+                report.missing_assets.push(MissingAsset {
+                    path: reference.path.clone(),
+                    path_hash: Some(reference.path_hash),
+                    source_file: source_file.to_string(),
+                    asset_type: reference.asset_type.clone(),
+                });
+            }
         }
     }
 
     tracing::info!(
-        "Validation complete: {}/{} valid ({:.1}%)",
+        "Validation complete: {}/{} valid ({:.1}%), {} from game files",
         report.valid_references,
         report.total_references,
-        report.success_rate()
+        report.success_rate(),
+        report.provided_by_game.len()
     );
 
     report
@@ -236,7 +337,7 @@ fn extract_paths_from_line(line: &str) -> Vec<String> {
         let remainder = &line[start + 6..];
         if let Some(end) = remainder.find('"') {
             let path = &remainder[..end];
-            if is_asset_path(path) && !paths.contains(&path.to_string()) {
+            if is_asset_path(path) && !paths.iter().any(|p| p == path) {
                 paths.push(path.to_string());
             }
         }
@@ -303,7 +404,7 @@ fn compute_path_hash(path: &str) -> u64 {
 }
 
 /// Infers asset type from file path/extension
-fn infer_asset_type(path: &str) -> String {
+pub(crate) fn infer_asset_type(path: &str) -> String {
     let lower = path.to_lowercase();
     
     if lower.ends_with(".dds") || lower.ends_with(".tex") || lower.ends_with(".png") {
@@ -416,4 +517,33 @@ mod tests {
         assert_eq!(report.missing_count(), 1);
         assert!(!report.is_valid());
     }
+
+    #[test]
+    fn test_validate_assets_with_game() {
+        use crate::core::wad::test_util::write_wad;
+
+        let dir = tempfile::tempdir().unwrap();
+        let wad_path = dir.path().join("Ahri.wad.client");
+        write_wad(&wad_path, &[("assets/characters/ahri/vanilla.dds", b"DDS ")]);
+
+        let paths = ["assets/characters/ahri/mod.dds", "assets/characters/ahri/vanilla.dds", "assets/gone.dds"];
+        let refs: Vec<AssetReference> = paths
+            .into_iter()
+            .map(|path| AssetReference::new(path, compute_path_hash(path)))
+            .collect();
+        let shipped = HashSet::from([refs[0].path_hash]);
+        let mut game = GameWadLookup::new(vec![wad_path]);
+
+        let report = validate_assets_with_game(&refs, &shipped, Some(&mut game), "skin0.bin");
+        assert_eq!(report.valid_references, 2);
+        assert_eq!(report.provided_by_game.len(), 1);
+        assert_eq!(report.provided_by_game[0].path, "assets/characters/ahri/vanilla.dds");
+        assert!(report.provided_by_game[0].wad.ends_with("Ahri.wad.client"));
+        assert_eq!(report.stats_by_type["Texture"].provided_by_game, 1);
+        assert_eq!(report.missing_assets.len(), 1);
+        assert_eq!(report.missing_assets[0].path, "assets/gone.dds");
+
+        let without_game = validate_assets_with_game(&refs, &shipped, None, "skin0.bin");
+        assert_eq!(without_game.missing_assets.len(), 2);
+    }
 }
@@ -0,0 +1,214 @@
+//! Asset dependency graph of a project
+//!
+//! The flat reference report only says which paths are missing. Renaming or
+//! deleting a file needs the edges too: which BINs reference a texture, and
+//! what a given BIN pulls in. The graph has one node per game path (project
+//! files and every path a project BIN references) with edges in both
+//! directions, so the frontend can walk it either way.
+
+use crate::core::bin::closure::GameWadLookup;
+use crate::core::project::Project;
+use crate::core::repath::refather::scan_bin_for_paths;
+use crate::core::validation::engine::AssetReference;
+use crate::core::validation::summary::layer_game_paths;
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use ts_rs::TS;
+
+/// Where a node's path is available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetLocation {
+    /// Shipped by an enabled layer of the project
+    Project,
+    /// Not in the project, but present in a game WAD
+    Game,
+    /// Referenced but found nowhere
+    Missing,
+}
+
+/// One game path in the graph
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AssetNode {
+    /// Game path, lowercase with '/' separators
+    pub path: String,
+    /// Asset type based on extension (e.g. "Texture", "Binary")
+    pub asset_type: String,
+    pub location: AssetLocation,
+    /// Project files providing this path, relative to `content/base` or
+    /// prefixed with their layer name
+    pub files: Vec<String>,
+    /// Indices of the nodes this BIN references
+    pub references: Vec<usize>,
+    /// Indices of the BIN nodes referencing this path
+    pub referenced_by: Vec<usize>,
+}
+
+/// Dependency graph of a project's enabled layers
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AssetGraph {
+    pub nodes: Vec<AssetNode>,
+    /// Number of BIN → path edges
+    pub edge_count: usize,
+    /// Project BINs that couldn't be parsed, so their edges are absent
+    pub unreadable_bins: Vec<String>,
+}
+
+impl AssetGraph {
+    fn index_of(&mut self, indices: &mut HashMap<String, usize>, path: &str, location: AssetLocation) -> usize {
+        *indices.entry(path.to_string()).or_insert_with(|| {
+            self.nodes.push(AssetNode {
+                path: path.to_string(),
+                asset_type: AssetReference::new(path, 0).asset_type,
+                location,
+                files: Vec::new(),
+                references: Vec::new(),
+                referenced_by: Vec::new(),
+            });
+            self.nodes.len() - 1
+        })
+    }
+}
+
+/// Builds the dependency graph of a project
+///
+/// Every file in an enabled layer becomes a node, so unreferenced files show
+/// up with no incoming edges. Paths referenced by a BIN but not shipped by the
+/// project are looked up in the game WADs when the project has a League
+/// installation, and marked missing otherwise.
+pub fn build_asset_graph(project: &Project) -> Result<AssetGraph> {
+    let mut game = project.league_path.as_ref().and_then(|league_path| {
+        GameWadLookup::from_league_path(league_path)
+            .inspect_err(|e| tracing::warn!("Building asset graph without game data: {}", e))
+            .ok()
+    });
+
+    let content_dir = project.project_path.join("content");
+    let mut graph = AssetGraph::default();
+    let mut indices = HashMap::new();
+    let mut bins = Vec::new();
+
+    for layer in project.enabled_layers() {
+        let layer_dir = content_dir.join(&layer.name);
+        let mut files = layer_game_paths(&layer_dir);
+        files.sort();
+        for (relative, game_path) in files {
+            let label = if layer.name == "base" { relative.clone() } else { format!("{}/{}", layer.name, relative) };
+            let index = graph.index_of(&mut indices, &game_path, AssetLocation::Project);
+            graph.nodes[index].files.push(label.clone());
+            if game_path.ends_with(".bin") {
+                bins.push((index, label, layer_dir.join(&relative)));
+            }
+        }
+    }
+
+    for (bin_index, label, bin_path) in bins {
+        let paths = match scan_bin_for_paths(&bin_path) {
+            Ok(paths) => paths,
+            Err(e) => {
+                tracing::warn!("Skipping unreadable BIN {}: {}", label, e);
+                graph.unreadable_bins.push(label);
+                continue;
+            }
+        };
+
+        for path in paths.iter() {
+            let location = if indices.contains_key(path) {
+                AssetLocation::Project
+            } else if game.as_mut().is_some_and(|g| g.find(path).is_some()) {
+                AssetLocation::Game
+            } else {
+                AssetLocation::Missing
+            };
+            let target = graph.index_of(&mut indices, path, location);
+            // A layer override can ship the same BIN twice; keep one edge
+            if target == bin_index || graph.nodes[bin_index].references.contains(&target) {
+                continue;
+            }
+            graph.nodes[bin_index].references.push(target);
+            graph.nodes[target].referenced_by.push(bin_index);
+            graph.edge_count += 1;
+        }
+    }
+
+    tracing::info!(
+        "Asset graph for {}: {} nodes, {} edges",
+        project.display_name,
+        graph.nodes.len(),
+        graph.edge_count
+    );
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+    use std::fs;
+
+    fn node<'a>(graph: &'a AssetGraph, path: &str) -> &'a AssetNode {
+        graph.nodes.iter().find(|n| n.path == path).unwrap()
+    }
+
+    #[test]
+    fn test_graph_links_bins_and_assets() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = Project::new("Test", "Ahri", 0, dir.path().join("no-league"), dir.path(), None);
+        let wad = project.assets_path().join("ahri.wad.client");
+        let bin_text = |paths: &[&str]| {
+            let props: String = paths
+                .iter()
+                .enumerate()
+                .map(|(i, p)| format!("        p{}: string = \"{}\"\n", i, p))
+                .collect();
+            format!(
+                "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {{\n    \"Test\" = Foo {{\n{}    }}\n}}\n",
+                props
+            )
+        };
+        let skins = wad.join("data/characters/ahri/skins");
+        fs::create_dir_all(&skins).unwrap();
+        fs::create_dir_all(wad.join("assets")).unwrap();
+        let skin0 = text_to_tree(&bin_text(&["assets/shared.dds", "assets/missing.dds"])).unwrap();
+        let skin1 = text_to_tree(&bin_text(&["ASSETS/Shared.dds"])).unwrap();
+        fs::write(skins.join("skin0.bin"), write_bin(&skin0).unwrap()).unwrap();
+        fs::write(skins.join("skin1.bin"), write_bin(&skin1).unwrap()).unwrap();
+        fs::write(skins.join("broken.bin"), b"nope").unwrap();
+        fs::write(wad.join("assets/shared.dds"), b"DDS ").unwrap();
+        fs::write(wad.join("assets/orphan.dds"), b"DDS ").unwrap();
+
+        let graph = build_asset_graph(&project).unwrap();
+
+        let mut referencing: Vec<&str> = node(&graph, "assets/shared.dds")
+            .referenced_by
+            .iter()
+            .map(|&i| graph.nodes[i].path.as_str())
+            .collect();
+        referencing.sort();
+        assert_eq!(
+            referencing,
+            vec!["data/characters/ahri/skins/skin0.bin", "data/characters/ahri/skins/skin1.bin"]
+        );
+
+        let shared = node(&graph, "assets/shared.dds");
+        assert_eq!(shared.location, AssetLocation::Project);
+        assert_eq!(shared.files, vec!["ahri.wad.client/assets/shared.dds".to_string()]);
+        assert_eq!(shared.asset_type, "Texture");
+
+        let missing = node(&graph, "assets/missing.dds");
+        assert_eq!(missing.location, AssetLocation::Missing);
+        assert!(missing.files.is_empty());
+
+        let skin0 = node(&graph, "data/characters/ahri/skins/skin0.bin");
+        assert_eq!(skin0.references.len(), 2);
+        assert!(node(&graph, "assets/orphan.dds").referenced_by.is_empty());
+
+        assert_eq!(graph.edge_count, 3);
+        assert_eq!(graph.unreadable_bins, vec!["ahri.wad.client/data/characters/ahri/skins/broken.bin".to_string()]);
+    }
+}
@@ -1,5 +1,17 @@
 // Validation module exports
 pub mod engine;
+pub mod conventional;
+pub mod vram;
+pub mod summary;
+pub mod graph;
 
 #[allow(unused_imports)]
-pub use engine::{validate_assets, extract_asset_references, ValidationReport, MissingAsset, AssetReference};
+pub use engine::{validate_assets, validate_assets_with_game, extract_asset_references, ValidationReport, MissingAsset, GameProvidedAsset, AssetReference};
+#[allow(unused_imports)]
+pub use conventional::{check_conventional_assets, is_conventional_asset, ConventionalAssetKind, ConventionalAssetStatus};
+#[allow(unused_imports)]
+pub use vram::{estimate_project_vram, estimate_vram, texture_footprint, TextureBudgetEntry, VramReport};
+#[allow(unused_imports)]
+pub use summary::{validate_project, ProjectValidation, ReportFormat};
+#[allow(unused_imports)]
+pub use graph::{build_asset_graph, AssetGraph, AssetLocation, AssetNode};
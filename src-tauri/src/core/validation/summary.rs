@@ -0,0 +1,443 @@
+//! Shareable project validation summary
+//!
+//! Collects the project-level checks (BIN references, unresolved hashes,
+//! conventional assets, texture memory) into one report and renders it as
+//! Markdown or HTML, so a mod's health can be reviewed outside the app.
+
+use crate::core::bin::closure::GameWadLookup;
+use crate::core::project::Project;
+use crate::core::repath::refather::scan_bin_for_paths;
+use crate::core::validation::conventional::{check_conventional_assets, ConventionalAssetStatus};
+use crate::core::validation::engine::{validate_assets_with_game, AssetReference, ValidationReport};
+use crate::core::validation::vram::{estimate_vram, VramReport};
+use crate::core::wad::extractor::strip_ltk_suffix;
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use ts_rs::TS;
+use walkdir::WalkDir;
+use xxhash_rust::xxh64::xxh64;
+
+/// Document format of an exported validation report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+}
+
+/// Results of every project-level check
+#[derive(Debug, Clone)]
+pub struct ProjectValidation {
+    pub project_name: String,
+    pub champion: String,
+    pub skin_id: u32,
+    /// BIN references of all enabled layers, checked against the project and game WADs
+    pub references: ValidationReport,
+    /// Project files still named by their path hash
+    pub unresolved_files: Vec<String>,
+    pub conventional: Vec<ConventionalAssetStatus>,
+    pub vram: VramReport,
+}
+
+/// Paths of the files in a layer folder, relative to their WAD folder
+///
+/// Game paths drop the `.ltk` suffix of extension-less chunks, since that is
+/// the path BINs reference them by.
+pub(crate) fn layer_game_paths(layer_dir: &Path) -> Vec<(String, String)> {
+    WalkDir::new(layer_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let relative = e.path().strip_prefix(layer_dir).ok()?;
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            let game_path = relative
+                .split_once(".wad.client/")
+                .map(|(_, rest)| rest.to_string())
+                .unwrap_or_else(|| relative.clone());
+            let game_path = strip_ltk_suffix(&game_path).to_lowercase();
+            Some((relative, game_path))
+        })
+        .collect()
+}
+
+/// Path hash of file names like `0123456789abcdef.dds`, used for chunks
+/// whose path wasn't in the hashtable
+fn hash_name(relative: &str) -> Option<u64> {
+    let name = relative.rsplit('/').next().unwrap_or(relative);
+    let stem = name.split('.').next().unwrap_or(name);
+    if stem.len() != 16 || !stem.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u64::from_str_radix(stem, 16).ok()
+}
+
+/// Runs every project-level check
+///
+/// References are valid if the path exists in any enabled layer or, when the
+/// project has a League installation, in a game WAD; the latter are listed as
+/// provided by the game. Files still named by their hash count as the path
+/// they were extracted from.
+pub fn validate_project(project: &Project) -> Result<ProjectValidation> {
+    let mut game = project.league_path.as_ref().and_then(|league_path| {
+        GameWadLookup::from_league_path(league_path)
+            .inspect_err(|e| tracing::warn!("Validating without game data: {}", e))
+            .ok()
+    });
+
+    let content_dir = project.project_path.join("content");
+    let layers: Vec<String> = project.enabled_layers().iter().map(|l| l.name.clone()).collect();
+
+    let mut project_hashes = HashSet::new();
+    let mut unresolved_files = Vec::new();
+    let mut bins = Vec::new();
+    for layer in &layers {
+        let layer_dir = content_dir.join(layer);
+        for (relative, game_path) in layer_game_paths(&layer_dir) {
+            let label = if layer == "base" { relative.clone() } else { format!("{}/{}", layer, relative) };
+            match hash_name(&relative) {
+                Some(path_hash) => {
+                    project_hashes.insert(path_hash);
+                    unresolved_files.push(label.clone());
+                }
+                None => {
+                    project_hashes.insert(xxh64(game_path.as_bytes(), 0));
+                }
+            }
+            if game_path.ends_with(".bin") {
+                bins.push((label, layer_dir.join(&relative)));
+            }
+        }
+    }
+    unresolved_files.sort();
+    bins.sort();
+
+    let mut references = ValidationReport::new();
+    for (label, bin_path) in &bins {
+        let paths = match scan_bin_for_paths(bin_path) {
+            Ok(paths) => paths,
+            Err(e) => {
+                tracing::warn!("Skipping unreadable BIN {}: {}", label, e);
+                continue;
+            }
+        };
+
+        let refs: Vec<AssetReference> = paths
+            .iter()
+            .map(|path| AssetReference::new(path.as_str(), xxh64(path.as_bytes(), 0)))
+            .collect();
+        references.merge(validate_assets_with_game(&refs, &project_hashes, game.as_mut(), label));
+    }
+
+    let conventional = check_conventional_assets(&project.assets_path(), &project.champion, project.skin_id);
+    let vram = estimate_vram(&content_dir, game.as_mut(), &project.champion, project.skin_id)?;
+
+    Ok(ProjectValidation {
+        project_name: project.display_name.clone(),
+        champion: project.champion.clone(),
+        skin_id: project.skin_id,
+        references,
+        unresolved_files,
+        conventional,
+        vram,
+    })
+}
+
+/// Format-independent building blocks of the report
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    List(Vec<String>),
+    Table { headers: Vec<&'static str>, rows: Vec<Vec<String>> },
+}
+
+fn mib(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+impl ProjectValidation {
+    /// Renders the report in the given format
+    pub fn render(&self, format: ReportFormat) -> String {
+        let blocks = self.blocks();
+        match format {
+            ReportFormat::Markdown => render_markdown(&self.title(), &blocks),
+            ReportFormat::Html => render_html(&self.title(), &blocks),
+        }
+    }
+
+    fn title(&self) -> String {
+        format!("Validation report: {}", self.project_name)
+    }
+
+    fn blocks(&self) -> Vec<Block> {
+        let references = &self.references;
+        let missing_conventional = self.conventional.iter().filter(|c| c.found_path.is_none()).count();
+        let texture_warnings: Vec<String> = self
+            .vram
+            .warnings
+            .iter()
+            .cloned()
+            .chain(self.vram.textures.iter().flat_map(|t| {
+                t.warnings.iter().map(move |w| format!("{}: {}", t.path, w))
+            }))
+            .collect();
+
+        let skin = if self.skin_id == 0 { "Base".to_string() } else { format!("Skin {}", self.skin_id) };
+        let mut blocks = vec![Block::List(vec![
+            format!("Champion: {} ({})", self.champion, skin),
+            format!(
+                "References: {}/{} valid ({:.1}%)",
+                references.valid_references,
+                references.total_references,
+                references.success_rate()
+            ),
+            format!("Missing assets: {}", references.missing_assets.len()),
+            format!("Provided by game files: {}", references.provided_by_game.len()),
+            format!("Unresolved hashes: {}", self.unresolved_files.len()),
+            format!("Missing conventional assets: {}", missing_conventional),
+            format!("Texture memory: {} ({} warnings)", mib(self.vram.total_vram_bytes), texture_warnings.len()),
+        ])];
+
+        blocks.push(Block::Heading("Missing assets".to_string()));
+        if references.missing_assets.is_empty() {
+            blocks.push(Block::Paragraph("Every referenced asset was found.".to_string()));
+        } else {
+            blocks.push(Block::Table {
+                headers: vec!["Path", "Type", "Referenced by"],
+                rows: references
+                    .missing_assets
+                    .iter()
+                    .map(|m| vec![m.path.clone(), m.asset_type.clone(), m.source_file.clone()])
+                    .collect(),
+            });
+        }
+
+        if !references.stats_by_type.is_empty() {
+            let mut types: Vec<_> = references.stats_by_type.iter().collect();
+            types.sort_by(|a, b| a.0.cmp(b.0));
+            blocks.push(Block::Heading("References by type".to_string()));
+            blocks.push(Block::Table {
+                headers: vec!["Type", "Total", "Valid", "From game", "Missing"],
+                rows: types
+                    .into_iter()
+                    .map(|(t, s)| {
+                        vec![
+                            t.clone(),
+                            s.total.to_string(),
+                            s.valid.to_string(),
+                            s.provided_by_game.to_string(),
+                            s.missing.to_string(),
+                        ]
+                    })
+                    .collect(),
+            });
+        }
+
+        blocks.push(Block::Heading("Unresolved hashes".to_string()));
+        if self.unresolved_files.is_empty() {
+            blocks.push(Block::Paragraph("All files have resolved names.".to_string()));
+        } else {
+            blocks.push(Block::List(self.unresolved_files.clone()));
+        }
+
+        blocks.push(Block::Heading("Conventional assets".to_string()));
+        blocks.push(Block::Table {
+            headers: vec!["Asset", "Status"],
+            rows: self
+                .conventional
+                .iter()
+                .map(|c| vec![c.label.clone(), c.found_path.clone().unwrap_or_else(|| "Missing".to_string())])
+                .collect(),
+        });
+
+        blocks.push(Block::Heading("Textures".to_string()));
+        if texture_warnings.is_empty() {
+            blocks.push(Block::Paragraph("No oversized or uncompressed textures.".to_string()));
+        } else {
+            blocks.push(Block::List(texture_warnings));
+        }
+        if !self.vram.unreadable.is_empty() {
+            blocks.push(Block::Paragraph("Textures that couldn't be read:".to_string()));
+            blocks.push(Block::List(self.vram.unreadable.clone()));
+        }
+
+        blocks
+    }
+}
+
+fn escape_markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+fn render_markdown(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!("# {}\n", title);
+    for block in blocks {
+        out.push('\n');
+        match block {
+            Block::Heading(text) => out.push_str(&format!("## {}\n", text)),
+            Block::Paragraph(text) => out.push_str(&format!("{}\n", text)),
+            Block::List(items) => {
+                for item in items {
+                    out.push_str(&format!("- {}\n", item));
+                }
+            }
+            Block::Table { headers, rows } => {
+                out.push_str(&format!("| {} |\n", headers.join(" | ")));
+                out.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|c| escape_markdown_cell(c)).collect();
+                    out.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_html(title: &str, blocks: &[Block]) -> String {
+    let title = escape_html(title);
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{0}</title>\n<style>\n\
+         body {{ font-family: sans-serif; margin: 2em; }}\n\
+         table {{ border-collapse: collapse; }}\n\
+         th, td {{ border: 1px solid #ccc; padding: 4px 8px; text-align: left; }}\n\
+         </style>\n</head>\n<body>\n<h1>{0}</h1>\n",
+        title
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(text) => out.push_str(&format!("<h2>{}</h2>\n", escape_html(text))),
+            Block::Paragraph(text) => out.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+            Block::List(items) => {
+                out.push_str("<ul>\n");
+                for item in items {
+                    out.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::Table { headers, rows } => {
+                out.push_str("<table>\n<tr>");
+                for header in headers {
+                    out.push_str(&format!("<th>{}</th>", escape_html(header)));
+                }
+                out.push_str("</tr>\n");
+                for row in rows {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::validation::engine::validate_assets;
+
+    fn sample() -> ProjectValidation {
+        let mut references = ValidationReport::new();
+        references.merge(validate_assets(
+            &[
+                AssetReference::new("assets/a.dds", 1),
+                AssetReference::new("assets/b|<c>.dds", 2),
+            ],
+            &HashSet::from([1]),
+            "data/skin0.bin",
+        ));
+        ProjectValidation {
+            project_name: "Test & Mod".to_string(),
+            champion: "Ahri".to_string(),
+            skin_id: 3,
+            references,
+            unresolved_files: vec!["ahri.wad.client/0123456789abcdef.dds".to_string()],
+            conventional: Vec::new(),
+            vram: VramReport::default(),
+        }
+    }
+
+    #[test]
+    fn test_validate_project() {
+        use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+
+        let dir = tempfile::tempdir().unwrap();
+        let project = Project::new("Test", "Ahri", 0, dir.path().join("no-league"), dir.path(), None);
+        let wad = project.assets_path().join("ahri.wad.client");
+        let tree = text_to_tree(
+            "#PROP_text\ntype: string = \"PROP\"\nversion: u32 = 3\nentries: map[hash,embed] = {\n    \"Test\" = Foo {\n        a: string = \"assets/a.dds\"\n        b: string = \"assets/missing.dds\"\n        c: string = \"assets/bare\"\n        d: string = \"assets/hashed.dds\"\n    }\n}\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(wad.join("data/characters/ahri/skins")).unwrap();
+        std::fs::create_dir_all(wad.join("assets")).unwrap();
+        std::fs::write(wad.join("data/characters/ahri/skins/skin0.bin"), write_bin(&tree).unwrap()).unwrap();
+        std::fs::write(wad.join("assets/a.dds"), b"DDS ").unwrap();
+        // Extension-less chunk saved with the extraction suffix
+        std::fs::write(wad.join("assets/bare.ltk.dds"), b"DDS ").unwrap();
+        std::fs::write(wad.join("0123456789abcdef.bin"), b"").unwrap();
+        // Chunk whose name wasn't resolved still satisfies its path
+        let hashed = format!("{:016x}.dds", xxh64(b"assets/hashed.dds", 0));
+        std::fs::write(wad.join(&hashed), b"DDS ").unwrap();
+
+        let validation = validate_project(&project).unwrap();
+        assert_eq!(validation.references.total_references, 4);
+        assert_eq!(validation.references.valid_references, 3);
+        assert_eq!(validation.references.missing_assets[0].path, "assets/missing.dds");
+        assert_eq!(validation.references.missing_assets[0].source_file, "ahri.wad.client/data/characters/ahri/skins/skin0.bin");
+        assert_eq!(
+            validation.unresolved_files,
+            vec!["ahri.wad.client/0123456789abcdef.bin".to_string(), format!("ahri.wad.client/{}", hashed)]
+        );
+    }
+
+    #[test]
+    fn test_hash_name() {
+        assert_eq!(hash_name("ahri.wad.client/0123456789abcdef.dds"), Some(0x0123456789abcdef));
+        assert_eq!(hash_name("0123456789ABCDEF"), Some(0x0123456789abcdef));
+        assert_eq!(hash_name("assets/characters/ahri/ahri.dds"), None);
+        assert_eq!(hash_name("assets/a.0123456789abcdef.dds"), None);
+        assert_eq!(hash_name("+123456789abcdef.dds"), None);
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let report = sample().render(ReportFormat::Markdown);
+        assert!(report.starts_with("# Validation report: Test & Mod\n"));
+        assert!(report.contains("- References: 1/2 valid (50.0%)\n"));
+        assert!(report.contains("| assets/b\\|<c>.dds | Texture | data/skin0.bin |\n"));
+        assert!(report.contains("- ahri.wad.client/0123456789abcdef.dds\n"));
+    }
+
+    #[test]
+    fn test_render_html_escapes() {
+        let report = sample().render(ReportFormat::Html);
+        assert!(report.contains("<h1>Validation report: Test &amp; Mod</h1>"));
+        assert!(report.contains("<td>assets/b|&lt;c&gt;.dds</td>"));
+        assert!(!report.contains("<c>"));
+    }
+}
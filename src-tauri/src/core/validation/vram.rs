@@ -0,0 +1,392 @@
+//! Texture memory budget estimation
+//!
+//! Oversized custom textures are a common cause of in-game stutter. This
+//! estimates how much GPU memory each texture a mod ships occupies once
+//! loaded (format block size times the full mip chain) and compares it with
+//! the game texture it replaces.
+
+use crate::core::bin::closure::GameWadLookup;
+use crate::core::project::Project;
+use crate::core::wad::explorer::read_chunk_data;
+use crate::error::Result;
+use ltk_texture::Texture;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::OnceLock;
+use ts_rs::TS;
+use walkdir::WalkDir;
+use xxhash_rust::xxh64::xxh64;
+
+/// Textures larger than this on either edge are flagged
+pub const MAX_TEXTURE_EDGE: u32 = 2048;
+/// Flag a texture that needs this many times the memory of its original
+pub const TEXTURE_GROWTH_WARNING: f64 = 2.0;
+/// Flag the mod when replaced textures need this many times the original memory
+pub const TOTAL_GROWTH_WARNING: f64 = 1.5;
+/// Uncompressed textures above this edge length are flagged
+const UNCOMPRESSED_EDGE_WARNING: u32 = 256;
+
+/// GPU memory layout of a texture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextureFootprint {
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
+    pub mip_count: u32,
+    /// Array layers (6 for cubemaps)
+    pub layers: u32,
+    /// Block edge in pixels (4 for block-compressed formats, 1 otherwise)
+    pub block_dim: u32,
+    pub bytes_per_block: u32,
+}
+
+impl TextureFootprint {
+    /// Memory used by all mip levels of all layers
+    pub fn vram_bytes(&self) -> u64 {
+        let block_dim = self.block_dim.max(1);
+        let per_layer: u64 = (0..self.mip_count.max(1))
+            .map(|level| {
+                let w = (self.width >> level).max(1);
+                let h = (self.height >> level).max(1);
+                let blocks = w.div_ceil(block_dim) as u64 * h.div_ceil(block_dim) as u64;
+                blocks * self.bytes_per_block as u64
+            })
+            .sum();
+        per_layer * self.layers.max(1) as u64
+    }
+
+    fn is_compressed(&self) -> bool {
+        self.block_dim > 1
+    }
+}
+
+/// Reads the memory layout of a DDS or TEX texture
+///
+/// # Returns
+/// * `None` if the data isn't a texture in a known format
+pub fn texture_footprint(data: &[u8]) -> Option<TextureFootprint> {
+    match data.get(0..4)? {
+        b"DDS " => {
+            let dds = ddsfile::Dds::read(Cursor::new(data)).ok()?;
+            let format = dds.get_format()?;
+            let (block_dim, bytes_per_block) = match format.get_block_size() {
+                Some(block) => (4, block),
+                None => (1, format.get_bits_per_pixel()? as u32 / 8),
+            };
+            let name = dds
+                .get_dxgi_format()
+                .map(|f| format!("{:?}", f))
+                .or_else(|| dds.get_d3d_format().map(|f| format!("{:?}", f)))
+                .unwrap_or_default();
+            Some(TextureFootprint {
+                format: name,
+                width: dds.get_width(),
+                height: dds.get_height(),
+                mip_count: dds.get_num_mipmap_levels(),
+                layers: dds.get_num_array_layers(),
+                block_dim,
+                bytes_per_block,
+            })
+        }
+        b"TEX\0" => {
+            let Texture::Tex(tex) = Texture::from_reader(&mut Cursor::new(data)).ok()? else {
+                return None;
+            };
+            let (block_dim, _) = tex.format.block_size();
+            Some(TextureFootprint {
+                format: format!("{:?}", tex.format),
+                width: tex.width as u32,
+                height: tex.height as u32,
+                mip_count: tex.mip_count,
+                // resource_type 1 is a cubemap
+                layers: if tex.resource_type == 1 { 6 } else { 1 },
+                block_dim: block_dim as u32,
+                bytes_per_block: tex.format.bytes_per_block() as u32,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Estimated memory of one texture shipped by the mod
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TextureBudgetEntry {
+    /// Path relative to the project content directory
+    pub path: String,
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
+    pub mip_count: u32,
+    #[ts(type = "number")]
+    pub vram_bytes: u64,
+    /// Game path of the texture this one replaces, if found
+    pub original_path: Option<String>,
+    #[ts(type = "number | null")]
+    pub original_vram_bytes: Option<u64>,
+    pub warnings: Vec<String>,
+}
+
+/// Texture memory estimate for a whole project
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct VramReport {
+    /// Textures sorted by estimated memory, largest first
+    pub textures: Vec<TextureBudgetEntry>,
+    /// Memory of every texture the mod ships
+    #[ts(type = "number")]
+    pub total_vram_bytes: u64,
+    /// Memory of the textures that replace a game texture
+    #[ts(type = "number")]
+    pub replaced_vram_bytes: u64,
+    /// Memory of the game textures they replace
+    #[ts(type = "number")]
+    pub original_vram_bytes: u64,
+    /// Files with a texture extension that couldn't be parsed
+    pub unreadable: Vec<String>,
+    /// Project-wide warnings
+    pub warnings: Vec<String>,
+}
+
+fn is_texture_file(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext.eq_ignore_ascii_case("tex") || ext.eq_ignore_ascii_case("dds"))
+        .unwrap_or(false)
+}
+
+/// Game paths a project texture may have been extracted from
+///
+/// Repathed files live under `assets/{creator}/{project}/` with the champion
+/// folder renamed and skin numbers remapped, so the original is guessed by
+/// undoing those steps for the project's champion and skin.
+fn original_candidates(relative_path: &str, champion: &str, skin_id: u32) -> Vec<String> {
+    let path = relative_path.to_lowercase();
+    let mut candidates = vec![path.clone()];
+
+    let parts: Vec<&str> = path.split('/').collect();
+    if parts.len() > 5 && parts[0] == "assets" && parts[3] == "characters" {
+        let mut rest = parts[3..].to_vec();
+        let champion = champion.to_lowercase();
+        rest[1] = &champion;
+        let rest = rest.join("/");
+
+        static SKIN_FOLDER: OnceLock<Regex> = OnceLock::new();
+        static SKIN_FILE: OnceLock<Regex> = OnceLock::new();
+        let skin_folder = SKIN_FOLDER.get_or_init(|| Regex::new(r"skins/skin\d+/").unwrap());
+        let skin_file = SKIN_FILE.get_or_init(|| Regex::new(r"_skin\d+([_.])").unwrap());
+        let rest = skin_folder.replace_all(&rest, format!("skins/skin{}/", skin_id).as_str());
+        let rest = skin_file.replace_all(&rest, format!("_skin{}$1", skin_id).as_str());
+
+        candidates.push(format!("assets/{}", rest));
+        if skin_id == 0 {
+            candidates.push(format!("assets/{}", rest.replace("skins/skin0/", "skins/base/")));
+        }
+    }
+
+    candidates
+}
+
+fn find_original(game: &mut GameWadLookup, candidates: &[String]) -> Option<(String, TextureFootprint)> {
+    candidates.iter().find_map(|candidate| {
+        let wad = game.find(candidate)?;
+        let data = read_chunk_data(&wad, xxh64(candidate.as_bytes(), 0)).ok()?;
+        texture_footprint(&data).map(|footprint| (candidate.clone(), footprint))
+    })
+}
+
+fn texture_warnings(footprint: &TextureFootprint, original: Option<&TextureFootprint>) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let edge = footprint.width.max(footprint.height);
+
+    if edge > MAX_TEXTURE_EDGE {
+        warnings.push(format!(
+            "{}x{} exceeds the {}px the game uses for skin textures",
+            footprint.width, footprint.height, MAX_TEXTURE_EDGE
+        ));
+    }
+    if !footprint.is_compressed() && edge > UNCOMPRESSED_EDGE_WARNING {
+        warnings.push(format!(
+            "Uncompressed {} uses 4-8x the memory of BC1/BC3",
+            footprint.format
+        ));
+    }
+    if footprint.mip_count <= 1 && edge > UNCOMPRESSED_EDGE_WARNING {
+        warnings.push("No mipmaps; distant models sample the full-size texture".to_string());
+    }
+    if let Some(original) = original {
+        let ratio = footprint.vram_bytes() as f64 / original.vram_bytes().max(1) as f64;
+        if ratio >= TEXTURE_GROWTH_WARNING {
+            warnings.push(format!(
+                "Uses {:.1}x the memory of the original ({}x{} {})",
+                ratio, original.width, original.height, original.format
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Estimates the texture memory of a content directory
+///
+/// # Arguments
+/// * `content_dir` - Directory whose textures are scanned recursively
+/// * `game` - Game WADs to compare against, if a League install is known
+/// * `champion` - Project champion, used to find originals of repathed files
+/// * `skin_id` - Project skin, used to find originals of repathed files
+pub fn estimate_vram(
+    content_dir: &Path,
+    mut game: Option<&mut GameWadLookup>,
+    champion: &str,
+    skin_id: u32,
+) -> Result<VramReport> {
+    let mut report = VramReport::default();
+
+    let files = WalkDir::new(content_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && is_texture_file(e.path()));
+
+    for entry in files {
+        let relative = entry
+            .path()
+            .strip_prefix(content_dir)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        let footprint = std::fs::read(entry.path()).ok().and_then(|data| texture_footprint(&data));
+        let Some(footprint) = footprint else {
+            tracing::warn!("Skipping unreadable texture: {}", relative);
+            report.unreadable.push(relative);
+            continue;
+        };
+
+        // Layer and WAD folders (base/ahri.wad.client/...) aren't part of the game path
+        let game_path = relative
+            .split_once(".wad.client/")
+            .map(|(_, rest)| rest)
+            .unwrap_or(&relative);
+        let original = game.as_deref_mut().and_then(|game| {
+            find_original(game, &original_candidates(game_path, champion, skin_id))
+        });
+
+        let vram_bytes = footprint.vram_bytes();
+        report.total_vram_bytes += vram_bytes;
+        if let Some((_, ref original)) = original {
+            report.replaced_vram_bytes += vram_bytes;
+            report.original_vram_bytes += original.vram_bytes();
+        }
+
+        report.textures.push(TextureBudgetEntry {
+            warnings: texture_warnings(&footprint, original.as_ref().map(|(_, f)| f)),
+            path: relative,
+            format: footprint.format.clone(),
+            width: footprint.width,
+            height: footprint.height,
+            mip_count: footprint.mip_count,
+            vram_bytes,
+            original_vram_bytes: original.as_ref().map(|(_, f)| f.vram_bytes()),
+            original_path: original.map(|(path, _)| path),
+        });
+    }
+
+    report.textures.sort_by_key(|t| std::cmp::Reverse(t.vram_bytes));
+
+    if report.original_vram_bytes > 0 {
+        let ratio = report.replaced_vram_bytes as f64 / report.original_vram_bytes as f64;
+        if ratio >= TOTAL_GROWTH_WARNING {
+            report.warnings.push(format!(
+                "Replaced textures use {:.1}x the memory of the originals ({:.1} MB vs {:.1} MB)",
+                ratio,
+                report.replaced_vram_bytes as f64 / (1024.0 * 1024.0),
+                report.original_vram_bytes as f64 / (1024.0 * 1024.0)
+            ));
+        }
+    }
+
+    Ok(report)
+}
+
+/// Estimates the texture memory of a project, comparing against game data
+/// when the project has a League installation
+pub fn estimate_project_vram(project: &Project) -> Result<VramReport> {
+    let mut game = match project.league_path.as_ref() {
+        Some(league_path) => match GameWadLookup::from_league_path(league_path) {
+            Ok(game) => Some(game),
+            Err(e) => {
+                tracing::warn!("Comparing without game data: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    estimate_vram(
+        &project.project_path.join("content"),
+        game.as_mut(),
+        &project.champion,
+        project.skin_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bc3(width: u32, height: u32, mip_count: u32) -> TextureFootprint {
+        TextureFootprint {
+            format: "Bc3".to_string(),
+            width,
+            height,
+            mip_count,
+            layers: 1,
+            block_dim: 4,
+            bytes_per_block: 16,
+        }
+    }
+
+    #[test]
+    fn test_vram_bytes_counts_mip_chain() {
+        assert_eq!(bc3(1024, 1024, 1).vram_bytes(), 1024 * 1024);
+        // 1024 + 256 + 64 + 16 + 4 + 1 + 1 + 1 blocks (4x4, 2x2 and 1x1 take one each)
+        assert_eq!(bc3(128, 128, 8).vram_bytes(), (1024 + 256 + 64 + 16 + 4 + 1 + 1 + 1) * 16);
+    }
+
+    #[test]
+    fn test_texture_footprint_reads_tex() {
+        let image = image::RgbaImage::new(64, 32);
+        let tex = ltk_texture::Tex::encode_rgba_image(
+            &image,
+            ltk_texture::tex::EncodeOptions::new(ltk_texture::tex::Format::Bgra8),
+        )
+        .unwrap();
+        let mut data = Vec::new();
+        tex.write(&mut data).unwrap();
+
+        let footprint = texture_footprint(&data).unwrap();
+        assert_eq!((footprint.width, footprint.height), (64, 32));
+        assert_eq!(footprint.vram_bytes(), 64 * 32 * 4);
+        assert!(texture_footprint(b"PROP").is_none());
+    }
+
+    #[test]
+    fn test_original_candidates_undo_repath() {
+        let candidates = original_candidates(
+            "ASSETS/me/mymod/characters/mymod/skins/skin42/ahri_skin42_tx_cm.tex",
+            "Ahri",
+            3,
+        );
+        assert!(candidates.contains(&"assets/characters/ahri/skins/skin3/ahri_skin3_tx_cm.tex".to_string()));
+    }
+
+    #[test]
+    fn test_texture_warnings() {
+        let original = bc3(1024, 1024, 11);
+        assert!(texture_warnings(&bc3(1024, 1024, 11), Some(&original)).is_empty());
+
+        let warnings = texture_warnings(&bc3(4096, 4096, 13), Some(&original));
+        assert_eq!(warnings.len(), 2);
+    }
+}
@@ -0,0 +1,307 @@
+//! Git version control for projects
+//!
+//! Optional: a project becomes a repository when the user asks for it at
+//! creation or later. Commits stage the whole project the way `git add -A`
+//! does; generated files are kept out by a `.gitignore` Flint maintains
+//! (ritobin caches next to BINs, `output/`, and the `.flint` checkpoint
+//! store, which would only duplicate history git already keeps).
+//!
+//! Only local repositories are used, so git2 is built without network
+//! transports; pushing is left to the user's own git tooling.
+
+use crate::error::{Error, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use git2::{Delta, DiffOptions, IndexAddOption, Patch, Repository, Signature, Sort};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Rules Flint keeps in a project's `.gitignore`
+const IGNORE_RULES: &[&str] = &["output/", "*.ritobin", ".flint/", ".trash/", "*.flint-tmp"];
+
+/// Identity used when git has no `user.name` / `user.email` configured
+const FALLBACK_EMAIL: &str = "flint@localhost";
+
+/// One commit of a project's history
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CommitInfo {
+    pub id: String,
+    /// First line of the message
+    pub summary: String,
+    pub message: String,
+    pub author: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// How a file differs from the last commit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+    Untracked,
+}
+
+/// One changed file in the working tree
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FileChange {
+    /// Path relative to the project, with `/` separators
+    pub path: String,
+    pub kind: ChangeKind,
+    /// Binary files (textures, meshes, BINs) have no line counts
+    pub binary: bool,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Uncommitted changes of a project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WorkingTreeDiff {
+    /// Commit the changes are relative to; `None` before the first commit
+    pub head: Option<String>,
+    pub files: Vec<FileChange>,
+}
+
+/// Returns true if the project folder is the root of a git repository
+pub fn is_repository(project_path: &Path) -> bool {
+    project_path.join(".git").exists()
+}
+
+fn open_repository(project_path: &Path) -> Result<Repository> {
+    if !is_repository(project_path) {
+        return Err(Error::InvalidInput(format!(
+            "{} is not under version control",
+            project_path.display()
+        )));
+    }
+    Ok(Repository::open(project_path)?)
+}
+
+/// Adds Flint's rules to the project's `.gitignore`, keeping the user's own
+pub fn ensure_gitignore(project_path: &Path) -> Result<()> {
+    let path = project_path.join(".gitignore");
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(Error::io_with_path(e, &path)),
+    };
+
+    let missing: Vec<&str> = IGNORE_RULES
+        .iter()
+        .copied()
+        .filter(|rule| !content.lines().any(|line| line.trim() == *rule))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let mut content = content;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    if content.is_empty() {
+        content.push_str("# Generated files, maintained by Flint\n");
+    }
+    for rule in missing {
+        content.push_str(rule);
+        content.push('\n');
+    }
+    fs::write(&path, content).map_err(|e| Error::io_with_path(e, &path))
+}
+
+/// Makes a project a git repository
+///
+/// An existing repository is kept; only the `.gitignore` is brought up to date.
+///
+/// # Returns
+/// True if a new repository was created
+pub fn init_repository(project_path: &Path) -> Result<bool> {
+    let created = !is_repository(project_path);
+    if created {
+        Repository::init(project_path)?;
+        tracing::info!("Initialized git repository in {}", project_path.display());
+    }
+    ensure_gitignore(project_path)?;
+    Ok(created)
+}
+
+fn commit_info(commit: &git2::Commit<'_>) -> CommitInfo {
+    CommitInfo {
+        id: commit.id().to_string(),
+        summary: commit.summary().unwrap_or_default().to_string(),
+        message: commit.message().unwrap_or_default().trim_end().to_string(),
+        author: commit.author().name().unwrap_or_default().to_string(),
+        timestamp: Utc
+            .timestamp_opt(commit.time().seconds(), 0)
+            .single()
+            .unwrap_or_default(),
+    }
+}
+
+/// Commits every change in the project
+///
+/// # Arguments
+/// * `message` - Commit message
+/// * `author` - Name used when git has no identity configured; the project's
+///   creator is a good default
+pub fn commit_project(project_path: &Path, message: &str, author: Option<&str>) -> Result<CommitInfo> {
+    if message.trim().is_empty() {
+        return Err(Error::InvalidInput("A commit needs a message".to_string()));
+    }
+    let repo = open_repository(project_path)?;
+    ensure_gitignore(project_path)?;
+
+    let mut index = repo.index()?;
+    index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
+    index.update_all(["*"], None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch || e.code() == git2::ErrorCode::NotFound => None,
+        Err(e) => return Err(e.into()),
+    };
+    if parent.as_ref().is_some_and(|parent| parent.tree_id() == tree.id()) {
+        return Err(Error::InvalidInput("Nothing to commit: the project has no changes".to_string()));
+    }
+
+    let signature = match repo.signature() {
+        Ok(signature) => signature,
+        Err(_) => Signature::now(author.filter(|a| !a.trim().is_empty()).unwrap_or("Flint"), FALLBACK_EMAIL)?,
+    };
+    let parents: Vec<_> = parent.iter().collect();
+    let id = repo.commit(Some("HEAD"), &signature, &signature, message.trim(), &tree, &parents)?;
+
+    let commit = repo.find_commit(id)?;
+    tracing::info!("Committed {} in {}", commit.id(), project_path.display());
+    Ok(commit_info(&commit))
+}
+
+/// Lists the commits reachable from HEAD, newest first
+///
+/// # Arguments
+/// * `limit` - Maximum number of commits returned
+pub fn project_history(project_path: &Path, limit: usize) -> Result<Vec<CommitInfo>> {
+    let repo = open_repository(project_path)?;
+    let mut walk = repo.revwalk()?;
+    // Changing the sorting resets the walk, so it comes first
+    walk.set_sorting(Sort::TIME)?;
+    match walk.push_head() {
+        Ok(()) => {}
+        // No commits yet
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch || e.code() == git2::ErrorCode::NotFound => {
+            return Ok(Vec::new())
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    walk.take(limit)
+        .map(|id| -> Result<CommitInfo> { Ok(commit_info(&repo.find_commit(id?)?)) })
+        .collect()
+}
+
+/// Lists the uncommitted changes of a project, staged or not
+pub fn diff_working_tree(project_path: &Path) -> Result<WorkingTreeDiff> {
+    let repo = open_repository(project_path)?;
+    let head = repo.head().ok().map(|head| head.peel_to_commit()).transpose()?;
+    let head_tree = head.as_ref().map(|commit| commit.tree()).transpose()?;
+
+    let mut options = DiffOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true);
+    let diff = repo.diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut options))?;
+
+    let mut files = Vec::new();
+    for (index, delta) in diff.deltas().enumerate() {
+        let kind = match delta.status() {
+            Delta::Added => ChangeKind::Added,
+            Delta::Deleted => ChangeKind::Deleted,
+            Delta::Renamed => ChangeKind::Renamed,
+            Delta::Untracked => ChangeKind::Untracked,
+            Delta::Unmodified | Delta::Ignored => continue,
+            _ => ChangeKind::Modified,
+        };
+        let path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+
+        // Generating the patch is what detects binary content
+        let patch = Patch::from_diff(&diff, index)?;
+        let binary = patch.as_ref().map_or(delta.flags().is_binary(), |p| p.delta().flags().is_binary());
+        let (insertions, deletions) = match &patch {
+            Some(patch) if !binary => {
+                let (_, insertions, deletions) = patch.line_stats()?;
+                (insertions, deletions)
+            }
+            _ => (0, 0),
+        };
+
+        files.push(FileChange {
+            path,
+            kind,
+            binary,
+            insertions,
+            deletions,
+        });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(WorkingTreeDiff {
+        head: head.map(|commit| commit.id().to_string()),
+        files,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_commit_history_and_diff() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path();
+        let data = project.join("content/base/Ahri.wad.client/data");
+        fs::create_dir_all(&data).unwrap();
+        fs::write(data.join("skin0.bin"), b"PROP\x00binary").unwrap();
+        fs::write(data.join("skin0.bin.ritobin"), "#PROP_text\n").unwrap();
+        fs::write(project.join("README.md"), "line one\n").unwrap();
+
+        assert!(init_repository(project).unwrap());
+        assert!(!init_repository(project).unwrap());
+        let gitignore = fs::read_to_string(project.join(".gitignore")).unwrap();
+        assert_eq!(gitignore.matches("*.ritobin").count(), 1);
+
+        let first = commit_project(project, "Initial extraction", Some("SirDexal")).unwrap();
+        assert_eq!(first.summary, "Initial extraction");
+        assert!(commit_project(project, "Again", None).is_err());
+
+        fs::write(project.join("README.md"), "line one\nline two\n").unwrap();
+        fs::write(data.join("skin1.bin"), b"PROP\x00new").unwrap();
+        let diff = diff_working_tree(project).unwrap();
+        assert_eq!(diff.head.as_deref(), Some(first.id.as_str()));
+        assert_eq!(diff.files.len(), 2);
+        let readme = diff.files.iter().find(|f| f.path == "README.md").unwrap();
+        assert_eq!((readme.kind, readme.insertions, readme.deletions), (ChangeKind::Modified, 1, 0));
+        assert!(diff.files.iter().any(|f| f.path.ends_with("skin1.bin") && f.kind == ChangeKind::Untracked));
+
+        commit_project(project, "Add skin1", None).unwrap();
+        let history = project_history(project, 10).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].summary, "Add skin1");
+        assert!(diff_working_tree(project).unwrap().files.is_empty());
+    }
+}
@@ -0,0 +1,419 @@
+//! VFX color editing
+//!
+//! Particle colors live in `VfxSystemDefinitionData` objects, spread over
+//! the emitters of each system: a constant `vec4` per color property, or an
+//! animated curve of `vec4` keys under `dynamics`. This module finds every
+//! color value below a color property (`color`, `birthColor`, ...) and
+//! recolors them in bulk, so a recolor doesn't mean editing each curve by
+//! hand in ritobin text.
+//!
+//! Particle colors are HDR: components above 1.0 make an effect glow. The
+//! transforms keep that intensity instead of clamping to 0..1, which is why
+//! recoloring works in HSV (value scales with intensity) rather than the HSL
+//! used for textures in [`crate::core::chroma`].
+
+use crate::core::bin::editor::{self, BinKind};
+use crate::core::bin::ltk_bridge::HashMapProvider;
+use crate::core::bin::search::{field_segment, key_segment};
+use crate::error::Result;
+use league_toolkit::hash::fnv1a::hash_lower;
+use league_toolkit::primitives::Color;
+use ltk_meta::value::*;
+use ltk_meta::{BinProperty, BinTree, PropertyValueEnum};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Class of the objects holding particle systems
+const VFX_SYSTEM_CLASS: &str = "VfxSystemDefinitionData";
+
+/// Emitter color properties, for when the field hash list isn't loaded
+const COLOR_FIELDS: &[&str] = &["color", "birthColor", "fresnelColor", "reflectionFresnelColor"];
+
+/// One color value of a particle system
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct VfxColor {
+    /// `emitterName` of the emitter the color belongs to
+    pub emitter: Option<String>,
+    /// Path from the system object, as `set_bin_property` takes it
+    pub property_path: Vec<String>,
+    /// `vec4` (float, may exceed 1.0) or `rgba` (bytes)
+    pub kind: BinKind,
+    /// RGBA, normalized to 0..1 for `rgba` values
+    pub value: [f32; 4],
+}
+
+/// The colors of one `VfxSystemDefinitionData` object
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct VfxSystemColors {
+    pub object_hash: u32,
+    pub object_name: Option<String>,
+    pub colors: Vec<VfxColor>,
+}
+
+/// Maps colors close to `from` onto `to`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PaletteMapping {
+    pub from: [f32; 3],
+    pub to: [f32; 3],
+}
+
+/// How colors are changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum VfxRecolor {
+    /// Rotate the hue and scale saturation and value
+    Hsv {
+        /// Degrees added to the hue
+        hue: f32,
+        /// Saturation multiplier (1.0 keeps it)
+        saturation: f32,
+        /// Value multiplier (1.0 keeps it)
+        value: f32,
+    },
+    /// Move each color by the offset between its nearest `from` and that
+    /// mapping's `to`, keeping the differences between shades of a curve
+    Palette {
+        mappings: Vec<PaletteMapping>,
+        /// Colors farther than this (RGB distance) from every `from` stay
+        max_distance: Option<f32>,
+    },
+}
+
+/// Outcome of a recolor
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct VfxRecolorResult {
+    /// Systems that were recolored, with their new colors
+    pub systems: Vec<VfxSystemColors>,
+    /// Color values that changed
+    pub changed: usize,
+}
+
+fn rgb_to_hsv([r, g, b]: [f32; 3]) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    if max <= 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    let s = d / max;
+    if d == 0.0 {
+        return (0.0, s, max);
+    }
+
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h * 60.0, s, max)
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> [f32; 3] {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let c = v * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    [r + m, g + m, b + m]
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum::<f32>().sqrt()
+}
+
+impl VfxRecolor {
+    /// Applies the transform to one RGBA color; alpha is never changed
+    pub fn apply(&self, [r, g, b, a]: [f32; 4]) -> [f32; 4] {
+        let [r, g, b] = match self {
+            Self::Hsv { hue, saturation, value } => {
+                let (h, s, v) = rgb_to_hsv([r, g, b]);
+                hsv_to_rgb(h + hue, (s * saturation).clamp(0.0, 1.0), (v * value).max(0.0))
+            }
+            Self::Palette { mappings, max_distance } => {
+                let nearest = mappings
+                    .iter()
+                    .map(|m| (distance([r, g, b], m.from), m))
+                    .filter(|(d, _)| max_distance.is_none_or(|max| *d <= max))
+                    .min_by(|a, b| a.0.total_cmp(&b.0));
+                match nearest {
+                    Some((_, m)) => [
+                        (r + m.to[0] - m.from[0]).max(0.0),
+                        (g + m.to[1] - m.from[1]).max(0.0),
+                        (b + m.to[2] - m.from[2]).max(0.0),
+                    ],
+                    None => [r, g, b],
+                }
+            }
+        };
+        [r, g, b, a]
+    }
+}
+
+/// Walks a system's properties, recording (and optionally changing) the
+/// values below color properties
+struct ColorWalker<'a> {
+    names: &'a HashMapProvider,
+    color_fields: &'a HashSet<u32>,
+    recolor: Option<&'a VfxRecolor>,
+    path: Vec<String>,
+    emitter: Option<String>,
+    in_color: bool,
+    colors: Vec<VfxColor>,
+    changed: usize,
+}
+
+impl ColorWalker<'_> {
+    fn is_color_field(&self, name_hash: u32) -> bool {
+        self.color_fields.contains(&name_hash)
+            || self.names.fields.get(&name_hash).is_some_and(|name| name.to_lowercase().contains("color"))
+    }
+
+    fn record(&mut self, kind: BinKind, old: [f32; 4]) -> [f32; 4] {
+        let new = self.recolor.map_or(old, |recolor| recolor.apply(old));
+        if new != old {
+            self.changed += 1;
+        }
+        self.colors.push(VfxColor {
+            emitter: self.emitter.clone(),
+            property_path: self.path.clone(),
+            kind,
+            value: new,
+        });
+        new
+    }
+
+    fn descend(&mut self, segment: String, value: &mut PropertyValueEnum) {
+        self.path.push(segment);
+        self.visit(value);
+        self.path.pop();
+    }
+
+    fn fields(&mut self, properties: &mut indexmap::IndexMap<u32, BinProperty>) {
+        let emitter_name = properties.get(&hash_lower("emitterName")).and_then(|prop| match &prop.value {
+            PropertyValueEnum::String(s) => Some(s.0.clone()),
+            _ => None,
+        });
+        let outer_emitter = match emitter_name {
+            Some(name) => self.emitter.replace(name),
+            None => self.emitter.clone(),
+        };
+
+        for prop in properties.values_mut() {
+            let outer_color = self.in_color;
+            self.in_color |= self.is_color_field(prop.name_hash);
+            self.descend(field_segment(prop.name_hash, self.names), &mut prop.value);
+            self.in_color = outer_color;
+        }
+
+        self.emitter = outer_emitter;
+    }
+
+    fn visit(&mut self, value: &mut PropertyValueEnum) {
+        use PropertyValueEnum as P;
+        match value {
+            P::Vector4(v) if self.in_color => {
+                v.0 = self.record(BinKind::Vec4, v.0.to_array()).into();
+            }
+            P::Color(c) if self.in_color => {
+                let old = [c.0.r, c.0.g, c.0.b, c.0.a].map(|x| x as f32 / 255.0);
+                let [r, g, b, a] = self.record(BinKind::Rgba, old).map(|x| (x.clamp(0.0, 1.0) * 255.0).round() as u8);
+                c.0 = Color::new(r, g, b, a);
+            }
+            P::Container(c) | P::UnorderedContainer(UnorderedContainerValue(c)) => {
+                for (i, item) in c.items.iter_mut().enumerate() {
+                    self.descend(i.to_string(), item);
+                }
+            }
+            P::Struct(s) | P::Embedded(EmbeddedValue(s)) => self.fields(&mut s.properties),
+            P::Optional(o) => {
+                if let Some(value) = &mut o.value {
+                    self.descend("0".to_string(), value);
+                }
+            }
+            P::Map(m) => {
+                for (key, value) in m.entries.iter_mut() {
+                    self.descend(key_segment(&key.0, self.names), value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Walks the particle systems of a tree, recoloring them if `recolor` is set
+///
+/// Only systems listed in `object_hashes` are touched when it's given.
+fn walk_systems(
+    tree: &mut BinTree,
+    names: &HashMapProvider,
+    object_hashes: Option<&[u32]>,
+    recolor: Option<&VfxRecolor>,
+) -> (Vec<VfxSystemColors>, usize) {
+    let class_hash = hash_lower(VFX_SYSTEM_CLASS);
+    let color_fields: HashSet<u32> = COLOR_FIELDS.iter().map(|name| hash_lower(name)).collect();
+
+    let mut systems = Vec::new();
+    let mut changed = 0;
+    for object in tree.objects.values_mut() {
+        if object.class_hash != class_hash || object_hashes.is_some_and(|hashes| !hashes.contains(&object.path_hash)) {
+            continue;
+        }
+
+        let mut walker = ColorWalker {
+            names,
+            color_fields: &color_fields,
+            recolor,
+            path: Vec::new(),
+            emitter: None,
+            in_color: false,
+            colors: Vec::new(),
+            changed: 0,
+        };
+        walker.fields(&mut object.properties);
+        changed += walker.changed;
+
+        if !walker.colors.is_empty() {
+            systems.push(VfxSystemColors {
+                object_hash: object.path_hash,
+                object_name: names.entries.get(&object.path_hash).cloned(),
+                colors: walker.colors,
+            });
+        }
+    }
+    (systems, changed)
+}
+
+/// Recolors the particle systems of a tree
+///
+/// # Returns
+/// The recolored systems and the number of color values that changed
+pub fn recolor_tree(
+    tree: &mut BinTree,
+    names: &HashMapProvider,
+    object_hashes: Option<&[u32]>,
+    recolor: &VfxRecolor,
+) -> VfxRecolorResult {
+    let (systems, changed) = walk_systems(tree, names, object_hashes, Some(recolor));
+    VfxRecolorResult { systems, changed }
+}
+
+/// Lists the colors of every particle system in a BIN file
+pub fn get_vfx_colors(path: &Path, names: &HashMapProvider) -> Result<Vec<VfxSystemColors>> {
+    let mut tree = editor::load(path)?;
+    Ok(walk_systems(&mut tree, names, None, None).0)
+}
+
+/// Recolors the particle systems of a BIN file and saves it
+///
+/// # Arguments
+/// * `path` - BIN file with `VfxSystemDefinitionData` objects
+/// * `names` - Hash names, used for color fields and property paths
+/// * `object_hashes` - Systems to recolor, all of them if None
+/// * `recolor` - The color transform
+pub fn recolor_vfx(
+    path: &Path,
+    names: &HashMapProvider,
+    object_hashes: Option<&[u32]>,
+    recolor: &VfxRecolor,
+) -> Result<VfxRecolorResult> {
+    let mut tree = editor::load(path)?;
+    let result = recolor_tree(&mut tree, names, object_hashes, recolor);
+    if result.changed > 0 {
+        editor::save(path, &tree)?;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bin::ltk_bridge::{text_to_tree, write_bin};
+    use std::fs;
+
+    const PARTICLES: &str = r#"#PROP_text
+type: string = "PROP"
+version: u32 = 3
+linked: list[string] = {}
+entries: map[hash,embed] = {
+    "Characters/Ahri/Skins/Skin0/Particles/Ahri_Base_Q" = VfxSystemDefinitionData {
+        complexEmitterDefinitionData: list[pointer] = {
+            VfxEmitterDefinitionData {
+                emitterName: string = "orb"
+                color: embed = ValueColor {
+                    constantValue: vec4 = { 1, 0, 0, 1 }
+                    dynamics: pointer = VfxAnimatedColorVariableData {
+                        times: list[f32] = { 0, 1 }
+                        values: list[vec4] = {
+                            { 2, 0, 0, 1 }
+                            { 0, 1, 0, 0.5 }
+                        }
+                    }
+                }
+                birthScale0: embed = ValueVector3 {
+                    constantValue: vec3 = { 1, 1, 1 }
+                }
+            }
+        }
+    }
+    "Characters/Ahri/Skins/Skin0/Materials/Body" = StaticMaterialDef {
+        tint: vec4 = { 1, 0, 0, 1 }
+    }
+}
+"#;
+
+    #[test]
+    fn test_hsv_round_trip_keeps_hdr() {
+        let shift = VfxRecolor::Hsv { hue: 120.0, saturation: 1.0, value: 1.0 };
+        let [r, g, b, a] = shift.apply([2.0, 0.0, 0.0, 0.5]);
+        assert!((r.abs() + b.abs()) < 1e-4 && (g - 2.0).abs() < 1e-4 && a == 0.5);
+    }
+
+    #[test]
+    fn test_get_and_recolor_vfx() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("skin0.bin");
+        fs::write(&path, write_bin(&text_to_tree(PARTICLES).unwrap()).unwrap()).unwrap();
+        let names = HashMapProvider::new();
+
+        let systems = get_vfx_colors(&path, &names).unwrap();
+        assert_eq!(systems.len(), 1);
+        let colors = &systems[0].colors;
+        assert_eq!(colors.len(), 3);
+        assert_eq!(colors[0].emitter.as_deref(), Some("orb"));
+        assert_eq!(colors[1].value, [2.0, 0.0, 0.0, 1.0]);
+        assert_eq!(colors[0].property_path[1], "0");
+
+        // Reds go blue; the green key is out of range and stays
+        let recolor = VfxRecolor::Palette {
+            mappings: vec![PaletteMapping { from: [1.0, 0.0, 0.0], to: [0.0, 0.0, 1.0] }],
+            max_distance: Some(1.2),
+        };
+        let result = recolor_vfx(&path, &names, None, &recolor).unwrap();
+        assert_eq!(result.changed, 2);
+
+        let colors = &get_vfx_colors(&path, &names).unwrap()[0].colors;
+        assert_eq!(colors[0].value, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(colors[1].value, [1.0, 0.0, 1.0, 1.0]);
+        assert_eq!(colors[2].value, [0.0, 1.0, 0.0, 0.5]);
+
+        // Filtering by system leaves everything else alone
+        let result = recolor_vfx(&path, &names, Some(&[1]), &recolor).unwrap();
+        assert_eq!(result.changed, 0);
+    }
+}
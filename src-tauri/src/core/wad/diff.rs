@@ -0,0 +1,213 @@
+//! Chunk-level comparison of two WAD files
+//!
+//! Used to see what a League patch changed in a champion WAD before updating
+//! a skin mod. Chunks are matched by path hash and compared by their stored
+//! checksum, so nothing has to be decompressed.
+
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::wad::reader::WadReader;
+use crate::error::Result;
+use league_toolkit::wad::WadChunk;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use ts_rs::TS;
+
+/// A chunk that differs between the two WADs
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WadChunkDiff {
+    /// Path hash in hex
+    pub path_hash: String,
+    /// Resolved path, if the hash is known
+    pub path: Option<String>,
+    /// Uncompressed size in the old WAD (not set for added chunks)
+    pub old_size: Option<u32>,
+    /// Uncompressed size in the new WAD (not set for removed chunks)
+    pub new_size: Option<u32>,
+}
+
+/// Differences between two WAD files
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WadDiff {
+    pub old_path: String,
+    pub new_path: String,
+    /// Chunks only in the new WAD
+    pub added: Vec<WadChunkDiff>,
+    /// Chunks only in the old WAD
+    pub removed: Vec<WadChunkDiff>,
+    /// Chunks in both whose contents changed
+    pub modified: Vec<WadChunkDiff>,
+    /// Chunks in both with identical contents
+    pub unchanged: usize,
+}
+
+/// What a chunk's header says about its data, enough to tell whether it changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkStamp {
+    pub checksum: u64,
+    #[serde(default)]
+    pub uncompressed_size: usize,
+    #[serde(default)]
+    pub compressed_size: usize,
+}
+
+impl From<&WadChunk> for ChunkStamp {
+    fn from(chunk: &WadChunk) -> Self {
+        Self {
+            checksum: chunk.checksum(),
+            uncompressed_size: chunk.uncompressed_size(),
+            compressed_size: chunk.compressed_size(),
+        }
+    }
+}
+
+impl ChunkStamp {
+    /// True if the two chunks hold different data
+    ///
+    /// WADs without checksums (older versions store 0) fall back to comparing sizes.
+    pub fn differs_from(&self, other: &ChunkStamp) -> bool {
+        if self.checksum != 0 && other.checksum != 0 {
+            return self.checksum != other.checksum;
+        }
+        self.uncompressed_size != other.uncompressed_size || self.compressed_size != other.compressed_size
+    }
+}
+
+/// Path hashes of two chunk sets, sorted by how they changed
+#[derive(Debug, Clone, Default)]
+pub struct ChunkChanges {
+    pub added: Vec<u64>,
+    pub removed: Vec<u64>,
+    pub modified: Vec<u64>,
+    pub unchanged: Vec<u64>,
+}
+
+/// Compares two chunk sets by path hash
+///
+/// The old set doesn't have to come from a WAD still on disk: a project's
+/// vanilla manifest records the stamps of the chunks it was extracted from.
+pub fn compare_chunks(old: &HashMap<u64, ChunkStamp>, new: &HashMap<u64, ChunkStamp>) -> ChunkChanges {
+    let mut changes = ChunkChanges::default();
+    for (hash, old_stamp) in old {
+        match new.get(hash) {
+            None => changes.removed.push(*hash),
+            Some(new_stamp) if old_stamp.differs_from(new_stamp) => changes.modified.push(*hash),
+            Some(_) => changes.unchanged.push(*hash),
+        }
+    }
+    changes.added = new.keys().filter(|hash| !old.contains_key(hash)).copied().collect();
+    changes
+}
+
+/// Stamps of every chunk in a WAD, by path hash
+pub fn chunk_stamps(reader: &WadReader) -> HashMap<u64, ChunkStamp> {
+    reader.chunks().iter().map(|(hash, chunk)| (*hash, ChunkStamp::from(chunk))).collect()
+}
+
+/// Sorts resolved paths first (alphabetically), then unresolved hashes
+fn sort_chunks(chunks: &mut [WadChunkDiff]) {
+    chunks.sort_by(|a, b| match (&a.path, &b.path) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.path_hash.cmp(&b.path_hash),
+    });
+}
+
+/// Compares two WAD files chunk by chunk
+///
+/// # Arguments
+/// * `old_path` - WAD from the earlier patch
+/// * `new_path` - WAD from the later patch
+/// * `hashtable` - Optional hashtable for path resolution
+pub fn diff_wads(
+    old_path: impl AsRef<Path>,
+    new_path: impl AsRef<Path>,
+    hashtable: Option<&Hashtable>,
+) -> Result<WadDiff> {
+    let (old_path, new_path) = (old_path.as_ref(), new_path.as_ref());
+    let old = WadReader::open(old_path)?;
+    let new = WadReader::open(new_path)?;
+
+    let (old, new) = (chunk_stamps(&old), chunk_stamps(&new));
+    let entry = |hash: &u64| WadChunkDiff {
+        path_hash: format!("{:016x}", hash),
+        path: hashtable.and_then(|ht| ht.lookup(*hash, HashVariant::Xxh64).map(str::to_string)),
+        old_size: old.get(hash).map(|c| c.uncompressed_size as u32),
+        new_size: new.get(hash).map(|c| c.uncompressed_size as u32),
+    };
+
+    let changes = compare_chunks(&old, &new);
+    let mut diff = WadDiff {
+        old_path: old_path.display().to_string(),
+        new_path: new_path.display().to_string(),
+        added: changes.added.iter().map(entry).collect(),
+        removed: changes.removed.iter().map(entry).collect(),
+        modified: changes.modified.iter().map(entry).collect(),
+        unchanged: changes.unchanged.len(),
+    };
+
+    sort_chunks(&mut diff.added);
+    sort_chunks(&mut diff.removed);
+    sort_chunks(&mut diff.modified);
+
+    tracing::info!(
+        "WAD diff {} -> {}: {} added, {} removed, {} modified, {} unchanged",
+        old_path.display(),
+        new_path.display(),
+        diff.added.len(),
+        diff.removed.len(),
+        diff.modified.len(),
+        diff.unchanged
+    );
+
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad;
+    use std::fs;
+    use xxhash_rust::xxh64::xxh64;
+
+    #[test]
+    fn test_diff_wads_classifies_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = dir.path().join("old.wad.client");
+        let new = dir.path().join("new.wad.client");
+        write_wad::<&[u8]>(&old, &[
+            ("data/characters/ahri/ahri.bin", b"same bin"),
+            ("assets/characters/ahri/ahri.dds", b"old texture"),
+            ("assets/characters/ahri/removed.dds", b"gone"),
+        ]);
+        write_wad::<&[u8]>(&new, &[
+            ("data/characters/ahri/ahri.bin", b"same bin"),
+            ("assets/characters/ahri/ahri.dds", b"new texture!"),
+            ("assets/characters/ahri/added.dds", b"new data"),
+        ]);
+
+        let hash_dir = dir.path().join("hashes");
+        fs::create_dir_all(&hash_dir).unwrap();
+        let added = "assets/characters/ahri/added.dds";
+        fs::write(hash_dir.join("hashes.game.txt"), format!("{:016x} {}\n", xxh64(added.as_bytes(), 0), added)).unwrap();
+        let hashtable = Hashtable::from_directory(&hash_dir).unwrap();
+
+        let diff = diff_wads(&old, &new, Some(&hashtable)).unwrap();
+        assert_eq!(diff.unchanged, 1);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].path.as_deref(), Some(added));
+        assert_eq!((diff.added[0].old_size, diff.added[0].new_size), (None, Some(8)));
+
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].path, None);
+        let removed_hash = xxh64(b"assets/characters/ahri/removed.dds", 0);
+        assert_eq!(diff.removed[0].path_hash, format!("{:016x}", removed_hash));
+
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!((diff.modified[0].old_size, diff.modified[0].new_size), (Some(11), Some(12)));
+    }
+}
@@ -0,0 +1,302 @@
+//! Read-only game file explorer
+//!
+//! Mounts the WAD archives of a League installation as a browsable virtual
+//! tree without creating a project. WADs are discovered once, and their
+//! contents are only listed one directory level at a time so huge archives
+//! (Map11, Global) stay cheap to browse.
+
+use crate::core::hash::hashtable::Hashtable;
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use ts_rs::TS;
+
+/// Category a game WAD belongs to, based on its location under DATA/FINAL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum GameWadCategory {
+    Champions,
+    Maps,
+    Global,
+}
+
+/// A WAD archive found in the League installation
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct GameWadEntry {
+    /// File name (e.g., "Ahri.wad.client")
+    pub name: String,
+    /// Path relative to DATA/FINAL using forward slashes
+    pub relative_path: String,
+    /// Absolute path to the WAD file
+    pub path: PathBuf,
+    pub category: GameWadCategory,
+    /// Size of the WAD file on disk in bytes
+    #[ts(type = "number")]
+    pub size: u64,
+}
+
+/// A single node of the virtual tree inside a WAD
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExplorerNode {
+    /// Last path component (directory or file name)
+    pub name: String,
+    /// Full virtual path inside the WAD (lowercase, forward slashes)
+    pub path: String,
+    pub is_dir: bool,
+    /// Hex path hash for files, None for directories
+    pub path_hash: Option<String>,
+    /// Uncompressed size for files, sum of all descendants for directories
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Number of files below this node (1 for files)
+    pub file_count: usize,
+}
+
+/// Returns the DATA/FINAL directory of a League installation
+fn final_dir(league_path: &Path) -> PathBuf {
+    league_path.join("Game").join("DATA").join("FINAL")
+}
+
+/// Categorizes a WAD by the first directory component under DATA/FINAL
+fn categorize(relative_path: &str) -> GameWadCategory {
+    match relative_path.split('/').next().map(|s| s.to_lowercase()) {
+        Some(ref first) if first == "champions" => GameWadCategory::Champions,
+        Some(ref first) if first == "maps" => GameWadCategory::Maps,
+        _ => GameWadCategory::Global,
+    }
+}
+
+/// Discovers every WAD archive in a League installation
+///
+/// # Arguments
+/// * `league_path` - Path to the League installation root
+///
+/// # Returns
+/// * `Result<Vec<GameWadEntry>>` - WADs sorted by category, then path
+pub fn scan_game_wads(league_path: impl AsRef<Path>) -> Result<Vec<GameWadEntry>> {
+    let final_dir = final_dir(league_path.as_ref());
+
+    if !final_dir.is_dir() {
+        return Err(Error::InvalidInput(format!(
+            "Game data directory not found: {}",
+            final_dir.display()
+        )));
+    }
+
+    let mut wads = Vec::new();
+
+    for entry in WalkDir::new(&final_dir).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.to_lowercase().ends_with(".wad.client") {
+            continue;
+        }
+
+        let relative_path = entry
+            .path()
+            .strip_prefix(&final_dir)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+
+        wads.push(GameWadEntry {
+            name,
+            category: categorize(&relative_path),
+            relative_path,
+            path: entry.path().to_path_buf(),
+            size,
+        });
+    }
+
+    wads.sort_by(|a, b| {
+        a.category
+            .cmp(&b.category)
+            .then_with(|| a.relative_path.to_lowercase().cmp(&b.relative_path.to_lowercase()))
+    });
+
+    tracing::info!("Found {} game WADs in {}", wads.len(), final_dir.display());
+
+    Ok(wads)
+}
+
+/// Groups resolved chunk paths into the direct children of a directory
+///
+/// # Arguments
+/// * `entries` - Iterator of (path_hash, resolved_path, uncompressed_size)
+/// * `directory` - Virtual directory to list ("" for the root)
+///
+/// # Returns
+/// * Directories first, then files, each sorted by name
+pub fn group_directory<'a>(
+    entries: impl IntoIterator<Item = (u64, &'a str, u64)>,
+    directory: &str,
+) -> Vec<ExplorerNode> {
+    let prefix = directory.trim_matches('/').to_lowercase();
+    let prefix = if prefix.is_empty() { prefix } else { format!("{}/", prefix) };
+
+    let mut dirs: BTreeMap<String, ExplorerNode> = BTreeMap::new();
+    let mut files: BTreeMap<String, ExplorerNode> = BTreeMap::new();
+
+    for (path_hash, resolved, size) in entries {
+        let lower = resolved.to_lowercase().replace('\\', "/");
+        let Some(rest) = lower.strip_prefix(&prefix) else {
+            continue;
+        };
+
+        match rest.split_once('/') {
+            Some((dir_name, _)) => {
+                let node = dirs.entry(dir_name.to_string()).or_insert_with(|| ExplorerNode {
+                    name: dir_name.to_string(),
+                    path: format!("{}{}", prefix, dir_name),
+                    is_dir: true,
+                    path_hash: None,
+                    size: 0,
+                    file_count: 0,
+                });
+                node.size += size;
+                node.file_count += 1;
+            }
+            None => {
+                files.insert(
+                    rest.to_string(),
+                    ExplorerNode {
+                        name: rest.to_string(),
+                        path: lower.clone(),
+                        is_dir: false,
+                        path_hash: Some(format!("{:016x}", path_hash)),
+                        size,
+                        file_count: 1,
+                    },
+                );
+            }
+        }
+    }
+
+    dirs.into_values().chain(files.into_values()).collect()
+}
+
+/// Lists the direct children of a virtual directory inside a WAD
+///
+/// Unresolved chunks are listed at the root under their hex hash.
+///
+/// # Arguments
+/// * `wad_path` - Path to the WAD file
+/// * `directory` - Virtual directory to list ("" for the root)
+/// * `hashtable` - Optional hashtable for path resolution
+pub fn list_wad_directory(
+    wad_path: impl AsRef<Path>,
+    directory: &str,
+    hashtable: Option<&Hashtable>,
+) -> Result<Vec<ExplorerNode>> {
+    let reader = WadReader::open(wad_path)?;
+
+    let resolved: Vec<(u64, String, u64)> = reader
+        .chunks()
+        .iter()
+        .map(|(hash, chunk)| {
+            let path = match hashtable {
+                Some(ht) => ht.resolve(*hash).to_string(),
+                None => format!("{:016x}", hash),
+            };
+            (*hash, path, chunk.uncompressed_size() as u64)
+        })
+        .collect();
+
+    Ok(group_directory(
+        resolved.iter().map(|(h, p, s)| (*h, p.as_str(), *s)),
+        directory,
+    ))
+}
+
+/// Reads and decompresses a single chunk for previewing
+///
+/// # Arguments
+/// * `wad_path` - Path to the WAD file
+/// * `path_hash` - Hash of the chunk's path
+pub fn read_chunk_data(wad_path: impl AsRef<Path>, path_hash: u64) -> Result<Vec<u8>> {
+    let wad_path = wad_path.as_ref();
+    let mut reader = WadReader::open(wad_path)?;
+
+    let chunk = *reader.get_chunk(path_hash).ok_or_else(|| {
+        Error::wad_with_path(format!("Chunk {:016x} not found", path_hash), wad_path)
+    })?;
+
+    let (mut decoder, _) = reader.wad_mut().decode();
+    let data = decoder
+        .load_chunk_decompressed(&chunk)
+        .map_err(|e| Error::wad_with_path(format!("Failed to decompress chunk: {}", e), wad_path))?;
+
+    Ok(data.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<(u64, &'static str, u64)> {
+        vec![
+            (1, "assets/characters/ahri/skins/base/ahri.skn", 100),
+            (2, "assets/characters/ahri/skins/base/ahri.dds", 50),
+            (3, "data/characters/ahri/ahri.bin", 10),
+            (4, "Assets/Characters/Ahri/ahri_icon.dds", 5),
+            (5, "0123456789abcdef", 1),
+        ]
+    }
+
+    #[test]
+    fn test_group_root() {
+        let nodes = group_directory(sample(), "");
+        let names: Vec<_> = nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["assets", "data", "0123456789abcdef"]);
+        assert_eq!(nodes[0].file_count, 3);
+        assert_eq!(nodes[0].size, 155);
+        assert!(!nodes[2].is_dir);
+    }
+
+    #[test]
+    fn test_group_nested_directory() {
+        let nodes = group_directory(sample(), "/assets/characters/ahri/");
+        assert_eq!(nodes.len(), 2);
+        assert!(nodes[0].is_dir);
+        assert_eq!(nodes[0].path, "assets/characters/ahri/skins");
+        assert_eq!(nodes[1].path_hash.as_deref(), Some("0000000000000004"));
+    }
+
+    #[test]
+    fn test_categorize() {
+        assert_eq!(categorize("Champions/Ahri.wad.client"), GameWadCategory::Champions);
+        assert_eq!(categorize("Maps/Shipping/Map11.wad.client"), GameWadCategory::Maps);
+        assert_eq!(categorize("UI.wad.client"), GameWadCategory::Global);
+    }
+
+    #[test]
+    fn test_scan_missing_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(scan_game_wads(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_scan_finds_wads() {
+        let dir = tempfile::tempdir().unwrap();
+        let champions = final_dir(dir.path()).join("Champions");
+        std::fs::create_dir_all(&champions).unwrap();
+        std::fs::write(champions.join("Ahri.wad.client"), b"x").unwrap();
+        std::fs::write(final_dir(dir.path()).join("UI.wad.client"), b"x").unwrap();
+        std::fs::write(champions.join("readme.txt"), b"x").unwrap();
+
+        let wads = scan_game_wads(dir.path()).unwrap();
+        assert_eq!(wads.len(), 2);
+        assert_eq!(wads[0].category, GameWadCategory::Champions);
+        assert_eq!(wads[0].relative_path, "Champions/Ahri.wad.client");
+    }
+}
@@ -1,19 +1,304 @@
 use crate::core::hash::hashtable::Hashtable;
+use crate::core::store::{write_replacing, AssetStore};
+use crate::core::wad::filter::ExtractionFilter;
 use crate::error::{Error, Result};
+use crate::messages::Message;
+use crate::state::CancelToken;
 use league_toolkit::file::LeagueFileKind;
-use league_toolkit::wad::{Wad, WadChunk};
+use league_toolkit::wad::{Wad, WadChunk, WadChunkCompression};
+use rayon::prelude::*;
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs::{self, File};
+use std::io::Read;
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use ts_rs::TS;
+use xxhash_rust::xxh64::xxh64;
 
 /// Result of an extraction operation
 #[derive(Debug, Clone)]
 pub struct ExtractionResult {
     /// Number of chunks successfully extracted
     pub extracted_count: usize,
-    /// Mapping of original paths to actual paths (for long filenames saved with hashes)
+    /// Mapping of original paths to actual paths (for long filenames saved with hashes
+    /// and chunks renamed because their path collided with another chunk)
     pub path_mappings: HashMap<String, String>,
+    /// Problems that didn't stop extraction, such as colliding chunk paths
+    pub warnings: Vec<String>,
+    /// Chunks left alone because an interrupted earlier run already wrote them
+    pub skipped_count: usize,
+    /// Chunks the extraction filter left out
+    pub filtered_count: usize,
+}
+
+/// Outcome of resuming an interrupted project extraction
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExtractionResumeResult {
+    /// Chunks written by this run
+    pub extracted_count: usize,
+    /// Chunks the interrupted run had already written intact
+    pub skipped_count: usize,
+    pub warnings: Vec<String>,
+}
+
+/// A progress update sent as each chunk is finished
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ExtractionProgress {
+    pub current: usize,
+    pub total: usize,
+    /// Chunk that was just finished
+    pub item: String,
+}
+
+impl ExtractionProgress {
+    /// Status line for progress events
+    pub fn message(&self) -> Message {
+        Message::new(
+            "progress.project.extract_chunks",
+            [("current", self.current.to_string()), ("total", self.total.to_string())],
+        )
+    }
+
+    pub fn fraction(&self) -> f64 {
+        if self.total > 0 {
+            self.current as f64 / self.total as f64
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Chunks read from the WAD before being decompressed and written in parallel;
+/// cancellation and checkpoint saves happen between batches
+const EXTRACT_BATCH: usize = 128;
+
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Decompresses chunk data read with `load_chunk_raw`
+///
+/// Does the same as the decoder's `load_chunk_decompressed`, but on bytes
+/// already in memory so it can run off the thread that owns the file.
+fn decompress_chunk(chunk: &WadChunk, raw: &[u8]) -> Result<Vec<u8>> {
+    let mut data = vec![0; chunk.uncompressed_size];
+    match chunk.compression_type {
+        WadChunkCompression::None => return Ok(raw.to_vec()),
+        WadChunkCompression::GZip => flate2::read::GzDecoder::new(raw).read_exact(&mut data)?,
+        WadChunkCompression::Zstd => zstd::Decoder::new(raw)?.read_exact(&mut data)?,
+        WadChunkCompression::ZstdMulti => {
+            // Subchunked data starts with uncompressed bytes before the first zstd frame
+            let offset = raw
+                .windows(ZSTD_MAGIC.len())
+                .position(|w| w == ZSTD_MAGIC)
+                .ok_or_else(|| Error::InvalidInput(format!("No zstd frame in chunk {:016x}", chunk.path_hash)))?;
+            let prefix = offset.min(data.len());
+            data[..prefix].copy_from_slice(&raw[..prefix]);
+            zstd::Decoder::new(&raw[offset..])?.read_exact(&mut data[prefix..])?;
+        }
+        WadChunkCompression::Satellite => {
+            return Err(Error::InvalidInput("Satellite chunks are not supported".to_string()))
+        }
+    }
+    Ok(data)
+}
+
+/// File name of the extraction checkpoint inside a project's `.flint` folder
+pub const EXTRACTION_CHECKPOINT_FILE: &str = "extract_checkpoint.json";
+
+/// Returns where a project keeps its extraction checkpoint
+pub fn extraction_checkpoint_path(project_path: &Path) -> PathBuf {
+    project_path.join(".flint").join(EXTRACTION_CHECKPOINT_FILE)
+}
+
+/// Chunks written so far by an extraction that hasn't finished yet
+///
+/// Saved periodically while extracting and removed once the run completes, so
+/// a checkpoint on disk always means the last run was interrupted.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ExtractionCheckpoint {
+    completed: HashMap<u64, CompletedChunk>,
+    /// Filter of the interrupted run, so resuming extracts the same chunks
+    #[serde(default)]
+    filter: ExtractionFilter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompletedChunk {
+    /// Chunk path before collision and long-name handling, relative to the WAD folder
+    path: PathBuf,
+    size: usize,
+    checksum: u64,
+}
+
+impl ExtractionCheckpoint {
+    /// Loads a checkpoint, starting empty if there is none or it can't be read
+    pub fn load(path: &Path) -> Self {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring unreadable extraction checkpoint {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    /// Writes the checkpoint through a temporary file so a crash mid-write
+    /// can't leave a truncated one behind
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        let content = serde_json::to_string(self).map_err(|e| Error::Wad {
+            message: format!("Failed to serialize extraction checkpoint: {}", e),
+            path: Some(path.to_path_buf()),
+        })?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, content).map_err(|e| Error::io_with_path(e, &tmp))?;
+        fs::rename(&tmp, path).map_err(|e| Error::io_with_path(e, path))
+    }
+
+    pub fn len(&self) -> usize {
+        self.completed.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.completed.is_empty()
+    }
+
+    pub fn filter(&self) -> &ExtractionFilter {
+        &self.filter
+    }
+
+    /// Path recorded for a chunk, if the same chunk data was written before
+    fn completed_path(&self, chunk: &WadChunk) -> Option<&Path> {
+        self.completed
+            .get(&chunk.path_hash)
+            .filter(|done| done.checksum == chunk.checksum && done.size == chunk.uncompressed_size)
+            .map(|done| done.path.as_path())
+    }
+
+    fn record(&mut self, chunk: &WadChunk, path: PathBuf) {
+        self.completed.insert(
+            chunk.path_hash,
+            CompletedChunk {
+                path,
+                size: chunk.uncompressed_size,
+                checksum: chunk.checksum,
+            },
+        );
+    }
+}
+
+/// Output paths claimed during an extraction, compared case-insensitively
+///
+/// Some WADs hold several chunks whose resolved paths only differ in case (or
+/// match exactly because of a bad hashtable entry). Written naively they
+/// overwrite each other, and which one survives depends on map iteration order.
+#[derive(Debug, Default)]
+struct PathClaims {
+    claimed: HashMap<String, u64>,
+}
+
+impl PathClaims {
+    /// Claims `path` for a chunk
+    ///
+    /// Returns the path the chunk should be written to, plus the hash of the
+    /// chunk that already owned `path` if the two collided.
+    fn claim(&mut self, path: &Path, path_hash: u64) -> (PathBuf, Option<u64>) {
+        let key = normalize_path(path);
+        match self.claimed.get(&key) {
+            None => {
+                self.claimed.insert(key, path_hash);
+                (path.to_path_buf(), None)
+            }
+            Some(&owner) => {
+                let renamed = disambiguated_path(path, path_hash);
+                self.claimed.insert(normalize_path(&renamed), path_hash);
+                (renamed, Some(owner))
+            }
+        }
+    }
+}
+
+/// Lowercases a relative path and normalizes its separators to '/'
+fn normalize_path(path: &Path) -> String {
+    path.to_string_lossy().to_lowercase().replace('\\', "/")
+}
+
+/// Strips the `.ltk` or `.ltk.{ext}` suffix [`resolve_chunk_path`] gives
+/// extension-less chunks, returning the path BINs reference them by
+pub fn strip_ltk_suffix(path: &str) -> &str {
+    let name_start = path.rfind('/').map_or(0, |i| i + 1);
+    let Some(at) = path[name_start..].find(".ltk").map(|i| name_start + i) else {
+        return path;
+    };
+    let rest = &path[at + 4..];
+    if rest.is_empty() || (rest.starts_with('.') && !rest[1..].contains('.')) {
+        &path[..at]
+    } else {
+        path
+    }
+}
+
+/// Maps an extension-less chunk path to the file it was written to
+///
+/// The first chunk to claim a path keeps the mapping, matching what
+/// [`PathClaims`] lets keep the unrenamed file.
+fn record_suffixed(resolved_path: &str, placed: &Path, path_mappings: &mut HashMap<String, String>) {
+    let unresolved = resolved_path.chars().all(|c| c.is_ascii_hexdigit());
+    if unresolved || Path::new(resolved_path).extension().is_some() {
+        return;
+    }
+    path_mappings
+        .entry(normalize_path(Path::new(resolved_path)))
+        .or_insert_with(|| normalize_path(placed));
+}
+
+/// Inserts the chunk hash before the extension (`skin0.bin` -> `skin0.{hash}.bin`)
+fn disambiguated_path(path: &Path, path_hash: u64) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .unwrap_or(OsStr::new("unknown"))
+        .to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{}.{:016x}.{}", stem, path_hash, ext.to_string_lossy()),
+        None => format!("{}.{:016x}", stem, path_hash),
+    };
+    path.with_file_name(name)
+}
+
+/// Orders chunks so colliding paths resolve the same way on every run
+///
+/// Chunks whose hash actually matches their resolved path come first, so when
+/// two chunks collide the genuine one keeps the original name; ties are broken
+/// by hash.
+fn extraction_order(
+    chunks: &HashMap<u64, WadChunk>,
+    resolve: impl Fn(u64) -> String,
+) -> Vec<(u64, &WadChunk, String)> {
+    let mut ordered: Vec<_> = chunks
+        .iter()
+        .map(|(path_hash, chunk)| (*path_hash, chunk, resolve(*path_hash)))
+        .collect();
+    ordered.sort_by_key(|(path_hash, _, resolved)| {
+        let genuine = xxh64(resolved.to_lowercase().as_bytes(), 0) == *path_hash;
+        (!genuine, *path_hash)
+    });
+    ordered
+}
+
+/// Builds the warning reported for a pair of colliding chunks
+fn collision_warning(path: &Path, owner: u64, path_hash: u64, renamed: &Path) -> String {
+    format!(
+        "Chunks {:016x} and {:016x} both resolve to '{}'; the second was saved as '{}'",
+        owner,
+        path_hash,
+        normalize_path(path),
+        normalize_path(renamed)
+    )
 }
 
 /// Extracts a single chunk from a WAD archive to the specified output path
@@ -81,7 +366,7 @@ pub fn extract_chunk(
     }
     
     // Write the chunk data to disk
-    fs::write(output_path, &chunk_data)
+    write_replacing(output_path, &chunk_data)
         .map_err(|e| {
             tracing::error!("Failed to write chunk to '{}': {}", output_path.display(), e);
             Error::io_with_path(e, output_path)
@@ -104,7 +389,7 @@ pub fn extract_chunk(
 /// * `hashtable` - Optional hashtable for path resolution
 /// 
 /// # Returns
-/// * `Result<usize>` - Number of chunks successfully extracted, or an error
+/// * `Result<ExtractionResult>` - Extraction result with count, renamed chunks and warnings, or an error
 /// 
 /// # Requirements
 /// Validates: Requirements 4.1, 4.2, 4.3, 4.4, 4.5, 4.6
@@ -112,8 +397,25 @@ pub fn extract_all(
     wad: &mut Wad<File>,
     output_dir: impl AsRef<Path>,
     hashtable: Option<&Hashtable>,
-) -> Result<usize> {
+) -> Result<ExtractionResult> {
+    extract_filtered(wad, output_dir, hashtable, &ExtractionFilter::default())
+}
+
+/// Extracts the chunks of a WAD archive that pass `filter`
+///
+/// Same as [`extract_all`] otherwise. Unresolved chunks are matched by their
+/// hex name, so path globs and kinds other than [`ExtractFileKind::Other`]
+/// leave them out.
+///
+/// [`ExtractFileKind::Other`]: crate::core::wad::filter::ExtractFileKind::Other
+pub fn extract_filtered(
+    wad: &mut Wad<File>,
+    output_dir: impl AsRef<Path>,
+    hashtable: Option<&Hashtable>,
+    filter: &ExtractionFilter,
+) -> Result<ExtractionResult> {
     let output_dir = output_dir.as_ref();
+    let matcher = filter.matcher()?;
     
     tracing::info!("Extracting all chunks to: {}", output_dir.display());
     
@@ -124,16 +426,23 @@ pub fn extract_all(
     tracing::info!("Total chunks to extract: {}", total_chunks);
     
     let mut extracted_count = 0;
+    let mut path_mappings: HashMap<String, String> = HashMap::new();
+    let mut warnings = Vec::new();
+    let mut claims = PathClaims::default();
+    let mut filtered_count = 0;
+    
+    // Resolve chunk paths, falling back to hex hashes if no hashtable provided
+    let ordered = extraction_order(chunks, |path_hash| match hashtable {
+        Some(ht) => ht.resolve(path_hash).to_string(),
+        None => format!("{:016x}", path_hash),
+    });
     
     // Extract each chunk
-    for (path_hash, chunk) in chunks.iter() {
-        // Resolve the chunk path
-        let resolved_path = if let Some(ht) = hashtable {
-            ht.resolve(*path_hash).to_string()
-        } else {
-            // Fall back to hex hash if no hashtable provided
-            format!("{:016x}", path_hash)
-        };
+    for (path_hash, chunk, resolved_path) in ordered {
+        if !matcher.matches(&resolved_path) {
+            filtered_count += 1;
+            continue;
+        }
         
         tracing::debug!("Extracting chunk: {} (hash: {:016x})", resolved_path, path_hash);
         
@@ -169,6 +478,8 @@ pub fn extract_all(
         
         // Resolve the final chunk path with extension handling
         let final_path = resolve_chunk_path(&resolved_path, &chunk_data);
+        let final_path = place_chunk(final_path, path_hash, &mut claims, &mut path_mappings, &mut warnings);
+        record_suffixed(&resolved_path, &final_path, &mut path_mappings);
         let full_output_path = output_dir.join(&final_path);
         
         // Create parent directories
@@ -181,7 +492,7 @@ pub fn extract_all(
         }
         
         // Write the chunk data
-        match fs::write(&full_output_path, &chunk_data) {
+        match write_replacing(&full_output_path, &chunk_data) {
             Ok(_) => {
                 extracted_count += 1;
                 if extracted_count % 100 == 0 {
@@ -195,7 +506,7 @@ pub fn extract_all(
                 let hex_output_path = resolve_chunk_path(&hex_path, &chunk_data);
                 let full_hex_path = output_dir.join(&hex_output_path);
                 
-                fs::write(&full_hex_path, &chunk_data)
+                write_replacing(&full_hex_path, &chunk_data)
                     .map_err(|e| {
                         tracing::error!("Failed to write chunk to '{}': {}", full_hex_path.display(), e);
                         Error::io_with_path(e, &full_hex_path)
@@ -211,7 +522,58 @@ pub fn extract_all(
     
     tracing::info!("Successfully extracted {}/{} chunks", extracted_count, total_chunks);
     
-    Ok(extracted_count)
+    Ok(ExtractionResult {
+        extracted_count,
+        path_mappings,
+        warnings,
+        skipped_count: 0,
+        filtered_count,
+    })
+}
+
+/// Picks the path a chunk is written to, relative to the WAD folder
+///
+/// Colliding paths get the chunk hash inserted into the file name, and very
+/// long paths are shortened to `{hash}.{ext}` (Windows path limits). Both
+/// cases are recorded in `path_mappings` so refather can still find the file.
+fn place_chunk(
+    final_path: PathBuf,
+    path_hash: u64,
+    claims: &mut PathClaims,
+    path_mappings: &mut HashMap<String, String>,
+    warnings: &mut Vec<String>,
+) -> PathBuf {
+    // Keep colliding chunks side by side instead of overwriting each other
+    let final_path = match claims.claim(&final_path, path_hash) {
+        (renamed, Some(owner)) => {
+            let warning = collision_warning(&final_path, owner, path_hash, &renamed);
+            tracing::warn!("{}", warning);
+            warnings.push(warning);
+            // Keyed by the path as the chunk resolved; the lowercase form is
+            // what lookups use and stays with the chunk that kept the path
+            let original = final_path.to_string_lossy().replace('\\', "/");
+            if original != normalize_path(&final_path) {
+                path_mappings.insert(original, normalize_path(&renamed));
+            }
+            renamed
+        }
+        (path, None) => path,
+    };
+
+    // Check if filename is too long (Windows path limit issues)
+    if final_path.to_string_lossy().len() <= 200 {
+        return final_path;
+    }
+
+    // Use hex hash for very long filenames
+    let parent = final_path.parent().unwrap_or(Path::new("data"));
+    let ext = final_path.extension().and_then(|e| e.to_str()).unwrap_or("bin");
+    let hash_path = parent.join(format!("{:016x}.{}", path_hash, ext));
+    tracing::info!("Using hash for long filename: {} -> {}", final_path.display(), hash_path.display());
+
+    // Record the mapping so refather can find the file
+    path_mappings.insert(normalize_path(&final_path), normalize_path(&hash_path));
+    hash_path
 }
 
 /// Find the champion WAD file in a League installation
@@ -251,7 +613,7 @@ pub fn find_champion_wad(league_path: impl AsRef<Path>, champion: &str) -> Optio
 
 /// Extract skin-specific assets from a WAD archive
 /// 
-/// This function extracts ALL files from the WAD. Cleanup of unused files
+/// Without a filter this extracts ALL files from the WAD. Cleanup of unused files
 /// happens later during the repathing phase based on what the skin BIN references.
 /// 
 /// # Arguments
@@ -260,17 +622,31 @@ pub fn find_champion_wad(league_path: impl AsRef<Path>, champion: &str) -> Optio
 /// * `champion` - Champion internal name (e.g., "kayn")
 /// * `skin_id` - Skin ID to extract (e.g., 1 for first skin)
 /// * `hashtable` - Hashtable for path resolution
+/// * `checkpoint_path` - Where to persist progress; chunks recorded there by an
+///   interrupted run are skipped if their file is still on disk with the right size
+/// * `store` - Shared asset store to place files through, if enabled
+/// * `filter` - Which chunks to extract; the default takes everything
+/// * `cancel` - Stops extraction with [`Error::Cancelled`] between batches; the
+///   checkpoint is kept so a later run can resume
+/// * `on_progress` - Called after every chunk, from the worker threads
 /// 
 /// # Returns
 /// * `Result<ExtractionResult>` - Extraction result with count and path mappings, or an error
+#[allow(clippy::too_many_arguments)]
 pub fn extract_skin_assets(
     wad: &mut Wad<File>,
     output_dir: impl AsRef<Path>,
     champion: &str,
     _skin_id: u32,
     hashtable: &Hashtable,
+    checkpoint_path: Option<&Path>,
+    store: Option<&AssetStore>,
+    filter: &ExtractionFilter,
+    cancel: &CancelToken,
+    on_progress: Option<&(dyn Fn(ExtractionProgress) + Sync)>,
 ) -> Result<ExtractionResult> {
     let output_dir = output_dir.as_ref();
+    let matcher = filter.matcher()?;
     
     // Create the WAD folder structure: {Champion}.wad.client/
     // This is required by ltk_fantome for proper fantome/modpkg packing
@@ -292,79 +668,161 @@ pub fn extract_skin_assets(
     
     let mut extracted_count = 0;
     let mut path_mappings: HashMap<String, String> = HashMap::new();
+    let mut warnings = Vec::new();
+    let mut claims = PathClaims::default();
+    
+    let mut checkpoint = checkpoint_path.map(ExtractionCheckpoint::load).unwrap_or_default();
+    let mut skipped_count = 0;
+    if !checkpoint.is_empty() {
+        tracing::info!("Resuming interrupted extraction ({} chunks recorded)", checkpoint.len());
+    }
+    checkpoint.filter = filter.clone();
+    // Saved right away so a run that dies before its first periodic save can
+    // still be recognized as interrupted
+    if let Some(path) = checkpoint_path {
+        checkpoint.save(path)?;
+    }
     
-    // Extract all chunks - we'll clean up unused files later based on skin BIN references
+    // Extract everything under assets/ or data/ - we'll clean up unused files
+    // later based on skin BIN references
     let mut skipped_unknown = 0;
-    for (path_hash, chunk) in chunks.iter() {
-        // Resolve the chunk path
-        let resolved_path = hashtable.resolve(*path_hash).to_string();
+    let mut filtered_count = 0;
+    let mut wanted = Vec::new();
+    for (path_hash, chunk, resolved_path) in extraction_order(chunks, |path_hash| hashtable.resolve(path_hash).to_string()) {
         let path_lower = resolved_path.to_lowercase();
-        
-        // Check if this is an unresolved hash (hex string that doesn't look like a path)
-        let is_unresolved = resolved_path.chars().all(|c| c.is_ascii_hexdigit());
-        
-        // Extract everything under assets/ or data/
-        // Also extract unresolved hashes (they might be important shared assets)
-        if !path_lower.starts_with("assets/") && !path_lower.starts_with("data/") {
-            if is_unresolved {
-                // Log unresolved hashes for debugging - these are files in WAD but not in hashtable
-                skipped_unknown += 1;
-                if skipped_unknown <= 5 {
-                    tracing::debug!("Unresolved hash in WAD: {:016x}", path_hash);
-                }
+        if path_lower.starts_with("assets/") || path_lower.starts_with("data/") {
+            if !matcher.matches(&path_lower) {
+                filtered_count += 1;
+                continue;
+            }
+            wanted.push((path_hash, chunk, resolved_path));
+        } else if resolved_path.chars().all(|c| c.is_ascii_hexdigit()) {
+            // Files in the WAD but not in the hashtable
+            skipped_unknown += 1;
+            if skipped_unknown <= 5 {
+                tracing::debug!("Unresolved hash in WAD: {:016x}", path_hash);
             }
-            continue;
         }
-        
-        // Decompress the chunk data
-        let chunk_data = match decoder.load_chunk_decompressed(chunk) {
-            Ok(data) => data,
-            Err(e) => {
-                tracing::warn!("Failed to decompress chunk '{}': {}", resolved_path, e);
-                continue;
+    }
+    
+    let total = wanted.len();
+    let done = AtomicUsize::new(0);
+    let report = |item: &str| {
+        let current = done.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(cb) = on_progress {
+            cb(ExtractionProgress { current, total, item: item.to_string() });
+        }
+    };
+    
+    for batch in wanted.chunks(EXTRACT_BATCH) {
+        if cancel.is_cancelled() {
+            // The checkpoint is kept, so the project can pick up from here
+            if let Some(path) = checkpoint_path {
+                checkpoint.save(path)?;
             }
-        };
+            tracing::info!("Extraction cancelled after {} chunks", extracted_count);
+            return Err(Error::Cancelled);
+        }
         
-        // Resolve the final chunk path with extension handling
-        let final_path = resolve_chunk_path(&resolved_path, &chunk_data);
-        // Check if filename is too long (Windows path limit issues)
-        let filename_len = final_path.to_string_lossy().len();
-        let output_path_to_use = if filename_len > 200 {
-            // Use hex hash for very long filenames
-            let parent = final_path.parent().unwrap_or(Path::new("data"));
-            let ext = final_path.extension().and_then(|e| e.to_str()).unwrap_or("bin");
-            let hash_name = format!("{:016x}.{}", path_hash, ext);
-            let hash_path = parent.join(&hash_name);
-            tracing::info!("Using hash for long filename: {} -> {}", final_path.display(), hash_path.display());
-            
-            // Record the mapping so refather can find the file
-            let original_normalized = final_path.to_string_lossy().to_lowercase().replace('\\', "/");
-            let actual_normalized = hash_path.to_string_lossy().to_lowercase().replace('\\', "/");
-            path_mappings.insert(original_normalized, actual_normalized);
-            
-            wad_output_dir.join(&hash_path)
-        } else {
-            wad_output_dir.join(&final_path)
-        };
+        // Read sequentially from the one file handle. Chunks an earlier run
+        // finished are read later, and only if their file turns out damaged.
+        let mut raw = Vec::with_capacity(batch.len());
+        for (_, chunk, resolved_path) in batch {
+            let resumed = checkpoint.completed_path(chunk).map(Path::to_path_buf);
+            let data = match resumed {
+                Some(_) => None,
+                None => match decoder.load_chunk_raw(chunk) {
+                    Ok(data) => Some(data),
+                    Err(e) => {
+                        tracing::warn!("Failed to read chunk '{}': {}", resolved_path, e);
+                        report(resolved_path);
+                        continue;
+                    }
+                },
+            };
+            raw.push((*chunk, resolved_path.as_str(), resumed, data));
+        }
         
-        // Create parent directories
-        if let Some(parent) = output_path_to_use.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                tracing::error!("Failed to create directory '{}': {}", parent.display(), e);
-                continue;
-            }
+        let decoded: Vec<_> = raw
+            .into_par_iter()
+            .filter_map(|(chunk, resolved_path, resumed, data)| {
+                let data = match data.map(|data| decompress_chunk(chunk, &data)).transpose() {
+                    Ok(data) => data,
+                    Err(e) => {
+                        tracing::warn!("Failed to decompress chunk '{}': {}", resolved_path, e);
+                        report(resolved_path);
+                        return None;
+                    }
+                };
+                Some((chunk, resolved_path, resumed, data))
+            })
+            .collect();
+        
+        // Placement is claimed in chunk order, so collisions and path
+        // mappings come out the same on every run
+        let mut placed = Vec::with_capacity(decoded.len());
+        for (chunk, resolved_path, resumed, data) in decoded {
+            let (final_path, placement, data) = match (resumed, data) {
+                (Some(path), _) => {
+                    let placement = place_chunk(path.clone(), chunk.path_hash, &mut claims, &mut path_mappings, &mut warnings);
+                    let on_disk = fs::metadata(wad_output_dir.join(&placement)).map(|m| m.len()).ok();
+                    if on_disk == Some(chunk.uncompressed_size as u64) {
+                        skipped_count += 1;
+                        report(resolved_path);
+                        continue;
+                    }
+                    match decoder.load_chunk_decompressed(chunk) {
+                        Ok(data) => (path, placement, data.into_vec()),
+                        Err(e) => {
+                            tracing::warn!("Failed to decompress chunk '{}': {}", resolved_path, e);
+                            report(resolved_path);
+                            continue;
+                        }
+                    }
+                }
+                (None, Some(data)) => {
+                    let final_path = resolve_chunk_path(resolved_path, &data);
+                    let placement = place_chunk(final_path.clone(), chunk.path_hash, &mut claims, &mut path_mappings, &mut warnings);
+                    (final_path, placement, data)
+                }
+                (None, None) => continue,
+            };
+            record_suffixed(resolved_path, &placement, &mut path_mappings);
+            placed.push((chunk, resolved_path, final_path, wad_output_dir.join(placement), data));
         }
         
-        // Write the chunk data
-        match fs::write(&output_path_to_use, &chunk_data) {
-            Ok(_) => {
-                extracted_count += 1;
-                if extracted_count % 100 == 0 {
-                    tracing::info!("Extracted {}/{} chunks", extracted_count, total_chunks);
+        let written: Vec<_> = placed
+            .into_par_iter()
+            .filter_map(|(chunk, resolved_path, final_path, output_path, data)| {
+                let written = write_chunk(&output_path, &data, store);
+                report(resolved_path);
+                match written {
+                    Ok(()) => Some((chunk, final_path)),
+                    Err(e) => {
+                        tracing::warn!("Failed to write '{}': {}", output_path.display(), e);
+                        None
+                    }
                 }
+            })
+            .collect();
+        
+        extracted_count += written.len();
+        for (chunk, final_path) in written {
+            checkpoint.record(chunk, final_path);
+        }
+        if let Some(path) = checkpoint_path {
+            if let Err(e) = checkpoint.save(path) {
+                tracing::warn!("Failed to save extraction checkpoint: {}", e);
             }
-            Err(e) => {
-                tracing::warn!("Failed to write '{}': {}", output_path_to_use.display(), e);
+        }
+        tracing::debug!("Extracted {}/{} chunks", done.load(Ordering::Relaxed), total);
+    }
+    
+    // The run finished, nothing left to resume
+    if let Some(path) = checkpoint_path {
+        if let Err(e) = fs::remove_file(path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("Failed to remove extraction checkpoint {}: {}", path.display(), e);
             }
         }
     }
@@ -376,6 +834,14 @@ pub fn extract_skin_assets(
         );
     }
     
+    if skipped_count > 0 {
+        tracing::info!("Skipped {} chunks already extracted by an earlier run", skipped_count);
+    }
+    
+    if filtered_count > 0 {
+        tracing::info!("Left out {} chunks not matching the extraction filter", filtered_count);
+    }
+    
     tracing::info!(
         "Extracted {}/{} chunks (with {} path mappings)",
         extracted_count, total_chunks, path_mappings.len()
@@ -384,9 +850,143 @@ pub fn extract_skin_assets(
     Ok(ExtractionResult {
         extracted_count,
         path_mappings,
+        warnings,
+        skipped_count,
+        filtered_count,
     })
 }
 
+/// Writes one extracted chunk, through the asset store if there is one
+fn write_chunk(output_path: &Path, data: &[u8], store: Option<&AssetStore>) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+    match store {
+        Some(store) => store.place(data, output_path).map(|_| ()),
+        None => write_replacing(output_path, data).map_err(|e| Error::io_with_path(e, output_path)),
+    }
+}
+
+/// Result of merging an external WAD into a project
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WadImportResult {
+    /// WAD folder the chunks were merged into (e.g. "ahri.wad.client")
+    pub wad_folder: String,
+    /// Number of chunks written
+    pub imported_count: usize,
+    /// Number of chunks that replaced a file already in the project
+    pub replaced_count: usize,
+    /// Mapping of original paths to actual paths (renamed or shortened chunks)
+    pub path_mappings: HashMap<String, String>,
+    /// Chunks that couldn't be imported or had to be renamed
+    pub warnings: Vec<String>,
+}
+
+/// Returns the `{name}.wad.client` folder name for a WAD file
+///
+/// Accepts `.wad.client` and bare `.wad` archives, lowercased like the
+/// folders extraction creates.
+pub fn wad_folder_name(wad_path: &Path) -> Result<String> {
+    let name = wad_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    if name.ends_with(".wad.client") {
+        Ok(name)
+    } else if name.ends_with(".wad") {
+        Ok(format!("{}.client", name))
+    } else {
+        Err(Error::InvalidInput(format!(
+            "Not a WAD archive: {}",
+            wad_path.display()
+        )))
+    }
+}
+
+/// Merges every chunk of a WAD into a WAD folder of a project
+///
+/// Unlike skin extraction nothing is filtered: chunks from another mod are
+/// all part of that mod. Existing files are overwritten, since importing is
+/// how a user brings the other mod's version of an asset in. Unresolved
+/// hashes are written under their hex name.
+///
+/// # Arguments
+/// * `wad` - Mutable reference to the Wad for decoding
+/// * `wad_output_dir` - The `{name}.wad.client` folder inside a project layer
+/// * `hashtable` - Optional hashtable for path resolution
+pub fn import_wad(
+    wad: &mut Wad<File>,
+    wad_output_dir: impl AsRef<Path>,
+    hashtable: Option<&Hashtable>,
+) -> Result<WadImportResult> {
+    let wad_output_dir = wad_output_dir.as_ref();
+    let wad_folder = wad_output_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    tracing::info!("Importing WAD chunks into: {}", wad_output_dir.display());
+
+    let (mut decoder, chunks) = wad.decode();
+    let mut result = WadImportResult {
+        wad_folder,
+        imported_count: 0,
+        replaced_count: 0,
+        path_mappings: HashMap::new(),
+        warnings: Vec::new(),
+    };
+    let mut claims = PathClaims::default();
+
+    let ordered = extraction_order(chunks, |path_hash| match hashtable {
+        Some(ht) => ht.resolve(path_hash).to_string(),
+        None => format!("{:016x}", path_hash),
+    });
+    for (path_hash, chunk, resolved_path) in ordered {
+        let chunk_data = match decoder.load_chunk_decompressed(chunk) {
+            Ok(data) => data,
+            Err(e) => {
+                let warning = format!("Failed to decompress '{}': {}", resolved_path, e);
+                tracing::warn!("{}", warning);
+                result.warnings.push(warning);
+                continue;
+            }
+        };
+
+        let final_path = resolve_chunk_path(&resolved_path, &chunk_data);
+        let relative = place_chunk(
+            final_path,
+            path_hash,
+            &mut claims,
+            &mut result.path_mappings,
+            &mut result.warnings,
+        );
+        record_suffixed(&resolved_path, &relative, &mut result.path_mappings);
+        let output_path = wad_output_dir.join(&relative);
+
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        let existed = output_path.exists();
+        write_replacing(&output_path, &chunk_data).map_err(|e| Error::io_with_path(e, &output_path))?;
+
+        result.imported_count += 1;
+        if existed {
+            result.replaced_count += 1;
+        }
+    }
+
+    tracing::info!(
+        "Imported {} chunks ({} replaced existing files, {} path mappings)",
+        result.imported_count,
+        result.replaced_count,
+        result.path_mappings.len()
+    );
+
+    Ok(result)
+}
+
 /// Resolves the final chunk path by handling extensions
 /// 
 /// This function:
@@ -480,4 +1080,185 @@ mod tests {
         // Should add .ltk extension to hex path
         assert!(resolved.to_string_lossy().contains(".ltk"));
     }
+    
+    #[test]
+    fn test_strip_ltk_suffix() {
+        assert_eq!(strip_ltk_suffix("assets/ahri/foo.ltk"), "assets/ahri/foo");
+        assert_eq!(strip_ltk_suffix("assets/ahri/foo.ltk.dds"), "assets/ahri/foo");
+        assert_eq!(strip_ltk_suffix("assets/ahri/foo.dds"), "assets/ahri/foo.dds");
+        assert_eq!(strip_ltk_suffix("assets/a.ltk/foo.dds"), "assets/a.ltk/foo.dds");
+        assert_eq!(strip_ltk_suffix("assets/ahri/foo.ltkx"), "assets/ahri/foo.ltkx");
+
+        let mut mappings = HashMap::new();
+        let path = "assets/ahri/Foo";
+        let placed = resolve_chunk_path(path, &[0u8; 16]);
+        record_suffixed(path, &placed, &mut mappings);
+        record_suffixed("1a2b3c4d5e6f7a8b", Path::new("1a2b3c4d5e6f7a8b.ltk"), &mut mappings);
+        record_suffixed("assets/ahri/foo.dds", Path::new("assets/ahri/foo.dds"), &mut mappings);
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings["assets/ahri/foo"], "assets/ahri/foo.ltk");
+    }
+
+    #[test]
+    fn test_path_claims_disambiguate_case_collisions() {
+        let mut claims = PathClaims::default();
+        let (first, owner) = claims.claim(Path::new("data/Skin0.bin"), 1);
+        assert_eq!(first, PathBuf::from("data/Skin0.bin"));
+        assert_eq!(owner, None);
+        
+        let (second, owner) = claims.claim(Path::new("data/skin0.bin"), 0xab);
+        assert_eq!(second, PathBuf::from("data/skin0.00000000000000ab.bin"));
+        assert_eq!(owner, Some(1));
+    }
+    
+    #[test]
+    fn test_place_chunk_maps_collisions_by_original_path() {
+        let mut claims = PathClaims::default();
+        let mut mappings = HashMap::new();
+        let mut warnings = Vec::new();
+        for (path, path_hash) in [("data/skin0.bin", 1), ("DATA/Skin0.bin", 0xab), ("data/skin0.bin", 0xcd)] {
+            place_chunk(PathBuf::from(path), path_hash, &mut claims, &mut mappings, &mut warnings);
+        }
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings["DATA/Skin0.bin"], "data/skin0.00000000000000ab.bin");
+    }
+    
+    #[test]
+    fn test_import_wad_keeps_store_objects() {
+        use crate::core::wad::test_util::write_wad;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = AssetStore::new(dir.path().join("store"));
+        let path = "assets/characters/ahri/ahri.dds";
+        let project_file = dir.path().join("project/ahri.wad.client").join(path);
+        let other_project_file = dir.path().join("other/ahri.wad.client").join(path);
+        for file in [&project_file, &other_project_file] {
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            store.place(b"vanilla texture", file).unwrap();
+        }
+
+        let wad_path = dir.path().join("mod.wad.client");
+        write_wad(&wad_path, &[(path, b"modded texture")]);
+        let hash_dir = dir.path().join("hashes");
+        fs::create_dir_all(&hash_dir).unwrap();
+        fs::write(hash_dir.join("hashes.game.txt"), format!("{:016x} {}\n", xxh64(path.as_bytes(), 0), path)).unwrap();
+        let hashtable = Hashtable::from_directory(&hash_dir).unwrap();
+
+        let mut wad = Wad::mount(File::open(&wad_path).unwrap()).unwrap();
+        let result = import_wad(&mut wad, dir.path().join("project/ahri.wad.client"), Some(&hashtable)).unwrap();
+
+        assert_eq!(result.replaced_count, 1);
+        assert_eq!(fs::read(&project_file).unwrap(), b"modded texture");
+        assert_eq!(fs::read(&other_project_file).unwrap(), b"vanilla texture");
+    }
+    
+    #[test]
+    fn test_wad_folder_name() {
+        assert_eq!(wad_folder_name(Path::new("mods/Ahri.wad.client")).unwrap(), "ahri.wad.client");
+        assert_eq!(wad_folder_name(Path::new("Map11.wad")).unwrap(), "map11.wad.client");
+        assert!(wad_folder_name(Path::new("ahri.fantome")).is_err());
+    }
+    
+    #[test]
+    fn test_extraction_order_prefers_genuine_hash() {
+        let path = "data/skin0.bin";
+        let genuine = xxh64(path.as_bytes(), 0);
+        let mut chunks = HashMap::new();
+        for path_hash in [0, genuine] {
+            chunks.insert(path_hash, WadChunk {
+                path_hash,
+                data_offset: 0,
+                compressed_size: 0,
+                uncompressed_size: 0,
+                compression_type: league_toolkit::wad::WadChunkCompression::None,
+                is_duplicated: false,
+                frame_count: 0,
+                start_frame: 0,
+                checksum: 0,
+            });
+        }
+        
+        let ordered = extraction_order(&chunks, |_| path.to_string());
+        let hashes: Vec<u64> = ordered.iter().map(|(hash, _, _)| *hash).collect();
+        assert_eq!(hashes, vec![genuine, 0]);
+    }
+
+    #[test]
+    fn test_extract_skin_assets_resumes_from_checkpoint() {
+        use crate::core::wad::test_util::write_wad;
+
+        let dir = tempfile::tempdir().unwrap();
+        let files: Vec<(&str, Vec<u8>)> = vec![
+            ("data/characters/ahri/skins/skin0.bin", vec![1u8; 64]),
+            ("assets/characters/ahri/skins/base/ahri.dds", vec![2u8; 128]),
+            ("assets/characters/ahri/skins/base/ahri.skn", vec![3u8; 32]),
+        ];
+
+        let wad_path = dir.path().join("Ahri.wad.client");
+        write_wad(&wad_path, &files);
+
+        let hash_dir = dir.path().join("hashes");
+        fs::create_dir_all(&hash_dir).unwrap();
+        let hash_lines: String = files
+            .iter()
+            .map(|(name, _)| format!("{:016x} {}\n", xxh64(name.as_bytes(), 0), name))
+            .collect();
+        fs::write(hash_dir.join("hashes.game.txt"), hash_lines).unwrap();
+        let hashtable = Hashtable::from_directory(&hash_dir).unwrap();
+
+        // Pretend an earlier run wrote the first two chunks, but the second
+        // file was cut short when it died
+        let output = dir.path().join("content");
+        let wad_dir = output.join("ahri.wad.client");
+        let mut wad = Wad::mount(File::open(&wad_path).unwrap()).unwrap();
+        let mut checkpoint = ExtractionCheckpoint::default();
+        for (name, data) in &files[..2] {
+            let chunk = &wad.chunks()[&xxh64(name.as_bytes(), 0)];
+            checkpoint.record(chunk, PathBuf::from(name));
+            fs::create_dir_all(wad_dir.join(name).parent().unwrap()).unwrap();
+            fs::write(wad_dir.join(name), data).unwrap();
+        }
+        fs::write(wad_dir.join(files[1].0), [2u8; 10]).unwrap();
+        let checkpoint_path = dir.path().join(".flint").join(EXTRACTION_CHECKPOINT_FILE);
+        checkpoint.save(&checkpoint_path).unwrap();
+
+        // Cancelling stops before any chunk and leaves the checkpoint in place
+        let requests = crate::state::RequestState::default();
+        let request = requests.begin(Some("extract".to_string()));
+        requests.cancel("extract");
+        let everything = ExtractionFilter::default();
+        let cancelled = extract_skin_assets(
+            &mut wad, &output, "Ahri", 0, &hashtable, Some(&checkpoint_path), None, &everything, &request.token(), None,
+        );
+        assert!(matches!(cancelled, Err(Error::Cancelled)));
+        assert!(checkpoint_path.exists());
+        assert_eq!(fs::read(wad_dir.join(files[1].0)).unwrap().len(), 10);
+
+        let updates = std::sync::Mutex::new(Vec::new());
+        let on_progress = |p: ExtractionProgress| updates.lock().unwrap().push(p.current);
+        let result = extract_skin_assets(
+            &mut wad,
+            &output,
+            "Ahri",
+            0,
+            &hashtable,
+            Some(&checkpoint_path),
+            None,
+            &everything,
+            &CancelToken::default(),
+            Some(&on_progress),
+        )
+        .unwrap();
+
+        let mut updates = updates.into_inner().unwrap();
+        updates.sort();
+        assert_eq!(updates, vec![1, 2, 3]);
+        assert_eq!(result.skipped_count, 1);
+        assert_eq!(result.extracted_count, 2);
+        assert_eq!(fs::read(wad_dir.join(files[1].0)).unwrap(), files[1].1);
+        assert_eq!(fs::read(wad_dir.join(files[2].0)).unwrap(), files[2].1);
+        assert!(!checkpoint_path.exists());
+    }
 }
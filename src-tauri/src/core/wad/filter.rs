@@ -0,0 +1,179 @@
+//! Choosing which chunks an extraction writes
+//!
+//! Extraction normally takes everything under `assets/` and `data/` and leaves
+//! cleanup to the repath pass. Authors who only retexture a skin don't need
+//! the animations, meshes and other skins' files of a champion WAD, so a
+//! filter can narrow it down by skin, by kind of file and by path glob.
+//! Everything is decided from the resolved path, before a chunk is read.
+
+use crate::error::{Error, Result};
+use glob::{MatchOptions, Pattern};
+use league_toolkit::file::LeagueFileKind;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Broad kind of a WAD file, by extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractFileKind {
+    Texture,
+    Bin,
+    Mesh,
+    Animation,
+    Audio,
+    Other,
+}
+
+impl ExtractFileKind {
+    pub fn of_path(path: &str) -> Self {
+        let extension = path.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+        match LeagueFileKind::from_extension(extension.to_ascii_lowercase()) {
+            LeagueFileKind::Texture
+            | LeagueFileKind::TextureDds
+            | LeagueFileKind::Png
+            | LeagueFileKind::Jpeg
+            | LeagueFileKind::Tga
+            | LeagueFileKind::Svg => Self::Texture,
+            LeagueFileKind::PropertyBin | LeagueFileKind::PropertyBinOverride => Self::Bin,
+            LeagueFileKind::SimpleSkin
+            | LeagueFileKind::Skeleton
+            | LeagueFileKind::StaticMeshAscii
+            | LeagueFileKind::StaticMeshBinary
+            | LeagueFileKind::MapGeometry
+            | LeagueFileKind::WorldGeometry => Self::Mesh,
+            LeagueFileKind::Animation => Self::Animation,
+            LeagueFileKind::WwiseBank | LeagueFileKind::WwisePackage => Self::Audio,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Which chunks an extraction should write
+///
+/// The default keeps everything. Leaving BINs out means repath has no skin
+/// BIN to work from, so the project keeps the game's paths.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(default)]
+pub struct ExtractionFilter {
+    /// Drop files that belong to other skins (`skins/skinN/...`, `skinN.bin`);
+    /// base skin files are always kept since other skins build on them
+    pub skin_id: Option<u32>,
+    /// Kinds of file to keep, all if empty
+    pub kinds: Vec<ExtractFileKind>,
+    /// Globs a path must match one of (`assets/**/*.dds`), all if empty
+    pub include: Vec<String>,
+    /// Globs of paths to leave out, applied after `include`
+    pub exclude: Vec<String>,
+}
+
+impl ExtractionFilter {
+    pub fn is_empty(&self) -> bool {
+        self.skin_id.is_none() && self.kinds.is_empty() && self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Compiles the globs, failing on one that doesn't parse
+    pub fn matcher(&self) -> Result<FilterMatcher<'_>> {
+        let compile = |globs: &[String]| {
+            globs
+                .iter()
+                .map(|glob| {
+                    Pattern::new(&glob.replace('\\', "/"))
+                        .map_err(|e| Error::InvalidInput(format!("Invalid path filter '{}': {}", glob, e)))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(FilterMatcher {
+            filter: self,
+            include: compile(&self.include)?,
+            exclude: compile(&self.exclude)?,
+        })
+    }
+}
+
+/// An [`ExtractionFilter`] ready to test paths against
+pub struct FilterMatcher<'a> {
+    filter: &'a ExtractionFilter,
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+impl FilterMatcher<'_> {
+    /// True if the chunk at `path` (a resolved WAD path) should be extracted
+    pub fn matches(&self, path: &str) -> bool {
+        let path = path.replace('\\', "/");
+        if let Some(skin_id) = self.filter.skin_id {
+            if skin_number(&path).is_some_and(|n| n != 0 && n != skin_id) {
+                return false;
+            }
+        }
+        if !self.filter.kinds.is_empty() && !self.filter.kinds.contains(&ExtractFileKind::of_path(&path)) {
+            return false;
+        }
+        if !self.include.is_empty() && !self.include.iter().any(|p| p.matches_with(&path, MATCH_OPTIONS)) {
+            return false;
+        }
+        !self.exclude.iter().any(|p| p.matches_with(&path, MATCH_OPTIONS))
+    }
+}
+
+/// Skin a path belongs to, from a `skinN` folder or `skinN.bin` file
+fn skin_number(path: &str) -> Option<u32> {
+    path.split('/').find_map(|segment| {
+        let segment = segment.to_ascii_lowercase();
+        let digits = segment.strip_prefix("skin")?;
+        let digits = digits.strip_suffix(".bin").unwrap_or(digits);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_matches() {
+        let everything = ExtractionFilter::default();
+        assert!(everything.is_empty());
+        assert!(everything.matcher().unwrap().matches("assets/characters/ahri/skins/skin03/ahri.skn"));
+
+        let filter = ExtractionFilter {
+            skin_id: Some(3),
+            kinds: vec![ExtractFileKind::Texture, ExtractFileKind::Bin],
+            include: vec![],
+            exclude: vec!["assets/**/particles/**".to_string()],
+        };
+        let matcher = filter.matcher().unwrap();
+        assert!(matcher.matches("assets/characters/ahri/skins/base/ahri_base_tx_cm.dds"));
+        assert!(matcher.matches("ASSETS/Characters/Ahri/Skins/Skin03/Ahri_Skin03_TX_CM.tex"));
+        assert!(matcher.matches("data/characters/ahri/skins/skin3.bin"));
+        assert!(matcher.matches("data/characters/ahri/skins/skin0.bin"));
+        assert!(!matcher.matches("data/characters/ahri/skins/skin5.bin"));
+        assert!(!matcher.matches("assets/characters/ahri/skins/skin05/ahri_skin05_tx_cm.dds"));
+        assert!(!matcher.matches("assets/characters/ahri/skins/base/ahri.skn"));
+        assert!(!matcher.matches("assets/characters/ahri/skins/base/particles/ahri_base_q.dds"));
+        // "skins" and "skinned" aren't skin folders
+        assert!(matcher.matches("assets/shared/skinned/skinline.dds"));
+
+        let textures = ExtractionFilter {
+            include: vec!["assets/characters/*/skins/base/*.dds".to_string()],
+            ..Default::default()
+        };
+        let matcher = textures.matcher().unwrap();
+        assert!(matcher.matches("assets/characters/ahri/skins/base/ahri.dds"));
+        assert!(!matcher.matches("assets/characters/ahri/skins/base/nested/ahri.dds"));
+
+        let invalid = ExtractionFilter { include: vec!["assets/[".to_string()], ..Default::default() };
+        assert!(invalid.matcher().is_err());
+    }
+}
@@ -0,0 +1,287 @@
+//! Guessing what a loose WAD file is for
+//!
+//! A WAD dropped onto the app may not come from a League installation, so
+//! there is no folder layout to go by. The file name usually gives it away
+//! (`Ahri.wad.client`, `Map11.wad.client`, `UI.en_US.wad.client`), and the
+//! chunks confirm it: champion WADs hold `data/characters/{name}/{name}.bin`
+//! and skin BINs at known paths, which can be probed by hash without a
+//! hashtable. With a hashtable, a sample of resolved paths covers renamed
+//! files.
+
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::wad::reader::WadReader;
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use ts_rs::TS;
+
+/// Chunks resolved when sampling paths
+const PATH_SAMPLE_LIMIT: usize = 8192;
+
+/// Highest skin number probed for skin BINs
+const MAX_SKIN_ID: u32 = 999;
+
+/// WAD names that hold data shared by every game mode
+const GLOBAL_WADS: &[&str] = &["common", "global", "shaders", "localized"];
+
+/// What a WAD holds data for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum WadTarget {
+    Champion,
+    Map,
+    Ui,
+    Global,
+    Unknown,
+}
+
+/// The inferred target of a WAD file
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WadIdentity {
+    pub target: WadTarget,
+    /// Champion internal name (lowercase, as in `data/characters/`) or map
+    /// name (`map11`)
+    pub name: Option<String>,
+    /// Locale of a localized WAD (e.g. "en_US")
+    pub locale: Option<String>,
+    /// Skins with a skin BIN in the WAD, for champion WADs
+    pub skin_ids: Vec<u32>,
+    /// The file name pointed at the target
+    pub from_file_name: bool,
+    /// Chunk paths pointed at the target
+    pub from_chunk_paths: bool,
+    pub chunk_count: usize,
+    /// Sampled chunks whose path the hashtable resolved
+    pub resolved_sampled: usize,
+}
+
+/// Splits a WAD file name into its lowercase stem and locale
+///
+/// `Ahri.wad.client` gives ("ahri", None), `UI.en_US.wad.client` gives
+/// ("ui", Some("en_US")).
+fn split_file_name(name: &str) -> (String, Option<String>) {
+    let lower = name.to_lowercase();
+    let stem_len = lower
+        .strip_suffix(".wad.client")
+        .or_else(|| lower.strip_suffix(".wad"))
+        .map(str::len)
+        .unwrap_or(lower.len());
+    let stem = &name[..stem_len];
+
+    match stem.split_once('.') {
+        Some((base, locale)) => (base.to_lowercase(), Some(locale.to_string())),
+        None => (stem.to_lowercase(), None),
+    }
+}
+
+/// Map name at the start of a WAD stem ("map11levels" -> "map11")
+fn map_name(stem: &str) -> Option<String> {
+    let digits = stem.strip_prefix("map")?;
+    let len = digits.chars().take_while(char::is_ascii_digit).count();
+    (len > 0).then(|| format!("map{}", &digits[..len]))
+}
+
+/// Folder after `characters/` in a `data/` or `assets/` path
+fn character_folder(path: &str) -> Option<&str> {
+    let rest = path
+        .strip_prefix("data/characters/")
+        .or_else(|| path.strip_prefix("assets/characters/"))?;
+    rest.split('/').next().filter(|name| !name.is_empty())
+}
+
+fn is_map_path(path: &str) -> bool {
+    path.starts_with("data/maps/") || path.starts_with("assets/maps/") || path.starts_with("levels/")
+}
+
+fn is_ui_path(path: &str) -> bool {
+    path.starts_with("assets/ux/") || path.starts_with("clientstates/") || path.starts_with("ux/")
+}
+
+/// Counts of what the resolved chunk paths point at
+#[derive(Default)]
+struct PathSample {
+    resolved: usize,
+    characters: HashMap<String, usize>,
+    maps: usize,
+    ui: usize,
+}
+
+impl PathSample {
+    fn collect(reader: &WadReader, hashtable: &Hashtable) -> Self {
+        let mut sample = Self::default();
+        for hash in reader.chunks().keys().take(PATH_SAMPLE_LIMIT) {
+            let Some(path) = hashtable.lookup(*hash, HashVariant::Xxh64) else {
+                continue;
+            };
+            let path = path.to_lowercase().replace('\\', "/");
+            sample.resolved += 1;
+            if let Some(name) = character_folder(&path) {
+                *sample.characters.entry(name.to_string()).or_default() += 1;
+            } else if is_map_path(&path) {
+                sample.maps += 1;
+            } else if is_ui_path(&path) {
+                sample.ui += 1;
+            }
+        }
+        sample
+    }
+
+    /// Character folder with the most chunks
+    fn top_character(&self) -> Option<&str> {
+        self.characters
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+fn has_path(reader: &WadReader, path: &str) -> bool {
+    reader.get_chunk(HashVariant::Xxh64.hash(path)).is_some()
+}
+
+/// Skin numbers with a `data/characters/{champion}/skins/skin{n}.bin` chunk
+fn probe_skin_ids(reader: &WadReader, champion: &str) -> Vec<u32> {
+    (0..=MAX_SKIN_ID)
+        .filter(|id| has_path(reader, &format!("data/characters/{0}/skins/skin{1}.bin", champion, id)))
+        .collect()
+}
+
+/// Whether the WAD holds a champion's root or skin BINs
+fn has_champion_bins(reader: &WadReader, champion: &str, skin_ids: &[u32]) -> bool {
+    !skin_ids.is_empty() || has_path(reader, &format!("data/characters/{0}/{0}.bin", champion))
+}
+
+/// Infers the champion, map or other target of a WAD file
+///
+/// # Arguments
+/// * `path` - Path to the WAD file
+/// * `hashtable` - Resolves a sample of chunk paths; without it only the
+///   file name and probed champion paths are used
+pub fn identify_wad(path: &Path, hashtable: Option<&Hashtable>) -> Result<WadIdentity> {
+    let reader = WadReader::open(path)?;
+    let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let (stem, locale) = split_file_name(&file_name);
+    let sample = hashtable.map(|ht| PathSample::collect(&reader, ht)).unwrap_or_default();
+
+    let mut identity = WadIdentity {
+        target: WadTarget::Unknown,
+        name: None,
+        locale,
+        skin_ids: Vec::new(),
+        from_file_name: false,
+        from_chunk_paths: false,
+        chunk_count: reader.chunk_count(),
+        resolved_sampled: sample.resolved,
+    };
+
+    // Non-champion WADs are named after what they hold
+    if stem == "ui" {
+        identity.target = WadTarget::Ui;
+        identity.from_file_name = true;
+        identity.from_chunk_paths = sample.ui > 0;
+        return Ok(identity);
+    }
+    if let Some(map) = map_name(&stem) {
+        identity.target = WadTarget::Map;
+        identity.name = Some(map);
+        identity.from_file_name = true;
+        identity.from_chunk_paths = sample.maps > 0;
+        return Ok(identity);
+    }
+    if GLOBAL_WADS.contains(&stem.as_str()) {
+        identity.target = WadTarget::Global;
+        identity.from_file_name = true;
+        return Ok(identity);
+    }
+
+    // Champion WADs are named after the champion's internal name
+    let skin_ids = probe_skin_ids(&reader, &stem);
+    if has_champion_bins(&reader, &stem, &skin_ids) {
+        identity.target = WadTarget::Champion;
+        identity.name = Some(stem);
+        identity.skin_ids = skin_ids;
+        identity.from_file_name = true;
+        identity.from_chunk_paths = true;
+        return Ok(identity);
+    }
+
+    // Renamed file: go by the chunk paths alone
+    if let Some(champion) = sample.top_character() {
+        identity.skin_ids = probe_skin_ids(&reader, champion);
+        identity.target = WadTarget::Champion;
+        identity.name = Some(champion.to_string());
+        identity.from_chunk_paths = true;
+    } else if sample.maps > 0 && sample.maps >= sample.ui {
+        identity.target = WadTarget::Map;
+        identity.from_chunk_paths = true;
+    } else if sample.ui > 0 {
+        identity.target = WadTarget::Ui;
+        identity.from_chunk_paths = true;
+    }
+
+    Ok(identity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad_filled;
+    use std::fs;
+
+    #[test]
+    fn test_split_file_name() {
+        assert_eq!(split_file_name("Ahri.wad.client"), ("ahri".to_string(), None));
+        assert_eq!(split_file_name("UI.en_US.wad.client"), ("ui".to_string(), Some("en_US".to_string())));
+        assert_eq!(map_name("map11levels"), Some("map11".to_string()));
+        assert_eq!(map_name("mapleader"), None);
+    }
+
+    #[test]
+    fn test_identify_wad() {
+        let dir = tempfile::tempdir().unwrap();
+        let chunks = [
+            "data/characters/ahri/ahri.bin",
+            "data/characters/ahri/skins/skin0.bin",
+            "data/characters/ahri/skins/skin14.bin",
+            "assets/characters/ahri/skins/base/ahri.skn",
+        ];
+
+        // Named after the champion: no hashtable needed
+        let named = dir.path().join("Ahri.wad.client");
+        write_wad_filled(&named, &chunks, b"data");
+        let identity = identify_wad(&named, None).unwrap();
+        assert_eq!(identity.target, WadTarget::Champion);
+        assert_eq!(identity.name.as_deref(), Some("ahri"));
+        assert_eq!(identity.skin_ids, vec![0, 14]);
+        assert!(identity.from_file_name && identity.from_chunk_paths);
+
+        // Renamed: found through the resolved paths
+        let renamed = dir.path().join("my_mod.wad.client");
+        write_wad_filled(&renamed, &chunks, b"data");
+        assert_eq!(identify_wad(&renamed, None).unwrap().target, WadTarget::Unknown);
+
+        let hashes = dir.path().join("hashes");
+        fs::create_dir_all(&hashes).unwrap();
+        let lines: String = chunks
+            .iter()
+            .map(|p| format!("{:016x} {}\n", HashVariant::Xxh64.hash(p), p))
+            .collect();
+        fs::write(hashes.join("hashes.game.txt"), lines).unwrap();
+        let hashtable = Hashtable::from_directory(&hashes).unwrap();
+
+        let identity = identify_wad(&renamed, Some(&hashtable)).unwrap();
+        assert_eq!(identity.target, WadTarget::Champion);
+        assert_eq!(identity.name.as_deref(), Some("ahri"));
+        assert_eq!(identity.skin_ids, vec![0, 14]);
+        assert!(!identity.from_file_name);
+        assert_eq!(identity.resolved_sampled, 4);
+
+        let map = dir.path().join("Map11LEVELS.wad.client");
+        write_wad_filled(&map, &["data/maps/mapgeometry/map11/base_srx.mapgeo"], b"data");
+        let identity = identify_wad(&map, None).unwrap();
+        assert_eq!((identity.target, identity.name.as_deref()), (WadTarget::Map, Some("map11")));
+    }
+}
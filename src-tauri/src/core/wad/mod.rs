@@ -1,3 +1,13 @@
 // WAD module exports
 pub mod reader;
 pub mod extractor;
+pub mod explorer;
+pub mod diff;
+pub mod patcher;
+pub mod packer;
+pub mod search_index;
+pub mod ui;
+pub mod identify;
+pub mod filter;
+#[cfg(test)]
+pub(crate) mod test_util;
@@ -0,0 +1,308 @@
+//! Building a WAD archive from a project folder
+//!
+//! The reverse of extraction: every file under a `{name}.wad.client` folder
+//! becomes a chunk keyed by the xxh64 of its lowercased relative path. Files
+//! extraction couldn't name keep the hash they were written under
+//! (`{hash}.ltk.dds`, or `skin0.{hash}.bin` for renamed collisions), and the
+//! `.ltk` suffix given to extension-less chunks is dropped again.
+//!
+//! Chunks are zstd compressed except Wwise audio, which the game streams
+//! uncompressed, and written as a v3.4 WAD with the TOC sorted by hash as
+//! the game requires. The signature is left zeroed like in every mod WAD.
+//!
+//! The writer is the same layout `WadBuilder` produces, but written here
+//! because `WadChunkBuilder` only takes a path and hashes it itself: chunks
+//! kept under their raw hash couldn't be packed. Writing it directly also
+//! lets a pack be cancelled between chunks and report progress.
+
+use crate::core::hash::HashVariant;
+use crate::core::wad::extractor::strip_ltk_suffix;
+use crate::error::{Error, Result};
+use crate::state::CancelToken;
+use league_toolkit::file::LeagueFileKind;
+use league_toolkit::wad::{FileExt, WadChunk, WadChunkCompression};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use ts_rs::TS;
+use walkdir::WalkDir;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Size of the v3 header up to the chunk count (magic, version, signature, checksum)
+const V3_HEADER_SIZE: usize = 4 + 256 + 8;
+/// Size of one v3 TOC entry
+const V3_TOC_ENTRY_SIZE: usize = 32;
+/// zstd level used for chunk data
+const ZSTD_LEVEL: i32 = 3;
+
+/// Result of packing a WAD
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WadPackResult {
+    pub output_path: String,
+    pub chunk_count: usize,
+    /// Uncompressed size of all chunks
+    #[ts(type = "number")]
+    pub total_size: u64,
+    /// Size of the written WAD
+    #[ts(type = "number")]
+    pub packed_size: u64,
+    /// Files left out, e.g. a second file for the same chunk
+    pub warnings: Vec<String>,
+}
+
+/// A 16-digit hex segment of a file name
+fn hash_segment(segment: &str) -> Option<u64> {
+    if segment.len() != 16 {
+        return None;
+    }
+    u64::from_str_radix(segment, 16).ok()
+}
+
+/// Path hash a file in a WAD folder is packed under
+///
+/// # Arguments
+/// * `relative` - Path inside the WAD folder, with `/` separators
+pub fn chunk_hash(relative: &str) -> u64 {
+    let (dir, name) = relative.rsplit_once('/').unwrap_or(("", relative));
+
+    // `{hash}.ext` at the WAD root: a chunk extraction couldn't resolve
+    if dir.is_empty() {
+        if let Some(hash) = name.split('.').next().and_then(hash_segment) {
+            return hash;
+        }
+    }
+    // `name.{hash}.ext`: a chunk renamed because its path collided
+    if let Some(hash) = name.split('.').skip(1).find_map(hash_segment) {
+        return hash;
+    }
+
+    HashVariant::Xxh64.hash(strip_ltk_suffix(relative))
+}
+
+/// Compresses chunk data the way the game expects for its kind
+pub(crate) fn compress(data: &[u8]) -> Result<(Vec<u8>, WadChunkCompression)> {
+    compress_as(data, LeagueFileKind::identify_from_bytes(data).ideal_compression())
+}
+
+/// Compresses chunk data with `compression`, or plain zstd where that can't be produced
+///
+/// Subchunked and satellite data can't be rewritten as such, while plain
+/// zstd is valid for any chunk; the returned compression is what was used.
+pub(crate) fn compress_as(
+    data: &[u8],
+    compression: WadChunkCompression,
+) -> Result<(Vec<u8>, WadChunkCompression)> {
+    match compression {
+        WadChunkCompression::None => Ok((data.to_vec(), WadChunkCompression::None)),
+        WadChunkCompression::GZip => {
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data)?;
+            Ok((encoder.finish()?, WadChunkCompression::GZip))
+        }
+        WadChunkCompression::Zstd | WadChunkCompression::ZstdMulti | WadChunkCompression::Satellite => {
+            Ok((zstd::encode_all(data, ZSTD_LEVEL)?, WadChunkCompression::Zstd))
+        }
+    }
+}
+
+/// Collects the files of a WAD folder by chunk hash
+fn collect_chunks(wad_dir: &Path, warnings: &mut Vec<String>) -> Result<BTreeMap<u64, (String, PathBuf)>> {
+    let mut chunks = BTreeMap::new();
+    for entry in WalkDir::new(wad_dir).sort_by_file_name() {
+        let entry = entry.map_err(|e| Error::InvalidInput(format!("Failed to read {}: {}", wad_dir.display(), e)))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(wad_dir)
+            .unwrap_or(entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+        let hash = chunk_hash(&relative);
+        if let Some((kept, _)) = chunks.get(&hash) {
+            warnings.push(format!(
+                "'{}' and '{}' are the same chunk ({:016x}); only '{}' was packed",
+                kept, relative, hash, kept
+            ));
+            continue;
+        }
+        chunks.insert(hash, (relative, entry.into_path()));
+    }
+    Ok(chunks)
+}
+
+/// Packs a `.wad.client` folder into a WAD archive
+///
+/// # Arguments
+/// * `wad_dir` - Folder whose files become the chunks
+/// * `output_path` - WAD file to write
+/// * `cancel` - Checked between chunks; a cancelled pack leaves no file behind
+/// * `on_progress` - Called with (chunks written, total) after every chunk
+pub fn pack_wad(
+    wad_dir: &Path,
+    output_path: &Path,
+    cancel: &CancelToken,
+    on_progress: Option<&dyn Fn(usize, usize)>,
+) -> Result<WadPackResult> {
+    if !wad_dir.is_dir() {
+        return Err(Error::InvalidInput(format!("WAD folder does not exist: {}", wad_dir.display())));
+    }
+
+    let mut warnings = Vec::new();
+    let chunks = collect_chunks(wad_dir, &mut warnings)?;
+    if chunks.is_empty() {
+        return Err(Error::InvalidInput(format!("{} holds no files to pack", wad_dir.display())));
+    }
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+    let result = write_wad(&chunks, output_path, cancel, on_progress);
+    if result.is_err() {
+        let _ = std::fs::remove_file(output_path);
+    }
+    let total_size = result?;
+
+    let packed_size = std::fs::metadata(output_path).map(|m| m.len()).unwrap_or(0);
+    tracing::info!(
+        "Packed {} chunks from {} into {} ({} bytes)",
+        chunks.len(),
+        wad_dir.display(),
+        output_path.display(),
+        packed_size
+    );
+
+    Ok(WadPackResult {
+        output_path: output_path.display().to_string(),
+        chunk_count: chunks.len(),
+        total_size,
+        packed_size,
+        warnings,
+    })
+}
+
+/// Writes the header, chunk data and TOC; returns the uncompressed size
+fn write_wad(
+    chunks: &BTreeMap<u64, (String, PathBuf)>,
+    output_path: &Path,
+    cancel: &CancelToken,
+    on_progress: Option<&dyn Fn(usize, usize)>,
+) -> Result<u64> {
+    let file = File::create(output_path).map_err(|e| Error::io_with_path(e, output_path))?;
+    let mut writer = BufWriter::new(file);
+
+    // Header with an empty signature, then a TOC placeholder filled in at the end
+    writer.write_all(b"RW")?;
+    writer.write_all(&[3, 4])?;
+    writer.write_all(&[0; V3_HEADER_SIZE - 4])?;
+    writer.write_all(&(chunks.len() as u32).to_le_bytes())?;
+    let toc_offset = writer.stream_position()?;
+    writer.write_all(&vec![0; chunks.len() * V3_TOC_ENTRY_SIZE])?;
+
+    let mut toc = Vec::with_capacity(chunks.len());
+    let mut total_size = 0;
+    for (index, (hash, (_, path))) in chunks.iter().enumerate() {
+        if cancel.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        let data = std::fs::read(path).map_err(|e| Error::io_with_path(e, path))?;
+        let (compressed, compression) = compress(&data)?;
+        let data_offset = writer.stream_position()?;
+        if data_offset + compressed.len() as u64 > u32::MAX as u64 {
+            return Err(Error::wad_with_path("WAD would exceed 4 GiB", output_path));
+        }
+        writer.write_all(&compressed)?;
+
+        toc.push(WadChunk {
+            path_hash: *hash,
+            data_offset: data_offset as usize,
+            compressed_size: compressed.len(),
+            uncompressed_size: data.len(),
+            compression_type: compression,
+            is_duplicated: false,
+            frame_count: 0,
+            start_frame: 0,
+            checksum: xxh3_64(&compressed),
+        });
+        total_size += data.len() as u64;
+        if let Some(on_progress) = on_progress {
+            on_progress(index + 1, chunks.len());
+        }
+    }
+
+    writer.seek(SeekFrom::Start(toc_offset))?;
+    for chunk in &toc {
+        chunk
+            .write_v3_4(&mut writer)
+            .map_err(|e| Error::wad_with_path(format!("Failed to write TOC: {}", e), output_path))?;
+    }
+    writer.flush()?;
+    Ok(total_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::reader::WadReader;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_chunk_hash() {
+        let path = "data/characters/ahri/skins/skin0.bin";
+        assert_eq!(chunk_hash(path), HashVariant::Xxh64.hash(path));
+        assert_eq!(chunk_hash("DATA/Characters/Ahri/Skins/Skin0.bin"), HashVariant::Xxh64.hash(path));
+        assert_eq!(chunk_hash("0123456789abcdef.ltk.dds"), 0x0123456789abcdef);
+        assert_eq!(chunk_hash("data/skin0.0123456789abcdef.bin"), 0x0123456789abcdef);
+        assert_eq!(chunk_hash("data/noext.ltk"), HashVariant::Xxh64.hash("data/noext"));
+    }
+
+    #[test]
+    fn test_compress_as() {
+        let data = b"DDS texture data, repeated: DDS texture data";
+        let (gzip, compression) = compress_as(data, WadChunkCompression::GZip).unwrap();
+        assert_eq!(compression, WadChunkCompression::GZip);
+        let mut decoded = Vec::new();
+        std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&gzip[..]), &mut decoded).unwrap();
+        assert_eq!(decoded, data);
+
+        // Subchunked data is rewritten as plain zstd
+        let (zstd, compression) = compress_as(data, WadChunkCompression::ZstdMulti).unwrap();
+        assert_eq!(compression, WadChunkCompression::Zstd);
+        assert_eq!(zstd::decode_all(&zstd[..]).unwrap(), data);
+    }
+
+    #[test]
+    fn test_pack_wad_round_trips() {
+        let temp_dir = TempDir::new().unwrap();
+        let wad_dir = temp_dir.path().join("content/base/Ahri.wad.client");
+        let files: &[(&str, &[u8])] = &[
+            ("data/characters/ahri/skins/skin0.bin", b"PROP skin"),
+            ("assets/characters/ahri/skins/base/ahri.dds", b"DDS texture data"),
+            ("0123456789abcdef.ltk.dds", b"DDS unknown"),
+        ];
+        for (path, data) in files {
+            let path = wad_dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, data).unwrap();
+        }
+
+        let output = temp_dir.path().join("out/Ahri.wad.client");
+        let result = pack_wad(&wad_dir, &output, &CancelToken::default(), None).unwrap();
+        assert_eq!(result.chunk_count, 3);
+        assert!(result.warnings.is_empty());
+
+        let mut reader = WadReader::open(&output).unwrap();
+        let chunk = *reader
+            .get_chunk(HashVariant::Xxh64.hash("assets/characters/ahri/skins/base/ahri.dds"))
+            .unwrap();
+        assert_eq!(chunk.compression_type(), WadChunkCompression::Zstd);
+        assert!(reader.get_chunk(0x0123456789abcdef).is_some());
+        let (mut decoder, _) = reader.wad_mut().decode();
+        assert_eq!(&*decoder.load_chunk_decompressed(&chunk).unwrap(), b"DDS texture data");
+    }
+}
@@ -0,0 +1,256 @@
+//! Single-chunk WAD patching
+//!
+//! Replaces the data of one chunk inside an existing WAD without rebuilding
+//! the archive. The new (recompressed) data is appended to the end of the
+//! file and only that chunk's TOC entry is rewritten, so swapping a single
+//! texture in a 1GB map WAD costs a few kilobytes of I/O. The old data is
+//! left in place as dead space.
+
+use crate::core::wad::packer::compress_as;
+use crate::error::{Error, Result};
+use league_toolkit::wad::WadChunkCompression;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use xxhash_rust::{xxh3::xxh3_64, xxh64::xxh64};
+use ts_rs::TS;
+
+/// Offset of the chunk count in a v3 WAD header (magic + version + signature + checksum)
+const V3_CHUNK_COUNT_OFFSET: u64 = 4 + 256 + 8;
+/// Size of one v3 TOC entry
+const V3_TOC_ENTRY_SIZE: u64 = 32;
+
+/// Result of patching a chunk
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ChunkPatchResult {
+    /// Hex path hash of the patched chunk
+    pub path_hash: String,
+    #[ts(type = "number")]
+    pub old_size: u64,
+    #[ts(type = "number")]
+    pub new_size: u64,
+    #[ts(type = "number")]
+    pub compressed_size: u64,
+    pub compression: String,
+}
+
+/// Resolves a chunk path or 16-digit hex hash to a path hash
+///
+/// Paths are hashed with xxhash64 after lowercasing and normalizing separators.
+pub fn resolve_chunk_hash(path_or_hash: &str) -> u64 {
+    let trimmed = path_or_hash.trim();
+    let hex = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+    if hex.len() == 16 {
+        if let Ok(hash) = u64::from_str_radix(hex, 16) {
+            return hash;
+        }
+    }
+
+    let normalized = trimmed.to_lowercase().replace('\\', "/");
+    xxh64(normalized.as_bytes(), 0)
+}
+
+/// Finds the TOC entry offset of a chunk by scanning the on-disk table
+fn find_toc_entry(file: &mut File, path_hash: u64) -> Result<Option<u64>> {
+    file.seek(SeekFrom::Start(V3_CHUNK_COUNT_OFFSET))?;
+    let mut count = [0u8; 4];
+    file.read_exact(&mut count)?;
+    let count = u32::from_le_bytes(count) as u64;
+
+    let toc_start = V3_CHUNK_COUNT_OFFSET + 4;
+    let mut toc = vec![0u8; (count * V3_TOC_ENTRY_SIZE) as usize];
+    file.read_exact(&mut toc)?;
+
+    Ok(toc
+        .chunks_exact(V3_TOC_ENTRY_SIZE as usize)
+        .position(|entry| u64::from_le_bytes(entry[0..8].try_into().unwrap()) == path_hash)
+        .map(|index| toc_start + index as u64 * V3_TOC_ENTRY_SIZE))
+}
+
+/// Replaces the data of a single chunk in a WAD file
+///
+/// # Arguments
+/// * `wad_path` - WAD file to patch in place
+/// * `path_or_hash` - Chunk path or 16-digit hex path hash
+/// * `data` - New uncompressed chunk data
+///
+/// # Returns
+/// * `Result<ChunkPatchResult>` - Sizes and compression of the patched chunk
+pub fn patch_wad_chunk(
+    wad_path: impl AsRef<Path>,
+    path_or_hash: &str,
+    data: &[u8],
+) -> Result<ChunkPatchResult> {
+    let wad_path = wad_path.as_ref();
+    let path_hash = resolve_chunk_hash(path_or_hash);
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(wad_path)
+        .map_err(|e| Error::io_with_path(e, wad_path))?;
+
+    let mut header = [0u8; 4];
+    file.read_exact(&mut header)
+        .map_err(|e| Error::io_with_path(e, wad_path))?;
+    if &header[0..2] != b"RW" {
+        return Err(Error::wad_with_path("Not a WAD file", wad_path));
+    }
+    let (major, minor) = (header[2], header[3]);
+    if major != 3 || (minor != 1 && minor != 4) {
+        return Err(Error::wad_with_path(
+            format!("Unsupported WAD version {}.{} for patching", major, minor),
+            wad_path,
+        ));
+    }
+
+    let entry_offset = find_toc_entry(&mut file, path_hash)?.ok_or_else(|| {
+        Error::wad_with_path(format!("Chunk {:016x} not found", path_hash), wad_path)
+    })?;
+
+    file.seek(SeekFrom::Start(entry_offset))?;
+    let mut entry = [0u8; V3_TOC_ENTRY_SIZE as usize];
+    file.read_exact(&mut entry)?;
+
+    let old_size = u32::from_le_bytes(entry[16..20].try_into().unwrap()) as u64;
+    let original = WadChunkCompression::try_from(entry[20] & 0xF).map_err(|_| {
+        Error::wad_with_path(format!("Invalid compression type {}", entry[20] & 0xF), wad_path)
+    })?;
+
+    let (compressed, compression) = compress_as(data, original)?;
+
+    let data_offset = file.seek(SeekFrom::End(0))?;
+    if data_offset + compressed.len() as u64 > u32::MAX as u64 {
+        return Err(Error::wad_with_path(
+            "Patched WAD would exceed the 4GB format limit",
+            wad_path,
+        ));
+    }
+    file.write_all(&compressed)
+        .map_err(|e| Error::io_with_path(e, wad_path))?;
+
+    // Rewrite the entry: offset, sizes, type (no subchunk frames), and checksum.
+    // v3.1 stores a duplicate flag + u16 start frame where v3.4 has a 24-bit start frame;
+    // both are zeroed here, which is valid for either layout.
+    entry[8..12].copy_from_slice(&(data_offset as u32).to_le_bytes());
+    entry[12..16].copy_from_slice(&(compressed.len() as u32).to_le_bytes());
+    entry[16..20].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    entry[20] = compression as u8;
+    entry[21..24].fill(0);
+    entry[24..32].copy_from_slice(&xxh3_64(&compressed).to_le_bytes());
+
+    file.seek(SeekFrom::Start(entry_offset))?;
+    file.write_all(&entry)
+        .map_err(|e| Error::io_with_path(e, wad_path))?;
+    file.flush()?;
+
+    tracing::info!(
+        "Patched chunk {:016x} in {} ({} -> {} bytes)",
+        path_hash,
+        wad_path.display(),
+        old_size,
+        data.len()
+    );
+
+    Ok(ChunkPatchResult {
+        path_hash: format!("{:016x}", path_hash),
+        old_size,
+        new_size: data.len() as u64,
+        compressed_size: compressed.len() as u64,
+        compression: compression.to_string(),
+    })
+}
+
+/// Copies a WAD and patches a single chunk in the copy
+///
+/// # Arguments
+/// * `wad_path` - Source WAD (left untouched)
+/// * `output_path` - Where the patched copy is written
+/// * `path_or_hash` - Chunk path or 16-digit hex path hash
+/// * `data` - New uncompressed chunk data
+///
+/// Fails if `output_path` is the source WAD itself, which copying would truncate.
+pub fn patch_wad_chunk_copy(
+    wad_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+    path_or_hash: &str,
+    data: &[u8],
+) -> Result<ChunkPatchResult> {
+    let wad_path = wad_path.as_ref();
+    let output_path = output_path.as_ref();
+
+    let source = fs::canonicalize(wad_path).map_err(|e| Error::io_with_path(e, wad_path))?;
+    if fs::canonicalize(output_path).is_ok_and(|output| output == source) {
+        return Err(Error::InvalidInput(format!(
+            "Output path is the source WAD: {}",
+            output_path.display()
+        )));
+    }
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+    }
+    fs::copy(wad_path, output_path).map_err(|e| Error::io_with_path(e, wad_path))?;
+
+    patch_wad_chunk(output_path, path_or_hash, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad;
+
+    fn build_wad(path: &Path) {
+        write_wad(path, &[("data/a.bin", [0xAA; 64]), ("assets/b.dds", [0xBB; 64])]);
+    }
+
+    #[test]
+    fn test_resolve_chunk_hash() {
+        assert_eq!(resolve_chunk_hash("0123456789abcdef"), 0x0123456789abcdef);
+        assert_eq!(resolve_chunk_hash("0x0123456789ABCDEF"), 0x0123456789abcdef);
+        assert_eq!(resolve_chunk_hash("Data\\A.bin"), resolve_chunk_hash("data/a.bin"));
+    }
+
+    #[test]
+    fn test_patch_chunk_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("test.wad.client");
+        let patched = dir.path().join("out/test.wad.client");
+        build_wad(&source);
+
+        let result = patch_wad_chunk_copy(&source, &patched, "assets/b.dds", b"replacement data").unwrap();
+        assert_eq!(result.old_size, 64);
+        assert_eq!(result.new_size, 16);
+
+        let read = |wad: &Path, path: &str| {
+            crate::core::wad::explorer::read_chunk_data(wad, resolve_chunk_hash(path)).unwrap()
+        };
+        assert_eq!(read(&patched, "assets/b.dds"), b"replacement data");
+        assert_eq!(read(&patched, "data/a.bin"), vec![0xAA; 64]);
+        // Source is untouched
+        assert_eq!(read(&source, "assets/b.dds"), vec![0xBB; 64]);
+    }
+
+    #[test]
+    fn test_patch_chunk_copy_onto_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let wad = dir.path().join("test.wad.client");
+        build_wad(&wad);
+        let before = fs::read(&wad).unwrap();
+
+        let same = dir.path().join(".").join("test.wad.client");
+        assert!(patch_wad_chunk_copy(&wad, &same, "assets/b.dds", b"x").is_err());
+        assert_eq!(fs::read(&wad).unwrap(), before);
+    }
+
+    #[test]
+    fn test_patch_missing_chunk() {
+        let dir = tempfile::tempdir().unwrap();
+        let wad = dir.path().join("test.wad.client");
+        build_wad(&wad);
+
+        assert!(patch_wad_chunk(&wad, "missing/file.bin", b"x").is_err());
+    }
+}
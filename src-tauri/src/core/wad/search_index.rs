@@ -0,0 +1,442 @@
+//! Persisted search index over every game WAD
+//!
+//! Searching a full installation means mounting 30+ GB of archives, which is
+//! far too slow to repeat on every launch. The index stores each chunk as a
+//! hash-prefixed record (hash, size, resolved path) grouped by WAD, together
+//! with the WAD's mtime and size, so later launches only rescan the archives a
+//! patch actually touched.
+
+use crate::core::hash::hashtable::Hashtable;
+use crate::core::wad::explorer::scan_game_wads;
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use ts_rs::TS;
+
+const INDEX_MAGIC: &[u8; 4] = b"FSIX";
+const INDEX_VERSION: u32 = 1;
+
+/// File name of the index inside the app data directory
+pub const INDEX_FILE_NAME: &str = "wad_search_index.bin";
+
+/// A chunk record in the index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedChunk {
+    pub path_hash: u64,
+    /// Uncompressed size in bytes
+    pub size: u64,
+    /// Lowercase resolved path, None if the hash was unknown when indexed
+    pub path: Option<String>,
+}
+
+/// All chunks of one WAD plus the file state they were read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedWad {
+    /// Modification time in seconds since the Unix epoch
+    pub mtime: u64,
+    /// WAD file size in bytes
+    pub size: u64,
+    pub chunks: Vec<IndexedChunk>,
+}
+
+/// Search index for a League installation
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchIndex {
+    pub league_path: PathBuf,
+    /// WADs keyed by path relative to DATA/FINAL
+    pub wads: BTreeMap<String, IndexedWad>,
+}
+
+/// What an index update had to do
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct IndexUpdateStats {
+    /// WADs whose records were kept from the persisted index
+    pub reused: usize,
+    /// WADs that were new or changed and had to be rescanned
+    pub rebuilt: usize,
+    /// WADs that no longer exist in the installation
+    pub removed: usize,
+    /// WADs that couldn't be read
+    pub failed: usize,
+    /// Total chunks in the index
+    pub chunk_count: usize,
+}
+
+/// A search match
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WadSearchHit {
+    /// WAD path relative to DATA/FINAL
+    pub wad: String,
+    /// Resolved chunk path, or the hex hash if unknown
+    pub path: String,
+    /// Hex path hash
+    pub path_hash: String,
+    #[ts(type = "number")]
+    pub size: u64,
+}
+
+fn file_state(path: &Path) -> Result<(u64, u64)> {
+    let metadata = fs::metadata(path).map_err(|e| Error::io_with_path(e, path))?;
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok((mtime, metadata.len()))
+}
+
+fn resolve(path_hash: u64, hashtable: Option<&Hashtable>) -> Option<String> {
+    // Unknown hashes come back as an owned hex string
+    match hashtable?.resolve(path_hash) {
+        Cow::Borrowed(path) => Some(path.to_lowercase()),
+        Cow::Owned(_) => None,
+    }
+}
+
+fn index_wad(path: &Path, mtime: u64, size: u64, hashtable: Option<&Hashtable>) -> Result<IndexedWad> {
+    let reader = WadReader::open(path)?;
+    let mut chunks: Vec<IndexedChunk> = reader
+        .chunks()
+        .iter()
+        .map(|(hash, chunk)| IndexedChunk {
+            path_hash: *hash,
+            size: chunk.uncompressed_size() as u64,
+            path: resolve(*hash, hashtable),
+        })
+        .collect();
+    chunks.sort_by_key(|c| c.path_hash);
+    Ok(IndexedWad { mtime, size, chunks })
+}
+
+impl SearchIndex {
+    /// Loads a persisted index
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = File::open(path).map_err(|e| Error::io_with_path(e, path))?;
+        Self::read_from(&mut BufReader::new(file), path)
+    }
+
+    /// Persists the index, replacing any existing file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io_with_path(e, parent))?;
+        }
+        // Write to a sibling file first so a crash never leaves a truncated index
+        let temp = path.with_extension("tmp");
+        {
+            let file = File::create(&temp).map_err(|e| Error::io_with_path(e, &temp))?;
+            let mut writer = BufWriter::new(file);
+            self.write_to(&mut writer)
+                .and_then(|_| writer.flush())
+                .map_err(|e| Error::io_with_path(e, &temp))?;
+        }
+        fs::rename(&temp, path).map_err(|e| Error::io_with_path(e, path))
+    }
+
+    fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(INDEX_MAGIC)?;
+        writer.write_u32::<LE>(INDEX_VERSION)?;
+        write_string(writer, &self.league_path.to_string_lossy())?;
+        writer.write_u32::<LE>(self.wads.len() as u32)?;
+        for (name, wad) in &self.wads {
+            write_string(writer, name)?;
+            writer.write_u64::<LE>(wad.mtime)?;
+            writer.write_u64::<LE>(wad.size)?;
+            writer.write_u32::<LE>(wad.chunks.len() as u32)?;
+            for chunk in &wad.chunks {
+                writer.write_u64::<LE>(chunk.path_hash)?;
+                writer.write_u64::<LE>(chunk.size)?;
+                write_string(writer, chunk.path.as_deref().unwrap_or(""))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_from<R: Read>(reader: &mut R, path: &Path) -> Result<Self> {
+        let io = |e: std::io::Error| Error::io_with_path(e, path);
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic).map_err(io)?;
+        let version = reader.read_u32::<LE>().map_err(io)?;
+        if &magic != INDEX_MAGIC || version != INDEX_VERSION {
+            return Err(Error::parse_with_path(
+                0,
+                "Not a search index or unsupported index version",
+                path,
+            ));
+        }
+
+        let league_path = PathBuf::from(read_string(reader, path)?);
+        let wad_count = reader.read_u32::<LE>().map_err(io)?;
+        let mut wads = BTreeMap::new();
+        for _ in 0..wad_count {
+            let name = read_string(reader, path)?;
+            let mtime = reader.read_u64::<LE>().map_err(io)?;
+            let size = reader.read_u64::<LE>().map_err(io)?;
+            let chunk_count = reader.read_u32::<LE>().map_err(io)? as usize;
+            let mut chunks = Vec::with_capacity(chunk_count);
+            for _ in 0..chunk_count {
+                let path_hash = reader.read_u64::<LE>().map_err(io)?;
+                let size = reader.read_u64::<LE>().map_err(io)?;
+                let chunk_path = read_string(reader, path)?;
+                chunks.push(IndexedChunk {
+                    path_hash,
+                    size,
+                    path: (!chunk_path.is_empty()).then_some(chunk_path),
+                });
+            }
+            wads.insert(name, IndexedWad { mtime, size, chunks });
+        }
+
+        Ok(Self { league_path, wads })
+    }
+
+    /// Total number of indexed chunks
+    pub fn chunk_count(&self) -> usize {
+        self.wads.values().map(|w| w.chunks.len()).sum()
+    }
+
+    /// Searches chunk paths and hashes
+    ///
+    /// Every whitespace-separated term must appear in the path (case-insensitive);
+    /// a term also matches a chunk whose hex hash starts with it.
+    ///
+    /// # Arguments
+    /// * `query` - Search terms
+    /// * `limit` - Maximum number of hits to return
+    pub fn search(&self, query: &str, limit: usize) -> Vec<WadSearchHit> {
+        let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut hits = Vec::new();
+        for (wad, indexed) in &self.wads {
+            for chunk in &indexed.chunks {
+                let hex = format!("{:016x}", chunk.path_hash);
+                let path = chunk.path.as_deref().unwrap_or(&hex);
+                let matches = terms
+                    .iter()
+                    .all(|term| path.contains(term.as_str()) || hex.starts_with(term.as_str()));
+                if !matches {
+                    continue;
+                }
+
+                hits.push(WadSearchHit {
+                    wad: wad.clone(),
+                    path: path.to_string(),
+                    path_hash: hex,
+                    size: chunk.size,
+                });
+                if hits.len() >= limit {
+                    return hits;
+                }
+            }
+        }
+        hits
+    }
+}
+
+fn write_string<W: Write>(writer: &mut W, value: &str) -> std::io::Result<()> {
+    writer.write_u16::<LE>(value.len() as u16)?;
+    writer.write_all(value.as_bytes())?;
+    Ok(())
+}
+
+fn read_string<R: Read>(reader: &mut R, path: &Path) -> Result<String> {
+    let len = reader.read_u16::<LE>().map_err(|e| Error::io_with_path(e, path))? as usize;
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes).map_err(|e| Error::io_with_path(e, path))?;
+    String::from_utf8(bytes)
+        .map_err(|e| Error::parse_with_path(0, format!("Invalid string in search index: {}", e), path))
+}
+
+/// Brings an index up to date with the installation
+///
+/// WADs whose mtime and size match the previous index keep their records
+/// (unknown hashes are re-resolved, since the hashtable may have grown); new
+/// or changed WADs are rescanned in parallel.
+///
+/// # Arguments
+/// * `previous` - Index loaded from disk, if any
+/// * `league_path` - Path to the League installation root
+/// * `hashtable` - Optional hashtable for path resolution
+pub fn update_index(
+    previous: Option<SearchIndex>,
+    league_path: &Path,
+    hashtable: Option<&Hashtable>,
+) -> Result<(SearchIndex, IndexUpdateStats)> {
+    let game_wads = scan_game_wads(league_path)?;
+    let mut previous = previous
+        .filter(|index| index.league_path == league_path)
+        .map(|index| index.wads)
+        .unwrap_or_default();
+    let mut stats = IndexUpdateStats::default();
+
+    let mut wads = BTreeMap::new();
+    let mut stale = Vec::new();
+    for entry in &game_wads {
+        let (mtime, size) = match file_state(&entry.path) {
+            Ok(state) => state,
+            Err(e) => {
+                tracing::warn!("Skipping {} in search index: {}", entry.relative_path, e);
+                stats.failed += 1;
+                continue;
+            }
+        };
+
+        match previous.remove(&entry.relative_path) {
+            Some(mut indexed) if indexed.mtime == mtime && indexed.size == size => {
+                for chunk in indexed.chunks.iter_mut().filter(|c| c.path.is_none()) {
+                    chunk.path = resolve(chunk.path_hash, hashtable);
+                }
+                wads.insert(entry.relative_path.clone(), indexed);
+                stats.reused += 1;
+            }
+            _ => stale.push((entry, mtime, size)),
+        }
+    }
+    stats.removed = previous.len();
+
+    let rebuilt: Vec<_> = stale
+        .par_iter()
+        .map(|(entry, mtime, size)| (entry, index_wad(&entry.path, *mtime, *size, hashtable)))
+        .collect();
+    for (entry, result) in rebuilt {
+        match result {
+            Ok(indexed) => {
+                wads.insert(entry.relative_path.clone(), indexed);
+                stats.rebuilt += 1;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to index {}: {}", entry.relative_path, e);
+                stats.failed += 1;
+            }
+        }
+    }
+
+    let index = SearchIndex {
+        league_path: league_path.to_path_buf(),
+        wads,
+    };
+    stats.chunk_count = index.chunk_count();
+
+    tracing::info!(
+        "Search index updated: {} reused, {} rebuilt, {} removed, {} failed ({} chunks)",
+        stats.reused,
+        stats.rebuilt,
+        stats.removed,
+        stats.failed,
+        stats.chunk_count
+    );
+
+    Ok((index, stats))
+}
+
+/// Loads the persisted index, updates it and saves it back if anything changed
+///
+/// A missing or unreadable index file is treated as empty and fully rebuilt.
+///
+/// # Arguments
+/// * `index_path` - Location of the persisted index
+/// * `league_path` - Path to the League installation root
+/// * `hashtable` - Optional hashtable for path resolution
+pub fn refresh_index(
+    index_path: &Path,
+    league_path: &Path,
+    hashtable: Option<&Hashtable>,
+) -> Result<(SearchIndex, IndexUpdateStats)> {
+    let previous = if index_path.exists() {
+        match SearchIndex::load(index_path) {
+            Ok(index) => Some(index),
+            Err(e) => {
+                tracing::warn!("Discarding unreadable search index: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let unchanged = previous.clone();
+    let (index, stats) = update_index(previous, league_path, hashtable)?;
+    if unchanged.as_ref() != Some(&index) {
+        index.save(index_path)?;
+    }
+
+    Ok((index, stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SearchIndex {
+        let mut wads = BTreeMap::new();
+        wads.insert(
+            "Champions/Ahri.wad.client".to_string(),
+            IndexedWad {
+                mtime: 100,
+                size: 2048,
+                chunks: vec![
+                    IndexedChunk {
+                        path_hash: 0x1a2b,
+                        size: 10,
+                        path: Some("data/characters/ahri/skins/skin0.bin".to_string()),
+                    },
+                    IndexedChunk {
+                        path_hash: 0xdead_beef_0000_0001,
+                        size: 20,
+                        path: None,
+                    },
+                ],
+            },
+        );
+        SearchIndex {
+            league_path: PathBuf::from("C:/Riot Games/League of Legends"),
+            wads,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(INDEX_FILE_NAME);
+        let index = sample();
+        index.save(&path).unwrap();
+        assert_eq!(SearchIndex::load(&path).unwrap(), index);
+    }
+
+    #[test]
+    fn test_search_terms_and_hash_prefix() {
+        let index = sample();
+
+        let hits = index.search("AHRI skin0", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].path_hash, "0000000000001a2b");
+
+        let hits = index.search("deadbeef", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].path, "deadbeef00000001");
+
+        assert!(index.search("ahri annie", 10).is_empty());
+        assert!(index.search("   ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_foreign_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(INDEX_FILE_NAME);
+        fs::write(&path, b"not an index").unwrap();
+        assert!(SearchIndex::load(&path).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+//! WAD fixtures shared by tests
+
+use league_toolkit::wad::{WadBuilder, WadChunkBuilder};
+use std::io::{Cursor, Write};
+use std::path::Path;
+use xxhash_rust::xxh64::xxh64;
+
+/// Packs `(path, data)` chunks into an in-memory WAD
+pub(crate) fn wad_bytes<D: AsRef<[u8]>>(files: &[(&str, D)]) -> Vec<u8> {
+    let mut builder = WadBuilder::default();
+    for (name, _) in files {
+        builder = builder.with_chunk(WadChunkBuilder::default().with_path(name));
+    }
+    let mut cursor = Cursor::new(Vec::new());
+    builder
+        .build_to_writer(&mut cursor, |hash, out| {
+            let (_, data) = files
+                .iter()
+                .find(|(name, _)| xxh64(name.to_lowercase().as_bytes(), 0) == hash)
+                .unwrap();
+            out.write_all(data.as_ref())?;
+            Ok(())
+        })
+        .unwrap();
+    cursor.into_inner()
+}
+
+/// Writes a WAD with `(path, data)` chunks to `path`
+pub(crate) fn write_wad<D: AsRef<[u8]>>(path: &Path, files: &[(&str, D)]) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(path, wad_bytes(files)).unwrap();
+}
+
+/// Writes a WAD whose chunks all hold the same data
+pub(crate) fn write_wad_filled(path: &Path, names: &[&str], data: &[u8]) {
+    let files: Vec<(&str, &[u8])> = names.iter().map(|name| (*name, data)).collect();
+    write_wad(path, &files);
+}
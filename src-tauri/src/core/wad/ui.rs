@@ -0,0 +1,412 @@
+//! Read-only inspection of the UI WADs for HUD mods
+//!
+//! HUD mods replace fonts, HUD atlases and UI data from `UI.wad.client` and
+//! its localized variants (`UI.en_US.wad.client`, ...) instead of a champion
+//! WAD. Extraction layout, repathing and export are shared with skin mods;
+//! this module finds the UI archives, sorts their chunks by kind and builds a
+//! preview suited to each kind.
+
+use crate::core::bin::{read_bin, tree_to_text_cached};
+use crate::core::export::preview::{decode_texture_rgba, encode_preview, PreviewFormat};
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::wad::explorer::{read_chunk_data, scan_game_wads, GameWadEntry};
+use crate::core::wad::extractor::{extract_chunk, wad_folder_name};
+use crate::core::wad::reader::WadReader;
+use crate::error::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use ts_rs::TS;
+
+/// Longest text preview returned, in bytes
+const MAX_TEXT_PREVIEW: usize = 256 * 1024;
+
+/// What a UI chunk holds, guessed from its path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum UiAssetKind {
+    Font,
+    /// Texture under a HUD folder or named as an atlas
+    HudAtlas,
+    Texture,
+    /// BIN files describing UI scenes and elements
+    Data,
+    Other,
+}
+
+/// Classifies a UI chunk path
+pub fn classify_ui_asset(path: &str) -> UiAssetKind {
+    let lower = path.to_lowercase().replace('\\', "/");
+    let extension = lower.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+
+    match extension {
+        "ttf" | "otf" | "ttc" | "woff" | "woff2" => UiAssetKind::Font,
+        "tex" | "dds" | "png" | "tga" => {
+            let name = lower.rsplit('/').next().unwrap_or(&lower);
+            if lower.contains("/hud/") || name.contains("atlas") {
+                UiAssetKind::HudAtlas
+            } else {
+                UiAssetKind::Texture
+            }
+        }
+        "bin" => UiAssetKind::Data,
+        _ if lower.contains("/fonts/") => UiAssetKind::Font,
+        _ => UiAssetKind::Other,
+    }
+}
+
+/// A UI archive in the League installation
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UiWad {
+    pub wad: GameWadEntry,
+    /// Locale of a localized UI WAD (e.g. "en_US"), None for the shared one
+    pub locale: Option<String>,
+}
+
+/// Locale of a UI WAD file name, or None if the name isn't a UI WAD
+///
+/// `Some(None)` is the shared `UI.wad.client`.
+fn ui_wad_locale(name: &str) -> Option<Option<String>> {
+    let lower = name.to_lowercase();
+    let stem = lower.strip_suffix(".wad.client")?;
+    if stem == "ui" {
+        return Some(None);
+    }
+    let locale_len = stem.strip_prefix("ui.")?.len();
+    // Keep the locale's original casing ("en_US")
+    Some(Some(name.get(3..3 + locale_len)?.to_string()))
+}
+
+/// Finds the UI WADs of a League installation, shared archive first
+pub fn scan_ui_wads(league_path: impl AsRef<Path>) -> Result<Vec<UiWad>> {
+    let mut wads: Vec<UiWad> = scan_game_wads(league_path)?
+        .into_iter()
+        .filter_map(|wad| {
+            let locale = ui_wad_locale(&wad.name)?;
+            Some(UiWad { wad, locale })
+        })
+        .collect();
+    wads.sort_by(|a, b| a.locale.cmp(&b.locale));
+    Ok(wads)
+}
+
+/// A chunk of a UI WAD
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UiAsset {
+    /// Hex path hash
+    pub path_hash: String,
+    /// Resolved path, if the hash is known
+    pub path: Option<String>,
+    pub kind: UiAssetKind,
+    /// Uncompressed size in bytes
+    #[ts(type = "number")]
+    pub size: u64,
+}
+
+/// Lists the chunks of a UI WAD, optionally only those of one kind
+///
+/// Chunks whose path can't be resolved are classified as `Other`.
+pub fn list_ui_assets(
+    wad_path: impl AsRef<Path>,
+    hashtable: Option<&Hashtable>,
+    kind: Option<UiAssetKind>,
+) -> Result<Vec<UiAsset>> {
+    let reader = WadReader::open(wad_path)?;
+
+    let mut assets: Vec<UiAsset> = reader
+        .chunks()
+        .iter()
+        .map(|(hash, chunk)| {
+            let path = hashtable.and_then(|ht| ht.lookup(*hash, HashVariant::Xxh64).map(str::to_string));
+            UiAsset {
+                path_hash: format!("{:016x}", hash),
+                kind: path.as_deref().map(classify_ui_asset).unwrap_or(UiAssetKind::Other),
+                path,
+                size: chunk.uncompressed_size() as u64,
+            }
+        })
+        .filter(|asset| kind.is_none_or(|kind| asset.kind == kind))
+        .collect();
+
+    assets.sort_by(|a, b| {
+        a.kind
+            .cmp(&b.kind)
+            .then_with(|| a.path.is_none().cmp(&b.path.is_none()))
+            .then_with(|| a.path.cmp(&b.path))
+            .then_with(|| a.path_hash.cmp(&b.path_hash))
+    });
+
+    Ok(assets)
+}
+
+/// Names and glyph count read from a TrueType/OpenType font
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FontInfo {
+    /// "truetype" or "opentype"
+    pub format: String,
+    pub family: Option<String>,
+    pub style: Option<String>,
+    pub glyph_count: Option<u16>,
+}
+
+/// Preview of a UI chunk, shaped by what the chunk turned out to be
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UiAssetPreview {
+    /// Decoded texture as base64 PNG
+    Image { data: String, width: u32, height: u32 },
+    /// Font metadata plus the raw file as base64, for an `@font-face` sample
+    Font { info: FontInfo, data: String },
+    /// Ritobin text of a BIN, or the chunk itself if it is text
+    Text { text: String, truncated: bool },
+    /// Anything else
+    Binary {
+        #[ts(type = "number")]
+        size: u64,
+    },
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+/// Returns the offset of an sfnt table
+fn find_table(data: &[u8], tag: &[u8; 4]) -> Option<usize> {
+    let count = read_u16(data, 4)? as usize;
+    (0..count).map(|i| 12 + i * 16).find_map(|record| {
+        if data.get(record..record + 4)? != tag {
+            return None;
+        }
+        Some(read_u32(data, record + 8)? as usize)
+    })
+}
+
+/// Reads a name record, preferring Windows (UTF-16) over Mac Roman entries
+fn font_name(data: &[u8], name_id: u16) -> Option<String> {
+    let table = find_table(data, b"name")?;
+    let count = read_u16(data, table + 2)? as usize;
+    let strings = table + read_u16(data, table + 4)? as usize;
+
+    let mut fallback = None;
+    for record in (0..count).map(|i| table + 6 + i * 12) {
+        if read_u16(data, record + 6)? != name_id {
+            continue;
+        }
+        let platform = read_u16(data, record)?;
+        let length = read_u16(data, record + 8)? as usize;
+        let start = strings + read_u16(data, record + 10)? as usize;
+        let bytes = data.get(start..start + length)?;
+        match platform {
+            0 | 3 => {
+                let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+                return String::from_utf16(&units).ok();
+            }
+            1 if fallback.is_none() => fallback = Some(bytes.iter().map(|&b| b as char).collect()),
+            _ => {}
+        }
+    }
+    fallback
+}
+
+/// Parses the header of a TrueType/OpenType font, None if `data` isn't one
+pub fn read_font_info(data: &[u8]) -> Option<FontInfo> {
+    let format = match data.get(0..4)? {
+        [0x00, 0x01, 0x00, 0x00] | b"true" => "truetype",
+        b"OTTO" => "opentype",
+        _ => return None,
+    };
+    let glyph_count = find_table(data, b"maxp").and_then(|offset| read_u16(data, offset + 4));
+
+    Some(FontInfo {
+        format: format.to_string(),
+        family: font_name(data, 1),
+        style: font_name(data, 2),
+        glyph_count,
+    })
+}
+
+/// Builds a preview for one chunk of a UI WAD
+///
+/// The kind is detected from the chunk's contents, so unresolved chunks get
+/// a useful preview too.
+pub fn preview_ui_asset(wad_path: impl AsRef<Path>, path_hash: u64) -> Result<UiAssetPreview> {
+    let data = read_chunk_data(wad_path, path_hash)?;
+    let magic = data.get(0..4).unwrap_or(&[]);
+
+    if magic == b"TEX\0" || magic == b"DDS " {
+        let image = decode_texture_rgba(&data)?;
+        let (width, height) = image.dimensions();
+        let png = encode_preview(vec![image], PreviewFormat::Png, None, 0)?;
+        return Ok(UiAssetPreview::Image {
+            data: STANDARD.encode(png),
+            width,
+            height,
+        });
+    }
+
+    if let Some(info) = read_font_info(&data) {
+        return Ok(UiAssetPreview::Font {
+            info,
+            data: STANDARD.encode(&data),
+        });
+    }
+
+    let text = if magic == b"PROP" || magic == b"PTCH" {
+        let text = read_bin(&data)
+            .and_then(|tree| tree_to_text_cached(&tree))
+            .map_err(|e| Error::BinConversion { message: e.to_string(), path: None })?;
+        Some(text)
+    } else {
+        std::str::from_utf8(&data).ok().map(str::to_string)
+    };
+
+    Ok(match text {
+        Some(mut text) => {
+            let truncated = text.len() > MAX_TEXT_PREVIEW;
+            if truncated {
+                let mut end = MAX_TEXT_PREVIEW;
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                text.truncate(end);
+            }
+            UiAssetPreview::Text { text, truncated }
+        }
+        None => UiAssetPreview::Binary { size: data.len() as u64 },
+    })
+}
+
+/// Extracts the UI chunks of the given kinds
+///
+/// Files are written to `{output_dir}/{wad name}/{path}`, the layout of a
+/// project's content folder, so they can be dropped into a HUD mod as they
+/// are. Unresolved chunks are skipped since their kind is unknown.
+///
+/// # Returns
+/// * Number of extracted files
+pub fn extract_ui_assets(
+    wad_path: impl AsRef<Path>,
+    output_dir: impl AsRef<Path>,
+    hashtable: &Hashtable,
+    kinds: &[UiAssetKind],
+) -> Result<usize> {
+    let wad_path = wad_path.as_ref();
+    let target = output_dir.as_ref().join(wad_folder_name(wad_path)?);
+    let mut reader = WadReader::open(wad_path)?;
+
+    let selected: Vec<_> = reader
+        .chunks()
+        .iter()
+        .filter_map(|(hash, chunk)| {
+            let path = hashtable.lookup(*hash, HashVariant::Xxh64)?;
+            (kinds.is_empty() || kinds.contains(&classify_ui_asset(path)))
+                .then(|| (path.to_lowercase(), *chunk))
+        })
+        .collect();
+
+    for (path, chunk) in &selected {
+        extract_chunk(reader.wad_mut(), chunk, target.join(path), None)?;
+    }
+
+    tracing::info!(
+        "Extracted {} UI assets from {} to {}",
+        selected.len(),
+        wad_path.display(),
+        target.display()
+    );
+
+    Ok(selected.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wad::test_util::write_wad;
+    use std::fs;
+    use xxhash_rust::xxh64::xxh64;
+
+    /// Minimal TrueType header with a `name` table holding one family name
+    fn font_bytes(family: &str) -> Vec<u8> {
+        let name: Vec<u8> = family.encode_utf16().flat_map(|u| u.to_be_bytes()).collect();
+        let mut table = Vec::new();
+        for value in [0u16, 1, 18, 3, 1, 0x409, 1, name.len() as u16, 0] {
+            table.extend_from_slice(&value.to_be_bytes());
+        }
+        table.extend_from_slice(&name);
+
+        let mut data = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(b"name");
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&28u32.to_be_bytes());
+        data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        data.extend_from_slice(&table);
+        data
+    }
+
+    #[test]
+    fn test_classify_and_locale() {
+        assert_eq!(classify_ui_asset("ux/fonts/beaufortforlol-bold.ttf"), UiAssetKind::Font);
+        assert_eq!(classify_ui_asset("assets/ux/hud/hud_atlas.tex"), UiAssetKind::HudAtlas);
+        assert_eq!(classify_ui_asset("assets/ux/minimap/icons/ward.dds"), UiAssetKind::Texture);
+        assert_eq!(classify_ui_asset("clientstates/gameplay/ux/hud/hud.bin"), UiAssetKind::Data);
+        assert_eq!(classify_ui_asset("ux/sounds/click.wem"), UiAssetKind::Other);
+
+        assert_eq!(ui_wad_locale("UI.wad.client"), Some(None));
+        assert_eq!(ui_wad_locale("UI.en_US.wad.client"), Some(Some("en_US".to_string())));
+        assert_eq!(ui_wad_locale("Ahri.wad.client"), None);
+    }
+
+    #[test]
+    fn test_list_preview_and_extract() {
+        let dir = tempfile::tempdir().unwrap();
+        let font = "ux/fonts/beaufort.ttf";
+        let data_bin = "clientstates/gameplay/ux/hud/hud.json";
+        let files: Vec<(&str, Vec<u8>)> = vec![
+            (font, font_bytes("Beaufort")),
+            (data_bin, b"{ \"scene\": 1 }".to_vec()),
+        ];
+
+        let wad = dir.path().join("UI.wad.client");
+        write_wad(&wad, &files);
+
+        let hash_dir = dir.path().join("hashes");
+        fs::create_dir_all(&hash_dir).unwrap();
+        let lines: String = files.iter().map(|(p, _)| format!("{:016x} {}\n", xxh64(p.as_bytes(), 0), p)).collect();
+        fs::write(hash_dir.join("hashes.game.txt"), lines).unwrap();
+        let hashtable = Hashtable::from_directory(&hash_dir).unwrap();
+
+        let fonts = list_ui_assets(&wad, Some(&hashtable), Some(UiAssetKind::Font)).unwrap();
+        assert_eq!(fonts.len(), 1);
+        assert_eq!(fonts[0].path.as_deref(), Some(font));
+
+        match preview_ui_asset(&wad, xxh64(font.as_bytes(), 0)).unwrap() {
+            UiAssetPreview::Font { info, .. } => {
+                assert_eq!(info.format, "truetype");
+                assert_eq!(info.family.as_deref(), Some("Beaufort"));
+            }
+            other => panic!("expected a font preview, got {:?}", other),
+        }
+        match preview_ui_asset(&wad, xxh64(data_bin.as_bytes(), 0)).unwrap() {
+            UiAssetPreview::Text { text, truncated } => {
+                assert!(text.contains("scene"));
+                assert!(!truncated);
+            }
+            other => panic!("expected a text preview, got {:?}", other),
+        }
+
+        let out = dir.path().join("out");
+        let count = extract_ui_assets(&wad, &out, &hashtable, &[UiAssetKind::Font]).unwrap();
+        assert_eq!(count, 1);
+        assert!(out.join("ui.wad.client").join(font).is_file());
+        assert!(!out.join("ui.wad.client").join(data_bin).exists());
+    }
+}
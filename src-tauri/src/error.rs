@@ -1,3 +1,5 @@
+use crate::messages::Message;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("IO error{}: {}", .path.as_ref().map(|p| format!(" at '{}'", p.display())).unwrap_or_default(), .source)]
@@ -9,6 +11,9 @@ pub enum Error {
     #[error("Network error: {0}")]
     Network(#[from] reqwest::Error),
 
+    #[error("Git error: {}", .0.message())]
+    Git(#[from] git2::Error),
+
     #[error("Parse error{} at line {}: {}", .path.as_ref().map(|p| format!(" in file '{}'", p.display())).unwrap_or_default(), .line, .message)]
     Parse {
         line: usize,
@@ -33,6 +38,9 @@ pub enum Error {
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+
+    #[error("Request was cancelled")]
+    Cancelled,
 }
 
 impl Error {
@@ -77,6 +85,41 @@ impl Error {
     }
 }
 
+impl Error {
+    /// Catalog message for this error, for display in the user's language
+    pub fn message(&self) -> Message {
+        let at = |path: &Option<std::path::PathBuf>| path.as_ref().map(|p| p.display().to_string());
+        match self {
+            Error::Io { source, path } => match at(path) {
+                Some(path) => Message::new("error.io_at_path", [("path", path), ("detail", source.to_string())]),
+                None => Message::new("error.io", [("detail", source.to_string())]),
+            },
+            Error::Network(e) => Message::new("error.network", [("detail", e.to_string())]),
+            Error::Git(e) => Message::new("error.git", [("detail", e.message().to_string())]),
+            Error::Parse { line, message, path } => {
+                let params = [("line", line.to_string()), ("detail", message.clone())];
+                match at(path) {
+                    Some(path) => Message::new("error.parse_in_file", params.into_iter().chain([("path", path)])),
+                    None => Message::new("error.parse", params),
+                }
+            }
+            Error::Wad { message, path } => match at(path) {
+                Some(path) => Message::new("error.wad_in_file", [("path", path), ("detail", message.clone())]),
+                None => Message::new("error.wad", [("detail", message.clone())]),
+            },
+            Error::Hash(message) => Message::new("error.hash", [("detail", message.clone())]),
+            Error::BinConversion { message, path } => match at(path) {
+                Some(path) => {
+                    Message::new("error.bin_conversion_in_file", [("path", path), ("detail", message.clone())])
+                }
+                None => Message::new("error.bin_conversion", [("detail", message.clone())]),
+            },
+            Error::InvalidInput(message) => Message::new("error.invalid_input", [("detail", message.clone())]),
+            Error::Cancelled => Message::plain("error.cancelled"),
+        }
+    }
+}
+
 // Implement From<std::io::Error> manually since we changed the variant structure
 impl From<std::io::Error> for Error {
     fn from(source: std::io::Error) -> Self {
@@ -91,6 +134,17 @@ impl From<Error> for String {
     }
 }
 
+/// Error type of Tauri commands
+///
+/// Serializes as `{ key, params, message }`; see [`crate::messages`].
+pub type CommandError = Message;
+
+impl From<Error> for Message {
+    fn from(error: Error) -> Self {
+        error.message()
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[cfg(test)]
@@ -198,6 +252,30 @@ mod tests {
         assert!(s.contains("test error"));
     }
 
+    #[test]
+    fn test_message_matches_display() {
+        let errors = [
+            Error::from(std::io::Error::new(std::io::ErrorKind::NotFound, "file not found")),
+            Error::io_with_path(std::io::Error::new(std::io::ErrorKind::NotFound, "file not found"), "/a.txt"),
+            Error::Parse { line: 3, message: "bad".to_string(), path: None },
+            Error::parse_with_path(3, "bad", "/a.py"),
+            Error::Wad { message: "bad header".to_string(), path: None },
+            Error::wad_with_path("bad header", "/a.wad"),
+            Error::Hash("missing".to_string()),
+            Error::bin_conversion_with_path("bad", "/a.bin"),
+            Error::InvalidInput("empty path".to_string()),
+            Error::Git(git2::Error::from_str("not a repository")),
+            Error::Cancelled,
+        ];
+        for error in errors {
+            assert_eq!(error.message().message, error.to_string());
+        }
+
+        let message: CommandError = Error::wad_with_path("bad header", "/a.wad").into();
+        assert_eq!(message.key, "error.wad_in_file");
+        assert_eq!(message.params["path"], "/a.wad");
+    }
+
     #[test]
     fn test_result_type() {
         fn returns_result() -> Result<i32> {
@@ -2,4 +2,5 @@
 pub mod commands;
 pub mod core;
 pub mod error;
+pub mod messages;
 pub mod state;
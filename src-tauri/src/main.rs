@@ -4,11 +4,12 @@
 mod commands;
 mod core;
 mod error;
+mod messages;
 mod state;
 
 use core::hash::get_ritoshark_hash_dir;
 use core::frontend_log::{FrontendLogLayer, set_app_handle};
-use state::HashtableState;
+use state::{HashtableState, RequestState, SearchIndexState};
 use tauri::Manager;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
@@ -29,6 +30,8 @@ fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .manage(HashtableState::new())
+        .manage(SearchIndexState::default())
+        .manage(RequestState::default())
         .setup(|app| {
             // Set app handle for frontend logging
             set_app_handle(app.handle().clone());
@@ -44,15 +47,20 @@ fn main() {
             
             tracing::info!("Hash directory: {}", hash_dir.display());
             
+            // Apply the user's BIN size limit before anything parses BINs
+            if let Ok(app_data_dir) = app.path().app_data_dir() {
+                core::bin::limits::load_settings(&app_data_dir).apply();
+            }
+            
             // Set the hash directory for lazy loading (hashtable will load on first use)
             let hashtable_state = app.state::<HashtableState>().inner().clone();
             hashtable_state.set_hash_dir(hash_dir.clone());
             
-            // Spawn background task to download hashes (but NOT load them - lazy loading handles that)
+            // Spawn background task to check for hash updates (rate-limited by the scheduler,
+            // and NOT loading them - lazy loading handles that)
             tauri::async_runtime::spawn(async move {
-                tracing::info!("Checking for hash updates...");
-                match core::hash::download_hashes(&hash_dir, false).await {
-                    Ok(stats) => {
+                match core::hash::scheduler::run_scheduled_update(&hash_dir).await {
+                    Ok(Some(stats)) => {
                         if stats.downloaded > 0 {
                             tracing::info!(
                                 "Hash update: {} downloaded, {} up-to-date",
@@ -62,6 +70,7 @@ fn main() {
                             tracing::debug!("Hashes up-to-date ({} files)", stats.skipped);
                         }
                     }
+                    Ok(None) => {}
                     Err(e) => {
                         tracing::warn!("Failed to update hashes (will use existing): {}", e);
                     }
@@ -73,11 +82,35 @@ fn main() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::hash::download_hashes,
+            commands::hash::rescan_after_hash_update,
             commands::hash::get_hash_status,
             commands::hash::reload_hashes,
+            commands::hash::force_hash_update,
+            commands::hash::get_hash_update_schedule,
+            commands::hash::set_hash_update_interval,
+            commands::hash::lookup_hash,
+            commands::hash::hash_string,
+            commands::hash::add_custom_hashes,
+            commands::hash::import_hash_file,
+            commands::hash::remove_hash_source,
+            commands::hash::list_hash_sources,
+            commands::hash::report_unknown_hashes,
             commands::wad::read_wad,
+            commands::wad::identify_wad,
             commands::wad::get_wad_chunks,
             commands::wad::extract_wad,
+            commands::wad::patch_wad_chunk,
+            // Game file explorer (read-only)
+            commands::wad::list_game_wads,
+            commands::wad::browse_wad_directory,
+            commands::wad::read_wad_chunk,
+            commands::wad::diff_wads,
+            commands::wad::refresh_wad_search_index,
+            commands::wad::search_game_wads,
+            commands::wad::list_ui_wads,
+            commands::wad::list_ui_assets,
+            commands::wad::preview_ui_asset,
+            commands::wad::extract_ui_assets,
             commands::bin::convert_bin_to_text,
             commands::bin::convert_bin_to_json,
             commands::bin::convert_text_to_bin,
@@ -86,46 +119,105 @@ fn main() {
             commands::bin::parse_bin_file_to_text,
             commands::bin::read_or_convert_bin,
             commands::bin::save_ritobin_to_bin,
+            commands::bin::get_bin_settings,
+            commands::bin::set_bin_size_limit,
+            commands::bin::read_bin_index,
+            commands::bin::read_bin_objects,
+            commands::bin::get_bin_tree,
+            commands::bin::set_bin_property,
+            commands::bin::add_bin_object,
+            commands::bin::search_bins,
+            commands::bin::replace_in_bins,
             // League detection commands
 
             commands::league::detect_league,
+            commands::league::detect_league_installs,
             commands::league::validate_league,
             // Project management commands
             commands::project::create_project,
             commands::project::open_project,
             commands::project::save_project,
+            commands::project::relink_league_path,
+            commands::project::check_project_compatibility,
+            commands::project::set_project_variables,
+            commands::project::set_repath_options,
+            commands::project::set_layer_metadata,
+            commands::project::set_project_thumbnail,
+            commands::project::get_project_metadata,
+            commands::project::update_project_metadata,
+            commands::project::resolve_linked_bins,
+            commands::project::get_bin_dependency_tree,
+            commands::project::clean_orphan_caches,
+            commands::project::import_wad_into_project,
+            commands::project::resume_project_extraction,
+            commands::project::cancel_extraction,
+            commands::project::add_project_target,
+            commands::project::update_project_assets,
+            commands::project::import_fantome,
+            commands::project::import_raw_mod,
             commands::project::list_project_files,
             commands::project::preconvert_project_bins,
             // Champion discovery commands
             commands::champion::discover_champions,
             commands::champion::get_champion_skins,
+            commands::champion::preview_skin_contents,
             commands::champion::search_champions,
             // Validation commands
             commands::validation::extract_asset_references,
             commands::validation::validate_assets,
+            commands::validation::validate_project_references,
+            commands::validation::check_conventional_assets,
+            commands::validation::estimate_vram_usage,
+            commands::validation::export_validation_report,
+            commands::validation::build_asset_graph,
             // File commands (preview system)
             commands::file::read_file_bytes,
+            commands::file::read_file_hex,
             commands::file::read_file_info,
             commands::file::decode_dds_to_png,
+            commands::file::decode_texture,
+            commands::file::get_file_thumbnail,
+            commands::file::replace_texture,
+            commands::file::convert_dds_to_tex,
+            commands::file::convert_png_to_tex,
+            commands::file::cancel_request,
+            commands::file::export_preview_image,
             commands::file::read_text_file,
+            commands::file::diff_text_files,
             commands::file::recolor_image,
             commands::file::recolor_folder,
             commands::file::colorize_image,
             commands::file::colorize_folder,
             // Export commands
             commands::export::repath_project_cmd,
+            commands::export::preview_organize_project,
             commands::export::export_fantome,
             commands::export::export_modpkg,
+            commands::export::pack_wad,
             commands::export::get_fantome_filename,
+            commands::export::validate_fantome,
+            commands::export::check_conflicts,
             commands::export::get_export_preview,
             // Mesh commands (3D preview)
             commands::mesh::read_skn_mesh,
             commands::mesh::read_scb_mesh,
             commands::mesh::read_skl_skeleton,
             commands::mesh::read_animation_list,
+            commands::mesh::get_animation_audio_timeline,
             commands::mesh::read_animation,
             commands::mesh::evaluate_animation,
+            commands::mesh::bake_animation,
+            commands::mesh::render_model_turntable,
+            commands::mesh::replace_animation_clip,
+            commands::mesh::retarget_animation,
             commands::mesh::resolve_asset_path,
+            commands::mesh::get_shader_catalog,
+            commands::mesh::get_material_params,
+            commands::mesh::set_material_param,
+            commands::mesh::get_skin_submeshes,
+            commands::mesh::set_submesh_texture,
+            commands::mesh::set_submesh_hidden,
+            commands::mesh::rename_submesh,
             // Auto-update commands
             commands::updater::get_current_version,
             commands::updater::check_for_updates,
@@ -137,6 +229,44 @@ fn main() {
             commands::checkpoint::compare_checkpoints,
             commands::checkpoint::delete_checkpoint,
             commands::checkpoint::read_checkpoint_file,
+            commands::checkpoint::create_snapshot,
+            commands::checkpoint::list_snapshots,
+            commands::checkpoint::restore_snapshot,
+            // Asset store commands
+            commands::store::get_asset_store_status,
+            commands::store::set_asset_store_enabled,
+            // Chroma commands
+            commands::chroma::create_chroma_layer,
+            commands::chroma::apply_color_shift,
+            // VFX commands
+            commands::vfx::get_vfx_colors,
+            commands::vfx::recolor_vfx,
+            // Audio commands
+            commands::audio::list_audio_events,
+            commands::audio::list_audio_events_for_skin,
+            commands::audio::extract_audio,
+            commands::audio::replace_audio_clip,
+            // String table commands
+            commands::stringtable::read_stringtable,
+            commands::stringtable::write_stringtable,
+            // Diagnostics
+            commands::bench::run_benchmarks,
+            // Deploy commands
+            commands::deploy::list_deploy_profiles,
+            commands::deploy::save_deploy_profile,
+            commands::deploy::delete_deploy_profile,
+            commands::deploy::deploy_to_overlay,
+            // Version control commands
+            commands::vcs::init_project_repository,
+            commands::vcs::commit_project,
+            commands::vcs::project_history,
+            commands::vcs::diff_working_tree,
+            // Trash commands
+            commands::trash::list_cleaned_files,
+            commands::trash::restore_cleaned_files,
+            // Onboarding commands
+            commands::onboarding::get_onboarding_status,
+            commands::onboarding::complete_onboarding_step,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,261 @@
+//! Message catalog for text shown to the user
+//!
+//! Errors and progress updates reach the frontend as a catalog key plus named
+//! parameters, so the UI can render them in the user's language. Each message
+//! also carries its English rendering, built from the templates below, which
+//! is what gets logged and what the frontend falls back to for keys its
+//! translations don't cover yet.
+//!
+//! Templates use `{name}` placeholders. Parameters are plain strings; values
+//! that are themselves prose (an underlying error, for instance) are passed
+//! through as `detail` and stay untranslated.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use ts_rs::TS;
+
+/// English templates, keyed by message key
+const CATALOG: &[(&str, &str)] = &[
+    // Errors
+    ("error.generic", "{detail}"),
+    ("error.task_failed", "Task failed: {detail}"),
+    ("error.cancelled", "Request was cancelled"),
+    ("error.io", "IO error: {detail}"),
+    ("error.io_at_path", "IO error at '{path}': {detail}"),
+    ("error.network", "Network error: {detail}"),
+    ("error.git", "Git error: {detail}"),
+    ("error.parse", "Parse error at line {line}: {detail}"),
+    ("error.parse_in_file", "Parse error in file '{path}' at line {line}: {detail}"),
+    ("error.wad", "WAD error: {detail}"),
+    ("error.wad_in_file", "WAD error in file '{path}': {detail}"),
+    ("error.hash", "Hash error: {detail}"),
+    ("error.bin_conversion", "Bin conversion error: {detail}"),
+    ("error.bin_conversion_in_file", "Bin conversion error in file '{path}': {detail}"),
+    ("error.invalid_input", "Invalid input: {detail}"),
+    ("error.hashtable_unavailable", "Failed to load hashtable. Please check that hash files are available."),
+    ("error.app_data_dir", "Failed to resolve app data directory: {detail}"),
+    ("error.file_too_large", "Reading {size} bytes of '{path}' at once is over the {limit} byte limit. Read it in chunks instead."),
+    ("error.champion_wad_not_found", "Champion WAD not found for '{champion}'. Please check League installation."),
+    ("error.target_wad_not_found", "WAD '{name}' not found. Please check League installation."),
+    ("error.path_empty", "Path cannot be empty"),
+    ("error.input_path_empty", "Input path cannot be empty"),
+    ("error.output_path_empty", "Output path cannot be empty"),
+    ("error.file_not_found", "File not found: {path}"),
+    ("error.folder_not_found", "Folder not found: {path}"),
+    // Projects
+    ("error.project_path_missing", "Project path does not exist: {path}"),
+    ("error.project_extraction", "Asset extraction failed: {detail}. Project creation cancelled."),
+    ("error.no_extraction_to_resume", "This project has no interrupted extraction to resume"),
+    ("error.content_dir_missing", "Content directory not found: {path}"),
+    // BIN files
+    ("error.bin_open", "Failed to open BIN file '{path}': {detail}"),
+    ("error.bin_parse", "Failed to parse BIN file '{path}': {detail}"),
+    ("error.bin_to_text", "Failed to convert '{path}' to text: {detail}"),
+    ("error.bin_to_json", "Failed to convert '{path}' to JSON: {detail}"),
+    ("error.bin_text_parse", "Failed to parse text for '{path}': {detail}"),
+    ("error.bin_json_parse", "Failed to parse JSON for '{path}': {detail}"),
+    ("error.bin_write", "Failed to write BIN '{path}': {detail}"),
+    ("error.bin_too_large", "BIN file '{path}' is too large ({size} bytes, max {limit} bytes) - likely corrupt, skipping"),
+    // Hashes
+    ("error.hash_dir", "Failed to get hash directory: {detail}"),
+    ("error.hash_download", "Failed to download hashes: {detail}"),
+    ("error.hash_update", "Failed to update hashes: {detail}"),
+    ("error.hash_value_invalid", "Invalid hash value '{hash}': {detail}"),
+    ("error.hash_variant_unknown", "Can't tell whether '{hash}' is a 32-bit FNV or 64-bit xxhash value; specify the variant"),
+    ("error.hash_too_wide", "'{hash}' doesn't fit a 32-bit FNV hash"),
+    ("error.nothing_to_hash", "Nothing to hash"),
+    ("error.chunk_hash_invalid", "Invalid hash format '{hash}': {detail}"),
+    // Meshes and animations
+    ("error.scb_parse", "Failed to parse SCB file '{path}': {detail}"),
+    ("error.skn_parse", "Failed to parse SKN file '{path}': {detail}"),
+    ("error.skl_parse", "Failed to parse SKL file '{path}': {detail}"),
+    ("error.asset_not_found", "Asset not found: {path} (searched from {root})"),
+    ("error.skin_bin_not_found", "No skin BIN found for '{path}'"),
+    ("error.animation_bin_not_found", "No animation BIN found for '{path}'"),
+    ("error.animation_list", "Failed to read animations from '{path}': {detail}"),
+    ("error.animation_timeline", "Failed to read the timeline of '{clip}': {detail}"),
+    ("error.animation_path_unresolved", "Could not resolve animation path: {path}"),
+    ("error.animation_not_found", "Animation file not found: {path}"),
+    ("error.animation_parse", "Failed to parse animation '{path}': {detail}"),
+    ("error.animation_evaluate", "Failed to evaluate animation '{path}': {detail}"),
+    ("error.animation_load", "Failed to load animation '{path}': {detail}"),
+    ("error.animation_bake", "Failed to bake animation: {detail}"),
+    ("error.animation_needs_skeleton", "An animation needs a skeleton (.skl)"),
+    ("error.animation_retarget", "Failed to retarget animation '{path}': {detail}"),
+    ("error.clip_replace", "Failed to replace clip '{clip}': {detail}"),
+    ("error.turntable_render", "Failed to render turntable: {detail}"),
+    ("error.shader_catalog", "Failed to read shaders from '{path}': {detail}"),
+    // Textures and images
+    ("error.texture_parse", "Failed to parse texture '{path}': {detail}"),
+    ("error.texture_too_small", "File too small to be a valid texture: {path}"),
+    ("error.png_encode", "Failed to encode PNG: {detail}"),
+    ("error.image_write", "Failed to write image '{path}': {detail}"),
+    ("error.preview_frame", "Invalid frame data: {detail}"),
+    // Export and deploy
+    ("error.mod_config_missing", "{path} not found - cannot export modpkg without project metadata"),
+    ("error.mod_config_parse", "Failed to parse '{path}': {detail}"),
+    ("error.flatten_layers", "Failed to flatten layers: {detail}"),
+    ("error.manifest", "Failed to generate manifest: {detail}"),
+    ("error.modpkg_build", "Failed to build modpkg: {detail}"),
+    ("error.wad_folder_ambiguous", "The base layer holds several WAD folders; choose one to pack"),
+    ("error.deploy_profile_required", "Choose a deploy profile first"),
+    ("error.deploy_profile_not_found", "No deploy profile named '{name}'"),
+    // Updates
+    ("error.update_check", "Failed to check for updates: {detail}"),
+    ("error.update_url_missing", "No download URL provided"),
+    ("error.update_download", "Failed to download update: {detail}"),
+    ("error.update_launch", "Failed to launch installer: {detail}"),
+    ("error.update_unsupported", "Auto-update is only supported on Windows"),
+    // Project creation
+    ("progress.project.init", "Initializing..."),
+    ("progress.project.create", "Creating project structure..."),
+    ("progress.project.extract", "Extracting {champion} skin {skin_id} assets..."),
+    ("progress.project.extract_wad", "Extracting {wad} assets..."),
+    ("progress.project.extract_chunks", "Extracting assets ({current}/{total})"),
+    ("progress.project.repath", "Repathing assets to ASSETS/{creator}/{project}..."),
+    ("progress.project.complete", "Project created successfully!"),
+    // BIN concat, repath and asset relocation
+    ("progress.organize.concat", "Combining linked BINs ({current}/{total})"),
+    ("progress.organize.scan", "Scanning BINs ({current}/{total})"),
+    ("progress.organize.repath", "Repathing BINs ({current}/{total})"),
+    ("progress.organize.relocate", "Relocating assets ({current}/{total})"),
+    ("progress.repath.starting", "Starting repathing..."),
+    ("progress.repath.complete", "Repathed {paths} paths in {bins} BIN files"),
+    ("progress.repath.failed", "Repathing failed: {detail}"),
+    // Export
+    ("progress.export.repathing", "Repathing assets..."),
+    ("progress.export.fantome", "Creating fantome package..."),
+    ("progress.export.packing", "Packing files ({current}/{total})"),
+    ("progress.export.modpkg", "Creating modpkg package..."),
+    ("progress.export.wad", "Packing WAD chunks ({current}/{total})"),
+    ("progress.export.verifying", "Verifying package contents..."),
+    ("progress.export.complete", "Export complete: {path}"),
+    ("progress.export.failed", "Export failed: {detail}"),
+    // Deploy
+    ("progress.deploy.wad", "Deploying WADs ({current}/{total})"),
+    ("progress.deploy.complete", "Deployed as {mod}"),
+    ("progress.deploy.failed", "Deploy failed: {detail}"),
+];
+
+/// English template for a key
+pub fn template(key: &str) -> Option<&'static str> {
+    CATALOG.iter().find(|(k, _)| *k == key).map(|(_, t)| *t)
+}
+
+/// Fills `{name}` placeholders; unknown placeholders are left as they are
+fn render(template: &str, params: &BTreeMap<String, String>) -> String {
+    let mut text = template.to_string();
+    for (name, value) in params {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
+
+/// A user-facing message: catalog key, parameters and English text
+///
+/// Also the error type of every Tauri command, so failures reach the frontend
+/// as `{ key, params, message }` instead of a bare string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct Message {
+    pub key: String,
+    pub params: BTreeMap<String, String>,
+    /// English rendering of the message
+    pub message: String,
+}
+
+impl Message {
+    /// Builds a message from a catalog key and its parameters
+    ///
+    /// Keys missing from the catalog render as the key itself.
+    pub fn new<'a>(key: &str, params: impl IntoIterator<Item = (&'a str, String)>) -> Self {
+        let params: BTreeMap<String, String> =
+            params.into_iter().map(|(name, value)| (name.to_string(), value)).collect();
+        let message = match template(key) {
+            Some(template) => render(template, &params),
+            None => {
+                tracing::warn!("Message key missing from catalog: {}", key);
+                key.to_string()
+            }
+        };
+        Self {
+            key: key.to_string(),
+            params,
+            message,
+        }
+    }
+
+    /// Message without parameters
+    pub fn plain(key: &str) -> Self {
+        Self::new(key, std::iter::empty())
+    }
+
+    /// Message wrapping text that has no catalog entry of its own
+    pub fn generic(detail: impl Into<String>) -> Self {
+        Self::new("error.generic", [("detail", detail.into())])
+    }
+
+    /// Progress event payload: `fields` plus the message's key, params and text
+    ///
+    /// Keeps the `message` field progress listeners already read.
+    pub fn progress_payload(&self, mut fields: serde_json::Value) -> serde_json::Value {
+        if let Some(object) = fields.as_object_mut() {
+            object.insert("key".to_string(), self.key.clone().into());
+            object.insert("params".to_string(), serde_json::json!(self.params));
+            object.insert("message".to_string(), self.message.clone().into());
+        }
+        fields
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for Message {
+    fn from(detail: String) -> Self {
+        Self::generic(detail)
+    }
+}
+
+impl From<&str> for Message {
+    fn from(detail: &str) -> Self {
+        Self::generic(detail)
+    }
+}
+
+impl From<tokio::task::JoinError> for Message {
+    fn from(e: tokio::task::JoinError) -> Self {
+        Self::new("error.task_failed", [("detail", e.to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_renders_template() {
+        let message = Message::new(
+            "progress.project.extract",
+            [("champion", "Ahri".to_string()), ("skin_id", "3".to_string())],
+        );
+        assert_eq!(message.message, "Extracting Ahri skin 3 assets...");
+        assert_eq!(message.params["skin_id"], "3");
+
+        let payload = message.progress_payload(serde_json::json!({ "phase": "extract" }));
+        assert_eq!(payload["phase"], "extract");
+        assert_eq!(payload["key"], "progress.project.extract");
+        assert_eq!(payload["message"], "Extracting Ahri skin 3 assets...");
+    }
+
+    #[test]
+    fn test_catalog_keys_are_unique() {
+        let keys: std::collections::HashSet<_> = CATALOG.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys.len(), CATALOG.len());
+        assert!(keys.iter().all(|k| k.starts_with("error.") || k.starts_with("progress.")));
+    }
+}
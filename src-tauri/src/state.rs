@@ -1,11 +1,15 @@
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::{Arc, OnceLock};
-use crate::core::hash::Hashtable;
-use crate::error::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use crate::core::hash::{HashVariant, Hashtable};
+use crate::core::wad::search_index::SearchIndex;
+use crate::error::{CommandError, Result};
 
-/// Global lazy-loaded hashtable - only loaded when first accessed
-static LAZY_HASHTABLE: OnceLock<Arc<Hashtable>> = OnceLock::new();
+/// Global lazy-loaded hashtable - only loaded when first accessed, and
+/// swapped out after a hash update
+static LAZY_HASHTABLE: RwLock<Option<Arc<Hashtable>>> = RwLock::new(None);
 
 /// Thread-safe wrapper for the global hashtable state.
 /// Supports lazy loading - hashtable is only loaded from disk when first accessed.
@@ -49,33 +53,56 @@ impl HashtableState {
     /// Only loads from disk on first call
     pub fn get_hashtable(&self) -> Option<Arc<Hashtable>> {
         // Return cached if already loaded
-        if let Some(ht) = LAZY_HASHTABLE.get() {
-            return Some(Arc::clone(ht));
+        if let Some(ht) = self.loaded() {
+            return Some(ht);
         }
         
         // Try to load lazily
         let hash_dir = self.0.lock().clone()?;
         
-        // Use get_or_init to handle race conditions
-        let ht = LAZY_HASHTABLE.get_or_init(|| {
-            tracing::info!("Lazy loading hashtable from {}...", hash_dir.display());
-            match Hashtable::from_directory(&hash_dir) {
-                Ok(hashtable) => {
-                    tracing::info!("Hashtable lazy-loaded: {} entries", hashtable.len());
-                    Arc::new(hashtable)
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to load hashtable: {}", e);
-                    Arc::new(Hashtable::empty())
-                }
+        // Hold the write lock while loading so concurrent callers wait for
+        // this load instead of starting their own
+        let mut slot = LAZY_HASHTABLE.write();
+        if let Some(ht) = slot.as_ref() {
+            return Some(Arc::clone(ht));
+        }
+        
+        tracing::info!("Lazy loading hashtable from {}...", hash_dir.display());
+        let ht = match Hashtable::from_directory(&hash_dir) {
+            Ok(hashtable) => {
+                tracing::info!("Hashtable lazy-loaded: {} entries", hashtable.len());
+                Arc::new(hashtable)
             }
-        });
+            Err(e) => {
+                tracing::warn!("Failed to load hashtable: {}", e);
+                Arc::new(Hashtable::empty())
+            }
+        };
+        *slot = Some(Arc::clone(&ht));
         
-        Some(Arc::clone(ht))
+        Some(ht)
+    }
+    
+    /// Returns the hashtable if it has been loaded, without loading it
+    pub fn loaded(&self) -> Option<Arc<Hashtable>> {
+        LAZY_HASHTABLE.read().as_ref().map(Arc::clone)
+    }
+    
+    /// Replaces the loaded hashtable, e.g. with one freshly read after a
+    /// hash update. Callers holding the previous table keep their copy.
+    pub fn replace(&self, hashtable: Hashtable) -> Arc<Hashtable> {
+        let ht = Arc::new(hashtable);
+        *LAZY_HASHTABLE.write() = Some(Arc::clone(&ht));
+        ht
     }
     
     pub fn len(&self) -> usize {
-        LAZY_HASHTABLE.get().map(|h| h.len()).unwrap_or(0)
+        self.loaded().map(|h| h.len()).unwrap_or(0)
+    }
+
+    /// Number of loaded mappings for one hash variant
+    pub fn count(&self, variant: HashVariant) -> usize {
+        self.loaded().map(|h| h.count(variant)).unwrap_or(0)
     }
 
     #[allow(dead_code)]
@@ -86,6 +113,136 @@ impl HashtableState {
     /// Check if the hashtable has been loaded yet
     #[allow(dead_code)] // Kept for API completeness
     pub fn is_loaded(&self) -> bool {
-        LAZY_HASHTABLE.get().is_some()
+        self.loaded().is_some()
+    }
+}
+
+/// In-memory copy of the persisted WAD search index, shared between searches
+#[derive(Clone, Default)]
+pub struct SearchIndexState(pub Arc<Mutex<Option<Arc<SearchIndex>>>>);
+
+impl SearchIndexState {
+    /// Returns the loaded index if it belongs to the given installation
+    pub fn get(&self, league_path: &std::path::Path) -> Option<Arc<SearchIndex>> {
+        self.0
+            .lock()
+            .as_ref()
+            .filter(|index| index.league_path == league_path)
+            .cloned()
+    }
+
+    /// Replaces the loaded index
+    pub fn set(&self, index: SearchIndex) -> Arc<SearchIndex> {
+        let index = Arc::new(index);
+        *self.0.lock() = Some(Arc::clone(&index));
+        index
+    }
+}
+
+/// Cancellation flag of one running request
+///
+/// Long commands check it between stages and stop with `error.cancelled`
+/// once the frontend has lost interest in the result.
+#[derive(Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Errors if the request has been cancelled
+    pub fn check(&self) -> std::result::Result<(), CommandError> {
+        if self.is_cancelled() {
+            Err(CommandError::plain("error.cancelled"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Requests that can be cancelled, keyed by the ID the frontend chose
+#[derive(Clone, Default)]
+pub struct RequestState(Arc<Mutex<HashMap<String, CancelToken>>>);
+
+impl RequestState {
+    /// Registers a request until the returned guard is dropped
+    ///
+    /// Requests without an ID get a token nothing can cancel. Reusing the ID
+    /// of a running request cancels the older one, which is what repeatedly
+    /// previewing into the same panel wants.
+    pub fn begin(&self, request_id: Option<String>) -> RequestGuard {
+        let token = CancelToken::default();
+        if let Some(id) = &request_id {
+            if let Some(previous) = self.0.lock().insert(id.clone(), token.clone()) {
+                previous.0.store(true, Ordering::Relaxed);
+            }
+        }
+        RequestGuard {
+            requests: self.clone(),
+            request_id,
+            token,
+        }
+    }
+
+    /// Cancels a running request; false if no request has that ID
+    pub fn cancel(&self, request_id: &str) -> bool {
+        match self.0.lock().remove(request_id) {
+            Some(token) => {
+                token.0.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Keeps a request registered while its command runs
+pub struct RequestGuard {
+    requests: RequestState,
+    request_id: Option<String>,
+    token: CancelToken,
+}
+
+impl RequestGuard {
+    pub fn token(&self) -> CancelToken {
+        self.token.clone()
+    }
+}
+
+impl Drop for RequestGuard {
+    fn drop(&mut self) {
+        let Some(id) = &self.request_id else { return };
+        let mut requests = self.requests.0.lock();
+        // The ID may already belong to a newer request
+        if requests.get(id).is_some_and(|t| Arc::ptr_eq(&t.0, &self.token.0)) {
+            requests.remove(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_cancellation() {
+        let requests = RequestState::default();
+        let first = requests.begin(Some("preview".to_string()));
+        assert!(first.token().check().is_ok());
+
+        // A newer request with the same ID supersedes the first
+        let second = requests.begin(Some("preview".to_string()));
+        assert!(first.token().is_cancelled());
+        drop(first);
+        assert!(!second.token().is_cancelled());
+
+        let token = second.token();
+        assert!(requests.cancel("preview"));
+        assert_eq!(token.check().unwrap_err().key, "error.cancelled");
+        assert!(!requests.cancel("preview"));
+
+        drop(second);
+        assert!(!requests.begin(None).token().is_cancelled());
     }
 }
@@ -1,4 +1,5 @@
 use flint::commands::wad::{read_wad, WadInfo, ChunkInfo, ExtractionResult};
+use flint::error::CommandError;
 
 #[tokio::test]
 async fn test_read_wad_nonexistent_file() {
@@ -11,7 +12,7 @@ async fn test_read_wad_nonexistent_file() {
     
     // Verify error message is meaningful
     if let Err(e) = result {
-        assert!(e.contains("IO error") || e.contains("WAD error") || e.contains("No such file"));
+        assert!(e.message.contains("IO error") || e.message.contains("WAD error") || e.message.contains("No such file"));
     }
 }
 
@@ -55,6 +56,7 @@ async fn test_extraction_result_serialization() {
     let result = ExtractionResult {
         extracted_count: 10,
         failed_count: 2,
+        warnings: Vec::new(),
     };
     
     let json = serde_json::to_string(&result).unwrap();
@@ -72,7 +74,7 @@ fn test_wad_commands_api_exists() {
     // The fact that this compiles means our API is correct
     fn _check_api() {
         // These functions should exist and be async
-        let _: std::pin::Pin<Box<dyn std::future::Future<Output = Result<WadInfo, String>>>> = 
+        let _: std::pin::Pin<Box<dyn std::future::Future<Output = Result<WadInfo, CommandError>>>> = 
             Box::pin(read_wad("test.wad".to_string()));
     }
 }
@@ -5,6 +5,7 @@
 import React, { useState, useEffect } from 'react';
 import * as api from '../../lib/api';
 import { getIcon } from '../../lib/fileIcons';
+import type { HexDump } from '../../lib/bindings/HexDump';
 
 interface HexViewerProps {
     filePath: string;
@@ -13,10 +14,9 @@ interface HexViewerProps {
 const MAX_BYTES = 16 * 1024; // 16KB max display
 
 export const HexViewer: React.FC<HexViewerProps> = ({ filePath }) => {
-    const [data, setData] = useState<Uint8Array | null>(null);
+    const [dump, setDump] = useState<HexDump | null>(null);
     const [loading, setLoading] = useState(true);
     const [error, setError] = useState<string | null>(null);
-    const [isTruncated, setIsTruncated] = useState(false);
 
     useEffect(() => {
         const loadData = async () => {
@@ -24,14 +24,7 @@ export const HexViewer: React.FC<HexViewerProps> = ({ filePath }) => {
             setError(null);
 
             try {
-                const bytes = await api.readFileBytes(filePath);
-                if (bytes.length > MAX_BYTES) {
-                    setData(bytes.slice(0, MAX_BYTES));
-                    setIsTruncated(true);
-                } else {
-                    setData(bytes);
-                    setIsTruncated(false);
-                }
+                setDump(await api.readFileHex(filePath, { length: MAX_BYTES }));
             } catch (err) {
                 console.error('[HexViewer] Error:', err);
                 setError((err as Error).message || 'Failed to load file');
@@ -61,32 +54,26 @@ export const HexViewer: React.FC<HexViewerProps> = ({ filePath }) => {
         );
     }
 
-    if (!data) return null;
+    if (!dump) return null;
 
-    // Generate rows (16 bytes each)
-    const rows: Array<{
-        offset: string;
-        hex: string;
-        ascii: string;
-    }> = [];
+    const shown = dump.rows.reduce((total, row) => total + (row.hex.length + 1) / 3, 0);
+    const isTruncated = dump.file_size > dump.offset + shown;
 
-    for (let i = 0; i < data.length; i += 16) {
-        const slice = data.slice(i, i + 16);
-        const offset = i.toString(16).padStart(8, '0').toUpperCase();
-        const hex = Array.from(slice)
-            .map((b) => b.toString(16).padStart(2, '0').toUpperCase())
-            .join(' ');
-        const ascii = Array.from(slice)
-            .map((b) => (b >= 32 && b <= 126 ? String.fromCharCode(b) : '.'))
-            .join('');
-
-        rows.push({ offset, hex, ascii });
-    }
+    // Fields overlapping each row, shown as the row's tooltip
+    const rowTitle = (rowOffset: number) =>
+        dump.annotations
+            .filter((a) => a.offset < rowOffset + 16 && a.offset + a.length > rowOffset)
+            .map((a) => `${a.label} = ${a.value}`)
+            .join('\n') || undefined;
 
     return (
         <div className="hex-viewer">
             <div className="hex-viewer__toolbar">
-                <span>{data.length} bytes{isTruncated ? ' (truncated)' : ''}</span>
+                <span>
+                    {dump.file_size} bytes{isTruncated ? ' (truncated)' : ''}
+                    {dump.structure ? ` · ${dump.structure}` : ''}
+                </span>
+                {dump.problem && <span className="hex-viewer__problem">{dump.problem}</span>}
             </div>
             <div className="hex-viewer__header">
                 <span className="hex-viewer__offset-col">Offset</span>
@@ -94,9 +81,11 @@ export const HexViewer: React.FC<HexViewerProps> = ({ filePath }) => {
                 <span className="hex-viewer__ascii-col">ASCII</span>
             </div>
             <div className="hex-viewer__content">
-                {rows.map((row) => (
-                    <div key={row.offset} className="hex-viewer__row">
-                        <span className="hex-viewer__offset">{row.offset}</span>
+                {dump.rows.map((row) => (
+                    <div key={row.offset} className="hex-viewer__row" title={rowTitle(row.offset)}>
+                        <span className="hex-viewer__offset">
+                            {row.offset.toString(16).padStart(8, '0').toUpperCase()}
+                        </span>
                         <span className="hex-viewer__hex">{row.hex.padEnd(47, ' ')}</span>
                         <span className="hex-viewer__ascii">{row.ascii}</span>
                     </div>
@@ -4,7 +4,8 @@
  */
 
 import { invoke } from '@tauri-apps/api/core';
-import type { HashStatus, Project, FileTreeNode, Champion } from './types';
+import type { HashStatus, Project, FileTreeNode } from './types';
+import { formatMessage, isBackendMessage } from './messages';
 
 // =============================================================================
 // Error Handling
@@ -16,15 +17,22 @@ import type { HashStatus, Project, FileTreeNode, Champion } from './types';
 export class FlintError extends Error {
     command: string;
     originalError: unknown;
+    /** Catalog key of the backend error, if the command returned one */
+    key: string | null;
+    params: Record<string, string>;
 
     constructor(command: string, originalError: unknown) {
-        const message = typeof originalError === 'string'
-            ? originalError
-            : (originalError as Error)?.message || 'Unknown error';
+        const message = isBackendMessage(originalError)
+            ? formatMessage(originalError)
+            : typeof originalError === 'string'
+                ? originalError
+                : (originalError as Error)?.message || 'Unknown error';
         super(message);
         this.name = 'FlintError';
         this.command = command;
         this.originalError = originalError;
+        this.key = isBackendMessage(originalError) ? originalError.key : null;
+        this.params = isBackendMessage(originalError) ? originalError.params : {};
     }
 
     /**
@@ -39,13 +47,19 @@ export class FlintError extends Error {
             'reload_hashes': 'Failed to reload hash files.',
             'discover_champions': 'Failed to discover champions.',
             'get_champion_skins': 'Failed to get skins for this champion.',
+            'preview_skin_contents': 'Failed to preview the skin contents.',
             'search_champions': 'Champion search failed.',
             'create_project': 'Failed to create project.',
+            'import_raw_mod': 'Failed to import the mod folder.',
+            'add_project_target': 'Failed to add the champion to the project.',
+            'cancel_extraction': 'Failed to cancel extraction.',
             'open_project': 'Failed to open project. The project file may be corrupted.',
             'save_project': 'Failed to save project.',
             'list_project_files': 'Failed to list project files.',
             'preconvert_project_bins': 'Failed to pre-convert BIN files.',
+            'get_bin_dependency_tree': 'Failed to read the linked BINs of the project.',
             'read_wad': 'Failed to read WAD file. The file may be corrupted.',
+            'identify_wad': 'Failed to identify WAD file. The file may be corrupted.',
             'get_wad_chunks': 'Failed to read WAD contents.',
             'extract_wad': 'Failed to extract files from WAD.',
             'convert_bin_to_text': 'Failed to convert BIN to text format.',
@@ -56,6 +70,19 @@ export class FlintError extends Error {
             'parse_bin_file_to_text': 'Failed to parse BIN file.',
             'read_or_convert_bin': 'Failed to load BIN file.',
             'save_ritobin_to_bin': 'Failed to save BIN file.',
+            'get_bin_tree': 'Failed to load BIN properties.',
+            'set_bin_property': 'Failed to edit BIN property.',
+            'add_bin_object': 'Failed to add BIN object.',
+            'search_bins': 'Failed to search BIN files.',
+            'replace_in_bins': 'Failed to replace in BIN files.',
+            'get_vfx_colors': 'Failed to read VFX colors.',
+            'recolor_vfx': 'Failed to recolor VFX.',
+            'list_audio_events': 'Failed to read audio bank. The file may be corrupted.',
+            'extract_audio': 'Failed to extract audio.',
+            'replace_audio_clip': 'Failed to replace audio clip.',
+            'read_stringtable': 'Failed to read string table. The file may be corrupted.',
+            'write_stringtable': 'Failed to save string table.',
+            'run_benchmarks': 'Failed to run benchmarks.',
             'parse_bin_to_tree': 'Failed to parse BIN structure.',
             'get_bin_paths': 'Failed to extract paths from BIN file.',
             'read_file_bytes': 'Failed to read file.',
@@ -63,19 +90,35 @@ export class FlintError extends Error {
             'decode_dds_to_png': 'Failed to decode texture file.',
             'decode_texture_to_png': 'Failed to decode texture file.',
             'read_text_file': 'Failed to read text file.',
+            'replace_texture': 'Failed to replace texture.',
             'recolor_image': 'Failed to recolor image.',
             'recolor_folder': 'Failed to recolor folder assets.',
             'extract_asset_references': 'Failed to extract asset references.',
             'validate_assets': 'Asset validation failed.',
+            'validate_project_references': 'Asset validation failed.',
             'export_fantome': 'Failed to export Fantome package.',
             'export_modpkg': 'Failed to export modpkg package.',
+            'check_conflicts': 'Failed to check mod conflicts.',
+            'set_repath_options': 'Failed to save repath settings.',
+            'set_project_thumbnail': 'Failed to set the project thumbnail.',
+            'update_project_metadata': 'Failed to update the mod metadata.',
+            'preview_organize_project': 'Failed to preview repathing.',
+            'pack_wad': 'Failed to pack WAD file.',
+            'deploy_to_overlay': 'Failed to deploy project to the mod manager.',
             'read_skn_mesh': 'Failed to read SKN mesh file.',
             'read_scb_mesh': 'Failed to read SCB mesh file.',
+            'get_material_params': 'Failed to read material parameters.',
+            'set_material_param': 'Failed to update material parameter.',
             'create_checkpoint': 'Failed to create checkpoint.',
             'list_checkpoints': 'Failed to load checkpoints.',
             'restore_checkpoint': 'Failed to restore checkpoint.',
             'compare_checkpoints': 'Failed to compare checkpoints.',
             'delete_checkpoint': 'Failed to delete checkpoint.',
+            'create_snapshot': 'Failed to create snapshot.',
+            'restore_snapshot': 'Failed to restore snapshot.',
+            'commit_project': 'Failed to commit project changes.',
+            'project_history': 'Failed to load project history.',
+            'restore_cleaned_files': 'Failed to restore cleaned files.',
         };
         return messages[this.command] || this.message;
     }
@@ -94,6 +137,7 @@ export class FlintError extends Error {
             'save_project': 'Check that the project folder still exists and is writable.',
             'save_ritobin_to_bin': 'Check for syntax errors in the BIN editor.',
             'decode_dds_to_png': 'The texture format may not be supported.',
+            'replace_texture': 'Use a PNG, JPG or TGA image whose width and height are multiples of 4.',
             'recolor_image': 'Make sure the texture format is supported and the file is not read-only.',
             'recolor_folder': 'Check if the folder contains valid texture files.',
             'read_file_bytes': 'Check that the file exists and is accessible.',
@@ -133,6 +177,52 @@ export async function reloadHashes(): Promise<{ count: number }> {
     return invokeCommand('reload_hashes');
 }
 
+import type { HashedString } from './bindings/HashedString';
+
+/** Compute the WAD path (xxh64) and BIN name (FNV-1a) hashes of a string. */
+export async function hashString(value: string): Promise<HashedString> {
+    return invokeCommand('hash_string', { value });
+}
+
+import type { HashSource } from './bindings/HashSource';
+import type { CustomHashResult } from './bindings/CustomHashResult';
+
+/**
+ * Add hashes to the user hash list, which wins over CommunityDragon names.
+ * Entries are `<hash> <name>` lines or bare names to hash.
+ */
+export async function addCustomHashes(entries: string[]): Promise<CustomHashResult> {
+    return invokeCommand('add_custom_hashes', { entries });
+}
+
+/** Copy a `<hash> <name>` file into the hash directory as a user list. */
+export async function importHashFile(path: string): Promise<HashSource> {
+    return invokeCommand('import_hash_file', { path });
+}
+
+/** Delete a user hash list by file name. */
+export async function removeHashSource(name: string): Promise<void> {
+    return invokeCommand('remove_hash_source', { name });
+}
+
+export async function listHashSources(): Promise<HashSource[]> {
+    return invokeCommand('list_hash_sources');
+}
+
+import type { UnknownHashReport } from './bindings/UnknownHashReport';
+
+/**
+ * List the chunk hashes of a WAD or project that no hash list names.
+ * With `guess`, common champion paths are hashed to suggest names.
+ */
+export async function reportUnknownHashes(
+    path: string,
+    guess = false,
+    champions?: string[]
+): Promise<UnknownHashReport> {
+    return invokeCommand('report_unknown_hashes', { path, guess, champions });
+}
+
 // =============================================================================
 // League Detection Commands
 // =============================================================================
@@ -141,6 +231,15 @@ export async function detectLeague(): Promise<{ path: string; source: string }>
     return invokeCommand('detect_league');
 }
 
+import type { InstallCandidate } from './bindings/InstallCandidate';
+
+/**
+ * List every League install found (Riot Client, registry, Garena and Tencent folders), best first
+ */
+export async function detectLeagueInstalls(): Promise<InstallCandidate[]> {
+    return invokeCommand('detect_league_installs');
+}
+
 interface LeagueInstallation {
     path: string;
     game_path: string;
@@ -160,15 +259,53 @@ export async function validateLeague(path: string): Promise<{ valid: boolean; pa
 // Champion Discovery Commands
 // =============================================================================
 
-export async function discoverChampions(leaguePath: string): Promise<Champion[]> {
-    return invokeCommand('discover_champions', { leaguePath });
+import type { ChampionInfo } from './bindings/ChampionInfo';
+import type { SkinInfo } from './bindings/SkinInfo';
+
+/**
+ * Discover the champions of a League installation.
+ * `includeIcons` adds each champion's square icon (base64 PNG) from its WAD;
+ * `includeMetadata` adds display names, titles and keys from Data Dragon.
+ */
+export async function discoverChampions(
+    leaguePath: string,
+    options: { includeIcons?: boolean; includeMetadata?: boolean } = {}
+): Promise<ChampionInfo[]> {
+    return invokeCommand('discover_champions', { leaguePath, ...options });
 }
 
+/**
+ * List a champion's skins with their display names and chroma parents
+ */
 export async function getChampionSkins(
     leaguePath: string,
-    championId: string
-): Promise<Array<{ id: number; name: string }>> {
-    return invokeCommand('get_champion_skins', { leaguePath, championId });
+    champion: string
+): Promise<SkinInfo[]> {
+    return invokeCommand('get_champion_skins', { leaguePath, champion });
+}
+
+interface SkinContentsPreview {
+    champion: string;
+    skin_id: number;
+    skin_bin: string;
+    linked_bins: Array<{ path: string; in_wad: boolean; size: number }>;
+    asset_types: Array<{ asset_type: string; count: number; missing: number; size: number }>;
+    referenced_count: number;
+    referenced_size: number;
+    extraction_count: number;
+    extraction_size: number;
+    warnings: string[];
+}
+
+/**
+ * Summarize a skin's linked BINs and referenced assets without extracting it
+ */
+export async function previewSkinContents(
+    leaguePath: string,
+    champion: string,
+    skinId: number
+): Promise<SkinContentsPreview> {
+    return invokeCommand('preview_skin_contents', { leaguePath, champion, skinId });
 }
 
 export async function searchChampions(
@@ -182,13 +319,25 @@ export async function searchChampions(
 // Project Management Commands
 // =============================================================================
 
+import type { TargetKind } from './bindings/TargetKind';
+
+import type { ExtractionFilter } from './bindings/ExtractionFilter';
+
 interface CreateProjectParams {
     name: string;
+    /** Champion name, or WAD name (e.g. "map11") for map and global projects */
     champion: string;
     skin: number;
     projectPath: string;
     leaguePath: string;
     creatorName?: string;
+    targetKind?: TargetKind;
+    /** Create the project from this WAD instead of looking the target up */
+    wadPath?: string;
+    /** Extract only one skin, some kinds of file or matching paths */
+    extractionFilter?: ExtractionFilter;
+    /** Put the project under git, with the extracted files as the first commit */
+    initGit?: boolean;
 }
 
 export async function createProject(params: CreateProjectParams): Promise<Project> {
@@ -199,9 +348,46 @@ export async function createProject(params: CreateProjectParams): Promise<Projec
         outputPath: params.projectPath,
         leaguePath: params.leaguePath,
         creatorName: params.creatorName,
+        targetKind: params.targetKind,
+        wadPath: params.wadPath,
+        extractionFilter: params.extractionFilter,
+        initGit: params.initGit,
     });
 }
 
+import type { RawImportResult } from './bindings/RawImportResult';
+
+/**
+ * Create a project from a loose ASSETS/DATA folder mod.
+ * With `unrepath` (the default) a repath prefix found under ASSETS/ is undone.
+ */
+export async function importRawMod(
+    folder: string,
+    outputPath: string,
+    options: { name?: string; leaguePath?: string; unrepath?: boolean } = {}
+): Promise<RawImportResult> {
+    return invokeCommand('import_raw_mod', { folder, outputPath, ...options });
+}
+
+/** Extract another champion skin into the project, next to the primary champion. */
+export async function addProjectTarget(
+    projectPath: string,
+    champion: string,
+    skinId: number,
+    filter?: ExtractionFilter
+): Promise<Project> {
+    return invokeCommand('add_project_target', { projectPath, champion, skinId, filter });
+}
+
+/**
+ * Stop the asset extraction running for a project. The checkpoint is kept, so
+ * an existing project can resume later; a project still being created is removed.
+ * Returns false if nothing was extracting.
+ */
+export async function cancelExtraction(projectPath: string): Promise<boolean> {
+    return invokeCommand('cancel_extraction', { projectPath });
+}
+
 export async function openProject(projectPath: string): Promise<Project> {
     return invokeCommand('open_project', { path: projectPath });
 }
@@ -210,6 +396,76 @@ export async function saveProject(project: Project): Promise<void> {
     return invokeCommand('save_project', { project });
 }
 
+import type { ProjectCompatibility } from './bindings/ProjectCompatibility';
+
+/**
+ * Compare the game version a project was extracted from with its League install.
+ * `update_advised` is set when the patch differs and assets should be updated first.
+ */
+export async function checkProjectCompatibility(projectPath: string): Promise<ProjectCompatibility> {
+    return invokeCommand('check_project_compatibility', { projectPath });
+}
+
+import type { RepathOptions } from './bindings/RepathOptions';
+
+/**
+ * Save the project's repath prefix template (placeholders `{creator}`, `{project}`,
+ * `{champion}`, `{skin}`, `{version}`), extensions left in place, and BIN-only mode.
+ */
+export async function setRepathOptions(projectPath: string, options: RepathOptions): Promise<Project> {
+    return invokeCommand('set_repath_options', { projectPath, options });
+}
+
+/**
+ * Set the thumbnail shown in mod managers from a PNG, JPEG, WebP, DDS or TEX image,
+ * or pass null to remove it. Exports embed it in modpkg metadata and the fantome META/image.png.
+ */
+export async function setProjectThumbnail(projectPath: string, imagePath: string | null): Promise<Project> {
+    return invokeCommand('set_project_thumbnail', { projectPath, imagePath });
+}
+
+import type { ProjectMetadata } from './bindings/ProjectMetadata';
+import type { MetadataUpdate } from './bindings/MetadataUpdate';
+
+/**
+ * Get the name, version, description, authors, license and thumbnail from mod.config.json.
+ */
+export async function getProjectMetadata(projectPath: string): Promise<ProjectMetadata> {
+    return invokeCommand('get_project_metadata', { projectPath });
+}
+
+/**
+ * Update mod.config.json metadata. Omitted fields are kept; the name must be a slug,
+ * the version semver (or pass `bump` instead), and a license with an empty name removes it.
+ * Exports read their metadata from here.
+ */
+export async function updateProjectMetadata(projectPath: string, update: Partial<MetadataUpdate>): Promise<ProjectMetadata> {
+    return invokeCommand('update_project_metadata', { projectPath, update });
+}
+
+import type { OrganizePlan } from './bindings/OrganizePlan';
+
+/**
+ * Show what repathing would do (concatenated BINs, rewritten paths, moved and
+ * deleted files) without changing the project.
+ */
+export async function previewOrganizeProject(
+    projectPath: string,
+    options: { creatorName?: string; projectName?: string } = {}
+): Promise<OrganizePlan> {
+    return invokeCommand('preview_organize_project', { projectPath, ...options });
+}
+
+import type { BinDependencyNode } from './bindings/BinDependencyNode';
+
+/**
+ * Get the linked BIN tree of the project's main skin BIN, with each BIN's
+ * category, object count and size, and which BINs concatenation would merge.
+ */
+export async function getBinDependencyTree(projectPath: string): Promise<BinDependencyNode> {
+    return invokeCommand('get_bin_dependency_tree', { projectPath });
+}
+
 // Backend file tree entry format
 interface BackendFileEntry {
     path: string;
@@ -284,6 +540,15 @@ export async function readWad(wadPath: string): Promise<{ version: string; chunk
     return invokeCommand('read_wad', { wadPath });
 }
 
+import type { WadIdentity } from './bindings/WadIdentity';
+
+/**
+ * Guess the champion, map or UI target of a loose WAD file
+ */
+export async function identifyWad(path: string): Promise<WadIdentity> {
+    return invokeCommand('identify_wad', { path });
+}
+
 export async function getWadChunks(
     wadPath: string
 ): Promise<Array<{ hash: string; path: string | null; size: number }>> {
@@ -293,9 +558,10 @@ export async function getWadChunks(
 export async function extractWad(
     wadPath: string,
     outputPath: string,
-    hashes: string[] | null = null
+    hashes: string[] | null = null,
+    filter: ExtractionFilter | null = null
 ): Promise<{ extracted: number }> {
-    return invokeCommand('extract_wad', { wadPath, outputPath, hashes });
+    return invokeCommand('extract_wad', { wadPath, outputPath, hashes, filter });
 }
 
 // =============================================================================
@@ -328,14 +594,171 @@ export async function parseBinFileToText(path: string): Promise<string> {
     return invokeCommand('parse_bin_file_to_text', { path });
 }
 
-export async function readOrConvertBin(binPath: string): Promise<string> {
-    return invokeCommand('read_or_convert_bin', { binPath });
+export async function readOrConvertBin(binPath: string, requestId?: string): Promise<string> {
+    return invokeCommand('read_or_convert_bin', { binPath, requestId });
 }
 
 export async function saveRitobinToBin(binPath: string, content: string): Promise<void> {
     return invokeCommand('save_ritobin_to_bin', { binPath, content });
 }
 
+import type { BinTreeNode } from './bindings/BinTreeNode';
+import type { BinObjectNode } from './bindings/BinObjectNode';
+import type { BinValue } from './bindings/BinValue';
+import type { BinField } from './bindings/BinField';
+import type { BinSearchMode } from './bindings/BinSearchMode';
+import type { BinSearchResult } from './bindings/BinSearchResult';
+import type { BinReplaceResult } from './bindings/BinReplaceResult';
+
+/**
+ * Read a BIN as typed nodes for the structured property editor
+ */
+export async function getBinTree(path: string): Promise<BinTreeNode> {
+    return invokeCommand('get_bin_tree', { path });
+}
+
+/**
+ * Replace one property value; the value must keep the property's kind.
+ * `propertyPath` starts with the object's field name, followed by field
+ * names, list indices or map keys.
+ */
+export async function setBinProperty(
+    path: string,
+    objectHash: number,
+    propertyPath: string[],
+    value: BinValue
+): Promise<BinObjectNode> {
+    return invokeCommand('set_bin_property', { path, objectHash, propertyPath, value });
+}
+
+export async function addBinObject(
+    path: string,
+    objectPath: string,
+    className: string,
+    properties?: BinField[]
+): Promise<BinObjectNode> {
+    return invokeCommand('add_bin_object', { path, objectPath, className, properties });
+}
+
+/**
+ * Search every BIN in a project for text, a hash name or `0x` value, or a regex
+ */
+export async function searchBins(
+    projectPath: string,
+    query: string,
+    mode?: BinSearchMode,
+    caseSensitive?: boolean,
+    maxResults?: number
+): Promise<BinSearchResult> {
+    return invokeCommand('search_bins', { projectPath, query, mode, caseSensitive, maxResults });
+}
+
+/**
+ * Replace text in string values across every BIN in a project.
+ * Use `dryRun` to preview the replacements first.
+ */
+export async function replaceInBins(
+    projectPath: string,
+    query: string,
+    replacement: string,
+    mode?: BinSearchMode,
+    caseSensitive?: boolean,
+    dryRun?: boolean
+): Promise<BinReplaceResult> {
+    return invokeCommand('replace_in_bins', { projectPath, query, replacement, mode, caseSensitive, dryRun });
+}
+
+import type { VfxSystemColors } from './bindings/VfxSystemColors';
+import type { VfxRecolor } from './bindings/VfxRecolor';
+import type { VfxRecolorResult } from './bindings/VfxRecolorResult';
+
+/**
+ * List the color values of every particle system in a BIN
+ */
+export async function getVfxColors(path: string): Promise<VfxSystemColors[]> {
+    return invokeCommand('get_vfx_colors', { path });
+}
+
+/**
+ * Recolor particle systems with an HSV shift or palette mapping.
+ * Only the systems in `objectHashes` are changed when it's given.
+ */
+export async function recolorVfx(
+    path: string,
+    recolor: VfxRecolor,
+    objectHashes?: number[]
+): Promise<VfxRecolorResult> {
+    return invokeCommand('recolor_vfx', { path, recolor, objectHashes });
+}
+
+import type { AudioContainerInfo } from './bindings/AudioContainerInfo';
+import type { AudioExtractResult } from './bindings/AudioExtractResult';
+import type { AudioReplaceResult } from './bindings/AudioReplaceResult';
+import type { SkinAudioEvents } from './bindings/SkinAudioEvents';
+
+/**
+ * List the WEMs and events of a BNK or WPK file
+ */
+export async function listAudioEvents(path: string): Promise<AudioContainerInfo> {
+    return invokeCommand('list_audio_events', { path });
+}
+
+/**
+ * Map the Wwise events of a skin or audio BIN to their banks and WEM ids
+ */
+export async function listAudioEventsForSkin(binPath: string): Promise<SkinAudioEvents> {
+    return invokeCommand('list_audio_events_for_skin', { binPath });
+}
+
+/**
+ * Extract WEMs from a BNK or WPK file, converting PCM to WAV when `convert` is set
+ */
+export async function extractAudio(
+    path: string,
+    outputDir: string,
+    names?: string[],
+    convert?: boolean
+): Promise<AudioExtractResult> {
+    return invokeCommand('extract_audio', { path, outputDir, names, convert });
+}
+
+/**
+ * Replace one WEM of a BNK or WPK file with a .wem or a 16-bit PCM WAV
+ */
+export async function replaceAudioClip(
+    path: string,
+    entry: string,
+    sourcePath: string
+): Promise<AudioReplaceResult> {
+    return invokeCommand('replace_audio_clip', { path, entry, sourcePath });
+}
+
+import type { StringTable } from './bindings/StringTable';
+
+/**
+ * Read the entries of a .stringtable file
+ */
+export async function readStringtable(path: string): Promise<StringTable> {
+    return invokeCommand('read_stringtable', { path });
+}
+
+/**
+ * Write a .stringtable file; entries with a `key` are stored under that key's hash
+ */
+export async function writeStringtable(path: string, table: StringTable): Promise<void> {
+    return invokeCommand('write_stringtable', { path, table });
+}
+
+import type { BenchmarkReport } from './bindings/BenchmarkReport';
+
+/**
+ * Time hashtable load, WAD decode and BIN conversion on generated data.
+ * Not shown in the UI; used when investigating performance reports.
+ */
+export async function runBenchmarks(iterations?: number): Promise<BenchmarkReport> {
+    return invokeCommand('run_benchmarks', { iterations });
+}
+
 export async function parseBinToTree(binPath: string): Promise<unknown[]> {
     return invokeCommand('parse_bin_to_tree', { binPath });
 }
@@ -348,11 +771,31 @@ export async function getBinPaths(binPath: string): Promise<unknown[]> {
 // File Commands (Preview System)
 // =============================================================================
 
-export async function readFileBytes(path: string): Promise<Uint8Array> {
-    const result = await invokeCommand<number[]>('read_file_bytes', { path });
+/**
+ * Read a file's bytes, or `length` bytes from `offset`.
+ * A single read is limited to 64 MB; read bigger files in chunks
+ * (`readFileInfo` gives the size).
+ */
+export async function readFileBytes(
+    path: string,
+    options: { offset?: number; length?: number } = {}
+): Promise<Uint8Array> {
+    const result = await invokeCommand<ArrayBuffer>('read_file_bytes', { path, ...options });
     return new Uint8Array(result);
 }
 
+import type { HexDump } from './bindings/HexDump';
+/**
+ * Read up to `length` bytes (default 4 KB, at most 64 KB) from `offset` as hex rows.
+ * WAD, BIN and SKN files come back with their header fields annotated.
+ */
+export async function readFileHex(
+    path: string,
+    options: { offset?: number; length?: number } = {}
+): Promise<HexDump> {
+    return invokeCommand('read_file_hex', { path, ...options });
+}
+
 interface FileInfo {
     path: string;
     size: number;
@@ -370,14 +813,98 @@ interface DecodedTexture {
     width: number;
     height: number;
     format: string;
+    mip_level: number;
+    mip_count: number;
 }
 
 /**
  * Decode DDS or TEX texture file to PNG
  * Despite the name, this handles both DDS and TEX formats
  */
-export async function decodeDdsToPng(path: string): Promise<DecodedTexture> {
-    return invokeCommand('decode_dds_to_png', { path });
+export async function decodeDdsToPng(path: string, requestId?: string): Promise<DecodedTexture> {
+    return invokeCommand('decode_dds_to_png', { path, requestId });
+}
+
+import type { TextureView } from './bindings/TextureView';
+
+/**
+ * Decode one mip level of a DDS or TEX texture to PNG, optionally isolating
+ * a channel, rebuilding a normal map or showing alpha over a checkerboard.
+ * The result carries `mip_count` so the caller can offer the other levels.
+ */
+export async function decodeTexture(
+    path: string,
+    options: { mip?: number; view?: TextureView; requestId?: string } = {}
+): Promise<DecodedTexture> {
+    return invokeCommand('decode_texture', { path, ...options });
+}
+
+import type { Thumbnail } from './bindings/Thumbnail';
+
+/**
+ * Get a small cached PNG preview of a texture, image or mesh for the project tree.
+ * `size` is the longest side in pixels (16-512).
+ */
+export async function getFileThumbnail(path: string, size: number): Promise<Thumbnail> {
+    return invokeCommand('get_file_thumbnail', { path, size });
+}
+
+/**
+ * Abandon a texture decode, mesh load or BIN conversion started with `requestId`.
+ * The cancelled command rejects with the `error.cancelled` key.
+ */
+export async function cancelRequest(requestId: string): Promise<boolean> {
+    return invokeCommand('cancel_request', { requestId });
+}
+
+interface TextureReplacement {
+    format: string;
+    width: number;
+    height: number;
+    mip_count: number;
+    previous_width: number;
+    previous_height: number;
+}
+
+/**
+ * Replace a DDS or TEX file with a PNG/JPG/TGA image, keeping its format
+ */
+export async function replaceTexture(sourcePath: string, targetPath: string): Promise<TextureReplacement> {
+    return invokeCommand('replace_texture', { sourcePath, targetPath });
+}
+
+type TexFormat = 'bc1' | 'bc3' | 'bgra8';
+
+interface TexConversion {
+    output_path: string;
+    format: TexFormat;
+    width: number;
+    height: number;
+    mip_count: number;
+}
+
+/**
+ * Convert a DDS file to TEX, keeping its compression unless `format` is given
+ */
+export async function convertDdsToTex(
+    inputPath: string,
+    outputPath?: string,
+    format?: TexFormat,
+    mipmaps?: boolean
+): Promise<TexConversion> {
+    return invokeCommand('convert_dds_to_tex', { inputPath, outputPath, format, mipmaps });
+}
+
+/**
+ * Convert a PNG to TEX (BC3 if it has transparency, BC1 otherwise, unless `format` is given)
+ */
+export async function convertPngToTex(
+    inputPath: string,
+    outputPath?: string,
+    format?: TexFormat,
+    mipmaps?: boolean
+): Promise<TexConversion> {
+    return invokeCommand('convert_png_to_tex', { inputPath, outputPath, format, mipmaps });
 }
 
 export async function readTextFile(path: string): Promise<string> {
@@ -435,6 +962,16 @@ export async function validateAssets(
     return invokeCommand('validate_assets', { assetPaths, wadPath });
 }
 
+import type { ValidationReport } from './bindings/ValidationReport';
+
+/**
+ * Check every BIN reference of a project. Paths the mod doesn't ship but a game
+ * WAD does are listed in `provided_by_game` instead of `missing_assets`.
+ */
+export async function validateProjectReferences(projectPath: string): Promise<ValidationReport> {
+    return invokeCommand('validate_project_references', { projectPath });
+}
+
 // =============================================================================
 // Export Commands
 // =============================================================================
@@ -452,6 +989,10 @@ interface ExportParams {
     format: 'fantome' | 'modpkg';
     champion: string;
     metadata: ExportMetadata;
+    /** Lets `cancelRequest` stop packing a fantome */
+    requestId?: string;
+    /** Re-open the package afterwards and check its files and BIN chain */
+    verify?: boolean;
 }
 
 export async function exportProject(params: ExportParams): Promise<{ path: string }> {
@@ -462,15 +1003,91 @@ export async function exportProject(params: ExportParams): Promise<{ path: strin
             champion: params.champion,
             metadata: params.metadata,
             autoRepath: true,
+            verify: params.verify,
+            requestId: params.requestId,
         });
     }
     // modpkg format
     return invokeCommand('export_modpkg', {
         projectPath: params.projectPath,
         outputPath: params.outputPath,
+        verify: params.verify,
     });
 }
 
+import type { ExportPreview } from './bindings/ExportPreview';
+
+/**
+ * List what an export would pack: per-file size, kind, WAD and chunk hash, with
+ * compressed-size estimates per WAD and for the whole package.
+ */
+export async function getExportPreview(projectPath: string): Promise<ExportPreview> {
+    return invokeCommand('get_export_preview', { projectPath });
+}
+
+import type { WadPackResult } from './bindings/WadPackResult';
+
+/**
+ * Pack a `.wad.client` folder of the base layer into a raw WAD.
+ * Writes to `output/{wadName}` in the project unless `outputPath` is given.
+ */
+export async function packWad(
+    projectPath: string,
+    options: { wadName?: string; outputPath?: string; requestId?: string } = {}
+): Promise<WadPackResult> {
+    return invokeCommand('pack_wad', { projectPath, ...options });
+}
+
+import type { ConflictReport } from './bindings/ConflictReport';
+
+/**
+ * Find project files that other mods also replace. `modPaths` may be .fantome
+ * or .modpkg files, mod folders, or a folder of mod folders such as cslol's `installed`.
+ */
+export async function checkConflicts(projectPath: string, modPaths: string[]): Promise<ConflictReport> {
+    return invokeCommand('check_conflicts', { projectPath, modPaths });
+}
+
+// =============================================================================
+// Deploy Commands (test in game)
+// =============================================================================
+
+import type { DeployProfile } from './bindings/DeployProfile';
+import type { DeployResult } from './bindings/DeployResult';
+import type { DeploySettings } from './bindings/DeploySettings';
+
+/**
+ * Get the saved deploy profiles and the last one used
+ */
+export async function listDeployProfiles(): Promise<DeploySettings> {
+    return invokeCommand('list_deploy_profiles');
+}
+
+/**
+ * Save a deploy profile, replacing one with the same name
+ */
+export async function saveDeployProfile(profile: DeployProfile): Promise<DeploySettings> {
+    return invokeCommand('save_deploy_profile', { profile });
+}
+
+/**
+ * Delete a deploy profile; mods it deployed stay installed
+ */
+export async function deleteDeployProfile(name: string): Promise<DeploySettings> {
+    return invokeCommand('delete_deploy_profile', { name });
+}
+
+/**
+ * Deploy a project into a cslol-manager installation and optionally start the overlay.
+ * Uses the last deploy profile unless `profileName` is given.
+ */
+export async function deployToOverlay(
+    projectPath: string,
+    options: { profileName?: string; requestId?: string } = {}
+): Promise<DeployResult> {
+    return invokeCommand('deploy_to_overlay', { projectPath, ...options });
+}
+
 // =============================================================================
 // Mesh Commands (3D Preview)
 // =============================================================================
@@ -498,8 +1115,8 @@ interface SknMeshData {
 /**
  * Read and parse an SKN (skinned mesh) file for 3D preview
  */
-export async function readSknMesh(path: string): Promise<SknMeshData> {
-    return invokeCommand('read_skn_mesh', { path });
+export async function readSknMesh(path: string, requestId?: string): Promise<SknMeshData> {
+    return invokeCommand('read_skn_mesh', { path, requestId });
 }
 
 // SCB/SCO Static Mesh types
@@ -521,6 +1138,74 @@ export async function readScbMesh(path: string): Promise<ScbMeshData> {
     return invokeCommand('read_scb_mesh', { path });
 }
 
+import type { MaterialParams } from './bindings/MaterialParams';
+
+/**
+ * List the shader params of a StaticMaterialDef
+ */
+export async function getMaterialParams(binPath: string, material: string): Promise<MaterialParams> {
+    return invokeCommand('get_material_params', { binPath, material });
+}
+
+/**
+ * Set a StaticMaterialDef shader param and save the BIN.
+ * Pass one value for scalar params (e.g. Emissive_Intensity), up to four for colors.
+ */
+export async function setMaterialParam(
+    binPath: string,
+    material: string,
+    param: string,
+    value: number[]
+): Promise<MaterialParams> {
+    return invokeCommand('set_material_param', { binPath, material, param, value });
+}
+
+import type { SkinSubmeshes } from './bindings/SkinSubmeshes';
+
+/**
+ * List the submeshes of an SKN with their texture override and visibility.
+ * The skin BIN is found next to the SKN unless `binPath` is given.
+ */
+export async function getSkinSubmeshes(sknPath: string, binPath?: string): Promise<SkinSubmeshes> {
+    return invokeCommand('get_skin_submeshes', { sknPath, binPath });
+}
+
+/**
+ * Set a submesh's texture override, or clear it with `null`
+ */
+export async function setSubmeshTexture(
+    sknPath: string,
+    submesh: string,
+    texture: string | null,
+    binPath?: string
+): Promise<SkinSubmeshes> {
+    return invokeCommand('set_submesh_texture', { sknPath, binPath, submesh, texture });
+}
+
+/**
+ * Hide or show a submesh by default (initialSubmeshToHide)
+ */
+export async function setSubmeshHidden(
+    sknPath: string,
+    submesh: string,
+    hidden: boolean,
+    binPath?: string
+): Promise<SkinSubmeshes> {
+    return invokeCommand('set_submesh_hidden', { sknPath, binPath, submesh, hidden });
+}
+
+/**
+ * Rename a submesh in the SKN and everywhere the skin BIN refers to it
+ */
+export async function renameSubmesh(
+    sknPath: string,
+    oldName: string,
+    newName: string,
+    binPath?: string
+): Promise<SkinSubmeshes> {
+    return invokeCommand('rename_submesh', { sknPath, binPath, oldName, newName });
+}
+
 // =============================================================================
 // Skeleton Commands (SKL)
 // =============================================================================
@@ -613,6 +1298,20 @@ export async function evaluateAnimation(
     return invokeCommand('evaluate_animation', { path, basePath, time });
 }
 
+import type { BakedAnimation } from './bindings/BakedAnimation';
+
+/**
+ * Bake an animation to skinning matrices for every frame in one call.
+ * Frame `f`, joint `j` (in SKL bone order) starts at `(f * joint_count + j) * 16`.
+ */
+export async function bakeAnimation(
+    path: string,
+    sklPath: string,
+    options: { fps?: number; basePath?: string } = {}
+): Promise<BakedAnimation> {
+    return invokeCommand('bake_animation', { path, sklPath, ...options });
+}
+
 /**
  * Resolve an asset path from a BIN file to an actual file path
  * Searches in WAD folders, extracted folders, and parent directories
@@ -684,4 +1383,86 @@ export async function readCheckpointFile(
     return invokeCommand('read_checkpoint_file', { projectPath, hash, filePath });
 }
 
+import type { SnapshotInfo } from './bindings/SnapshotInfo';
+
+/**
+ * Snapshot the project's `content/` folder so a destructive step can be undone.
+ * Repathing takes one automatically.
+ */
+export async function createSnapshot(projectPath: string, message: string): Promise<SnapshotInfo> {
+    return invokeCommand('create_snapshot', { projectPath, message });
+}
+
+export async function listSnapshots(projectPath: string): Promise<SnapshotInfo[]> {
+    return invokeCommand('list_snapshots', { projectPath });
+}
+
+/**
+ * Roll `content/` back to a snapshot. The current content is snapshotted first.
+ */
+export async function restoreSnapshot(projectPath: string, snapshotId: string): Promise<SnapshotInfo> {
+    return invokeCommand('restore_snapshot', { projectPath, snapshotId });
+}
+
+// =============================================================================
+// Version Control Commands
+// =============================================================================
+
+import type { CommitInfo } from './bindings/CommitInfo';
+import type { WorkingTreeDiff } from './bindings/WorkingTreeDiff';
+
+/**
+ * Put a project under git. Returns false if it already was a repository.
+ */
+export async function initProjectRepository(projectPath: string): Promise<boolean> {
+    return invokeCommand('init_project_repository', { projectPath });
+}
+
+/**
+ * Commit every change in the project
+ */
+export async function commitProject(projectPath: string, message: string): Promise<CommitInfo> {
+    return invokeCommand('commit_project', { projectPath, message });
+}
+
+/**
+ * List the project's commits, newest first
+ */
+export async function projectHistory(projectPath: string, limit?: number): Promise<CommitInfo[]> {
+    return invokeCommand('project_history', { projectPath, limit });
+}
+
+/**
+ * List files changed since the last commit
+ */
+export async function diffWorkingTree(projectPath: string): Promise<WorkingTreeDiff> {
+    return invokeCommand('diff_working_tree', { projectPath });
+}
+
+// =============================================================================
+// Trash Commands
+// =============================================================================
+
+import type { TrashSessionInfo } from './bindings/TrashSessionInfo';
+import type { RestoreResult } from './bindings/RestoreResult';
+
+/**
+ * List files repath cleanup moved to the project's `.trash/`, newest session first
+ */
+export async function listCleanedFiles(projectPath: string): Promise<TrashSessionInfo[]> {
+    return invokeCommand('list_cleaned_files', { projectPath });
+}
+
+/**
+ * Move cleaned files back. Restores the whole session when `paths` is omitted;
+ * files whose original path is taken again are reported as conflicts.
+ */
+export async function restoreCleanedFiles(
+    projectPath: string,
+    sessionId: string,
+    paths?: string[]
+): Promise<RestoreResult> {
+    return invokeCommand('restore_cleaned_files', { projectPath, sessionId, paths });
+}
+
 
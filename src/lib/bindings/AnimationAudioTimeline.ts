@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TimelineEvent } from "./TimelineEvent";
+
+/**
+ * Animation events of one clip, sorted by start time
+ */
+export type AnimationAudioTimeline = { clip: string, 
+/**
+ * .anm path referenced by the clip
+ */
+animation_path: string | null, events: Array<TimelineEvent>, };
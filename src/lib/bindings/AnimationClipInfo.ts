@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Information about a single animation clip
+ */
+export type AnimationClipInfo = { 
+/**
+ * Name/ID of the clip (from hash or derived from path)
+ */
+name: string, 
+/**
+ * Track data name (e.g., "Default")
+ */
+track_name: string | null, 
+/**
+ * Full path to the .anm file
+ */
+animation_path: string, };
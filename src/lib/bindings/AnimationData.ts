@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Parsed animation data from an ANM file
+ */
+export type AnimationData = { duration: number, fps: number, joint_count: number, joint_hashes: Array<number>, };
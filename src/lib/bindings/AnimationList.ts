@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AnimationClipInfo } from "./AnimationClipInfo";
+
+/**
+ * List of animations extracted from animation BIN file
+ */
+export type AnimationList = { 
+/**
+ * All animation clips found
+ */
+clips: Array<AnimationClipInfo>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JointTransform } from "./JointTransform";
+
+/**
+ * Animation pose containing all joint transforms at a specific time
+ */
+export type AnimationPose = { 
+/**
+ * Time in seconds
+ */
+time: number, 
+/**
+ * Joint hash → transform mapping
+ */
+joints: { [key in number]?: JointTransform }, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetNode } from "./AssetNode";
+
+/**
+ * Dependency graph of a project's enabled layers
+ */
+export type AssetGraph = { nodes: Array<AssetNode>, 
+/**
+ * Number of BIN → path edges
+ */
+edge_count: number, 
+/**
+ * Project BINs that couldn't be parsed, so their edges are absent
+ */
+unreadable_bins: Array<string>, };
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetLocation } from "./AssetLocation";
+
+/**
+ * One game path in the graph
+ */
+export type AssetNode = { 
+/**
+ * Game path, lowercase with '/' separators
+ */
+path: string, 
+/**
+ * Asset type based on extension (e.g. "Texture", "Binary")
+ */
+asset_type: string, location: AssetLocation, 
+/**
+ * Project files providing this path, relative to `content/base` or
+ * prefixed with their layer name
+ */
+files: Array<string>, 
+/**
+ * Indices of the nodes this BIN references
+ */
+references: Array<number>, 
+/**
+ * Indices of the BIN nodes referencing this path
+ */
+referenced_by: Array<number>, };
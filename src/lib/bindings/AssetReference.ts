@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Represents an asset reference found in a file
+ */
+export type AssetReference = { 
+/**
+ * The referenced path
+ */
+path: string, 
+/**
+ * Hash of the path
+ */
+path_hash: number, 
+/**
+ * Asset type based on extension
+ */
+asset_type: string, 
+/**
+ * Line number or offset where reference was found
+ */
+location: number | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * User setting for the asset store
+ */
+export type AssetStoreSettings = { 
+/**
+ * Place extracted files into projects through the shared store
+ */
+enabled: boolean, };
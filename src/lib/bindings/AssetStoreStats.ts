@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Size of the store on disk
+ */
+export type AssetStoreStats = { enabled: boolean, path: string, object_count: number, 
+/**
+ * Bytes held by the store; each object is counted once however many
+ * projects link to it
+ */
+total_bytes: number, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Statistics for a specific asset type
+ */
+export type AssetTypeStats = { 
+/**
+ * Total references of this type
+ */
+total: number, 
+/**
+ * Valid references of this type
+ */
+valid: number, 
+/**
+ * Missing references of this type
+ */
+missing: number, 
+/**
+ * Valid references of this type found only in game WADs
+ */
+provided_by_game: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Referenced assets of one type
+ */
+export type AssetTypeSummary = { 
+/**
+ * Asset type based on extension (e.g. "Texture", "Model")
+ */
+asset_type: string, count: number, 
+/**
+ * References not found in the champion WAD
+ */
+missing: number, 
+/**
+ * Uncompressed size of the referenced files found in the WAD
+ */
+size: number, };
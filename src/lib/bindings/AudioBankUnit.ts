@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AudioEventMapping } from "./AudioEventMapping";
+
+/**
+ * A `BankUnit` of a skin or audio BIN
+ */
+export type AudioBankUnit = { name: string, voice_over: boolean, bank_paths: Array<string>, 
+/**
+ * Bank paths with no file found near the BIN
+ */
+missing_banks: Array<string>, events: Array<AudioEventMapping>, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AudioContainerKind } from "./AudioContainerKind";
+import type { AudioEntry } from "./AudioEntry";
+
+/**
+ * Contents of a BNK or WPK file
+ */
+export type AudioContainerInfo = { kind: AudioContainerKind, version: number, 
+/**
+ * Sound bank id, for BNKs
+ */
+bank_id: number | null, entries: Array<AudioEntry>, 
+/**
+ * Ids of the bank's events; WPKs have none
+ */
+event_ids: Array<number>, };
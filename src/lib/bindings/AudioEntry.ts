@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WemCodec } from "./WemCodec";
+
+/**
+ * A WEM inside a BNK or WPK
+ */
+export type AudioEntry = { 
+/**
+ * Wwise media id; for WPK entries, parsed from the file name
+ */
+id: number | null, 
+/**
+ * File name the WEM is extracted under
+ */
+name: string, offset: number, size: number, codec: WemCodec, };
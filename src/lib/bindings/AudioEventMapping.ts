@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AudioEventWem } from "./AudioEventWem";
+
+/**
+ * A Wwise event declared by a bank unit
+ */
+export type AudioEventMapping = { name: string, 
+/**
+ * Wwise short id (FNV-1 of the lowercased name)
+ */
+event_id: number, 
+/**
+ * Whether a loaded bank defines the event
+ */
+defined: boolean, wems: Array<AudioEventWem>, };
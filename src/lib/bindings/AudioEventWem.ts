@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A WEM an event can play
+ */
+export type AudioEventWem = { 
+/**
+ * Wwise media id, also the WEM's entry name (`{id}.wem`)
+ */
+id: number, 
+/**
+ * Bank path (as written in the BIN) of the BNK or WPK holding the WEM
+ */
+container: string | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of an audio extraction
+ */
+export type AudioExtractResult = { 
+/**
+ * Written files, relative to the output folder
+ */
+extracted: Array<string>, 
+/**
+ * WEMs written as `.wem` because their codec couldn't be converted
+ */
+unconverted: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WemCodec } from "./WemCodec";
+
+/**
+ * Outcome of replacing a WEM
+ */
+export type AudioReplaceResult = { entry: string, old_size: number, new_size: number, codec: WemCodec, 
+/**
+ * Whether the source was a WAV converted to WEM
+ */
+reencoded: boolean, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An animation sampled at a fixed rate against a skeleton
+ */
+export type BakedAnimation = { 
+/**
+ * Samples per second
+ */
+fps: number, duration: number, frame_count: number, 
+/**
+ * Matrices per frame, the skeleton's bone count
+ */
+joint_count: number, 
+/**
+ * Skinning matrices (world * inverse bind) as column-major 4x4s, in
+ * SKL bone order within a frame and frame after frame, so frame `f`
+ * joint `j` starts at `(f * joint_count + j) * 16`
+ */
+matrices: Array<number>, };
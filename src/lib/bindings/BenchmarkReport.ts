@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BenchmarkResult } from "./BenchmarkResult";
+import type { MachineProfile } from "./MachineProfile";
+
+export type BenchmarkReport = { machine: MachineProfile, results: Array<BenchmarkResult>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Timings of one benchmark
+ */
+export type BenchmarkResult = { name: string, 
+/**
+ * What one iteration processes
+ */
+workload: string, iterations: number, mean_ms: number, min_ms: number, max_ms: number, };
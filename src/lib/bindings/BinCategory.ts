@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Category of a BIN file based on its path pattern
+ */
+export type BinCategory = "champion_root" | "animation" | "linked_data" | "ignore";
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinCategory } from "./BinCategory";
+
+/**
+ * A BIN in the dependency tree of a main skin BIN
+ */
+export type BinDependencyNode = { 
+/**
+ * Normalized path (lowercase, forward slashes), relative to the WAD folder
+ */
+path: string, category: BinCategory, 
+/**
+ * False if the BIN isn't in the project (game data or missing)
+ */
+in_project: boolean, 
+/**
+ * Merged into the concat BIN when the project is concatenated
+ */
+concatenated: boolean, object_count: number, file_size: number, 
+/**
+ * Already listed earlier in the tree (or links back to an ancestor);
+ * its size and links are only filled in where it first appears
+ */
+repeated: boolean, 
+/**
+ * Why the BIN couldn't be read
+ */
+error: string | null, children: Array<BinDependencyNode>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinValue } from "./BinValue";
+
+/**
+ * A named property of an object or struct
+ */
+export type BinField = { name_hash: number, name: string | null, value: BinValue, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinObjectHeader } from "./BinObjectHeader";
+
+/**
+ * Object listing of a BIN read without parsing object bodies
+ */
+export type BinIndex = { version: number, is_override: boolean, dependencies: Array<string>, file_size: number, objects: Array<BinObjectHeader>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Header of one object in a BIN opened in streaming mode
+ */
+export type BinObjectHeader = { path_hash: number, class_hash: number, 
+/**
+ * Entry path, if the hash is known
+ */
+path_name: string | null, 
+/**
+ * Class name, if the hash is known
+ */
+class_name: string | null, 
+/**
+ * Size of the object body in bytes
+ */
+size: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinField } from "./BinField";
+
+/**
+ * A top-level object of a BIN
+ */
+export type BinObjectNode = { path_hash: number, path_name: string | null, class_hash: number, class_name: string | null, properties: Array<BinField>, };
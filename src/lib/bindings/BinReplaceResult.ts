@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinReplacement } from "./BinReplacement";
+
+export type BinReplaceResult = { replacements: Array<BinReplacement>, 
+/**
+ * BINs that were rewritten; empty in a dry run
+ */
+files_changed: Array<string>, files_searched: number, 
+/**
+ * BINs that couldn't be parsed or saved, with the reason
+ */
+files_skipped: Array<string>, dry_run: boolean, };
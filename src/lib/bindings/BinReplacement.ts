@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One string value rewritten by a replace (or, in a dry run, that would be)
+ */
+export type BinReplacement = { 
+/**
+ * BIN file, relative to the searched folder
+ */
+file: string, object_hash: number, object_name: string | null, property_path: Array<string>, old_value: string, new_value: string, };
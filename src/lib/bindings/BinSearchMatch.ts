@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One place a query matched
+ */
+export type BinSearchMatch = { 
+/**
+ * BIN file, relative to the searched folder
+ */
+file: string, object_hash: number, object_name: string | null, 
+/**
+ * Empty when the object's own path matched
+ */
+property_path: Array<string>, 
+/**
+ * The matched value, as text
+ */
+value: string, };
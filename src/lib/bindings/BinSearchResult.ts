@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinSearchMatch } from "./BinSearchMatch";
+
+export type BinSearchResult = { matches: Array<BinSearchMatch>, files_searched: number, 
+/**
+ * BINs that couldn't be parsed, with the reason
+ */
+files_skipped: Array<string>, 
+/**
+ * More matches were found than `max_results`
+ */
+truncated: boolean, };
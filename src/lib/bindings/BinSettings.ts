@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Persisted BIN parsing settings
+ */
+export type BinSettings = { 
+/**
+ * Files above this size are rejected by a full parse
+ */
+max_bin_size_mb: number, };
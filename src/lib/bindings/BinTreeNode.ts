@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinObjectNode } from "./BinObjectNode";
+
+/**
+ * A whole BIN as a tree of typed nodes
+ */
+export type BinTreeNode = { version: number, is_override: boolean, dependencies: Array<string>, objects: Array<BinObjectNode>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinField } from "./BinField";
+import type { BinKind } from "./BinKind";
+import type { BinMapEntry } from "./BinMapEntry";
+
+/**
+ * A BIN value as the frontend sees it
+ *
+ * `name` fields are filled from the hash lists when reading and ignored when
+ * writing; only the hashes are stored in the BIN.
+ */
+export type BinValue = { "kind": "none" } | { "kind": "bool", value: boolean, } | { "kind": "i8", value: number, } | { "kind": "u8", value: number, } | { "kind": "i16", value: number, } | { "kind": "u16", value: number, } | { "kind": "i32", value: number, } | { "kind": "u32", value: number, } | { "kind": "i64", value: number, } | { "kind": "u64", value: number, } | { "kind": "f32", value: number, } | { "kind": "vec2", value: [number, number], } | { "kind": "vec3", value: [number, number, number], } | { "kind": "vec4", value: [number, number, number, number], } | { "kind": "mtx44", value: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], } | { "kind": "rgba", value: [number, number, number, number], } | { "kind": "string", value: string, } | { "kind": "hash", value: number, name: string | null, } | { "kind": "file", value: number, } | { "kind": "list", item_kind: BinKind, items: Array<BinValue>, } | { "kind": "list2", item_kind: BinKind, items: Array<BinValue>, } | { "kind": "pointer", class_hash: number, class_name: string | null, properties: Array<BinField>, } | { "kind": "embed", class_hash: number, class_name: string | null, properties: Array<BinField>, } | { "kind": "link", value: number, name: string | null, } | { "kind": "option", item_kind: BinKind, value: BinValue | null, } | { "kind": "map", key_kind: BinKind, value_kind: BinKind, entries: Array<BinMapEntry>, } | { "kind": "flag", value: boolean, };
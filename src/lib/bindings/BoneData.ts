@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Bone data for a single joint in the skeleton
+ */
+export type BoneData = { name: string, id: number, parent_id: number, 
+/**
+ * Local translation relative to parent [x, y, z]
+ */
+local_translation: [number, number, number], 
+/**
+ * Local rotation as quaternion [x, y, z, w]
+ */
+local_rotation: [number, number, number, number], 
+/**
+ * Local scale [x, y, z]
+ */
+local_scale: [number, number, number], 
+/**
+ * World position in bind pose (computed from inverted bind matrix) [x, y, z]
+ */
+world_position: [number, number, number], 
+/**
+ * Inverse bind matrix for skinning (4x4 matrix in column-major order)
+ * Transforms vertices from model space to bone-local space
+ */
+inverse_bind_matrix: [[number, number, number, number], [number, number, number, number], [number, number, number, number], [number, number, number, number]], };
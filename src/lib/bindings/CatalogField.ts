@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A sampler or param observed on a shader
+ */
+export type CatalogField = { 
+/**
+ * Raw name as it appears in the BIN (e.g., "Diffuse_Texture")
+ */
+name: string, 
+/**
+ * Human-friendly label (e.g., "Diffuse (base color)")
+ */
+label: string, 
+/**
+ * Number of materials using this field
+ */
+count: number, };
@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkinInfo } from "./SkinInfo";
+
+/**
+ * Represents a discovered champion
+ */
+export type ChampionInfo = { 
+/**
+ * Display name of the champion
+ */
+name: string, 
+/**
+ * Internal name used in file paths (e.g., "Ahri")
+ */
+internal_name: string, 
+/**
+ * List of available skins
+ */
+skins: Array<SkinInfo>, 
+/**
+ * Path to champion WAD file
+ */
+wad_path: string | null, 
+/**
+ * Champion title (e.g. "the Nine-Tailed Fox"), when roster metadata was requested
+ */
+title: string | null, 
+/**
+ * Numeric champion id used by the game client (e.g. 103 for Ahri)
+ */
+key: number | null, 
+/**
+ * Square icon as base64-encoded PNG, when icons were requested
+ */
+icon: string | null, };
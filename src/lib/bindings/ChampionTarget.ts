@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Another champion skin a project pulls assets from
+ *
+ * Its files live in their own `{champion}.wad.client` folder next to the
+ * primary champion's.
+ */
+export type ChampionTarget = { champion: string, skin_id: number, };
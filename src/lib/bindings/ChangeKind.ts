@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a file differs from the last commit
+ */
+export type ChangeKind = "added" | "modified" | "deleted" | "renamed" | "untracked";
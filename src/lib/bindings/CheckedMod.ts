@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One mod compared against the project
+ */
+export type CheckedMod = { 
+/**
+ * File or folder name of the mod
+ */
+name: string, path: string, 
+/**
+ * Chunks the mod ships
+ */
+chunk_count: number, 
+/**
+ * Of those, chunks the project also ships
+ */
+conflict_count: number, 
+/**
+ * Why the mod couldn't be read
+ */
+error: string | null, };
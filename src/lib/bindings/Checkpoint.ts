@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileEntry } from "./FileEntry";
+
+export type Checkpoint = { id: string, timestamp: string, message: string, author: string | null, tags: Array<string>, file_manifest: { [key in string]?: FileEntry }, };
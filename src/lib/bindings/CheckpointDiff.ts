@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileEntry } from "./FileEntry";
+
+export type CheckpointDiff = { added: Array<FileEntry>, modified: Array<[FileEntry, FileEntry]>, deleted: Array<FileEntry>, };
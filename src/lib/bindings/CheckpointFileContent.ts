@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Content types returned when reading a checkpoint file for preview
+ */
+export type CheckpointFileContent = { "type": "image", data: string, width: number, height: number, } | { "type": "text", data: string, } | { "type": "binary", size: number, };
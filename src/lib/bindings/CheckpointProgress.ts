@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Progress information emitted during checkpoint creation
+ */
+export type CheckpointProgress = { phase: string, current: number, total: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A project file other mods also replace
+ */
+export type ChunkConflict = { 
+/**
+ * WAD the file belongs to (e.g. "ahri.wad.client")
+ */
+wad: string, 
+/**
+ * Game path of the file, or its hex path hash if no side knows the name
+ */
+path: string, 
+/**
+ * Names of the mods shipping the same chunk
+ */
+mods: Array<string>, };
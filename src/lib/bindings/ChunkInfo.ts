@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Information about a chunk within a WAD archive
+ */
+export type ChunkInfo = { path_hash: string, resolved_path: string | null, compressed_size: number, uncompressed_size: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of patching a chunk
+ */
+export type ChunkPatchResult = { 
+/**
+ * Hex path hash of the patched chunk
+ */
+path_hash: string, old_size: number, new_size: number, compressed_size: number, compression: string, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of replacing an animation clip
+ */
+export type ClipReplacement = { clip: string, 
+/**
+ * Animation BIN that was updated
+ */
+bin_path: string, 
+/**
+ * Previous `mAnimationFilePath`
+ */
+old_animation_path: string, 
+/**
+ * New `mAnimationFilePath`
+ */
+new_animation_path: string, 
+/**
+ * Where the .anm was copied to on disk
+ */
+copied_to: string, 
+/**
+ * Skeleton the animation was checked against, if one was found
+ */
+skeleton: string | null, 
+/**
+ * Joint hashes animated by the clip that the skeleton doesn't have
+ */
+unmatched_tracks: Array<number>, 
+/**
+ * Number of skeleton joints the clip doesn't animate (they stay in bind pose)
+ */
+unanimated_joints: number, warnings: Array<string>, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Color transform applied to a texture
+ */
+export type ColorShift = { "mode": "hsl", 
+/**
+ * Degrees added to the hue
+ */
+hue: number, 
+/**
+ * Saturation multiplier (1.0 keeps it)
+ */
+saturation: number, 
+/**
+ * Lightness multiplier (1.0 keeps it)
+ */
+brightness: number, } | { "mode": "tint", 
+/**
+ * Target hue in degrees
+ */
+hue: number, preserve_saturation: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A texture [`apply_color_shift`] couldn't recolor
+ */
+export type ColorShiftFailure = { path: string, error: string, };
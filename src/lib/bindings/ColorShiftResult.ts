@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ColorShiftFailure } from "./ColorShiftFailure";
+
+/**
+ * Outcome of recoloring the textures of a layer
+ */
+export type ColorShiftResult = { 
+/**
+ * Recolored textures, relative to the layer folder
+ */
+recolored: Array<string>, failed: Array<ColorShiftFailure>, };
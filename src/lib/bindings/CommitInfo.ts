@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One commit of a project's history
+ */
+export type CommitInfo = { id: string, 
+/**
+ * First line of the message
+ */
+summary: string, message: string, author: string, timestamp: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a project's game version relates to the installed one
+ */
+export type CompatibilityStatus = "match" | "hotfix" | "newer_patch" | "older_patch" | "unknown";
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An object defined by more than one BIN of a concatenation
+ */
+export type ConcatCollision = { path_hash: number, 
+/**
+ * Object name from the hash tables, if known
+ */
+name: string | null, 
+/**
+ * BINs defining the object: the main skin BIN first if it does, then
+ * the linked BINs in link order
+ */
+sources: Array<string>, 
+/**
+ * BIN whose definition ships; None if the policy leaves it unresolved
+ */
+winner: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How concatenation settles an object defined by more than one BIN
+ */
+export type ConcatMergePolicy = { "type": "last_wins" } | { "type": "prefer_main_skin" } | { "type": "error" } | { "type": "resolve", choices: { [key in number]?: string }, };
@@ -0,0 +1,33 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConcatCollision } from "./ConcatCollision";
+
+/**
+ * What concatenation would do, worked out without writing anything
+ *
+ * Paths are relative to the folder the concat runs in, as in [`ConcatResult`].
+ */
+export type ConcatPlan = { 
+/**
+ * Main skin BIN whose linked list is rewritten
+ */
+main_bin: string, 
+/**
+ * Concat BIN that would be written
+ */
+concat_path: string, 
+/**
+ * Linked BINs merged into the concat BIN, then moved to the trash
+ */
+sources: Array<string>, 
+/**
+ * The main BIN's linked list afterwards
+ */
+new_links: Array<string>, 
+/**
+ * Linked Type 3 BINs that aren't on disk
+ */
+missing: Array<string>, 
+/**
+ * Objects defined by more than one BIN; any without a winner would stop the concat
+ */
+collisions: Array<ConcatCollision>, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CheckedMod } from "./CheckedMod";
+import type { ChunkConflict } from "./ChunkConflict";
+
+/**
+ * Result of checking a project against other mods
+ */
+export type ConflictReport = { 
+/**
+ * Chunks the project's export would contain
+ */
+project_chunks: number, mods: Array<CheckedMod>, 
+/**
+ * Overlapping files, sorted by WAD and path
+ */
+conflicts: Array<ChunkConflict>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kinds of assets the game loads by path convention
+ */
+export type ConventionalAssetKind = "LoadScreen" | "SquareIcon" | "CircleIcon";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConventionalAssetKind } from "./ConventionalAssetKind";
+
+/**
+ * Presence of one conventional asset in a project
+ */
+export type ConventionalAssetStatus = { kind: ConventionalAssetKind, label: string, 
+/**
+ * Path of the matching file (relative to its WAD folder), if present
+ */
+found_path: string | null, };
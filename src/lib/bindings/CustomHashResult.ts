@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HashSource } from "./HashSource";
+
+/**
+ * Result of adding hashes to the user list
+ */
+export type CustomHashResult = { 
+/**
+ * Mappings newly written to the list
+ */
+added: number, 
+/**
+ * Mappings the list already held
+ */
+duplicates: number, source: HashSource, };
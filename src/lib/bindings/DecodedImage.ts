@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of decoding a DDS file
+ */
+export type DecodedImage = { 
+/**
+ * Base64-encoded PNG data
+ */
+data: string, 
+/**
+ * Size of the decoded mip level
+ */
+width: number, height: number, format: string, mip_level: number, mip_count: number, };
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DeployFormat } from "./DeployFormat";
+
+/**
+ * Where and how a project is deployed
+ */
+export type DeployProfile = { 
+/**
+ * Name the profile is saved under
+ */
+name: string, 
+/**
+ * cslol-manager folder holding `installed/` and `profiles/`
+ */
+manager_path: string, 
+/**
+ * cslol profile the mod is enabled in
+ */
+cslol_profile: string | null, 
+/**
+ * League `Game` folder for the overlay; the project's League
+ * installation when not set
+ */
+game_path: string | null, format: DeployFormat, 
+/**
+ * Rebuild the overlay and start the runner after deploying
+ */
+run_overlay: boolean, };
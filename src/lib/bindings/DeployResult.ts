@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of deploying a project
+ */
+export type DeployResult = { 
+/**
+ * Folder name of the mod in the manager
+ */
+mod_name: string, mod_path: string, 
+/**
+ * WAD folders or files written
+ */
+wad_count: number, 
+/**
+ * Whether the overlay runner was started
+ */
+overlay_started: boolean, warnings: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DeployProfile } from "./DeployProfile";
+
+/**
+ * Saved deploy profiles
+ */
+export type DeploySettings = { profiles: Array<DeployProfile>, 
+/**
+ * Profile used by the last deploy
+ */
+last_profile: string | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiffLine } from "./DiffLine";
+
+/**
+ * A run of changes with surrounding context
+ */
+export type DiffHunk = { 
+/**
+ * 1-based first line of the hunk in the old text
+ */
+old_start: number, old_count: number, 
+/**
+ * 1-based first line of the hunk in the new text
+ */
+new_start: number, new_count: number, lines: Array<DiffLine>, };
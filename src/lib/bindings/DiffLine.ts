@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiffLineKind } from "./DiffLineKind";
+
+/**
+ * One line of a hunk
+ */
+export type DiffLine = { kind: DiffLineKind, 
+/**
+ * 1-based line number in the old text (not set for added lines)
+ */
+old_line: number | null, 
+/**
+ * 1-based line number in the new text (not set for removed lines)
+ */
+new_line: number | null, text: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Statistics about a hash download operation
+ */
+export type DownloadStats = { downloaded: number, skipped: number, errors: number, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single node of the virtual tree inside a WAD
+ */
+export type ExplorerNode = { 
+/**
+ * Last path component (directory or file name)
+ */
+name: string, 
+/**
+ * Full virtual path inside the WAD (lowercase, forward slashes)
+ */
+path: string, is_dir: boolean, 
+/**
+ * Hex path hash for files, None for directories
+ */
+path_hash: string | null, 
+/**
+ * Uncompressed size for files, sum of all descendants for directories
+ */
+size: number, 
+/**
+ * Number of files below this node (1 for files)
+ */
+file_count: number, };
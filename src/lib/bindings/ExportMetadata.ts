@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Metadata for export operations (received from frontend)
+ */
+export type ExportMetadata = { name: string, author: string, version: string, description: string, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportPreviewFile } from "./ExportPreviewFile";
+import type { ExportPreviewWad } from "./ExportPreviewWad";
+
+/**
+ * Everything an export would contain
+ */
+export type ExportPreview = { 
+/**
+ * Files of every enabled layer, lowest priority first
+ */
+files: Array<ExportPreviewFile>, 
+/**
+ * Per-WAD totals of the files that aren't overridden
+ */
+wads: Array<ExportPreviewWad>, 
+/**
+ * Uncompressed size of the files that aren't overridden
+ */
+total_size: number, 
+/**
+ * Estimated package size: the compressed files that aren't overridden
+ */
+compressed_size: number, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file an export would contain
+ */
+export type ExportPreviewFile = { 
+/**
+ * Path relative to `content/base`, prefixed with the layer name for other layers
+ */
+path: string, layer: string, 
+/**
+ * WAD folder the file is packed into (e.g. "ahri.wad.client"), None outside one
+ */
+wad: string | null, 
+/**
+ * Hex path hash of the file's chunk in that WAD
+ */
+chunk_hash: string | null, 
+/**
+ * Kind detected from the file's contents, or its extension
+ */
+kind: string, size: number, 
+/**
+ * Size once compressed the way the WAD packer does
+ */
+compressed_size: number, 
+/**
+ * A higher-priority layer ships the same file, so this copy isn't seen in game
+ */
+overridden: boolean, };
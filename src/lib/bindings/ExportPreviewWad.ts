@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Totals for one WAD of an export
+ */
+export type ExportPreviewWad = { name: string, chunk_count: number, size: number, compressed_size: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PackageVerification } from "./PackageVerification";
+
+/**
+ * Result of export operation (sent to frontend)
+ */
+export type ExportResult = { success: boolean, output_path: string, file_count: number, total_size: number, message: string, 
+/**
+ * Path of the README manifest written next to the package, if generated
+ */
+manifest_path: string | null, 
+/**
+ * Smoke test of the written package, if requested
+ */
+verification: PackageVerification | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Broad kind of a WAD file, by extension
+ */
+export type ExtractFileKind = "texture" | "bin" | "mesh" | "animation" | "audio" | "other";
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExtractFileKind } from "./ExtractFileKind";
+
+/**
+ * Which chunks an extraction should write
+ *
+ * The default keeps everything. Leaving BINs out means repath has no skin
+ * BIN to work from, so the project keeps the game's paths.
+ */
+export type ExtractionFilter = { 
+/**
+ * Drop files that belong to other skins (`skins/skinN/...`, `skinN.bin`);
+ * base skin files are always kept since other skins build on them
+ */
+skin_id: number | null, 
+/**
+ * Kinds of file to keep, all if empty
+ */
+kinds: Array<ExtractFileKind>, 
+/**
+ * Globs a path must match one of (`assets/**/*.dds`), all if empty
+ */
+include: Array<string>, 
+/**
+ * Globs of paths to leave out, applied after `include`
+ */
+exclude: Array<string>, };
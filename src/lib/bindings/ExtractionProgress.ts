@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A progress update sent as each chunk is finished
+ */
+export type ExtractionProgress = { current: number, total: number, 
+/**
+ * Chunk that was just finished
+ */
+item: string, };
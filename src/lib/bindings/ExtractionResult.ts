@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of a WAD extraction operation
+ */
+export type ExtractionResult = { extracted_count: number, failed_count: number, 
+/**
+ * Chunks whose paths collided and were saved under disambiguated names
+ */
+warnings: Array<string>, };
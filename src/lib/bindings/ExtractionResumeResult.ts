@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of resuming an interrupted project extraction
+ */
+export type ExtractionResumeResult = { 
+/**
+ * Chunks written by this run
+ */
+extracted_count: number, 
+/**
+ * Chunks the interrupted run had already written intact
+ */
+skipped_count: number, warnings: Array<string>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IssueSeverity } from "./IssueSeverity";
+
+/**
+ * A structural problem found in a package
+ */
+export type FantomeIssue = { severity: IssueSeverity, 
+/**
+ * Stable identifier for the kind of issue (e.g. "missing_info")
+ */
+code: string, 
+/**
+ * Archive entry the issue applies to
+ */
+entry: string | null, message: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Metadata read from `META/info.json`
+ */
+export type FantomeMetadata = { name: string, author: string, version: string, description: string, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FantomeIssue } from "./FantomeIssue";
+import type { FantomeMetadata } from "./FantomeMetadata";
+
+/**
+ * Result of validating a .fantome package
+ */
+export type FantomeValidationReport = { 
+/**
+ * True if no errors were found (warnings are allowed)
+ */
+valid: boolean, entry_count: number, 
+/**
+ * WAD folder or packed WAD names under `WAD/`
+ */
+wads: Array<string>, metadata: FantomeMetadata | null, issues: Array<FantomeIssue>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeKind } from "./ChangeKind";
+
+/**
+ * One changed file in the working tree
+ */
+export type FileChange = { 
+/**
+ * Path relative to the project, with `/` separators
+ */
+path: string, kind: ChangeKind, 
+/**
+ * Binary files (textures, meshes, BINs) have no line counts
+ */
+binary: boolean, insertions: number, deletions: number, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetType } from "./AssetType";
+
+export type FileEntry = { path: string, hash: string, size: number, asset_type: AssetType, };
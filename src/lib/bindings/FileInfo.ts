@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Information about a file
+ */
+export type FileInfo = { path: string, size: number, file_type: string, extension: string, 
+/**
+ * For images: width x height
+ */
+dimensions: [number, number] | null, };
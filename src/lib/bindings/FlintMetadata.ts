@@ -0,0 +1,58 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionTarget } from "./ChampionTarget";
+import type { RepathOptions } from "./RepathOptions";
+import type { TargetKind } from "./TargetKind";
+
+/**
+ * Flint-specific metadata (stored separately from mod.config.json)
+ */
+export type FlintMetadata = { 
+/**
+ * Champion internal name (e.g., "Ahri"), or the WAD name for map and
+ * global projects (e.g., "map11")
+ */
+champion: string, 
+/**
+ * What the project modifies; projects from before map support are champions
+ */
+target_kind: TargetKind, 
+/**
+ * Skin ID (0 for base skin)
+ */
+skin_id: number, 
+/**
+ * Champion skins the project uses besides the primary one
+ */
+extra_targets: Array<ChampionTarget>, 
+/**
+ * Path to League of Legends installation
+ */
+league_path: string | null, 
+/**
+ * Game client version detected at the League path (e.g., "14.23.636.7270")
+ */
+game_version: string | null, 
+/**
+ * User-defined variables substituted in BIN text (`${NAME}`)
+ */
+variables: { [key in string]?: string }, 
+/**
+ * Replace variable values with `${NAME}` when converting BIN to text
+ */
+collapse_variables: boolean, 
+/**
+ * Layers left out of exports (league-mod has no per-layer toggle)
+ */
+disabled_layers: Array<string>, 
+/**
+ * Repath prefix template and strategy
+ */
+repath: RepathOptions, 
+/**
+ * When the project was created (ISO 8601)
+ */
+created_at: string, 
+/**
+ * When the project was last modified (ISO 8601)
+ */
+modified_at: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Names and glyph count read from a TrueType/OpenType font
+ */
+export type FontInfo = { 
+/**
+ * "truetype" or "opentype"
+ */
+format: string, family: string | null, style: string | null, glyph_count: number | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A reference the mod doesn't ship but the game does
+ */
+export type GameProvidedAsset = { 
+/**
+ * The path that was referenced
+ */
+path: string, 
+/**
+ * Source file that contains this reference
+ */
+source_file: string, 
+/**
+ * Asset type based on file extension
+ */
+asset_type: string, 
+/**
+ * WAD holding the path, relative to `DATA/FINAL` when possible
+ */
+wad: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Category a game WAD belongs to, based on its location under DATA/FINAL
+ */
+export type GameWadCategory = "Champions" | "Maps" | "Global";
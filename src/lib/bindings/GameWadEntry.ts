@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GameWadCategory } from "./GameWadCategory";
+
+/**
+ * A WAD archive found in the League installation
+ */
+export type GameWadEntry = { 
+/**
+ * File name (e.g., "Ahri.wad.client")
+ */
+name: string, 
+/**
+ * Path relative to DATA/FINAL using forward slashes
+ */
+relative_path: string, 
+/**
+ * Absolute path to the WAD file
+ */
+path: string, category: GameWadCategory, 
+/**
+ * Size of the WAD file on disk in bytes
+ */
+size: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HashVariant } from "./HashVariant";
+
+/**
+ * Result of looking up a single hash
+ */
+export type HashLookup = { 
+/**
+ * The hash in canonical hex form (8 digits for FNV, 16 for xxh64)
+ */
+hash: string, variant: HashVariant, 
+/**
+ * True when the variant was detected from the input rather than given
+ */
+detected: boolean, 
+/**
+ * The name or path, if the hash is known
+ */
+path: string | null, };
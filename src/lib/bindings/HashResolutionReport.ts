@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceResolution } from "./SourceResolution";
+
+/**
+ * Result of auditing several sources after a hash update
+ */
+export type HashResolutionReport = { 
+/**
+ * Sources with at least one newly resolved hash
+ */
+improved: Array<SourceResolution>, 
+/**
+ * Number of sources that were audited successfully
+ */
+scanned: number, 
+/**
+ * Sources that couldn't be read, with the reason
+ */
+failed: Array<string>, 
+/**
+ * Summary line per improved source
+ */
+messages: Array<string>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One hash list file in the hash directory
+ */
+export type HashSource = { 
+/**
+ * File name, used to remove the source
+ */
+name: string, 
+/**
+ * True for user lists, which take priority over CDragon lists
+ */
+custom: boolean, size: number, 
+/**
+ * Number of mappings, counted for user lists only
+ */
+entries: number | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Status information about the loaded hashtable
+ */
+export type HashStatus = { loaded_count: number, 
+/**
+ * xxh64 WAD path hashes
+ */
+path_count: number, 
+/**
+ * FNV-1a BIN entry/field/type name hashes
+ */
+bin_name_count: number, last_updated: string | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Persisted hash update schedule
+ */
+export type HashUpdateSchedule = { 
+/**
+ * Unix timestamp (seconds) of the last update check, successful or not
+ */
+last_check: number | null, 
+/**
+ * Minimum hours between automatic checks
+ */
+interval_hours: number, };
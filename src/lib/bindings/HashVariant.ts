@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Hash function a hash value was computed with
+ *
+ * BIN entry, field and type names use 32-bit FNV-1a, WAD chunk paths use
+ * 64-bit xxHash. Both are computed over the lowercased string.
+ */
+export type HashVariant = "fnv1a32" | "xxh64";
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Hashes of a name or path a mod author typed in
+ */
+export type HashedString = { 
+/**
+ * xxh64 of the lowercased path, as WAD chunks are keyed (16 hex digits)
+ */
+xxh64: string, 
+/**
+ * FNV-1a of the lowercased name, as BIN entries and fields are keyed (8 hex digits)
+ */
+fnv1a32: string, 
+/**
+ * True when a loaded hash file already lists the path
+ */
+known_path: boolean, 
+/**
+ * True when a loaded hash file already lists the BIN name
+ */
+known_bin_name: boolean, };
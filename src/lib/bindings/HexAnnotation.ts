@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A labelled field of a recognised structure
+ */
+export type HexAnnotation = { offset: number, length: number, 
+/**
+ * Field path, e.g. `entries[3].path_hash`
+ */
+label: string, 
+/**
+ * The field's decoded value
+ */
+value: string, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HexAnnotation } from "./HexAnnotation";
+import type { HexRow } from "./HexRow";
+
+/**
+ * A window of a file as hex, with the known fields inside it
+ */
+export type HexDump = { offset: number, file_size: number, 
+/**
+ * Recognised format, e.g. "WAD v3.4"
+ */
+structure: string | null, rows: Array<HexRow>, 
+/**
+ * Fields overlapping the window, in file order
+ */
+annotations: Array<HexAnnotation>, 
+/**
+ * Where the structure stopped making sense (truncated or corrupt data)
+ */
+problem: string | null, };
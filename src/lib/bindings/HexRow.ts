@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One row of the hex view
+ */
+export type HexRow = { offset: number, 
+/**
+ * Space separated uppercase bytes
+ */
+hex: string, 
+/**
+ * Printable ASCII, with `.` for everything else
+ */
+ascii: string, };
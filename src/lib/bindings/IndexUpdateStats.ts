@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What an index update had to do
+ */
+export type IndexUpdateStats = { 
+/**
+ * WADs whose records were kept from the persisted index
+ */
+reused: number, 
+/**
+ * WADs that were new or changed and had to be rescanned
+ */
+rebuilt: number, 
+/**
+ * WADs that no longer exist in the installation
+ */
+removed: number, 
+/**
+ * WADs that couldn't be read
+ */
+failed: number, 
+/**
+ * Total chunks in the index
+ */
+chunk_count: number, };
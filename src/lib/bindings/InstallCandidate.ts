@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InstallSource } from "./InstallSource";
+
+/**
+ * A possible League install
+ */
+export type InstallCandidate = { 
+/**
+ * Install root, the folder holding `Game`
+ */
+path: string, source: InstallSource, 
+/**
+ * Game version from `Game/content-metadata.json` or the game executable
+ */
+version: string | null, 
+/**
+ * Whether the folder passes the same checks as a manually set path
+ */
+valid: boolean, 
+/**
+ * Why the folder isn't valid
+ */
+problem: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where an install was found; earlier variants rank higher
+ */
+export type InstallSource = "riot_client" | "running" | "registry" | "garena" | "tencent" | "common_path";
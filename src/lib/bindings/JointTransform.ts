@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Transform data for a single joint at a specific time
+ */
+export type JointTransform = { 
+/**
+ * Rotation quaternion (x, y, z, w)
+ */
+rotation: [number, number, number, number], 
+/**
+ * Translation vector
+ */
+translation: [number, number, number], 
+/**
+ * Scale vector
+ */
+scale: [number, number, number], };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RepathPlan } from "./RepathPlan";
+
+/**
+ * What repathing one non-base layer for one champion would do
+ */
+export type LayerPlan = { layer: string, champion: string, repath: RepathPlan, };
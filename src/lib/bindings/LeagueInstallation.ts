@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Represents a detected League of Legends installation
+ */
+export type LeagueInstallation = { 
+/**
+ * Path to the League of Legends installation directory
+ */
+path: string, 
+/**
+ * Path to the Game directory
+ */
+game_path: string, 
+/**
+ * Whether this was detected automatically or set manually
+ */
+auto_detected: boolean, };
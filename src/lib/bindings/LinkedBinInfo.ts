@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A BIN linked from the skin BIN
+ */
+export type LinkedBinInfo = { path: string, 
+/**
+ * Whether the champion WAD contains it; shared BINs usually live elsewhere
+ */
+in_wad: boolean, size: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LinkedDependency } from "./LinkedDependency";
+
+/**
+ * Result of resolving a linked BIN closure
+ */
+export type LinkedClosureReport = { dependencies: Array<LinkedDependency>, extracted_count: number, missing_count: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DependencyStatus } from "./DependencyStatus";
+
+/**
+ * A single dependency in the closure
+ */
+export type LinkedDependency = { 
+/**
+ * Normalized dependency path (lowercase, forward slashes)
+ */
+path: string, 
+/**
+ * BIN whose linked list references this dependency
+ */
+required_by: string, status: DependencyStatus, 
+/**
+ * Game WAD the dependency was found in, relative to DATA/FINAL when known
+ */
+source_wad: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Log entry sent to frontend
+ */
+export type LogEvent = { timestamp: number, level: string, target: string, message: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The machine a benchmark ran on
+ */
+export type MachineProfile = { os: string, arch: string, cpu_model: string | null, logical_cores: number, app_version: string, 
+/**
+ * Debug builds are several times slower; their numbers don't compare
+ */
+release_build: boolean, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Material data including texture and UV parameters for frontend consumption
+ */
+export type MaterialData = { 
+/**
+ * Base64-encoded PNG texture data
+ */
+texture: string, 
+/**
+ * UV scale (tiling) - [scaleU, scaleV]
+ */
+uv_scale: [number, number] | null, 
+/**
+ * UV offset (shift) - [offsetU, offsetV]
+ */
+uv_offset: [number, number] | null, 
+/**
+ * Flipbook texture atlas size - [columns, rows]
+ */
+flipbook_size: [number, number] | null, 
+/**
+ * Current flipbook frame index
+ */
+flipbook_frame: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One shader constant of a material
+ */
+export type MaterialParam = { name: string, value: [number, number, number, number], };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaterialParam } from "./MaterialParam";
+
+/**
+ * The shader constants of a `StaticMaterialDef`
+ */
+export type MaterialParams = { object_hash: number, object_name: string | null, params: Array<MaterialParam>, };
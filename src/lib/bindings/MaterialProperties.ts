@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Extended material properties including UV transformations
+ */
+export type MaterialProperties = { 
+/**
+ * Diffuse texture path
+ */
+texture_path: string, 
+/**
+ * UV scale (tiling) - [scaleU, scaleV]
+ * From paramValue "UVScaleAndOffset" vec4[0,1]
+ */
+uv_scale: [number, number] | null, 
+/**
+ * UV offset (shift) - [offsetU, offsetV]  
+ * From paramValue "UVScaleAndOffset" vec4[2,3]
+ */
+uv_offset: [number, number] | null, 
+/**
+ * Flipbook texture atlas size - [columns, rows]
+ * From paramValue "FlipbookSize" vec4[0,1]
+ */
+flipbook_size: [number, number] | null, 
+/**
+ * Current flipbook frame index
+ * From paramValue "FrameIndex" vec4[0]
+ */
+flipbook_frame: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Material range data for frontend consumption
+ */
+export type MaterialRange = { name: string, start_index: number, index_count: number, start_vertex: number, vertex_count: number, };
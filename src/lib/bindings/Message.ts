@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A user-facing message: catalog key, parameters and English text
+ *
+ * Also the error type of every Tauri command, so failures reach the frontend
+ * as `{ key, params, message }` instead of a bare string.
+ */
+export type Message = { key: string, params: { [key in string]?: string }, 
+/**
+ * English rendering of the message
+ */
+message: string, };
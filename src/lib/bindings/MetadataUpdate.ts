@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProjectAuthor } from "./ProjectAuthor";
+import type { ProjectLicense } from "./ProjectLicense";
+import type { VersionBump } from "./VersionBump";
+
+/**
+ * Changes to a project's metadata; fields left as None are kept
+ */
+export type MetadataUpdate = { 
+/**
+ * New slug: letters, digits, `_` and `-` only
+ */
+name: string | null, display_name: string | null, 
+/**
+ * New semver version; can't be combined with `bump`
+ */
+version: string | null, 
+/**
+ * Increment the current version instead of setting one
+ */
+bump: VersionBump | null, description: string | null, 
+/**
+ * Replaces the author list
+ */
+authors: Array<ProjectAuthor> | null, 
+/**
+ * New license; an empty name removes it
+ */
+license: ProjectLicense | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Represents a missing asset reference
+ */
+export type MissingAsset = { 
+/**
+ * The path that was referenced
+ */
+path: string, 
+/**
+ * Hash of the path (if available)
+ */
+path_hash: number | null, 
+/**
+ * Source file that contains this reference
+ */
+source_file: string, 
+/**
+ * Asset type based on file extension
+ */
+asset_type: string, };
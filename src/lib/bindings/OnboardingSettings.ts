@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnboardingStep } from "./OnboardingStep";
+
+/**
+ * Persisted onboarding progress and the values chosen along the way
+ */
+export type OnboardingSettings = { league_path: string | null, creator_name: string | null, output_directory: string | null, completed: Array<OnboardingStep>, skipped: Array<OnboardingStep>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnboardingSettings } from "./OnboardingSettings";
+import type { OnboardingStep } from "./OnboardingStep";
+import type { OnboardingStepStatus } from "./OnboardingStepStatus";
+
+/**
+ * Full onboarding status
+ */
+export type OnboardingStatus = { steps: Array<OnboardingStepStatus>, 
+/**
+ * Step the flow should show next, None once every step is done
+ */
+current_step: OnboardingStep | null, complete: boolean, settings: OnboardingSettings, 
+/**
+ * Whether hash files are already present, so the download step can be
+ * presented as optional
+ */
+hashes_present: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One step of the first-run flow, in the order they are presented
+ */
+export type OnboardingStep = "league_path" | "hash_download" | "creator_name" | "output_directory";
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OnboardingStep } from "./OnboardingStep";
+import type { StepState } from "./StepState";
+
+/**
+ * State of one step as reported to the frontend
+ */
+export type OnboardingStepStatus = { step: OnboardingStep, state: StepState, };
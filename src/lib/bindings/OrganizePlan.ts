@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LayerPlan } from "./LayerPlan";
+import type { TargetPlan } from "./TargetPlan";
+
+/**
+ * What [`organize_layers`] would do, worked out without writing anything
+ *
+ * When concatenation runs, the repath plan lists rewrites in the source BINs;
+ * the real run makes the same rewrites in the concat BIN built from them.
+ */
+export type OrganizePlan = { layers: Array<LayerPlan>, 
+/**
+ * The primary champion first, then the extra targets
+ */
+targets: Array<TargetPlan>, 
+/**
+ * `.ritobin` caches removed with their BIN, relative to `content/base`
+ */
+orphan_caches: Array<string>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A progress update from one of the organize steps
+ */
+export type OrganizeProgress = { 
+/**
+ * "concat", "scan", "repath" or "relocate"
+ */
+phase: string, current: number, total: number, 
+/**
+ * BIN or asset being processed
+ */
+item: string | null, };
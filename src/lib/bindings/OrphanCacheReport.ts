@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of removing orphaned caches
+ */
+export type OrphanCacheReport = { 
+/**
+ * Removed cache files, relative to the scanned directory
+ */
+removed: Array<string>, 
+/**
+ * Total size of the removed files in bytes
+ */
+reclaimed_bytes: number, };
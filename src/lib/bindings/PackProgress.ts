@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A progress update sent after each entry is written
+ */
+export type PackProgress = { current: number, total: number, bytes_written: number, total_bytes: number, 
+/**
+ * Entry that was just written
+ */
+item: string, };
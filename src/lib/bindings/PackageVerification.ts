@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of verifying an exported package
+ */
+export type PackageVerification = { 
+/**
+ * True if nothing below is wrong
+ */
+valid: boolean, 
+/**
+ * Files found in the package
+ */
+files_checked: number, 
+/**
+ * Project files that didn't make it into the package
+ */
+missing_files: Array<string>, 
+/**
+ * Packed files with no content
+ */
+empty_files: Array<string>, 
+/**
+ * Packed BINs that don't parse, with the reason
+ */
+invalid_bins: Array<string>, 
+/**
+ * Main skin BIN in the package, if found
+ */
+main_bin: string | null, 
+/**
+ * BINs reached from the main skin BIN, itself included
+ */
+bins_followed: number, 
+/**
+ * Paths the main skin BIN chain references that nothing provides
+ */
+unresolved_references: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Maps colors close to `from` onto `to`
+ */
+export type PaletteMapping = { from: [number, number, number], to: [number, number, number], };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A BIN whose references a repath would rewrite
+ */
+export type PlannedBinRewrite = { bin: string, paths_modified: number, };
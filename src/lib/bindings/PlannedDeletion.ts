@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file an organize step would delete
+ */
+export type PlannedDeletion = { path: string, 
+/**
+ * Why, e.g. "unreferenced" or "wrong skin"
+ */
+reason: string, };
@@ -0,0 +1,87 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionTarget } from "./ChampionTarget";
+import type { ProjectAuthor } from "./ProjectAuthor";
+import type { ProjectLicense } from "./ProjectLicense";
+import type { RepathOptions } from "./RepathOptions";
+import type { TargetKind } from "./TargetKind";
+
+/**
+ * Represents a Flint mod project (runtime representation)
+ * 
+ * This struct combines league-mod compatible ModProject with Flint-specific
+ * champion/skin data needed for the extraction workflow.
+ */
+export type Project = { 
+/**
+ * The name of the mod (slug format, no spaces)
+ */
+name: string, 
+/**
+ * The display name of the mod
+ */
+display_name: string, 
+/**
+ * The version of the mod (semver format)
+ */
+version: string, 
+/**
+ * The description of the mod
+ */
+description: string, 
+/**
+ * Layers of the mod project
+ */
+layers: Array<{ name: string, priority: number, description?: string }>, 
+/**
+ * Authors of the mod, with optional roles
+ */
+authors: Array<ProjectAuthor>, 
+/**
+ * License of the mod
+ */
+license: ProjectLicense | null, 
+/**
+ * Thumbnail image, relative to the project folder
+ */
+thumbnail: string | null, 
+/**
+ * Champion internal name (e.g., "Ahri"), or WAD name for map and
+ * global projects - Flint specific
+ */
+champion: string, 
+/**
+ * Champion, map or global WAD project - Flint specific
+ */
+target_kind: TargetKind, 
+/**
+ * Skin ID (0 for base skin) - Flint specific
+ */
+skin_id: number, 
+/**
+ * Champion skins used besides the primary one - Flint specific
+ */
+extra_targets: Array<ChampionTarget>, 
+/**
+ * Game client version the project was extracted from - Flint specific
+ */
+game_version: string | null, 
+/**
+ * User-defined BIN text variables - Flint specific
+ */
+variables: { [key in string]?: string }, 
+/**
+ * Whether BIN to text conversion collapses variable values - Flint specific
+ */
+collapse_variables: boolean, 
+/**
+ * Layers excluded from export - Flint specific
+ */
+disabled_layers: Array<string>, 
+/**
+ * Repath prefix template and strategy - Flint specific
+ */
+repath: RepathOptions, 
+/**
+ * Path to the project directory
+ */
+project_path: string, };
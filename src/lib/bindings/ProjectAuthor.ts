@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An author of the mod, optionally credited with a role (e.g. "Textures")
+ *
+ * Reads both league-mod forms: a plain name string or `{ name, role }`.
+ */
+export type ProjectAuthor = { name: string, role: string | null, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CompatibilityStatus } from "./CompatibilityStatus";
+
+/**
+ * Result of comparing a project with its League installation
+ */
+export type ProjectCompatibility = { 
+/**
+ * Game version recorded when the project was extracted
+ */
+project_version: string | null, 
+/**
+ * Game version of the linked League installation
+ */
+install_version: string | null, status: CompatibilityStatus, 
+/**
+ * Whether updating the project's assets before validating or exporting is advised
+ */
+update_advised: boolean, 
+/**
+ * Explanation to show the user, None when the versions match
+ */
+warning: string | null, };
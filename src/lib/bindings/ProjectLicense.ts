@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * License of the mod: an SPDX identifier (e.g. "MIT"), or a custom
+ * license name with a link to its text
+ */
+export type ProjectLicense = { name: string, 
+/**
+ * Link to the license text; None for SPDX identifiers
+ */
+url: string | null, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProjectAuthor } from "./ProjectAuthor";
+import type { ProjectLicense } from "./ProjectLicense";
+
+/**
+ * The mod.config.json metadata of a project
+ */
+export type ProjectMetadata = { 
+/**
+ * Slug used for package file names and repath prefixes
+ */
+name: string, display_name: string, 
+/**
+ * Semver version
+ */
+version: string, description: string, authors: Array<ProjectAuthor>, license: ProjectLicense | null, 
+/**
+ * Thumbnail image, relative to the project folder
+ */
+thumbnail: string | null, };
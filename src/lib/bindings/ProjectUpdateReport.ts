@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of updating a project to the installed patch
+ */
+export type ProjectUpdateReport = { 
+/**
+ * Game version the project was extracted from before the update
+ */
+previous_game_version: string | null, 
+/**
+ * Game version of the League installation now
+ */
+game_version: string | null, 
+/**
+ * Files replaced with the new vanilla version
+ */
+updated: Array<string>, 
+/**
+ * Files whose vanilla version changed but which the author edited, left as they were
+ */
+preserved: Array<string>, 
+/**
+ * BINs whose vanilla version changed; preserved ones need their edits merged by hand
+ */
+flagged_bins: Array<string>, 
+/**
+ * Extracted chunks the new WAD no longer contains
+ */
+removed_upstream: Array<string>, 
+/**
+ * Extracted chunks the patch didn't touch
+ */
+unchanged: number, 
+/**
+ * False if the project had no manifest and edits were detected by modification time
+ */
+used_manifest: boolean, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Project } from "./Project";
+import type { RawModLayout } from "./RawModLayout";
+
+/**
+ * Outcome of [`import_raw_mod`]
+ */
+export type RawImportResult = { project: Project, layout: RawModLayout, files_copied: number, 
+/**
+ * Repath prefix found under `assets/`, e.g. `bob/ahrimod`
+ */
+detected_prefix: string | null, 
+/**
+ * Whether the prefix was taken off the files and BIN references
+ */
+prefix_removed: boolean, 
+/**
+ * BIN strings that were pointed back at the un-prefixed paths
+ */
+references_restored: number, warnings: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How the files of a raw mod are laid out
+ */
+export type RawModLayout = "loose" | "wad_folders";
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConcatMergePolicy } from "./ConcatMergePolicy";
+
+/**
+ * How a project wants its assets repathed, stored in flint.json
+ */
+export type RepathOptions = { 
+/**
+ * Folder under `ASSETS/` that repathed assets move to, e.g.
+ * `{creator}/{champion}_skin{skin}`. Placeholders: `{creator}`,
+ * `{project}`, `{champion}`, `{skin}` and `{version}` (the mod version).
+ * Defaults to `{creator}/{project}`.
+ */
+prefix_template: string | null, 
+/**
+ * Extensions (e.g. `wpk`, `bnk`) whose files keep their original path;
+ * BIN references to them are left alone too
+ */
+skip_extensions: Array<string>, 
+/**
+ * Only rewrite the paths in BINs; no file is moved or cleaned up
+ */
+bins_only: boolean, 
+/**
+ * How concatenation settles objects defined by more than one linked BIN
+ */
+concat_policy: ConcatMergePolicy, };
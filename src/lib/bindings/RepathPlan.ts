@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PathChange } from "./PathChange";
+import type { PlannedBinRewrite } from "./PlannedBinRewrite";
+import type { PlannedDeletion } from "./PlannedDeletion";
+
+/**
+ * What repathing one content folder would do, worked out without writing anything
+ *
+ * File paths are relative to `folder`, with `/` separators.
+ */
+export type RepathPlan = { 
+/**
+ * Folder holding the files, relative to the content folder
+ * (`{champion}.wad.client`, or empty for legacy projects)
+ */
+folder: string, 
+/**
+ * Where assets move, e.g. `ASSETS/SirDexal/Star-Ahri`
+ */
+prefix: string, 
+/**
+ * BINs whose references change
+ */
+bins: Array<PlannedBinRewrite>, 
+/**
+ * Referenced asset paths and what the BINs say instead
+ */
+rewrites: Array<PathChange>, 
+/**
+ * Files moved to their repathed location
+ */
+relocations: Array<PathChange>, 
+/**
+ * Files removed by cleanup
+ */
+deletions: Array<PlannedDeletion>, 
+/**
+ * Paths referenced in BINs that aren't on disk
+ */
+missing_paths: Array<string>, };
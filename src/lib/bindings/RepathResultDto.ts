@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConcatCollision } from "./ConcatCollision";
+
+/**
+ * Result of repath operation (sent to frontend)
+ */
+export type RepathResultDto = { success: boolean, bins_processed: number, paths_modified: number, files_relocated: number, missing_paths: Array<string>, 
+/**
+ * Objects more than one linked BIN defined, and which definition was kept
+ */
+concat_collisions: Array<ConcatCollision>, 
+/**
+ * Why concatenation was skipped, per failed target
+ */
+concat_errors: Array<string>, message: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Document format of an exported validation report
+ */
+export type ReportFormat = "markdown" | "html";
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of [`restore_files`]
+ */
+export type RestoreResult = { 
+/**
+ * Files moved back, relative to the project root
+ */
+restored: Array<string>, 
+/**
+ * Files left in the trash because their original path is taken again
+ */
+conflicts: Array<string>, 
+/**
+ * Files no longer in the trash; dropped from the manifest
+ */
+missing: Array<string>, };
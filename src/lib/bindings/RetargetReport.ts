@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of retargeting an animation
+ */
+export type RetargetReport = { output_path: string, frame_count: number, 
+/**
+ * Target joints that received a track
+ */
+mapped_joints: number, 
+/**
+ * Target joints with no source joint; they stay in bind pose
+ */
+unmapped_joints: Array<string>, 
+/**
+ * Source tracks that no target joint uses
+ */
+dropped_tracks: number, };
@@ -0,0 +1,38 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Complete static mesh data serializable to JSON for frontend
+ */
+export type ScbMeshData = { 
+/**
+ * Mesh name from file
+ */
+name: string, 
+/**
+ * Material names present in the mesh
+ */
+materials: Array<string>, 
+/**
+ * Vertex positions as [x, y, z] arrays
+ */
+positions: Array<[number, number, number]>, 
+/**
+ * Vertex normals as [x, y, z] arrays (computed from faces)
+ */
+normals: Array<[number, number, number]>, 
+/**
+ * Texture coordinates as [u, v] arrays
+ */
+uvs: Array<[number, number]>, 
+/**
+ * Triangle indices
+ */
+indices: Array<number>, 
+/**
+ * Bounding box as [min, max] where each is [x, y, z]
+ */
+bounding_box: [[number, number, number], [number, number, number]], 
+/**
+ * Material ranges for per-material rendering (material_name -> (start_index, index_count))
+ */
+material_ranges: { [key in string]?: [number, number] }, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ShaderEntry } from "./ShaderEntry";
+
+/**
+ * Catalog of every shader observed in a set of BIN files
+ */
+export type ShaderCatalog = { shaders: Array<ShaderEntry>, 
+/**
+ * Number of BIN files that contained material definitions
+ */
+bins_scanned: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatalogField } from "./CatalogField";
+
+/**
+ * Conventions observed for a single shader
+ */
+export type ShaderEntry = { 
+/**
+ * Shader link path (e.g., "Shaders/SkinnedMesh/Default")
+ */
+shader: string, 
+/**
+ * Number of StaticMaterialDefs using this shader
+ */
+material_count: number, samplers: Array<CatalogField>, params: Array<CatalogField>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AudioBankUnit } from "./AudioBankUnit";
+
+/**
+ * The bank units of a BIN with their events resolved
+ */
+export type SkinAudioEvents = { bin_path: string, units: Array<AudioBankUnit>, };
@@ -0,0 +1,36 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetTypeSummary } from "./AssetTypeSummary";
+import type { LinkedBinInfo } from "./LinkedBinInfo";
+
+/**
+ * What a skin references and what extracting it will write
+ */
+export type SkinContentsPreview = { champion: string, skin_id: number, 
+/**
+ * Path of the skin BIN inside the WAD
+ */
+skin_bin: string, linked_bins: Array<LinkedBinInfo>, 
+/**
+ * Referenced assets grouped by type, largest first
+ */
+asset_types: Array<AssetTypeSummary>, 
+/**
+ * Distinct asset paths referenced by the skin and its linked BINs
+ */
+referenced_count: number, 
+/**
+ * Uncompressed size of the referenced files found in the WAD
+ */
+referenced_size: number, 
+/**
+ * Files project creation extracts (everything under `assets/` and `data/`)
+ */
+extraction_count: number, 
+/**
+ * Uncompressed size of those files
+ */
+extraction_size: number, 
+/**
+ * Linked BINs in the WAD that couldn't be parsed
+ */
+warnings: Array<string>, };
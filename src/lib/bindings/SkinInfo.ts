@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Represents a discovered skin
+ */
+export type SkinInfo = { 
+/**
+ * Skin ID (0 = base skin)
+ */
+id: number, 
+/**
+ * Skin name (from CommunityDragon metadata when available, otherwise generated)
+ */
+name: string, 
+/**
+ * Internal folder name (e.g., "Skin0", "Skin1")
+ */
+folder_name: string, 
+/**
+ * Skin ID of the skin this one is a chroma of
+ */
+chroma_of: number | null, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SubmeshInfo } from "./SubmeshInfo";
+
+/**
+ * The submeshes of an SKN and the skin BIN's defaults
+ */
+export type SkinSubmeshes = { 
+/**
+ * Texture of submeshes without an override
+ */
+default_texture: string | null, 
+/**
+ * Material of submeshes without an override
+ */
+default_material: string | null, 
+/**
+ * In SKN range order
+ */
+submeshes: Array<SubmeshInfo>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BoneData } from "./BoneData";
+
+/**
+ * Complete skeleton data serializable to JSON for frontend
+ */
+export type SklData = { name: string, asset_name: string, bones: Array<BoneData>, 
+/**
+ * Influence mapping array - maps vertex bone indices to actual bone IDs
+ * Vertex bone_indices[i] refers to influences[bone_indices[i]] which gives the actual bone ID
+ */
+influences: Array<number>, };
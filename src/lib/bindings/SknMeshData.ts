@@ -0,0 +1,50 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaterialData } from "./MaterialData";
+import type { MaterialRange } from "./MaterialRange";
+
+/**
+ * Complete mesh data serializable to JSON for frontend
+ */
+export type SknMeshData = { 
+/**
+ * Material ranges for visibility control
+ */
+materials: Array<MaterialRange>, 
+/**
+ * Vertex positions as [x, y, z] arrays
+ */
+positions: Array<[number, number, number]>, 
+/**
+ * Vertex normals as [x, y, z] arrays
+ */
+normals: Array<[number, number, number]>, 
+/**
+ * Texture coordinates as [u, v] arrays
+ */
+uvs: Array<[number, number]>, 
+/**
+ * Triangle indices
+ */
+indices: Array<number>, 
+/**
+ * Bounding box as [min, max] where each is [x, y, z]
+ */
+bounding_box: [[number, number, number], [number, number, number]], 
+/**
+ * Per-submesh textures as base64 PNG data (DEPRECATED - use material_data)
+ */
+textures: { [key in string]?: string }, 
+/**
+ * Per-material data including textures AND UV transform parameters
+ */
+material_data: { [key in string]?: MaterialData }, 
+/**
+ * Bone weights for skinning - 4 weights per vertex [w0, w1, w2, w3]
+ * Weights should sum to 1.0 for proper skinning
+ */
+bone_weights: Array<[number, number, number, number]>, 
+/**
+ * Bone indices for skinning - 4 bone indices per vertex [i0, i1, i2, i3]
+ * Each index refers to a bone in the skeleton
+ */
+bone_indices: Array<[number, number, number, number]>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Snapshot metadata, without the file manifest
+ */
+export type SnapshotInfo = { id: string, timestamp: string, message: string, tags: Array<string>, file_count: number, 
+/**
+ * Size of all files in the snapshot
+ */
+total_size: number, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditSourceKind } from "./AuditSourceKind";
+
+/**
+ * Resolution changes for one WAD or project
+ */
+export type SourceResolution = { kind: AuditSourceKind, 
+/**
+ * File name of the WAD or folder name of the project
+ */
+name: string, path: string, 
+/**
+ * Hashes the previous hashtable couldn't resolve
+ */
+previously_unknown: number, 
+/**
+ * Of those, how many the updated hashtable resolves
+ */
+now_resolved: number, 
+/**
+ * A few of the newly resolved paths
+ */
+examples: Array<string>, };
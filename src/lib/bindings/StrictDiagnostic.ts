@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StrictIssueKind } from "./StrictIssueKind";
+
+/**
+ * A single strict-mode finding
+ */
+export type StrictDiagnostic = { 
+/**
+ * 1-based line number
+ */
+line: number, 
+/**
+ * 1-based column number
+ */
+column: number, kind: StrictIssueKind, message: string, };
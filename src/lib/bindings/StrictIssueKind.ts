@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Category of a strict-mode problem
+ */
+export type StrictIssueKind = "UnknownProperty" | "UnknownType" | "MalformedHash" | "KindMismatch" | "MissingTypeHint" | "Syntax";
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StringTableEntry } from "./StringTableEntry";
+
+/**
+ * A parsed string table
+ */
+export type StringTable = { version: number, 
+/**
+ * Font config string of version 2 tables
+ */
+font_config: string | null, 
+/**
+ * Flag byte of versions before 5, kept as read
+ */
+mode: number, entries: Array<StringTableEntry>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One string of a table
+ */
+export type StringTableEntry = { 
+/**
+ * Key hash, truncated to the table's hash bits
+ */
+hash: number, 
+/**
+ * Key name; when set on write, the entry is stored under its hash instead of `hash`
+ */
+key: string | null, text: string, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A submesh of an SKN with what the skin BIN sets for it
+ */
+export type SubmeshInfo = { name: string, 
+/**
+ * Texture of its `materialOverride` entry
+ */
+texture: string | null, 
+/**
+ * Material linked by its `materialOverride` entry (path, or hex hash if unknown)
+ */
+material: string | null, 
+/**
+ * Listed in `initialSubmeshToHide`
+ */
+hidden: boolean, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConcatPlan } from "./ConcatPlan";
+import type { RepathPlan } from "./RepathPlan";
+
+/**
+ * What organizing one champion's WAD folder would do
+ */
+export type TargetPlan = { champion: string, 
+/**
+ * Folder the plan's paths are relative to, inside `content/base`
+ */
+folder: string, concat: ConcatPlan | null, repath: RepathPlan | null, 
+/**
+ * Steps that would be skipped or fail
+ */
+warnings: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TexFormat } from "./TexFormat";
+
+/**
+ * Result of a conversion to TEX
+ */
+export type TexConversion = { output_path: string, format: TexFormat, width: number, height: number, mip_count: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * TEX formats Flint can write
+ */
+export type TexFormat = "bc1" | "bc3" | "bgra8";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiffHunk } from "./DiffHunk";
+
+/**
+ * Structured line diff of two texts
+ */
+export type TextDiff = { hunks: Array<DiffHunk>, added: number, removed: number, old_line_count: number, new_line_count: number, 
+/**
+ * The texts differed too much for a minimal diff; part of it is shown
+ * as one removed block followed by one added block
+ */
+approximate: boolean, };
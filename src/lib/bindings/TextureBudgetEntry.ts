@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Estimated memory of one texture shipped by the mod
+ */
+export type TextureBudgetEntry = { 
+/**
+ * Path relative to the project content directory
+ */
+path: string, format: string, width: number, height: number, mip_count: number, vram_bytes: number, 
+/**
+ * Game path of the texture this one replaces, if found
+ */
+original_path: string | null, original_vram_bytes: number | null, warnings: Array<string>, };
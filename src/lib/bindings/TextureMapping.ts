@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaterialProperties } from "./MaterialProperties";
+
+/**
+ * Texture mapping extracted from BIN file with UV transform parameters
+ */
+export type TextureMapping = { 
+/**
+ * Default texture path for meshes without specific override
+ */
+default_texture: string | null, 
+/**
+ * Per-material properties including texture and UV transforms
+ * Key = submesh/material name, Value = material properties
+ */
+material_properties: { [key in string]?: MaterialProperties }, 
+/**
+ * Static material references that couldn't be resolved (for debugging)
+ */
+static_materials: Array<string>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of replacing a texture
+ */
+export type TextureReplacement = { 
+/**
+ * Encoding the file was written in, e.g. "TEX Bc3"
+ */
+format: string, width: number, height: number, mip_count: number, 
+/**
+ * Size of the original texture
+ */
+previous_width: number, previous_height: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How to show a decoded texture
+ */
+export type TextureView = "rgba" | "rgb" | "red" | "green" | "blue" | "alpha" | "normal" | "checkerboard";
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A thumbnail, no larger than the requested size on either side
+ */
+export type Thumbnail = { 
+/**
+ * Base64-encoded PNG data
+ */
+data: string, width: number, height: number, 
+/**
+ * Served from the cache without decoding the source
+ */
+cached: boolean, };
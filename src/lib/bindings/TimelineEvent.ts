@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AnimationEventKind } from "./AnimationEventKind";
+
+/**
+ * A single event on the clip timeline
+ */
+export type TimelineEvent = { kind: AnimationEventKind, 
+/**
+ * Sound event name, particle effect key, or event class hash for others
+ */
+name: string, start_frame: number, start_time: number, end_time: number | null, is_loop: boolean, 
+/**
+ * Wwise short ID of the sound event (FNV-1 of the lowercased name)
+ */
+wwise_event_id: number | null, };
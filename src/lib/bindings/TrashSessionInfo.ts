@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TrashedFile } from "./TrashedFile";
+
+/**
+ * A trash session as listed for the user
+ */
+export type TrashSessionInfo = { 
+/**
+ * Session folder name, also the time it was created (`YYYYMMDD-HHMMSS.mmm`)
+ */
+id: string, files: Array<TrashedFile>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file moved into the trash
+ */
+export type TrashedFile = { 
+/**
+ * Where the file was, relative to the project root, with `/` separators
+ */
+original: string, 
+/**
+ * Where it is kept, relative to the session folder
+ */
+stored: string, 
+/**
+ * Why cleanup removed it, e.g. "wrong skin"
+ */
+reason: string, };
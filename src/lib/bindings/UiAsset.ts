@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UiAssetKind } from "./UiAssetKind";
+
+/**
+ * A chunk of a UI WAD
+ */
+export type UiAsset = { 
+/**
+ * Hex path hash
+ */
+path_hash: string, 
+/**
+ * Resolved path, if the hash is known
+ */
+path: string | null, kind: UiAssetKind, 
+/**
+ * Uncompressed size in bytes
+ */
+size: number, };
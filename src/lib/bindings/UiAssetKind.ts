@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a UI chunk holds, guessed from its path
+ */
+export type UiAssetKind = "font" | "hud_atlas" | "texture" | "data" | "other";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FontInfo } from "./FontInfo";
+
+/**
+ * Preview of a UI chunk, shaped by what the chunk turned out to be
+ */
+export type UiAssetPreview = { "type": "image", data: string, width: number, height: number, } | { "type": "font", info: FontInfo, data: string, } | { "type": "text", text: string, truncated: boolean, } | { "type": "binary", size: number, };
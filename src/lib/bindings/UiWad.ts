@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GameWadEntry } from "./GameWadEntry";
+
+/**
+ * A UI archive in the League installation
+ */
+export type UiWad = { wad: GameWadEntry, 
+/**
+ * Locale of a localized UI WAD (e.g. "en_US"), None for the shared one
+ */
+locale: string | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A chunk or extracted file without a known path
+ */
+export type UnknownChunk = { 
+/**
+ * Path hash, 16 hex digits
+ */
+hash: string, 
+/**
+ * Extension of the detected file kind, if the content was recognized
+ */
+kind: string | null, 
+/**
+ * Uncompressed size in bytes
+ */
+size: number, 
+/**
+ * Guessed path whose hash matches
+ */
+guess: string | null, };
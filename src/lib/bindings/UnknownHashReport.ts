@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UnknownChunk } from "./UnknownChunk";
+
+/**
+ * Unknown hashes of one WAD or project
+ */
+export type UnknownHashReport = { path: string, 
+/**
+ * Chunks in the WAD, or files in the project's content folder
+ */
+total: number, 
+/**
+ * Sorted by hash
+ */
+unknown: Array<UnknownChunk>, 
+/**
+ * Champions paths were guessed for
+ */
+champions: Array<string>, 
+/**
+ * Unknown chunks a guess named
+ */
+guessed: number, };
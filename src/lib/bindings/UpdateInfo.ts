@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpdateInfo = { available: boolean, current_version: string, latest_version: string, release_notes: string, download_url: string, published_at: string, };
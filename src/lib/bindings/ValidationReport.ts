@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetTypeStats } from "./AssetTypeStats";
+import type { GameProvidedAsset } from "./GameProvidedAsset";
+import type { MissingAsset } from "./MissingAsset";
+
+/**
+ * Validation report for asset references
+ */
+export type ValidationReport = { 
+/**
+ * Total number of asset references found
+ */
+total_references: number, 
+/**
+ * Number of valid (existing) references
+ */
+valid_references: number, 
+/**
+ * List of missing assets
+ */
+missing_assets: Array<MissingAsset>, 
+/**
+ * Valid references the mod doesn't ship, loaded from vanilla game WADs
+ */
+provided_by_game: Array<GameProvidedAsset>, 
+/**
+ * Summary statistics by asset type
+ */
+stats_by_type: { [key in string]?: AssetTypeStats }, };
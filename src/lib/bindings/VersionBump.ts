@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Part of a semver version to increment
+ */
+export type VersionBump = "major" | "minor" | "patch";
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinKind } from "./BinKind";
+
+/**
+ * One color value of a particle system
+ */
+export type VfxColor = { 
+/**
+ * `emitterName` of the emitter the color belongs to
+ */
+emitter: string | null, 
+/**
+ * Path from the system object, as `set_bin_property` takes it
+ */
+property_path: Array<string>, 
+/**
+ * `vec4` (float, may exceed 1.0) or `rgba` (bytes)
+ */
+kind: BinKind, 
+/**
+ * RGBA, normalized to 0..1 for `rgba` values
+ */
+value: [number, number, number, number], };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PaletteMapping } from "./PaletteMapping";
+
+/**
+ * How colors are changed
+ */
+export type VfxRecolor = { "mode": "hsv", 
+/**
+ * Degrees added to the hue
+ */
+hue: number, 
+/**
+ * Saturation multiplier (1.0 keeps it)
+ */
+saturation: number, 
+/**
+ * Value multiplier (1.0 keeps it)
+ */
+value: number, } | { "mode": "palette", mappings: Array<PaletteMapping>, 
+/**
+ * Colors farther than this (RGB distance) from every `from` stay
+ */
+max_distance: number | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VfxSystemColors } from "./VfxSystemColors";
+
+/**
+ * Outcome of a recolor
+ */
+export type VfxRecolorResult = { 
+/**
+ * Systems that were recolored, with their new colors
+ */
+systems: Array<VfxSystemColors>, 
+/**
+ * Color values that changed
+ */
+changed: number, };
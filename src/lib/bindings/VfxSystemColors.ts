@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VfxColor } from "./VfxColor";
+
+/**
+ * The colors of one `VfxSystemDefinitionData` object
+ */
+export type VfxSystemColors = { object_hash: number, object_name: string | null, colors: Array<VfxColor>, };
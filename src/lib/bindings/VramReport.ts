@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TextureBudgetEntry } from "./TextureBudgetEntry";
+
+/**
+ * Texture memory estimate for a whole project
+ */
+export type VramReport = { 
+/**
+ * Textures sorted by estimated memory, largest first
+ */
+textures: Array<TextureBudgetEntry>, 
+/**
+ * Memory of every texture the mod ships
+ */
+total_vram_bytes: number, 
+/**
+ * Memory of the textures that replace a game texture
+ */
+replaced_vram_bytes: number, 
+/**
+ * Memory of the game textures they replace
+ */
+original_vram_bytes: number, 
+/**
+ * Files with a texture extension that couldn't be parsed
+ */
+unreadable: Array<string>, 
+/**
+ * Project-wide warnings
+ */
+warnings: Array<string>, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A chunk that differs between the two WADs
+ */
+export type WadChunkDiff = { 
+/**
+ * Path hash in hex
+ */
+path_hash: string, 
+/**
+ * Resolved path, if the hash is known
+ */
+path: string | null, 
+/**
+ * Uncompressed size in the old WAD (not set for added chunks)
+ */
+old_size: number | null, 
+/**
+ * Uncompressed size in the new WAD (not set for removed chunks)
+ */
+new_size: number | null, };
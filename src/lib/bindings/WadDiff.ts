@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WadChunkDiff } from "./WadChunkDiff";
+
+/**
+ * Differences between two WAD files
+ */
+export type WadDiff = { old_path: string, new_path: string, 
+/**
+ * Chunks only in the new WAD
+ */
+added: Array<WadChunkDiff>, 
+/**
+ * Chunks only in the old WAD
+ */
+removed: Array<WadChunkDiff>, 
+/**
+ * Chunks in both whose contents changed
+ */
+modified: Array<WadChunkDiff>, 
+/**
+ * Chunks in both with identical contents
+ */
+unchanged: number, };
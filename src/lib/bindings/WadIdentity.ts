@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WadTarget } from "./WadTarget";
+
+/**
+ * The inferred target of a WAD file
+ */
+export type WadIdentity = { target: WadTarget, 
+/**
+ * Champion internal name (lowercase, as in `data/characters/`) or map
+ * name (`map11`)
+ */
+name: string | null, 
+/**
+ * Locale of a localized WAD (e.g. "en_US")
+ */
+locale: string | null, 
+/**
+ * Skins with a skin BIN in the WAD, for champion WADs
+ */
+skin_ids: Array<number>, 
+/**
+ * The file name pointed at the target
+ */
+from_file_name: boolean, 
+/**
+ * Chunk paths pointed at the target
+ */
+from_chunk_paths: boolean, chunk_count: number, 
+/**
+ * Sampled chunks whose path the hashtable resolved
+ */
+resolved_sampled: number, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of merging an external WAD into a project
+ */
+export type WadImportResult = { 
+/**
+ * WAD folder the chunks were merged into (e.g. "ahri.wad.client")
+ */
+wad_folder: string, 
+/**
+ * Number of chunks written
+ */
+imported_count: number, 
+/**
+ * Number of chunks that replaced a file already in the project
+ */
+replaced_count: number, 
+/**
+ * Mapping of original paths to actual paths (renamed or shortened chunks)
+ */
+path_mappings: { [key in string]?: string }, 
+/**
+ * Chunks that couldn't be imported or had to be renamed
+ */
+warnings: Array<string>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of packing a WAD
+ */
+export type WadPackResult = { output_path: string, chunk_count: number, 
+/**
+ * Uncompressed size of all chunks
+ */
+total_size: number, 
+/**
+ * Size of the written WAD
+ */
+packed_size: number, 
+/**
+ * Files left out, e.g. a second file for the same chunk
+ */
+warnings: Array<string>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A search match
+ */
+export type WadSearchHit = { 
+/**
+ * WAD path relative to DATA/FINAL
+ */
+wad: string, 
+/**
+ * Resolved chunk path, or the hex hash if unknown
+ */
+path: string, 
+/**
+ * Hex path hash
+ */
+path_hash: string, size: number, };
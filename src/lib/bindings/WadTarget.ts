@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a WAD holds data for
+ */
+export type WadTarget = "champion" | "map" | "ui" | "global" | "unknown";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileChange } from "./FileChange";
+
+/**
+ * Uncommitted changes of a project
+ */
+export type WorkingTreeDiff = { 
+/**
+ * Commit the changes are relative to; `None` before the first commit
+ */
+head: string | null, files: Array<FileChange>, };
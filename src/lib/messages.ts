@@ -0,0 +1,43 @@
+/**
+ * Flint - Backend Message Localization
+ * Renders catalog messages sent by the backend in the user's language
+ */
+
+/**
+ * A message as sent by the backend: command errors and progress events
+ * carry a catalog key, named parameters and the English text
+ */
+export interface BackendMessage {
+    key: string;
+    params: Record<string, string>;
+    message: string;
+}
+
+/**
+ * Templates per language, keyed by message key. Keys missing from a
+ * language fall back to the English text the backend already rendered.
+ */
+const translations: Record<string, Record<string, string>> = {};
+
+/**
+ * Register templates for a language (e.g. 'de', 'pt-BR')
+ */
+export function registerTranslations(language: string, templates: Record<string, string>): void {
+    translations[language] = { ...translations[language], ...templates };
+}
+
+export function isBackendMessage(value: unknown): value is BackendMessage {
+    return typeof value === 'object' && value !== null
+        && typeof (value as BackendMessage).key === 'string'
+        && typeof (value as BackendMessage).message === 'string';
+}
+
+/**
+ * Render a backend message, trying the full language tag, then its base language
+ */
+export function formatMessage(msg: BackendMessage, language: string = navigator.language): string {
+    const template = translations[language]?.[msg.key]
+        ?? translations[language.split('-')[0]]?.[msg.key];
+    if (!template) return msg.message;
+    return template.replace(/\{(\w+)\}/g, (match, name: string) => msg.params[name] ?? match);
+}
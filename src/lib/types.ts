@@ -2,6 +2,8 @@
  * Flint - TypeScript Type Definitions
  */
 
+import type { RepathOptions } from './bindings/RepathOptions';
+
 // =============================================================================
 // Application State Types
 // =============================================================================
@@ -45,10 +47,12 @@ export interface Project {
     display_name?: string;
     champion: string;
     skin_id: number;
+    extra_targets?: { champion: string; skin_id: number }[];
     creator?: string;
     version?: string;
     description?: string;
     project_path?: string;
+    repath?: RepathOptions;
 }
 
 export interface Champion {